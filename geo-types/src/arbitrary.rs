@@ -2,7 +2,8 @@ use crate::{
     Coord, CoordFloat, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
     MultiPolygon, Point, Polygon, Rect, Triangle,
 };
-use std::mem;
+use alloc::vec::Vec;
+use core::mem;
 
 impl<'a, T> arbitrary::Arbitrary<'a> for Coord<T>
 where
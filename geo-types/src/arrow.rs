@@ -0,0 +1,646 @@
+//! Reading and writing geometries as the buffers of [GeoArrow] arrays, so that columnar data
+//! can be handed to geometry algorithms without converting every feature.
+//!
+//! A GeoArrow array of line strings, say, is a buffer of coordinates and a buffer of offsets:
+//! the coordinates of the `i`th line string are those between `offsets[i]` and
+//! `offsets[i + 1]`. Polygons add a level of offsets, from polygons to rings, and multi-polygons
+//! another, from multi-polygons to polygons. Multi-points have the layout of line strings, and
+//! multi-line strings the layout of polygons.
+//!
+//! Only coordinates stored interleaved, as `[x0, y0, x1, y1, ...]`, and `i32` offsets are read
+//! directly. Such a buffer *is* a slice of [`Coord<f64>`]s, so [`LineStringArray`],
+//! [`PolygonArray`] and [`MultiPolygonArray`] borrow their buffers, and give out the coordinates
+//! of their geometries, and of parts of them, without copying them. They're copied only to build
+//! geometries, and a single `LineString` can be refilled with the coordinates of every line
+//! string in turn to avoid allocating one for each.
+//!
+//! The buffers are those of any Arrow implementation, e.g. the `values()` of the arrays of
+//! arrow-rs. Coordinates stored separately, as an `x` and a `y` buffer, can be interleaved with
+//! [`interleave_coords`].
+//!
+//! [GeoArrow]: https://github.com/geoarrow/geoarrow/blob/main/format.md
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::arrow::{LineStringArray, LineStringBuffers};
+//! use geo_types::{line_string, LineString};
+//!
+//! let coords = [0., 0., 1., 1., 2., 0., 10., 10., 11., 11.];
+//! let offsets = [0, 3, 5];
+//! let array = LineStringArray::try_new(&offsets, &coords).unwrap();
+//! assert_eq!(array.len(), 2);
+//! assert_eq!(array.line_string(1), line_string![(x: 10., y: 10.), (x: 11., y: 11.)]);
+//!
+//! // a single allocation, reused for every line string
+//! let mut line_string = LineString::new(vec![]);
+//! for i in 0..array.len() {
+//!     line_string.0.clear();
+//!     line_string.0.extend_from_slice(array.coords(i));
+//!     // ...
+//! }
+//!
+//! let mut buffers = LineStringBuffers::new();
+//! buffers.push_line_string(&array.line_string(0));
+//! buffers.push_line_string(&array.line_string(1));
+//! assert_eq!(buffers.geom_offsets, offsets);
+//! assert_eq!(buffers.coords, coords);
+//! ```
+
+use crate::{Coord, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Range;
+
+/// The ways in which reading [GeoArrow](crate::arrow) buffers can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeoArrowError {
+    /// A buffer of interleaved coordinates with an odd number of values.
+    OddCoordinates(usize),
+    /// Separate `x` and `y` buffers of different lengths.
+    MismatchedCoordinates { x: usize, y: usize },
+    /// Offsets which are negative, decreasing, or beyond the end of what they point into.
+    InvalidOffsets,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GeoArrowError {}
+
+impl fmt::Display for GeoArrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoArrowError::OddCoordinates(len) => {
+                write!(
+                    f,
+                    "{} interleaved coordinate values isn't an even number",
+                    len
+                )
+            }
+            GeoArrowError::MismatchedCoordinates { x, y } => {
+                write!(f, "{} x values don't match {} y values", x, y)
+            }
+            GeoArrowError::InvalidOffsets => write!(f, "Invalid GeoArrow offsets"),
+        }
+    }
+}
+
+/// View a buffer of interleaved `x` and `y` values as coordinates, without copying it.
+pub fn coords_from_interleaved(values: &[f64]) -> Result<&[Coord<f64>], GeoArrowError> {
+    if values.len() & 1 == 1 {
+        return Err(GeoArrowError::OddCoordinates(values.len()));
+    }
+    // SAFETY: `Coord<f64>` is `repr(C)`, with two `f64` fields, so it has the size of two `f64`s
+    // and their alignment, and `values` holds a whole number of them.
+    Ok(unsafe {
+        core::slice::from_raw_parts(values.as_ptr() as *const Coord<f64>, values.len() / 2)
+    })
+}
+
+/// View coordinates as a buffer of interleaved `x` and `y` values, without copying them.
+pub fn interleaved_from_coords(coords: &[Coord<f64>]) -> &[f64] {
+    // SAFETY: see `coords_from_interleaved`
+    unsafe { core::slice::from_raw_parts(coords.as_ptr() as *const f64, coords.len() * 2) }
+}
+
+/// Interleave separate buffers of `x` and `y` values into coordinates.
+pub fn interleave_coords(x: &[f64], y: &[f64]) -> Result<Vec<Coord<f64>>, GeoArrowError> {
+    if x.len() != y.len() {
+        return Err(GeoArrowError::MismatchedCoordinates {
+            x: x.len(),
+            y: y.len(),
+        });
+    }
+    Ok(x.iter().zip(y).map(|(&x, &y)| Coord { x, y }).collect())
+}
+
+// Check that `offsets` are non-decreasing indices into `len` items.
+fn check_offsets(offsets: &[i32], len: usize) -> Result<(), GeoArrowError> {
+    let valid = offsets.windows(2).all(|pair| pair[0] <= pair[1])
+        && offsets
+            .iter()
+            .all(|&offset| offset >= 0 && offset as usize <= len);
+    if valid {
+        Ok(())
+    } else {
+        Err(GeoArrowError::InvalidOffsets)
+    }
+}
+
+fn range(offsets: &[i32], index: usize) -> Range<usize> {
+    offsets[index] as usize..offsets[index + 1] as usize
+}
+
+fn offset(len: usize) -> i32 {
+    i32::try_from(len).expect("GeoArrow buffers are limited to i32::MAX items")
+}
+
+/// A borrowed GeoArrow array of line strings, or of multi-points, which have the same layout.
+///
+/// See the [module documentation](crate::arrow).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineStringArray<'a> {
+    geom_offsets: &'a [i32],
+    coords: &'a [Coord<f64>],
+}
+
+impl<'a> LineStringArray<'a> {
+    /// Borrow the offsets and interleaved coordinates of an array, checking that they're
+    /// consistent.
+    pub fn try_new(geom_offsets: &'a [i32], coords: &'a [f64]) -> Result<Self, GeoArrowError> {
+        let coords = coords_from_interleaved(coords)?;
+        check_offsets(geom_offsets, coords.len())?;
+        Ok(LineStringArray {
+            geom_offsets,
+            coords,
+        })
+    }
+
+    /// The number of geometries in the array.
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len().saturating_sub(1)
+    }
+
+    /// Whether the array has no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The coordinates of the `index`th geometry, without copying them.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn coords(&self, index: usize) -> &'a [Coord<f64>] {
+        &self.coords[range(self.geom_offsets, index)]
+    }
+
+    /// The `index`th geometry, as a line string.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn line_string(&self, index: usize) -> LineString<f64> {
+        LineString::new(self.coords(index).to_vec())
+    }
+
+    /// The `index`th geometry, as a multi-point.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn multi_point(&self, index: usize) -> MultiPoint<f64> {
+        MultiPoint::new(self.coords(index).iter().copied().map(Point).collect())
+    }
+}
+
+/// A borrowed GeoArrow array of polygons, or of multi-line strings, which have the same layout.
+///
+/// See the [module documentation](crate::arrow).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolygonArray<'a> {
+    geom_offsets: &'a [i32],
+    ring_offsets: &'a [i32],
+    coords: &'a [Coord<f64>],
+}
+
+impl<'a> PolygonArray<'a> {
+    /// Borrow the offsets and interleaved coordinates of an array, checking that they're
+    /// consistent.
+    pub fn try_new(
+        geom_offsets: &'a [i32],
+        ring_offsets: &'a [i32],
+        coords: &'a [f64],
+    ) -> Result<Self, GeoArrowError> {
+        let coords = coords_from_interleaved(coords)?;
+        check_offsets(ring_offsets, coords.len())?;
+        check_offsets(geom_offsets, ring_offsets.len().saturating_sub(1))?;
+        Ok(PolygonArray {
+            geom_offsets,
+            ring_offsets,
+            coords,
+        })
+    }
+
+    /// The number of geometries in the array.
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len().saturating_sub(1)
+    }
+
+    /// Whether the array has no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The rings of the `index`th polygon, or the line strings of the `index`th multi-line
+    /// string, as an array borrowing the same buffers.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn rings(&self, index: usize) -> LineStringArray<'a> {
+        let rings = range(self.geom_offsets, index);
+        LineStringArray {
+            geom_offsets: &self.ring_offsets[rings.start..=rings.end],
+            coords: self.coords,
+        }
+    }
+
+    /// The `index`th geometry, as a polygon.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn polygon(&self, index: usize) -> Polygon<f64> {
+        let rings = self.rings(index);
+        let mut rings = (0..rings.len()).map(|i| rings.line_string(i));
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+        Polygon::new(exterior, rings.collect())
+    }
+
+    /// The `index`th geometry, as a multi-line string.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn multi_line_string(&self, index: usize) -> MultiLineString<f64> {
+        let rings = self.rings(index);
+        MultiLineString::new((0..rings.len()).map(|i| rings.line_string(i)).collect())
+    }
+}
+
+/// A borrowed GeoArrow array of multi-polygons.
+///
+/// See the [module documentation](crate::arrow).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MultiPolygonArray<'a> {
+    geom_offsets: &'a [i32],
+    polygon_offsets: &'a [i32],
+    ring_offsets: &'a [i32],
+    coords: &'a [Coord<f64>],
+}
+
+impl<'a> MultiPolygonArray<'a> {
+    /// Borrow the offsets and interleaved coordinates of an array, checking that they're
+    /// consistent.
+    pub fn try_new(
+        geom_offsets: &'a [i32],
+        polygon_offsets: &'a [i32],
+        ring_offsets: &'a [i32],
+        coords: &'a [f64],
+    ) -> Result<Self, GeoArrowError> {
+        let coords = coords_from_interleaved(coords)?;
+        check_offsets(ring_offsets, coords.len())?;
+        check_offsets(polygon_offsets, ring_offsets.len().saturating_sub(1))?;
+        check_offsets(geom_offsets, polygon_offsets.len().saturating_sub(1))?;
+        Ok(MultiPolygonArray {
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+            coords,
+        })
+    }
+
+    /// The number of multi-polygons in the array.
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len().saturating_sub(1)
+    }
+
+    /// Whether the array has no multi-polygons.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The polygons of the `index`th multi-polygon, as an array borrowing the same buffers.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn polygons(&self, index: usize) -> PolygonArray<'a> {
+        let polygons = range(self.geom_offsets, index);
+        PolygonArray {
+            geom_offsets: &self.polygon_offsets[polygons.start..=polygons.end],
+            ring_offsets: self.ring_offsets,
+            coords: self.coords,
+        }
+    }
+
+    /// The `index`th multi-polygon.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn multi_polygon(&self, index: usize) -> MultiPolygon<f64> {
+        let polygons = self.polygons(index);
+        MultiPolygon::new((0..polygons.len()).map(|i| polygons.polygon(i)).collect())
+    }
+}
+
+/// The buffers of a GeoArrow array of line strings, or of multi-points, being written.
+///
+/// # Panics
+///
+/// Pushing more than `i32::MAX` coordinates panics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineStringBuffers {
+    pub geom_offsets: Vec<i32>,
+    pub coords: Vec<f64>,
+}
+
+impl LineStringBuffers {
+    /// Empty buffers.
+    pub fn new() -> Self {
+        LineStringBuffers {
+            geom_offsets: vec![0],
+            coords: vec![],
+        }
+    }
+
+    /// Append a line string.
+    pub fn push_line_string(&mut self, line_string: &LineString<f64>) {
+        self.push_coords(&line_string.0);
+    }
+
+    /// Append a multi-point.
+    pub fn push_multi_point(&mut self, multi_point: &MultiPoint<f64>) {
+        for point in multi_point {
+            self.coords.extend_from_slice(&[point.x(), point.y()]);
+        }
+        self.geom_offsets.push(offset(self.coords.len() / 2));
+    }
+
+    fn push_coords(&mut self, coords: &[Coord<f64>]) {
+        self.coords
+            .extend_from_slice(interleaved_from_coords(coords));
+        self.geom_offsets.push(offset(self.coords.len() / 2));
+    }
+
+    /// Borrow the buffers as an array.
+    ///
+    /// # Panics
+    ///
+    /// If `coords` has been given an odd number of values.
+    pub fn array(&self) -> LineStringArray<'_> {
+        LineStringArray {
+            geom_offsets: &self.geom_offsets,
+            coords: coords_from_interleaved(&self.coords).unwrap(),
+        }
+    }
+}
+
+impl Default for LineStringBuffers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The buffers of a GeoArrow array of polygons, or of multi-line strings, being written.
+///
+/// # Panics
+///
+/// Pushing more than `i32::MAX` coordinates, or rings, panics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonBuffers {
+    pub geom_offsets: Vec<i32>,
+    pub ring_offsets: Vec<i32>,
+    pub coords: Vec<f64>,
+}
+
+impl PolygonBuffers {
+    /// Empty buffers.
+    pub fn new() -> Self {
+        PolygonBuffers {
+            geom_offsets: vec![0],
+            ring_offsets: vec![0],
+            coords: vec![],
+        }
+    }
+
+    fn push_ring(&mut self, ring: &LineString<f64>) {
+        self.coords
+            .extend_from_slice(interleaved_from_coords(&ring.0));
+        self.ring_offsets.push(offset(self.coords.len() / 2));
+    }
+
+    /// Append a polygon.
+    pub fn push_polygon(&mut self, polygon: &Polygon<f64>) {
+        if !polygon.exterior().0.is_empty() {
+            self.push_ring(polygon.exterior());
+            for interior in polygon.interiors() {
+                self.push_ring(interior);
+            }
+        }
+        self.geom_offsets.push(offset(self.ring_offsets.len() - 1));
+    }
+
+    /// Append a multi-line string.
+    pub fn push_multi_line_string(&mut self, multi_line_string: &MultiLineString<f64>) {
+        for line_string in multi_line_string {
+            self.push_ring(line_string);
+        }
+        self.geom_offsets.push(offset(self.ring_offsets.len() - 1));
+    }
+
+    /// Borrow the buffers as an array.
+    ///
+    /// # Panics
+    ///
+    /// If `coords` has been given an odd number of values.
+    pub fn array(&self) -> PolygonArray<'_> {
+        PolygonArray {
+            geom_offsets: &self.geom_offsets,
+            ring_offsets: &self.ring_offsets,
+            coords: coords_from_interleaved(&self.coords).unwrap(),
+        }
+    }
+}
+
+impl Default for PolygonBuffers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The buffers of a GeoArrow array of multi-polygons, being written.
+///
+/// # Panics
+///
+/// Pushing more than `i32::MAX` coordinates, rings or polygons panics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPolygonBuffers {
+    pub geom_offsets: Vec<i32>,
+    pub polygon_offsets: Vec<i32>,
+    pub ring_offsets: Vec<i32>,
+    pub coords: Vec<f64>,
+}
+
+impl MultiPolygonBuffers {
+    /// Empty buffers.
+    pub fn new() -> Self {
+        MultiPolygonBuffers {
+            geom_offsets: vec![0],
+            polygon_offsets: vec![0],
+            ring_offsets: vec![0],
+            coords: vec![],
+        }
+    }
+
+    /// Append a multi-polygon.
+    pub fn push_multi_polygon(&mut self, multi_polygon: &MultiPolygon<f64>) {
+        for polygon in multi_polygon {
+            if !polygon.exterior().0.is_empty() {
+                for ring in core::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                    self.coords
+                        .extend_from_slice(interleaved_from_coords(&ring.0));
+                    self.ring_offsets.push(offset(self.coords.len() / 2));
+                }
+            }
+            self.polygon_offsets
+                .push(offset(self.ring_offsets.len() - 1));
+        }
+        self.geom_offsets
+            .push(offset(self.polygon_offsets.len() - 1));
+    }
+
+    /// Borrow the buffers as an array.
+    ///
+    /// # Panics
+    ///
+    /// If `coords` has been given an odd number of values.
+    pub fn array(&self) -> MultiPolygonArray<'_> {
+        MultiPolygonArray {
+            geom_offsets: &self.geom_offsets,
+            polygon_offsets: &self.polygon_offsets,
+            ring_offsets: &self.ring_offsets,
+            coords: coords_from_interleaved(&self.coords).unwrap(),
+        }
+    }
+}
+
+impl Default for MultiPolygonBuffers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn coords_are_not_copied() {
+        let values = [1., 2., 3., 4.];
+        let coords = coords_from_interleaved(&values).unwrap();
+        assert_eq!(coords, &[Coord { x: 1., y: 2. }, Coord { x: 3., y: 4. }]);
+        assert_eq!(coords.as_ptr() as *const f64, values.as_ptr());
+        assert_eq!(interleaved_from_coords(coords), &values);
+
+        assert_eq!(
+            coords_from_interleaved(&values[..3]),
+            Err(GeoArrowError::OddCoordinates(3))
+        );
+        assert_eq!(
+            interleave_coords(&[1., 3.], &[2., 4.]).unwrap(),
+            coords.to_vec()
+        );
+        assert_eq!(
+            interleave_coords(&[1., 3.], &[2.]),
+            Err(GeoArrowError::MismatchedCoordinates { x: 2, y: 1 })
+        );
+    }
+
+    #[test]
+    fn line_strings_and_multi_points() {
+        let mut buffers = LineStringBuffers::new();
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        let multi_point = MultiPoint::new(vec![point!(x: 5., y: 5.)]);
+        buffers.push_line_string(&line_string);
+        buffers.push_multi_point(&multi_point);
+        buffers.push_line_string(&LineString::new(vec![]));
+        assert_eq!(buffers.geom_offsets, vec![0, 2, 3, 3]);
+
+        let array = buffers.array();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.line_string(0), line_string);
+        assert_eq!(array.multi_point(1), multi_point);
+        assert!(array.coords(2).is_empty());
+        assert_eq!(
+            array,
+            LineStringArray::try_new(&buffers.geom_offsets, &buffers.coords).unwrap()
+        );
+    }
+
+    #[test]
+    fn polygons_and_multi_line_strings() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        ];
+        let multi_line_string =
+            MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.)]]);
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+
+        let mut buffers = PolygonBuffers::new();
+        buffers.push_polygon(&polygon);
+        buffers.push_multi_line_string(&multi_line_string);
+        buffers.push_polygon(&empty);
+        assert_eq!(buffers.geom_offsets, vec![0, 2, 3, 3]);
+        assert_eq!(buffers.ring_offsets, vec![0, 5, 9, 11]);
+
+        let array = PolygonArray::try_new(
+            &buffers.geom_offsets,
+            &buffers.ring_offsets,
+            &buffers.coords,
+        )
+        .unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.polygon(0), polygon);
+        assert_eq!(array.rings(0).coords(1), &polygon.interiors()[0].0[..]);
+        assert_eq!(array.multi_line_string(1), multi_line_string);
+        assert_eq!(array.polygon(2), empty);
+    }
+
+    #[test]
+    fn multi_polygons() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)],
+            polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 5., y: 6.)],
+        ]);
+        let mut buffers = MultiPolygonBuffers::new();
+        buffers.push_multi_polygon(&MultiPolygon::new(vec![]));
+        buffers.push_multi_polygon(&multi_polygon);
+
+        let array = buffers.array();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.multi_polygon(0), MultiPolygon::new(vec![]));
+        assert_eq!(array.multi_polygon(1), multi_polygon);
+        assert_eq!(array.polygons(1).polygon(1), multi_polygon.0[1]);
+    }
+
+    #[test]
+    fn sliced_and_invalid_offsets() {
+        // the second and third line strings of an array, as Arrow slices them
+        let coords = [0., 0., 1., 1., 2., 2., 3., 3.];
+        let array = LineStringArray::try_new(&[1, 3, 4], &coords).unwrap();
+        assert_eq!(array.len(), 2);
+        assert_eq!(
+            array.coords(0),
+            &[Coord { x: 1., y: 1. }, Coord { x: 2., y: 2. }]
+        );
+
+        for offsets in [&[0, 5][..], &[2, 1], &[-1, 2]] {
+            assert_eq!(
+                LineStringArray::try_new(offsets, &coords),
+                Err(GeoArrowError::InvalidOffsets)
+            );
+        }
+        assert_eq!(
+            PolygonArray::try_new(&[0, 3], &[0, 2, 4], &coords),
+            Err(GeoArrowError::InvalidOffsets)
+        );
+        assert!(LineStringArray::try_new(&[], &[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,297 @@
+//! Builders to construct geometries incrementally, e.g. from the output of a parser, one
+//! coordinate at a time.
+//!
+//! A builder keeps the coordinates pushed to it in a buffer, which [`build`] checks and copies
+//! into a geometry of the exact size before clearing it, so that the same builder can be reused
+//! for many geometries without growing its buffer again.
+//!
+//! [`build`]: LineStringBuilder::build
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{line_string, polygon, LineStringBuilder, PolygonBuilder};
+//!
+//! let mut builder = LineStringBuilder::new();
+//! builder.push((0., 0.)).push((1., 1.)).extend([(2., 1.), (3., 0.)]);
+//! assert_eq!(
+//!     builder.build(),
+//!     Ok(line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 1.), (x: 3., y: 0.)])
+//! );
+//!
+//! let mut builder = PolygonBuilder::new();
+//! builder
+//!     .extend([(0., 0.), (10., 0.), (10., 10.), (0., 10.)])
+//!     .finish_ring()
+//!     .extend([(1., 1.), (2., 1.), (2., 2.)]);
+//! assert_eq!(
+//!     builder.build(),
+//!     Ok(polygon![
+//!         exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+//!         interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+//!     ])
+//! );
+//! ```
+
+use crate::{Coord, CoordNum, LineString, Polygon};
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The reasons a builder can't build a valid geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// The line string has fewer than two coordinates.
+    LineStringTooShort { len: usize },
+    /// A ring of the polygon, the exterior at index 0 and then the interiors, has fewer than
+    /// four coordinates once closed.
+    RingTooShort { ring: usize, len: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::LineStringTooShort { len } => {
+                write!(f, "A line string needs 2 coordinates, but has {}", len)
+            }
+            BuildError::RingTooShort { ring, len } => write!(
+                f,
+                "A closed ring needs 4 coordinates, but ring {} has {}",
+                ring, len
+            ),
+        }
+    }
+}
+
+/// A builder of [`LineString`]s.
+#[derive(Debug, Clone)]
+pub struct LineStringBuilder<T: CoordNum = f64> {
+    coords: Vec<Coord<T>>,
+}
+
+impl<T: CoordNum> Default for LineStringBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> LineStringBuilder<T> {
+    /// An empty builder.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// An empty builder with room for `capacity` coordinates.
+    pub fn with_capacity(capacity: usize) -> Self {
+        LineStringBuilder {
+            coords: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Append a coordinate.
+    pub fn push(&mut self, coord: impl Into<Coord<T>>) -> &mut Self {
+        self.coords.push(coord.into());
+        self
+    }
+
+    /// Append coordinates.
+    pub fn extend<IC: Into<Coord<T>>>(
+        &mut self,
+        coords: impl IntoIterator<Item = IC>,
+    ) -> &mut Self {
+        self.coords.extend(coords.into_iter().map(Into::into));
+        self
+    }
+
+    /// Append the first coordinate again, unless the line string is already closed.
+    pub fn close(&mut self) -> &mut Self {
+        if let (Some(&first), Some(last)) = (self.coords.first(), self.coords.last()) {
+            if first != *last {
+                self.coords.push(first);
+            }
+        }
+        self
+    }
+
+    /// The number of coordinates pushed since the last build.
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Whether no coordinate was pushed since the last build.
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    /// Build the line string, or an error if it has fewer than two coordinates. The builder is
+    /// cleared either way, keeping its buffer.
+    pub fn build(&mut self) -> Result<LineString<T>, BuildError> {
+        let len = self.coords.len();
+        let result = if len < 2 {
+            Err(BuildError::LineStringTooShort { len })
+        } else {
+            Ok(LineString::new(self.coords.clone()))
+        };
+        self.coords.clear();
+        result
+    }
+}
+
+/// A builder of [`Polygon`]s, ring by ring: first the exterior, then the interiors. Rings are
+/// closed as they're finished.
+#[derive(Debug, Clone)]
+pub struct PolygonBuilder<T: CoordNum = f64> {
+    coords: Vec<Coord<T>>,
+    /// Where each finished ring ends in `coords`.
+    ring_ends: Vec<usize>,
+}
+
+impl<T: CoordNum> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> PolygonBuilder<T> {
+    /// An empty builder.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// An empty builder with room for `capacity` coordinates.
+    pub fn with_capacity(capacity: usize) -> Self {
+        PolygonBuilder {
+            coords: Vec::with_capacity(capacity),
+            ring_ends: Vec::new(),
+        }
+    }
+
+    /// Append a coordinate to the current ring.
+    pub fn push(&mut self, coord: impl Into<Coord<T>>) -> &mut Self {
+        self.coords.push(coord.into());
+        self
+    }
+
+    /// Append coordinates to the current ring.
+    pub fn extend<IC: Into<Coord<T>>>(
+        &mut self,
+        coords: impl IntoIterator<Item = IC>,
+    ) -> &mut Self {
+        self.coords.extend(coords.into_iter().map(Into::into));
+        self
+    }
+
+    /// Close the current ring, and start the next one.
+    pub fn finish_ring(&mut self) -> &mut Self {
+        let start = self.current_ring_start();
+        if let Some(&first) = self.coords.get(start) {
+            if self.coords.last() != Some(&first) {
+                self.coords.push(first);
+            }
+        }
+        self.ring_ends.push(self.coords.len());
+        self
+    }
+
+    /// The number of rings, including the current one if a coordinate was pushed to it.
+    pub fn num_rings(&self) -> usize {
+        self.ring_ends.len() + usize::from(self.coords.len() > self.current_ring_start())
+    }
+
+    /// Build the polygon, finishing the current ring if a coordinate was pushed to it, or an
+    /// error if a ring has fewer than four coordinates once closed. Without any ring, the
+    /// polygon is empty. The builder is cleared either way, keeping its buffers.
+    pub fn build(&mut self) -> Result<Polygon<T>, BuildError> {
+        if self.coords.len() > self.current_ring_start() {
+            self.finish_ring();
+        }
+        let mut rings = Vec::with_capacity(self.ring_ends.len());
+        let mut start = 0;
+        let mut result = Ok(());
+        for (ring, &end) in self.ring_ends.iter().enumerate() {
+            let len = end - start;
+            if len < 4 {
+                result = Err(BuildError::RingTooShort { ring, len });
+                break;
+            }
+            rings.push(LineString::new(self.coords[start..end].to_vec()));
+            start = end;
+        }
+        self.coords.clear();
+        self.ring_ends.clear();
+        result?;
+
+        let mut rings = rings.into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(Vec::new()));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    fn current_ring_start(&self) -> usize {
+        self.ring_ends.last().copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn line_strings() {
+        let mut builder = LineStringBuilder::with_capacity(4);
+        builder.push((0, 0)).push((1, 0)).push((1, 1)).close();
+        assert_eq!(builder.len(), 4);
+        assert_eq!(
+            builder.build(),
+            Ok(line_string![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 0)])
+        );
+        assert!(builder.is_empty());
+
+        builder.push((0, 0));
+        assert_eq!(
+            builder.build(),
+            Err(BuildError::LineStringTooShort { len: 1 })
+        );
+        // reused after an error
+        builder.extend([(0, 0), (1, 1)]);
+        assert_eq!(
+            builder.build(),
+            Ok(line_string![(x: 0, y: 0), (x: 1, y: 1)])
+        );
+    }
+
+    #[test]
+    fn polygons() {
+        let mut builder = PolygonBuilder::new();
+        // already closed
+        builder
+            .extend([(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)])
+            .finish_ring()
+            .extend([(1, 1), (2, 1), (2, 2)]);
+        assert_eq!(builder.num_rings(), 2);
+        assert_eq!(
+            builder.build(),
+            Ok(polygon![
+                exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)],
+                interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+            ])
+        );
+        assert_eq!(builder.num_rings(), 0);
+
+        builder
+            .extend([(0, 0), (4, 0), (4, 4)])
+            .finish_ring()
+            .extend([(1, 1), (2, 1)]);
+        assert_eq!(
+            builder.build(),
+            Err(BuildError::RingTooShort { ring: 1, len: 3 })
+        );
+        assert_eq!(
+            builder.build(),
+            Ok(Polygon::new(LineString::new(Vec::new()), Vec::new()))
+        );
+    }
+}
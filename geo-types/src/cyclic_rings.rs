@@ -0,0 +1,196 @@
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{Coord, CoordNum, LineString, MultiPolygon, Polygon};
+
+/// Compares the closed rings of a geometry regardless of the coordinate they start at and of
+/// their winding order, where the usual comparisons require the same coordinates in the same
+/// order.
+///
+/// The rings of two polygons built by different algorithms, or before and after orienting
+/// them, are seldom the same sequences of coordinates, even when they describe the same
+/// shape: wrapping the polygons in `CyclicRings` lets them be compared with `==` and the
+/// assertions of [approx] all the same. `CyclicRings` is implemented for `LineString`,
+/// `Polygon` and `MultiPolygon`. The interiors of polygons, and the polygons of
+/// multi-polygons, must still come in the same order, and line strings which aren't closed are
+/// compared as usual.
+///
+/// [approx]: https://github.com/brendanzab/approx
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{polygon, CyclicRings};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+/// // starts at another corner, and is wound the other way round
+/// let b = polygon![(x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.), (x: 1., y: 1.)];
+/// assert_ne!(a, b);
+/// assert_eq!(CyclicRings(a.clone()), CyclicRings(b.clone()));
+///
+/// let c = polygon![(x: 1., y: 1.001), (x: 1., y: 0.), (x: 0., y: 0.), (x: 1., y: 1.001)];
+/// approx::assert_relative_eq!(CyclicRings(a), CyclicRings(c), max_relative = 0.01);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CyclicRings<G>(pub G);
+
+// Whether `a` and `b` are the same ring, whichever coordinate they start at and whichever way
+// round they go, if both are closed, or the same line string otherwise.
+fn rings_eq<T: CoordNum>(
+    a: &LineString<T>,
+    b: &LineString<T>,
+    eq: impl Fn(&Coord<T>, &Coord<T>) -> bool,
+) -> bool {
+    if a.0.len() != b.0.len() {
+        return false;
+    }
+    if a.0.len() < 2 || !a.is_closed() || !b.is_closed() {
+        return a.0.iter().zip(&b.0).all(|(a, b)| eq(a, b));
+    }
+    // the closing coordinates repeat the first ones
+    let n = a.0.len() - 1;
+    let (a, b) = (&a.0[..n], &b.0[..n]);
+    (0..n).any(|start| {
+        (0..n).all(|i| eq(&a[i], &b[(start + i) % n]))
+            || (0..n).all(|i| eq(&a[i], &b[(start + n - i) % n]))
+    })
+}
+
+fn polygons_eq<T: CoordNum>(
+    a: &Polygon<T>,
+    b: &Polygon<T>,
+    eq: impl Fn(&Coord<T>, &Coord<T>) -> bool + Copy,
+) -> bool {
+    rings_eq(a.exterior(), b.exterior(), eq)
+        && a.interiors().len() == b.interiors().len()
+        && a.interiors()
+            .iter()
+            .zip(b.interiors())
+            .all(|(a, b)| rings_eq(a, b, eq))
+}
+
+fn multi_polygons_eq<T: CoordNum>(
+    a: &MultiPolygon<T>,
+    b: &MultiPolygon<T>,
+    eq: impl Fn(&Coord<T>, &Coord<T>) -> bool + Copy,
+) -> bool {
+    a.0.len() == b.0.len() && a.iter().zip(b).all(|(a, b)| polygons_eq(a, b, eq))
+}
+
+macro_rules! cyclic_rings_impl {
+    ($geometry:ident, $eq:ident) => {
+        impl<T: CoordNum> PartialEq for CyclicRings<$geometry<T>> {
+            fn eq(&self, other: &Self) -> bool {
+                $eq(&self.0, &other.0, |a, b| a == b)
+            }
+        }
+
+        impl<T> RelativeEq for CyclicRings<$geometry<T>>
+        where
+            T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+        {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                T::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $eq(&self.0, &other.0, |a, b| {
+                    a.relative_eq(b, epsilon, max_relative)
+                })
+            }
+        }
+
+        impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for CyclicRings<$geometry<T>> {
+            type Epsilon = T;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $eq(&self.0, &other.0, |a, b| a.abs_diff_eq(b, epsilon))
+            }
+        }
+    };
+}
+
+cyclic_rings_impl!(LineString, rings_eq);
+cyclic_rings_impl!(Polygon, polygons_eq);
+cyclic_rings_impl!(MultiPolygon, multi_polygons_eq);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn rings() {
+        let ring =
+            line_string![(x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 2), (x: 0, y: 0)];
+        let rotated =
+            line_string![(x: 2, y: 2), (x: 0, y: 2), (x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2)];
+        let reversed =
+            line_string![(x: 2, y: 0), (x: 0, y: 0), (x: 0, y: 2), (x: 2, y: 2), (x: 2, y: 0)];
+        let other =
+            line_string![(x: 0, y: 0), (x: 2, y: 0), (x: 0, y: 2), (x: 2, y: 2), (x: 0, y: 0)];
+        assert_eq!(CyclicRings(ring.clone()), CyclicRings(rotated));
+        assert_eq!(CyclicRings(ring.clone()), CyclicRings(reversed));
+        assert_ne!(CyclicRings(ring.clone()), CyclicRings(other));
+
+        // open line strings must match exactly
+        let open = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        let backwards = line_string![(x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        assert_relative_ne!(CyclicRings(open.clone()), CyclicRings(backwards));
+        assert_relative_eq!(CyclicRings(open.clone()), CyclicRings(open));
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(CyclicRings(empty.clone()), CyclicRings(empty));
+    }
+
+    #[test]
+    fn polygons() {
+        let a = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        );
+        let b = polygon!(
+            exterior: [(x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 0.), (x: 0., y: 4.)],
+            interiors: [[(x: 2., y: 2.), (x: 1., y: 1.0001), (x: 2., y: 1.)]],
+        );
+        assert_abs_diff_eq!(
+            CyclicRings(a.clone()),
+            CyclicRings(b.clone()),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_ne!(
+            CyclicRings(a.clone()),
+            CyclicRings(b.clone()),
+            epsilon = 1e-5
+        );
+
+        let no_holes = Polygon::new(a.exterior().clone(), vec![]);
+        assert_ne!(CyclicRings(a.clone()), CyclicRings(no_holes.clone()));
+
+        let multi_polygon = |polygons: &[&Polygon<f64>]| {
+            CyclicRings(MultiPolygon::new(
+                polygons.iter().map(|&p| p.clone()).collect(),
+            ))
+        };
+        assert_relative_eq!(
+            multi_polygon(&[&a, &no_holes]),
+            multi_polygon(&[&b, &no_holes]),
+            epsilon = 1e-3
+        );
+        assert_relative_ne!(
+            multi_polygon(&[&a, &no_holes]),
+            multi_polygon(&[&no_holes, &b]),
+            epsilon = 1e-3
+        );
+    }
+}
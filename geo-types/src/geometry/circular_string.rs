@@ -0,0 +1,121 @@
+#[cfg(any(feature = "approx", test))]
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{Coord, CoordNum};
+use alloc::vec::Vec;
+
+/// A sequence of circular arcs, as in the SQL/MM `CIRCULARSTRING` type, each defined by three
+/// [`Coord`]s: its start, a point anywhere along it, and its end, which is the start of the
+/// next arc.
+///
+/// A valid `CircularString` is empty, or has an odd number of coordinates, at least three. An
+/// arc whose start and end are the same is a full circle, of which the middle coordinate is
+/// the diametrically opposite point.
+///
+/// Use the `Linearize` algorithm of the `geo` crate to approximate it with a `LineString`.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{coord, CircularString};
+///
+/// // a half circle, from (0, 0) to (2, 0) through (1, 1)
+/// let arc = CircularString::new(vec![
+///     coord! { x: 0., y: 0. },
+///     coord! { x: 1., y: 1. },
+///     coord! { x: 2., y: 0. },
+/// ]);
+/// assert_eq!(arc.arcs().count(), 1);
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CircularString<T: CoordNum = f64>(pub Vec<Coord<T>>);
+
+impl<T: CoordNum> CircularString<T> {
+    pub fn new(coords: Vec<Coord<T>>) -> Self {
+        CircularString(coords)
+    }
+
+    /// Iterate over the arcs, as triples of start, middle and end coordinates. Trailing
+    /// coordinates that don't complete an arc are ignored.
+    pub fn arcs(&self) -> impl Iterator<Item = [Coord<T>; 3]> + '_ {
+        (0..self.0.len().saturating_sub(1) / 2)
+            .map(move |i| [self.0[2 * i], self.0[2 * i + 1], self.0[2 * i + 2]])
+    }
+}
+
+impl<T: CoordNum, IC: Into<Coord<T>>> From<Vec<IC>> for CircularString<T> {
+    fn from(coords: Vec<IC>) -> Self {
+        CircularString(coords.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> RelativeEq for CircularString<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Equality assertion within a relative limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::CircularString;
+    ///
+    /// let a = CircularString::<f32>::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+    /// let b = CircularString::<f32>::from(vec![(0., 0.), (1.01, 1.), (2., 0.)]);
+    ///
+    /// approx::assert_relative_eq!(a, b, max_relative=0.1);
+    /// approx::assert_relative_ne!(a, b, max_relative=0.001);
+    /// ```
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon, max_relative))
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for CircularString<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Equality assertion with an absolute limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::CircularString;
+    ///
+    /// let a = CircularString::<f32>::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+    /// let b = CircularString::<f32>::from(vec![(0., 0.), (1.01, 1.), (2., 0.)]);
+    ///
+    /// approx::assert_abs_diff_eq!(a, b, epsilon=0.1);
+    /// approx::assert_abs_diff_ne!(a, b, epsilon=0.001);
+    /// ```
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon))
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(any(feature = "approx", test))]
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{CircularString, CoordNum, LineString};
+use alloc::vec::Vec;
+
+/// Any of the curve types of SQL/MM: a straight [`LineString`], a [`CircularString`], or a
+/// [`CompoundCurve`] mixing both. The rings of a [`CurvePolygon`](crate::CurvePolygon) are
+/// `Curve`s.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Curve<T: CoordNum = f64> {
+    LineString(LineString<T>),
+    CircularString(CircularString<T>),
+    CompoundCurve(CompoundCurve<T>),
+}
+
+impl<T: CoordNum> From<LineString<T>> for Curve<T> {
+    fn from(x: LineString<T>) -> Self {
+        Curve::LineString(x)
+    }
+}
+
+impl<T: CoordNum> From<CircularString<T>> for Curve<T> {
+    fn from(x: CircularString<T>) -> Self {
+        Curve::CircularString(x)
+    }
+}
+
+impl<T: CoordNum> From<CompoundCurve<T>> for Curve<T> {
+    fn from(x: CompoundCurve<T>) -> Self {
+        Curve::CompoundCurve(x)
+    }
+}
+
+/// A continuous curve made of [`LineString`]s and [`CircularString`]s, each starting where
+/// the previous one ends, as in the SQL/MM `COMPOUNDCURVE` type.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, CircularString, CompoundCurve, Curve};
+///
+/// // a straight segment, followed by a half circle
+/// let curve = CompoundCurve::new(vec![
+///     Curve::LineString(line_string![(x: 0., y: 0.), (x: 2., y: 0.)]),
+///     Curve::CircularString(CircularString::from(vec![(2., 0.), (3., 1.), (4., 0.)])),
+/// ]);
+/// assert_eq!(curve.0.len(), 2);
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CompoundCurve<T: CoordNum = f64>(pub Vec<Curve<T>>);
+
+impl<T: CoordNum> CompoundCurve<T> {
+    pub fn new(components: Vec<Curve<T>>) -> Self {
+        CompoundCurve(components)
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> RelativeEq for Curve<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Equality assertion within a relative limit. Curves of different kinds are never equal,
+    /// even if they have the same shape.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        match (self, other) {
+            (Curve::LineString(c1), Curve::LineString(c2)) => {
+                c1.relative_eq(c2, epsilon, max_relative)
+            }
+            (Curve::CircularString(c1), Curve::CircularString(c2)) => {
+                c1.relative_eq(c2, epsilon, max_relative)
+            }
+            (Curve::CompoundCurve(c1), Curve::CompoundCurve(c2)) => {
+                c1.relative_eq(c2, epsilon, max_relative)
+            }
+            (_, _) => false,
+        }
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Curve<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Equality assertion with an absolute limit. Curves of different kinds are never equal,
+    /// even if they have the same shape.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        match (self, other) {
+            (Curve::LineString(c1), Curve::LineString(c2)) => c1.abs_diff_eq(c2, epsilon),
+            (Curve::CircularString(c1), Curve::CircularString(c2)) => c1.abs_diff_eq(c2, epsilon),
+            (Curve::CompoundCurve(c1), Curve::CompoundCurve(c2)) => c1.abs_diff_eq(c2, epsilon),
+            (_, _) => false,
+        }
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> RelativeEq for CompoundCurve<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Equality assertion within a relative limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, CircularString, CompoundCurve, Curve};
+    ///
+    /// let a: CompoundCurve<f32> = CompoundCurve::new(vec![
+    ///     Curve::LineString(line_string![(x: 0., y: 0.), (x: 2., y: 0.)]),
+    ///     Curve::CircularString(CircularString::from(vec![(2., 0.), (3., 1.), (4., 0.)])),
+    /// ]);
+    /// let b: CompoundCurve<f32> = CompoundCurve::new(vec![
+    ///     Curve::LineString(line_string![(x: 0., y: 0.), (x: 2., y: 0.)]),
+    ///     Curve::CircularString(CircularString::from(vec![(2., 0.), (3., 1.01), (4., 0.)])),
+    /// ]);
+    ///
+    /// approx::assert_relative_eq!(a, b, max_relative=0.1);
+    /// approx::assert_relative_ne!(a, b, max_relative=0.001);
+    /// ```
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon, max_relative))
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for CompoundCurve<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Equality assertion with an absolute limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, CircularString, CompoundCurve, Curve};
+    ///
+    /// let a: CompoundCurve<f32> = CompoundCurve::new(vec![
+    ///     Curve::LineString(line_string![(x: 0., y: 0.), (x: 2., y: 0.)]),
+    ///     Curve::CircularString(CircularString::from(vec![(2., 0.), (3., 1.), (4., 0.)])),
+    /// ]);
+    /// let b: CompoundCurve<f32> = CompoundCurve::new(vec![
+    ///     Curve::LineString(line_string![(x: 0., y: 0.), (x: 2., y: 0.)]),
+    ///     Curve::CircularString(CircularString::from(vec![(2., 0.), (3., 1.01), (4., 0.)])),
+    /// ]);
+    ///
+    /// approx::assert_abs_diff_eq!(a, b, epsilon=0.1);
+    /// approx::assert_abs_diff_ne!(a, b, epsilon=0.001);
+    /// ```
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon))
+    }
+}
@@ -22,9 +22,16 @@ use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 /// is that the coordinates it contains are valid numbers
 /// (for eg. not `f64::NAN`).
 ///
+/// # Layout
+///
+/// `Coord` has the layout of a C struct of `x` and `y`, so that a slice of coordinates can be
+/// viewed as a slice of interleaved `x` and `y` values, and back, without copying, as the
+/// `arrow` feature does.
+///
 /// [vector space]: //en.wikipedia.org/wiki/Vector_space
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
 pub struct Coord<T: CoordNum = f64> {
     pub x: T,
     pub y: T,
@@ -100,7 +107,7 @@ impl<T: CoordNum> Coord<T> {
     }
 }
 
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Negate a coordinate.
 ///
@@ -0,0 +1,136 @@
+#[cfg(any(feature = "approx", test))]
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{CoordNum, Curve};
+use alloc::vec::Vec;
+
+/// A polygon whose rings may be curved, as in the SQL/MM `CURVEPOLYGON` type.
+///
+/// Unlike those of a [`Polygon`](crate::Polygon), the rings aren't closed automatically: each
+/// must end where it starts.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{coord, CircularString, Curve, CurvePolygon};
+///
+/// // a disc of radius 1, centred on the origin
+/// let disc = CurvePolygon::new(
+///     Curve::CircularString(CircularString::new(vec![
+///         coord! { x: 1., y: 0. },
+///         coord! { x: -1., y: 0. },
+///         coord! { x: 1., y: 0. },
+///     ])),
+///     vec![],
+/// );
+/// assert!(disc.interiors().is_empty());
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CurvePolygon<T: CoordNum = f64> {
+    exterior: Curve<T>,
+    interiors: Vec<Curve<T>>,
+}
+
+impl<T: CoordNum> CurvePolygon<T> {
+    pub fn new(exterior: Curve<T>, interiors: Vec<Curve<T>>) -> Self {
+        CurvePolygon {
+            exterior,
+            interiors,
+        }
+    }
+
+    pub fn exterior(&self) -> &Curve<T> {
+        &self.exterior
+    }
+
+    pub fn interiors(&self) -> &[Curve<T>] {
+        &self.interiors
+    }
+
+    /// Consume the `CurvePolygon`, returning its exterior and interior rings.
+    pub fn into_inner(self) -> (Curve<T>, Vec<Curve<T>>) {
+        (self.exterior, self.interiors)
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> RelativeEq for CurvePolygon<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Equality assertion within a relative limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{CircularString, Curve, CurvePolygon};
+    ///
+    /// let disc = |x: f32| {
+    ///     CurvePolygon::new(
+    ///         Curve::CircularString(CircularString::from(vec![(x, 0.), (-1., 0.), (x, 0.)])),
+    ///         vec![],
+    ///     )
+    /// };
+    ///
+    /// approx::assert_relative_eq!(disc(1.), disc(1.01), max_relative=0.1);
+    /// approx::assert_relative_ne!(disc(1.), disc(1.01), max_relative=0.001);
+    /// ```
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.exterior
+            .relative_eq(&other.exterior, epsilon, max_relative)
+            && self.interiors.len() == other.interiors.len()
+            && self
+                .interiors
+                .iter()
+                .zip(&other.interiors)
+                .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon, max_relative))
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for CurvePolygon<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Equality assertion with an absolute limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{CircularString, Curve, CurvePolygon};
+    ///
+    /// let disc = |x: f32| {
+    ///     CurvePolygon::new(
+    ///         Curve::CircularString(CircularString::from(vec![(x, 0.), (-1., 0.), (x, 0.)])),
+    ///         vec![],
+    ///     )
+    /// };
+    ///
+    /// approx::assert_abs_diff_eq!(disc(1.), disc(1.01), epsilon=0.1);
+    /// approx::assert_abs_diff_ne!(disc(1.), disc(1.01), epsilon=0.001);
+    /// ```
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.exterior.abs_diff_eq(&other.exterior, epsilon)
+            && self.interiors.len() == other.interiors.len()
+            && self
+                .interiors
+                .iter()
+                .zip(&other.interiors)
+                .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    Coord, CoordNum, Geometry, LineStringRef, MultiLineString, MultiPolygon, Point, PolygonRef,
+};
+use alloc::vec::Vec;
+
+/// A borrowed [`Geometry`], made of views over slices of coordinates, such as those decoded in
+/// place from a GeoArrow or WKB buffer. See [`LineStringRef`] and [`PolygonRef`].
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, Geometry, GeometryRef, LineStringRef};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+/// let view = GeometryRef::from(LineStringRef::from(&line_string));
+/// assert_eq!(view.to_geometry(), Geometry::LineString(line_string));
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub enum GeometryRef<'a, T: CoordNum = f64> {
+    Point(Point<T>),
+    LineString(LineStringRef<'a, T>),
+    Polygon(PolygonRef<'a, T>),
+    MultiPoint(&'a [Coord<T>]),
+    MultiLineString(Vec<LineStringRef<'a, T>>),
+    MultiPolygon(Vec<PolygonRef<'a, T>>),
+}
+
+impl<'a, T: CoordNum> GeometryRef<'a, T> {
+    /// Copy the coordinates into an owned [`Geometry`].
+    pub fn to_geometry(&self) -> Geometry<T> {
+        match self {
+            GeometryRef::Point(point) => Geometry::Point(*point),
+            GeometryRef::LineString(line_string) => {
+                Geometry::LineString(line_string.to_line_string())
+            }
+            GeometryRef::Polygon(polygon) => Geometry::Polygon(polygon.to_polygon()),
+            GeometryRef::MultiPoint(coords) => {
+                Geometry::MultiPoint(coords.iter().map(|&coord| Point(coord)).collect())
+            }
+            GeometryRef::MultiLineString(line_strings) => Geometry::MultiLineString(
+                MultiLineString::new(line_strings.iter().map(|l| l.to_line_string()).collect()),
+            ),
+            GeometryRef::MultiPolygon(polygons) => Geometry::MultiPolygon(MultiPolygon::new(
+                polygons.iter().map(|p| p.to_polygon()).collect(),
+            )),
+        }
+    }
+}
+
+impl<'a, T: CoordNum> From<Point<T>> for GeometryRef<'a, T> {
+    fn from(x: Point<T>) -> Self {
+        Self::Point(x)
+    }
+}
+
+impl<'a, T: CoordNum> From<LineStringRef<'a, T>> for GeometryRef<'a, T> {
+    fn from(x: LineStringRef<'a, T>) -> Self {
+        Self::LineString(x)
+    }
+}
+
+impl<'a, T: CoordNum> From<PolygonRef<'a, T>> for GeometryRef<'a, T> {
+    fn from(x: PolygonRef<'a, T>) -> Self {
+        Self::Polygon(x)
+    }
+}
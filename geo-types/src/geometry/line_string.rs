@@ -1,9 +1,11 @@
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
 
-use crate::{Coord, CoordNum, Line, Point, Triangle};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use crate::{Coord, CoordFloat, CoordNum, Line, Point, Triangle};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
 
 /// An ordered collection of two or more [`Coord`]s, representing a
 /// path between locations.
@@ -136,7 +138,7 @@ pub struct LineString<T: CoordNum = f64>(pub Vec<Coord<T>>);
 
 /// A [`Point`] iterator returned by the `points` method
 #[derive(Debug)]
-pub struct PointsIter<'a, T: CoordNum + 'a>(::std::slice::Iter<'a, Coord<T>>);
+pub struct PointsIter<'a, T: CoordNum + 'a>(::core::slice::Iter<'a, Coord<T>>);
 
 impl<'a, T: CoordNum> Iterator for PointsIter<'a, T> {
     type Item = Point<T>;
@@ -164,7 +166,7 @@ impl<'a, T: CoordNum> DoubleEndedIterator for PointsIter<'a, T> {
 
 /// A [`Coord`] iterator used by the `into_iter` method on a [`LineString`]
 #[derive(Debug)]
-pub struct CoordinatesIter<'a, T: CoordNum + 'a>(::std::slice::Iter<'a, Coord<T>>);
+pub struct CoordinatesIter<'a, T: CoordNum + 'a>(::core::slice::Iter<'a, Coord<T>>);
 
 impl<'a, T: CoordNum> Iterator for CoordinatesIter<'a, T> {
     type Item = &'a Coord<T>;
@@ -190,6 +192,67 @@ impl<'a, T: CoordNum> DoubleEndedIterator for CoordinatesIter<'a, T> {
     }
 }
 
+/// An iterator over the vertices of a [`LineString`] along with their neighbours, returned by
+/// the `vertex_windows` method
+#[derive(Debug, Clone)]
+pub struct VertexWindows<'a, T: CoordNum + 'a> {
+    /// The vertices, without the closing coordinate if the line string is closed.
+    coords: &'a [Coord<T>],
+    closed: bool,
+    /// The index of the next vertex from the front.
+    front: usize,
+    /// One past the index of the next vertex from the back.
+    back: usize,
+}
+
+impl<'a, T: CoordNum> VertexWindows<'a, T> {
+    fn window(&self, index: usize) -> [Coord<T>; 3] {
+        if self.closed {
+            let len = self.coords.len();
+            [
+                self.coords[(index + len - 1) % len],
+                self.coords[index],
+                self.coords[(index + 1) % len],
+            ]
+        } else {
+            [
+                self.coords[index - 1],
+                self.coords[index],
+                self.coords[index + 1],
+            ]
+        }
+    }
+}
+
+impl<'a, T: CoordNum> Iterator for VertexWindows<'a, T> {
+    type Item = [Coord<T>; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.window(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: CoordNum> ExactSizeIterator for VertexWindows<'a, T> {}
+
+impl<'a, T: CoordNum> DoubleEndedIterator for VertexWindows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.window(self.back))
+    }
+}
+
 impl<T: CoordNum> LineString<T> {
     /// Instantiate Self from the raw content value
     pub fn new(value: Vec<Coord<T>>) -> Self {
@@ -198,12 +261,12 @@ impl<T: CoordNum> LineString<T> {
 
     /// Return an iterator yielding the coordinates of a [`LineString`] as [`Point`]s
     #[deprecated(note = "Use points() instead")]
-    pub fn points_iter(&self) -> PointsIter<T> {
+    pub fn points_iter(&self) -> PointsIter<'_, T> {
         PointsIter(self.0.iter())
     }
 
     /// Return an iterator yielding the coordinates of a [`LineString`] as [`Point`]s
-    pub fn points(&self) -> PointsIter<T> {
+    pub fn points(&self) -> PointsIter<'_, T> {
         PointsIter(self.0.iter())
     }
 
@@ -276,6 +339,56 @@ impl<T: CoordNum> LineString<T> {
         })
     }
 
+    /// An iterator which yields each vertex of the [`LineString`] along with the previous and
+    /// next ones, as `[previous, vertex, next]`, e.g. to compute the turn at each vertex, or to
+    /// detect spikes, without juggling indices.
+    ///
+    /// If the [`LineString`] is closed, the iterator wraps around: each vertex is yielded once,
+    /// the closing coordinate being the same as the first one, and the last vertex is followed
+    /// by the first one. Otherwise, the first and last coordinates, which have a single
+    /// neighbour, are only yielded as neighbours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, line_string};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 7., y: 9.)];
+    /// let mut windows = line_string.vertex_windows();
+    /// assert_eq!(
+    ///     Some([coord! { x: 0., y: 0. }, coord! { x: 5., y: 0. }, coord! { x: 7., y: 9. }]),
+    ///     windows.next()
+    /// );
+    /// assert!(windows.next().is_none());
+    ///
+    /// let ring = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 7., y: 9.), (x: 0., y: 0.)];
+    /// let mut windows = ring.vertex_windows();
+    /// assert_eq!(3, windows.len());
+    /// assert_eq!(
+    ///     Some([coord! { x: 7., y: 9. }, coord! { x: 0., y: 0. }, coord! { x: 5., y: 0. }]),
+    ///     windows.next()
+    /// );
+    /// assert_eq!(
+    ///     Some([coord! { x: 5., y: 0. }, coord! { x: 7., y: 9. }, coord! { x: 0., y: 0. }]),
+    ///     windows.next_back()
+    /// );
+    /// ```
+    pub fn vertex_windows(&self) -> VertexWindows<'_, T> {
+        let closed = self.is_closed();
+        let (coords, front, back) = if closed {
+            let len = self.0.len().saturating_sub(1);
+            (&self.0[..len], 0, len)
+        } else {
+            (&self.0[..], 1, self.0.len().saturating_sub(1))
+        };
+        VertexWindows {
+            coords,
+            closed,
+            front,
+            back: back.max(front),
+        }
+    }
+
     /// Close the [`LineString`]. Specifically, if the [`LineString`] has at least one [`Coord`], and
     /// the value of the first [`Coord`] **does not** equal the value of the last [`Coord`], then a
     /// new [`Coord`] is added to the end with the value of the first [`Coord`].
@@ -335,6 +448,57 @@ impl<T: CoordNum> LineString<T> {
     }
 }
 
+impl<T: CoordFloat> LineString<T> {
+    /// A circular arc of `radius` around `center`, from `start_angle` to `end_angle`, in degrees
+    /// counter-clockwise from the positive x axis, approximated by `n_segments` segments
+    /// spanning equal angles. The arc turns clockwise if `end_angle` is less than
+    /// `start_angle`.
+    ///
+    /// # Panics
+    ///
+    /// If `n_segments` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo_types::{coord, LineString};
+    ///
+    /// let center = coord! { x: 1f64, y: 1. };
+    /// // a quarter circle, from east to north
+    /// let arc = LineString::arc(center, 2., 0., 90., 8);
+    ///
+    /// assert_eq!(arc.0.len(), 9);
+    /// assert_eq!(arc[0], coord! { x: 3., y: 1. });
+    /// assert_relative_eq!(arc[8].x, 1.);
+    /// assert_relative_eq!(arc[8].y, 3.);
+    /// for coord in arc.coords() {
+    ///     assert_relative_eq!((coord.x - center.x).hypot(coord.y - center.y), 2.);
+    /// }
+    /// ```
+    pub fn arc(
+        center: Coord<T>,
+        radius: T,
+        start_angle: T,
+        end_angle: T,
+        n_segments: usize,
+    ) -> Self {
+        assert!(n_segments > 0, "an arc needs at least one segment");
+        let start = start_angle.to_radians();
+        let sweep = (end_angle - start_angle).to_radians();
+        let n = T::from(n_segments).unwrap();
+        (0..=n_segments)
+            .map(|i| {
+                let angle = start + sweep * T::from(i).unwrap() / n;
+                Coord {
+                    x: center.x + radius * angle.cos(),
+                    y: center.y + radius * angle.sin(),
+                }
+            })
+            .collect()
+    }
+}
+
 /// Turn a [`Vec`] of [`Point`]-like objects into a [`LineString`].
 impl<T: CoordNum, IC: Into<Coord<T>>> From<Vec<IC>> for LineString<T> {
     fn from(v: Vec<IC>) -> Self {
@@ -358,7 +522,7 @@ impl<T: CoordNum, IC: Into<Coord<T>>> FromIterator<IC> for LineString<T> {
 /// Iterate over all the [`Coord`]s in this [`LineString`].
 impl<T: CoordNum> IntoIterator for LineString<T> {
     type Item = Coord<T>;
-    type IntoIter = ::std::vec::IntoIter<Coord<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Coord<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -377,9 +541,9 @@ impl<'a, T: CoordNum> IntoIterator for &'a LineString<T> {
 /// Mutably iterate over all the [`Coordinate`]s in this [`LineString`]
 impl<'a, T: CoordNum> IntoIterator for &'a mut LineString<T> {
     type Item = &'a mut Coord<T>;
-    type IntoIter = ::std::slice::IterMut<'a, Coord<T>>;
+    type IntoIter = ::core::slice::IterMut<'a, Coord<T>>;
 
-    fn into_iter(self) -> ::std::slice::IterMut<'a, Coord<T>> {
+    fn into_iter(self) -> ::core::slice::IterMut<'a, Coord<T>> {
         self.0.iter_mut()
     }
 }
@@ -529,7 +693,40 @@ impl_rstar_line_string!(rstar_0_9);
 mod test {
     use super::*;
     use crate::coord;
-    use approx::AbsDiffEq;
+    use approx::{assert_relative_eq, AbsDiffEq};
+
+    #[test]
+    fn vertex_windows() {
+        let ring: LineString = vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)].into();
+        let windows: Vec<_> = ring.vertex_windows().collect();
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0], [ring[3], ring[0], ring[1]]);
+        assert_eq!(windows[3], [ring[2], ring[3], ring[0]]);
+        let mut reversed: Vec<_> = ring.vertex_windows().rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, windows);
+
+        let open: LineString = vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)].into();
+        assert_eq!(
+            open.vertex_windows().collect::<Vec<_>>(),
+            vec![[open[0], open[1], open[2]], [open[1], open[2], open[3]]]
+        );
+
+        for coords in [vec![], vec![(0., 0.)], vec![(0., 0.), (1., 1.)]] {
+            assert_eq!(LineString::from(coords).vertex_windows().len(), 0);
+        }
+    }
+
+    #[test]
+    fn arc() {
+        // clockwise, from north to east
+        let arc = LineString::arc(coord! { x: 0., y: 0. }, 1., 90., 0., 2);
+        assert_relative_eq!(
+            arc,
+            LineString::from(vec![(0., 1.), (0.5f64.sqrt(), 0.5f64.sqrt()), (1., 0.)]),
+            epsilon = 1e-12
+        );
+    }
 
     #[test]
     fn test_exact_size() {
@@ -0,0 +1,55 @@
+use crate::{Coord, CoordNum, Line, LineString};
+
+/// A borrowed [`LineString`]: a view over a slice of coordinates, such as those decoded in
+/// place from a GeoArrow or WKB buffer.
+///
+/// The algorithms of the `geo` crate implemented for `LineStringRef` process the coordinates
+/// where they are, rather than copying them into the `Vec` of a `LineString` first.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{coord, line_string, LineStringRef};
+///
+/// let coords = [coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. }];
+/// let view = LineStringRef::new(&coords);
+/// assert_eq!(view.lines().count(), 1);
+/// assert_eq!(view.to_line_string(), line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+/// ```
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+pub struct LineStringRef<'a, T: CoordNum = f64>(pub &'a [Coord<T>]);
+
+impl<'a, T: CoordNum> LineStringRef<'a, T> {
+    pub fn new(coords: &'a [Coord<T>]) -> Self {
+        LineStringRef(coords)
+    }
+
+    /// Return an iterator yielding one [`Line`] for each line segment, like
+    /// [`LineString::lines`].
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = Line<T>> + 'a {
+        self.0.windows(2).map(|w| Line::new(w[0], w[1]))
+    }
+
+    /// Whether the first and last coordinates are the same, or there are none, like
+    /// [`LineString::is_closed`].
+    pub fn is_closed(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+
+    /// Copy the coordinates into an owned [`LineString`].
+    pub fn to_line_string(&self) -> LineString<T> {
+        LineString::new(self.0.to_vec())
+    }
+}
+
+impl<'a, T: CoordNum> From<&'a [Coord<T>]> for LineStringRef<'a, T> {
+    fn from(coords: &'a [Coord<T>]) -> Self {
+        LineStringRef(coords)
+    }
+}
+
+impl<'a, T: CoordNum> From<&'a LineString<T>> for LineStringRef<'a, T> {
+    fn from(line_string: &'a LineString<T>) -> Self {
+        LineStringRef(&line_string.0)
+    }
+}
@@ -0,0 +1,241 @@
+#[cfg(any(feature = "approx", test))]
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{Coord, CoordNum, LineString};
+use alloc::vec::Vec;
+
+/// A [`LineString`] whose coordinates carry an elevation (Z) and/or a measure (M), such as a
+/// GPS track or a LiDAR profile.
+///
+/// The Z and M values are stored next to the two-dimensional `LineString`, with one value per
+/// coordinate, so two-dimensional algorithms can be run on [`LineStringZM::xy`] directly,
+/// while the algorithms of the `geo` crate implemented for `LineStringZM` itself, such as
+/// transforms and simplification, keep the Z and M values of the coordinates they keep.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, LineStringZM};
+///
+/// let track = LineStringZM::new(
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+///     Some(vec![100., 110., 105.]),
+///     None,
+/// );
+/// assert_eq!(track.z(), Some(&[100., 110., 105.][..]));
+/// let (coord, z, m) = track.coords_zm().last().unwrap();
+/// assert_eq!((coord.x, coord.y, z, m), (1., 1., Some(105.), None));
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineStringZM<T: CoordNum = f64> {
+    xy: LineString<T>,
+    z: Option<Vec<T>>,
+    m: Option<Vec<T>>,
+}
+
+impl<T: CoordNum> Default for LineStringZM<T> {
+    fn default() -> Self {
+        LineStringZM {
+            xy: LineString::new(Vec::new()),
+            z: None,
+            m: None,
+        }
+    }
+}
+
+impl<T: CoordNum> LineStringZM<T> {
+    /// Creates a new `LineStringZM` from its coordinates, and their Z and M values, if any.
+    ///
+    /// # Panics
+    ///
+    /// If `z` or `m` doesn't have exactly one value per coordinate of `xy`.
+    pub fn new(xy: LineString<T>, z: Option<Vec<T>>, m: Option<Vec<T>>) -> Self {
+        let line_string = LineStringZM { xy, z, m };
+        line_string.assert_dimensions();
+        line_string
+    }
+
+    fn assert_dimensions(&self) {
+        let len = self.xy.0.len();
+        assert!(
+            self.z.iter().all(|z| z.len() == len),
+            "expected one Z value per coordinate"
+        );
+        assert!(
+            self.m.iter().all(|m| m.len() == len),
+            "expected one M value per coordinate"
+        );
+    }
+
+    /// The two-dimensional coordinates.
+    pub fn xy(&self) -> &LineString<T> {
+        &self.xy
+    }
+
+    /// Execute the provided closure `f`, which is provided with a mutable reference to the
+    /// two-dimensional coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If `f` changes the number of coordinates while the line string has Z or M values.
+    pub fn xy_mut<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut LineString<T>),
+    {
+        f(&mut self.xy);
+        self.assert_dimensions();
+    }
+
+    /// The Z values, one per coordinate, if any.
+    pub fn z(&self) -> Option<&[T]> {
+        self.z.as_deref()
+    }
+
+    /// The M values, one per coordinate, if any.
+    pub fn m(&self) -> Option<&[T]> {
+        self.m.as_deref()
+    }
+
+    /// Iterate over the coordinates, with their Z and M values.
+    pub fn coords_zm(&self) -> impl Iterator<Item = (Coord<T>, Option<T>, Option<T>)> + '_ {
+        self.xy.0.iter().enumerate().map(move |(i, coord)| {
+            (
+                *coord,
+                self.z.as_ref().map(|z| z[i]),
+                self.m.as_ref().map(|m| m[i]),
+            )
+        })
+    }
+
+    /// Return the coordinates, and their Z and M values, if any.
+    pub fn into_inner(self) -> (LineString<T>, Option<Vec<T>>, Option<Vec<T>>) {
+        (self.xy, self.z, self.m)
+    }
+}
+
+impl<T: CoordNum> From<LineString<T>> for LineStringZM<T> {
+    fn from(xy: LineString<T>) -> Self {
+        LineStringZM::new(xy, None, None)
+    }
+}
+
+/// Drops the Z and M values.
+impl<T: CoordNum> From<LineStringZM<T>> for LineString<T> {
+    fn from(line_string: LineStringZM<T>) -> Self {
+        line_string.xy
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> RelativeEq for LineStringZM<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Equality assertion within a relative limit. The Z and M values must be present in both
+    /// line strings, or in neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, LineStringZM};
+    ///
+    /// let xy = line_string![(x: 0.0f32, y: 0.), (x: 1., y: 0.)];
+    /// let a = LineStringZM::new(xy.clone(), Some(vec![100., 110.]), None);
+    /// let b = LineStringZM::new(xy, Some(vec![100., 110.1]), None);
+    ///
+    /// approx::assert_relative_eq!(a, b, max_relative=0.1);
+    /// approx::assert_relative_ne!(a, b, max_relative=0.0001);
+    /// ```
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let eq = |lhs: &Option<Vec<T>>, rhs: &Option<Vec<T>>| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs
+                .iter()
+                .zip(rhs)
+                .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon, max_relative)),
+            (None, None) => true,
+            (_, _) => false,
+        };
+        // the lengths of the Z and M values are those of the coordinates
+        self.xy.relative_eq(&other.xy, epsilon, max_relative)
+            && eq(&self.z, &other.z)
+            && eq(&self.m, &other.m)
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for LineStringZM<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Equality assertion with an absolute limit. The Z and M values must be present in both
+    /// line strings, or in neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, LineStringZM};
+    ///
+    /// let xy = line_string![(x: 0.0f32, y: 0.), (x: 1., y: 0.)];
+    /// let a = LineStringZM::new(xy.clone(), Some(vec![100., 110.]), None);
+    /// let b = LineStringZM::new(xy, Some(vec![100., 110.1]), None);
+    ///
+    /// approx::assert_abs_diff_eq!(a, b, epsilon=0.5);
+    /// approx::assert_abs_diff_ne!(a, b, epsilon=0.01);
+    /// ```
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        let eq = |lhs: &Option<Vec<T>>, rhs: &Option<Vec<T>>| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs
+                .iter()
+                .zip(rhs)
+                .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon)),
+            (None, None) => true,
+            (_, _) => false,
+        };
+        // the lengths of the Z and M values are those of the coordinates
+        self.xy.abs_diff_eq(&other.xy, epsilon) && eq(&self.z, &other.z) && eq(&self.m, &other.m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    #[should_panic(expected = "expected one Z value per coordinate")]
+    fn mismatched_z() {
+        LineStringZM::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            Some(vec![1.]),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected one M value per coordinate")]
+    fn removing_coordinates() {
+        let mut line_string = LineStringZM::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            None,
+            Some(vec![0., 1.]),
+        );
+        line_string.xy_mut(|xy| {
+            xy.0.pop();
+        });
+    }
+}
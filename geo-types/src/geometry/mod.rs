@@ -1,26 +1,42 @@
+pub(crate) mod circular_string;
+pub(crate) mod compound_curve;
 pub(crate) mod coord;
+pub(crate) mod curve_polygon;
 pub(crate) mod geometry_collection;
+pub(crate) mod geometry_ref;
 pub(crate) mod line;
 pub(crate) mod line_string;
+pub(crate) mod line_string_ref;
+pub(crate) mod line_string_zm;
 pub(crate) mod multi_line_string;
 pub(crate) mod multi_point;
 pub(crate) mod multi_polygon;
 pub(crate) mod point;
+pub(crate) mod point_zm;
 pub(crate) mod polygon;
+pub(crate) mod polygon_ref;
 pub(crate) mod rect;
 pub(crate) mod triangle;
 
 // re-export all the geometry variants:
+pub use circular_string::CircularString;
+pub use compound_curve::{CompoundCurve, Curve};
 #[allow(deprecated)]
 pub use coord::{Coord, Coordinate};
+pub use curve_polygon::CurvePolygon;
 pub use geometry_collection::GeometryCollection;
+pub use geometry_ref::GeometryRef;
 pub use line::Line;
 pub use line_string::LineString;
+pub use line_string_ref::LineStringRef;
+pub use line_string_zm::LineStringZM;
 pub use multi_line_string::MultiLineString;
 pub use multi_point::MultiPoint;
 pub use multi_polygon::MultiPolygon;
 pub use point::Point;
+pub use point_zm::PointZM;
 pub use polygon::Polygon;
+pub use polygon_ref::PolygonRef;
 pub use rect::Rect;
 pub use triangle::Triangle;
 
@@ -29,7 +45,7 @@ use crate::{CoordNum, Error};
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
 use core::any::type_name;
-use std::convert::TryFrom;
+use core::convert::TryFrom;
 
 /// An enum representing any possible geometry type.
 ///
@@ -251,7 +267,7 @@ try_from_geometry_impl!(
     Triangle
 );
 
-fn inner_type_name<T>(geometry: Geometry<T>) -> &'static str
+pub(crate) fn inner_type_name<T>(geometry: Geometry<T>) -> &'static str
 where
     T: CoordNum,
 {
@@ -371,3 +387,46 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Geometry<T> {
         }
     }
 }
+
+#[cfg(any(feature = "rstar_0_8", feature = "rstar_0_9"))]
+macro_rules! impl_rstar_geometry {
+    ($rstar:ident) => {
+        impl<T> ::$rstar::RTreeObject for Geometry<T>
+        where
+            T: ::num_traits::Float + ::$rstar::RTreeNum,
+        {
+            type Envelope = ::$rstar::AABB<Point<T>>;
+
+            fn envelope(&self) -> Self::Envelope {
+                use num_traits::Bounded;
+                let bounding_rect = crate::private_utils::geometry_bounding_rect(self);
+                match bounding_rect {
+                    None => ::$rstar::AABB::from_corners(
+                        Point::new(Bounded::min_value(), Bounded::min_value()),
+                        Point::new(Bounded::max_value(), Bounded::max_value()),
+                    ),
+                    Some(b) => ::$rstar::AABB::from_corners(
+                        Point::new(b.min().x, b.min().y),
+                        Point::new(b.max().x, b.max().y),
+                    ),
+                }
+            }
+        }
+
+        impl<T> ::$rstar::PointDistance for Geometry<T>
+        where
+            T: ::num_traits::Float + ::$rstar::RTreeNum,
+        {
+            fn distance_2(&self, point: &Point<T>) -> T {
+                let d = crate::private_utils::point_geometry_euclidean_distance(*point, self);
+                d.powi(2)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rstar_0_8")]
+impl_rstar_geometry!(rstar_0_8);
+
+#[cfg(feature = "rstar_0_9")]
+impl_rstar_geometry!(rstar_0_9);
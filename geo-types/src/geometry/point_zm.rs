@@ -0,0 +1,125 @@
+#[cfg(any(feature = "approx", test))]
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{Coord, CoordNum, Point};
+
+/// A [`Point`] carrying an optional elevation (Z) and an optional measure (M), such as a
+/// GPS fix or a LiDAR return.
+///
+/// Two-dimensional algorithms can be run on [`PointZM::xy`] directly, while transforms of the
+/// `geo` crate applied to the `PointZM` itself keep its Z and M values.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{point, PointZM};
+///
+/// let fix = PointZM::new(point!(x: 6.86, y: 45.83), Some(4808.), None);
+/// assert_eq!(fix.z, Some(4808.));
+/// assert_eq!(fix.m, None);
+/// ```
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointZM<T: CoordNum = f64> {
+    pub xy: Point<T>,
+    pub z: Option<T>,
+    pub m: Option<T>,
+}
+
+impl<T: CoordNum> PointZM<T> {
+    pub fn new(xy: Point<T>, z: Option<T>, m: Option<T>) -> Self {
+        PointZM { xy, z, m }
+    }
+
+    pub fn coord(&self) -> Coord<T> {
+        self.xy.0
+    }
+}
+
+impl<T: CoordNum> From<Point<T>> for PointZM<T> {
+    fn from(xy: Point<T>) -> Self {
+        PointZM::new(xy, None, None)
+    }
+}
+
+/// Drops the Z and M values.
+impl<T: CoordNum> From<PointZM<T>> for Point<T> {
+    fn from(point: PointZM<T>) -> Self {
+        point.xy
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T> RelativeEq for PointZM<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + RelativeEq,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    /// Equality assertion within a relative limit. The Z and M values must be present in both
+    /// points, or in neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, PointZM};
+    ///
+    /// let a = PointZM::new(point!(x: 1.0f32, y: 2.0), Some(100.), None);
+    /// let b = PointZM::new(point!(x: 1.0f32, y: 2.0), Some(100.1), None);
+    ///
+    /// approx::assert_relative_eq!(a, b, max_relative=0.1);
+    /// approx::assert_relative_ne!(a, b, max_relative=0.0001);
+    /// approx::assert_relative_ne!(a, PointZM::from(a.xy), max_relative=0.1);
+    /// ```
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let eq = |lhs: &Option<T>, rhs: &Option<T>| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs.relative_eq(rhs, epsilon, max_relative),
+            (None, None) => true,
+            (_, _) => false,
+        };
+        self.xy.relative_eq(&other.xy, epsilon, max_relative)
+            && eq(&self.z, &other.z)
+            && eq(&self.m, &other.m)
+    }
+}
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for PointZM<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    /// Equality assertion with an absolute limit. The Z and M values must be present in both
+    /// points, or in neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, PointZM};
+    ///
+    /// let a = PointZM::new(point!(x: 1.0f32, y: 2.0), Some(100.), None);
+    /// let b = PointZM::new(point!(x: 1.0f32, y: 2.0), Some(100.1), None);
+    ///
+    /// approx::assert_abs_diff_eq!(a, b, epsilon=0.5);
+    /// approx::assert_abs_diff_ne!(a, b, epsilon=0.01);
+    /// ```
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        let eq = |lhs: &Option<T>, rhs: &Option<T>| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs.abs_diff_eq(rhs, epsilon),
+            (None, None) => true,
+            (_, _) => false,
+        };
+        self.xy.abs_diff_eq(&other.xy, epsilon) && eq(&self.z, &other.z) && eq(&self.m, &other.m)
+    }
+}
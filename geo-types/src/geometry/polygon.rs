@@ -1,4 +1,6 @@
-use crate::{CoordFloat, CoordNum, LineString, Point, Rect, Triangle};
+use crate::{Coord, CoordFloat, CoordNum, LineString, Point, Rect, Triangle};
+use alloc::vec;
+use alloc::vec::Vec;
 use num_traits::{Float, Signed};
 
 #[cfg(any(feature = "approx", test))]
@@ -393,6 +395,202 @@ impl<T: CoordNum> Polygon<T> {
     }
 }
 
+impl<T: CoordFloat> Polygon<T> {
+    /// A circle of `radius` around `center`, approximated by a regular polygon of `n_segments`
+    /// sides, wound counter-clockwise from its eastmost vertex.
+    ///
+    /// # Panics
+    ///
+    /// If `n_segments` is less than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Polygon, Triangle};
+    ///
+    /// let circle = Polygon::circle(coord! { x: 0., y: 0. }, 10., 64);
+    /// assert_eq!(circle.exterior().0.len(), 65);
+    ///
+    /// // the circle through three points
+    /// let triangle = Triangle::new(
+    ///     coord! { x: 0., y: 0. },
+    ///     coord! { x: 2., y: 0. },
+    ///     coord! { x: 0., y: 2. },
+    /// );
+    /// let (center, radius) = triangle.circumcircle().unwrap();
+    /// assert_eq!(center, coord! { x: 1., y: 1. });
+    /// let circle = Polygon::circle(center, radius, 64);
+    /// ```
+    pub fn circle(center: Coord<T>, radius: T, n_segments: usize) -> Self {
+        Polygon::regular(center, radius, n_segments, T::zero())
+    }
+
+    /// A regular polygon of `sides` sides, with its vertices on the circle of `radius` around
+    /// `center`, the first one at `rotation` degrees counter-clockwise from the positive x axis.
+    /// It's wound counter-clockwise.
+    ///
+    /// # Panics
+    ///
+    /// If `sides` is less than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo_types::{coord, Polygon};
+    ///
+    /// // a square standing on a corner
+    /// let square = Polygon::regular(coord! { x: 0., y: 0. }, 1., 4, 90.);
+    /// assert_eq!(square.exterior().0.len(), 5);
+    /// assert_relative_eq!(square.exterior()[1].x, -1.);
+    /// assert_relative_eq!(square.exterior()[1].y, 0., epsilon = 1e-12);
+    /// ```
+    pub fn regular(center: Coord<T>, radius: T, sides: usize, rotation: T) -> Self {
+        assert!(sides >= 3, "a polygon needs at least three sides");
+        let end = rotation + T::from(360).unwrap();
+        let mut exterior = LineString::arc(center, radius, rotation, end, sides);
+        // closed exactly
+        exterior.0[sides] = exterior.0[0];
+        Polygon::new(exterior, Vec::new())
+    }
+
+    /// An ellipse around `center`, with semi-axes of `radius_x` and `radius_y` along the x and
+    /// y axes before it's rotated by `rotation` degrees counter-clockwise, approximated by
+    /// segments which deviate from it by `max_deviation` at most. It's wound counter-clockwise.
+    ///
+    /// # Panics
+    ///
+    /// If `max_deviation` isn't strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo_types::{coord, Polygon};
+    ///
+    /// let ellipse = Polygon::ellipse(coord! { x: 0., y: 0. }, 10., 5., 30., 0.1);
+    /// assert_eq!(ellipse.exterior().0.len(), 24);
+    /// // the end of the rotated x semi-axis
+    /// assert_relative_eq!(ellipse.exterior()[0].x, 75f64.sqrt());
+    /// assert_relative_eq!(ellipse.exterior()[0].y, 5.);
+    /// ```
+    pub fn ellipse(
+        center: Coord<T>,
+        radius_x: T,
+        radius_y: T,
+        rotation: T,
+        max_deviation: T,
+    ) -> Self {
+        let full_turn = T::from(360).unwrap();
+        let radius = radius_x.abs().max(radius_y.abs());
+        let n_segments = segment_count(radius, full_turn, max_deviation).max(3);
+        let step = full_turn.to_radians() / T::from(n_segments).unwrap();
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        let mut coords: Vec<_> = (0..n_segments)
+            .map(|i| {
+                let angle = step * T::from(i).unwrap();
+                let (x, y) = (radius_x * angle.cos(), radius_y * angle.sin());
+                Coord {
+                    x: center.x + x * cos - y * sin,
+                    y: center.y + x * sin + y * cos,
+                }
+            })
+            .collect();
+        coords.push(coords[0]);
+        Polygon::new(LineString::new(coords), Vec::new())
+    }
+
+    /// A circular sector, or wedge, of `radius` around `center`, from `start_angle` to
+    /// `end_angle`, in degrees counter-clockwise from the positive x axis, its arc approximated
+    /// by segments which deviate from it by `max_deviation` at most. It's wound from `center`
+    /// along the arc, counter-clockwise if `end_angle` is greater than `start_angle`.
+    ///
+    /// # Panics
+    ///
+    /// If `max_deviation` isn't strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Polygon};
+    ///
+    /// let center = coord! { x: 0., y: 0. };
+    /// // the north-east quarter of a circle
+    /// let sector = Polygon::sector(center, 10., 0., 90., 0.1);
+    /// assert_eq!(sector.exterior().0.len(), 9);
+    /// assert_eq!(sector.exterior()[0], center);
+    /// assert_eq!(sector.exterior()[1], coord! { x: 10., y: 0. });
+    /// ```
+    pub fn sector(
+        center: Coord<T>,
+        radius: T,
+        start_angle: T,
+        end_angle: T,
+        max_deviation: T,
+    ) -> Self {
+        let n_segments = segment_count(radius, end_angle - start_angle, max_deviation);
+        let mut coords = vec![center];
+        coords.extend(LineString::arc(
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            n_segments,
+        ));
+        coords.push(center);
+        Polygon::new(LineString::new(coords), Vec::new())
+    }
+
+    /// An annulus, the ring between two circles around `center`, of `outer_radius` and of
+    /// `inner_radius`, approximated by segments which deviate from them by `max_deviation` at
+    /// most. The outer circle is the exterior, wound counter-clockwise, and the inner one the
+    /// interior, wound clockwise.
+    ///
+    /// # Panics
+    ///
+    /// If `max_deviation` isn't strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Polygon};
+    ///
+    /// let annulus = Polygon::annulus(coord! { x: 0., y: 0. }, 5., 10., 0.1);
+    /// assert_eq!(annulus.exterior().0.len(), 24);
+    /// assert_eq!(annulus.interiors()[0].0.len(), 17);
+    /// ```
+    pub fn annulus(center: Coord<T>, inner_radius: T, outer_radius: T, max_deviation: T) -> Self {
+        let circle = |radius: T| {
+            let full_turn = T::from(360).unwrap();
+            let n_segments = segment_count(radius, full_turn, max_deviation).max(3);
+            Polygon::circle(center, radius, n_segments).into_inner().0
+        };
+        let mut interior = circle(inner_radius);
+        interior.0.reverse();
+        Polygon::new(circle(outer_radius), vec![interior])
+    }
+}
+
+/// The number of segments spanning equal angles to approximate an arc of `radius` spanning
+/// `sweep` degrees with, so that none deviates from it by more than `max_deviation`.
+fn segment_count<T: CoordFloat>(radius: T, sweep: T, max_deviation: T) -> usize {
+    assert!(
+        max_deviation > T::zero(),
+        "the maximum deviation must be strictly positive"
+    );
+    let two = T::one() + T::one();
+    // a chord spanning an angle θ deviates from the arc by r (1 - cos(θ / 2))
+    let max_angle = two
+        * (T::one() - max_deviation / radius.abs())
+            .max(-T::one())
+            .acos();
+    (sweep.to_radians().abs() / max_angle)
+        .ceil()
+        .to_usize()
+        .unwrap_or(1)
+        .max(1)
+}
+
 // used to check the sign of a vec of floats
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum ListSign {
@@ -556,6 +754,16 @@ macro_rules! impl_rstar_polygon {
                 self.exterior.envelope()
             }
         }
+
+        impl<T> $rstar::PointDistance for Polygon<T>
+        where
+            T: ::num_traits::Float + ::$rstar::RTreeNum,
+        {
+            fn distance_2(&self, point: &Point<T>) -> T {
+                let d = crate::private_utils::point_polygon_euclidean_distance(*point, self);
+                d.powi(2)
+            }
+        }
     };
 }
 
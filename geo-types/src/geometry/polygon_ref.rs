@@ -0,0 +1,63 @@
+use crate::{CoordNum, LineStringRef, Polygon};
+use alloc::vec::Vec;
+
+/// A borrowed [`Polygon`], whose rings are [`LineStringRef`]s: views over slices of
+/// coordinates, such as those decoded in place from a GeoArrow or WKB buffer.
+///
+/// Unlike `Polygon::new`, `PolygonRef::new` can't close the rings, which must already be
+/// closed. Only the list of interiors is allocated, not the coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{polygon, LineStringRef, PolygonRef};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+/// let view = PolygonRef::new(LineStringRef::new(&polygon.exterior().0), vec![]);
+/// assert_eq!(view, PolygonRef::from(&polygon));
+/// assert_eq!(view.to_polygon(), polygon);
+/// ```
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub struct PolygonRef<'a, T: CoordNum = f64> {
+    exterior: LineStringRef<'a, T>,
+    interiors: Vec<LineStringRef<'a, T>>,
+}
+
+impl<'a, T: CoordNum> PolygonRef<'a, T> {
+    /// Create a new `PolygonRef` from its closed exterior and interior rings.
+    pub fn new(exterior: LineStringRef<'a, T>, interiors: Vec<LineStringRef<'a, T>>) -> Self {
+        debug_assert!(exterior.is_closed() && interiors.iter().all(|ring| ring.is_closed()));
+        PolygonRef {
+            exterior,
+            interiors,
+        }
+    }
+
+    pub fn exterior(&self) -> &LineStringRef<'a, T> {
+        &self.exterior
+    }
+
+    pub fn interiors(&self) -> &[LineStringRef<'a, T>] {
+        &self.interiors
+    }
+
+    /// Copy the coordinates into an owned [`Polygon`].
+    pub fn to_polygon(&self) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.to_line_string(),
+            self.interiors
+                .iter()
+                .map(|ring| ring.to_line_string())
+                .collect(),
+        )
+    }
+}
+
+impl<'a, T: CoordNum> From<&'a Polygon<T>> for PolygonRef<'a, T> {
+    fn from(polygon: &'a Polygon<T>) -> Self {
+        PolygonRef {
+            exterior: polygon.exterior().into(),
+            interiors: polygon.interiors().iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{coord, polygon, Coord, CoordFloat, CoordNum, Line, Polygon};
+use crate::{coord, polygon, Coord, CoordFloat, CoordNum, Line, LineString, Polygon};
+use alloc::vec::Vec;
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
@@ -374,6 +375,63 @@ impl<T: CoordFloat> Rect<T> {
             y: (self.max.y + self.min.y) / two,
         }
     }
+
+    /// Create a `Polygon` from the `Rect` with its corners rounded: each is replaced by a
+    /// quarter circle of `radius`, approximated by `segments` segments. The radius is reduced to
+    /// half the width or the height of the `Rect` if it's larger. The polygon is wound
+    /// counter-clockwise, from the bottom of the lower right corner.
+    ///
+    /// # Panics
+    ///
+    /// If `segments` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 4. });
+    ///
+    /// let rounded = rect.to_rounded_polygon(1., 4);
+    /// assert_eq!(rounded.exterior().0.len(), 21);
+    /// assert_eq!(rect.to_rounded_polygon(0., 4), rect.to_polygon());
+    /// ```
+    pub fn to_rounded_polygon(self, radius: T, segments: usize) -> Polygon<T> {
+        assert!(segments > 0, "a rounded corner needs at least one segment");
+        let two = T::one() + T::one();
+        let radius = radius.min(self.width() / two).min(self.height() / two);
+        if radius <= T::zero() {
+            return self.to_polygon();
+        }
+
+        let quarter_turn = T::from(90).unwrap();
+        let (inner_min, inner_max) = (
+            self.min + coord! { x: radius, y: radius },
+            self.max - coord! { x: radius, y: radius },
+        );
+        let corners = [
+            coord! { x: inner_max.x, y: inner_min.y },
+            inner_max,
+            coord! { x: inner_min.x, y: inner_max.y },
+            inner_min,
+        ];
+        let mut coords = Vec::with_capacity(4 * (segments + 1) + 1);
+        for (i, corner) in corners.into_iter().enumerate() {
+            let start = quarter_turn * (T::from(i).unwrap() - T::one());
+            coords.extend(LineString::arc(
+                corner,
+                radius,
+                start,
+                start + quarter_turn,
+                segments,
+            ));
+        }
+        // where the corners meet, if the radius is half the width or the height
+        coords.dedup();
+        let mut exterior = LineString::new(coords);
+        exterior.close();
+        Polygon::new(exterior, Vec::new())
+    }
 }
 
 static RECT_INVALID_BOUNDS_ERROR: &str = "Failed to create Rect: 'min' coordinate's x/y value must be smaller or equal to the 'max' x/y value";
@@ -468,11 +526,12 @@ where
 pub struct InvalidRectCoordinatesError;
 
 #[allow(deprecated)]
+#[cfg(feature = "std")]
 impl std::error::Error for InvalidRectCoordinatesError {}
 
 #[allow(deprecated)]
-impl std::fmt::Display for InvalidRectCoordinatesError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for InvalidRectCoordinatesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", RECT_INVALID_BOUNDS_ERROR)
     }
 }
@@ -481,6 +540,36 @@ impl std::fmt::Display for InvalidRectCoordinatesError {
 mod test {
     use super::*;
     use crate::coord;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rounded_polygon() {
+        let rect = Rect::new((0., 0.), (10., 4.));
+        let rounded = rect.to_rounded_polygon(1., 2);
+        let corner = 0.5f64.sqrt();
+        assert_relative_eq!(
+            rounded,
+            polygon![
+                (x: 9., y: 0.),
+                (x: 9. + corner, y: 1. - corner),
+                (x: 10., y: 1.),
+                (x: 10., y: 3.),
+                (x: 9. + corner, y: 3. + corner),
+                (x: 9., y: 4.),
+                (x: 1., y: 4.),
+                (x: 1. - corner, y: 3. + corner),
+                (x: 0., y: 3.),
+                (x: 0., y: 1.),
+                (x: 1. - corner, y: 1. - corner),
+                (x: 1., y: 0.),
+            ],
+            epsilon = 1e-12
+        );
+
+        // the corners meet on the short sides
+        let rounded = rect.to_rounded_polygon(5., 2);
+        assert_eq!(rounded.exterior().0.len(), 4 * 3 - 2 + 1);
+    }
 
     #[test]
     fn rect() {
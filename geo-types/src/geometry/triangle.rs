@@ -1,4 +1,4 @@
-use crate::{polygon, Coord, CoordNum, Line, Polygon};
+use crate::{polygon, Coord, CoordFloat, CoordNum, Line, Polygon};
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
@@ -57,6 +57,42 @@ impl<T: CoordNum> Triangle<T> {
     }
 }
 
+impl<T: CoordFloat> Triangle<T> {
+    /// The circle through the three vertices of the triangle, as its center and radius, or
+    /// `None` if they're collinear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo_types::{coord, Triangle};
+    ///
+    /// let triangle = Triangle::new(
+    ///     coord! { x: 0., y: 0. },
+    ///     coord! { x: 4., y: 0. },
+    ///     coord! { x: 0., y: 2. },
+    /// );
+    /// let (center, radius) = triangle.circumcircle().unwrap();
+    /// assert_eq!(center, coord! { x: 2., y: 1. });
+    /// assert_relative_eq!(radius, 5f64.sqrt());
+    /// ```
+    pub fn circumcircle(&self) -> Option<(Coord<T>, T)> {
+        let two = T::one() + T::one();
+        let (b, c) = (self.1 - self.0, self.2 - self.0);
+        // twice the cross product of the sides from the first vertex
+        let d = two * (b.x * c.y - b.y * c.x);
+        if d == T::zero() {
+            return None;
+        }
+        let (b_sq, c_sq) = (b.x * b.x + b.y * b.y, c.x * c.x + c.y * c.y);
+        let offset = Coord {
+            x: (c.y * b_sq - b.y * c_sq) / d,
+            y: (b.x * c_sq - c.x * b_sq) / d,
+        };
+        Some((self.0 + offset, offset.x.hypot(offset.y)))
+    }
+}
+
 impl<IC: Into<Coord<T>> + Copy, T: CoordNum> From<[IC; 3]> for Triangle<T> {
     fn from(array: [IC; 3]) -> Self {
         Self(array[0].into(), array[1].into(), array[2].into())
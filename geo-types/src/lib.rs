@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_debug_implementations)]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/georust/meta/master/logo/logo.png")]
 //! The `geo-types` library defines geometric types for the [GeoRust] ecosystem.
@@ -60,11 +61,18 @@
 //!
 //! The following optional [Cargo features] are available:
 //!
-//! - `approx`: Allows geometry types to be checked for approximate equality with [approx]
+//! - `approx`: Allows geometry types to be checked for approximate equality with [approx], and their rings to be compared regardless of where they start and which way round they go with `CyclicRings`
 //! - `arbitrary`: Allows geometry types to be created from unstructured input with [arbitrary]
+//! - `arrow`: Allows geometries to be read from, and written to, the buffers of [GeoArrow](arrow) arrays, mostly without copying
+//! - `polyline`: Allows `LineString<f64>` to be encoded as, and decoded from, Google's [Encoded Polyline](polyline) format
 //! - `serde`: Allows geometry types to be serialized and deserialized with [Serde]
+//! - `shapefile`: Allows geometries to be converted to and from the shapes of the [shapefile] crate with `From` and `TryFrom`. It can't be combined with the `geo-types` feature of `shapefile`, which converts the other way round
+//! - `smallvec`: Adds the line strings and polygons of the [small](small) module, which store the coordinates of small rings inline rather than allocating them
+//! - `std`: Enables use of the full `std` library. Enabled by default. Without it, the crate only depends on `alloc`, and can be used in `no_std` environments
 //! - `use-rstar_0_8`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.8`)
 //! - `use-rstar_0_9`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.9`)
+//! - `wkb`: Allows geometry types to be written as, and read from, [WKB](wkb), including PostGIS' EWKB with SRIDs
+//! - `wkt`: Allows geometry types to be written as, and parsed from, [WKT](wkt) text with `Display` and `FromStr`
 //!
 //! [approx]: https://github.com/brendanzab/approx
 //! [arbitrary]: https://github.com/rust-fuzz/arbitrary
@@ -75,9 +83,14 @@
 //! [OGC-SFA]: https://www.ogc.org/standards/sfa
 //! [rstar]: https://github.com/Stoeoef/rstar
 //! [Serde]: https://serde.rs/
+//! [shapefile]: https://crates.io/crates/shapefile
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
 extern crate num_traits;
+use core::fmt::Debug;
 use num_traits::{Float, Num, NumCast};
-use std::fmt::Debug;
 
 #[cfg(feature = "serde")]
 #[macro_use]
@@ -113,7 +126,7 @@ impl<T: CoordNum + Float> CoordFloat for T {}
 pub mod geometry;
 pub use geometry::*;
 
-pub use geometry::line_string::PointsIter;
+pub use geometry::line_string::{PointsIter, VertexWindows};
 
 #[allow(deprecated)]
 pub use geometry::rect::InvalidRectCoordinatesError;
@@ -121,16 +134,64 @@ pub use geometry::rect::InvalidRectCoordinatesError;
 mod error;
 pub use error::Error;
 
+pub mod builder;
+pub use builder::{BuildError, LineStringBuilder, PolygonBuilder};
+
+#[cfg(any(feature = "approx", test))]
+mod cyclic_rings;
+#[cfg(any(feature = "approx", test))]
+pub use cyclic_rings::CyclicRings;
+
+#[cfg(any(feature = "wkt", feature = "wkb"))]
+mod shapes;
+
+#[cfg(feature = "wkt")]
+pub mod wkt;
+#[cfg(feature = "wkt")]
+pub use wkt::WktError;
+
+#[cfg(feature = "wkb")]
+pub mod wkb;
+#[cfg(feature = "wkb")]
+pub use wkb::WkbError;
+
+#[cfg(feature = "polyline")]
+pub mod polyline;
+#[cfg(feature = "polyline")]
+pub use polyline::PolylineError;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "arrow")]
+pub use arrow::GeoArrowError;
+
+#[cfg(feature = "smallvec")]
+pub mod small;
+
 #[macro_use]
 mod macros;
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
 
+#[cfg(feature = "shapefile")]
+mod shapefile;
+#[cfg(feature = "shapefile")]
+pub use crate::shapefile::UnsupportedShapeError;
+
 #[cfg(any(feature = "rstar_0_8", feature = "rstar_0_9"))]
 #[doc(hidden)]
 pub mod private_utils;
 
+#[doc(hidden)]
+pub mod _alloc {
+    //! Needed to access these types from `alloc` in macros when the `std` feature is disabled,
+    //! and the calling crate doesn't declare `extern crate alloc`. These are _not_ meant for
+    //! public use.
+    pub use ::alloc::boxed::Box;
+    pub use ::alloc::vec;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,6 +298,92 @@ mod tests {
         assert_relative_eq!(25.999999999999996, l.distance_2(&Point::new(4.0, 10.0)));
     }
 
+    #[cfg(feature = "rstar_0_8")]
+    #[test]
+    /// ensure the SpatialObject impls of areal geometries measure 0 inside them
+    fn geometry_test() {
+        use rstar_0_8::{PointDistance, RTree, RTreeObject};
+
+        let square: Polygon<f64> =
+            Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. }).into();
+        let hole = LineString::from(vec![(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)]);
+        let framed = Polygon::new(square.exterior().clone(), vec![hole]);
+        assert_relative_eq!(framed.distance_2(&Point::new(0.5, 2.)), 0.);
+        assert_relative_eq!(framed.distance_2(&Point::new(2., 2.)), 1.);
+        assert_relative_eq!(framed.distance_2(&Point::new(7., 8.)), 25.);
+
+        let multi = MultiPolygon::new(vec![
+            square.clone(),
+            Rect::new(coord! { x: 10., y: 0. }, coord! { x: 11., y: 1. }).to_polygon(),
+        ]);
+        assert_eq!(
+            multi.envelope(),
+            Geometry::from(Rect::new(coord! { x: 0., y: 0. }, coord! { x: 11., y: 4. })).envelope()
+        );
+        assert_relative_eq!(multi.distance_2(&Point::new(10.5, 2.)), 1.);
+
+        let tree = RTree::bulk_load(vec![
+            Geometry::from(square),
+            Geometry::from(Point::new(10., 10.)),
+            Geometry::from(Line::new(
+                coord! { x: 20., y: 0. },
+                coord! { x: 20., y: 10. },
+            )),
+            Geometry::from(multi),
+        ]);
+        assert_eq!(
+            tree.nearest_neighbor(&Point::new(19., 5.)),
+            Some(&Geometry::from(Line::new(
+                coord! { x: 20., y: 0. },
+                coord! { x: 20., y: 10. }
+            )))
+        );
+        assert_eq!(tree.locate_all_at_point(&Point::new(2., 2.)).count(), 2);
+    }
+
+    #[cfg(feature = "rstar_0_9")]
+    #[test]
+    /// ensure the SpatialObject impls of areal geometries measure 0 inside them
+    fn geometry_test_0_9() {
+        use rstar_0_9::{PointDistance, RTree, RTreeObject};
+
+        let square: Polygon<f64> =
+            Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. }).into();
+        let hole = LineString::from(vec![(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)]);
+        let framed = Polygon::new(square.exterior().clone(), vec![hole]);
+        assert_relative_eq!(framed.distance_2(&Point::new(0.5, 2.)), 0.);
+        assert_relative_eq!(framed.distance_2(&Point::new(2., 2.)), 1.);
+        assert_relative_eq!(framed.distance_2(&Point::new(7., 8.)), 25.);
+
+        let multi = MultiPolygon::new(vec![
+            square.clone(),
+            Rect::new(coord! { x: 10., y: 0. }, coord! { x: 11., y: 1. }).to_polygon(),
+        ]);
+        assert_eq!(
+            multi.envelope(),
+            Geometry::from(Rect::new(coord! { x: 0., y: 0. }, coord! { x: 11., y: 4. })).envelope()
+        );
+        assert_relative_eq!(multi.distance_2(&Point::new(10.5, 2.)), 1.);
+
+        let tree = RTree::bulk_load(vec![
+            Geometry::from(square),
+            Geometry::from(Point::new(10., 10.)),
+            Geometry::from(Line::new(
+                coord! { x: 20., y: 0. },
+                coord! { x: 20., y: 10. },
+            )),
+            Geometry::from(multi),
+        ]);
+        assert_eq!(
+            tree.nearest_neighbor(&Point::new(19., 5.)),
+            Some(&Geometry::from(Line::new(
+                coord! { x: 20., y: 0. },
+                coord! { x: 20., y: 10. }
+            )))
+        );
+        assert_eq!(tree.locate_all_at_point(&Point::new(2., 2.)).count(), 2);
+    }
+
     #[test]
     fn test_rects() {
         let r = Rect::new(coord! { x: -1., y: -1. }, coord! { x: 1., y: 1. });
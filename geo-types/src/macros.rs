@@ -121,7 +121,7 @@ macro_rules! coord {
 /// [`LineString`]: ./line_string/struct.LineString.html
 #[macro_export]
 macro_rules! line_string {
-    () => { $crate::LineString::new(vec![]) };
+    () => { $crate::LineString::new($crate::_alloc::vec![]) };
     (
         $(( $($tag:tt : $val:expr),* $(,)? )),*
         $(,)?
@@ -138,7 +138,7 @@ macro_rules! line_string {
     ) => {
         $crate::LineString::new(
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::Box::new(
                     [$($coord), *]
                 )
             )
@@ -214,7 +214,7 @@ macro_rules! line_string {
 /// [`Polygon`]: ./struct.Polygon.html
 #[macro_export]
 macro_rules! polygon {
-    () => { $crate::Polygon::new($crate::line_string![], vec![]) };
+    () => { $crate::Polygon::new($crate::line_string![], $crate::_alloc::vec![]) };
     (
         exterior: [
             $(( $($exterior_tag:tt : $exterior_val:expr),* $(,)? )),*
@@ -261,7 +261,7 @@ macro_rules! polygon {
                 $($exterior_coord), *
             ],
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::Box::new(
                     [
                         $(
                             $crate::line_string![$($interior_coord),*]
@@ -285,7 +285,7 @@ macro_rules! polygon {
     ) => {
         $crate::Polygon::new(
             $crate::line_string![$($coord,)*],
-            vec![],
+            $crate::_alloc::vec![],
         )
     };
 }
@@ -0,0 +1,284 @@
+//! Encoding and decoding line strings as Google's [Encoded Polyline] format, as used by many
+//! routing APIs.
+//!
+//! `LineString<f64>` has `to_polyline` and `from_polyline` methods, which take the precision of
+//! the encoding: the number of decimal places coordinates are rounded to, usually 5, as Google
+//! uses, or 6, as e.g. OSRM and Valhalla can.
+//!
+//! Coordinates are longitudes (`x`) and latitudes (`y`), which the format stores in the opposite
+//! order, as `(latitude, longitude)` pairs.
+//!
+//! [Encoded Polyline]: https://developers.google.com/maps/documentation/utilities/polylinealgorithm
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{line_string, LineString};
+//!
+//! let line_string = line_string![
+//!     (x: -120.2, y: 38.5),
+//!     (x: -120.95, y: 40.7),
+//!     (x: -126.453, y: 43.252),
+//! ];
+//! let polyline = line_string.to_polyline(5).unwrap();
+//! assert_eq!(polyline, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+//! assert_eq!(LineString::from_polyline(&polyline, 5).unwrap(), line_string);
+//! ```
+
+use crate::{Coord, LineString};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use num_traits::Float;
+
+/// The ways in which encoding or decoding an [Encoded Polyline](crate::polyline) can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolylineError {
+    /// A coordinate isn't a valid longitude or latitude.
+    InvalidCoordinate(f64),
+    /// More than [`MAX_PRECISION`] decimal places.
+    InvalidPrecision(u32),
+    /// A character outside of the `?` to `~` range of the format.
+    InvalidCharacter { position: usize, found: char },
+    /// The value starting at `position` doesn't fit in 64 bits.
+    Overflow { position: usize },
+    /// The polyline ended in the middle of a value, or of a coordinate.
+    UnexpectedEnd,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolylineError {}
+
+impl fmt::Display for PolylineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolylineError::InvalidCoordinate(value) => {
+                write!(f, "{} isn't a valid longitude or latitude", value)
+            }
+            PolylineError::InvalidPrecision(precision) => write!(
+                f,
+                "A precision of {} decimal places is more than the {} supported",
+                precision, MAX_PRECISION
+            ),
+            PolylineError::InvalidCharacter { position, found } => {
+                write!(f, "Invalid polyline character {:?} at {}", found, position)
+            }
+            PolylineError::Overflow { position } => {
+                write!(f, "Polyline value at {} is too large", position)
+            }
+            PolylineError::UnexpectedEnd => write!(f, "Unexpected end of polyline"),
+        }
+    }
+}
+
+/// The most decimal places coordinates can be encoded with: the difference between a longitude
+/// of 180° and one of -180° must fit in 64 bits.
+pub const MAX_PRECISION: u32 = 16;
+
+fn factor(precision: u32) -> Result<f64, PolylineError> {
+    if precision > MAX_PRECISION {
+        return Err(PolylineError::InvalidPrecision(precision));
+    }
+    Ok(Float::powi(10., precision as i32))
+}
+
+fn scale(value: f64, limit: f64, factor: f64) -> Result<i64, PolylineError> {
+    if !(-limit..=limit).contains(&value) {
+        return Err(PolylineError::InvalidCoordinate(value));
+    }
+    Ok(Float::round(value * factor) as i64)
+}
+
+// Append the zigzag-encoded `value` in chunks of 5 bits, least significant first, each offset
+// by 63 to be printable, and all but the last flagged with 0x20.
+fn encode_value(value: i64, polyline: &mut String) {
+    let mut value = if value < 0 {
+        !((value as u64) << 1)
+    } else {
+        (value as u64) << 1
+    };
+    while value >= 0x20 {
+        polyline.push(char::from((0x20 | (value & 0x1f) as u8) + 63));
+        value >>= 5;
+    }
+    polyline.push(char::from(value as u8 + 63));
+}
+
+fn decode_value(
+    chars: &mut impl Iterator<Item = (usize, char)>,
+) -> Option<Result<i64, PolylineError>> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    let mut first = None;
+    loop {
+        let (position, c) = match chars.next() {
+            Some(next) => next,
+            None if first.is_none() => return None,
+            None => return Some(Err(PolylineError::UnexpectedEnd)),
+        };
+        let start = *first.get_or_insert(position);
+        let chunk = match u64::from(c).checked_sub(63) {
+            Some(chunk) if chunk < 0x40 => chunk,
+            _ => return Some(Err(PolylineError::InvalidCharacter { position, found: c })),
+        };
+        if shift >= 64 || (chunk & 0x1f) << shift >> shift != chunk & 0x1f {
+            return Some(Err(PolylineError::Overflow { position: start }));
+        }
+        value |= (chunk & 0x1f) << shift;
+        shift += 5;
+        if chunk < 0x20 {
+            break;
+        }
+    }
+    let value = if value & 1 == 1 {
+        !(value >> 1) as i64
+    } else {
+        (value >> 1) as i64
+    };
+    Some(Ok(value))
+}
+
+impl LineString<f64> {
+    /// Encode this line string as an [Encoded Polyline](crate::polyline), rounding its
+    /// coordinates to `precision` decimal places.
+    ///
+    /// Fails if a coordinate isn't a valid longitude or latitude, or if `precision` is more than
+    /// [`MAX_PRECISION`].
+    pub fn to_polyline(&self, precision: u32) -> Result<String, PolylineError> {
+        let factor = factor(precision)?;
+        let mut polyline = String::new();
+        let mut previous = (0, 0);
+        for coord in &self.0 {
+            let lat = scale(coord.y, 90., factor)?;
+            let lng = scale(coord.x, 180., factor)?;
+            encode_value(lat - previous.0, &mut polyline);
+            encode_value(lng - previous.1, &mut polyline);
+            previous = (lat, lng);
+        }
+        Ok(polyline)
+    }
+
+    /// Decode a line string from an [Encoded Polyline](crate::polyline) of the given
+    /// `precision`, which can't be more than [`MAX_PRECISION`].
+    pub fn from_polyline(polyline: &str, precision: u32) -> Result<Self, PolylineError> {
+        let factor = factor(precision)?;
+        let mut chars = polyline.char_indices();
+        let mut coords = Vec::new();
+        let (mut lat, mut lng) = (0i64, 0i64);
+        while let Some(lat_delta) = decode_value(&mut chars) {
+            let lng_delta = decode_value(&mut chars).ok_or(PolylineError::UnexpectedEnd)?;
+            lat = lat.wrapping_add(lat_delta?);
+            lng = lng.wrapping_add(lng_delta?);
+            coords.push(Coord {
+                x: lng as f64 / factor,
+                y: lat as f64 / factor,
+            });
+        }
+        Ok(LineString::new(coords))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn google_example() {
+        let line_string = line_string![
+            (x: -120.2, y: 38.5),
+            (x: -120.95, y: 40.7),
+            (x: -126.453, y: 43.252),
+        ];
+        assert_eq!(
+            line_string.to_polyline(5).unwrap(),
+            "_p~iF~ps|U_ulLnnqC_mqNvxq`@"
+        );
+        assert_eq!(
+            LineString::from_polyline("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5).unwrap(),
+            line_string
+        );
+    }
+
+    #[test]
+    fn precision() {
+        let line_string =
+            line_string![(x: 13.388_860, y: 52.517_037), (x: 13.397_634, y: 52.529_407)];
+        let polyline = line_string.to_polyline(6).unwrap();
+        let decoded = LineString::from_polyline(&polyline, 6).unwrap();
+        for (a, b) in decoded.coords().zip(line_string.coords()) {
+            assert!((a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9);
+        }
+        // the same polyline read with the wrong precision is ten times too small
+        let wrong = LineString::from_polyline(&polyline, 7).unwrap();
+        assert!((wrong.0[0].y - 5.251_703_7).abs() < 1e-9);
+
+        // rounding
+        let polyline = line_string![(x: 0.000_004, y: 0.000_006)]
+            .to_polyline(5)
+            .unwrap();
+        assert_eq!(
+            LineString::from_polyline(&polyline, 5).unwrap(),
+            line_string![(x: 0., y: 0.000_01)]
+        );
+    }
+
+    #[test]
+    fn max_precision() {
+        let line_string = line_string![(x: 180., y: 90.), (x: -180., y: -90.)];
+        let polyline = line_string.to_polyline(MAX_PRECISION).unwrap();
+        assert_eq!(
+            LineString::from_polyline(&polyline, MAX_PRECISION).unwrap(),
+            line_string
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(LineString::new(vec![]).to_polyline(5).unwrap(), "");
+        assert_eq!(
+            LineString::from_polyline("", 5).unwrap(),
+            LineString::new(vec![])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            line_string![(x: 0., y: 91.)].to_polyline(5),
+            Err(PolylineError::InvalidCoordinate(91.))
+        );
+        assert!(matches!(
+            line_string![(x: f64::NAN, y: 0.)].to_polyline(5),
+            Err(PolylineError::InvalidCoordinate(_))
+        ));
+        assert_eq!(
+            line_string![(x: 180., y: 0.), (x: -180., y: 0.)].to_polyline(17),
+            Err(PolylineError::InvalidPrecision(17))
+        );
+        assert_eq!(
+            LineString::from_polyline("??", 17),
+            Err(PolylineError::InvalidPrecision(17))
+        );
+        assert_eq!(
+            LineString::from_polyline("_p~iF~ps|U_ulL", 5),
+            Err(PolylineError::UnexpectedEnd)
+        );
+        assert_eq!(
+            LineString::from_polyline("_p~iF~ps|", 5),
+            Err(PolylineError::UnexpectedEnd)
+        );
+        assert_eq!(
+            LineString::from_polyline("_p~iF ps|U", 5),
+            Err(PolylineError::InvalidCharacter {
+                position: 5,
+                found: ' '
+            })
+        );
+        assert_eq!(
+            LineString::from_polyline("_p~iF~~~~~~~~~~~~~~~~?", 5),
+            Err(PolylineError::Overflow { position: 5 })
+        );
+    }
+}
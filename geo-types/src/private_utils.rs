@@ -3,7 +3,9 @@
 // hidden module is public so the geo crate can reuse these algorithms to
 // prevent duplication. These functions are _not_ meant for public consumption.
 
-use crate::{Coord, CoordFloat, CoordNum, Line, LineString, Point, Rect};
+use crate::{
+    Coord, CoordFloat, CoordNum, Geometry, Line, LineString, MultiPolygon, Point, Polygon, Rect,
+};
 
 pub fn line_string_bounding_rect<T>(line_string: &LineString<T>) -> Option<Rect<T>>
 where
@@ -19,6 +21,44 @@ where
     Rect::new(line.start, line.end)
 }
 
+pub fn multi_polygon_bounding_rect<T>(multi_polygon: &MultiPolygon<T>) -> Option<Rect<T>>
+where
+    T: CoordNum,
+{
+    get_bounding_rect(
+        multi_polygon
+            .iter()
+            .flat_map(|polygon| polygon.exterior().coords().cloned()),
+    )
+}
+
+pub fn geometry_bounding_rect<T>(geometry: &Geometry<T>) -> Option<Rect<T>>
+where
+    T: CoordNum,
+{
+    match geometry {
+        Geometry::Point(point) => Some(Rect::new(point.0, point.0)),
+        Geometry::Line(line) => Some(line_bounding_rect(*line)),
+        Geometry::LineString(line_string) => line_string_bounding_rect(line_string),
+        Geometry::Polygon(polygon) => line_string_bounding_rect(polygon.exterior()),
+        Geometry::MultiPoint(multi_point) => get_bounding_rect(multi_point.iter().map(|p| p.0)),
+        Geometry::MultiLineString(multi_line_string) => get_bounding_rect(
+            multi_line_string
+                .iter()
+                .flat_map(|line_string| line_string.coords().cloned()),
+        ),
+        Geometry::MultiPolygon(multi_polygon) => multi_polygon_bounding_rect(multi_polygon),
+        Geometry::GeometryCollection(collection) => get_bounding_rect(
+            collection
+                .iter()
+                .filter_map(geometry_bounding_rect)
+                .flat_map(|rect| [rect.min(), rect.max()]),
+        ),
+        Geometry::Rect(rect) => Some(*rect),
+        Geometry::Triangle(triangle) => get_bounding_rect(triangle.to_array()),
+    }
+}
+
 pub fn get_bounding_rect<I, T>(collection: I) -> Option<Rect<T>>
 where
     T: CoordNum,
@@ -171,3 +211,78 @@ where
     }
     false
 }
+
+// Crossing number test. Coordinates on the boundary may be reported either way, as only the
+// distance functions below use it, and those measure 0 to the boundary regardless.
+fn ring_contains_coord<T>(ring: &LineString<T>, coord: Coord<T>) -> bool
+where
+    T: CoordFloat,
+{
+    let mut inside = false;
+    for line in ring.lines() {
+        if (line.start.y > coord.y) != (line.end.y > coord.y) {
+            let x = line.start.x + (coord.y - line.start.y) / line.dy() * line.dx();
+            if coord.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+pub fn point_polygon_euclidean_distance<T>(p: Point<T>, polygon: &Polygon<T>) -> T
+where
+    T: CoordFloat,
+{
+    if ring_contains_coord(polygon.exterior(), p.0)
+        && !polygon
+            .interiors()
+            .iter()
+            .any(|ring| ring_contains_coord(ring, p.0))
+    {
+        return T::zero();
+    }
+    core::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.lines())
+        .map(|line| line_segment_distance(p.0, line.start, line.end))
+        .fold(T::max_value(), |accum, val| accum.min(val))
+}
+
+pub fn point_multi_polygon_euclidean_distance<T>(p: Point<T>, mp: &MultiPolygon<T>) -> T
+where
+    T: CoordFloat,
+{
+    mp.iter()
+        .map(|polygon| point_polygon_euclidean_distance(p, polygon))
+        .fold(T::max_value(), |accum, val| accum.min(val))
+}
+
+pub fn point_geometry_euclidean_distance<T>(p: Point<T>, geometry: &Geometry<T>) -> T
+where
+    T: CoordFloat,
+{
+    match geometry {
+        Geometry::Point(point) => line_euclidean_length(Line::new(p, *point)),
+        Geometry::Line(line) => point_line_euclidean_distance(p, *line),
+        Geometry::LineString(line_string) => point_line_string_euclidean_distance(p, line_string),
+        Geometry::Polygon(polygon) => point_polygon_euclidean_distance(p, polygon),
+        Geometry::MultiPoint(multi_point) => multi_point
+            .iter()
+            .map(|point| line_euclidean_length(Line::new(p, *point)))
+            .fold(T::max_value(), |accum, val| accum.min(val)),
+        Geometry::MultiLineString(multi_line_string) => multi_line_string
+            .iter()
+            .map(|line_string| point_line_string_euclidean_distance(p, line_string))
+            .fold(T::max_value(), |accum, val| accum.min(val)),
+        Geometry::MultiPolygon(multi_polygon) => {
+            point_multi_polygon_euclidean_distance(p, multi_polygon)
+        }
+        Geometry::GeometryCollection(collection) => collection
+            .iter()
+            .map(|geometry| point_geometry_euclidean_distance(p, geometry))
+            .fold(T::max_value(), |accum, val| accum.min(val)),
+        Geometry::Rect(rect) => point_polygon_euclidean_distance(p, &rect.to_polygon()),
+        Geometry::Triangle(triangle) => point_polygon_euclidean_distance(p, &triangle.to_polygon()),
+    }
+}
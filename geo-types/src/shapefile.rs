@@ -0,0 +1,381 @@
+//! Conversions between geometries and the shapes of the [shapefile] crate.
+//!
+//! The M and Z values of shapes are dropped. Polylines, which may have several parts, become
+//! `MultiLineString`s, and polygons, which may have several outer rings, `MultiPolygon`s: each
+//! inner ring becomes a hole of the smallest outer ring containing it, wherever it comes in the
+//! shape, and inner rings which no outer ring contains become polygons of their own.
+//!
+//! [shapefile]: https://crates.io/crates/shapefile
+
+use crate::{
+    Coord, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use shapefile::PolygonRing;
+
+/// The shapes and geometries which can't be converted to one another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsupportedShapeError {
+    /// A null shape, which has no geometry.
+    NullShape,
+    /// A multipatch, whose surfaces have no equivalent geometry.
+    Multipatch,
+    /// A geometry collection, which has no equivalent shape.
+    GeometryCollection,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedShapeError {}
+
+impl fmt::Display for UnsupportedShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnsupportedShapeError::NullShape => write!(f, "A null shape has no geometry"),
+            UnsupportedShapeError::Multipatch => {
+                write!(f, "Multipatch shapes can't be converted to geometries")
+            }
+            UnsupportedShapeError::GeometryCollection => {
+                write!(f, "Geometry collections can't be converted to shapes")
+            }
+        }
+    }
+}
+
+// The points of shapes, with or without M and Z values.
+trait ShapePoint {
+    fn coord(&self) -> Coord<f64>;
+}
+
+macro_rules! shape_point_impl {
+    ($($point:ident),*) => {
+        $(
+            impl ShapePoint for shapefile::$point {
+                fn coord(&self) -> Coord<f64> {
+                    Coord {
+                        x: self.x,
+                        y: self.y,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+shape_point_impl!(Point, PointM, PointZ);
+
+fn line_string<P: ShapePoint>(points: &[P]) -> LineString<f64> {
+    points.iter().map(ShapePoint::coord).collect()
+}
+
+// Crossing number test of `coord` against the closed ring `ring`.
+fn ring_contains(ring: &LineString<f64>, coord: Coord<f64>) -> bool {
+    let mut inside = false;
+    for line in ring.lines() {
+        if (line.start.y > coord.y) != (line.end.y > coord.y) {
+            let x = line.start.x + (coord.y - line.start.y) / line.dy() * line.dx();
+            if coord.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+fn ring_area(ring: &LineString<f64>) -> f64 {
+    ring.lines()
+        .map(|line| line.determinant())
+        .sum::<f64>()
+        .abs()
+        / 2.
+}
+
+// Group the rings of a shapefile polygon into polygons, giving each inner ring to the smallest
+// outer ring containing its first point.
+fn multi_polygon<P: ShapePoint>(rings: &[PolygonRing<P>]) -> MultiPolygon<f64> {
+    let mut outers = vec![];
+    let mut inners = vec![];
+    for ring in rings {
+        match ring {
+            PolygonRing::Outer(points) => outers.push(line_string(points)),
+            PolygonRing::Inner(points) => inners.push(line_string(points)),
+        }
+    }
+    let areas: Vec<f64> = outers.iter().map(ring_area).collect();
+    let mut holes: Vec<Vec<LineString<f64>>> = vec![vec![]; outers.len()];
+    let mut orphans = vec![];
+    for inner in inners {
+        let container = inner.0.first().and_then(|&first| {
+            (0..outers.len())
+                .filter(|&i| ring_contains(&outers[i], first))
+                .min_by(|&a, &b| areas[a].partial_cmp(&areas[b]).unwrap())
+        });
+        match container {
+            Some(i) => holes[i].push(inner),
+            None => orphans.push(Polygon::new(inner, vec![])),
+        }
+    }
+    outers
+        .into_iter()
+        .zip(holes)
+        .map(|(exterior, interiors)| Polygon::new(exterior, interiors))
+        .chain(orphans)
+        .collect()
+}
+
+macro_rules! from_shape_impl {
+    ($point:ident, $multipoint:ident, $polyline:ident, $polygon:ident) => {
+        impl From<shapefile::$point> for Point<f64> {
+            fn from(point: shapefile::$point) -> Self {
+                Point(point.coord())
+            }
+        }
+
+        impl From<shapefile::$multipoint> for MultiPoint<f64> {
+            fn from(multipoint: shapefile::$multipoint) -> Self {
+                multipoint
+                    .points()
+                    .iter()
+                    .map(|point| Point(point.coord()))
+                    .collect()
+            }
+        }
+
+        impl From<shapefile::$polyline> for MultiLineString<f64> {
+            fn from(polyline: shapefile::$polyline) -> Self {
+                MultiLineString::new(
+                    polyline
+                        .parts()
+                        .iter()
+                        .map(|part| line_string(part))
+                        .collect(),
+                )
+            }
+        }
+
+        impl From<shapefile::$polygon> for MultiPolygon<f64> {
+            fn from(polygon: shapefile::$polygon) -> Self {
+                multi_polygon(polygon.rings())
+            }
+        }
+    };
+}
+
+from_shape_impl!(Point, Multipoint, Polyline, Polygon);
+from_shape_impl!(PointM, MultipointM, PolylineM, PolygonM);
+from_shape_impl!(PointZ, MultipointZ, PolylineZ, PolygonZ);
+
+impl TryFrom<shapefile::Shape> for Geometry<f64> {
+    type Error = UnsupportedShapeError;
+
+    fn try_from(shape: shapefile::Shape) -> Result<Self, Self::Error> {
+        use shapefile::Shape;
+
+        Ok(match shape {
+            Shape::NullShape => return Err(UnsupportedShapeError::NullShape),
+            Shape::Multipatch(_) => return Err(UnsupportedShapeError::Multipatch),
+            Shape::Point(point) => Point::from(point).into(),
+            Shape::PointM(point) => Point::from(point).into(),
+            Shape::PointZ(point) => Point::from(point).into(),
+            Shape::Multipoint(multipoint) => MultiPoint::from(multipoint).into(),
+            Shape::MultipointM(multipoint) => MultiPoint::from(multipoint).into(),
+            Shape::MultipointZ(multipoint) => MultiPoint::from(multipoint).into(),
+            Shape::Polyline(polyline) => MultiLineString::from(polyline).into(),
+            Shape::PolylineM(polyline) => MultiLineString::from(polyline).into(),
+            Shape::PolylineZ(polyline) => MultiLineString::from(polyline).into(),
+            Shape::Polygon(polygon) => MultiPolygon::from(polygon).into(),
+            Shape::PolygonM(polygon) => MultiPolygon::from(polygon).into(),
+            Shape::PolygonZ(polygon) => MultiPolygon::from(polygon).into(),
+        })
+    }
+}
+
+fn shape_point(coord: Coord<f64>) -> shapefile::Point {
+    shapefile::Point::new(coord.x, coord.y)
+}
+
+fn shape_points(line_string: &LineString<f64>) -> Vec<shapefile::Point> {
+    line_string.coords().copied().map(shape_point).collect()
+}
+
+// The rings of a polygon, which `shapefile::Polygon::with_rings` winds as shapefiles require.
+fn shape_rings(polygon: &Polygon<f64>) -> Vec<PolygonRing<shapefile::Point>> {
+    if polygon.exterior().0.is_empty() {
+        return vec![];
+    }
+    core::iter::once(PolygonRing::Outer(shape_points(polygon.exterior())))
+        .chain(
+            polygon
+                .interiors()
+                .iter()
+                .map(|interior| PolygonRing::Inner(shape_points(interior))),
+        )
+        .collect()
+}
+
+impl From<Point<f64>> for shapefile::Point {
+    fn from(point: Point<f64>) -> Self {
+        shape_point(point.0)
+    }
+}
+
+impl From<MultiPoint<f64>> for shapefile::Multipoint {
+    fn from(multi_point: MultiPoint<f64>) -> Self {
+        shapefile::Multipoint::new(
+            multi_point
+                .iter()
+                .map(|point| shape_point(point.0))
+                .collect(),
+        )
+    }
+}
+
+impl From<LineString<f64>> for shapefile::Polyline {
+    fn from(line_string: LineString<f64>) -> Self {
+        shapefile::Polyline::new(shape_points(&line_string))
+    }
+}
+
+impl From<MultiLineString<f64>> for shapefile::Polyline {
+    fn from(multi_line_string: MultiLineString<f64>) -> Self {
+        shapefile::Polyline::with_parts(multi_line_string.iter().map(shape_points).collect())
+    }
+}
+
+impl From<Polygon<f64>> for shapefile::Polygon {
+    fn from(polygon: Polygon<f64>) -> Self {
+        shapefile::Polygon::with_rings(shape_rings(&polygon))
+    }
+}
+
+impl From<MultiPolygon<f64>> for shapefile::Polygon {
+    fn from(multi_polygon: MultiPolygon<f64>) -> Self {
+        shapefile::Polygon::with_rings(multi_polygon.iter().flat_map(shape_rings).collect())
+    }
+}
+
+impl TryFrom<Geometry<f64>> for shapefile::Shape {
+    type Error = UnsupportedShapeError;
+
+    /// `Line`s become `Polyline`s, and `Rect`s and `Triangle`s `Polygon`s.
+    fn try_from(geometry: Geometry<f64>) -> Result<Self, Self::Error> {
+        use shapefile::Shape;
+
+        Ok(match geometry {
+            Geometry::Point(point) => Shape::Point(point.into()),
+            Geometry::MultiPoint(multi_point) => Shape::Multipoint(multi_point.into()),
+            Geometry::Line(line) => {
+                Shape::Polyline(LineString::new(vec![line.start, line.end]).into())
+            }
+            Geometry::LineString(line_string) => Shape::Polyline(line_string.into()),
+            Geometry::MultiLineString(multi_line_string) => {
+                Shape::Polyline(multi_line_string.into())
+            }
+            Geometry::Polygon(polygon) => Shape::Polygon(polygon.into()),
+            Geometry::MultiPolygon(multi_polygon) => Shape::Polygon(multi_polygon.into()),
+            Geometry::Rect(rect) => Shape::Polygon(rect.to_polygon().into()),
+            Geometry::Triangle(triangle) => Shape::Polygon(triangle.to_polygon().into()),
+            Geometry::GeometryCollection(_) => {
+                return Err(UnsupportedShapeError::GeometryCollection)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GeometryCollection;
+
+    fn square(min: f64, max: f64) -> Vec<shapefile::Point> {
+        [(min, min), (min, max), (max, max), (max, min), (min, min)]
+            .iter()
+            .map(|&(x, y)| shapefile::Point::new(x, y))
+            .collect()
+    }
+
+    #[test]
+    fn ring_grouping() {
+        // a lake with an island, a hole listed before its outer ring, and an inner ring outside
+        // of any outer ring
+        let shape = shapefile::Polygon::with_rings(vec![
+            PolygonRing::Inner(square(22., 28.)),
+            PolygonRing::Outer(square(0., 10.)),
+            PolygonRing::Inner(square(2., 8.)),
+            PolygonRing::Outer(square(4., 6.)),
+            PolygonRing::Outer(square(20., 30.)),
+            PolygonRing::Inner(square(40., 50.)),
+        ]);
+        let multi_polygon = MultiPolygon::from(shape);
+        let holes: Vec<usize> = multi_polygon
+            .iter()
+            .map(|polygon| polygon.interiors().len())
+            .collect();
+        assert_eq!(holes, vec![1, 0, 1, 0]);
+        assert_eq!(
+            multi_polygon.0[0].interiors()[0].0[0],
+            Coord { x: 2., y: 2. }
+        );
+        assert_eq!(
+            multi_polygon.0[2].interiors()[0].0[0],
+            Coord { x: 22., y: 22. }
+        );
+        assert_eq!(multi_polygon.0[3].exterior().0[0], Coord { x: 40., y: 40. });
+    }
+
+    #[test]
+    fn round_trips() {
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 1., y: 2.).into(),
+            MultiPoint::new(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]).into(),
+            MultiLineString::new(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 2., y: 2.), (x: 3., y: 1.)],
+            ])
+            .into(),
+            MultiPolygon::new(vec![polygon![
+                exterior: [(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+            ]])
+            .into(),
+        ];
+        for geometry in geometries {
+            let shape = shapefile::Shape::try_from(geometry.clone()).unwrap();
+            assert_eq!(Geometry::try_from(shape), Ok(geometry));
+        }
+    }
+
+    #[test]
+    fn shapes_with_measures() {
+        let polyline = shapefile::PolylineZ::new(vec![
+            shapefile::PointZ::new(0., 0., 10., 1.),
+            shapefile::PointZ::new(1., 1., 20., 2.),
+        ]);
+        assert_eq!(
+            Geometry::try_from(shapefile::Shape::PolylineZ(polyline)),
+            Ok(MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.)]]).into())
+        );
+        assert_eq!(
+            Point::from(shapefile::PointM::new(1., 2., 3.)),
+            point!(x: 1., y: 2.)
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            Geometry::try_from(shapefile::Shape::NullShape),
+            Err(UnsupportedShapeError::NullShape)
+        );
+        let collection: Geometry<f64> =
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![]));
+        assert_eq!(
+            shapefile::Shape::try_from(collection).map(|_| ()),
+            Err(UnsupportedShapeError::GeometryCollection)
+        );
+    }
+}
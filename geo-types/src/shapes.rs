@@ -0,0 +1,38 @@
+// Formats such as WKT and WKB have no `Line`, `Triangle` or `Rect`, which are written as line
+// strings and polygons instead. These recognize them when reading them back.
+
+use crate::{CoordNum, Line, LineString, Polygon, Rect, Triangle};
+
+pub(crate) fn line_from_line_string<T: CoordNum>(line_string: &LineString<T>) -> Option<Line<T>> {
+    match line_string.0.as_slice() {
+        [start, end] => Some(Line::new(*start, *end)),
+        _ => None,
+    }
+}
+
+pub(crate) fn triangle_from_polygon<T: CoordNum>(polygon: &Polygon<T>) -> Option<Triangle<T>> {
+    match polygon.exterior().0.as_slice() {
+        [a, b, c, _] if polygon.interiors().is_empty() => Some(Triangle(*a, *b, *c)),
+        _ => None,
+    }
+}
+
+pub(crate) fn rect_from_polygon<T: CoordNum>(polygon: &Polygon<T>) -> Option<Rect<T>> {
+    let exterior = polygon.exterior();
+    // four edges, alternately horizontal and vertical
+    let is_rect = |first_is_horizontal: bool| {
+        exterior.lines().enumerate().all(|(i, line)| {
+            if (i % 2 == 0) == first_is_horizontal {
+                line.dy() == T::zero()
+            } else {
+                line.dx() == T::zero()
+            }
+        })
+    };
+    if exterior.0.len() == 5 && polygon.interiors().is_empty() && (is_rect(true) || is_rect(false))
+    {
+        Some(Rect::new(exterior[0], exterior[2]))
+    } else {
+        None
+    }
+}
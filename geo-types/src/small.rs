@@ -0,0 +1,240 @@
+//! Line strings and polygons which store up to [`INLINE_COORDS`] coordinates per ring inline,
+//! in a [`SmallVec`], rather than in a heap-allocated `Vec`.
+//!
+//! Most of the polygons of some datasets, e.g. building footprints, are tiny, and building a
+//! `Polygon` for each of them means an allocation for each ring, which can dominate the time
+//! spent processing them. A [`SmallLineString`] or [`SmallPolygon`] with at most
+//! [`INLINE_COORDS`] coordinates per ring, and no interiors, doesn't allocate at all.
+//!
+//! Their coordinates are exposed as slices, so the algorithms of the `geo` crate can process
+//! them through [`LineStringRef`] and [`PolygonRef`] views, without copying them.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::small::{SmallLineString, SmallPolygon};
+//! use geo_types::{coord, polygon, PolygonRef};
+//!
+//! let exterior: SmallLineString = [
+//!     coord! { x: 0., y: 0. },
+//!     coord! { x: 1., y: 0. },
+//!     coord! { x: 1., y: 1. },
+//! ]
+//! .into_iter()
+//! .collect();
+//! let footprint = SmallPolygon::new(exterior, vec![]);
+//! assert!(!footprint.spilled());
+//!
+//! let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+//! assert_eq!(footprint.as_view(), PolygonRef::from(&polygon));
+//! assert_eq!(footprint.to_polygon(), polygon);
+//! ```
+
+use crate::{Coord, CoordNum, LineString, LineStringRef, Polygon, PolygonRef};
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use smallvec::SmallVec;
+
+/// The number of coordinates a [`SmallLineString`] stores inline.
+pub const INLINE_COORDS: usize = 16;
+
+/// A [`LineString`] which stores up to [`INLINE_COORDS`] coordinates inline, and only
+/// allocates for longer ones.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub struct SmallLineString<T: CoordNum = f64>(pub SmallVec<[Coord<T>; INLINE_COORDS]>);
+
+impl<T: CoordNum> SmallLineString<T> {
+    pub fn new(coords: SmallVec<[Coord<T>; INLINE_COORDS]>) -> Self {
+        SmallLineString(coords)
+    }
+
+    /// Borrow the coordinates as a [`LineStringRef`], to which the algorithms of the `geo`
+    /// crate apply.
+    pub fn as_view(&self) -> LineStringRef<'_, T> {
+        LineStringRef(&self.0)
+    }
+
+    /// Whether the coordinates have outgrown the inline storage and been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        self.0.spilled()
+    }
+
+    /// Whether the first and last coordinates are the same, or there are none, like
+    /// [`LineString::is_closed`].
+    pub fn is_closed(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+
+    /// Close the `SmallLineString`, like [`LineString::close`].
+    pub fn close(&mut self) {
+        if !self.is_closed() {
+            // by definition, we treat empty LineString's as closed.
+            debug_assert!(!self.0.is_empty());
+            self.0.push(self.0[0]);
+        }
+    }
+
+    /// Copy the coordinates into a [`LineString`].
+    pub fn to_line_string(&self) -> LineString<T> {
+        LineString::new(self.0.to_vec())
+    }
+}
+
+impl<T: CoordNum> FromIterator<Coord<T>> for SmallLineString<T> {
+    fn from_iter<I: IntoIterator<Item = Coord<T>>>(iter: I) -> Self {
+        SmallLineString(iter.into_iter().collect())
+    }
+}
+
+impl<T: CoordNum> From<&[Coord<T>]> for SmallLineString<T> {
+    fn from(coords: &[Coord<T>]) -> Self {
+        SmallLineString(SmallVec::from_slice(coords))
+    }
+}
+
+impl<T: CoordNum> From<LineString<T>> for SmallLineString<T> {
+    fn from(line_string: LineString<T>) -> Self {
+        let mut coords = SmallVec::from_vec(line_string.0);
+        // move the coordinates inline if they fit, freeing the `Vec`'s buffer
+        coords.shrink_to_fit();
+        SmallLineString(coords)
+    }
+}
+
+impl<T: CoordNum> From<SmallLineString<T>> for LineString<T> {
+    fn from(line_string: SmallLineString<T>) -> Self {
+        LineString::new(line_string.0.into_vec())
+    }
+}
+
+impl<'a, T: CoordNum> From<&'a SmallLineString<T>> for LineStringRef<'a, T> {
+    fn from(line_string: &'a SmallLineString<T>) -> Self {
+        line_string.as_view()
+    }
+}
+
+/// A [`Polygon`] whose rings are [`SmallLineString`]s. One without interiors, and with at most
+/// [`INLINE_COORDS`] coordinates in its exterior, doesn't allocate.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub struct SmallPolygon<T: CoordNum = f64> {
+    exterior: SmallLineString<T>,
+    interiors: Vec<SmallLineString<T>>,
+}
+
+impl<T: CoordNum> SmallPolygon<T> {
+    /// Create a new `SmallPolygon`, closing its rings like [`Polygon::new`].
+    pub fn new(mut exterior: SmallLineString<T>, mut interiors: Vec<SmallLineString<T>>) -> Self {
+        exterior.close();
+        for interior in &mut interiors {
+            interior.close();
+        }
+        SmallPolygon {
+            exterior,
+            interiors,
+        }
+    }
+
+    pub fn exterior(&self) -> &SmallLineString<T> {
+        &self.exterior
+    }
+
+    pub fn interiors(&self) -> &[SmallLineString<T>] {
+        &self.interiors
+    }
+
+    /// Borrow the rings as a [`PolygonRef`], to which the algorithms of the `geo` crate apply.
+    /// Only a polygon with interiors allocates, for the list of their views.
+    pub fn as_view(&self) -> PolygonRef<'_, T> {
+        PolygonRef::new(
+            self.exterior.as_view(),
+            self.interiors.iter().map(|ring| ring.as_view()).collect(),
+        )
+    }
+
+    /// Whether any of the rings has outgrown its inline storage and been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        self.exterior.spilled() || self.interiors.iter().any(|ring| ring.spilled())
+    }
+
+    /// Copy the coordinates into a [`Polygon`].
+    pub fn to_polygon(&self) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.to_line_string(),
+            self.interiors
+                .iter()
+                .map(|ring| ring.to_line_string())
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordNum> From<Polygon<T>> for SmallPolygon<T> {
+    fn from(polygon: Polygon<T>) -> Self {
+        let (exterior, interiors) = polygon.into_inner();
+        SmallPolygon {
+            exterior: exterior.into(),
+            interiors: interiors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<T: CoordNum> From<SmallPolygon<T>> for Polygon<T> {
+    fn from(polygon: SmallPolygon<T>) -> Self {
+        Polygon::new(
+            polygon.exterior.into(),
+            polygon.interiors.into_iter().map(Into::into).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon};
+
+    #[test]
+    fn inline_until_spilled() {
+        let mut line_string: SmallLineString = (0..INLINE_COORDS)
+            .map(|i| coord! { x: i as f64, y: 0. })
+            .collect();
+        assert!(!line_string.spilled());
+        assert!(!line_string.is_closed());
+
+        line_string.close();
+        assert!(line_string.spilled());
+        assert_eq!(line_string.0.len(), INLINE_COORDS + 1);
+        assert!(line_string.as_view().is_closed());
+    }
+
+    #[test]
+    fn round_trip() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        ];
+        let small = SmallPolygon::from(polygon.clone());
+        assert!(!small.spilled());
+        assert_eq!(small.as_view(), PolygonRef::from(&polygon));
+        assert_eq!(small.to_polygon(), polygon);
+        assert_eq!(Polygon::from(small), polygon);
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        let small = SmallLineString::from(&line_string.0[..]);
+        assert_eq!(LineString::from(small), line_string);
+    }
+
+    #[test]
+    fn new_closes_rings() {
+        let exterior: SmallLineString = [
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 1., y: 1. },
+        ]
+        .into_iter()
+        .collect();
+        let polygon = SmallPolygon::new(exterior, vec![]);
+        assert!(polygon.exterior().is_closed());
+        assert_eq!(polygon.exterior().0.len(), 4);
+    }
+}
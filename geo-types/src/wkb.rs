@@ -0,0 +1,870 @@
+//! Reading and writing geometries as [Well-Known Binary], and as PostGIS' Extended WKB.
+//!
+//! Every geometry type has `to_wkb` and `from_wkb` methods, and `to_ewkb` and `from_ewkb`
+//! methods which also write and read the SRID of the geometry, as PostGIS stores them.
+//! Geometries are written in little-endian byte order, and read in either byte order.
+//!
+//! Coordinates are stored as `f64`s, which are converted to and from the coordinate type with
+//! [`NumCast`], so reading coordinates that don't fit in an integer coordinate type fails.
+//!
+//! Both the Z and M flags of EWKB and the Z and M geometry types of ISO WKB are read. Their Z
+//! and M values are dropped, except by [`PointZM`] and [`LineStringZM`], which keep them, and
+//! also write them.
+//!
+//! `Line`s are written as two-coordinate `LineString`s, and `Rect`s and `Triangle`s as
+//! `Polygon`s, which they are read back from as long as the geometry has that shape.
+//!
+//! [Well-Known Binary]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{line_string, LineString, Point};
+//!
+//! let point = Point::new(1., 2.);
+//! let wkb = point.to_wkb();
+//! assert_eq!(wkb.len(), 21);
+//! assert_eq!(Point::from_wkb(&wkb).unwrap(), point);
+//!
+//! let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+//! let ewkb = line_string.to_ewkb(Some(4326));
+//! assert_eq!(
+//!     LineString::from_ewkb(&ewkb).unwrap(),
+//!     (line_string, Some(4326))
+//! );
+//! ```
+
+use crate::geometry::inner_type_name;
+use crate::shapes::{line_from_line_string, rect_from_polygon, triangle_from_polygon};
+use crate::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, LineStringZM, MultiLineString,
+    MultiPoint, MultiPolygon, Point, PointZM, Polygon, Rect, Triangle,
+};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::convert::TryFrom;
+use core::fmt;
+use num_traits::NumCast;
+
+/// The ways in which reading [WKB](crate::wkb) can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WkbError {
+    /// The bytes ended before the geometry was complete.
+    UnexpectedEnd,
+    /// The byte order marker is neither 0 (big-endian) nor 1 (little-endian).
+    InvalidByteOrder(u8),
+    /// The geometry type isn't one of the seven types of the Simple Features specification.
+    UnsupportedType(u32),
+    /// A coordinate value can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+    /// There are bytes left over after the geometry.
+    TrailingBytes(usize),
+    /// The bytes describe a different kind of geometry than the one being read.
+    MismatchedGeometry {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// Geometry collections are nested more than [`MAX_NESTING`] levels deep.
+    TooDeep,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WkbError {}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WkbError::UnexpectedEnd => write!(f, "Unexpected end of WKB"),
+            WkbError::InvalidByteOrder(order) => write!(f, "Invalid WKB byte order {}", order),
+            WkbError::UnsupportedType(kind) => write!(f, "Unsupported WKB geometry type {}", kind),
+            WkbError::InvalidCoordinate(value) => {
+                write!(f, "WKB coordinate {} is out of range", value)
+            }
+            WkbError::TrailingBytes(count) => write!(f, "{} trailing bytes after WKB", count),
+            WkbError::MismatchedGeometry { expected, found } => {
+                write!(f, "Expected a {}, but found a {}", expected, found)
+            }
+            WkbError::TooDeep => write!(
+                f,
+                "WKB collections nested more than {} levels deep",
+                MAX_NESTING
+            ),
+        }
+    }
+}
+
+impl From<crate::Error> for WkbError {
+    fn from(error: crate::Error) -> Self {
+        match error {
+            crate::Error::MismatchedGeometry { expected, found } => {
+                WkbError::MismatchedGeometry { expected, found }
+            }
+        }
+    }
+}
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+/// The most levels of multi-geometries and geometry collections read inside one another, so
+/// that a small input can't overflow the stack.
+pub const MAX_NESTING: usize = 64;
+
+// EWKB flags, in the high bits of the geometry type
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+// Whether the Z and M flags or types are written as in EWKB, or as in ISO WKB
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Flavor {
+    Iso,
+    Extended,
+}
+
+// Writing
+
+struct Writer {
+    bytes: Vec<u8>,
+    flavor: Flavor,
+}
+
+impl Writer {
+    fn header(&mut self, kind: u32, has_z: bool, has_m: bool, srid: Option<i32>) {
+        self.bytes.push(1);
+        let kind = match self.flavor {
+            Flavor::Iso => kind + has_z as u32 * 1000 + has_m as u32 * 2000,
+            Flavor::Extended => {
+                let mut kind = kind;
+                if has_z {
+                    kind |= EWKB_Z;
+                }
+                if has_m {
+                    kind |= EWKB_M;
+                }
+                if srid.is_some() {
+                    kind |= EWKB_SRID;
+                }
+                kind
+            }
+        };
+        self.u32(kind);
+        if let (Flavor::Extended, Some(srid)) = (self.flavor, srid) {
+            self.bytes.extend_from_slice(&srid.to_le_bytes());
+        }
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn count(&mut self, count: usize) {
+        self.u32(count as u32);
+    }
+
+    fn value<T: CoordNum>(&mut self, value: T) {
+        let value = value.to_f64().unwrap_or(f64::NAN);
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn coord<T: CoordNum>(&mut self, coord: Coord<T>) {
+        self.value(coord.x);
+        self.value(coord.y);
+    }
+
+    fn coords<T: CoordNum>(&mut self, coords: &[Coord<T>]) {
+        self.count(coords.len());
+        for coord in coords {
+            self.coord(*coord);
+        }
+    }
+
+    fn polygon_rings<T: CoordNum>(&mut self, polygon: &Polygon<T>) {
+        if polygon.exterior().0.is_empty() {
+            self.count(0);
+            return;
+        }
+        self.count(1 + polygon.interiors().len());
+        self.coords(&polygon.exterior().0);
+        for interior in polygon.interiors() {
+            self.coords(&interior.0);
+        }
+    }
+}
+
+// Writing a geometry, with its header. The SRID is only written for the outermost geometry.
+trait WriteWkb {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>);
+}
+
+impl<T: CoordNum> WriteWkb for Point<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        writer.header(POINT, false, false, srid);
+        writer.coord(self.0);
+    }
+}
+
+impl<T: CoordNum> WriteWkb for Line<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        writer.header(LINE_STRING, false, false, srid);
+        writer.coords(&[self.start, self.end]);
+    }
+}
+
+impl<T: CoordNum> WriteWkb for LineString<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        writer.header(LINE_STRING, false, false, srid);
+        writer.coords(&self.0);
+    }
+}
+
+impl<T: CoordNum> WriteWkb for Polygon<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        writer.header(POLYGON, false, false, srid);
+        writer.polygon_rings(self);
+    }
+}
+
+impl<T: CoordNum> WriteWkb for Rect<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        self.to_polygon().write_wkb(writer, srid);
+    }
+}
+
+impl<T: CoordNum> WriteWkb for Triangle<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        self.to_polygon().write_wkb(writer, srid);
+    }
+}
+
+macro_rules! write_wkb_for_collection_impl {
+    ($type: ident, $kind: expr) => {
+        impl<T: CoordNum> WriteWkb for $type<T> {
+            fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+                writer.header($kind, false, false, srid);
+                writer.count(self.0.len());
+                for member in &self.0 {
+                    member.write_wkb(writer, None);
+                }
+            }
+        }
+    };
+}
+
+write_wkb_for_collection_impl!(MultiPoint, MULTI_POINT);
+write_wkb_for_collection_impl!(MultiLineString, MULTI_LINE_STRING);
+write_wkb_for_collection_impl!(MultiPolygon, MULTI_POLYGON);
+write_wkb_for_collection_impl!(GeometryCollection, GEOMETRY_COLLECTION);
+
+impl<T: CoordNum> WriteWkb for Geometry<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        match self {
+            Geometry::Point(g) => g.write_wkb(writer, srid),
+            Geometry::Line(g) => g.write_wkb(writer, srid),
+            Geometry::LineString(g) => g.write_wkb(writer, srid),
+            Geometry::Polygon(g) => g.write_wkb(writer, srid),
+            Geometry::MultiPoint(g) => g.write_wkb(writer, srid),
+            Geometry::MultiLineString(g) => g.write_wkb(writer, srid),
+            Geometry::MultiPolygon(g) => g.write_wkb(writer, srid),
+            Geometry::GeometryCollection(g) => g.write_wkb(writer, srid),
+            Geometry::Rect(g) => g.write_wkb(writer, srid),
+            Geometry::Triangle(g) => g.write_wkb(writer, srid),
+        }
+    }
+}
+
+impl<T: CoordNum> WriteWkb for PointZM<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        writer.header(POINT, self.z.is_some(), self.m.is_some(), srid);
+        writer.coord(self.xy.0);
+        if let Some(z) = self.z {
+            writer.value(z);
+        }
+        if let Some(m) = self.m {
+            writer.value(m);
+        }
+    }
+}
+
+impl<T: CoordNum> WriteWkb for LineStringZM<T> {
+    fn write_wkb(&self, writer: &mut Writer, srid: Option<i32>) {
+        writer.header(LINE_STRING, self.z().is_some(), self.m().is_some(), srid);
+        writer.count(self.xy().0.len());
+        for (coord, z, m) in self.coords_zm() {
+            writer.coord(coord);
+            if let Some(z) = z {
+                writer.value(z);
+            }
+            if let Some(m) = m {
+                writer.value(m);
+            }
+        }
+    }
+}
+
+fn write<G: WriteWkb>(geometry: &G, flavor: Flavor, srid: Option<i32>) -> Vec<u8> {
+    let mut writer = Writer {
+        bytes: vec![],
+        flavor,
+    };
+    geometry.write_wkb(&mut writer, srid);
+    writer.bytes
+}
+
+// Reading
+
+struct Header {
+    little_endian: bool,
+    kind: u32,
+    has_z: bool,
+    has_m: bool,
+    srid: Option<i32>,
+}
+
+/// A coordinate, with its Z and M values if the geometry has them.
+type CoordZM<T> = (Coord<T>, Option<T>, Option<T>);
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    // the number of collections being read
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        let bytes = self
+            .bytes
+            .get(self.position..self.position + N)
+            .ok_or(WkbError::UnexpectedEnd)?;
+        self.position += N;
+        let mut array = [0; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    fn u32(&mut self, little_endian: bool) -> Result<u32, WkbError> {
+        let bytes = self.take()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    // A count of items of at least `item_size` bytes, checked against the bytes left, so that
+    // corrupt input can't make us allocate huge vectors.
+    fn count(&mut self, header: &Header, item_size: usize) -> Result<usize, WkbError> {
+        let count = self.u32(header.little_endian)? as usize;
+        if count.saturating_mul(item_size) > self.bytes.len() - self.position {
+            return Err(WkbError::UnexpectedEnd);
+        }
+        Ok(count)
+    }
+
+    fn value<T: CoordNum>(&mut self, header: &Header) -> Result<T, WkbError> {
+        let bytes = self.take()?;
+        let value = if header.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        };
+        NumCast::from(value).ok_or(WkbError::InvalidCoordinate(value))
+    }
+
+    fn coord_zm<T: CoordNum>(&mut self, header: &Header) -> Result<CoordZM<T>, WkbError> {
+        let coord = Coord {
+            x: self.value(header)?,
+            y: self.value(header)?,
+        };
+        let z = if header.has_z {
+            Some(self.value(header)?)
+        } else {
+            None
+        };
+        let m = if header.has_m {
+            Some(self.value(header)?)
+        } else {
+            None
+        };
+        Ok((coord, z, m))
+    }
+
+    fn coord_size(header: &Header) -> usize {
+        8 * (2 + header.has_z as usize + header.has_m as usize)
+    }
+
+    fn coords<T: CoordNum>(&mut self, header: &Header) -> Result<Vec<Coord<T>>, WkbError> {
+        let len = self.count(header, Self::coord_size(header))?;
+        (0..len)
+            .map(|_| self.coord_zm(header).map(|(coord, _, _)| coord))
+            .collect()
+    }
+
+    fn header(&mut self) -> Result<Header, WkbError> {
+        let little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            order => return Err(WkbError::InvalidByteOrder(order)),
+        };
+        let raw_kind = self.u32(little_endian)?;
+        let iso_kind = raw_kind & !(EWKB_Z | EWKB_M | EWKB_SRID);
+        let kind = iso_kind % 1000;
+        if !(POINT..=GEOMETRY_COLLECTION).contains(&kind) || iso_kind >= 4000 {
+            return Err(WkbError::UnsupportedType(raw_kind));
+        }
+        let srid = if raw_kind & EWKB_SRID != 0 {
+            Some(self.u32(little_endian)? as i32)
+        } else {
+            None
+        };
+        Ok(Header {
+            little_endian,
+            kind,
+            has_z: raw_kind & EWKB_Z != 0 || matches!(iso_kind / 1000, 1 | 3),
+            has_m: raw_kind & EWKB_M != 0 || matches!(iso_kind / 1000, 2 | 3),
+            srid,
+        })
+    }
+
+    fn members<T: CoordNum>(&mut self, header: &Header) -> Result<Vec<Geometry<T>>, WkbError> {
+        if self.depth == MAX_NESTING {
+            return Err(WkbError::TooDeep);
+        }
+        // the smallest member is an empty geometry: its byte order, type and count
+        let len = self.count(header, 9)?;
+        self.depth += 1;
+        let members = (0..len).map(|_| Ok(self.geometry()?.0)).collect();
+        self.depth -= 1;
+        members
+    }
+
+    fn geometry<T: CoordNum>(&mut self) -> Result<(Geometry<T>, Option<i32>), WkbError> {
+        let header = self.header()?;
+        let geometry = match header.kind {
+            POINT => Geometry::Point(Point(self.coord_zm(&header)?.0)),
+            LINE_STRING => Geometry::LineString(LineString(self.coords(&header)?)),
+            POLYGON => {
+                let len = self.count(&header, 4)?;
+                let mut rings = (0..len)
+                    .map(|_| self.coords(&header).map(LineString))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter();
+                let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+                Geometry::Polygon(Polygon::new(exterior, rings.collect()))
+            }
+            MULTI_POINT => Geometry::MultiPoint(MultiPoint(
+                self.members(&header)?
+                    .into_iter()
+                    .map(Point::try_from)
+                    .collect::<Result<_, _>>()?,
+            )),
+            MULTI_LINE_STRING => Geometry::MultiLineString(MultiLineString(
+                self.members(&header)?
+                    .into_iter()
+                    .map(LineString::try_from)
+                    .collect::<Result<_, _>>()?,
+            )),
+            MULTI_POLYGON => Geometry::MultiPolygon(MultiPolygon(
+                self.members(&header)?
+                    .into_iter()
+                    .map(Polygon::try_from)
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => Geometry::GeometryCollection(GeometryCollection(self.members(&header)?)),
+        };
+        Ok((geometry, header.srid))
+    }
+
+    fn finish(&self) -> Result<(), WkbError> {
+        match self.bytes.len() - self.position {
+            0 => Ok(()),
+            trailing => Err(WkbError::TrailingBytes(trailing)),
+        }
+    }
+}
+
+fn read<T: CoordNum>(bytes: &[u8]) -> Result<(Geometry<T>, Option<i32>), WkbError> {
+    let mut reader = Reader {
+        bytes,
+        position: 0,
+        depth: 0,
+    };
+    let geometry = reader.geometry()?;
+    reader.finish()?;
+    Ok(geometry)
+}
+
+fn mismatched<Expected, Found>() -> WkbError {
+    WkbError::MismatchedGeometry {
+        expected: type_name::<Expected>(),
+        found: type_name::<Found>(),
+    }
+}
+
+// Read a `Geometry`, then convert it to the type being read.
+trait ReadWkb<T: CoordNum>: Sized {
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, WkbError>;
+}
+
+impl<T: CoordNum> ReadWkb<T> for Geometry<T> {
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, WkbError> {
+        Ok(geometry)
+    }
+}
+
+macro_rules! read_wkb_via_try_from_impl {
+    ($($type: ident),+) => {
+        $(
+        impl<T: CoordNum> ReadWkb<T> for $type<T> {
+            fn from_geometry(geometry: Geometry<T>) -> Result<Self, WkbError> {
+                Ok($type::try_from(geometry)?)
+            }
+        }
+        )+
+    };
+}
+
+read_wkb_via_try_from_impl!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon
+);
+
+impl<T: CoordNum> ReadWkb<T> for GeometryCollection<T> {
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, WkbError> {
+        match geometry {
+            Geometry::GeometryCollection(collection) => Ok(collection),
+            other => Err(WkbError::MismatchedGeometry {
+                expected: type_name::<Self>(),
+                found: inner_type_name(other),
+            }),
+        }
+    }
+}
+
+impl<T: CoordNum> ReadWkb<T> for Line<T> {
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, WkbError> {
+        line_from_line_string(&LineString::from_geometry(geometry)?)
+            .ok_or_else(mismatched::<Self, LineString<T>>)
+    }
+}
+
+impl<T: CoordNum> ReadWkb<T> for Triangle<T> {
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, WkbError> {
+        triangle_from_polygon(&Polygon::from_geometry(geometry)?)
+            .ok_or_else(mismatched::<Self, Polygon<T>>)
+    }
+}
+
+impl<T: CoordNum> ReadWkb<T> for Rect<T> {
+    fn from_geometry(geometry: Geometry<T>) -> Result<Self, WkbError> {
+        rect_from_polygon(&Polygon::from_geometry(geometry)?)
+            .ok_or_else(mismatched::<Self, Polygon<T>>)
+    }
+}
+
+macro_rules! wkb_methods_impl {
+    ($($type: ident),+) => {
+        $(
+        impl<T: CoordNum> $type<T> {
+            /// Write this geometry as [WKB](crate::wkb).
+            pub fn to_wkb(&self) -> Vec<u8> {
+                write(self, Flavor::Iso, None)
+            }
+
+            /// Write this geometry as PostGIS [EWKB](crate::wkb), with its SRID, if any.
+            pub fn to_ewkb(&self, srid: Option<i32>) -> Vec<u8> {
+                write(self, Flavor::Extended, srid)
+            }
+
+            /// Read a geometry from [WKB](crate::wkb), or from EWKB, ignoring its SRID.
+            pub fn from_wkb(bytes: &[u8]) -> Result<Self, WkbError> {
+                Self::from_ewkb(bytes).map(|(geometry, _)| geometry)
+            }
+
+            /// Read a geometry, and its SRID, if any, from PostGIS [EWKB](crate::wkb).
+            pub fn from_ewkb(bytes: &[u8]) -> Result<(Self, Option<i32>), WkbError> {
+                let (geometry, srid) = read(bytes)?;
+                Ok((Self::from_geometry(geometry)?, srid))
+            }
+        }
+        )+
+    };
+}
+
+wkb_methods_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+impl<T: CoordNum> PointZM<T> {
+    /// Write this point as [WKB](crate::wkb), as an ISO `Point Z`, `Point M` or `Point ZM` if it
+    /// has Z or M values.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        write(self, Flavor::Iso, None)
+    }
+
+    /// Write this point as PostGIS [EWKB](crate::wkb), with its SRID, if any.
+    pub fn to_ewkb(&self, srid: Option<i32>) -> Vec<u8> {
+        write(self, Flavor::Extended, srid)
+    }
+
+    /// Read a point, and its Z and M values, if any, from [WKB](crate::wkb) or EWKB,
+    /// ignoring its SRID.
+    pub fn from_wkb(bytes: &[u8]) -> Result<Self, WkbError> {
+        Self::from_ewkb(bytes).map(|(point, _)| point)
+    }
+
+    /// Read a point, its Z and M values, if any, and its SRID, if any, from PostGIS
+    /// [EWKB](crate::wkb).
+    pub fn from_ewkb(bytes: &[u8]) -> Result<(Self, Option<i32>), WkbError> {
+        let mut reader = Reader {
+            bytes,
+            position: 0,
+            depth: 0,
+        };
+        let header = reader.header()?;
+        if header.kind != POINT {
+            return Err(mismatched::<Self, Geometry<T>>());
+        }
+        let (coord, z, m) = reader.coord_zm(&header)?;
+        reader.finish()?;
+        Ok((PointZM::new(Point(coord), z, m), header.srid))
+    }
+}
+
+impl<T: CoordNum> LineStringZM<T> {
+    /// Write this line string as [WKB](crate::wkb), as an ISO `LineString Z`, `LineString M`
+    /// or `LineString ZM` if it has Z or M values.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        write(self, Flavor::Iso, None)
+    }
+
+    /// Write this line string as PostGIS [EWKB](crate::wkb), with its SRID, if any.
+    pub fn to_ewkb(&self, srid: Option<i32>) -> Vec<u8> {
+        write(self, Flavor::Extended, srid)
+    }
+
+    /// Read a line string, and its Z and M values, if any, from [WKB](crate::wkb) or EWKB,
+    /// ignoring its SRID.
+    pub fn from_wkb(bytes: &[u8]) -> Result<Self, WkbError> {
+        Self::from_ewkb(bytes).map(|(line_string, _)| line_string)
+    }
+
+    /// Read a line string, its Z and M values, if any, and its SRID, if any, from PostGIS
+    /// [EWKB](crate::wkb).
+    pub fn from_ewkb(bytes: &[u8]) -> Result<(Self, Option<i32>), WkbError> {
+        let mut reader = Reader {
+            bytes,
+            position: 0,
+            depth: 0,
+        };
+        let header = reader.header()?;
+        if header.kind != LINE_STRING {
+            return Err(mismatched::<Self, Geometry<T>>());
+        }
+        let len = reader.count(&header, Reader::coord_size(&header))?;
+        let mut xy = Vec::with_capacity(len);
+        let mut z = Vec::with_capacity(if header.has_z { len } else { 0 });
+        let mut m = Vec::with_capacity(if header.has_m { len } else { 0 });
+        for _ in 0..len {
+            let (coord, coord_z, coord_m) = reader.coord_zm(&header)?;
+            xy.push(coord);
+            z.extend(coord_z);
+            m.extend(coord_m);
+        }
+        reader.finish()?;
+        let line_string = LineStringZM::new(
+            LineString(xy),
+            Some(z).filter(|_| header.has_z),
+            Some(m).filter(|_| header.has_m),
+        );
+        Ok((line_string, header.srid))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+
+    fn hex(bytes: &[u8]) -> alloc::string::String {
+        bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+
+    #[test]
+    fn write_point() {
+        // as written by PostGIS' ST_AsBinary and ST_AsEWKB
+        assert_eq!(
+            hex(&point!(x: 1., y: 2.).to_wkb()),
+            "0101000000000000000000F03F0000000000000040"
+        );
+        assert_eq!(
+            hex(&point!(x: 1., y: 2.).to_ewkb(Some(4326))),
+            "0101000020E6100000000000000000F03F0000000000000040"
+        );
+        let point_z = PointZM::new(point!(x: 1., y: 2.), Some(3.), None);
+        assert_eq!(
+            hex(&point_z.to_wkb()),
+            "01E9030000000000000000F03F00000000000000400000000000000840"
+        );
+        assert_eq!(
+            hex(&point_z.to_ewkb(None)),
+            "0101000080000000000000F03F00000000000000400000000000000840"
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+        ];
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 0.1, y: -7.).into(),
+            line_string![(x: -1., y: 2.5), (x: 3., y: 4.)].into(),
+            polygon.clone().into(),
+            Polygon::new(line_string![], vec![]).into(),
+            MultiPoint::new(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]).into(),
+            MultiLineString::new(vec![line_string![], line_string![(x: 1., y: 2.)]]).into(),
+            MultiPolygon::new(vec![polygon]).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 1., y: 2.).into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+            ])),
+        ];
+        for geometry in geometries {
+            assert_eq!(Geometry::from_wkb(&geometry.to_wkb()), Ok(geometry.clone()));
+            assert_eq!(
+                Geometry::from_ewkb(&geometry.to_ewkb(Some(3857))),
+                Ok((geometry, Some(3857)))
+            );
+        }
+
+        let rect = Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 });
+        assert_eq!(Rect::from_wkb(&rect.to_wkb()), Ok(rect));
+        let line = Line::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 });
+        assert_eq!(Line::from_wkb(&line.to_wkb()), Ok(line));
+        let triangle = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 3, y: 0 },
+            coord! { x: 0, y: 3 },
+        );
+        assert_eq!(Triangle::from_wkb(&triangle.to_wkb()), Ok(triangle));
+    }
+
+    #[test]
+    fn z_and_m_values() {
+        let track = LineStringZM::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            Some(vec![100., 110.]),
+            Some(vec![0., 5.]),
+        );
+        assert_eq!(LineStringZM::from_wkb(&track.to_wkb()), Ok(track.clone()));
+        assert_eq!(
+            LineStringZM::from_ewkb(&track.to_ewkb(Some(4326))),
+            Ok((track.clone(), Some(4326)))
+        );
+        // read as a plain line string, dropping them
+        assert_eq!(
+            LineString::from_wkb(&track.to_ewkb(None)),
+            Ok(track.xy().clone())
+        );
+
+        let fix = PointZM::new(point!(x: 6.86, y: 45.83), None, Some(12.));
+        assert_eq!(PointZM::from_ewkb(&fix.to_ewkb(None)), Ok((fix, None)));
+        assert_eq!(PointZM::from_wkb(&fix.to_wkb()), Ok(fix));
+        assert_eq!(
+            PointZM::from_wkb(&point!(x: 1., y: 2.).to_wkb()),
+            Ok(PointZM::new(point!(x: 1., y: 2.), None, None))
+        );
+    }
+
+    #[test]
+    fn big_endian() {
+        let bytes = [
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x3F, 0xF0, 0, 0, 0, 0, 0, 0, 0x40, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(Point::from_wkb(&bytes), Ok(point!(x: 1., y: 2.)));
+    }
+
+    #[test]
+    fn read_errors() {
+        let wkb = point!(x: 1.5, y: 2.).to_wkb();
+        assert_eq!(
+            Point::<f64>::from_wkb(&wkb[..20]),
+            Err(WkbError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Point::<i32>::from_wkb(&point!(x: 1e10, y: 2.).to_wkb()),
+            Err(WkbError::InvalidCoordinate(1e10))
+        );
+        let mut trailing = wkb.clone();
+        trailing.push(0);
+        assert_eq!(
+            Point::<f64>::from_wkb(&trailing),
+            Err(WkbError::TrailingBytes(1))
+        );
+        let mut curve = wkb.clone();
+        curve[1] = 8;
+        assert_eq!(
+            Geometry::<f64>::from_wkb(&curve),
+            Err(WkbError::UnsupportedType(8))
+        );
+        let mut order = wkb;
+        order[0] = 2;
+        assert_eq!(
+            Geometry::<f64>::from_wkb(&order),
+            Err(WkbError::InvalidByteOrder(2))
+        );
+        assert!(matches!(
+            LineString::<f64>::from_wkb(&point!(x: 1., y: 2.).to_wkb()),
+            Err(WkbError::MismatchedGeometry { .. })
+        ));
+        // a line string claiming far more coordinates than there are bytes
+        let huge = [1, 2, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF];
+        assert_eq!(
+            LineString::<f64>::from_wkb(&huge),
+            Err(WkbError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn nesting() {
+        // geometry collections, each holding the next
+        let nested = |depth: usize| {
+            let mut bytes = [1, 7, 0, 0, 0, 1, 0, 0, 0].repeat(depth);
+            bytes.extend_from_slice(&[1, 7, 0, 0, 0, 0, 0, 0, 0]);
+            bytes
+        };
+        assert!(Geometry::<f64>::from_wkb(&nested(MAX_NESTING - 1)).is_ok());
+        assert_eq!(
+            Geometry::<f64>::from_wkb(&nested(MAX_NESTING)),
+            Err(WkbError::TooDeep)
+        );
+        assert_eq!(
+            Geometry::<f64>::from_wkb(&nested(100_000)),
+            Err(WkbError::TooDeep)
+        );
+    }
+}
@@ -0,0 +1,765 @@
+//! Reading and writing geometries as [Well-Known Text].
+//!
+//! Every geometry type implements [`Display`](fmt::Display), writing its WKT representation, and
+//! [`FromStr`], parsing one. The precision of the formatter, if any, is applied to every
+//! coordinate value, so `format!("{:.2}", geometry)` writes coordinates with two decimals.
+//!
+//! `Line`s are written as two-coordinate `LINESTRING`s, and `Rect`s and `Triangle`s as
+//! `POLYGON`s, which they are parsed back from as long as the text describes that shape.
+//! Only two-dimensional geometries are supported: text with `Z` or `M` values is rejected.
+//!
+//! [Well-Known Text]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::{line_string, Geometry, LineString, Point};
+//!
+//! let point: Point = "POINT(1.5 -2)".parse().unwrap();
+//! assert_eq!(point, Point::new(1.5, -2.));
+//! assert_eq!(point.to_wkt_string(), "POINT(1.5 -2)");
+//!
+//! let line_string = line_string![(x: 1. / 3., y: 0.), (x: 2. / 3., y: 1.)];
+//! assert_eq!(
+//!     format!("{:.3}", line_string),
+//!     "LINESTRING(0.333 0.000,0.667 1.000)"
+//! );
+//!
+//! let geometry = Geometry::<f64>::from_wkt("multipoint ((0 0), (1 1))").unwrap();
+//! assert_eq!(geometry.to_wkt_string(), "MULTIPOINT((0 0),(1 1))");
+//! ```
+
+use crate::geometry::inner_type_name;
+use crate::shapes::{line_from_line_string, rect_from_polygon, triangle_from_polygon};
+use crate::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+/// The ways in which parsing [WKT](crate::wkt) can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WktError {
+    /// The text ended before the geometry was complete.
+    UnexpectedEnd,
+    /// The text holds something that isn't allowed at `position` (a byte offset), such as an
+    /// unknown geometry tag, a missing parenthesis, or an empty `POINT`, which can't be
+    /// represented.
+    UnexpectedToken { position: usize, found: String },
+    /// A coordinate value at `position` couldn't be parsed as the coordinate type.
+    InvalidNumber { position: usize, text: String },
+    /// The geometry at `position` has `Z` or `M` values.
+    UnsupportedDimension { position: usize },
+    /// The text describes a different kind of geometry than the one being parsed.
+    MismatchedGeometry {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// The `GEOMETRYCOLLECTION` at `position` is nested more than [`MAX_NESTING`] levels deep.
+    TooDeep { position: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WktError {}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WktError::UnexpectedEnd => write!(f, "Unexpected end of WKT"),
+            WktError::UnexpectedToken { position, found } => {
+                write!(f, "Unexpected `{}` at position {} of WKT", found, position)
+            }
+            WktError::InvalidNumber { position, text } => {
+                write!(
+                    f,
+                    "Invalid number `{}` at position {} of WKT",
+                    text, position
+                )
+            }
+            WktError::UnsupportedDimension { position } => write!(
+                f,
+                "Unsupported Z or M dimension at position {} of WKT",
+                position
+            ),
+            WktError::MismatchedGeometry { expected, found } => {
+                write!(f, "Expected a {}, but found a {}", expected, found)
+            }
+            WktError::TooDeep { position } => write!(
+                f,
+                "Collection nested more than {} levels deep at position {} of WKT",
+                MAX_NESTING, position
+            ),
+        }
+    }
+}
+
+impl From<crate::Error> for WktError {
+    fn from(error: crate::Error) -> Self {
+        match error {
+            crate::Error::MismatchedGeometry { expected, found } => {
+                WktError::MismatchedGeometry { expected, found }
+            }
+        }
+    }
+}
+
+// Writing
+
+fn write_coord<T>(f: &mut fmt::Formatter, coord: Coord<T>) -> fmt::Result
+where
+    T: CoordNum + fmt::Display,
+{
+    match f.precision() {
+        Some(precision) => write!(f, "{:.*} {:.*}", precision, coord.x, precision, coord.y),
+        None => write!(f, "{} {}", coord.x, coord.y),
+    }
+}
+
+// Write `(item,item,...)`, or `EMPTY` if there are no items.
+fn write_list<I, F>(f: &mut fmt::Formatter, items: I, mut write_item: F) -> fmt::Result
+where
+    I: IntoIterator,
+    F: FnMut(&mut fmt::Formatter, I::Item) -> fmt::Result,
+{
+    let mut items = items.into_iter().peekable();
+    if items.peek().is_none() {
+        return f.write_str("EMPTY");
+    }
+    f.write_str("(")?;
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            f.write_str(",")?;
+        }
+        write_item(f, item)?;
+    }
+    f.write_str(")")
+}
+
+fn write_line_string<T>(f: &mut fmt::Formatter, line_string: &LineString<T>) -> fmt::Result
+where
+    T: CoordNum + fmt::Display,
+{
+    write_list(f, line_string.0.iter(), |f, coord| write_coord(f, *coord))
+}
+
+fn write_polygon<T>(f: &mut fmt::Formatter, polygon: &Polygon<T>) -> fmt::Result
+where
+    T: CoordNum + fmt::Display,
+{
+    if polygon.exterior().0.is_empty() {
+        return f.write_str("EMPTY");
+    }
+    write_list(
+        f,
+        core::iter::once(polygon.exterior()).chain(polygon.interiors()),
+        write_line_string,
+    )
+}
+
+// Write the tag of a geometry, followed by a space if its text is `EMPTY`.
+fn write_tag(f: &mut fmt::Formatter, tag: &str, is_empty: bool) -> fmt::Result {
+    f.write_str(tag)?;
+    if is_empty {
+        f.write_str(" ")?;
+    }
+    Ok(())
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("POINT(")?;
+        write_coord(f, self.0)?;
+        f.write_str(")")
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for Line<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LINESTRING")?;
+        write_list(f, [self.start, self.end], write_coord)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for LineString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tag(f, "LINESTRING", self.0.is_empty())?;
+        write_line_string(f, self)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for Polygon<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tag(f, "POLYGON", self.exterior().0.is_empty())?;
+        write_polygon(f, self)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for MultiPoint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tag(f, "MULTIPOINT", self.0.is_empty())?;
+        write_list(f, self.iter(), |f, point| {
+            f.write_str("(")?;
+            write_coord(f, point.0)?;
+            f.write_str(")")
+        })
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for MultiLineString<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tag(f, "MULTILINESTRING", self.0.is_empty())?;
+        write_list(f, self.iter(), write_line_string)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for MultiPolygon<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tag(f, "MULTIPOLYGON", self.0.is_empty())?;
+        write_list(f, self.iter(), write_polygon)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for GeometryCollection<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_tag(f, "GEOMETRYCOLLECTION", self.0.is_empty())?;
+        write_list(f, self.iter(), |f, geometry| fmt::Display::fmt(geometry, f))
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for Rect<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_polygon(), f)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for Triangle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_polygon(), f)
+    }
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for Geometry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Geometry::Point(g) => fmt::Display::fmt(g, f),
+            Geometry::Line(g) => fmt::Display::fmt(g, f),
+            Geometry::LineString(g) => fmt::Display::fmt(g, f),
+            Geometry::Polygon(g) => fmt::Display::fmt(g, f),
+            Geometry::MultiPoint(g) => fmt::Display::fmt(g, f),
+            Geometry::MultiLineString(g) => fmt::Display::fmt(g, f),
+            Geometry::MultiPolygon(g) => fmt::Display::fmt(g, f),
+            Geometry::GeometryCollection(g) => fmt::Display::fmt(g, f),
+            Geometry::Rect(g) => fmt::Display::fmt(g, f),
+            Geometry::Triangle(g) => fmt::Display::fmt(g, f),
+        }
+    }
+}
+
+// Parsing
+
+/// The most levels of `GEOMETRYCOLLECTION`s parsed inside one another, so that a small input
+/// can't overflow the stack.
+pub const MAX_NESTING: usize = 64;
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+    // the number of geometry collections being parsed
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    // The next token, along with its position: one of `(`, `)` or `,`, or a run of any other
+    // characters up to the next whitespace or punctuation. Empty at the end of the text.
+    fn next_token(&mut self) -> (usize, &'a str) {
+        let text = self.text;
+        let rest = text[self.position..].trim_start();
+        let start = text.len() - rest.len();
+        let len = match rest.chars().next() {
+            Some('(') | Some(')') | Some(',') => 1,
+            _ => rest
+                .find(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | ','))
+                .unwrap_or(rest.len()),
+        };
+        self.position = start + len;
+        (start, &rest[..len])
+    }
+
+    fn peek_token(&mut self) -> &'a str {
+        let position = self.position;
+        let (_, token) = self.next_token();
+        self.position = position;
+        token
+    }
+
+    fn unexpected(position: usize, token: &str) -> WktError {
+        if token.is_empty() {
+            WktError::UnexpectedEnd
+        } else {
+            WktError::UnexpectedToken {
+                position,
+                found: token.to_string(),
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), WktError> {
+        match self.next_token() {
+            (_, token) if token == expected => Ok(()),
+            (position, token) => Err(Self::unexpected(position, token)),
+        }
+    }
+
+    // Consume an `EMPTY` keyword, if that's what comes next.
+    fn empty(&mut self) -> bool {
+        let is_empty = self.peek_token().eq_ignore_ascii_case("EMPTY");
+        if is_empty {
+            self.next_token();
+        }
+        is_empty
+    }
+
+    fn number<T: FromStr>(&mut self) -> Result<T, WktError> {
+        let (position, token) = self.next_token();
+        token.parse().map_err(|_| match token {
+            "" | "(" | ")" | "," => Self::unexpected(position, token),
+            _ => WktError::InvalidNumber {
+                position,
+                text: token.to_string(),
+            },
+        })
+    }
+
+    fn coord<T: CoordNum + FromStr>(&mut self) -> Result<Coord<T>, WktError> {
+        Ok(Coord {
+            x: self.number()?,
+            y: self.number()?,
+        })
+    }
+
+    // `(item,item,...)`, or `EMPTY`
+    fn list<R, F>(&mut self, mut item: F) -> Result<Vec<R>, WktError>
+    where
+        F: FnMut(&mut Self) -> Result<R, WktError>,
+    {
+        if self.empty() {
+            return Ok(vec![]);
+        }
+        self.expect("(")?;
+        let mut items = vec![item(self)?];
+        loop {
+            match self.next_token() {
+                (_, ",") => items.push(item(self)?),
+                (_, ")") => return Ok(items),
+                (position, token) => return Err(Self::unexpected(position, token)),
+            }
+        }
+    }
+
+    fn line_string<T: CoordNum + FromStr>(&mut self) -> Result<LineString<T>, WktError> {
+        self.list(Self::coord).map(LineString)
+    }
+
+    fn polygon<T: CoordNum + FromStr>(&mut self) -> Result<Polygon<T>, WktError> {
+        let mut rings = self.list(Self::line_string)?.into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    // Both `MULTIPOINT((0 0),(1 1))` and `MULTIPOINT(0 0,1 1)` are common.
+    fn multi_point_member<T: CoordNum + FromStr>(&mut self) -> Result<Point<T>, WktError> {
+        if self.peek_token() == "(" {
+            self.next_token();
+            let coord = self.coord()?;
+            self.expect(")")?;
+            Ok(Point(coord))
+        } else {
+            self.coord().map(Point)
+        }
+    }
+
+    fn geometry<T: CoordNum + FromStr>(&mut self) -> Result<Geometry<T>, WktError> {
+        let (position, token) = self.next_token();
+        let dimension = self.peek_token();
+        if ["Z", "M", "ZM"]
+            .iter()
+            .any(|d| dimension.eq_ignore_ascii_case(d))
+        {
+            return Err(WktError::UnsupportedDimension { position });
+        }
+
+        Ok(match token.to_ascii_uppercase().as_str() {
+            "POINT" => {
+                self.expect("(")?;
+                let coord = self.coord()?;
+                self.expect(")")?;
+                Geometry::Point(Point(coord))
+            }
+            "LINESTRING" => Geometry::LineString(self.line_string()?),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" => Geometry::MultiPoint(MultiPoint(self.list(Self::multi_point_member)?)),
+            "MULTILINESTRING" => {
+                Geometry::MultiLineString(MultiLineString(self.list(Self::line_string)?))
+            }
+            "MULTIPOLYGON" => Geometry::MultiPolygon(MultiPolygon(self.list(Self::polygon)?)),
+            "GEOMETRYCOLLECTION" => {
+                if self.depth == MAX_NESTING {
+                    return Err(WktError::TooDeep { position });
+                }
+                self.depth += 1;
+                let members = self.list(Self::geometry)?;
+                self.depth -= 1;
+                Geometry::GeometryCollection(GeometryCollection(members))
+            }
+            _ => return Err(Self::unexpected(position, token)),
+        })
+    }
+}
+
+fn parse_geometry<T: CoordNum + FromStr>(text: &str) -> Result<Geometry<T>, WktError> {
+    let mut parser = Parser {
+        text,
+        position: 0,
+        depth: 0,
+    };
+    let geometry = parser.geometry()?;
+    match parser.next_token() {
+        (_, "") => Ok(geometry),
+        (position, token) => Err(Parser::unexpected(position, token)),
+    }
+}
+
+fn mismatched<Expected, Found>() -> WktError {
+    WktError::MismatchedGeometry {
+        expected: type_name::<Expected>(),
+        found: type_name::<Found>(),
+    }
+}
+
+impl<T: CoordNum + FromStr> FromStr for Geometry<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_geometry(s)
+    }
+}
+
+macro_rules! from_str_via_geometry_impl {
+    ($($type: ident),+) => {
+        $(
+        impl<T: CoordNum + FromStr> FromStr for $type<T> {
+            type Err = WktError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($type::try_from(parse_geometry(s)?)?)
+            }
+        }
+        )+
+    };
+}
+
+from_str_via_geometry_impl!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon
+);
+
+impl<T: CoordNum + FromStr> FromStr for GeometryCollection<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_geometry(s)? {
+            Geometry::GeometryCollection(collection) => Ok(collection),
+            other => Err(WktError::MismatchedGeometry {
+                expected: type_name::<Self>(),
+                found: inner_type_name(other),
+            }),
+        }
+    }
+}
+
+impl<T: CoordNum + FromStr> FromStr for Line<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        line_from_line_string(&s.parse::<LineString<T>>()?)
+            .ok_or_else(mismatched::<Self, LineString<T>>)
+    }
+}
+
+impl<T: CoordNum + FromStr> FromStr for Triangle<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        triangle_from_polygon(&s.parse::<Polygon<T>>()?).ok_or_else(mismatched::<Self, Polygon<T>>)
+    }
+}
+
+impl<T: CoordNum + FromStr> FromStr for Rect<T> {
+    type Err = WktError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        rect_from_polygon(&s.parse::<Polygon<T>>()?).ok_or_else(mismatched::<Self, Polygon<T>>)
+    }
+}
+
+macro_rules! wkt_methods_impl {
+    ($($type: ident),+) => {
+        $(
+        impl<T: CoordNum> $type<T> {
+            /// Write this geometry as [WKT](crate::wkt).
+            ///
+            /// This is the same as `to_string()`; use `format!("{:.N}", geometry)` to round the
+            /// coordinates to `N` decimals. It is named so as not to shadow either method of the
+            /// `ToWkt` trait of the `wkt` crate, `to_wkt` and `wkt_string`.
+            pub fn to_wkt_string(&self) -> String
+            where
+                T: fmt::Display,
+            {
+                self.to_string()
+            }
+
+            /// Parse a geometry from [WKT](crate::wkt).
+            ///
+            /// This is the same as `wkt.parse()`.
+            pub fn from_wkt(wkt: &str) -> Result<Self, WktError>
+            where
+                T: FromStr,
+            {
+                wkt.parse()
+            }
+        }
+        )+
+    };
+}
+
+wkt_methods_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+
+    #[test]
+    fn write_geometries() {
+        assert_eq!(point!(x: 1, y: -2).to_wkt_string(), "POINT(1 -2)");
+        assert_eq!(
+            Line::new(coord! { x: 0., y: 0. }, coord! { x: 1.5, y: 2. }).to_wkt_string(),
+            "LINESTRING(0 0,1.5 2)"
+        );
+        assert_eq!(
+            LineString::<f64>::new(vec![]).to_wkt_string(),
+            "LINESTRING EMPTY"
+        );
+        let polygon = Polygon::new(
+            line_string![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 0)],
+            vec![line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 1)]],
+        );
+        assert_eq!(
+            polygon.to_wkt_string(),
+            "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))"
+        );
+        assert_eq!(
+            MultiLineString::new(vec![
+                line_string![],
+                line_string![(x: 1, y: 2), (x: 3, y: 4)]
+            ])
+            .to_wkt_string(),
+            "MULTILINESTRING(EMPTY,(1 2,3 4))"
+        );
+        assert_eq!(
+            MultiPolygon::new(vec![polygon.clone()]).to_wkt_string(),
+            "MULTIPOLYGON(((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1)))"
+        );
+        assert_eq!(
+            Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 }).to_wkt_string(),
+            "POLYGON((0 0,0 2,1 2,1 0,0 0))"
+        );
+        assert_eq!(
+            GeometryCollection::new_from(vec![point!(x: 1, y: 2).into(), polygon.into()])
+                .to_wkt_string(),
+            "GEOMETRYCOLLECTION(POINT(1 2),POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1)))"
+        );
+        assert_eq!(
+            GeometryCollection::<f64>::new_from(vec![]).to_wkt_string(),
+            "GEOMETRYCOLLECTION EMPTY"
+        );
+    }
+
+    #[test]
+    fn write_with_precision() {
+        let collection = GeometryCollection::new_from(vec![
+            point!(x: 2. / 3., y: 1.).into(),
+            MultiPoint::new(vec![point!(x: 0.126, y: -0.5)]).into(),
+        ]);
+        assert_eq!(
+            format!("{:.2}", collection),
+            "GEOMETRYCOLLECTION(POINT(0.67 1.00),MULTIPOINT((0.13 -0.50)))"
+        );
+        assert_eq!(format!("{:.1}", point!(x: 3, y: 4)), "POINT(3 4)");
+    }
+
+    #[test]
+    fn parse_geometries() {
+        assert_eq!(
+            Point::from_wkt(" point ( 1 -2e1 ) "),
+            Ok(point!(x: 1., y: -20.))
+        );
+        assert_eq!(
+            "MULTIPOINT(0 0, 1 1)".parse::<MultiPoint<i32>>(),
+            Ok(MultiPoint::new(vec![
+                point!(x: 0, y: 0),
+                point!(x: 1, y: 1)
+            ]))
+        );
+        assert_eq!(
+            "MULTIPOINT((0 0),(1 1))".parse::<MultiPoint<i32>>(),
+            Ok(MultiPoint::new(vec![
+                point!(x: 0, y: 0),
+                point!(x: 1, y: 1)
+            ]))
+        );
+        assert_eq!(
+            "POLYGON((0 0,4 0,4 4),(1 1,2 1,2 2))".parse::<Polygon<i32>>(),
+            Ok(Polygon::new(
+                line_string![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4)],
+                vec![line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2)]],
+            ))
+        );
+        assert_eq!(
+            "POLYGON EMPTY".parse::<Polygon<f64>>(),
+            Ok(Polygon::new(line_string![], vec![]))
+        );
+        assert_eq!(
+            "LINESTRING(1 2,3 4)".parse::<Line<i32>>(),
+            Ok(Line::new(coord! { x: 1, y: 2 }, coord! { x: 3, y: 4 }))
+        );
+        assert_eq!(
+            "POLYGON((0 0,3 0,0 3,0 0))".parse::<Triangle<i32>>(),
+            Ok(Triangle::new(
+                coord! { x: 0, y: 0 },
+                coord! { x: 3, y: 0 },
+                coord! { x: 0, y: 3 }
+            ))
+        );
+        assert_eq!(
+            "POLYGON((0 0,0 2,1 2,1 0,0 0))".parse::<Rect<i32>>(),
+            Ok(Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 }))
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 0.1, y: 1e-7).into(),
+            line_string![(x: -1., y: 2.5), (x: 3., y: 4.), (x: 5., y: 6.)].into(),
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)].into(),
+            MultiPolygon::<f64>::new(vec![]).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 1., y: 2.).into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+            ])),
+        ];
+        for geometry in geometries {
+            assert_eq!(Geometry::from_wkt(&geometry.to_wkt_string()), Ok(geometry));
+        }
+        let rect = Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 });
+        assert_eq!(Rect::from_wkt(&rect.to_wkt_string()), Ok(rect));
+        let triangle = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 3, y: 0 },
+            coord! { x: 0, y: 3 },
+        );
+        assert_eq!(Triangle::from_wkt(&triangle.to_wkt_string()), Ok(triangle));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Point::<f64>::from_wkt("POINT(1 2"),
+            Err(WktError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Point::<f64>::from_wkt("POINT EMPTY"),
+            Err(WktError::UnexpectedToken {
+                position: 6,
+                found: "EMPTY".to_string()
+            })
+        );
+        assert_eq!(
+            Point::<i32>::from_wkt("POINT(1.5 2)"),
+            Err(WktError::InvalidNumber {
+                position: 6,
+                text: "1.5".to_string()
+            })
+        );
+        assert_eq!(
+            Point::<f64>::from_wkt("POINT Z (1 2 3)"),
+            Err(WktError::UnsupportedDimension { position: 0 })
+        );
+        assert_eq!(
+            Geometry::<f64>::from_wkt("CIRCLE(0 0)"),
+            Err(WktError::UnexpectedToken {
+                position: 0,
+                found: "CIRCLE".to_string()
+            })
+        );
+        assert_eq!(
+            Point::<f64>::from_wkt("POINT(1 2) POINT(3 4)"),
+            Err(WktError::UnexpectedToken {
+                position: 11,
+                found: "POINT".to_string()
+            })
+        );
+        assert!(matches!(
+            Point::<f64>::from_wkt("LINESTRING(1 2,3 4)"),
+            Err(WktError::MismatchedGeometry { .. })
+        ));
+        assert!(matches!(
+            Line::<f64>::from_wkt("LINESTRING(1 2,3 4,5 6)"),
+            Err(WktError::MismatchedGeometry { .. })
+        ));
+        assert!(matches!(
+            Rect::<f64>::from_wkt("POLYGON((0 0,3 0,0 3,0 0))"),
+            Err(WktError::MismatchedGeometry { .. })
+        ));
+    }
+
+    #[test]
+    fn nesting() {
+        let nested =
+            |depth: usize| "GEOMETRYCOLLECTION(".repeat(depth) + "POINT(1 2)" + &")".repeat(depth);
+        assert!(Geometry::<f64>::from_wkt(&nested(MAX_NESTING)).is_ok());
+        let position = MAX_NESTING * "GEOMETRYCOLLECTION(".len();
+        assert_eq!(
+            Geometry::<f64>::from_wkt(&nested(MAX_NESTING + 1)),
+            Err(WktError::TooDeep { position })
+        );
+        assert_eq!(
+            Geometry::<f64>::from_wkt(&"GEOMETRYCOLLECTION(".repeat(100_000)),
+            Err(WktError::TooDeep { position })
+        );
+    }
+}
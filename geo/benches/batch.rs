@@ -0,0 +1,76 @@
+//! Compare the batched primitives with one-at-a-time loops. Run with `--features use-simd` to
+//! measure the vectorized implementation.
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::algorithm::batch;
+use geo::kernels::{Kernel, RobustKernel};
+use geo::prelude::*;
+use geo::{coord, Coord, Line, Point, Rect};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway = geo_test_fixtures::norway_main::<f64>();
+    let points: Vec<Coord<f64>> = norway.0.clone();
+    let segments: Vec<Line<f64>> = norway.lines().collect();
+    let rects: Vec<Rect<f64>> = segments.iter().map(|line| line.bounding_rect()).collect();
+    let p = coord! { x: 5., y: 58. };
+    let q = coord! { x: 30., y: 71. };
+    let rect = Rect::new(coord! { x: 10., y: 60. }, coord! { x: 15., y: 65. });
+
+    c.bench_function("orient2d batch", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(batch::orient2d(p, q, criterion::black_box(&points)));
+        });
+    });
+
+    c.bench_function("orient2d loop", |bencher| {
+        bencher.iter(|| {
+            let orientations: Vec<_> = criterion::black_box(&points)
+                .iter()
+                .map(|r| <RobustKernel as Kernel<f64>>::orient2d(p, q, *r))
+                .collect();
+            criterion::black_box(orientations);
+        });
+    });
+
+    c.bench_function("point segment distances batch", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(batch::point_segment_distances(
+                p,
+                criterion::black_box(&segments),
+            ));
+        });
+    });
+
+    c.bench_function("point segment distances loop", |bencher| {
+        let point = Point::from(p);
+        bencher.iter(|| {
+            let distances: Vec<f64> = criterion::black_box(&segments)
+                .iter()
+                .map(|line| line.euclidean_distance(&point))
+                .collect();
+            criterion::black_box(distances);
+        });
+    });
+
+    c.bench_function("rects intersect batch", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(batch::rects_intersect(rect, criterion::black_box(&rects)));
+        });
+    });
+
+    c.bench_function("rects intersect loop", |bencher| {
+        bencher.iter(|| {
+            let intersections: Vec<bool> = criterion::black_box(&rects)
+                .iter()
+                .map(|other| rect.intersects(other))
+                .collect();
+            criterion::black_box(intersections);
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+extern crate geo_test_fixtures;
+
+use criterion::Criterion;
+use geo::{Area, BooleanOps, IntersectionArea, MultiPolygon, Polygon, Translate};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+    let shifted = norway.translate(0.5, 0.5);
+
+    c.bench_function("intersection area", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(criterion::black_box(&norway).intersection_area(&shifted));
+        });
+    });
+
+    c.bench_function("intersection area with overlay", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&norway)
+                    .intersection(&shifted)
+                    .unsigned_area(),
+            );
+        });
+    });
+
+    let plot_polygons: MultiPolygon = geo_test_fixtures::nl_plots();
+    let zone_polygons: MultiPolygon = geo_test_fixtures::nl_zones();
+
+    c.bench_function("intersection area of plots and zones", |bencher| {
+        bencher.iter(|| {
+            for a in &plot_polygons {
+                for b in &zone_polygons {
+                    criterion::black_box(a.intersection_area(b));
+                }
+            }
+        });
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = criterion_benchmark
+}
+
+criterion_main!(benches);
@@ -0,0 +1,418 @@
+use crate::{
+    coord, BooleanOps, BoundingRect, Coord, CoordFloat, GeoFloat, LineString, MapCoords,
+    MapCoordsInPlace, MultiLineString, MultiPolygon, Polygon, Rect,
+};
+
+/// Wrap the longitudes of a geometry, in degrees, into the range from -180 inclusive to 180
+/// exclusive, e.g. to normalize coordinates from sources using 0 to 360.
+///
+/// Each coordinate is wrapped on its own, so a geometry crossing the antimeridian comes out
+/// spanning the world: use [`SplitAntimeridian`] to cut it there instead.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, WrapLongitude};
+///
+/// let line_string = line_string![(x: 170., y: 0.), (x: 190., y: 10.), (x: -540., y: 20.)];
+///
+/// assert_eq!(
+///     line_string.wrap_longitude(),
+///     line_string![(x: 170., y: 0.), (x: -170., y: 10.), (x: -180., y: 20.)]
+/// );
+/// ```
+pub trait WrapLongitude<T: CoordFloat> {
+    /// Wrap the longitudes into the range from -180 to 180.
+    #[must_use]
+    fn wrap_longitude(&self) -> Self;
+
+    /// Mutable version of [`wrap_longitude`](Self::wrap_longitude).
+    fn wrap_longitude_mut(&mut self);
+}
+
+impl<T, G> WrapLongitude<T> for G
+where
+    T: CoordFloat,
+    G: MapCoords<T, T, Output = G> + MapCoordsInPlace<T>,
+{
+    fn wrap_longitude(&self) -> Self {
+        self.map_coords(wrap_coord)
+    }
+
+    fn wrap_longitude_mut(&mut self) {
+        self.map_coords_in_place(wrap_coord)
+    }
+}
+
+/// Cut a geometry of longitudes and latitudes, in degrees, where it crosses the antimeridian,
+/// into pieces which don't, as [RFC 7946] recommends, so that it can be treated as planar
+/// without spanning the world.
+///
+/// Consecutive coordinates more than 180° of longitude apart are taken to cross the
+/// antimeridian, going the shorter way around, and they're cut at the latitude interpolated
+/// linearly between them. The pieces have their longitudes wrapped into the range from -180 to
+/// 180, as by [`WrapLongitude`], except where they're cut: the pieces reaching the antimeridian
+/// from the west end at 180, and those reaching it from the east at -180.
+///
+/// Polygons are cut with [`BooleanOps`], so their pieces are valid polygons. A ring going all
+/// the way around the world, such as one around Antarctica, is closed along the pole on its
+/// left, as for an exterior ring wound counter-clockwise: the North Pole going east, and the
+/// South Pole going west. Line string pieces of a single coordinate, such as where a line
+/// string only touches the antimeridian, are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Area, SplitAntimeridian};
+///
+/// let line_string = line_string![(x: 170., y: 0.), (x: -170., y: 10.), (x: -160., y: 10.)];
+/// let pieces = line_string.split_antimeridian();
+/// assert_eq!(pieces.0[0], line_string![(x: 170., y: 0.), (x: 180., y: 5.)]);
+/// assert_eq!(
+///     pieces.0[1],
+///     line_string![(x: -180., y: 5.), (x: -170., y: 10.), (x: -160., y: 10.)]
+/// );
+///
+/// let polygon = polygon![
+///     (x: 170., y: 0.),
+///     (x: -170., y: 0.),
+///     (x: -170., y: 10.),
+///     (x: 170., y: 10.),
+/// ];
+/// let pieces = polygon.split_antimeridian();
+/// assert_eq!(pieces.0.len(), 2);
+/// assert_eq!(pieces.unsigned_area(), 200.);
+/// ```
+///
+/// [RFC 7946]: https://www.rfc-editor.org/rfc/rfc7946#section-3.1.9
+pub trait SplitAntimeridian<T: CoordFloat> {
+    type Output;
+
+    fn split_antimeridian(&self) -> Self::Output;
+}
+
+impl<T: CoordFloat> SplitAntimeridian<T> for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let mut pieces = vec![];
+        split_line_string(self, &mut pieces);
+        MultiLineString::new(pieces)
+    }
+}
+
+impl<T: CoordFloat> SplitAntimeridian<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let mut pieces = vec![];
+        for line_string in self {
+            split_line_string(line_string, &mut pieces);
+        }
+        MultiLineString::new(pieces)
+    }
+}
+
+impl<T: GeoFloat> SplitAntimeridian<T> for Polygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let mut pieces = vec![];
+        split_polygon(self, &mut pieces);
+        MultiPolygon::new(pieces)
+    }
+}
+
+impl<T: GeoFloat> SplitAntimeridian<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let mut pieces = vec![];
+        for polygon in self {
+            split_polygon(polygon, &mut pieces);
+        }
+        MultiPolygon::new(pieces)
+    }
+}
+
+fn half_turn<T: CoordFloat>() -> T {
+    T::from(180).unwrap()
+}
+
+fn full_turn<T: CoordFloat>() -> T {
+    T::from(360).unwrap()
+}
+
+// The index of the 360° wide strip of longitudes holding `x`, counting from the one from -180
+// to 180.
+fn strip<T: CoordFloat>(x: T) -> T {
+    ((x + half_turn()) / full_turn()).floor()
+}
+
+fn wrap<T: CoordFloat>(x: T) -> T {
+    x - strip(x) * full_turn()
+}
+
+fn wrap_coord<T: CoordFloat>(coord: Coord<T>) -> Coord<T> {
+    coord! { x: wrap(coord.x), y: coord.y }
+}
+
+// Shift the longitudes of coordinates in the `strip` back into the one from -180 to 180.
+fn shift<T: CoordFloat>(coord: Coord<T>, strip: T) -> Coord<T> {
+    coord! { x: coord.x - strip * full_turn(), y: coord.y }
+}
+
+// The coordinates with their longitudes shifted by whole turns so that consecutive coordinates
+// are at most half a turn apart, continuing across the antimeridian rather than jumping.
+fn unwrap_longitudes<T: CoordFloat>(coords: &[Coord<T>]) -> Vec<Coord<T>> {
+    let mut unwrapped: Vec<Coord<T>> = Vec::with_capacity(coords.len());
+    for (i, &coord) in coords.iter().enumerate() {
+        let x = match unwrapped.last() {
+            Some(previous) => previous.x + wrap(coord.x - coords[i - 1].x),
+            None => coord.x,
+        };
+        unwrapped.push(Coord { x, y: coord.y });
+    }
+    unwrapped
+}
+
+fn split_line_string<T: CoordFloat>(line_string: &LineString<T>, pieces: &mut Vec<LineString<T>>) {
+    let coords = unwrap_longitudes(&line_string.0);
+    let first = match coords.first() {
+        Some(&first) => first,
+        None => return,
+    };
+    let mut current = strip(first.x);
+    let mut piece = vec![shift(first, current)];
+    let push = |piece: &mut Vec<Coord<T>>, coord: Coord<T>| {
+        if piece.last() != Some(&coord) {
+            piece.push(coord);
+        }
+    };
+    for pair in coords.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let next = strip(b.x);
+        if next != current {
+            // the longitudes of the cut, at the end of the piece and at the start of the next
+            let (end, start) = if next > current {
+                (half_turn::<T>(), -half_turn::<T>())
+            } else {
+                (-half_turn::<T>(), half_turn::<T>())
+            };
+            let boundary = current * full_turn() + end;
+            let y = a.y + (b.y - a.y) * (boundary - a.x) / (b.x - a.x);
+            push(&mut piece, Coord { x: end, y });
+            if piece.len() > 1 {
+                pieces.push(LineString::new(piece));
+            }
+            piece = vec![Coord { x: start, y }];
+            current = next;
+        }
+        push(&mut piece, shift(b, current));
+    }
+    if piece.len() > 1 {
+        pieces.push(LineString::new(piece));
+    }
+}
+
+// Unwrap a ring, closing it along a pole if it goes all the way around the world.
+fn unwrap_ring<T: CoordFloat>(ring: &LineString<T>) -> LineString<T> {
+    let mut coords = unwrap_longitudes(&ring.0);
+    if let (Some(&first), Some(&last)) = (coords.first(), coords.last()) {
+        if ring.is_closed() && first != last {
+            let pole = if last.x > first.x {
+                T::from(90).unwrap()
+            } else {
+                T::from(-90).unwrap()
+            };
+            coords.push(coord! { x: last.x, y: pole });
+            coords.push(coord! { x: first.x, y: pole });
+            coords.push(first);
+        }
+    }
+    LineString::new(coords)
+}
+
+fn split_polygon<T: GeoFloat>(polygon: &Polygon<T>, pieces: &mut Vec<Polygon<T>>) {
+    let exterior = unwrap_ring(polygon.exterior());
+    let bounds = match exterior.bounding_rect() {
+        Some(bounds) => bounds,
+        None => return,
+    };
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .map(|ring| {
+            let mut ring = unwrap_ring(ring);
+            // bring the hole into the turn of the exterior
+            if let Some(rect) = ring.bounding_rect() {
+                let turns = ((rect.center().x - bounds.center().x) / full_turn()).round();
+                ring.map_coords_in_place(|coord| shift(coord, turns));
+            }
+            ring
+        })
+        .collect();
+    let unwrapped = Polygon::new(exterior, interiors);
+
+    let (first, last) = (strip(bounds.min().x), strip(bounds.max().x));
+    if first == last {
+        pieces.push(unwrapped.map_coords(|coord| shift(coord, first)));
+        return;
+    }
+    let mut current = first;
+    while current <= last {
+        let center = current * full_turn();
+        let strip = Rect::new(
+            coord! { x: center - half_turn(), y: bounds.min().y - T::one() },
+            coord! { x: center + half_turn(), y: bounds.max().y + T::one() },
+        );
+        let clipped = unwrapped.intersection(&strip.to_polygon());
+        pieces.extend(
+            clipped
+                .into_iter()
+                .map(|piece| piece.map_coords(|coord| shift(coord, current))),
+        );
+        current = current + T::one();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Area};
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(
+            point!(x: 190., y: 10.).wrap_longitude(),
+            point!(x: -170., y: 10.)
+        );
+        assert_eq!(
+            point!(x: 180., y: 10.).wrap_longitude(),
+            point!(x: -180., y: 10.)
+        );
+        assert_eq!(
+            point!(x: -180., y: 10.).wrap_longitude(),
+            point!(x: -180., y: 10.)
+        );
+        assert_eq!(
+            point!(x: -190., y: 10.).wrap_longitude(),
+            point!(x: 170., y: 10.)
+        );
+        assert_eq!(
+            point!(x: 720., y: 10.).wrap_longitude(),
+            point!(x: 0., y: 10.)
+        );
+
+        let mut polygon = polygon![(x: 350., y: 0.), (x: 370., y: 0.), (x: 370., y: 10.)];
+        polygon.wrap_longitude_mut();
+        assert_eq!(
+            polygon,
+            polygon![(x: -10., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]
+        );
+    }
+
+    #[test]
+    fn line_strings() {
+        // going west
+        let line_string = line_string![(x: -170., y: 0.), (x: 170., y: 10.)];
+        assert_eq!(
+            line_string.split_antimeridian(),
+            MultiLineString::new(vec![
+                line_string![(x: -170., y: 0.), (x: -180., y: 5.)],
+                line_string![(x: 180., y: 5.), (x: 170., y: 10.)],
+            ])
+        );
+
+        // back and forth, out of range
+        let line_string = line_string![(x: 350., y: 0.), (x: 370., y: 10.), (x: 350., y: 20.)];
+        assert_eq!(
+            line_string.split_antimeridian(),
+            MultiLineString::new(vec![
+                line_string![(x: -10., y: 0.), (x: 10., y: 10.), (x: -10., y: 20.)],
+            ])
+        );
+
+        // touching the antimeridian
+        let line_string = line_string![(x: 170., y: 0.), (x: 180., y: 0.), (x: 170., y: 10.)];
+        assert_eq!(
+            line_string.split_antimeridian(),
+            MultiLineString::new(vec![
+                line_string![(x: 170., y: 0.), (x: 180., y: 0.)],
+                line_string![(x: 180., y: 0.), (x: 170., y: 10.)],
+            ])
+        );
+
+        let multi = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 175., y: 0.), (x: -175., y: 0.)],
+        ]);
+        assert_eq!(multi.split_antimeridian().0.len(), 3);
+    }
+
+    fn strip_bounds(pieces: &MultiPolygon<f64>) -> Vec<Rect<f64>> {
+        let mut bounds: Vec<Rect<f64>> = pieces
+            .iter()
+            .map(|piece| piece.bounding_rect().unwrap())
+            .collect();
+        bounds.sort_by(|a, b| a.min().x.partial_cmp(&b.min().x).unwrap());
+        bounds
+    }
+
+    #[test]
+    fn polygons() {
+        let polygon = polygon!(
+            exterior: [
+                (x: 160., y: -20.),
+                (x: -160., y: -20.),
+                (x: -160., y: 20.),
+                (x: 160., y: 20.),
+            ],
+            interiors: [[
+                (x: 175., y: -5.),
+                (x: -175., y: -5.),
+                (x: -175., y: 5.),
+                (x: 175., y: 5.),
+            ]],
+        );
+        let pieces = polygon.split_antimeridian();
+        assert_eq!(pieces.0.len(), 2);
+        assert_eq!(pieces.unsigned_area(), 40. * 40. - 10. * 10.);
+        assert_eq!(
+            strip_bounds(&pieces),
+            vec![
+                Rect::new((-180., -20.), (-160., 20.)),
+                Rect::new((160., -20.), (180., 20.)),
+            ]
+        );
+
+        // not crossing
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        assert_eq!(
+            polygon.split_antimeridian(),
+            MultiPolygon::new(vec![polygon.clone()])
+        );
+        let multi = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        assert_eq!(multi.split_antimeridian().0.len(), 2);
+    }
+
+    #[test]
+    fn around_a_pole() {
+        // going east, around the North Pole
+        let polygon = polygon![(x: 0., y: 80.), (x: 120., y: 80.), (x: -120., y: 80.)];
+        let pieces = polygon.split_antimeridian();
+        assert_eq!(pieces.unsigned_area(), 360. * 10.);
+        assert_eq!(
+            strip_bounds(&pieces),
+            vec![
+                Rect::new((-180., 80.), (0., 90.)),
+                Rect::new((0., 80.), (180., 90.)),
+            ]
+        );
+
+        // going west, around the South Pole
+        let polygon = polygon![(x: 0., y: -80.), (x: -120., y: -80.), (x: 120., y: -80.)];
+        let pieces = polygon.split_antimeridian();
+        assert_eq!(pieces.unsigned_area(), 360. * 10.);
+    }
+}
@@ -1,19 +1,19 @@
 use crate::geometry::*;
 use crate::{CoordFloat, CoordNum};
 
-pub(crate) fn twice_signed_ring_area<T>(linestring: &LineString<T>) -> T
+pub(crate) fn twice_signed_ring_area<T>(ring: &[Coord<T>]) -> T
 where
     T: CoordNum,
 {
     // LineString with less than 3 points is empty, or a
     // single point, or is not closed.
-    if linestring.0.len() < 3 {
+    if ring.len() < 3 {
         return T::zero();
     }
 
     // Above test ensures the vector has at least 2 elements.
     // We check if linestring is closed, and return 0 otherwise.
-    if linestring.0.first().unwrap() != linestring.0.last().unwrap() {
+    if ring.first().unwrap() != ring.last().unwrap() {
         return T::zero();
     }
 
@@ -27,12 +27,11 @@ where
     // of the coordinates, but it is not fool-proof to
     // divide by the length of the linestring (eg. a long
     // line-string with T = u8)
-    let shift = linestring.0[0];
+    let shift = ring[0];
 
     let mut tmp = T::zero();
-    for line in linestring.lines() {
-        use crate::MapCoords;
-        let line = line.map_coords(|c| c - shift);
+    for coords in ring.windows(2) {
+        let line = Line::new(coords[0] - shift, coords[1] - shift);
         tmp = tmp + line.determinant();
     }
 
@@ -79,7 +78,34 @@ pub(crate) fn get_linestring_area<T>(linestring: &LineString<T>) -> T
 where
     T: CoordFloat,
 {
-    twice_signed_ring_area(linestring) / (T::one() + T::one())
+    twice_signed_ring_area(&linestring.0) / (T::one() + T::one())
+}
+
+// The signed area of a polygon, with the sign of its exterior, whatever the orientation of its
+// interiors.
+fn polygon_signed_area<'a, T>(
+    exterior: &[Coord<T>],
+    interiors: impl Iterator<Item = &'a [Coord<T>]>,
+) -> T
+where
+    T: CoordFloat + 'a,
+{
+    let two = T::one() + T::one();
+    let area = twice_signed_ring_area(exterior) / two;
+
+    // We could use winding order here, but that would
+    // result in computing the shoelace formula twice.
+    let is_negative = area < T::zero();
+
+    let area = interiors.fold(area.abs(), |total, next| {
+        total - (twice_signed_ring_area(next) / two).abs()
+    });
+
+    if is_negative {
+        -area
+    } else {
+        area
+    }
 }
 
 impl<T> Area<T> for Point<T>
@@ -129,21 +155,10 @@ where
     T: CoordFloat,
 {
     fn signed_area(&self) -> T {
-        let area = get_linestring_area(self.exterior());
-
-        // We could use winding order here, but that would
-        // result in computing the shoelace formula twice.
-        let is_negative = area < T::zero();
-
-        let area = self.interiors().iter().fold(area.abs(), |total, next| {
-            total - get_linestring_area(next).abs()
-        });
-
-        if is_negative {
-            -area
-        } else {
-            area
-        }
+        polygon_signed_area(
+            &self.exterior().0,
+            self.interiors().iter().map(|ring| &ring.0[..]),
+        )
     }
 
     fn unsigned_area(&self) -> T {
@@ -259,6 +274,60 @@ where
     }
 }
 
+impl<'a, T> Area<T> for LineStringRef<'a, T>
+where
+    T: CoordNum,
+{
+    fn signed_area(&self) -> T {
+        T::zero()
+    }
+
+    fn unsigned_area(&self) -> T {
+        T::zero()
+    }
+}
+
+impl<'a, T> Area<T> for PolygonRef<'a, T>
+where
+    T: CoordFloat,
+{
+    fn signed_area(&self) -> T {
+        polygon_signed_area(
+            self.exterior().0,
+            self.interiors().iter().map(|ring| ring.0),
+        )
+    }
+
+    fn unsigned_area(&self) -> T {
+        self.signed_area().abs()
+    }
+}
+
+impl<'a, T> Area<T> for GeometryRef<'a, T>
+where
+    T: CoordFloat,
+{
+    fn signed_area(&self) -> T {
+        match self {
+            GeometryRef::Polygon(polygon) => polygon.signed_area(),
+            GeometryRef::MultiPolygon(polygons) => polygons
+                .iter()
+                .fold(T::zero(), |total, next| total + next.signed_area()),
+            _ => T::zero(),
+        }
+    }
+
+    fn unsigned_area(&self) -> T {
+        match self {
+            GeometryRef::Polygon(polygon) => polygon.unsigned_area(),
+            GeometryRef::MultiPolygon(polygons) => polygons
+                .iter()
+                .fold(T::zero(), |total, next| total + next.signed_area().abs()),
+            _ => T::zero(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::Area;
@@ -528,4 +597,23 @@ mod test {
         // Value from shapely
         assert_relative_eq!(poly.unsigned_area(), 0.006547948219252177, max_relative = 0.0001);
     }
+
+    #[test]
+    fn area_borrowed_views_test() {
+        use crate::{GeometryRef, LineStringRef, PolygonRef};
+
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let view = PolygonRef::from(&poly);
+        assert_eq!(view.signed_area(), poly.signed_area());
+        assert_eq!(view.unsigned_area(), 99.);
+
+        let geometry = GeometryRef::MultiPolygon(vec![view.clone(), view]);
+        assert_eq!(geometry.unsigned_area(), 198.);
+
+        let line_string = LineStringRef::from(poly.exterior());
+        assert_eq!(line_string.signed_area(), 0.);
+    }
 }
@@ -0,0 +1,365 @@
+//! Batched predicates and distances, for hot loops testing many coordinates against the same
+//! line, point or rectangle.
+//!
+//! The results of every function equal those of its one-at-a-time counterpart up to rounding,
+//! but with the `use-simd` feature, on x86-64 CPUs supporting AVX, four coordinates are
+//! processed at a time.
+//! The CPU is detected at runtime, and other targets fall back to plain loops.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::batch;
+//! use geo::{coord, Orientation};
+//!
+//! let points = [coord! { x: 0., y: 1. }, coord! { x: 2., y: 2. }, coord! { x: 1., y: -1. }];
+//! let orientations = batch::orient2d(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. }, &points);
+//! assert_eq!(
+//!     orientations,
+//!     vec![
+//!         Orientation::CounterClockwise,
+//!         Orientation::Collinear,
+//!         Orientation::Clockwise,
+//!     ]
+//! );
+//! ```
+
+use crate::kernels::{Kernel, Orientation, RobustKernel};
+use crate::{Coord, Line, Rect};
+
+// Half the machine epsilon, i.e. the unit roundoff of `f64`.
+const EPSILON: f64 = f64::EPSILON / 2.;
+
+// Shewchuk's bound on the error of the orientation determinant evaluated with floating point
+// arithmetic, relative to the sum of the magnitudes of its two products.
+const ORIENT2D_ERROR_BOUND: f64 = (3. + 16. * EPSILON) * EPSILON;
+
+/// The orientation of each of `points` with respect to the directed line from `p` to `q`.
+///
+/// The results are those of [`RobustKernel::orient2d`](Kernel::orient2d). Orientations are
+/// first evaluated with plain floating point arithmetic, and only the points too close to the
+/// line for the sign of the result to be certain are handed to the exact predicate.
+pub fn orient2d(p: Coord<f64>, q: Coord<f64>, points: &[Coord<f64>]) -> Vec<Orientation> {
+    let mut out = Vec::with_capacity(points.len());
+    #[cfg(all(feature = "use-simd", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx") {
+            // SAFETY: the CPU supports AVX
+            unsafe { avx::orient2d(p, q, points, &mut out) };
+            return out;
+        }
+    }
+    out.extend(points.iter().map(|r| filtered_orient2d(p, q, *r)));
+    out
+}
+
+/// The euclidean distance from `point` to each of `segments`.
+pub fn point_segment_distances(point: Coord<f64>, segments: &[Line<f64>]) -> Vec<f64> {
+    #[cfg(all(feature = "use-simd", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx") {
+            let mut out = vec![0.; segments.len()];
+            // SAFETY: the CPU supports AVX
+            unsafe { avx::point_segment_distances(point, segments, &mut out) };
+            return out;
+        }
+    }
+    segments
+        .iter()
+        .map(|segment| point_segment_distance(point, segment))
+        .collect()
+}
+
+/// Whether `rect` intersects each of `others`. Rectangles sharing only a boundary intersect.
+pub fn rects_intersect(rect: Rect<f64>, others: &[Rect<f64>]) -> Vec<bool> {
+    let mut out = Vec::with_capacity(others.len());
+    #[cfg(all(feature = "use-simd", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("avx") {
+            // SAFETY: the CPU supports AVX
+            unsafe { avx::rects_intersect(rect, others, &mut out) };
+            return out;
+        }
+    }
+    out.extend(others.iter().map(|other| rect_intersects(&rect, other)));
+    out
+}
+
+fn filtered_orient2d(p: Coord<f64>, q: Coord<f64>, r: Coord<f64>) -> Orientation {
+    let left = (p.x - r.x) * (q.y - r.y);
+    let right = (p.y - r.y) * (q.x - r.x);
+    let det = left - right;
+    let error = ORIENT2D_ERROR_BOUND * (left.abs() + right.abs());
+    if det > error {
+        Orientation::CounterClockwise
+    } else if det < -error {
+        Orientation::Clockwise
+    } else {
+        <RobustKernel as Kernel<f64>>::orient2d(p, q, r)
+    }
+}
+
+// The vectorized implementation performs the same operations in the same order, so that both
+// give identical results.
+#[allow(clippy::manual_clamp)]
+fn point_segment_distance(point: Coord<f64>, segment: &Line<f64>) -> f64 {
+    let (dx, dy) = (
+        segment.end.x - segment.start.x,
+        segment.end.y - segment.start.y,
+    );
+    let length_2 = dx * dx + dy * dy;
+    let t = if length_2 > 0. {
+        let dot = (point.x - segment.start.x) * dx + (point.y - segment.start.y) * dy;
+        // unlike `clamp`, this maps NaN to 0, as the vectorized `max` does
+        (dot / length_2).max(0.).min(1.)
+    } else {
+        0.
+    };
+    let ex = point.x - (segment.start.x + t * dx);
+    let ey = point.y - (segment.start.y + t * dy);
+    (ex * ex + ey * ey).sqrt()
+}
+
+fn rect_intersects(rect: &Rect<f64>, other: &Rect<f64>) -> bool {
+    rect.min().x <= other.max().x
+        && rect.max().x >= other.min().x
+        && rect.min().y <= other.max().y
+        && rect.max().y >= other.min().y
+}
+
+#[cfg(all(feature = "use-simd", target_arch = "x86_64"))]
+mod avx {
+    use super::*;
+    use std::arch::x86_64::*;
+
+    const LANES: usize = 4;
+
+    macro_rules! gather {
+        ($chunk:expr, |$item:ident: $item_type:ty| $value:expr) => {{
+            let lane = |$item: &$item_type| $value;
+            _mm256_setr_pd(
+                lane(&$chunk[0]),
+                lane(&$chunk[1]),
+                lane(&$chunk[2]),
+                lane(&$chunk[3]),
+            )
+        }};
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn orient2d(
+        p: Coord<f64>,
+        q: Coord<f64>,
+        points: &[Coord<f64>],
+        out: &mut Vec<Orientation>,
+    ) {
+        let (px, py) = (_mm256_set1_pd(p.x), _mm256_set1_pd(p.y));
+        let (qx, qy) = (_mm256_set1_pd(q.x), _mm256_set1_pd(q.y));
+        let sign_bit = _mm256_set1_pd(-0.);
+        let bound = _mm256_set1_pd(ORIENT2D_ERROR_BOUND);
+
+        let mut chunks = points.chunks_exact(LANES);
+        for chunk in &mut chunks {
+            let rx = gather!(chunk, |r: Coord<f64>| r.x);
+            let ry = gather!(chunk, |r: Coord<f64>| r.y);
+            let left = _mm256_mul_pd(_mm256_sub_pd(px, rx), _mm256_sub_pd(qy, ry));
+            let right = _mm256_mul_pd(_mm256_sub_pd(py, ry), _mm256_sub_pd(qx, rx));
+            let det = _mm256_sub_pd(left, right);
+            let magnitude = _mm256_add_pd(
+                _mm256_andnot_pd(sign_bit, left),
+                _mm256_andnot_pd(sign_bit, right),
+            );
+            let error = _mm256_mul_pd(bound, magnitude);
+            let ccw = _mm256_movemask_pd(_mm256_cmp_pd(det, error, _CMP_GT_OQ));
+            let cw = _mm256_movemask_pd(_mm256_cmp_pd(
+                det,
+                _mm256_xor_pd(error, sign_bit),
+                _CMP_LT_OQ,
+            ));
+            for (lane, r) in chunk.iter().enumerate() {
+                out.push(if ccw & (1 << lane) != 0 {
+                    Orientation::CounterClockwise
+                } else if cw & (1 << lane) != 0 {
+                    Orientation::Clockwise
+                } else {
+                    <RobustKernel as Kernel<f64>>::orient2d(p, q, *r)
+                });
+            }
+        }
+        out.extend(
+            chunks
+                .remainder()
+                .iter()
+                .map(|r| filtered_orient2d(p, q, *r)),
+        );
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn point_segment_distances(
+        point: Coord<f64>,
+        segments: &[Line<f64>],
+        out: &mut [f64],
+    ) {
+        let (x, y) = (_mm256_set1_pd(point.x), _mm256_set1_pd(point.y));
+        let (zero, one) = (_mm256_setzero_pd(), _mm256_set1_pd(1.));
+
+        let mut chunks = segments.chunks_exact(LANES);
+        for (idx, chunk) in (&mut chunks).enumerate() {
+            let start_x = gather!(chunk, |s: Line<f64>| s.start.x);
+            let start_y = gather!(chunk, |s: Line<f64>| s.start.y);
+            let dx = _mm256_sub_pd(gather!(chunk, |s: Line<f64>| s.end.x), start_x);
+            let dy = _mm256_sub_pd(gather!(chunk, |s: Line<f64>| s.end.y), start_y);
+            let length_2 = _mm256_add_pd(_mm256_mul_pd(dx, dx), _mm256_mul_pd(dy, dy));
+            let dot = _mm256_add_pd(
+                _mm256_mul_pd(_mm256_sub_pd(x, start_x), dx),
+                _mm256_mul_pd(_mm256_sub_pd(y, start_y), dy),
+            );
+            let t = _mm256_min_pd(_mm256_max_pd(_mm256_div_pd(dot, length_2), zero), one);
+            // degenerate segments are measured from their start
+            let t = _mm256_and_pd(t, _mm256_cmp_pd(length_2, zero, _CMP_GT_OQ));
+            let ex = _mm256_sub_pd(x, _mm256_add_pd(start_x, _mm256_mul_pd(t, dx)));
+            let ey = _mm256_sub_pd(y, _mm256_add_pd(start_y, _mm256_mul_pd(t, dy)));
+            let distance =
+                _mm256_sqrt_pd(_mm256_add_pd(_mm256_mul_pd(ex, ex), _mm256_mul_pd(ey, ey)));
+            _mm256_storeu_pd(out[idx * LANES..].as_mut_ptr(), distance);
+        }
+        let done = segments.len() - chunks.remainder().len();
+        for (distance, segment) in out[done..].iter_mut().zip(chunks.remainder()) {
+            *distance = point_segment_distance(point, segment);
+        }
+    }
+
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn rects_intersect(
+        rect: Rect<f64>,
+        others: &[Rect<f64>],
+        out: &mut Vec<bool>,
+    ) {
+        let (min_x, min_y) = (_mm256_set1_pd(rect.min().x), _mm256_set1_pd(rect.min().y));
+        let (max_x, max_y) = (_mm256_set1_pd(rect.max().x), _mm256_set1_pd(rect.max().y));
+
+        let mut chunks = others.chunks_exact(LANES);
+        for chunk in &mut chunks {
+            let x_overlap = _mm256_and_pd(
+                _mm256_cmp_pd(min_x, gather!(chunk, |r: Rect<f64>| r.max().x), _CMP_LE_OQ),
+                _mm256_cmp_pd(max_x, gather!(chunk, |r: Rect<f64>| r.min().x), _CMP_GE_OQ),
+            );
+            let y_overlap = _mm256_and_pd(
+                _mm256_cmp_pd(min_y, gather!(chunk, |r: Rect<f64>| r.max().y), _CMP_LE_OQ),
+                _mm256_cmp_pd(max_y, gather!(chunk, |r: Rect<f64>| r.min().y), _CMP_GE_OQ),
+            );
+            let mask = _mm256_movemask_pd(_mm256_and_pd(x_overlap, y_overlap));
+            out.extend((0..LANES).map(|lane| mask & (1 << lane) != 0));
+        }
+        out.extend(
+            chunks
+                .remainder()
+                .iter()
+                .map(|other| rect_intersects(&rect, other)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, EuclideanDistance, Intersects};
+
+    #[test]
+    fn orientations_match_robust_kernel() {
+        // points within a few ulps of the line through p and q, where the naive determinant
+        // gets the sign wrong
+        let p = coord! { x: 12., y: 12. };
+        let q = coord! { x: 24., y: 24. };
+        let points: Vec<Coord<f64>> = (0..37)
+            .flat_map(|i| {
+                (0..5).map(move |j| {
+                    coord! {
+                        x: 0.5 + i as f64 * f64::EPSILON,
+                        y: 0.5 + j as f64 * f64::EPSILON,
+                    }
+                })
+            })
+            .collect();
+        let expected: Vec<Orientation> = points
+            .iter()
+            .map(|r| <RobustKernel as Kernel<f64>>::orient2d(p, q, *r))
+            .collect();
+        assert_eq!(orient2d(p, q, &points), expected);
+        assert!(expected.contains(&Orientation::Collinear));
+        assert!(expected.contains(&Orientation::Clockwise));
+        assert!(expected.contains(&Orientation::CounterClockwise));
+    }
+
+    #[test]
+    fn distances_match_euclidean_distance() {
+        let point = coord! { x: 1., y: 2. };
+        let segments: Vec<Line<f64>> = (0..11)
+            .map(|i| {
+                let i = i as f64;
+                Line::new(
+                    coord! { x: -i, y: i * 0.5 },
+                    coord! { x: i * 2., y: 3. - i },
+                )
+            })
+            .chain(std::iter::once(Line::new((4., 4.), (4., 4.))))
+            .collect();
+        let distances = point_segment_distances(point, &segments);
+        assert_eq!(distances.len(), segments.len());
+        for (distance, segment) in distances.iter().zip(&segments) {
+            assert_relative_eq!(
+                *distance,
+                segment.euclidean_distance(&point),
+                epsilon = 1e-12
+            );
+        }
+        assert_relative_eq!(distances[11], 13f64.sqrt());
+    }
+
+    #[test]
+    fn non_finite_distances_match_one_at_a_time() {
+        let point = coord! { x: 1., y: 2. };
+        let segments = [
+            Line::new((0., 0.), (f64::NAN, 1.)),
+            Line::new((0., 0.), (f64::INFINITY, 1.)),
+            Line::new((f64::NEG_INFINITY, 0.), (f64::INFINITY, 0.)),
+            Line::new((0., f64::NAN), (0., f64::NAN)),
+            Line::new((0., 0.), (2., 0.)),
+            Line::new((0., 1e300), (1e300, 0.)),
+            Line::new((f64::INFINITY, 0.), (f64::INFINITY, 0.)),
+            Line::new((0., 0.), (0., 4.)),
+        ];
+        let distances = point_segment_distances(point, &segments);
+        for (distance, segment) in distances.iter().zip(&segments) {
+            let expected = point_segment_distance(point, segment);
+            assert!(
+                distance == &expected || (distance.is_nan() && expected.is_nan()),
+                "{:?} is {} away, not {}",
+                segment,
+                distance,
+                expected
+            );
+        }
+        assert!(distances[0].is_nan());
+        assert_eq!(distances[4], 2.);
+    }
+
+    #[test]
+    fn rect_intersections_match_intersects() {
+        let rect = Rect::new((0., 0.), (4., 4.));
+        let others: Vec<Rect<f64>> = (-3..4)
+            .flat_map(|i| {
+                (-3..4).map(move |j| {
+                    let (x, y) = (i as f64 * 2., j as f64 * 2.);
+                    Rect::new((x, y), (x + 1., y + 1.))
+                })
+            })
+            .collect();
+        let intersections = rects_intersect(rect, &others);
+        for (intersects, other) in intersections.iter().zip(&others) {
+            assert_eq!(*intersects, rect.intersects(other));
+        }
+        // the rectangles touching only the corners of `rect` intersect too
+        assert_eq!(intersections.iter().filter(|b| **b).count(), 9);
+    }
+}
@@ -31,14 +31,28 @@ impl<T: GeoFloat> Default for RegionAssembly<T> {
 }
 
 impl<T: GeoFloat> RegionAssembly<T> {
+    pub(super) fn new_in(segments: Vec<Segment<T>>) -> Self {
+        debug_assert!(segments.is_empty());
+        Self { segments }
+    }
+
     pub fn add_edge(&mut self, edge: LineOrPoint<T>) {
         debug_assert!(edge.is_line());
         trace!("add_edge: {edge:?}");
         self.segments.push(edge.into());
     }
     pub fn finish(self) -> MultiPolygon<T> {
+        self.finish_in(&mut vec![]).0
+    }
+
+    /// Like `finish`, but using `snakes`, which must be empty, as scratch space, and also
+    /// returning the buffer of segments, cleared, for reuse.
+    pub(super) fn finish_in(
+        mut self,
+        snakes: &mut Vec<Snake<T>>,
+    ) -> (MultiPolygon<T>, Vec<Segment<T>>) {
+        debug_assert!(snakes.is_empty());
         let mut iter = CrossingsIter::new_simple(self.segments.iter());
-        let mut snakes = vec![];
 
         while let Some(pt) = iter.next() {
             let num_segments = iter.intersections().len();
@@ -133,7 +147,12 @@ impl<T: GeoFloat> RegionAssembly<T> {
             }
         }
 
+        // release the borrow of the segments, so that they can be cleared
+        drop(iter);
+        self.segments.clear();
+
         let (rings, snakes_idx_map) = rings_from_snakes(&mut snakes[..]);
+        snakes.clear();
 
         let mut polygons = vec![];
         let mut children = HashMap::new();
@@ -170,7 +189,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
             polygons.push(Polygon::new(exterior, holes));
         }
 
-        polygons.into()
+        (polygons.into(), self.segments)
     }
 }
 
@@ -227,7 +246,7 @@ impl<T: GeoFloat> Default for LineAssembly<T> {
 }
 
 #[derive(Debug, Clone)]
-struct Ring<T: GeoFloat> {
+pub(super) struct Ring<T: GeoFloat> {
     ls: LineString<T>,
     is_hole: bool,
     parent_snake_idx: usize,
@@ -271,7 +290,7 @@ fn rings_from_snakes<T: GeoFloat>(
 }
 
 #[derive(Debug, Clone)]
-struct Snake<T: GeoFloat> {
+pub(super) struct Snake<T: GeoFloat> {
     points: Vec<SweepPoint<T>>,
     start_pair: usize,
     end_pair: Option<usize>,
@@ -360,7 +379,7 @@ impl<T: GeoFloat> Snake<T> {
 }
 
 #[derive(Debug, Clone)]
-struct Segment<T: GeoFloat> {
+pub(super) struct Segment<T: GeoFloat> {
     geom: LineOrPoint<T>,
     region: Cell<bool>,
     snake_idx: Cell<usize>,
@@ -1,6 +1,7 @@
 use geo_types::{MultiLineString, MultiPolygon};
 
 use crate::{CoordsIter, GeoFloat, GeoNum, Polygon};
+use std::{fmt, mem};
 
 /// Boolean Operations on geometry.
 ///
@@ -39,6 +40,23 @@ pub trait BooleanOps: Sized {
         self.boolean_op(other, OpType::Difference)
     }
 
+    /// Like [`boolean_op`](Self::boolean_op), but taking the buffers for the intermediate
+    /// results from `arena`, and handing them back to it afterwards, for the next operation.
+    ///
+    /// The default implementation ignores the arena.
+    fn boolean_op_in(
+        &self,
+        other: &Self,
+        op: OpType,
+        arena: &mut OverlayArena<Self::Scalar>,
+    ) -> MultiPolygon<Self::Scalar>
+    where
+        Self::Scalar: GeoFloat,
+    {
+        let _ = arena;
+        self.boolean_op(other, op)
+    }
+
     /// Clip a 1-D geometry with self.
     ///
     /// Returns the set-theoeretic intersection of `self` and `ls` if `invert`
@@ -58,6 +76,79 @@ pub enum OpType {
     Xor,
 }
 
+/// The buffers in which [`BooleanOps::boolean_op_in`] stores its intermediate results: the
+/// edges of the input geometries, the segments of the boundary of the output, and the chains
+/// of segments joined into its rings.
+///
+/// They're cleared after each operation, but keep their capacity, like a bump arena which is
+/// reset. Overlaying many pairs of geometries with the same arena, the buffers soon grow to
+/// fit the largest pair, after which only the sweep of the segments, and the output itself,
+/// allocate.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, BooleanOps, OpType, OverlayArena};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let mut arena = OverlayArena::new();
+/// for i in 0..10 {
+///     let x = f64::from(i);
+///     let other = polygon![(x: x, y: 1.), (x: x + 1., y: 1.), (x: x + 1., y: 3.), (x: x, y: 3.)];
+///     let intersection = square.boolean_op_in(&other, OpType::Intersection, &mut arena);
+///     assert_eq!(intersection, square.intersection(&other));
+/// }
+/// ```
+pub struct OverlayArena<T: GeoFloat> {
+    edges: Vec<Edge<T, BoolOp<T>>>,
+    segments: Vec<Segment<T>>,
+    snakes: Vec<Snake<T>>,
+}
+
+impl<T: GeoFloat> OverlayArena<T> {
+    pub fn new() -> Self {
+        OverlayArena {
+            edges: vec![],
+            segments: vec![],
+            snakes: vec![],
+        }
+    }
+
+    fn boolean_op(
+        &mut self,
+        op: OpType,
+        capacity: usize,
+        add_geometries: impl FnOnce(&mut Proc<T, BoolOp<T>>),
+    ) -> MultiPolygon<T> {
+        let spec = BoolOp::new_in(op, mem::take(&mut self.segments));
+        let mut bop = Proc::new_in(spec, capacity, mem::take(&mut self.edges));
+        add_geometries(&mut bop);
+        let (spec, mut edges) = bop.sweep_into_parts();
+        edges.clear();
+        self.edges = edges;
+
+        let (output, segments) = spec.finish_in(&mut self.snakes);
+        self.segments = segments;
+        output
+    }
+}
+
+impl<T: GeoFloat> Default for OverlayArena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GeoFloat> fmt::Debug for OverlayArena<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OverlayArena")
+            .field("edges_capacity", &self.edges.capacity())
+            .field("segments_capacity", &self.segments.capacity())
+            .field("snakes_capacity", &self.snakes.capacity())
+            .finish()
+    }
+}
+
 impl<T: GeoFloat> BooleanOps for Polygon<T> {
     type Scalar = T;
 
@@ -69,6 +160,18 @@ impl<T: GeoFloat> BooleanOps for Polygon<T> {
         bop.sweep()
     }
 
+    fn boolean_op_in(
+        &self,
+        other: &Self,
+        op: OpType,
+        arena: &mut OverlayArena<T>,
+    ) -> MultiPolygon<T> {
+        arena.boolean_op(op, self.coords_count() + other.coords_count(), |bop| {
+            bop.add_polygon(self, 0);
+            bop.add_polygon(other, 1);
+        })
+    }
+
     fn clip(
         &self,
         ls: &MultiLineString<Self::Scalar>,
@@ -94,6 +197,18 @@ impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
         bop.sweep()
     }
 
+    fn boolean_op_in(
+        &self,
+        other: &Self,
+        op: OpType,
+        arena: &mut OverlayArena<T>,
+    ) -> MultiPolygon<T> {
+        arena.boolean_op(op, self.coords_count() + other.coords_count(), |bop| {
+            bop.add_multi_polygon(self, 0);
+            bop.add_multi_polygon(other, 1);
+        })
+    }
+
     fn clip(
         &self,
         ls: &MultiLineString<Self::Scalar>,
@@ -109,6 +224,23 @@ impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
     }
 }
 
+/// Union a set of multi-polygons by merging them in pairs, then merging the results in pairs,
+/// and so on, so that each union operates on geometries of similar size.
+pub(crate) fn cascaded_union<T: GeoFloat>(mut geoms: Vec<MultiPolygon<T>>) -> MultiPolygon<T> {
+    while geoms.len() > 1 {
+        let mut merged = Vec::with_capacity((geoms.len() + 1) / 2);
+        let mut geoms_iter = geoms.into_iter();
+        while let Some(first) = geoms_iter.next() {
+            match geoms_iter.next() {
+                Some(second) => merged.push(first.union(&second)),
+                None => merged.push(first),
+            }
+        }
+        geoms = merged;
+    }
+    geoms.pop().unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
+
 mod op;
 use op::*;
 mod assembly;
@@ -20,6 +20,13 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
         }
     }
 
+    /// Like `new`, but pushing the edges into a buffer taken from an
+    /// [`OverlayArena`](super::OverlayArena).
+    pub(super) fn new_in(spec: S, capacity: usize, mut edges: Vec<Edge<T, S>>) -> Self {
+        edges.reserve(capacity);
+        Proc { spec, edges }
+    }
+
     // idx: whether it is from first input or second input
     pub(crate) fn add_multi_polygon(&mut self, mp: &MultiPolygon<T>, idx: usize) {
         mp.0.iter().for_each(|p| self.add_polygon(p, idx));
@@ -63,6 +70,18 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
     }
 
     pub fn sweep(mut self) -> S::Output {
+        self.run_sweep();
+        self.spec.finish()
+    }
+
+    /// Like `sweep`, but returning the spec unfinished, along with the buffer of edges, for
+    /// reuse.
+    pub(super) fn sweep_into_parts(mut self) -> (S, Vec<Edge<T, S>>) {
+        self.run_sweep();
+        (self.spec, self.edges)
+    }
+
+    fn run_sweep(&mut self) {
         let mut iter = CrossingsIter::from_iter(self.edges.iter());
 
         while let Some(pt) = iter.next() {
@@ -169,12 +188,11 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
                 idx += 1;
             }
         }
-        self.spec.finish()
     }
 }
 
 #[derive(Clone)]
-struct Edge<T: Float, S: Spec<T>> {
+pub(super) struct Edge<T: Float, S: Spec<T>> {
     geom: LineOrPoint<T>,
     idx: usize,
     _region: Cell<S::Region>,
@@ -26,6 +26,26 @@ impl<T: GeoFloat> From<OpType> for BoolOp<T> {
         }
     }
 }
+impl<T: GeoFloat> BoolOp<T> {
+    /// Like `from`, but pushing the output segments into a buffer taken from an
+    /// [`OverlayArena`].
+    pub(super) fn new_in(ty: OpType, segments: Vec<Segment<T>>) -> Self {
+        Self {
+            ty,
+            assembly: RegionAssembly::new_in(segments),
+        }
+    }
+
+    /// Like `finish`, but using `snakes` as scratch space, and also returning the buffer of
+    /// output segments, for reuse.
+    pub(super) fn finish_in(
+        self,
+        snakes: &mut Vec<Snake<T>>,
+    ) -> (MultiPolygon<T>, Vec<Segment<T>>) {
+        self.assembly.finish_in(snakes)
+    }
+}
+
 impl<T: GeoFloat> Spec<T> for BoolOp<T> {
     type Region = Region;
     type Output = MultiPolygon<T>;
@@ -221,6 +221,31 @@ fn test_issue_894() {
     println!("{}", intersection.to_wkt());
 }
 
+#[test]
+fn test_arena_reuse() -> Result<()> {
+    let wkt1 = "POLYGON((0 0, 40 0, 40 40, 0 40, 0 0), (10 10, 30 10, 30 30, 10 30, 10 10))";
+    let wkt2 = "POLYGON((5 5, 35 5, 35 35, 5 35, 5 5), (15 15, 25 15, 25 25, 15 25, 15 15))";
+    let poly1 = Polygon::<f64>::try_from_wkt_str(wkt1)?;
+    let poly2 = Polygon::<f64>::try_from_wkt_str(wkt2)?;
+    let multi1 = MultiPolygon::from(poly1.clone());
+    let multi2 = MultiPolygon::from(poly2.clone());
+
+    let mut arena = OverlayArena::new();
+    for ty in [
+        OpType::Intersection,
+        OpType::Union,
+        OpType::Difference,
+        OpType::Xor,
+    ] {
+        let expected = poly1.boolean_op(&poly2, ty);
+        assert_eq!(poly1.boolean_op_in(&poly2, ty, &mut arena), expected);
+        assert_eq!(multi1.boolean_op_in(&multi2, ty, &mut arena), expected);
+        assert!(arena.edges.is_empty() && arena.segments.is_empty() && arena.snakes.is_empty());
+    }
+    assert!(arena.edges.capacity() > 0 && arena.segments.capacity() > 0);
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn test_issue_buffer_box() {
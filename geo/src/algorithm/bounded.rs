@@ -0,0 +1,211 @@
+use crate::{BoundingRect, Contains, CoordNum, GeoNum, Intersects, Line, LinesIter, Rect};
+
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+
+/// A geometry along with its bounding rectangle, computed once.
+///
+/// `Intersects` and `Contains` first compare the cached bounding rectangle with the bounding
+/// rectangle of the other geometry, and only forward to the wrapped geometry if they can't rule
+/// the relation out, so that testing many geometries against the same one doesn't recompute its
+/// envelope, or walk its coordinates, every time.
+///
+/// An index of the edges of the geometry can also be built with
+/// [`with_edge_index`](Bounded::with_edge_index), which `Intersects` uses to look for an edge
+/// meeting the other geometry among the few edges near it, before testing the whole geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Bounded, Contains, Intersects, Point};
+///
+/// let polygon = Bounded::new(polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 0., y: 10.),
+/// ])
+/// .with_edge_index();
+///
+/// assert!(polygon.contains(&Point::new(5., 5.)));
+/// // rejected by the bounding rectangles alone
+/// assert!(!polygon.intersects(&line_string![(x: 20., y: 0.), (x: 30., y: 10.)]));
+/// // found among the edges near the line string
+/// assert!(polygon.intersects(&line_string![(x: 5., y: 5.), (x: 15., y: 5.)]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bounded<G, T>
+where
+    T: CoordNum + RTreeNum,
+{
+    geometry: G,
+    bounding_rect: Option<Rect<T>>,
+    edge_index: Option<RTree<IndexedEdge<T>>>,
+}
+
+impl<G, T> Bounded<G, T>
+where
+    T: CoordNum + RTreeNum,
+{
+    /// Wrap `geometry`, computing its bounding rectangle.
+    pub fn new(geometry: G) -> Self
+    where
+        G: BoundingRect<T>,
+    {
+        let bounding_rect = geometry.bounding_rect().into();
+        Bounded {
+            geometry,
+            bounding_rect,
+            edge_index: None,
+        }
+    }
+
+    /// Build an index of the edges of the geometry, for `Intersects` to use.
+    pub fn with_edge_index(mut self) -> Self
+    where
+        G: for<'a> LinesIter<'a, Scalar = T>,
+    {
+        let edges = self.geometry.lines_iter().map(IndexedEdge).collect();
+        self.edge_index = Some(RTree::bulk_load(edges));
+        self
+    }
+
+    /// The wrapped geometry.
+    pub fn geometry(&self) -> &G {
+        &self.geometry
+    }
+
+    /// Unwrap the geometry, dropping the cached bounding rectangle and edge index.
+    pub fn into_inner(self) -> G {
+        self.geometry
+    }
+}
+
+impl<G, T> BoundingRect<T> for Bounded<G, T>
+where
+    T: CoordNum + RTreeNum,
+{
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        self.bounding_rect
+    }
+}
+
+impl<G, T, Rhs> Intersects<Rhs> for Bounded<G, T>
+where
+    T: GeoNum + RTreeNum,
+    G: Intersects<Rhs>,
+    Line<T>: Intersects<Rhs>,
+    Rhs: BoundingRect<T>,
+{
+    fn intersects(&self, rhs: &Rhs) -> bool {
+        let rhs_rect: Option<Rect<T>> = rhs.bounding_rect().into();
+        if let (Some(bounding_rect), Some(rhs_rect)) = (self.bounding_rect, rhs_rect) {
+            if !bounding_rect.intersects(&rhs_rect) {
+                return false;
+            }
+            if let Some(edge_index) = &self.edge_index {
+                let envelope = AABB::from_corners(
+                    [rhs_rect.min().x, rhs_rect.min().y],
+                    [rhs_rect.max().x, rhs_rect.max().y],
+                );
+                if edge_index
+                    .locate_in_envelope_intersecting(&envelope)
+                    .any(|edge| edge.0.intersects(rhs))
+                {
+                    return true;
+                }
+            }
+        }
+        // no edge meets `rhs`, but one of them could still be inside the other
+        self.geometry.intersects(rhs)
+    }
+}
+
+impl<G, T, Rhs> Contains<Rhs> for Bounded<G, T>
+where
+    T: CoordNum + RTreeNum,
+    G: Contains<Rhs>,
+    Rhs: BoundingRect<T>,
+{
+    fn contains(&self, rhs: &Rhs) -> bool {
+        let rhs_rect: Option<Rect<T>> = rhs.bounding_rect().into();
+        if let (Some(bounding_rect), Some(rhs_rect)) = (self.bounding_rect, rhs_rect) {
+            // all of `rhs` has to be within `self`, and so within its bounding rect
+            if !bounding_rect.contains(&rhs_rect) {
+                return false;
+            }
+        }
+        self.geometry.contains(rhs)
+    }
+}
+
+// An edge stored in the index, with an envelope of plain arrays so that it isn't restricted to
+// floating point coordinates, unlike `Line`'s `RTreeObject` implementation.
+#[derive(Debug, Clone, Copy)]
+struct IndexedEdge<T: CoordNum>(Line<T>);
+
+impl<T> RTreeObject for IndexedEdge<T>
+where
+    T: CoordNum + RTreeNum,
+{
+    type Envelope = AABB<[T; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners(
+            [self.0.start.x, self.0.start.y],
+            [self.0.end.x, self.0.end.y],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon, Geometry, GeometryCollection, LineString, Point};
+
+    #[test]
+    fn matches_the_wrapped_geometry() {
+        // a U shape, whose bounding rect covers the notch
+        let u = polygon![
+            (x: 0, y: 0),
+            (x: 6, y: 0),
+            (x: 6, y: 6),
+            (x: 4, y: 6),
+            (x: 4, y: 2),
+            (x: 2, y: 2),
+            (x: 2, y: 6),
+            (x: 0, y: 6),
+        ];
+        let plain = Bounded::new(u.clone());
+        let indexed = Bounded::new(u.clone()).with_edge_index();
+        let others: Vec<Geometry<i32>> = vec![
+            Point::new(3, 4).into(),
+            Point::new(1, 1).into(),
+            Point::new(9, 9).into(),
+            line_string![(x: 3, y: 3), (x: 3, y: 5)].into(),
+            line_string![(x: 3, y: 3), (x: 5, y: 5)].into(),
+            line_string![(x: 7, y: 0), (x: 9, y: 9)].into(),
+            Rect::new(coord! { x: 1, y: 1 }, coord! { x: 5, y: 1 }).into(),
+            polygon![(x: 1, y: 1), (x: 5, y: 1), (x: 3, y: 0)].into(),
+        ];
+        for other in &others {
+            assert_eq!(plain.intersects(other), u.intersects(other));
+            assert_eq!(indexed.intersects(other), u.intersects(other));
+        }
+        for other in &others {
+            if let Geometry::Point(point) = other {
+                assert_eq!(plain.contains(point), u.contains(point));
+            }
+        }
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let empty = Bounded::new(LineString::<f64>::new(vec![])).with_edge_index();
+        assert_eq!(empty.bounding_rect(), None);
+        assert!(!empty.intersects(&Point::new(0., 0.)));
+        let collection = Bounded::new(GeometryCollection::<f64>::new_from(vec![]));
+        assert!(!collection.intersects(&Point::new(0., 0.)));
+    }
+}
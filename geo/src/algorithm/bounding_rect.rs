@@ -56,6 +56,19 @@ where
     }
 }
 
+impl<T> BoundingRect<T> for PointZM<T>
+where
+    T: CoordNum,
+{
+    type Output = Rect<T>;
+
+    /// Return the two-dimensional bounding rectangle of a `PointZM`. It will have zero width
+    /// and zero height.
+    fn bounding_rect(&self) -> Self::Output {
+        self.xy.bounding_rect()
+    }
+}
+
 impl<T> BoundingRect<T> for MultiPoint<T>
 where
     T: CoordNum,
@@ -93,6 +106,18 @@ where
     }
 }
 
+impl<T> BoundingRect<T> for LineStringZM<T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    /// Return the two-dimensional bounding rectangle of a `LineStringZM`.
+    fn bounding_rect(&self) -> Self::Output {
+        self.xy().bounding_rect()
+    }
+}
+
 impl<T> BoundingRect<T> for MultiLineString<T>
 where
     T: CoordNum,
@@ -199,6 +224,50 @@ where
     }
 }
 
+impl<'a, T> BoundingRect<T> for LineStringRef<'a, T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        get_bounding_rect(self.0.iter().cloned())
+    }
+}
+
+impl<'a, T> BoundingRect<T> for PolygonRef<'a, T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        self.exterior().bounding_rect()
+    }
+}
+
+impl<'a, T> BoundingRect<T> for GeometryRef<'a, T>
+where
+    T: CoordNum,
+{
+    type Output = Option<Rect<T>>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        match self {
+            GeometryRef::Point(point) => Some(point.bounding_rect()),
+            GeometryRef::LineString(line_string) => line_string.bounding_rect(),
+            GeometryRef::Polygon(polygon) => polygon.bounding_rect(),
+            GeometryRef::MultiPoint(coords) => get_bounding_rect(coords.iter().cloned()),
+            GeometryRef::MultiLineString(line_strings) => {
+                get_bounding_rect(line_strings.iter().flat_map(|l| l.0.iter().cloned()))
+            }
+            GeometryRef::MultiPolygon(polygons) => {
+                get_bounding_rect(polygons.iter().flat_map(|p| p.exterior().0.iter().cloned()))
+            }
+        }
+    }
+}
+
 // Return a new rectangle that encompasses the provided rectangles
 fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
     Rect::new(
@@ -0,0 +1,462 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::iter::{once, Sum};
+
+use crate::{
+    Contains, Coord, Densify, GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon,
+};
+
+/// Extract the main centerline of an elongated polygon, such as a river or a road mapped as an
+/// area, e.g. to place a label along it.
+///
+/// The boundary of the polygon is densified so that its coordinates are at most `spacing`
+/// apart, and the edges of the [Voronoi diagram](https://en.wikipedia.org/wiki/Voronoi_diagram)
+/// of these coordinates lying inside the polygon are kept. They approximate the
+/// [medial axis](https://en.wikipedia.org/wiki/Medial_axis) of the polygon, with more precision
+/// for smaller `spacing`.
+///
+/// The medial axis has a branch running into every corner of the polygon. Branches ending at a
+/// corner and shorter than `tolerance` are pruned, and the centerline is the longest remaining
+/// path, which goes around the holes of the polygon on whichever side is shortest.
+///
+/// The Voronoi diagram is computed from a Delaunay triangulation built by incremental insertion,
+/// which takes `O(n²)` time in the worst case, for `n` coordinates after densification.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Centerline, EuclideanLength, Polygon, Rect};
+///
+/// let road: Polygon = Rect::new((0., 0.), (10., 2.)).to_polygon();
+/// let centerline = road.centerline(0.5, 1.5).unwrap();
+///
+/// // the centerline runs along the middle of the road, stopping short of its ends
+/// assert!(centerline.coords().all(|c| (c.y - 1.).abs() < 1e-9));
+/// assert!((centerline.euclidean_length() - 8.).abs() < 1e-9);
+/// ```
+pub trait Centerline<T: GeoFloat> {
+    type Output;
+
+    fn centerline(&self, spacing: T, tolerance: T) -> Self::Output;
+}
+
+impl<T: GeoFloat + Sum> Centerline<T> for Polygon<T> {
+    type Output = Option<LineString<T>>;
+
+    fn centerline(&self, spacing: T, tolerance: T) -> Self::Output {
+        let densified = self.densify(spacing);
+        let mut sites: Vec<Coord<T>> = once(densified.exterior())
+            .chain(densified.interiors())
+            // skip the closing coordinate of every ring
+            .flat_map(|ring| ring.0.iter().skip(1).copied())
+            .collect();
+        sites.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap_or(Ordering::Equal)
+                .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+        });
+        sites.dedup();
+        if sites.len() < 3 {
+            return None;
+        }
+
+        // The Voronoi vertices are the circumcenters of the Delaunay triangles, and there is a
+        // Voronoi edge between the circumcenters of every pair of adjacent triangles.
+        let triangles = delaunay(&sites);
+        let inside: Vec<bool> = triangles
+            .iter()
+            .map(|triangle| self.contains(&triangle.circle.center))
+            .collect();
+        let sides: HashMap<(usize, usize), usize> = triangles
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, triangle)| triangle.edges().map(move |edge| (edge, idx)))
+            .collect();
+        let mut graph = Graph {
+            nodes: triangles
+                .iter()
+                .map(|triangle| triangle.circle.center)
+                .collect(),
+            edges: vec![vec![]; triangles.len()],
+        };
+        for (idx, triangle) in triangles.iter().enumerate() {
+            for (a, b) in triangle.edges() {
+                match sides.get(&(b, a)) {
+                    Some(&other) if other > idx && inside[idx] && inside[other] => {
+                        graph.connect(idx, other);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        graph.prune(tolerance);
+        let mut coords: Vec<Coord<T>> = graph
+            .longest_path()
+            .into_iter()
+            .map(|node| graph.nodes[node])
+            .collect();
+        coords.dedup();
+        if coords.len() < 2 {
+            return None;
+        }
+        Some(LineString::new(coords))
+    }
+}
+
+impl<T: GeoFloat + Sum> Centerline<T> for MultiPolygon<T> {
+    type Output = MultiLineString<T>;
+
+    fn centerline(&self, spacing: T, tolerance: T) -> Self::Output {
+        self.iter()
+            .filter_map(|polygon| polygon.centerline(spacing, tolerance))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Circle<T: GeoFloat> {
+    center: Coord<T>,
+    radius_2: T,
+}
+
+impl<T: GeoFloat> Circle<T> {
+    fn circumscribing(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Self {
+        let (b, c) = (b - a, c - a);
+        let d = (T::one() + T::one()) * (b.x * c.y - b.y * c.x);
+        let (b_2, c_2) = (b.x * b.x + b.y * b.y, c.x * c.x + c.y * c.y);
+        let center = Coord {
+            x: (c.y * b_2 - b.y * c_2) / d,
+            y: (b.x * c_2 - c.x * b_2) / d,
+        };
+        Circle {
+            center: a + center,
+            radius_2: center.x * center.x + center.y * center.y,
+        }
+    }
+
+    fn contains(&self, coord: Coord<T>) -> bool {
+        let delta = coord - self.center;
+        delta.x * delta.x + delta.y * delta.y < self.radius_2
+    }
+}
+
+// A counter-clockwise triangle, by the indices of its vertices.
+#[derive(Debug, Clone, Copy)]
+struct DelaunayTriangle<T: GeoFloat> {
+    vertices: [usize; 3],
+    circle: Circle<T>,
+}
+
+impl<T: GeoFloat> DelaunayTriangle<T> {
+    fn new(vertices: [usize; 3], coords: &[Coord<T>]) -> Self {
+        let [a, b, c] = vertices;
+        DelaunayTriangle {
+            vertices,
+            circle: Circle::circumscribing(coords[a], coords[b], coords[c]),
+        }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (usize, usize)> {
+        let [a, b, c] = self.vertices;
+        [(a, b), (b, c), (c, a)].into_iter()
+    }
+}
+
+// Delaunay triangulation of distinct coordinates, using the Bowyer-Watson algorithm.
+fn delaunay<T: GeoFloat>(sites: &[Coord<T>]) -> Vec<DelaunayTriangle<T>> {
+    // start from a triangle much larger than the sites
+    let (min, max) = sites.iter().fold((sites[0], sites[0]), |(min, max), c| {
+        (
+            Coord {
+                x: min.x.min(c.x),
+                y: min.y.min(c.y),
+            },
+            Coord {
+                x: max.x.max(c.x),
+                y: max.y.max(c.y),
+            },
+        )
+    });
+    let size = (max.x - min.x).max(max.y - min.y) * T::from(100).unwrap();
+    let mid = (min + max) / (T::one() + T::one());
+    let n = sites.len();
+    let mut coords = sites.to_vec();
+    coords.push(Coord {
+        x: mid.x - size,
+        y: mid.y - size,
+    });
+    coords.push(Coord {
+        x: mid.x + size,
+        y: mid.y - size,
+    });
+    coords.push(Coord {
+        x: mid.x,
+        y: mid.y + size,
+    });
+    let mut triangles = vec![DelaunayTriangle::new([n, n + 1, n + 2], &coords)];
+
+    for (idx, site) in sites.iter().enumerate() {
+        // remove the triangles whose circumcircle contains the site, and fill the cavity with
+        // triangles joining its boundary to the site
+        let mut cavity = vec![];
+        triangles.retain(|triangle| {
+            let bad = triangle.circle.contains(*site);
+            if bad {
+                cavity.extend(triangle.edges());
+            }
+            !bad
+        });
+        for &(a, b) in &cavity {
+            if !cavity.contains(&(b, a)) {
+                triangles.push(DelaunayTriangle::new([a, b, idx], &coords));
+            }
+        }
+    }
+    triangles.retain(|triangle| triangle.vertices.iter().all(|v| *v < n));
+    triangles
+}
+
+#[derive(Debug)]
+struct Graph<T: GeoFloat> {
+    nodes: Vec<Coord<T>>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<T: GeoFloat> Graph<T> {
+    fn connect(&mut self, a: usize, b: usize) {
+        self.edges[a].push(b);
+        self.edges[b].push(a);
+    }
+
+    fn disconnect(&mut self, a: usize, b: usize) {
+        self.edges[a].retain(|other| *other != b);
+        self.edges[b].retain(|other| *other != a);
+    }
+
+    fn length(&self, a: usize, b: usize) -> T {
+        let delta = self.nodes[b] - self.nodes[a];
+        delta.x.hypot(delta.y)
+    }
+
+    // Remove the branches running from a leaf to a junction, if they are shorter than
+    // `tolerance`. When all the edges of a junction are short branches, the two longest are kept,
+    // so that the path through the junction isn't removed.
+    fn prune(&mut self, tolerance: T) {
+        // the nodes of each branch, from the leaf to the junction, and its length
+        let mut branches: HashMap<usize, Vec<(Vec<usize>, T)>> = HashMap::new();
+        for leaf in (0..self.nodes.len()).filter(|node| self.edges[*node].len() == 1) {
+            let mut branch = vec![leaf];
+            let mut length = T::zero();
+            let mut node = leaf;
+            let mut previous = None;
+            let junction = loop {
+                let next = match self.edges[node].iter().find(|n| Some(**n) != previous) {
+                    Some(next) => *next,
+                    None => break None,
+                };
+                length = length + self.length(node, next);
+                branch.push(next);
+                previous = Some(node);
+                node = next;
+                match self.edges[node].len() {
+                    2 => continue,
+                    1 => break None,
+                    _ => break Some(node),
+                }
+            };
+            if let Some(junction) = junction {
+                if length < tolerance {
+                    branches.entry(junction).or_default().push((branch, length));
+                }
+            }
+        }
+
+        for (junction, mut short) in branches {
+            if short.len() == self.edges[junction].len() {
+                short.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+                short.drain(..2);
+            }
+            for (branch, _) in short {
+                for pair in branch.windows(2) {
+                    self.disconnect(pair[0], pair[1]);
+                }
+            }
+        }
+    }
+
+    // The longest of the shortest paths from the farthest node of a component, over all
+    // components. This is the longest path when the graph has no cycles.
+    fn longest_path(&self) -> Vec<usize> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut longest: (T, Vec<usize>) = (T::zero(), vec![]);
+        for node in 0..self.nodes.len() {
+            if visited[node] || self.edges[node].is_empty() {
+                continue;
+            }
+            let (distances, _) = self.shortest_paths(node);
+            for (idx, distance) in distances.iter().enumerate() {
+                visited[idx] |= distance.is_finite();
+            }
+            let start = farthest(&distances);
+            let (distances, previous) = self.shortest_paths(start);
+            let end = farthest(&distances);
+            if distances[end] > longest.0 || longest.1.is_empty() {
+                let mut path = vec![end];
+                while let Some(node) = previous[*path.last().unwrap()] {
+                    path.push(node);
+                }
+                longest = (distances[end], path);
+            }
+        }
+        longest.1
+    }
+
+    // Dijkstra's algorithm.
+    fn shortest_paths(&self, start: usize) -> (Vec<T>, Vec<Option<usize>>) {
+        let mut distances = vec![T::infinity(); self.nodes.len()];
+        let mut previous = vec![None; self.nodes.len()];
+        let mut queue = BinaryHeap::new();
+        distances[start] = T::zero();
+        queue.push(State {
+            distance: T::zero(),
+            node: start,
+        });
+        while let Some(State { distance, node }) = queue.pop() {
+            if distance > distances[node] {
+                continue;
+            }
+            for &next in &self.edges[node] {
+                let distance = distance + self.length(node, next);
+                if distance < distances[next] {
+                    distances[next] = distance;
+                    previous[next] = Some(node);
+                    queue.push(State {
+                        distance,
+                        node: next,
+                    });
+                }
+            }
+        }
+        (distances, previous)
+    }
+}
+
+// The index of the farthest reachable node.
+fn farthest<T: GeoFloat>(distances: &[T]) -> usize {
+    distances
+        .iter()
+        .enumerate()
+        .filter(|(_, distance)| distance.is_finite())
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+        .map(|(idx, _)| idx)
+        .unwrap()
+}
+
+#[derive(Debug)]
+struct State<T: GeoFloat> {
+    distance: T,
+    node: usize,
+}
+
+impl<T: GeoFloat> PartialEq for State<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl<T: GeoFloat> Eq for State<T> {}
+
+impl<T: GeoFloat> PartialOrd for State<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for State<T> {
+    // reversed, so that `BinaryHeap` pops the nearest state first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, EuclideanLength, Rect};
+
+    #[test]
+    fn delaunay_square() {
+        let sites = vec![
+            Coord { x: 0., y: 0. },
+            Coord { x: 1., y: 0. },
+            Coord { x: 0., y: 1. },
+            Coord { x: 1., y: 1. },
+            Coord { x: 0.5, y: 0.4 },
+        ];
+        let triangles = delaunay(&sites);
+        assert_eq!(triangles.len(), 4);
+        for triangle in &triangles {
+            assert!(sites
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| !triangle.vertices.contains(idx))
+                .all(|(_, site)| !triangle.circle.contains(*site)));
+        }
+    }
+
+    #[test]
+    fn rectangle() {
+        let poly: Polygon = Rect::new((0., 0.), (10., 2.)).to_polygon();
+        let centerline = poly.centerline(1., 1.).unwrap();
+        assert!(centerline.coords().all(|c| (c.y - 1.).abs() < 1e-9));
+        assert_relative_eq!(centerline.euclidean_length(), 8.);
+
+        // without pruning, the centerline runs into opposite corners
+        let centerline = poly.centerline(1., 0.).unwrap();
+        assert_relative_eq!(
+            centerline.euclidean_length(),
+            8. + 2f64.sqrt(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn bend() {
+        // an L-shaped corridor of width 2
+        let poly: Polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 8., y: 10.),
+            (x: 8., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let centerline = poly.centerline(0.25, 1.5).unwrap();
+        let (first, last) = (centerline.0[0], *centerline.0.last().unwrap());
+        let (start, end) = if first.x < last.x {
+            (first, last)
+        } else {
+            (last, first)
+        };
+        assert_relative_eq!(start, Coord { x: 1., y: 1. }, epsilon = 1e-9);
+        assert_relative_eq!(end, Coord { x: 9., y: 9. }, epsilon = 1e-9);
+        assert!(centerline
+            .coords()
+            .all(|c| (c.y - 1.).abs() < 1e-9 || (c.x - 9.).abs() < 1e-9 || c.x > 8.));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let polys: MultiPolygon = MultiPolygon::new(vec![
+            Rect::new((0., 0.), (10., 2.)).to_polygon(),
+            Rect::new((0., 5.), (2., 15.)).to_polygon(),
+        ]);
+        let centerlines = polys.centerline(1., 1.);
+        assert_eq!(centerlines.0.len(), 2);
+        assert!(centerlines.0[1].coords().all(|c| (c.x - 1.).abs() < 1e-9));
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{Coord, GeoFloat, LineString, MultiPoint, Point, Polygon};
+
+/// Descriptive statistics of the central tendency and dispersion of a set of points, as used in
+/// crime analysis and epidemiology.
+///
+/// Implemented for sets of points, where every point has the same weight, and for slices of
+/// `(Point, weight)` pairs. All methods return `None` for empty sets, or if the weights do not
+/// sum to a positive value.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Centrography, MultiPoint};
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: -1., y: -1.),
+///     point!(x: 1., y: -1.),
+///     point!(x: 1., y: 1.),
+///     point!(x: -1., y: 1.),
+/// ]);
+/// assert_eq!(points.mean_center(), Some(point!(x: 0., y: 0.)));
+/// assert_eq!(points.standard_distance(), Some(2f64.sqrt()));
+///
+/// // the first point is five times as important as the others
+/// let weighted = [
+///     (point!(x: 0., y: 0.), 5.),
+///     (point!(x: 4., y: 0.), 1.),
+///     (point!(x: 4., y: 4.), 1.),
+///     (point!(x: 0., y: 4.), 1.),
+/// ];
+/// assert_eq!(weighted.mean_center(), Some(point!(x: 1., y: 1.)));
+/// ```
+pub trait Centrography<T: GeoFloat> {
+    /// The weighted mean of the points' coordinates.
+    fn mean_center(&self) -> Option<Point<T>>;
+
+    /// The point minimising the weighted sum of the euclidean distances to all points, also
+    /// known as the geometric median.
+    ///
+    /// It is approximated with [Weiszfeld's algorithm](https://en.wikipedia.org/wiki/Geometric_median#Computation),
+    /// starting from the mean center, until an iteration moves the estimate by less than
+    /// `tolerance`, or after 1000 iterations.
+    fn median_center(&self, tolerance: T) -> Option<Point<T>>;
+
+    /// The square root of the weighted mean of the squared distances between the points and
+    /// their mean center.
+    ///
+    /// The circle around the mean center with this radius is the *standard distance circle*.
+    fn standard_distance(&self) -> Option<T>;
+
+    /// The ellipse summarising the spread of the points along their main direction.
+    ///
+    /// The axes of the ellipse follow the eigenvectors of the weighted covariance matrix of the
+    /// points, and the semi-axes are the standard deviations of the points along each axis.
+    fn standard_deviational_ellipse(&self) -> Option<StandardDeviationalEllipse<T>>;
+}
+
+/// The standard deviational ellipse of a set of points, as computed by
+/// [`Centrography::standard_deviational_ellipse`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StandardDeviationalEllipse<T: GeoFloat> {
+    /// The mean center of the points.
+    pub center: Point<T>,
+    /// The standard deviation of the points along the major axis.
+    pub semi_major: T,
+    /// The standard deviation of the points along the minor axis.
+    pub semi_minor: T,
+    /// The angle of the major axis, in degrees counter-clockwise from the x axis, in the range
+    /// `(-90, 90]`.
+    pub angle: T,
+}
+
+impl<T: GeoFloat> StandardDeviationalEllipse<T> {
+    /// Approximate the ellipse with a polygon of `segments` sides.
+    pub fn to_polygon(&self, segments: usize) -> Polygon<T> {
+        let (sin, cos) = self.angle.to_radians().sin_cos();
+        let step = T::from(std::f64::consts::TAU).unwrap() / T::from(segments).unwrap();
+        let exterior: LineString<T> = (0..segments)
+            .map(|i| {
+                let (t_sin, t_cos) = (step * T::from(i).unwrap()).sin_cos();
+                let (x, y) = (self.semi_major * t_cos, self.semi_minor * t_sin);
+                Coord {
+                    x: self.center.x() + x * cos - y * sin,
+                    y: self.center.y() + x * sin + y * cos,
+                }
+            })
+            .collect();
+        Polygon::new(exterior, vec![])
+    }
+}
+
+impl<T: GeoFloat> Centrography<T> for [(Point<T>, T)] {
+    fn mean_center(&self) -> Option<Point<T>> {
+        mean_center(self).map(Point::from)
+    }
+
+    fn median_center(&self, tolerance: T) -> Option<Point<T>> {
+        median_center(self, tolerance).map(Point::from)
+    }
+
+    fn standard_distance(&self) -> Option<T> {
+        let center = mean_center(self)?;
+        let (sum, total) =
+            self.iter()
+                .fold((T::zero(), T::zero()), |(sum, total), (point, weight)| {
+                    let delta = point.0 - center;
+                    (
+                        sum + *weight * (delta.x * delta.x + delta.y * delta.y),
+                        total + *weight,
+                    )
+                });
+        Some((sum / total).sqrt())
+    }
+
+    fn standard_deviational_ellipse(&self) -> Option<StandardDeviationalEllipse<T>> {
+        let center = mean_center(self)?;
+        // weighted sums of squared deviations, and of their products
+        let (mut xx, mut yy, mut xy, mut total) = (T::zero(), T::zero(), T::zero(), T::zero());
+        for (point, weight) in self {
+            let delta = point.0 - center;
+            xx = xx + *weight * delta.x * delta.x;
+            yy = yy + *weight * delta.y * delta.y;
+            xy = xy + *weight * delta.x * delta.y;
+            total = total + *weight;
+        }
+        let two = T::one() + T::one();
+        let (xx, yy, xy) = (xx / total, yy / total, xy / total);
+        // eigenvalues of the covariance matrix
+        let mean = (xx + yy) / two;
+        let spread = (((xx - yy) / two).powi(2) + xy * xy).sqrt();
+        let angle = (two * xy).atan2(xx - yy) / two;
+        let angle = angle.to_degrees();
+        Some(StandardDeviationalEllipse {
+            center: center.into(),
+            semi_major: (mean + spread).sqrt(),
+            // rounding may make the smallest eigenvalue slightly negative
+            semi_minor: (mean - spread).max(T::zero()).sqrt(),
+            angle: if angle <= T::from(-90).unwrap() {
+                angle + T::from(180).unwrap()
+            } else {
+                angle
+            },
+        })
+    }
+}
+
+impl<T: GeoFloat> Centrography<T> for [Point<T>] {
+    fn mean_center(&self) -> Option<Point<T>> {
+        with_unit_weights(self).mean_center()
+    }
+
+    fn median_center(&self, tolerance: T) -> Option<Point<T>> {
+        with_unit_weights(self).median_center(tolerance)
+    }
+
+    fn standard_distance(&self) -> Option<T> {
+        with_unit_weights(self).standard_distance()
+    }
+
+    fn standard_deviational_ellipse(&self) -> Option<StandardDeviationalEllipse<T>> {
+        with_unit_weights(self).standard_deviational_ellipse()
+    }
+}
+
+impl<T: GeoFloat> Centrography<T> for MultiPoint<T> {
+    fn mean_center(&self) -> Option<Point<T>> {
+        self.0.mean_center()
+    }
+
+    fn median_center(&self, tolerance: T) -> Option<Point<T>> {
+        self.0.median_center(tolerance)
+    }
+
+    fn standard_distance(&self) -> Option<T> {
+        self.0.standard_distance()
+    }
+
+    fn standard_deviational_ellipse(&self) -> Option<StandardDeviationalEllipse<T>> {
+        self.0.standard_deviational_ellipse()
+    }
+}
+
+fn with_unit_weights<T: GeoFloat>(points: &[Point<T>]) -> Vec<(Point<T>, T)> {
+    points.iter().map(|point| (*point, T::one())).collect()
+}
+
+fn mean_center<T: GeoFloat>(points: &[(Point<T>, T)]) -> Option<Coord<T>> {
+    let (sum, total) = points.iter().fold(
+        (Coord::zero(), T::zero()),
+        |(sum, total), (point, weight)| (sum + point.0 * *weight, total + *weight),
+    );
+    if total > T::zero() {
+        Some(sum / total)
+    } else {
+        None
+    }
+}
+
+fn median_center<T: GeoFloat>(points: &[(Point<T>, T)], tolerance: T) -> Option<Coord<T>> {
+    const MAX_ITERATIONS: usize = 1000;
+
+    let mut estimate = mean_center(points)?;
+    for _ in 0..MAX_ITERATIONS {
+        let mut sum = Coord::zero();
+        let mut total = T::zero();
+        for (point, weight) in points {
+            let delta = point.0 - estimate;
+            let distance = delta.x.hypot(delta.y);
+            // points at the current estimate are left out, which only slows convergence when the
+            // median lies on one of them
+            if distance > T::zero() {
+                sum = sum + point.0 * (*weight / distance);
+                total = total + *weight / distance;
+            }
+        }
+        if total == T::zero() {
+            break;
+        }
+        let next = sum / total;
+        let delta = next - estimate;
+        estimate = next;
+        if delta.x.hypot(delta.y) < tolerance {
+            break;
+        }
+    }
+    Some(estimate)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Area};
+
+    #[test]
+    fn empty() {
+        let points = MultiPoint::<f64>::new(vec![]);
+        assert_eq!(points.mean_center(), None);
+        assert_eq!(points.median_center(1e-9), None);
+        assert_eq!(points.standard_distance(), None);
+        assert_eq!(points.standard_deviational_ellipse(), None);
+        let zero_weights = [(point!(x: 1., y: 1.), 0.)];
+        assert_eq!(zero_weights.mean_center(), None);
+    }
+
+    #[test]
+    fn median_is_robust_to_outliers() {
+        let points = [
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 1.),
+            point!(x: 1., y: 1.),
+            point!(x: 100., y: 100.),
+        ];
+        let mean = points.mean_center().unwrap();
+        assert_relative_eq!(mean, point!(x: 20.4, y: 20.4));
+        let median = points.median_center(1e-12).unwrap();
+        assert!(median.x() > 0.5 && median.x() < 1.);
+        assert_relative_eq!(median.x(), median.y(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn median_of_collinear_points() {
+        let points = [
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 10., y: 0.),
+        ];
+        let median = points.median_center(1e-12).unwrap();
+        assert_relative_eq!(median, point!(x: 1., y: 0.), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn weighted_standard_distance() {
+        let weighted = [(point!(x: 0., y: 0.), 3.), (point!(x: 4., y: 0.), 1.)];
+        // the mean center is at x = 1
+        assert_relative_eq!(weighted.standard_distance().unwrap(), 3f64.sqrt());
+    }
+
+    #[test]
+    fn deviational_ellipse() {
+        let points = MultiPoint::new(vec![
+            point!(x: -2., y: -2.),
+            point!(x: 2., y: 2.),
+            point!(x: -1., y: 1.),
+            point!(x: 1., y: -1.),
+        ]);
+        let ellipse = points.standard_deviational_ellipse().unwrap();
+        assert_relative_eq!(ellipse.center, point!(x: 0., y: 0.));
+        assert_relative_eq!(ellipse.semi_major, 2.);
+        assert_relative_eq!(ellipse.semi_minor, 1.);
+        assert_relative_eq!(ellipse.angle, 45.);
+
+        let polygon = ellipse.to_polygon(1000);
+        assert_relative_eq!(
+            polygon.unsigned_area(),
+            std::f64::consts::PI * 2.,
+            max_relative = 1e-4
+        );
+    }
+
+    #[test]
+    fn vertical_ellipse() {
+        let points = [
+            point!(x: 0., y: -3.),
+            point!(x: 0., y: 3.),
+            point!(x: -1., y: 0.),
+            point!(x: 1., y: 0.),
+        ];
+        let ellipse = points.standard_deviational_ellipse().unwrap();
+        assert_relative_eq!(ellipse.angle, 90.);
+        assert!(ellipse.semi_major > ellipse.semi_minor);
+    }
+}
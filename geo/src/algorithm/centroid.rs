@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use crate::area::{get_linestring_area, Area};
 use crate::dimensions::{Dimensions, Dimensions::*, HasDimensions};
@@ -84,6 +84,18 @@ where
     }
 }
 
+impl<T> Centroid for LineStringZM<T>
+where
+    T: GeoFloat,
+{
+    type Output = Option<Point<T>>;
+
+    /// The two-dimensional centroid of the coordinates, ignoring their Z and M values.
+    fn centroid(&self) -> Self::Output {
+        self.xy().centroid()
+    }
+}
+
 impl<T> Centroid for MultiLineString<T>
 where
     T: GeoFloat,
@@ -147,6 +159,17 @@ where
     }
 }
 
+impl<T> Centroid for PointZM<T>
+where
+    T: GeoFloat,
+{
+    type Output = Point<T>;
+
+    fn centroid(&self) -> Self::Output {
+        self.xy
+    }
+}
+
 ///
 /// ```
 /// use geo::Centroid;
@@ -211,6 +234,32 @@ where
     }
 }
 
+// The centroid of a `MultiPolygon`, accumulating the weighted centroids of its polygons in
+// parallel.
+#[cfg(feature = "use-rayon")]
+pub(crate) fn par_multi_polygon_centroid<T>(multi_polygon: &MultiPolygon<T>) -> Option<Point<T>>
+where
+    T: GeoFloat + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    multi_polygon
+        .0
+        .par_iter()
+        .map(|polygon| {
+            let mut operation = CentroidOperation::new();
+            operation.add_polygon(polygon);
+            operation
+        })
+        .reduce(CentroidOperation::new, |mut operation, other| {
+            if let Some(weighted_centroid) = other.0 {
+                operation.add_weighted_centroid(weighted_centroid);
+            }
+            operation
+        })
+        .centroid()
+}
+
 struct CentroidOperation<T: GeoFloat>(Option<WeightedCentroid<T>>);
 impl<T: GeoFloat> CentroidOperation<T> {
     fn new() -> Self {
@@ -2,7 +2,10 @@ use std::ops::Mul;
 
 use num_traits::FromPrimitive;
 
-use crate::{coord, Coord, CoordFloat, LineString, MultiLineString, MultiPolygon, Polygon};
+use crate::area::get_linestring_area;
+use crate::{
+    coord, Coord, CoordFloat, EuclideanLength, LineString, MultiLineString, MultiPolygon, Polygon,
+};
 
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
 ///
@@ -83,6 +86,145 @@ where
     }
 }
 
+/// Smoothen `Polygon` and `MultiPolygon` using Chaikins algorithm, like [`ChaikinSmoothing`],
+/// while preserving the area enclosed by each of their rings.
+///
+/// Chaikins algorithm cuts the corners of the rings, shrinking convex shapes and growing concave
+/// ones, so the area of a smoothed polygon differs from the original, which matters e.g. for
+/// the cartographic generalization of statistical areas. Here, after the smoothing, the
+/// vertices of each ring are moved along their normals, by the same distance, until the ring
+/// encloses its original area to within a relative `tolerance`, redistributing the area lost at
+/// the corners around the whole ring.
+///
+/// The search for the distance stops after a fixed number of steps, so for shapes which can't
+/// be restored, such as rings folding over themselves, the area may be further off. Rings thin
+/// enough to be smoothed out entirely may come out self-intersecting.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, AreaPreservingChaikinSmoothing, Area, ChaikinSmoothing};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+///
+/// assert_relative_eq!(square.chaikin_smoothing(1).unsigned_area(), 87.5);
+///
+/// let smoothed = square.area_preserving_chaikin_smoothing(1, 1e-9);
+/// assert_relative_eq!(smoothed.unsigned_area(), 100., max_relative = 1e-9);
+/// ```
+pub trait AreaPreservingChaikinSmoothing<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    /// create a new geometry with the Chaikin smoothing being
+    /// applied `n_iterations` times, and the area of each ring restored to within `tolerance`
+    /// of the original, relative to it.
+    fn area_preserving_chaikin_smoothing(&self, n_iterations: usize, tolerance: T) -> Self;
+}
+
+impl<T> AreaPreservingChaikinSmoothing<T> for Polygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn area_preserving_chaikin_smoothing(&self, n_iterations: usize, tolerance: T) -> Self {
+        let smoothen = |ring: &LineString<T>| {
+            restore_area(
+                ring.chaikin_smoothing(n_iterations),
+                get_linestring_area(ring).abs(),
+                tolerance,
+            )
+        };
+        Polygon::new(
+            smoothen(self.exterior()),
+            self.interiors().iter().map(smoothen).collect(),
+        )
+    }
+}
+
+impl<T> AreaPreservingChaikinSmoothing<T> for MultiPolygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn area_preserving_chaikin_smoothing(&self, n_iterations: usize, tolerance: T) -> Self {
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|poly| poly.area_preserving_chaikin_smoothing(n_iterations, tolerance))
+                .collect(),
+        )
+    }
+}
+
+// the steps of the search for the distance restoring the area of a ring
+const MAX_AREA_STEPS: usize = 32;
+
+// Move the vertices of a closed ring along their outward normals, by the distance at which it
+// encloses `area`, found with the secant method.
+fn restore_area<T: CoordFloat>(ring: LineString<T>, area: T, tolerance: T) -> LineString<T> {
+    let enclosed = |ring: &LineString<T>| get_linestring_area(ring).abs();
+    let within_tolerance = |enclosed: T| (enclosed - area).abs() <= tolerance * area;
+    let perimeter = ring.lines().fold(T::zero(), |perimeter, line| {
+        perimeter + line.euclidean_length()
+    });
+    let (mut distance, mut enclosed_at) = (T::zero(), enclosed(&ring));
+    if within_tolerance(enclosed_at) || perimeter == T::zero() {
+        return ring;
+    }
+
+    let normals = vertex_normals(&ring);
+    let offset = |distance: T| -> LineString<T> {
+        ring.0
+            .iter()
+            .zip(&normals)
+            .map(|(&coord, &normal)| coord + normal * distance)
+            .collect()
+    };
+    // to a first approximation, the area changes by the perimeter times the distance
+    let mut next = (area - enclosed_at) / perimeter;
+    let mut restored = offset(next);
+    for _ in 0..MAX_AREA_STEPS {
+        let enclosed_next = enclosed(&restored);
+        if within_tolerance(enclosed_next) || enclosed_next == enclosed_at {
+            break;
+        }
+        let step = (area - enclosed_next) * (next - distance) / (enclosed_next - enclosed_at);
+        distance = next;
+        enclosed_at = enclosed_next;
+        next = next + step;
+        restored = offset(next);
+    }
+    restored
+}
+
+// The unit normals of the vertices of a closed ring, bisecting those of their two edges, and
+// pointing out of the ring. The last vertex repeats the first.
+fn vertex_normals<T: CoordFloat>(ring: &LineString<T>) -> Vec<Coord<T>> {
+    let outwards = if get_linestring_area(ring) < T::zero() {
+        -T::one()
+    } else {
+        T::one()
+    };
+    let unit = |c: Coord<T>| {
+        let length = c.x.hypot(c.y);
+        if length > T::zero() {
+            c / length
+        } else {
+            Coord::zero()
+        }
+    };
+    let edge_normals: Vec<Coord<T>> = ring
+        .lines()
+        .map(|line| unit(coord! { x: line.dy(), y: -line.dx() }) * outwards)
+        .collect();
+    let n = edge_normals.len();
+    let mut normals: Vec<Coord<T>> = (0..n)
+        .map(|i| unit(edge_normals[(i + n - 1) % n] + edge_normals[i]))
+        .collect();
+    normals.push(normals[0]);
+    normals
+}
+
 fn smoothen_linestring<T>(linestring: &LineString<T>) -> LineString<T>
 where
     T: CoordFloat + Mul<T> + FromPrimitive,
@@ -133,8 +275,10 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::ChaikinSmoothing;
+    use crate::{point, polygon, Area, Centroid, MultiPolygon};
+    use crate::{AreaPreservingChaikinSmoothing, ChaikinSmoothing};
     use crate::{LineString, Polygon};
+    use approx::assert_relative_eq;
 
     #[test]
     fn linestring_open() {
@@ -209,4 +353,64 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn polygon_area_preserving() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.)]],
+        );
+        let smoothed = poly.chaikin_smoothing(2);
+        let preserved = poly.area_preserving_chaikin_smoothing(2, 1e-9);
+        assert_eq!(preserved.exterior().0.len(), smoothed.exterior().0.len());
+        assert!(smoothed.unsigned_area() < 96.);
+
+        let exterior = Polygon::new(preserved.exterior().clone(), vec![]);
+        let interior = Polygon::new(preserved.interiors()[0].clone(), vec![]);
+        assert_relative_eq!(exterior.unsigned_area(), 100., max_relative = 1e-9);
+        assert_relative_eq!(interior.unsigned_area(), 4., max_relative = 1e-9);
+        assert_relative_eq!(preserved.unsigned_area(), 96., max_relative = 1e-8);
+        // spread evenly around the rings
+        assert_relative_eq!(
+            exterior.centroid().unwrap(),
+            point!(x: 5., y: 5.),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            interior.centroid().unwrap(),
+            point!(x: 3., y: 3.),
+            epsilon = 1e-9
+        );
+
+        // whichever the winding of the rings
+        let mut reversed = poly.exterior().clone();
+        reversed.0.reverse();
+        let preserved = Polygon::new(reversed, vec![]).area_preserving_chaikin_smoothing(2, 1e-9);
+        assert_relative_eq!(preserved.unsigned_area(), 100., max_relative = 1e-9);
+
+        assert_eq!(poly.area_preserving_chaikin_smoothing(0, 1e-9), poly);
+    }
+
+    #[test]
+    fn multi_polygon_area_preserving() {
+        let mp = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 3.)],
+            // with a concave corner, where the smoothing adds area
+            polygon![
+                (x: 10., y: 0.),
+                (x: 16., y: 0.),
+                (x: 16., y: 6.),
+                (x: 13., y: 1.),
+                (x: 10., y: 6.),
+            ],
+        ]);
+        let preserved = mp.area_preserving_chaikin_smoothing(3, 1e-6);
+        for (original, preserved) in mp.iter().zip(preserved.iter()) {
+            assert_relative_eq!(
+                preserved.unsigned_area(),
+                original.unsigned_area(),
+                max_relative = 1e-6
+            );
+        }
+    }
 }
@@ -70,7 +70,7 @@ where
     }
 }
 
-fn ring_area<T>(coords: &LineString<T>) -> T
+pub(crate) fn ring_area<T>(coords: &LineString<T>) -> T
 where
     T: CoordFloat,
 {
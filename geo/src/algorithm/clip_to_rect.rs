@@ -0,0 +1,233 @@
+use crate::clip_to_window::{line_string_geometry, non_empty, ClipToWindow, ClipWindow};
+use crate::geometry::*;
+use crate::GeoFloat;
+
+/// Clip a geometry to a [`Rect`], keeping the parts of it within the rectangle, e.g. to cut
+/// the features of a map into tiles. This is much faster than a general intersection with
+/// [`BooleanOps`](crate::BooleanOps), in linear time in the number of coordinates.
+///
+/// The clipped geometry is of the same type, or of its multi-geometry type where clipping can
+/// cut it in several parts: a `LineString` is clipped into a `MultiLineString`, and a
+/// `Triangle` into a `Polygon`. Points and parts which are on the border of the rectangle are
+/// within it. Geometries wholly outside the rectangle are clipped to `None`, or to an empty
+/// geometry.
+///
+/// The rings of polygons are clipped by the Sutherland–Hodgman algorithm, which keeps them
+/// whole: the parts of a ring outside the rectangle are replaced by the border of the
+/// rectangle between where the ring leaves it and comes back. The area of a clipped polygon is
+/// right, but if it leaves the rectangle several times, its parts are joined by edges along
+/// the border of the rectangle, with no area, rather than split into a `MultiPolygon`. Rings
+/// clipped to fewer than three distinct coordinates are removed. Lines are clipped by the
+/// Liang–Barsky algorithm.
+///
+/// To clip to another convex window than a rectangle, see [`ClipToWindow`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, ClipToRect, MultiLineString, Rect};
+///
+/// let tile = Rect::new((0., 0.), (10., 10.));
+///
+/// let road = line_string![(x: -5., y: 5.), (x: 5., y: 5.), (x: 5., y: 15.)];
+/// assert_eq!(
+///     road.clip_to_rect(&tile),
+///     MultiLineString::new(vec![line_string![
+///         (x: 0., y: 5.),
+///         (x: 5., y: 5.),
+///         (x: 5., y: 10.),
+///     ]])
+/// );
+///
+/// let field = polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)];
+/// assert_eq!(
+///     field.clip_to_rect(&tile),
+///     polygon![(x: 5., y: 10.), (x: 5., y: 5.), (x: 10., y: 5.), (x: 10., y: 10.)]
+/// );
+/// ```
+pub trait ClipToRect<T: GeoFloat> {
+    type Output;
+
+    /// The parts of the geometry within `rect`.
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output;
+}
+
+macro_rules! clip_impl {
+    ($type:ident, $output:ty) => {
+        impl<T: GeoFloat> ClipToRect<T> for $type<T> {
+            type Output = $output;
+
+            fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+                self.clip_to_window(&ClipWindow::from(*rect))
+            }
+        }
+    };
+}
+
+clip_impl!(Point, Option<Point<T>>);
+clip_impl!(MultiPoint, MultiPoint<T>);
+clip_impl!(Line, Option<Line<T>>);
+clip_impl!(LineString, MultiLineString<T>);
+clip_impl!(MultiLineString, MultiLineString<T>);
+clip_impl!(Polygon, Polygon<T>);
+clip_impl!(MultiPolygon, MultiPolygon<T>);
+clip_impl!(Triangle, Polygon<T>);
+
+impl<T: GeoFloat> ClipToRect<T> for Rect<T> {
+    type Output = Option<Rect<T>>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        let min = Coord {
+            x: self.min().x.max(rect.min().x),
+            y: self.min().y.max(rect.min().y),
+        };
+        let max = Coord {
+            x: self.max().x.min(rect.max().x),
+            y: self.max().y.min(rect.max().y),
+        };
+        (min.x <= max.x && min.y <= max.y).then(|| Rect::new(min, max))
+    }
+}
+
+impl<T: GeoFloat> ClipToRect<T> for Geometry<T> {
+    type Output = Option<Geometry<T>>;
+
+    /// The clipped geometry, of the same type but for a `LineString` cut into several parts,
+    /// which is clipped to a `MultiLineString`, and a `Triangle`, which is clipped to a
+    /// `Polygon`. `None` if it's wholly outside the rectangle.
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        match self {
+            Geometry::Point(g) => g.clip_to_rect(rect).map(Geometry::Point),
+            Geometry::Line(g) => g.clip_to_rect(rect).map(Geometry::Line),
+            Geometry::LineString(g) => line_string_geometry(g.clip_to_rect(rect)),
+            Geometry::Polygon(g) => non_empty(g.clip_to_rect(rect)).map(Geometry::Polygon),
+            Geometry::MultiPoint(g) => non_empty(g.clip_to_rect(rect)).map(Geometry::MultiPoint),
+            Geometry::MultiLineString(g) => {
+                non_empty(g.clip_to_rect(rect)).map(Geometry::MultiLineString)
+            }
+            Geometry::MultiPolygon(g) => {
+                non_empty(g.clip_to_rect(rect)).map(Geometry::MultiPolygon)
+            }
+            Geometry::GeometryCollection(g) => {
+                non_empty(g.clip_to_rect(rect)).map(Geometry::GeometryCollection)
+            }
+            Geometry::Rect(g) => g.clip_to_rect(rect).map(Geometry::Rect),
+            Geometry::Triangle(g) => non_empty(g.clip_to_rect(rect)).map(Geometry::Polygon),
+        }
+    }
+}
+
+impl<T: GeoFloat> ClipToRect<T> for GeometryCollection<T> {
+    type Output = GeometryCollection<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        GeometryCollection::new_from(
+            self.iter()
+                .filter_map(|geometry| geometry.clip_to_rect(rect))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Area};
+
+    fn tile() -> Rect<f64> {
+        Rect::new((0., 0.), (10., 10.))
+    }
+
+    #[test]
+    fn lines() {
+        let line_string = line_string![
+            (x: -5., y: 5.),
+            (x: 5., y: 5.),
+            (x: 5., y: 15.),
+            (x: 7., y: 15.),
+            (x: 7., y: 5.),
+            // a repeated coordinate
+            (x: 7., y: 5.),
+            (x: 15., y: 5.),
+            // touching a corner from outside
+            (x: 10., y: 0.),
+            (x: 15., y: -5.),
+        ];
+        assert_eq!(
+            line_string.clip_to_rect(&tile()),
+            MultiLineString::new(vec![
+                line_string![(x: 0., y: 5.), (x: 5., y: 5.), (x: 5., y: 10.)],
+                line_string![(x: 7., y: 10.), (x: 7., y: 5.), (x: 10., y: 5.)],
+            ])
+        );
+
+        assert_eq!(
+            Line::new((-5., -5.), (15., 15.)).clip_to_rect(&tile()),
+            Some(Line::new((0., 0.), (10., 10.)))
+        );
+        // along the border
+        assert_eq!(
+            Line::new((-5., 10.), (5., 10.)).clip_to_rect(&tile()),
+            Some(Line::new((0., 10.), (5., 10.)))
+        );
+        assert_eq!(Line::new((-5., 0.), (0., -5.)).clip_to_rect(&tile()), None);
+        assert_eq!(Line::new((11., 0.), (11., 5.)).clip_to_rect(&tile()), None);
+    }
+
+    #[test]
+    fn polygons() {
+        let polygon = polygon![
+            exterior: [(x: -5., y: -5.), (x: 5., y: -5.), (x: 5., y: 5.), (x: -5., y: 5.)],
+            interiors: [
+                // across the border
+                [(x: -1., y: 1.), (x: 1., y: 1.), (x: 1., y: 2.), (x: -1., y: 2.)],
+                // outside
+                [(x: -3., y: -3.), (x: -2., y: -3.), (x: -2., y: -2.)],
+            ],
+        ];
+        let clipped = polygon.clip_to_rect(&tile());
+        assert_eq!(clipped.exterior().0.len(), 5);
+        assert_eq!(clipped.interiors().len(), 1);
+        assert_eq!(clipped.unsigned_area(), 25. - 1.);
+
+        let outside = polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 25., y: 10.)];
+        assert!(outside.clip_to_rect(&tile()).exterior().0.is_empty());
+        assert_eq!(
+            MultiPolygon::new(vec![outside, polygon]).clip_to_rect(&tile()),
+            MultiPolygon::new(vec![clipped])
+        );
+
+        // a triangle around the tile
+        let triangle = Triangle::new((-100., -10.).into(), (100., -10.).into(), (0., 100.).into());
+        assert_eq!(triangle.clip_to_rect(&tile()).unsigned_area(), 100.);
+    }
+
+    #[test]
+    fn geometries() {
+        assert_eq!(
+            Geometry::from(point!(x: 10., y: 10.)).clip_to_rect(&tile()),
+            Some(point!(x: 10., y: 10.).into())
+        );
+        assert_eq!(
+            Geometry::from(point!(x: 10., y: 11.)).clip_to_rect(&tile()),
+            None
+        );
+        assert_eq!(
+            Geometry::from(line_string![(x: -5., y: 5.), (x: 5., y: 5.)]).clip_to_rect(&tile()),
+            Some(line_string![(x: 0., y: 5.), (x: 5., y: 5.)].into())
+        );
+        assert_eq!(
+            Geometry::from(Rect::new((5., 5.), (15., 15.))).clip_to_rect(&tile()),
+            Some(Rect::new((5., 5.), (10., 10.)).into())
+        );
+
+        let collection = GeometryCollection::new_from(vec![
+            point!(x: 20., y: 20.).into(),
+            MultiPoint::new(vec![point!(x: 20., y: 20.), point!(x: 1., y: 1.)]).into(),
+        ]);
+        assert_eq!(
+            collection.clip_to_rect(&tile()),
+            GeometryCollection::new_from(vec![MultiPoint::new(vec![point!(x: 1., y: 1.)]).into()])
+        );
+    }
+}
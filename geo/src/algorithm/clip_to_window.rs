@@ -0,0 +1,508 @@
+use crate::geometry::*;
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{GeoFloat, HasDimensions};
+
+/// A closed half-plane: the points on the left of a directed line, or on it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HalfPlane<T: GeoFloat> {
+    origin: Coord<T>,
+    direction: Coord<T>,
+}
+
+impl<T: GeoFloat> HalfPlane<T> {
+    /// The points on the left of `line`, looking from its start to its end, or on it.
+    pub fn left_of(line: Line<T>) -> Self {
+        HalfPlane {
+            origin: line.start,
+            direction: line.delta(),
+        }
+    }
+
+    /// Whether the half-plane contains `coord`.
+    pub fn contains(&self, coord: Coord<T>) -> bool {
+        Side::HalfPlane(*self).value(coord) >= T::zero()
+    }
+}
+
+/// A convex window to clip geometries to with [`ClipToWindow`]: the intersection of
+/// half-planes, e.g. a viewport or the footprint of a sensor.
+///
+/// Windows made from a [`Rect`] clip to its sides exactly: the coordinates of the clipped
+/// geometries where they cross a side are on it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClipWindow<T: GeoFloat> {
+    sides: Vec<Side<T>>,
+}
+
+impl<T: GeoFloat> ClipWindow<T> {
+    /// The intersection of `half_planes`, the whole plane if there's none.
+    pub fn from_half_planes(half_planes: impl IntoIterator<Item = HalfPlane<T>>) -> Self {
+        ClipWindow {
+            sides: half_planes.into_iter().map(Side::HalfPlane).collect(),
+        }
+    }
+
+    /// The window inside the exterior of a convex polygon, wound either way, or `None` if the
+    /// exterior has no winding order, e.g. if it has fewer than three distinct coordinates.
+    ///
+    /// Note: the polygon must be convex.
+    pub fn from_convex(polygon: &Polygon<T>) -> Option<Self> {
+        let counter_clockwise = match polygon.exterior().winding_order()? {
+            WindingOrder::CounterClockwise => true,
+            WindingOrder::Clockwise => false,
+        };
+        Some(Self::from_half_planes(
+            polygon
+                .exterior()
+                .lines()
+                .filter(|line| line.start != line.end)
+                .map(|line| {
+                    if counter_clockwise {
+                        HalfPlane::left_of(line)
+                    } else {
+                        HalfPlane::left_of(Line::new(line.end, line.start))
+                    }
+                }),
+        ))
+    }
+
+    /// Whether the window contains `coord`, on its border or inside it.
+    pub fn contains(&self, coord: Coord<T>) -> bool {
+        self.sides.iter().all(|side| side.value(coord) >= T::zero())
+    }
+
+    /// Clip a line by the Liang–Barsky, or Cyrus–Beck, algorithm: the line is inside a side
+    /// where `value(start) + rate(delta) * t >= 0`.
+    pub(crate) fn clip_line(&self, line: Line<T>) -> Option<Line<T>> {
+        let delta = line.delta();
+        let mut t0 = T::zero();
+        let mut t1 = T::one();
+        // the sides the line enters and leaves the window across
+        let mut entry = None;
+        let mut exit = None;
+        for side in &self.sides {
+            let (value, rate) = (side.value(line.start), side.rate(delta));
+            if rate == T::zero() {
+                if value < T::zero() {
+                    return None;
+                }
+                continue;
+            }
+            let t = -value / rate;
+            if rate > T::zero() {
+                if t > t0 {
+                    t0 = t;
+                    entry = Some(side);
+                }
+            } else if t < t1 {
+                t1 = t;
+                exit = Some(side);
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+        let start = entry.map_or(line.start, |side| side.snap(line.start + delta * t0));
+        let end = exit.map_or(line.end, |side| side.snap(line.start + delta * t1));
+        Some(Line::new(start, end))
+    }
+
+    /// Clip a line string into the parts of it in the window.
+    pub(crate) fn clip_line_string(&self, line_string: &LineString<T>) -> MultiLineString<T> {
+        let mut line_strings: Vec<LineString<T>> = vec![];
+        // whether the last part continues up to the end of the last line
+        let mut open = false;
+        for line in line_string.lines() {
+            match self.clip_line(line) {
+                Some(clipped) if clipped.start != clipped.end => {
+                    match line_strings.last_mut() {
+                        Some(last) if open && clipped.start == line.start => {
+                            last.0.push(clipped.end)
+                        }
+                        _ => line_strings.push(LineString::new(vec![clipped.start, clipped.end])),
+                    }
+                    open = clipped.end == line.end;
+                }
+                // a repeated coordinate inside the window doesn't break the part
+                Some(_) if line.start == line.end => {}
+                _ => open = false,
+            }
+        }
+        MultiLineString::new(line_strings)
+    }
+
+    /// Clip a ring by the Sutherland–Hodgman algorithm, against each side of the window in
+    /// turn, or `None` if fewer than three distinct coordinates are left.
+    pub(crate) fn clip_ring(&self, ring: &LineString<T>) -> Option<LineString<T>> {
+        let mut coords = ring.0.clone();
+        if ring.is_closed() {
+            coords.pop();
+        }
+        for side in &self.sides {
+            let input = std::mem::take(&mut coords);
+            let mut previous = match input.last() {
+                Some(&last) => last,
+                None => break,
+            };
+            for current in input {
+                let (a, b) = (side.value(previous), side.value(current));
+                match (a >= T::zero(), b >= T::zero()) {
+                    (true, true) => coords.push(current),
+                    (true, false) => coords.push(side.crossing(previous, current)),
+                    (false, true) => {
+                        coords.push(side.crossing(previous, current));
+                        coords.push(current);
+                    }
+                    (false, false) => {}
+                }
+                previous = current;
+            }
+        }
+
+        coords.dedup();
+        if coords.len() > 1 && coords.first() == coords.last() {
+            coords.pop();
+        }
+        (coords.len() >= 3).then(|| LineString::new(coords))
+    }
+
+    /// Clip a polygon, to an empty polygon if it's wholly outside the window.
+    pub(crate) fn clip_polygon(&self, polygon: &Polygon<T>) -> Polygon<T> {
+        match self.clip_ring(polygon.exterior()) {
+            Some(exterior) => Polygon::new(
+                exterior,
+                polygon
+                    .interiors()
+                    .iter()
+                    .filter_map(|interior| self.clip_ring(interior))
+                    .collect(),
+            ),
+            None => Polygon::new(LineString::new(vec![]), vec![]),
+        }
+    }
+}
+
+impl<T: GeoFloat> From<Rect<T>> for ClipWindow<T> {
+    fn from(rect: Rect<T>) -> Self {
+        ClipWindow {
+            sides: vec![
+                Side::MinX(rect.min().x),
+                Side::MaxX(rect.max().x),
+                Side::MinY(rect.min().y),
+                Side::MaxY(rect.max().y),
+            ],
+        }
+    }
+}
+
+/// A side of a window, the sides of rectangles being kept apart to be clipped to exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side<T: GeoFloat> {
+    MinX(T),
+    MaxX(T),
+    MinY(T),
+    MaxY(T),
+    HalfPlane(HalfPlane<T>),
+}
+
+impl<T: GeoFloat> Side<T> {
+    /// A value which is positive inside the side, 0 on it, and negative outside it, and is
+    /// linear in `coord`.
+    fn value(&self, coord: Coord<T>) -> T {
+        match self {
+            Side::MinX(bound) => coord.x - *bound,
+            Side::MaxX(bound) => *bound - coord.x,
+            Side::MinY(bound) => coord.y - *bound,
+            Side::MaxY(bound) => *bound - coord.y,
+            Side::HalfPlane(half_plane) => {
+                let offset = coord - half_plane.origin;
+                half_plane.direction.x * offset.y - half_plane.direction.y * offset.x
+            }
+        }
+    }
+
+    /// The change of the value along `delta`.
+    fn rate(&self, delta: Coord<T>) -> T {
+        match self {
+            Side::MinX(_) => delta.x,
+            Side::MaxX(_) => -delta.x,
+            Side::MinY(_) => delta.y,
+            Side::MaxY(_) => -delta.y,
+            Side::HalfPlane(half_plane) => {
+                half_plane.direction.x * delta.y - half_plane.direction.y * delta.x
+            }
+        }
+    }
+
+    /// Move a coordinate computed to be on the side exactly onto it.
+    fn snap(&self, coord: Coord<T>) -> Coord<T> {
+        match self {
+            Side::MinX(x) | Side::MaxX(x) => Coord { x: *x, y: coord.y },
+            Side::MinY(y) | Side::MaxY(y) => Coord { x: coord.x, y: *y },
+            Side::HalfPlane(_) => coord,
+        }
+    }
+
+    /// Where the segment from `a` to `b`, on either side of the side, crosses it.
+    fn crossing(&self, a: Coord<T>, b: Coord<T>) -> Coord<T> {
+        let (value_a, value_b) = (self.value(a), self.value(b));
+        self.snap(a + (b - a) * (value_a / (value_a - value_b)))
+    }
+}
+
+/// Clip a geometry to a convex [`ClipWindow`], keeping the parts of it within the window,
+/// e.g. to the viewport of a map, or to a sequence of half-planes. This is much cheaper than a
+/// general intersection with [`BooleanOps`](crate::BooleanOps), in `O(n m)` for `n`
+/// coordinates and `m` sides of the window.
+///
+/// The clipped geometry is of the same type, or of its multi-geometry type where clipping can
+/// cut it in several parts: a `LineString` is clipped into a `MultiLineString`, and a `Rect` or
+/// a `Triangle` into a `Polygon`. Points and parts on the border of the window are within it.
+/// Geometries wholly outside the window are clipped to `None`, or to an empty geometry.
+///
+/// The rings of polygons are clipped by the Sutherland–Hodgman algorithm, and lines by the
+/// Liang–Barsky algorithm, as with [`ClipToRect`](crate::ClipToRect), whose notes on the
+/// rings of clipped polygons apply.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{ClipToWindow, ClipWindow, HalfPlane};
+/// use geo::{line_string, polygon, Area, Line, MultiLineString};
+///
+/// let window = ClipWindow::from_convex(&polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 0., y: 10.),
+/// ])
+/// .unwrap();
+///
+/// let field = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// assert_eq!(field.clip_to_window(&window).unsigned_area(), 50.);
+///
+/// // above the x axis
+/// let half_plane = HalfPlane::left_of(Line::new((0., 0.), (1., 0.)));
+/// let road = line_string![(x: 0., y: -1.), (x: 2., y: 1.)];
+/// assert_eq!(
+///     road.clip_to_window(&ClipWindow::from_half_planes([half_plane])),
+///     MultiLineString::new(vec![line_string![(x: 1., y: 0.), (x: 2., y: 1.)]])
+/// );
+/// ```
+pub trait ClipToWindow<T: GeoFloat> {
+    type Output;
+
+    /// The parts of the geometry within `window`.
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output;
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for Point<T> {
+    type Output = Option<Point<T>>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        window.contains(self.0).then(|| *self)
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for MultiPoint<T> {
+    type Output = MultiPoint<T>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        self.iter()
+            .filter_map(|point| point.clip_to_window(window))
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for Line<T> {
+    type Output = Option<Line<T>>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        window.clip_line(*self)
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        window.clip_line_string(self)
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        MultiLineString::new(
+            self.iter()
+                .flat_map(|line_string| window.clip_line_string(line_string))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for Polygon<T> {
+    type Output = Polygon<T>;
+
+    /// The clipped polygon, with an empty exterior if it's wholly outside the window.
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        window.clip_polygon(self)
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        self.iter()
+            .filter_map(|polygon| non_empty(window.clip_polygon(polygon)))
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for Rect<T> {
+    type Output = Polygon<T>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        window.clip_polygon(&self.to_polygon())
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for Triangle<T> {
+    type Output = Polygon<T>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        window.clip_polygon(&self.to_polygon())
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for Geometry<T> {
+    type Output = Option<Geometry<T>>;
+
+    /// The clipped geometry, of the same type but for a `LineString` cut into several parts,
+    /// which is clipped to a `MultiLineString`, and a `Rect` or a `Triangle`, which are
+    /// clipped to a `Polygon`. `None` if it's wholly outside the window.
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        match self {
+            Geometry::Point(g) => g.clip_to_window(window).map(Geometry::Point),
+            Geometry::Line(g) => g.clip_to_window(window).map(Geometry::Line),
+            Geometry::LineString(g) => line_string_geometry(g.clip_to_window(window)),
+            Geometry::Polygon(g) => non_empty(g.clip_to_window(window)).map(Geometry::Polygon),
+            Geometry::MultiPoint(g) => {
+                non_empty(g.clip_to_window(window)).map(Geometry::MultiPoint)
+            }
+            Geometry::MultiLineString(g) => {
+                non_empty(g.clip_to_window(window)).map(Geometry::MultiLineString)
+            }
+            Geometry::MultiPolygon(g) => {
+                non_empty(g.clip_to_window(window)).map(Geometry::MultiPolygon)
+            }
+            Geometry::GeometryCollection(g) => {
+                non_empty(g.clip_to_window(window)).map(Geometry::GeometryCollection)
+            }
+            Geometry::Rect(g) => non_empty(g.clip_to_window(window)).map(Geometry::Polygon),
+            Geometry::Triangle(g) => non_empty(g.clip_to_window(window)).map(Geometry::Polygon),
+        }
+    }
+}
+
+impl<T: GeoFloat> ClipToWindow<T> for GeometryCollection<T> {
+    type Output = GeometryCollection<T>;
+
+    fn clip_to_window(&self, window: &ClipWindow<T>) -> Self::Output {
+        GeometryCollection::new_from(
+            self.iter()
+                .filter_map(|geometry| geometry.clip_to_window(window))
+                .collect(),
+        )
+    }
+}
+
+pub(crate) fn non_empty<G: HasDimensions>(geometry: G) -> Option<G> {
+    (!geometry.is_empty()).then(|| geometry)
+}
+
+/// The parts of a clipped `LineString`, as a `LineString` if there's only one.
+pub(crate) fn line_string_geometry<T: GeoFloat>(
+    mut line_strings: MultiLineString<T>,
+) -> Option<Geometry<T>> {
+    match line_strings.0.len() {
+        0 => None,
+        1 => line_strings.0.pop().map(Geometry::LineString),
+        _ => Some(Geometry::MultiLineString(line_strings)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Area};
+
+    fn triangle() -> Polygon<f64> {
+        polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 10.)]
+    }
+
+    #[test]
+    fn convex_window() {
+        let window = ClipWindow::from_convex(&triangle()).unwrap();
+        // wound the other way
+        let mut reversed = triangle();
+        reversed.exterior_mut(|exterior| exterior.0.reverse());
+
+        let square = Rect::new((0., 0.), (10., 10.));
+        assert_eq!(square.clip_to_window(&window).unsigned_area(), 50.);
+        assert_eq!(
+            square
+                .clip_to_window(&ClipWindow::from_convex(&reversed).unwrap())
+                .unsigned_area(),
+            50.
+        );
+        assert_eq!(
+            Line::new((-5., 5.), (15., 5.)).clip_to_window(&window),
+            Some(Line::new((0., 5.), (5., 5.)))
+        );
+        assert!(window.contains(Coord { x: 5., y: 5. }));
+        assert!(!window.contains(Coord { x: 5., y: 5.1 }));
+
+        let outside = polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 25., y: 10.)];
+        assert_eq!(
+            Geometry::from(outside.clone()).clip_to_window(&window),
+            None
+        );
+        assert!(MultiPolygon::new(vec![outside])
+            .clip_to_window(&window)
+            .0
+            .is_empty());
+
+        let degenerate = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        assert_eq!(ClipWindow::from_convex(&degenerate), None);
+    }
+
+    #[test]
+    fn half_planes() {
+        // above y = 2, and left of x = 4
+        let window = ClipWindow::from_half_planes([
+            HalfPlane::left_of(Line::new((0., 2.), (1., 2.))),
+            HalfPlane::left_of(Line::new((4., 0.), (4., 1.))),
+        ]);
+        let points = MultiPoint::new(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 0., y: 2.),
+            point!(x: 4., y: 3.),
+            point!(x: 5., y: 3.),
+        ]);
+        assert_eq!(
+            points.clip_to_window(&window),
+            MultiPoint::new(vec![point!(x: 0., y: 2.), point!(x: 4., y: 3.)])
+        );
+        assert_eq!(
+            Geometry::from(line_string![(x: 0., y: 0.), (x: 0., y: 4.), (x: 8., y: 4.)])
+                .clip_to_window(&window),
+            Some(line_string![(x: 0., y: 2.), (x: 0., y: 4.), (x: 4., y: 4.)].into())
+        );
+
+        // the whole plane
+        let everywhere = ClipWindow::from_half_planes([]);
+        assert_eq!(triangle().clip_to_window(&everywhere), triangle());
+    }
+}
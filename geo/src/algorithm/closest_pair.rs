@@ -0,0 +1,184 @@
+use std::cmp::Ordering;
+
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, MultiPoint, Point};
+
+/// Find the closest pair of points of a [`MultiPoint`], and the euclidean distance between
+/// them, e.g. to detect duplicate points, or to check that points keep a minimum spacing.
+///
+/// The pair is found by divide and conquer, in `O(n log n)`: the points are split in halves by
+/// their x coordinates, and the closest pair is either within a half, or straddles the split
+/// within the distance between the closest pair of either half.
+///
+/// Returns `None` if there are fewer than two points. Repeated points are a closest pair, at a
+/// distance of zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, ClosestPair, MultiPoint};
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 7., y: 1.),
+///     point!(x: 2., y: 4.),
+///     point!(x: 6., y: 4.),
+///     point!(x: 3., y: 0.),
+/// ]);
+///
+/// assert_eq!(
+///     points.closest_pair(),
+///     Some((point!(x: 0., y: 0.), point!(x: 3., y: 0.), 3.))
+/// );
+/// ```
+pub trait ClosestPair<T: GeoFloat> {
+    /// The closest pair of points, ordered by their x coordinates, and the distance between
+    /// them.
+    fn closest_pair(&self) -> Option<(Point<T>, Point<T>, T)>;
+}
+
+impl<T: GeoFloat> ClosestPair<T> for MultiPoint<T> {
+    fn closest_pair(&self) -> Option<(Point<T>, Point<T>, T)> {
+        let mut coords: Vec<Coord<T>> = self.iter().map(|point| point.0).collect();
+        coords.sort_by(lex_cmp);
+        let (a, b, squared) = closest_pair(&mut coords)?;
+        Some((a.into(), b.into(), squared.sqrt()))
+    }
+}
+
+fn squared_distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    let delta = a - b;
+    delta.x * delta.x + delta.y * delta.y
+}
+
+fn by_y<T: GeoFloat>(a: &Coord<T>, b: &Coord<T>) -> Ordering {
+    a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal)
+}
+
+// The closest pair of the coordinates, sorted by x, and their squared distance. The
+// coordinates are left sorted by y.
+fn closest_pair<T: GeoFloat>(coords: &mut [Coord<T>]) -> Option<(Coord<T>, Coord<T>, T)> {
+    let closer = |best: (Coord<T>, Coord<T>, T), (a, b)| {
+        let squared = squared_distance(a, b);
+        if squared < best.2 {
+            (a, b, squared)
+        } else {
+            best
+        }
+    };
+
+    if coords.len() <= 3 {
+        let pairs = (0..coords.len())
+            .flat_map(|i| (i + 1..coords.len()).map(move |j| (i, j)))
+            .map(|(i, j)| (coords[i], coords[j]));
+        let best = pairs.fold(None, |best, pair| match best {
+            Some(best) => Some(closer(best, pair)),
+            None => Some((pair.0, pair.1, squared_distance(pair.0, pair.1))),
+        });
+        coords.sort_by(by_y);
+        return best;
+    }
+
+    let middle = coords.len() / 2;
+    let split = coords[middle].x;
+    let (left, right) = coords.split_at_mut(middle);
+    // both halves have at least two coordinates
+    let (left, right) = (closest_pair(left)?, closest_pair(right)?);
+    let mut best = if right.2 < left.2 { right } else { left };
+
+    // merge the halves, sorted by y
+    let mut merged = Vec::with_capacity(coords.len());
+    let (mut i, mut j) = (0, middle);
+    while i < middle || j < coords.len() {
+        if j == coords.len() || (i < middle && by_y(&coords[i], &coords[j]) != Ordering::Greater) {
+            merged.push(coords[i]);
+            i += 1;
+        } else {
+            merged.push(coords[j]);
+            j += 1;
+        }
+    }
+    coords.copy_from_slice(&merged);
+
+    // the pairs straddling the split which may be closer, by increasing y
+    let strip: Vec<Coord<T>> = coords
+        .iter()
+        .filter(|coord| (coord.x - split) * (coord.x - split) < best.2)
+        .copied()
+        .collect();
+    for (i, &a) in strip.iter().enumerate() {
+        for &b in &strip[i + 1..] {
+            if (b.y - a.y) * (b.y - a.y) >= best.2 {
+                break;
+            }
+            best = closer(best, (a, b));
+        }
+    }
+    let (a, b, squared) = best;
+    Some(match lex_cmp(&a, &b) {
+        Ordering::Greater => (b, a, squared),
+        _ => (a, b, squared),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    fn brute_force(points: &MultiPoint<f64>) -> f64 {
+        let mut best = f64::INFINITY;
+        for (i, a) in points.iter().enumerate() {
+            for b in &points.0[i + 1..] {
+                best = best.min(squared_distance(a.0, b.0));
+            }
+        }
+        best.sqrt()
+    }
+
+    #[test]
+    fn against_brute_force() {
+        for n in [2, 3, 4, 5, 10, 31, 200, 1000] {
+            // scattered, deterministically
+            let points: MultiPoint<f64> = (0..n)
+                .map(|i| {
+                    let t = i as f64 + 0.5;
+                    point!(x: (t * 12.9898).sin() * 100., y: (t * 78.233).sin() * 100.)
+                })
+                .collect();
+            let (a, b, distance) = points.closest_pair().unwrap();
+            assert_eq!(distance, brute_force(&points));
+            assert_eq!(squared_distance(a.0, b.0).sqrt(), distance);
+            assert!(points.0.contains(&a) && points.0.contains(&b));
+        }
+    }
+
+    #[test]
+    fn duplicates_and_degenerate() {
+        // along the split, and repeated
+        let points = MultiPoint::new(vec![
+            point!(x: 1., y: 0.),
+            point!(x: 1., y: 10.),
+            point!(x: 1., y: 5.),
+            point!(x: 1., y: 3.),
+            point!(x: 1., y: 7.5),
+        ]);
+        assert_eq!(
+            points.closest_pair(),
+            Some((point!(x: 1., y: 3.), point!(x: 1., y: 5.), 2.))
+        );
+
+        let mut points = points;
+        points.0.push(point!(x: 1., y: 7.5));
+        assert_eq!(
+            points.closest_pair(),
+            Some((point!(x: 1., y: 7.5), point!(x: 1., y: 7.5), 0.))
+        );
+
+        assert_eq!(
+            MultiPoint::new(vec![point!(x: 1., y: 1.)]).closest_pair(),
+            None
+        );
+        assert_eq!(MultiPoint::<f64>::new(vec![]).closest_pair(), None);
+    }
+}
@@ -140,7 +140,7 @@ impl<F: GeoFloat> ClosestPoint<F> for Triangle<F> {
         if self.intersects(p) {
             return Closest::Intersection(*p);
         }
-        closest_of(&self.to_lines(), *p)
+        closest_of(self.to_lines(), *p)
     }
 }
 
@@ -149,7 +149,12 @@ impl<F: GeoFloat> ClosestPoint<F> for Rect<F> {
         if self.intersects(p) {
             return Closest::Intersection(*p);
         }
-        closest_of(&self.to_lines(), *p)
+        // clamping an outside point onto the rect gives the nearest point of its boundary
+        let (min, max) = (self.min(), self.max());
+        Closest::SinglePoint(Point::new(
+            p.x().max(min.x).min(max.x),
+            p.y().max(min.y).min(max.y),
+        ))
     }
 }
 
@@ -324,6 +329,23 @@ mod tests {
         assert_eq!(result, Closest::Intersection(point!(x: 1.0, y: 2.0)));
     }
 
+    #[test]
+    fn rect_clamps_outside_points() {
+        let rect = Rect::new((0., 0.), (2., 1.));
+        assert_eq!(
+            rect.closest_point(&Point::new(5., 0.5)),
+            Closest::SinglePoint(Point::new(2., 0.5))
+        );
+        assert_eq!(
+            rect.closest_point(&Point::new(-1., -1.)),
+            Closest::SinglePoint(Point::new(0., 0.))
+        );
+        assert_eq!(
+            rect.closest_point(&Point::new(1., 1.)),
+            Closest::Intersection(Point::new(1., 1.))
+        );
+    }
+
     #[test]
     fn multi_polygon_with_internal_and_external_points() {
         use crate::{point, polygon};
@@ -1,4 +1,6 @@
-use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
+use super::{
+    impl_contains_from_relate, impl_contains_geometry_for, impl_contains_multi_point_for, Contains,
+};
 use crate::algorithm::Intersects;
 use crate::geometry::*;
 use crate::{GeoFloat, GeoNum};
@@ -83,5 +85,6 @@ where
     }
 }
 
-impl_contains_from_relate!(Line<T>, [Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_from_relate!(Line<T>, [Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_multi_point_for!(Line<T>);
 impl_contains_geometry_for!(Line<T>);
@@ -1,4 +1,6 @@
-use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
+use super::{
+    impl_contains_from_relate, impl_contains_geometry_for, impl_contains_multi_point_for, Contains,
+};
 use crate::algorithm::Intersects;
 use crate::geometry::*;
 use crate::{CoordNum, GeoFloat, GeoNum};
@@ -114,14 +116,16 @@ where
     }
 }
 
-impl_contains_from_relate!(LineString<T>, [Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_from_relate!(LineString<T>, [Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_multi_point_for!(LineString<T>);
 impl_contains_geometry_for!(LineString<T>);
 
 // ┌─────────────────────────────────────┐
 // │ Implementations for MultiLineString │
 // └─────────────────────────────────────┘
 
-impl_contains_from_relate!(MultiLineString<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_from_relate!(MultiLineString<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_multi_point_for!(MultiLineString<T>);
 impl_contains_geometry_for!(MultiLineString<T>);
 
 impl<T> Contains<Point<T>> for MultiLineString<T>
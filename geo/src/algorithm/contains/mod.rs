@@ -62,6 +62,32 @@ macro_rules! impl_contains_from_relate {
 }
 pub(crate) use impl_contains_from_relate;
 
+// A `MultiPoint` is contained if none of its points is in the exterior, and at least one is in
+// the interior, which only needs exact predicates, unlike the general `Relate` case.
+macro_rules! impl_contains_multi_point_for {
+    ($for:ty) => {
+        impl<T> Contains<MultiPoint<T>> for $for
+        where
+            T: GeoNum,
+        {
+            fn contains(&self, multi_point: &MultiPoint<T>) -> bool {
+                use $crate::coordinate_position::{CoordPos, CoordinatePosition};
+
+                let mut any_inside = false;
+                for point in multi_point {
+                    match self.coordinate_position(&point.0) {
+                        CoordPos::Outside => return false,
+                        CoordPos::Inside => any_inside = true,
+                        CoordPos::OnBoundary => {}
+                    }
+                }
+                any_inside
+            }
+        }
+    };
+}
+pub(crate) use impl_contains_multi_point_for;
+
 macro_rules! impl_contains_geometry_for {
     ($geom_type: ty) => {
         impl<T> Contains<Geometry<T>> for $geom_type
@@ -555,4 +581,75 @@ mod test {
         let pt: Point = (0.5, 0.5).into();
         assert!(!tri.contains(&pt));
     }
+
+    #[test]
+    fn rect_contains_geometries_on_its_boundary() {
+        let rect = Rect::new(coord! { x: 0, y: 0 }, coord! { x: 4, y: 4 });
+        assert!(rect.contains(&Line::from([(0, 0), (4, 4)])));
+        assert!(rect.contains(&Line::from([(0, 1), (2, 4)])));
+        // along one side, or outside
+        assert!(!rect.contains(&Line::from([(0, 1), (0, 3)])));
+        assert!(!rect.contains(&Line::from([(1, 1), (5, 1)])));
+        // around two sides, and then inwards
+        let mut line_string = LineString::from(vec![(0, 2), (0, 4), (2, 4)]);
+        assert!(!rect.contains(&line_string));
+        line_string.0.push(coord! { x: 2, y: 2 });
+        assert!(rect.contains(&line_string));
+        assert!(!rect.contains(&LineString::<i32>::new(vec![])));
+
+        let triangle = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 4, y: 0 },
+            coord! { x: 4, y: 4 },
+        );
+        assert!(rect.contains(&triangle));
+        let flat = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 2, y: 0 },
+            coord! { x: 4, y: 0 },
+        );
+        assert!(!rect.contains(&flat));
+    }
+
+    #[test]
+    fn triangle_contains_geometries_on_its_boundary() {
+        let triangle = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 4, y: 0 },
+            coord! { x: 0, y: 4 },
+        );
+        assert!(triangle.contains(&Line::from([(0, 0), (2, 2)])));
+        assert!(!triangle.contains(&Line::from([(1, 3), (3, 1)])));
+        assert!(!triangle.contains(&Line::from([(0, 0), (3, 3)])));
+        let line_string = LineString::from(vec![(0, 2), (0, 0), (2, 0)]);
+        assert!(!triangle.contains(&line_string));
+        assert!(triangle.contains(&LineString::from(vec![(0, 2), (2, 0), (1, 1)])));
+
+        assert!(triangle.contains(&triangle));
+        assert!(triangle.contains(&Rect::new(coord! { x: 0, y: 0 }, coord! { x: 2, y: 2 })));
+        assert!(!triangle.contains(&Rect::new(coord! { x: 1, y: 1 }, coord! { x: 3, y: 3 })));
+        // a degenerate rect is a segment, here along an edge
+        assert!(!triangle.contains(&Rect::new(coord! { x: 0, y: 0 }, coord! { x: 0, y: 2 })));
+    }
+
+    #[test]
+    fn integer_geometries_contain_multi_points() {
+        use crate::{polygon, MultiPoint};
+
+        let square = polygon![(x: 0i32, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)];
+        let rect = Rect::new(coord! { x: 0i64, y: 0 }, coord! { x: 4, y: 4 });
+        let line = Line::new(coord! { x: 0i32, y: 0 }, coord! { x: 4, y: 4 });
+
+        // on the boundary, and inside
+        let points = MultiPoint::from(vec![(0i32, 2), (2, 2)]);
+        assert!(square.contains(&points));
+        assert!(line.contains(&MultiPoint::from(vec![(2i32, 2)])));
+        // only on the boundary
+        let points = MultiPoint::from(vec![(0i32, 2), (4, 2)]);
+        assert!(!square.contains(&points));
+        // one outside
+        let points = MultiPoint::from(vec![(2i64, 2), (5, 2)]);
+        assert!(!rect.contains(&points));
+        assert!(!square.contains(&MultiPoint::<i32>::new(vec![])));
+    }
 }
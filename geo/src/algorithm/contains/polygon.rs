@@ -1,4 +1,6 @@
-use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
+use super::{
+    impl_contains_from_relate, impl_contains_geometry_for, impl_contains_multi_point_for, Contains,
+};
 use crate::geometry::*;
 use crate::Relate;
 use crate::{GeoFloat, GeoNum};
@@ -26,9 +28,34 @@ where
     }
 }
 
-impl_contains_from_relate!(Polygon<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_from_relate!(Polygon<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_contains_multi_point_for!(Polygon<T>);
 impl_contains_geometry_for!(Polygon<T>);
 
+// ┌────────────────────────────────┐
+// │ Implementations for PolygonRef │
+// └────────────────────────────────┘
+
+impl<'a, T> Contains<Coord<T>> for PolygonRef<'a, T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, coord: &Coord<T>) -> bool {
+        use crate::coordinate_position::{CoordPos, CoordinatePosition};
+
+        self.coordinate_position(coord) == CoordPos::Inside
+    }
+}
+
+impl<'a, T> Contains<Point<T>> for PolygonRef<'a, T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, p: &Point<T>) -> bool {
+        self.contains(&p.0)
+    }
+}
+
 // ┌──────────────────────────────────┐
 // │ Implementations for MultiPolygon │
 // └──────────────────────────────────┘
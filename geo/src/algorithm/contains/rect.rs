@@ -1,6 +1,8 @@
-use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
+use super::{
+    impl_contains_from_relate, impl_contains_geometry_for, impl_contains_multi_point_for, Contains,
+};
 use crate::geometry::*;
-use crate::{CoordNum, GeoFloat};
+use crate::{CoordNum, GeoFloat, GeoNum, Intersects};
 
 // ┌──────────────────────────┐
 // │ Implementations for Rect │
@@ -41,5 +43,58 @@ where
     }
 }
 
-impl_contains_from_relate!(Rect<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Triangle<T>]);
+// A geometry within the closed rect has some point in its interior unless it runs along one of
+// the sides, so none of these need the general `Relate` machinery.
+impl<T> Contains<Line<T>> for Rect<T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, line: &Line<T>) -> bool {
+        self.intersects(&line.start) && self.intersects(&line.end) && !lies_along_side(self, line)
+    }
+}
+
+impl<T> Contains<LineString<T>> for Rect<T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, linestring: &LineString<T>) -> bool {
+        match linestring.0.as_slice() {
+            // Empty linestring has no interior, and not
+            // contained in anything.
+            [] => false,
+            [coord] => self.contains(coord),
+            coords => {
+                coords.iter().all(|coord| self.intersects(coord))
+                    && linestring.lines().any(|line| !lies_along_side(self, &line))
+            }
+        }
+    }
+}
+
+impl<T> Contains<Triangle<T>> for Rect<T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, triangle: &Triangle<T>) -> bool {
+        // at most two edges of a non-degenerate triangle can run along the sides
+        triangle
+            .to_array()
+            .iter()
+            .all(|coord| self.intersects(coord))
+            && triangle
+                .to_lines()
+                .iter()
+                .any(|edge| !lies_along_side(self, edge))
+    }
+}
+
+fn lies_along_side<T: CoordNum>(rect: &Rect<T>, line: &Line<T>) -> bool {
+    let (min, max) = (rect.min(), rect.max());
+    (line.start.x == line.end.x && (line.start.x == min.x || line.start.x == max.x))
+        || (line.start.y == line.end.y && (line.start.y == min.y || line.start.y == max.y))
+}
+
+impl_contains_from_relate!(Rect<T>, [Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>]);
+impl_contains_multi_point_for!(Rect<T>);
 impl_contains_geometry_for!(Rect<T>);
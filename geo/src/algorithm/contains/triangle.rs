@@ -1,6 +1,9 @@
-use super::{impl_contains_from_relate, impl_contains_geometry_for, Contains};
+use super::{
+    impl_contains_from_relate, impl_contains_geometry_for, impl_contains_multi_point_for, Contains,
+};
 use crate::geometry::*;
-use crate::{GeoFloat, GeoNum};
+use crate::kernels::{Kernel, Orientation};
+use crate::{GeoFloat, GeoNum, Intersects};
 
 // ┌──────────────────────────────┐
 // │ Implementations for Triangle │
@@ -11,9 +14,11 @@ where
     T: GeoNum,
 {
     fn contains(&self, coord: &Coord<T>) -> bool {
-        let ls = LineString::new(vec![self.0, self.1, self.2, self.0]);
-        use crate::utils::{coord_pos_relative_to_ring, CoordPos};
-        coord_pos_relative_to_ring(*coord, &ls) == CoordPos::Inside
+        // strictly on the same side of all three edges, which a degenerate triangle never is
+        let orientation = T::Ker::orient2d(self.0, self.1, *coord);
+        orientation != Orientation::Collinear
+            && T::Ker::orient2d(self.1, self.2, *coord) == orientation
+            && T::Ker::orient2d(self.2, self.0, *coord) == orientation
     }
 }
 
@@ -26,5 +31,82 @@ where
     }
 }
 
-impl_contains_from_relate!(Triangle<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+// A geometry within the closed triangle has some point in its interior unless it runs along one
+// of the edges, so none of these need the general `Relate` machinery.
+impl<T> Contains<Line<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, line: &Line<T>) -> bool {
+        self.intersects(&line.start) && self.intersects(&line.end) && !lies_along_edge(self, line)
+    }
+}
+
+impl<T> Contains<LineString<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, linestring: &LineString<T>) -> bool {
+        match linestring.0.as_slice() {
+            // Empty linestring has no interior, and not
+            // contained in anything.
+            [] => false,
+            [coord] => self.contains(coord),
+            coords => {
+                coords.iter().all(|coord| self.intersects(coord))
+                    && linestring.lines().any(|line| !lies_along_edge(self, &line))
+            }
+        }
+    }
+}
+
+impl<T> Contains<Rect<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, rect: &Rect<T>) -> bool {
+        // the four sides of a rect can't all run along the three edges, unless it's degenerate
+        rect.to_lines()
+            .iter()
+            .all(|side| self.intersects(&side.start))
+            && rect
+                .to_lines()
+                .iter()
+                .any(|side| !lies_along_edge(self, side))
+    }
+}
+
+impl<T> Contains<Triangle<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn contains(&self, triangle: &Triangle<T>) -> bool {
+        if !triangle
+            .to_array()
+            .iter()
+            .all(|coord| self.intersects(coord))
+        {
+            return false;
+        }
+        if T::Ker::orient2d(triangle.0, triangle.1, triangle.2) == Orientation::Collinear {
+            triangle
+                .to_lines()
+                .iter()
+                .any(|edge| !lies_along_edge(self, edge))
+        } else {
+            // the interior of `triangle` is then inside `self`
+            true
+        }
+    }
+}
+
+fn lies_along_edge<T: GeoNum>(triangle: &Triangle<T>, line: &Line<T>) -> bool {
+    triangle.to_lines().iter().any(|edge| {
+        T::Ker::orient2d(edge.start, edge.end, line.start) == Orientation::Collinear
+            && T::Ker::orient2d(edge.start, edge.end, line.end) == Orientation::Collinear
+    })
+}
+
+impl_contains_from_relate!(Triangle<T>, [Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>]);
+impl_contains_multi_point_for!(Triangle<T>);
 impl_contains_geometry_for!(Triangle<T>);
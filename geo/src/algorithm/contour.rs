@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::orient::{Direction, Orient};
+use crate::{
+    AffineTransform, Coord, GeoFloat, Intersects, LineString, MultiLineString, MultiPolygon,
+    Polygon,
+};
+
+/// A grid of values sampled at regularly spaced nodes, from which isolines and isobands can be
+/// extracted using the [marching squares](https://en.wikipedia.org/wiki/Marching_squares)
+/// algorithm.
+///
+/// Values are stored in row-major order. The node at column `col` and row `row` is located at
+/// `(col, row)`, mapped through the grid's transform (the identity by default). A raster with a
+/// GDAL-style geotransform `[x0, dx, rx, y0, ry, dy]` corresponds to
+/// `AffineTransform::new(dx, rx, x0, ry, dy, y0)`.
+///
+/// Contours are linearly interpolated along the edges of each cell. In ambiguous "saddle" cells,
+/// where a level crosses all four edges, the average of the cell's corners decides how the
+/// crossings are connected. Cells with a `NaN` corner are treated as having no data, and produce
+/// no contours.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{AffineTransform, Area, ContourGrid};
+///
+/// #[rustfmt::skip]
+/// let grid = ContourGrid::new(vec![
+///     0., 0., 0.,
+///     0., 2., 0.,
+///     0., 0., 0.,
+/// ], 3, 3)
+/// .with_transform(AffineTransform::scale(10., 10., (0., 0.)));
+///
+/// let isolines = grid.isolines(1.);
+/// assert_eq!(isolines.0.len(), 1);
+/// assert!(isolines.0[0].is_closed());
+///
+/// let isobands = grid.isobands(1., 3.);
+/// assert_eq!(isobands.unsigned_area(), 50.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContourGrid<T: GeoFloat> {
+    values: Vec<T>,
+    width: usize,
+    height: usize,
+    transform: AffineTransform<T>,
+}
+
+impl<T: GeoFloat> ContourGrid<T> {
+    /// Create a grid with `width` columns and `height` rows from values in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values is not `width * height`.
+    pub fn new(values: Vec<T>, width: usize, height: usize) -> Self {
+        assert_eq!(
+            values.len(),
+            width * height,
+            "a grid must have width * height values"
+        );
+        ContourGrid {
+            values,
+            width,
+            height,
+            transform: AffineTransform::identity(),
+        }
+    }
+
+    /// Map the grid's nodes from `(column, row)` to output coordinates with `transform`.
+    pub fn with_transform(mut self, transform: AffineTransform<T>) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// The lines along which the interpolated values equal `level`.
+    ///
+    /// Lines are oriented with values greater than `level` on their left (in grid space), and
+    /// are closed when they do not reach the edge of the grid or a cell without data.
+    pub fn isolines(&self, level: T) -> MultiLineString<T> {
+        let band = Band {
+            lower: level,
+            upper: None,
+        };
+        let (_, chords) = self.trace(&band);
+        chain(chords)
+            .into_iter()
+            .map(|line| self.line_string(&band, &line))
+            .collect()
+    }
+
+    /// The areas where the interpolated values are greater than or equal to `lower`, and less
+    /// than `upper`.
+    ///
+    /// # Panics
+    ///
+    /// If `lower` is not less than `upper`.
+    pub fn isobands(&self, lower: T, upper: T) -> MultiPolygon<T> {
+        assert!(
+            lower < upper,
+            "the lower bound must be less than the upper bound"
+        );
+        let band = Band {
+            lower,
+            upper: Some(upper),
+        };
+        let (edges, _) = self.trace(&band);
+
+        // Cells are traced counter-clockwise in grid space, so after removing the edges shared
+        // between cells, exteriors are counter-clockwise and interiors clockwise.
+        let mut shells: Vec<(LineString<T>, T, Vec<LineString<T>>)> = vec![];
+        let mut holes = vec![];
+        for ring in chain(edges) {
+            let ring: LineString<T> = ring.iter().map(|v| self.position(&band, *v)).collect();
+            let area = twice_signed_ring_area(&ring.0);
+            if area > T::zero() {
+                shells.push((ring, area, vec![]));
+            } else if area < T::zero() {
+                holes.push(ring);
+            }
+        }
+        for hole in holes {
+            let coord = hole.0[0];
+            let shell = shells
+                .iter_mut()
+                .filter(|(exterior, _, _)| {
+                    Polygon::new(exterior.clone(), vec![]).intersects(&coord)
+                })
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+            if let Some((_, _, interiors)) = shell {
+                interiors.push(hole);
+            }
+        }
+
+        shells
+            .into_iter()
+            .map(|(exterior, _, interiors)| {
+                Polygon::new(
+                    self.transform_ring(exterior),
+                    interiors
+                        .into_iter()
+                        .map(|ring| self.transform_ring(ring))
+                        .collect(),
+                )
+                // the transform may have flipped the orientation of the rings
+                .orient(Direction::Default)
+            })
+            .collect()
+    }
+
+    fn node(&self, index: usize) -> Coord<T> {
+        Coord {
+            x: T::from(index % self.width).unwrap(),
+            y: T::from(index / self.width).unwrap(),
+        }
+    }
+
+    // Grid space position of a vertex.
+    fn position(&self, band: &Band<T>, vertex: Vertex) -> Coord<T> {
+        match vertex {
+            Vertex::Node(index) => self.node(index),
+            Vertex::Crossing(a, b, upper) => {
+                let level = band.level(upper);
+                let t = (level - self.values[a]) / (self.values[b] - self.values[a]);
+                let (start, end) = (self.node(a), self.node(b));
+                start + (end - start) * t
+            }
+        }
+    }
+
+    fn transform_ring(&self, ring: LineString<T>) -> LineString<T> {
+        ring.into_iter().map(|c| self.transform.apply(c)).collect()
+    }
+
+    fn line_string(&self, band: &Band<T>, vertices: &[Vertex]) -> LineString<T> {
+        vertices
+            .iter()
+            .map(|v| self.transform.apply(self.position(band, *v)))
+            .collect()
+    }
+
+    // The vertex where a level crosses the edge between two nodes.
+    fn crossing(&self, band: &Band<T>, a: usize, b: usize, upper: bool) -> Vertex {
+        // order the nodes so that both cells sharing the edge produce the same vertex
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        let level = band.level(upper);
+        if self.values[a] == level {
+            Vertex::Node(a)
+        } else if self.values[b] == level {
+            Vertex::Node(b)
+        } else {
+            Vertex::Crossing(a, b, upper)
+        }
+    }
+
+    // Trace the part of every cell lying within `band`. Returns the directed boundary edges of
+    // the traced area, without the edges shared by neighbouring cells, and the contour segments
+    // ("chords") crossing the interior of cells.
+    fn trace(&self, band: &Band<T>) -> (Segments, Segments) {
+        let mut edges: Vec<Option<(Vertex, Vertex)>> = vec![];
+        let mut edge_indices: HashMap<(Vertex, Vertex), usize> = HashMap::new();
+        let mut chords = vec![];
+
+        for row in 0..self.height.saturating_sub(1) {
+            for col in 0..self.width.saturating_sub(1) {
+                let i = row * self.width + col;
+                let corners = [i, i + 1, i + 1 + self.width, i + self.width];
+                if corners.iter().any(|&c| self.values[c].is_nan()) {
+                    continue;
+                }
+                for ring in self.trace_cell(band, corners, &mut chords) {
+                    for (k, &from) in ring.iter().enumerate() {
+                        let edge = (from, ring[(k + 1) % ring.len()]);
+                        match edge_indices.remove(&(edge.1, edge.0)) {
+                            Some(index) => edges[index] = None,
+                            None => {
+                                edge_indices.insert(edge, edges.len());
+                                edges.push(Some(edge));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        (edges.into_iter().flatten().collect(), chords)
+    }
+
+    // Trace the rings bounding the part of a cell within `band`, given its corners in
+    // counter-clockwise order.
+    fn trace_cell(
+        &self,
+        band: &Band<T>,
+        corners: [usize; 4],
+        chords: &mut Segments,
+    ) -> Vec<Vec<Vertex>> {
+        // Walk around the cell, collecting the corners within the band, and the crossings of
+        // its levels where the walk enters or exits the band.
+        let mut boundary: Vec<(Vertex, Option<Crossing>)> = vec![];
+        for (k, &a) in corners.iter().enumerate() {
+            let b = corners[(k + 1) % 4];
+            let (class_a, class_b) = (band.classify(self.values[a]), band.classify(self.values[b]));
+            if class_a == Class::Inside {
+                boundary.push((Vertex::Node(a), None));
+            }
+            let crossings: &[(bool, bool)] = match (class_a, class_b) {
+                (Class::Below, Class::Inside) => &[(false, false)],
+                (Class::Inside, Class::Below) => &[(false, true)],
+                (Class::Inside, Class::Above) => &[(true, true)],
+                (Class::Above, Class::Inside) => &[(true, false)],
+                (Class::Below, Class::Above) => &[(false, false), (true, true)],
+                (Class::Above, Class::Below) => &[(true, false), (false, true)],
+                _ => &[],
+            };
+            for &(upper, exit) in crossings {
+                let vertex = self.crossing(band, a, b, upper);
+                boundary.push((vertex, Some(Crossing { upper, exit })));
+            }
+        }
+
+        // Connect every exit to an entry of the same level. With two crossings of a level there
+        // is only one choice; with four (a saddle), the contour is placed so that the center of
+        // the cell falls on the same side of the level as the average of its corners.
+        let len = boundary.len();
+        let center = corners
+            .iter()
+            .fold(T::zero(), |sum, &c| sum + self.values[c])
+            / T::from(4).unwrap();
+        let mut next: Vec<usize> = (1..=len).map(|p| p % len).collect();
+        for (p, (vertex, crossing)) in boundary.iter().enumerate() {
+            let crossing = match crossing {
+                Some(crossing) if crossing.exit => crossing,
+                _ => continue,
+            };
+            let forward = if crossing.upper {
+                center < band.level(true)
+            } else {
+                center >= band.lower
+            };
+            let entry = (1..len)
+                .map(|k| {
+                    if forward {
+                        (p + k) % len
+                    } else {
+                        (p + len - k) % len
+                    }
+                })
+                .find(|&q| matches!(boundary[q].1, Some(c) if c.upper == crossing.upper && !c.exit))
+                .expect("crossings of a level alternate between exits and entries");
+            next[p] = entry;
+            if *vertex != boundary[entry].0 {
+                chords.push((*vertex, boundary[entry].0));
+            }
+        }
+
+        let mut visited = vec![false; len];
+        let mut rings = vec![];
+        for start in 0..len {
+            if visited[start] {
+                continue;
+            }
+            let mut ring = vec![];
+            let mut p = start;
+            while !visited[p] {
+                visited[p] = true;
+                ring.push(boundary[p].0);
+                p = next[p];
+            }
+            ring.dedup();
+            while ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            if ring.len() >= 3 {
+                rings.push(ring);
+            }
+        }
+        rings
+    }
+}
+
+// A vertex of a contour, identified by its position in the grid so that neighbouring cells
+// produce identical vertices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Vertex {
+    // a node, by its index in the grid
+    Node(usize),
+    // the crossing of the lower (`false`) or upper (`true`) level of a band along the edge
+    // between two nodes, the lowest index first
+    Crossing(usize, usize, bool),
+}
+
+// Directed segments between vertices.
+type Segments = Vec<(Vertex, Vertex)>;
+
+#[derive(Debug, Clone, Copy)]
+struct Crossing {
+    upper: bool,
+    exit: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Below,
+    Inside,
+    Above,
+}
+
+// The range of values `lower <= value < upper`, unbounded above when tracing isolines.
+struct Band<T> {
+    lower: T,
+    upper: Option<T>,
+}
+
+impl<T: GeoFloat> Band<T> {
+    fn level(&self, upper: bool) -> T {
+        if upper {
+            self.upper.expect("isolines have no upper level")
+        } else {
+            self.lower
+        }
+    }
+
+    fn classify(&self, value: T) -> Class {
+        if value < self.lower {
+            Class::Below
+        } else if matches!(self.upper, Some(upper) if value >= upper) {
+            Class::Above
+        } else {
+            Class::Inside
+        }
+    }
+}
+
+// Join directed edges into chains, preferring to start at vertices with more outgoing than
+// incoming edges, so that open chains are not split. Closed chains end with their first vertex.
+fn chain(edges: Segments) -> Vec<Vec<Vertex>> {
+    let mut outgoing: HashMap<Vertex, Vec<Vertex>> = HashMap::new();
+    let mut balance: HashMap<Vertex, isize> = HashMap::new();
+    // vertices in order of appearance, for a deterministic output
+    let mut order = vec![];
+    for (from, to) in edges {
+        outgoing
+            .entry(from)
+            .or_insert_with(|| {
+                order.push(from);
+                vec![]
+            })
+            .push(to);
+        *balance.entry(from).or_insert(0) += 1;
+        *balance.entry(to).or_insert(0) -= 1;
+    }
+    for targets in outgoing.values_mut() {
+        targets.reverse();
+    }
+
+    let starts: Vec<Vertex> = order
+        .iter()
+        .filter(|v| balance[*v] > 0)
+        .chain(order.iter())
+        .copied()
+        .collect();
+    let mut chains = vec![];
+    for start in starts {
+        while matches!(outgoing.get(&start), Some(targets) if !targets.is_empty()) {
+            let mut chain = vec![start];
+            let mut current = start;
+            while let Some(next) = outgoing.get_mut(&current).and_then(|targets| targets.pop()) {
+                chain.push(next);
+                current = next;
+                if current == start {
+                    break;
+                }
+            }
+            chains.push(chain);
+        }
+    }
+    chains
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, Area, Winding};
+
+    fn peak() -> ContourGrid<f64> {
+        #[rustfmt::skip]
+        let values = vec![
+            0., 0., 0.,
+            0., 2., 0.,
+            0., 0., 0.,
+        ];
+        ContourGrid::new(values, 3, 3)
+    }
+
+    #[test]
+    fn closed_isoline() {
+        let isolines = peak().isolines(1.);
+        assert_eq!(isolines.0.len(), 1);
+        let ring = &isolines.0[0];
+        assert!(ring.is_closed());
+        assert_eq!(ring.0.len(), 5);
+        assert!(ring.is_ccw());
+        assert_relative_eq!(Polygon::new(ring.clone(), vec![]).unsigned_area(), 0.5);
+    }
+
+    #[test]
+    fn open_isoline() {
+        #[rustfmt::skip]
+        let grid = ContourGrid::new(vec![
+            0., 1., 2.,
+            0., 1., 2.,
+        ], 3, 2);
+        let isolines = grid.isolines(0.5);
+        assert_eq!(
+            isolines.0,
+            vec![line_string![(x: 0.5, y: 1.), (x: 0.5, y: 0.)]]
+        );
+        // a level running through nodes produces a single line
+        let isolines = grid.isolines(1.);
+        assert_eq!(isolines.0.len(), 1);
+        assert_eq!(isolines.0[0].0.len(), 2);
+    }
+
+    #[test]
+    fn isobands_with_hole() {
+        let inner = peak().isobands(1., 3.);
+        assert_eq!(inner.0.len(), 1);
+        assert_relative_eq!(inner.unsigned_area(), 0.5);
+
+        let outer = peak().isobands(-1., 1.);
+        assert_eq!(outer.0.len(), 1);
+        assert_eq!(outer.0[0].interiors().len(), 1);
+        assert_relative_eq!(outer.unsigned_area(), 3.5);
+    }
+
+    #[test]
+    fn saddle() {
+        #[rustfmt::skip]
+        let grid = ContourGrid::new(vec![
+            1., 0.,
+            0., 1.,
+        ], 2, 2);
+        // the center is within the band, so the corners are joined
+        let joined = grid.isobands(0.5, 2.);
+        assert_eq!(joined.0.len(), 1);
+        assert_relative_eq!(joined.unsigned_area(), 0.75);
+        // the center is below the band, so the corners are separate
+        let separate = grid.isobands(0.6, 2.);
+        assert_eq!(separate.0.len(), 2);
+        assert_relative_eq!(separate.unsigned_area(), 0.16, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn transformed() {
+        let grid = peak().with_transform(AffineTransform::new(10., 0., 100., 0., -10., 50.));
+        let bands = grid.isobands(1., 3.);
+        assert_relative_eq!(bands.unsigned_area(), 50.);
+        assert!(bands.0[0].exterior().is_ccw());
+    }
+
+    #[test]
+    fn missing_data() {
+        #[rustfmt::skip]
+        let grid = ContourGrid::new(vec![
+            f64::NAN, 1., 1.,
+            1., 1., 1.,
+            1., 1., 1.,
+        ], 3, 3);
+        assert_relative_eq!(grid.isobands(0., 2.).unsigned_area(), 3.);
+        assert!(grid.isolines(0.5).0.is_empty());
+    }
+}
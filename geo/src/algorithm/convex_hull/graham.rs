@@ -1,6 +1,7 @@
 use super::{swap_remove_to_first, trivial_hull};
 use crate::kernels::*;
 use crate::{Coord, GeoNum, LineString};
+use alloc::vec::Vec;
 
 /// The [Graham's scan] algorithm to compute the convex hull
 /// of a collection of points. This algorithm is less
@@ -32,7 +33,7 @@ where
 
     // Find lexicographically least point and add to hull
     use crate::utils::least_index;
-    use std::cmp::Ordering;
+    use core::cmp::Ordering;
     let min_idx = least_index(points);
     let head = swap_remove_to_first(&mut points, min_idx);
     output.push(*head);
@@ -1,8 +1,9 @@
 use crate::geometry::*;
 use crate::{coord, GeoNum, GeometryCow};
-use crate::{BoundingRect, HasDimensions, Intersects};
+use crate::{BoundingRect, Contains, HasDimensions, Intersects};
 
 /// The position of a `Coord` relative to a `Geometry`
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum CoordPos {
     OnBoundary,
@@ -182,9 +183,11 @@ where
         is_inside: &mut bool,
         boundary_count: &mut usize,
     ) {
-        // PERF TODO: I'm sure there's a better way to calculate than converting to a polygon
-        self.to_polygon()
-            .calculate_coordinate_position(coord, is_inside, boundary_count);
+        if self.contains(coord) {
+            *is_inside = true;
+        } else if self.intersects(coord) {
+            *boundary_count += 1;
+        }
     }
 }
 
@@ -199,9 +202,11 @@ where
         is_inside: &mut bool,
         boundary_count: &mut usize,
     ) {
-        // PERF TODO: I'm sure there's a better way to calculate than converting to a polygon
-        self.to_polygon()
-            .calculate_coordinate_position(coord, is_inside, boundary_count);
+        if self.contains(coord) {
+            *is_inside = true;
+        } else if self.intersects(coord) {
+            *boundary_count += 1;
+        }
     }
 }
 
@@ -242,27 +247,71 @@ where
             return;
         }
 
-        match coord_pos_relative_to_ring(*coord, self.exterior()) {
-            CoordPos::Outside => {}
-            CoordPos::OnBoundary => {
-                *boundary_count += 1;
-            }
-            CoordPos::Inside => {
-                for hole in self.interiors() {
-                    match coord_pos_relative_to_ring(*coord, hole) {
-                        CoordPos::Outside => {}
-                        CoordPos::OnBoundary => {
-                            *boundary_count += 1;
-                            return;
-                        }
-                        CoordPos::Inside => {
-                            return;
-                        }
+        polygon_coord_pos(
+            *coord,
+            &self.exterior().0,
+            self.interiors().iter().map(|hole| &hole.0[..]),
+            is_inside,
+            boundary_count,
+        );
+    }
+}
+
+impl<'a, T> CoordinatePosition for PolygonRef<'a, T>
+where
+    T: GeoNum,
+{
+    type Scalar = T;
+    fn calculate_coordinate_position(
+        &self,
+        coord: &Coord<T>,
+        is_inside: &mut bool,
+        boundary_count: &mut usize,
+    ) {
+        match self.bounding_rect() {
+            Some(rect) if rect.intersects(coord) => {}
+            _ => return,
+        }
+
+        polygon_coord_pos(
+            *coord,
+            self.exterior().0,
+            self.interiors().iter().map(|hole| hole.0),
+            is_inside,
+            boundary_count,
+        );
+    }
+}
+
+fn polygon_coord_pos<'a, T>(
+    coord: Coord<T>,
+    exterior: &[Coord<T>],
+    interiors: impl Iterator<Item = &'a [Coord<T>]>,
+    is_inside: &mut bool,
+    boundary_count: &mut usize,
+) where
+    T: GeoNum + 'a,
+{
+    match ring_coord_pos(coord, exterior) {
+        CoordPos::Outside => {}
+        CoordPos::OnBoundary => {
+            *boundary_count += 1;
+        }
+        CoordPos::Inside => {
+            for hole in interiors {
+                match ring_coord_pos(coord, hole) {
+                    CoordPos::Outside => {}
+                    CoordPos::OnBoundary => {
+                        *boundary_count += 1;
+                        return;
+                    }
+                    CoordPos::Inside => {
+                        return;
                     }
                 }
-                // the coord is *outside* the interior holes, so it's *inside* the polygon
-                *is_inside = true;
             }
+            // the coord is *outside* the interior holes, so it's *inside* the polygon
+            *is_inside = true;
         }
     }
 }
@@ -346,6 +395,13 @@ impl<'a, T: GeoNum> CoordinatePosition for GeometryCow<'a, T> {
 /// Calculate the position of a `Coord` relative to a
 /// closed `LineString`.
 pub fn coord_pos_relative_to_ring<T>(coord: Coord<T>, linestring: &LineString<T>) -> CoordPos
+where
+    T: GeoNum,
+{
+    ring_coord_pos(coord, &linestring.0)
+}
+
+fn ring_coord_pos<T>(coord: Coord<T>, ring: &[Coord<T>]) -> CoordPos
 where
     T: GeoNum,
 {
@@ -354,16 +410,16 @@ where
     //
     // See: https://en.wikipedia.org/wiki/Point_in_polygon
 
-    debug_assert!(linestring.is_closed());
+    debug_assert!(ring.first() == ring.last());
 
     // LineString without points
-    if linestring.0.is_empty() {
+    if ring.is_empty() {
         return CoordPos::Outside;
     }
-    if linestring.0.len() == 1 {
+    if ring.len() == 1 {
         // If LineString has one point, it will not generate
         // any lines.  So, we handle this edge case separately.
-        return if coord == linestring.0[0] {
+        return if coord == ring[0] {
             CoordPos::OnBoundary
         } else {
             CoordPos::Outside
@@ -371,59 +427,11 @@ where
     }
 
     let mut crossings = 0;
-    for line in linestring.lines() {
-        // Check if coord lies on the line
-        if line.intersects(&coord) {
-            return CoordPos::OnBoundary;
-        }
-
-        // Ignore if the line is strictly to the left of the coord.
-        let max_x = if line.start.x < line.end.x {
-            line.end.x
-        } else {
-            line.start.x
-        };
-        if max_x < coord.x {
-            continue;
-        }
-
-        // Ignore if line is horizontal. This includes an
-        // edge case where the ray would intersect a
-        // horizontal segment of the ring infinitely many
-        // times, and is irrelevant for the calculation.
-        if line.start.y == line.end.y {
-            continue;
-        }
-
-        // Ignore if the intersection of the line is
-        // possibly at the beginning/end of the line, and
-        // the line lies below the ray. This is to
-        // prevent a double counting when the ray passes
-        // through a vertex of the polygon.
-        //
-        // The below logic handles two cases:
-        //   1. if the ray enters/exits the polygon
-        //      at the point of intersection
-        //   2. if the ray touches a vertex,
-        //      but doesn't enter/exit at that point
-        if (line.start.y == coord.y && line.end.y < coord.y)
-            || (line.end.y == coord.y && line.start.y < coord.y)
-        {
-            continue;
-        }
-
-        // Otherwise, check if ray intersects the line
-        // segment. Enough to consider ray upto the max_x
-        // coordinate of the current segment.
-        let ray = Line::new(
-            coord,
-            coord! {
-                x: max_x,
-                y: coord.y,
-            },
-        );
-        if ray.intersects(&line) {
-            crossings += 1;
+    for line in ring.windows(2).map(|w| Line::new(w[0], w[1])) {
+        match ray_crossing(coord, line) {
+            None => return CoordPos::OnBoundary,
+            Some(true) => crossings += 1,
+            Some(false) => {}
         }
     }
     if crossings % 2 == 1 {
@@ -433,6 +441,67 @@ where
     }
 }
 
+/// How a segment of a ring relates to `coord`, in the ray-tracing algorithm of
+/// `coord_pos_relative_to_ring`: `None` if `coord` lies on the segment, otherwise whether the
+/// horizontal ray from `coord` to positive infinity crosses it, counting a crossing at a vertex
+/// for only one of the segments meeting there.
+pub(crate) fn ray_crossing<T>(coord: Coord<T>, line: Line<T>) -> Option<bool>
+where
+    T: GeoNum,
+{
+    // Check if coord lies on the line
+    if line.intersects(&coord) {
+        return None;
+    }
+
+    // Ignore if the line is strictly to the left of the coord.
+    let max_x = if line.start.x < line.end.x {
+        line.end.x
+    } else {
+        line.start.x
+    };
+    if max_x < coord.x {
+        return Some(false);
+    }
+
+    // Ignore if line is horizontal. This includes an
+    // edge case where the ray would intersect a
+    // horizontal segment of the ring infinitely many
+    // times, and is irrelevant for the calculation.
+    if line.start.y == line.end.y {
+        return Some(false);
+    }
+
+    // Ignore if the intersection of the line is
+    // possibly at the beginning/end of the line, and
+    // the line lies below the ray. This is to
+    // prevent a double counting when the ray passes
+    // through a vertex of the polygon.
+    //
+    // The below logic handles two cases:
+    //   1. if the ray enters/exits the polygon
+    //      at the point of intersection
+    //   2. if the ray touches a vertex,
+    //      but doesn't enter/exit at that point
+    if (line.start.y == coord.y && line.end.y < coord.y)
+        || (line.end.y == coord.y && line.start.y < coord.y)
+    {
+        return Some(false);
+    }
+
+    // Otherwise, check if ray intersects the line
+    // segment. Enough to consider ray upto the max_x
+    // coordinate of the current segment.
+    let ray = Line::new(
+        coord,
+        coord! {
+            x: max_x,
+            y: coord.y,
+        },
+    );
+    Some(ray.intersects(&line))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -732,4 +801,29 @@ mod test {
             CoordPos::Outside
         );
     }
+
+    #[test]
+    fn test_polygon_ref() {
+        use crate::PolygonRef;
+
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let view = PolygonRef::from(&poly);
+
+        for coord in [
+            coord! { x: 5., y: 5. },
+            coord! { x: 1.5, y: 1.5 },
+            coord! { x: 1., y: 1.5 },
+            coord! { x: 0., y: 5. },
+            coord! { x: 20., y: 5. },
+        ] {
+            assert_eq!(
+                view.coordinate_position(&coord),
+                poly.coordinate_position(&coord)
+            );
+            assert_eq!(view.contains(&coord), poly.contains(&coord));
+        }
+    }
 }
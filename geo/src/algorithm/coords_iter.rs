@@ -1,9 +1,11 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 use crate::geometry::*;
 use crate::{coord, CoordNum};
 
-use std::{fmt, iter, marker, slice};
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::{fmt, iter, marker, slice};
 
 type CoordinateChainOnce<T> = iter::Chain<iter::Once<Coord<T>>, iter::Once<Coord<T>>>;
 
@@ -89,6 +91,61 @@ pub trait CoordsIter<'a> {
     /// assert_eq!(None, iter.next());
     /// ```
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter;
+
+    /// Return the coordinate at `index`, or `None` if the geometry has fewer coordinates.
+    ///
+    /// Coordinates are indexed across the whole geometry, in the order of
+    /// [`coords_iter`](Self::coords_iter): the exterior of a polygon comes first, followed by
+    /// each of its interiors, and the parts of a multi-geometry follow each other. Finding a
+    /// coordinate skips whole rings and parts, rather than iterating over their coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::coords_iter::CoordsIter;
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon![
+    ///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+    ///     interiors: [[(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 1.)]],
+    /// ];
+    ///
+    /// assert_eq!(Some(geo::coord! { x: 4., y: 4. }), polygon.coord_at(2));
+    /// // the second coordinate of the interior
+    /// assert_eq!(Some(geo::coord! { x: 3., y: 1. }), polygon.coord_at(5));
+    /// assert_eq!(None, polygon.coord_at(8));
+    /// ```
+    fn coord_at(&'a self, index: usize) -> Option<Coord<Self::Scalar>> {
+        self.coords_iter().nth(index)
+    }
+
+    /// Iterate over all exterior and (if any) interior coordinates of a geometry, along with
+    /// their index as given to [`coord_at`](Self::coord_at).
+    ///
+    /// Unlike [`coords_iter`](Self::coords_iter), the iterator knows its exact length, can be
+    /// iterated from both ends, and skips coordinates with `nth` without visiting them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::coords_iter::CoordsIter;
+    /// use geo::line_string;
+    ///
+    /// let ls = line_string![(x: 1., y: 2.), (x: 23., y: 82.), (x: -1., y: 0.)];
+    ///
+    /// let mut iter = ls.indexed_coords_iter();
+    /// assert_eq!(3, iter.len());
+    /// assert_eq!(Some((2, geo::coord! { x: -1., y: 0. })), iter.next_back());
+    /// assert_eq!(Some((0, geo::coord! { x: 1., y: 2. })), iter.next());
+    /// assert_eq!(1, iter.len());
+    /// ```
+    fn indexed_coords_iter(&'a self) -> IndexedCoordsIter<'a, Self> {
+        IndexedCoordsIter {
+            geometry: self,
+            front: 0,
+            back: self.coords_count(),
+        }
+    }
 }
 
 // ┌──────────────────────────┐
@@ -109,6 +166,10 @@ impl<'a, T: CoordNum> CoordsIter<'a> for Point<T> {
         1
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        (index == 0).then(|| self.0)
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         self.coords_iter()
     }
@@ -132,6 +193,14 @@ impl<'a, T: CoordNum> CoordsIter<'a> for Line<T> {
         2
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        match index {
+            0 => Some(self.start),
+            1 => Some(self.end),
+            _ => None,
+        }
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         self.coords_iter()
     }
@@ -157,6 +226,10 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for LineString<T> {
         self.0.len()
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        self.0.get(index).copied()
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         self.coords_iter()
     }
@@ -192,6 +265,10 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for Polygon<T> {
                 .sum::<usize>()
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        coord_in_parts(iter::once(self.exterior()).chain(self.interiors()), index)
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         self.exterior().coords_iter()
     }
@@ -215,6 +292,10 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for MultiPoint<T> {
         self.0.len()
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        self.0.get(index).map(|point| point.0)
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         self.coords_iter()
     }
@@ -241,6 +322,10 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for MultiLineString<T> {
             .sum()
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        coord_in_parts(&self.0, index)
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         self.coords_iter()
     }
@@ -265,6 +350,10 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for MultiPolygon<T> {
         self.0.iter().map(|polygon| polygon.coords_count()).sum()
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        coord_in_parts(&self.0, index)
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         MapExteriorCoordsIter(self.0.iter(), marker::PhantomData).flatten()
     }
@@ -288,6 +377,10 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for GeometryCollection<T> {
         self.0.iter().map(|geometry| geometry.coords_count()).sum()
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        coord_in_parts(&self.0, index)
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         Box::new(
             self.0
@@ -361,6 +454,10 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for Triangle<T> {
         3
     }
 
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        self.to_array().get(index).copied()
+    }
+
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
         self.coords_iter()
     }
@@ -394,6 +491,9 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for Geometry<T> {
     crate::geometry_delegate_impl! {
         /// Return the number of coordinates in the `Geometry`.
         fn coords_count(&'a self) -> usize;
+
+        /// Return the coordinate at `index` in the `Geometry`.
+        fn coord_at(&'a self, index: usize) -> Option<Coord<T>>;
     }
 
     fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
@@ -422,6 +522,126 @@ impl<'a, T: CoordNum + 'a> CoordsIter<'a> for Geometry<T> {
     }
 }
 
+// ┌──────────────────────────────────┐
+// │ Implementation for LineStringRef │
+// └──────────────────────────────────┘
+
+impl<'a, 'b: 'a, T: CoordNum + 'b> CoordsIter<'a> for LineStringRef<'b, T> {
+    type Iter = LineStringIter<'b, T>;
+    type ExteriorIter = Self::Iter;
+    type Scalar = T;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.0.iter().copied()
+    }
+
+    /// Return the number of coordinates in the `LineStringRef`.
+    fn coords_count(&'a self) -> usize {
+        self.0.len()
+    }
+
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        self.0.get(index).copied()
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.coords_iter()
+    }
+}
+
+// ┌───────────────────────────────┐
+// │ Implementation for PolygonRef │
+// └───────────────────────────────┘
+
+impl<'a, 'b: 'a, T: CoordNum + 'b> CoordsIter<'a> for PolygonRef<'b, T> {
+    type Iter = iter::Chain<
+        LineStringIter<'b, T>,
+        iter::Flatten<
+            MapCoordsIter<'a, T, slice::Iter<'a, LineStringRef<'b, T>>, LineStringRef<'b, T>>,
+        >,
+    >;
+    type ExteriorIter = LineStringIter<'b, T>;
+    type Scalar = T;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        self.exterior()
+            .coords_iter()
+            .chain(MapCoordsIter(self.interiors().iter(), marker::PhantomData).flatten())
+    }
+
+    /// Return the number of coordinates in the `PolygonRef`.
+    fn coords_count(&'a self) -> usize {
+        self.exterior().coords_count()
+            + self
+                .interiors()
+                .iter()
+                .map(|i| i.coords_count())
+                .sum::<usize>()
+    }
+
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        coord_in_parts(iter::once(self.exterior()).chain(self.interiors()), index)
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        self.exterior().coords_iter()
+    }
+}
+
+// ┌────────────────────────────────┐
+// │ Implementation for GeometryRef │
+// └────────────────────────────────┘
+
+impl<'a, 'b: 'a, T: CoordNum + 'b> CoordsIter<'a> for GeometryRef<'b, T> {
+    type Iter = Box<dyn Iterator<Item = Coord<T>> + 'a>;
+    type ExteriorIter = Box<dyn Iterator<Item = Coord<T>> + 'a>;
+    type Scalar = T;
+
+    fn coords_iter(&'a self) -> Self::Iter {
+        match self {
+            GeometryRef::Point(g) => Box::new(g.coords_iter()),
+            GeometryRef::LineString(g) => Box::new(g.coords_iter()),
+            GeometryRef::Polygon(g) => Box::new(g.coords_iter()),
+            GeometryRef::MultiPoint(g) => Box::new(g.iter().copied()),
+            GeometryRef::MultiLineString(g) => Box::new(g.iter().flat_map(|l| l.coords_iter())),
+            GeometryRef::MultiPolygon(g) => Box::new(g.iter().flat_map(|p| p.coords_iter())),
+        }
+    }
+
+    /// Return the number of coordinates in the `GeometryRef`.
+    fn coords_count(&'a self) -> usize {
+        match self {
+            GeometryRef::Point(g) => g.coords_count(),
+            GeometryRef::LineString(g) => g.coords_count(),
+            GeometryRef::Polygon(g) => g.coords_count(),
+            GeometryRef::MultiPoint(g) => g.len(),
+            GeometryRef::MultiLineString(g) => g.iter().map(|l| l.coords_count()).sum(),
+            GeometryRef::MultiPolygon(g) => g.iter().map(|p| p.coords_count()).sum(),
+        }
+    }
+
+    fn coord_at(&'a self, index: usize) -> Option<Coord<T>> {
+        match self {
+            GeometryRef::Point(g) => g.coord_at(index),
+            GeometryRef::LineString(g) => g.coord_at(index),
+            GeometryRef::Polygon(g) => g.coord_at(index),
+            GeometryRef::MultiPoint(g) => g.get(index).copied(),
+            GeometryRef::MultiLineString(g) => coord_in_parts(g, index),
+            GeometryRef::MultiPolygon(g) => coord_in_parts(g, index),
+        }
+    }
+
+    fn exterior_coords_iter(&'a self) -> Self::ExteriorIter {
+        match self {
+            GeometryRef::Polygon(g) => Box::new(g.exterior_coords_iter()),
+            GeometryRef::MultiPolygon(g) => {
+                Box::new(g.iter().flat_map(|p| p.exterior_coords_iter()))
+            }
+            _ => self.coords_iter(),
+        }
+    }
+}
+
 // ┌───────────┐
 // │ Utilities │
 // └───────────┘
@@ -474,6 +694,85 @@ impl<'a, T: 'a + CoordNum, Iter1: Iterator<Item = &'a Iter2>, Iter2: CoordsIter<
     }
 }
 
+/// The coordinate at `index` across consecutive parts of a geometry, skipping whole parts.
+fn coord_in_parts<'a, G: 'a + CoordsIter<'a>>(
+    parts: impl IntoIterator<Item = &'a G>,
+    mut index: usize,
+) -> Option<Coord<G::Scalar>> {
+    for part in parts {
+        let count = part.coords_count();
+        if index < count {
+            return part.coord_at(index);
+        }
+        index -= count;
+    }
+    None
+}
+
+/// An exact-size, double-ended iterator over the coordinates of a geometry along with their
+/// index, returned by [`CoordsIter::indexed_coords_iter`].
+#[derive(Debug)]
+pub struct IndexedCoordsIter<'a, G: CoordsIter<'a> + ?Sized> {
+    geometry: &'a G,
+    /// The index of the next coordinate from the front.
+    front: usize,
+    /// One past the index of the next coordinate from the back.
+    back: usize,
+}
+
+impl<'a, G: CoordsIter<'a> + ?Sized> Clone for IndexedCoordsIter<'a, G> {
+    fn clone(&self) -> Self {
+        IndexedCoordsIter {
+            geometry: self.geometry,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<'a, G: CoordsIter<'a> + ?Sized> Iterator for IndexedCoordsIter<'a, G> {
+    type Item = (usize, Coord<G::Scalar>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let index = self.front;
+        self.front += 1;
+        self.geometry.coord_at(index).map(|coord| (index, coord))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, G: CoordsIter<'a> + ?Sized> DoubleEndedIterator for IndexedCoordsIter<'a, G> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = self.back;
+        self.geometry.coord_at(index).map(|coord| (index, coord))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<'a, G: CoordsIter<'a> + ?Sized> ExactSizeIterator for IndexedCoordsIter<'a, G> {}
+
+impl<'a, G: CoordsIter<'a> + ?Sized> iter::FusedIterator for IndexedCoordsIter<'a, G> {}
+
 // Utility to transform Geometry into Iterator<Coord>
 #[doc(hidden)]
 pub enum GeometryCoordsIter<'a, T: CoordNum + 'a> {
@@ -761,6 +1060,59 @@ mod test {
 
         assert_eq!(expected_coords, actual_coords);
     }
+    #[test]
+    fn test_coord_at() {
+        let (line_string, _) = create_line_string();
+        let (polygon, _) = create_polygon();
+        let (triangle, _) = create_triangle();
+        let geometries = vec![
+            Geometry::Point(create_point().0),
+            Geometry::Line(Line::new(coord! { x: 1., y: 2. }, coord! { x: 2., y: 3. })),
+            Geometry::LineString(line_string.clone()),
+            Geometry::Polygon(polygon.clone()),
+            Geometry::MultiPoint(MultiPoint::new(vec![create_point().0; 2])),
+            Geometry::MultiLineString(MultiLineString::new(vec![line_string.clone(); 2])),
+            Geometry::MultiPolygon(MultiPolygon::new(vec![polygon.clone(); 2])),
+            Geometry::Rect(create_rect().0),
+            Geometry::Triangle(triangle),
+        ];
+        let collection = GeometryCollection::new_from(geometries.clone());
+
+        for geometry in geometries
+            .iter()
+            .chain([&Geometry::GeometryCollection(collection)])
+        {
+            let coords = geometry.coords_iter().collect::<Vec<_>>();
+            for (index, coord) in coords.iter().enumerate() {
+                assert_eq!(Some(*coord), geometry.coord_at(index));
+            }
+            assert_eq!(None, geometry.coord_at(coords.len()));
+
+            let indexed = geometry.indexed_coords_iter();
+            assert_eq!(coords.len(), indexed.len());
+            assert_eq!(
+                coords.iter().copied().enumerate().rev().collect::<Vec<_>>(),
+                indexed.rev().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_indexed_coords_iter() {
+        let (polygon, coords) = create_polygon();
+
+        let mut iter = polygon.indexed_coords_iter();
+        assert_eq!(Some((2, coords[2])), iter.nth(2));
+        assert_eq!(Some((6, coords[6])), iter.nth_back(1));
+        assert_eq!(3, iter.len());
+        assert_eq!(
+            vec![(3, coords[3]), (4, coords[4]), (5, coords[5])],
+            iter.clone().collect::<Vec<_>>()
+        );
+        assert_eq!(None, iter.nth(3));
+        assert_eq!(None, iter.next_back());
+        assert_eq!(0, iter.len());
+    }
 
     fn create_point() -> (Point, Vec<Coord>) {
         (point!(x: 1., y: 2.), vec![coord! { x: 1., y: 2. }])
@@ -821,4 +1173,29 @@ mod test {
             ],
         )
     }
+
+    #[test]
+    fn test_borrowed_views() {
+        use crate::{GeometryRef, PolygonRef};
+
+        let poly = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        ];
+        let view = PolygonRef::from(&poly);
+        assert_eq!(
+            view.coords_iter().collect::<Vec<_>>(),
+            poly.coords_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(view.coords_count(), poly.coords_count());
+        assert_eq!(view.coord_at(5), poly.coord_at(5));
+        assert_eq!(
+            view.exterior_coords_iter().collect::<Vec<_>>(),
+            poly.exterior_coords_iter().collect::<Vec<_>>()
+        );
+
+        let geometry = GeometryRef::MultiPolygon(vec![view.clone(), view]);
+        assert_eq!(geometry.coords_count(), 16);
+        assert_eq!(geometry.coord_at(9), poly.coord_at(1));
+    }
 }
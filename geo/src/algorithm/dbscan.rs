@@ -0,0 +1,201 @@
+use crate::{GeoFloat, MultiPoint, Point, MEAN_EARTH_RADIUS};
+
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+
+/// Cluster points using [DBSCAN](https://en.wikipedia.org/wiki/DBSCAN) (density-based spatial
+/// clustering of applications with noise).
+///
+/// Based on: Ester, M., Kriegel, H., Sander, J., and Xu, X. (1996). *A density-based algorithm for
+/// discovering clusters in large spatial databases with noise.* In Proceedings of the Second
+/// International Conference on Knowledge Discovery and Data Mining, pages 226-231.
+///
+/// A point is a *core* point if at least `min_points` points (including itself) lie within
+/// `epsilon` of it. Clusters are made of core points within `epsilon` of each other, and of the
+/// *border* points within `epsilon` of a core point. All other points are *noise*.
+///
+/// Returns one label per input point, in the same order: `Some(cluster)`, with clusters numbered
+/// from 0 in the order they are discovered, or `None` for noise. A border point within reach of
+/// several clusters is assigned to the first one discovered.
+///
+/// Neighbourhoods are found using an R-tree, for a running time of `O(n log n)` for well-spread
+/// data.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Dbscan, MultiPoint};
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 0.5, y: 0.),
+///     point!(x: 0., y: 0.5),
+///     point!(x: 10., y: 10.),
+///     point!(x: 10.5, y: 10.),
+///     point!(x: 10., y: 10.5),
+///     point!(x: 5., y: 5.),
+/// ]);
+///
+/// let labels = points.dbscan(1., 3);
+/// assert_eq!(
+///     labels,
+///     vec![Some(0), Some(0), Some(0), Some(1), Some(1), Some(1), None]
+/// );
+/// ```
+pub trait Dbscan<T: GeoFloat> {
+    /// Cluster points using the euclidean distance.
+    fn dbscan(&self, epsilon: T, min_points: usize) -> Vec<Option<usize>>;
+
+    /// Cluster points using the [haversine distance](crate::HaversineDistance), with
+    /// coordinates in degrees of longitude (`x`) and latitude (`y`).
+    ///
+    /// # Units
+    ///
+    /// - `epsilon`: meters
+    fn haversine_dbscan(&self, epsilon: T, min_points: usize) -> Vec<Option<usize>>;
+}
+
+impl<T: GeoFloat> Dbscan<T> for [Point<T>] {
+    fn dbscan(&self, epsilon: T, min_points: usize) -> Vec<Option<usize>> {
+        dbscan(self.to_vec(), epsilon * epsilon, min_points)
+    }
+
+    fn haversine_dbscan(&self, epsilon: T, min_points: usize) -> Vec<Option<usize>> {
+        // Points on the unit sphere are compared by chord length, which increases with the
+        // distance along the sphere, so that neighbourhoods can be found with an R-tree.
+        let vectors = self
+            .iter()
+            .map(|point| {
+                let (lon, lat) = (point.x().to_radians(), point.y().to_radians());
+                [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+            })
+            .collect();
+        let two = T::one() + T::one();
+        let angle = (epsilon / T::from(MEAN_EARTH_RADIUS).unwrap())
+            .min(T::from(std::f64::consts::PI).unwrap());
+        let chord = two * (angle / two).sin();
+        dbscan(vectors, chord * chord, min_points)
+    }
+}
+
+impl<T: GeoFloat> Dbscan<T> for MultiPoint<T> {
+    fn dbscan(&self, epsilon: T, min_points: usize) -> Vec<Option<usize>> {
+        self.0.dbscan(epsilon, min_points)
+    }
+
+    fn haversine_dbscan(&self, epsilon: T, min_points: usize) -> Vec<Option<usize>> {
+        self.0.haversine_dbscan(epsilon, min_points)
+    }
+}
+
+fn dbscan<P>(points: Vec<P>, epsilon_2: P::Scalar, min_points: usize) -> Vec<Option<usize>>
+where
+    P: rstar::Point,
+{
+    let tree = RTree::bulk_load(
+        points
+            .iter()
+            .enumerate()
+            .map(|(idx, point)| GeomWithData::new(*point, idx))
+            .collect(),
+    );
+    let neighbours = |idx: usize| -> Vec<usize> {
+        tree.locate_within_distance(points[idx], epsilon_2)
+            .map(|neighbour| neighbour.data)
+            .collect()
+    };
+
+    let mut labels = vec![None; points.len()];
+    let mut visited = vec![false; points.len()];
+    let mut cluster = 0;
+    for idx in 0..points.len() {
+        if visited[idx] {
+            continue;
+        }
+        visited[idx] = true;
+        let mut queue = neighbours(idx);
+        if queue.len() < min_points {
+            // noise, unless it is later found to be a border point
+            continue;
+        }
+        labels[idx] = Some(cluster);
+        // expand the cluster from its core points
+        while let Some(neighbour) = queue.pop() {
+            if labels[neighbour].is_none() {
+                labels[neighbour] = Some(cluster);
+            }
+            if visited[neighbour] {
+                continue;
+            }
+            visited[neighbour] = true;
+            let reachable = neighbours(neighbour);
+            if reachable.len() >= min_points {
+                queue.extend(
+                    reachable
+                        .into_iter()
+                        .filter(|&other| !visited[other] || labels[other].is_none()),
+                );
+            }
+        }
+        cluster += 1;
+    }
+    labels
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn border_points() {
+        // a dense group at the origin, with a chain of points leading away from it
+        let points = [
+            point!(x: 0., y: 0.),
+            point!(x: 0.1, y: 0.),
+            point!(x: 0., y: 0.1),
+            point!(x: 0.1, y: 0.1),
+            point!(x: 1.05, y: 0.05),
+            point!(x: 2.1, y: 0.05),
+        ];
+        let labels = points.dbscan(1., 4);
+        // the fifth point is a border point, which doesn't extend the cluster to the last one
+        assert_eq!(
+            labels,
+            vec![Some(0), Some(0), Some(0), Some(0), Some(0), None]
+        );
+    }
+
+    #[test]
+    fn single_linkage_through_core_points() {
+        let points: Vec<_> = (0..10).map(|i| point!(x: i as f64, y: 0.)).collect();
+        let labels = points.dbscan(1., 3);
+        assert!(labels.iter().all(|label| *label == Some(0)));
+        // without enough neighbours, every point is noise
+        let labels = points.dbscan(1., 4);
+        assert!(labels.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn empty() {
+        let points = MultiPoint::<f64>::new(vec![]);
+        assert!(points.dbscan(1., 2).is_empty());
+    }
+
+    #[test]
+    fn haversine() {
+        // about 111 meters between consecutive points along the equator, and across the
+        // antimeridian
+        let points = MultiPoint::new(vec![
+            point!(x: 179.999, y: 0.),
+            point!(x: -180., y: 0.),
+            point!(x: -179.999, y: 0.),
+            point!(x: 0., y: 0.),
+            point!(x: 0.001, y: 0.),
+        ]);
+        let labels = points.haversine_dbscan(120., 2);
+        assert_eq!(labels, vec![Some(0), Some(0), Some(0), Some(1), Some(1)]);
+        let labels = points.haversine_dbscan(100., 2);
+        assert!(labels.iter().all(Option::is_none));
+    }
+}
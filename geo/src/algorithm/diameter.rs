@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+
+use crate::max_distance::farthest_pair;
+use crate::utils::lex_cmp;
+use crate::{ConvexHull, Coord, CoordNum, CoordsIter, GeoFloat};
+
+/// Calculate the diameter of a geometry: the largest euclidean distance between two of its
+/// points, which are two of its coordinates.
+///
+/// The farthest coordinates are vertices of the convex hull of the geometry, and they're found
+/// by [rotating calipers](crate::RotatingCalipers) around the hull, in linear time in the
+/// number of vertices of the hull, after computing the hull in `O(n log n)`.
+///
+/// Both methods return `None` if the geometry is empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, Diameter};
+///
+/// let line_string = line_string![
+///     (x: 0., y: 0.),
+///     (x: 3., y: 1.),
+///     (x: 1., y: 2.),
+///     (x: 4., y: 4.),
+///     (x: 2., y: 3.),
+/// ];
+///
+/// assert_eq!(line_string.diameter(), Some(32f64.sqrt()));
+/// assert_eq!(
+///     line_string.diameter_coords(),
+///     Some((coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. }))
+/// );
+/// ```
+pub trait Diameter<T: CoordNum> {
+    /// The distance between the farthest coordinates of the geometry.
+    fn diameter(&self) -> Option<T>;
+
+    /// The farthest coordinates of the geometry, ordered by their x, then y, coordinates.
+    fn diameter_coords(&self) -> Option<(Coord<T>, Coord<T>)>;
+}
+
+impl<T, G> Diameter<T> for G
+where
+    T: GeoFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T>,
+{
+    fn diameter(&self) -> Option<T> {
+        let (a, b) = self.diameter_coords()?;
+        Some((a - b).x.hypot((a - b).y))
+    }
+
+    fn diameter_coords(&self) -> Option<(Coord<T>, Coord<T>)> {
+        let hull = self.convex_hull();
+        let (a, b) = farthest_pair(&hull, &hull)?;
+        Some(match lex_cmp(&a, &b) {
+            Ordering::Greater => (b, a),
+            _ => (a, b),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, polygon, Geometry, LineString, MultiPoint, Polygon};
+
+    #[test]
+    fn against_brute_force() {
+        for n in [3, 4, 10, 101, 500] {
+            // scattered, deterministically
+            let points: MultiPoint<f64> = (0..n)
+                .map(|i| {
+                    let t = i as f64 + 0.5;
+                    point!(x: (t * 12.9898).sin() * 10., y: (t * 78.233).sin() * 7.)
+                })
+                .collect();
+            let mut expected = 0f64;
+            for a in points.iter() {
+                for b in points.iter() {
+                    expected = expected.max((a.0 - b.0).x.hypot((a.0 - b.0).y));
+                }
+            }
+            assert_eq!(points.diameter(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn geometries() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 8.), (x: 0., y: 8.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        ];
+        assert_eq!(polygon.diameter(), Some(10.));
+        assert_eq!(Geometry::from(polygon).diameter(), Some(10.));
+
+        // collinear
+        let line_string: LineString<f64> = vec![(1., 1.), (3., 3.), (2., 2.), (0., 0.)].into();
+        assert_eq!(
+            line_string.diameter_coords(),
+            Some((coord! { x: 0., y: 0. }, coord! { x: 3., y: 3. }))
+        );
+
+        assert_eq!(point!(x: 1., y: 2.).diameter(), Some(0.));
+        assert_eq!(
+            Polygon::<f64>::new(LineString::new(vec![]), vec![]).diameter(),
+            None
+        );
+    }
+}
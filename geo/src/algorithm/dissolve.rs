@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::algorithm::bool_ops::cascaded_union;
+use crate::{GeoFloat, MultiPolygon};
+
+/// Group geometries by a key, and merge the geometries of each group into a single
+/// `MultiPolygon`, e.g. to merge counties into states.
+///
+/// The geometries of a group are merged with a cascaded union: they are unioned in pairs, then
+/// the results are unioned in pairs, and so on, which is much faster than adding the geometries
+/// to a single, growing union one by one.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::dissolve::dissolve_by;
+/// use geo::{Area, Rect};
+///
+/// let counties = vec![
+///     Rect::new((0., 0.), (1., 1.)).to_polygon(),
+///     Rect::new((1., 0.), (2., 1.)).to_polygon(),
+///     Rect::new((0., -1.), (1., 0.)).to_polygon(),
+/// ];
+///
+/// let states = dissolve_by(counties, |county| {
+///     if county.exterior().0[0].y >= 0. {
+///         "north"
+///     } else {
+///         "south"
+///     }
+/// });
+/// assert_eq!(states.len(), 2);
+/// // the two northern counties are merged into a single polygon
+/// assert_eq!(states["north"].0.len(), 1);
+/// assert_eq!(states["north"].unsigned_area(), 2.);
+/// ```
+pub fn dissolve_by<T, G, K>(
+    geoms: impl IntoIterator<Item = G>,
+    key_fn: impl Fn(&G) -> K,
+) -> HashMap<K, MultiPolygon<T>>
+where
+    T: GeoFloat,
+    K: Hash + Eq,
+    G: Into<MultiPolygon<T>>,
+{
+    let mut groups: HashMap<K, Vec<MultiPolygon<T>>> = HashMap::new();
+    for geom in geoms {
+        groups.entry(key_fn(&geom)).or_default().push(geom.into());
+    }
+    groups
+        .into_iter()
+        .map(|(key, geoms)| (key, cascaded_union(geoms)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Area, Polygon, Rect};
+
+    #[test]
+    fn dissolve_grid_by_row() {
+        let cells: Vec<Polygon<f64>> = (0..4)
+            .flat_map(|row| {
+                (0..5).map(move |col| {
+                    Rect::new((col as f64, row as f64), (col as f64 + 1., row as f64 + 1.))
+                        .to_polygon()
+                })
+            })
+            .collect();
+        let rows = dissolve_by(cells, |cell| cell.exterior().0[0].y as i64);
+        assert_eq!(rows.len(), 4);
+        for row in rows.values() {
+            assert_eq!(row.0.len(), 1);
+            assert!(row.0[0].interiors().is_empty());
+            assert_relative_eq!(row.unsigned_area(), 5.);
+        }
+    }
+
+    #[test]
+    fn disjoint_and_empty_groups() {
+        let rows = dissolve_by(
+            vec![
+                Rect::new((0., 0.), (1., 1.)).to_polygon(),
+                Rect::new((5., 5.), (6., 6.)).to_polygon(),
+            ],
+            |_| 1,
+        );
+        assert_eq!(rows[&1].0.len(), 2);
+
+        let empty = dissolve_by(Vec::<Polygon<f64>>::new(), |_| 0);
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+use std::iter::Sum;
+
+use crate::{CoordFloat, EuclideanLength, LineStringZM};
+
+/// Query the elevation profile of a [`LineStringZM`], such as a GPS track, from its Z values.
+///
+/// Distances along the line string are measured horizontally, i.e. on its two-dimensional
+/// [`xy`](LineStringZM::xy) line string, as the distances along a route usually are, and the
+/// elevation is interpolated linearly between the coordinates.
+///
+/// Every method returns `None` if the line string has no Z values.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, ElevationProfile, LineStringZM};
+///
+/// let track = LineStringZM::new(
+///     line_string![(x: 0., y: 0.), (x: 100., y: 0.), (x: 100., y: 300.)],
+///     Some(vec![10., 30., 20.]),
+///     None,
+/// );
+///
+/// assert_eq!(track.elevation_at_distance(50.), Some(20.));
+/// assert_eq!(track.elevation_at_fraction(0.25), Some(30.));
+///
+/// let change = track.elevation_change().unwrap();
+/// assert_eq!((change.gain, change.loss), (20., 10.));
+/// ```
+pub trait ElevationProfile<T: CoordFloat> {
+    /// The elevation at the given horizontal distance from the start of the line string.
+    ///
+    /// Distances less than zero give the elevation of the start, and distances beyond the end
+    /// that of the end. Returns `None` if the distance is NaN, or the line string is empty.
+    fn elevation_at_distance(&self, distance: T) -> Option<T>;
+
+    /// The elevation at the given fraction of the horizontal length of the line string.
+    ///
+    /// Fractions less than zero give the elevation of the start, and fractions greater than
+    /// one that of the end. Returns `None` if the fraction is NaN, or the line string is empty.
+    fn elevation_at_fraction(&self, fraction: T) -> Option<T>;
+
+    /// The total elevation gained and lost going along the line string.
+    fn elevation_change(&self) -> Option<ElevationChange<T>>;
+}
+
+/// The cumulative elevation gain and loss along a line string, as computed by
+/// [`ElevationProfile::elevation_change`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElevationChange<T: CoordFloat> {
+    /// The sum of the rises between consecutive coordinates.
+    pub gain: T,
+    /// The sum of the falls between consecutive coordinates, as a positive value.
+    pub loss: T,
+}
+
+impl<T: CoordFloat> ElevationChange<T> {
+    /// The elevation of the end less that of the start.
+    pub fn net(&self) -> T {
+        self.gain - self.loss
+    }
+}
+
+impl<T> ElevationProfile<T> for LineStringZM<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn elevation_at_distance(&self, distance: T) -> Option<T> {
+        let z = self.z()?;
+        let (first, last) = (*z.first()?, *z.last()?);
+        if distance.is_nan() {
+            return None;
+        }
+        if distance <= T::zero() {
+            return Some(first);
+        }
+        let mut travelled = T::zero();
+        for (line, z) in self.xy().lines().zip(z.windows(2)) {
+            let length = line.euclidean_length();
+            // zero length segments are never reached: `travelled < distance` on entering them
+            if travelled + length >= distance {
+                let fraction = (distance - travelled) / length;
+                return Some(z[0] + (z[1] - z[0]) * fraction);
+            }
+            travelled = travelled + length;
+        }
+        Some(last)
+    }
+
+    fn elevation_at_fraction(&self, fraction: T) -> Option<T> {
+        if fraction.is_nan() {
+            return None;
+        }
+        let fraction = fraction.max(T::zero()).min(T::one());
+        self.elevation_at_distance(fraction * self.xy().euclidean_length())
+    }
+
+    fn elevation_change(&self) -> Option<ElevationChange<T>> {
+        let change = self.z()?.windows(2).fold(
+            ElevationChange {
+                gain: T::zero(),
+                loss: T::zero(),
+            },
+            |mut change, z| {
+                let rise = z[1] - z[0];
+                if rise > T::zero() {
+                    change.gain = change.gain + rise;
+                } else {
+                    change.loss = change.loss - rise;
+                }
+                change
+            },
+        );
+        Some(change)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn track() -> LineStringZM<f64> {
+        LineStringZM::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 3., y: 4.),
+                // a repeated coordinate, climbing vertically
+                (x: 3., y: 4.),
+                (x: 3., y: 8.),
+            ],
+            Some(vec![0., 10., 20., 12.]),
+            None,
+        )
+    }
+
+    #[test]
+    fn interpolation() {
+        let track = track();
+        assert_eq!(track.elevation_at_distance(-1.), Some(0.));
+        assert_eq!(track.elevation_at_distance(2.5), Some(5.));
+        // at the repeated coordinate, before climbing
+        assert_eq!(track.elevation_at_distance(5.), Some(10.));
+        assert_eq!(track.elevation_at_distance(7.), Some(16.));
+        assert_eq!(track.elevation_at_distance(100.), Some(12.));
+        assert_eq!(track.elevation_at_distance(f64::NAN), None);
+
+        assert_eq!(track.elevation_at_fraction(0.5), Some(9.));
+        assert_eq!(track.elevation_at_fraction(1.), Some(12.));
+        assert_eq!(track.elevation_at_fraction(f64::INFINITY), Some(12.));
+        assert_eq!(track.elevation_at_fraction(f64::NEG_INFINITY), Some(0.));
+    }
+
+    #[test]
+    fn missing_values() {
+        let flat: LineStringZM<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into();
+        assert_eq!(flat.elevation_at_distance(1.), None);
+        assert_eq!(flat.elevation_change(), None);
+
+        let empty = LineStringZM::<f64>::new(line_string![], Some(vec![]), None);
+        assert_eq!(empty.elevation_at_fraction(0.5), None);
+        assert_eq!(
+            empty.elevation_change(),
+            Some(ElevationChange { gain: 0., loss: 0. })
+        );
+
+        let point = LineStringZM::new(line_string![(x: 1., y: 1.)], Some(vec![5.]), None);
+        assert_eq!(point.elevation_at_fraction(0.5), Some(5.));
+    }
+
+    #[test]
+    fn gain_and_loss() {
+        let change = track().elevation_change().unwrap();
+        assert_eq!(
+            change,
+            ElevationChange {
+                gain: 20.,
+                loss: 8.
+            }
+        );
+        assert_eq!(change.net(), 12.);
+    }
+}
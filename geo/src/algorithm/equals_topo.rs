@@ -0,0 +1,126 @@
+use crate::relate::Relate;
+use crate::HasDimensions;
+
+/// Tests if two geometries are topologically equal, i.e. consist of exactly the same points.
+///
+/// Unlike `==`, which compares coordinates one by one, `equals_topo` ignores how the points
+/// are described: the rings of polygons may start anywhere and go either way round, segments
+/// may be split by extra vertices, points may be repeated, and geometries of different types,
+/// such as a `Rect` and a `Polygon`, can be equal.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[T*F**FFF*]`. Two
+/// empty geometries are equal as well.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, EqualsTopo, Rect};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// // starting at another corner, going the other way round, with a vertex halfway along an edge
+/// let other = polygon![
+///     (x: 2., y: 2.),
+///     (x: 2., y: 0.),
+///     (x: 1., y: 0.),
+///     (x: 0., y: 0.),
+///     (x: 0., y: 2.),
+/// ];
+/// assert_ne!(square, other);
+/// assert!(square.equals_topo(&other));
+/// assert!(square.equals_topo(&Rect::new((0., 0.), (2., 2.))));
+///
+/// // a polygon doesn't consist of the same points as its boundary
+/// assert!(!square.equals_topo(square.exterior()));
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+/// assert!(line_string.equals_topo(&line_string![(x: 0., y: 0.), (x: 3., y: 3.)]));
+/// ```
+///
+/// Note: `EqualsTopo` must not be called on geometries containing `NaN` coordinates.
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait EqualsTopo<F, Rhs = Self> {
+    fn equals_topo(&self, other: &Rhs) -> bool;
+}
+
+impl<F, G1, G2> EqualsTopo<F, G2> for G1
+where
+    G1: Relate<F, G2> + HasDimensions,
+    G2: HasDimensions,
+{
+    fn equals_topo(&self, other: &G2) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return self.is_empty() && other.is_empty();
+        }
+        self.relate(other).is_equal_topo()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        line_string, point, polygon, Geometry, GeometryCollection, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, Polygon, Rect,
+    };
+
+    #[test]
+    fn polygons() {
+        let a = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        );
+        let b = polygon!(
+            exterior: [
+                (x: 0., y: 4.),
+                (x: 4., y: 4.),
+                (x: 4., y: 2.),
+                (x: 4., y: 0.),
+                (x: 0., y: 0.),
+            ],
+            interiors: [[(x: 2., y: 2.), (x: 1., y: 1.), (x: 2., y: 1.)]],
+        );
+        assert!(a.equals_topo(&b));
+        assert!(b.equals_topo(&a));
+
+        let no_hole = Polygon::new(a.exterior().clone(), vec![]);
+        assert!(!a.equals_topo(&no_hole));
+        assert!(no_hole.equals_topo(&Rect::new((0., 0.), (4., 4.))));
+
+        let squares = MultiPolygon::new(vec![
+            no_hole.clone(),
+            Rect::new((5., 0.), (6., 1.)).to_polygon(),
+        ]);
+        assert!(!no_hole.equals_topo(&squares));
+        assert!(squares.equals_topo(&squares.clone()));
+    }
+
+    #[test]
+    fn lines_and_points() {
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let reversed = line_string![(x: 2., y: 2.), (x: 2., y: 1.), (x: 2., y: 0.), (x: 0., y: 0.)];
+        assert!(a.equals_topo(&reversed));
+
+        let split = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 2., y: 0.), (x: 2., y: 2.)],
+        ]);
+        assert!(a.equals_topo(&split));
+        assert!(!a.equals_topo(&line_string![(x: 0., y: 0.), (x: 2., y: 0.)]));
+
+        let points = MultiPoint::new(vec![point!(x: 1., y: 1.), point!(x: 1., y: 1.)]);
+        assert!(points.equals_topo(&point!(x: 1., y: 1.)));
+        assert!(!points.equals_topo(&point!(x: 1., y: 2.)));
+
+        // a point on a line isn't the line
+        assert!(!point!(x: 1., y: 0.).equals_topo(&a));
+    }
+
+    #[test]
+    fn empty() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert!(empty.equals_topo(&GeometryCollection::<f64>::new_from(vec![])));
+        assert!(!empty.equals_topo(&point!(x: 0., y: 0.)));
+        assert!(!Geometry::from(point!(x: 0., y: 0.)).equals_topo(&Geometry::from(empty)));
+    }
+}
@@ -2,14 +2,16 @@ use crate::polygon_distance_fast_path::*;
 use crate::utils::{coord_pos_relative_to_ring, CoordPos};
 use crate::EuclideanLength;
 use crate::Intersects;
+use crate::MapCoords;
 use crate::{
-    Coord, GeoFloat, GeoNum, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon, Triangle,
+    Coord, GeoFloat, GeoNum, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 use num_traits::{float::FloatConst, Bounded, Float, Signed};
 
-use rstar::RTree;
-use rstar::RTreeNum;
+use rstar::{Envelope, RTree, RTreeNode, RTreeNum, RTreeObject, AABB};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 
 /// Returns the distance between two geometries.
 
@@ -24,6 +26,13 @@ pub trait EuclideanDistance<T, Rhs = Self> {
     ///
     /// The distance between a `Point` and an empty `LineString` is `0.0`
     ///
+    /// Geometries with integer coordinates (`i16`, `i32`, `i64` and `isize`) are measured in
+    /// `f64`.
+    ///
+    /// Any two of the geometry types can be measured, including `Geometry` and
+    /// `GeometryCollection`. The distance to a multi-geometry or a collection is the smallest
+    /// distance to one of its members, so it's `T::max_value()` if it's empty.
+    ///
     /// # Examples
     ///
     /// `Point` to `Point`:
@@ -369,11 +378,14 @@ impl<T> EuclideanDistance<T, LineString<T>> for LineString<T>
 where
     T: GeoFloat + Signed + RTreeNum,
 {
+    /// Unless one of the `LineString`s only has a few segments, the segments of the shorter one
+    /// are indexed in an R* tree, which only has to be searched near each segment of the longer
+    /// one.
     fn euclidean_distance(&self, other: &LineString<T>) -> T {
         if self.intersects(other) {
             T::zero()
         } else {
-            nearest_neighbour_distance(self, other)
+            line_string_distance(self, other)
         }
     }
 }
@@ -514,6 +526,84 @@ where
     }
 }
 
+// ┌──────────────────────────┐
+// │ Implementations for Rect │
+// └──────────────────────────┘
+
+impl<T> EuclideanDistance<T, Point<T>> for Rect<T>
+where
+    T: GeoFloat,
+{
+    fn euclidean_distance(&self, point: &Point<T>) -> T {
+        // the gaps along each axis, which are zero where the projections overlap
+        let dx = (self.min().x - point.x())
+            .max(point.x() - self.max().x)
+            .max(T::zero());
+        let dy = (self.min().y - point.y())
+            .max(point.y() - self.max().y)
+            .max(T::zero());
+        dx.hypot(dy)
+    }
+}
+
+impl<T> EuclideanDistance<T, Rect<T>> for Point<T>
+where
+    T: GeoFloat,
+{
+    fn euclidean_distance(&self, rect: &Rect<T>) -> T {
+        rect.euclidean_distance(self)
+    }
+}
+
+impl<T> EuclideanDistance<T, Rect<T>> for Rect<T>
+where
+    T: GeoFloat,
+{
+    fn euclidean_distance(&self, other: &Rect<T>) -> T {
+        let dx = (self.min().x - other.max().x)
+            .max(other.min().x - self.max().x)
+            .max(T::zero());
+        let dy = (self.min().y - other.max().y)
+            .max(other.min().y - self.max().y)
+            .max(T::zero());
+        dx.hypot(dy)
+    }
+}
+
+// Unless they intersect, the distance from a `Rect` or a `Triangle` to another geometry is
+// reached on one of its edges, so there's no need to convert it to a `Polygon`.
+macro_rules! impl_euclidean_distance_from_edges {
+    ($for:ident, [$($rhs:ident),*]) => {
+        $(
+            impl<T> EuclideanDistance<T, $rhs<T>> for $for<T>
+            where
+                T: GeoFloat + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, other: &$rhs<T>) -> T {
+                    if self.intersects(other) {
+                        return T::zero();
+                    }
+                    self.to_lines()
+                        .iter()
+                        .map(|edge| edge.euclidean_distance(other))
+                        .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
+                }
+            }
+
+            impl<T> EuclideanDistance<T, $for<T>> for $rhs<T>
+            where
+                T: GeoFloat + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, other: &$for<T>) -> T {
+                    other.euclidean_distance(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_distance_from_edges!(Rect, [Line, LineString, Polygon]);
+
 // ┌──────────────────────────────┐
 // │ Implementations for Triangle │
 // └──────────────────────────────┘
@@ -534,6 +624,169 @@ where
     }
 }
 
+impl<T> EuclideanDistance<T, Triangle<T>> for Point<T>
+where
+    T: GeoFloat,
+{
+    fn euclidean_distance(&self, triangle: &Triangle<T>) -> T {
+        triangle.euclidean_distance(self)
+    }
+}
+
+impl<T> EuclideanDistance<T, Triangle<T>> for Triangle<T>
+where
+    T: GeoFloat + FloatConst + Signed + RTreeNum,
+{
+    fn euclidean_distance(&self, other: &Triangle<T>) -> T {
+        if self.intersects(other) {
+            return T::zero();
+        }
+        self.to_lines()
+            .iter()
+            .map(|edge| edge.euclidean_distance(other))
+            .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
+    }
+}
+
+impl_euclidean_distance_from_edges!(Triangle, [Line, LineString, Polygon, Rect]);
+
+// ┌───────────────────────────────────────────────┐
+// │ Implementations for multi-geometries, Geometry │
+// │ and GeometryCollection                         │
+// └───────────────────────────────────────────────┘
+
+// The distance to a multi-geometry or a collection is the smallest distance to one of its
+// members, or `T::max_value()` if it's empty.
+macro_rules! impl_euclidean_distance_for_iter_geometry {
+    ($for:ty, [$($target:ty),*]) => {
+        $(
+            impl<T> EuclideanDistance<T, $target> for $for
+            where
+                T: GeoFloat + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, target: &$target) -> T {
+                    self.iter()
+                        .map(|member| member.euclidean_distance(target))
+                        .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! symmetric_euclidean_distance_impl {
+    ($for:ty, [$($target:ty),*]) => {
+        $(
+            impl<T> EuclideanDistance<T, $target> for $for
+            where
+                T: GeoFloat + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, target: &$target) -> T {
+                    target.euclidean_distance(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_euclidean_distance_for_geometry {
+    ([$($target:ty),*]) => {
+        $(
+            impl<T> EuclideanDistance<T, $target> for Geometry<T>
+            where
+                T: GeoFloat + FloatConst + Signed + RTreeNum,
+            {
+                crate::geometry_delegate_impl! {
+                    fn euclidean_distance(&self, target: &$target) -> T;
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_distance_for_iter_geometry!(MultiPoint<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Rect<T>, Triangle<T>]);
+impl_euclidean_distance_for_iter_geometry!(MultiLineString<T>, [Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Rect<T>, Triangle<T>]);
+impl_euclidean_distance_for_iter_geometry!(MultiPolygon<T>, [LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Rect<T>, Triangle<T>]);
+impl_euclidean_distance_for_iter_geometry!(GeometryCollection<T>, [Point<T>, Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Rect<T>, Triangle<T>, Geometry<T>, GeometryCollection<T>]);
+impl_euclidean_distance_for_geometry!([Point<T>, Line<T>, LineString<T>, Polygon<T>, MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Rect<T>, Triangle<T>, Geometry<T>, GeometryCollection<T>]);
+
+symmetric_euclidean_distance_impl!(Point<T>, [Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(Line<T>, [MultiPoint<T>, MultiLineString<T>, Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(LineString<T>, [MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(Polygon<T>, [MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(Rect<T>, [MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(Triangle<T>, [MultiPoint<T>, MultiLineString<T>, MultiPolygon<T>, Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(MultiPoint<T>, [Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(MultiLineString<T>, [Geometry<T>, GeometryCollection<T>]);
+symmetric_euclidean_distance_impl!(MultiPolygon<T>, [Geometry<T>, GeometryCollection<T>]);
+
+// ┌─────────────────────────────────────────┐
+// │ Implementations for integer coordinates │
+// └─────────────────────────────────────────┘
+
+// Distances between integer geometries are irrational in general, so they are computed in
+// `f64`, after converting the coordinates, which is exact up to 2^53 in absolute value.
+macro_rules! impl_euclidean_distance_for_integers {
+    (@pairs $t:ty, [$(($for:ident, $rhs:ident)),*]) => {
+        $(
+            impl EuclideanDistance<f64, $rhs<$t>> for $for<$t> {
+                fn euclidean_distance(&self, rhs: &$rhs<$t>) -> f64 {
+                    let to_f64 = |coord: Coord<$t>| Coord {
+                        x: coord.x as f64,
+                        y: coord.y as f64,
+                    };
+                    self.map_coords(to_f64)
+                        .euclidean_distance(&rhs.map_coords(to_f64))
+                }
+            }
+        )*
+    };
+    ($($t:ty),*) => {
+        $(
+            impl_euclidean_distance_for_integers!(@pairs $t, [
+                (Point, Point),
+                (Point, MultiPoint),
+                (Point, Line),
+                (Point, LineString),
+                (Point, MultiLineString),
+                (Point, Polygon),
+                (Point, MultiPolygon),
+                (MultiPoint, Point),
+                (Line, Point),
+                (Line, Line),
+                (Line, LineString),
+                (Line, Polygon),
+                (Line, MultiPolygon),
+                (LineString, Point),
+                (LineString, Line),
+                (LineString, LineString),
+                (LineString, Polygon),
+                (MultiLineString, Point),
+                (Polygon, Point),
+                (Polygon, Line),
+                (Polygon, LineString),
+                (Polygon, Polygon),
+                (MultiPolygon, Point),
+                (MultiPolygon, Line),
+                (Rect, Point),
+                (Rect, Line),
+                (Rect, LineString),
+                (Rect, Polygon),
+                (Rect, Rect),
+                (Rect, Triangle),
+                (Triangle, Point),
+                (Triangle, Line),
+                (Triangle, LineString),
+                (Triangle, Polygon),
+                (Triangle, Rect),
+                (Triangle, Triangle)
+            ]);
+        )*
+    };
+}
+
+impl_euclidean_distance_for_integers!(i16, i32, i64, isize);
+
 // ┌───────────┐
 // │ Utilities │
 // └───────────┘
@@ -552,25 +805,169 @@ where
     }
 }
 
-/// Uses an R* tree and nearest-neighbour lookups to calculate minimum distances
-// This is somewhat slow and memory-inefficient, but certainly better than quadratic time
+/// Below this number of segments in the shorter of two `LineString`s, the distance between them
+/// is measured by comparing every pair of segments, rather than by indexing them.
+const INDEXED_DISTANCE_MIN_SEGMENTS: usize = 16;
+
+/// The minimum distance between the vertices of either `LineString` and the segments of the
+/// other, like `nearest_neighbour_distance`.
+///
+/// Each segment of the longer `LineString` is only compared with the segments of the shorter one
+/// whose envelopes are within the smallest distance found so far of its own, which an R* tree
+/// of the latter's segments finds.
+fn line_string_distance<T>(ls1: &LineString<T>, ls2: &LineString<T>) -> T
+where
+    T: GeoFloat + RTreeNum,
+{
+    let (long, short) = if ls1.0.len() >= ls2.0.len() {
+        (ls1, ls2)
+    } else {
+        (ls2, ls1)
+    };
+    if short.lines().len() < INDEXED_DISTANCE_MIN_SEGMENTS {
+        let mut mindist = <T as Bounded>::max_value();
+        for a in long.lines() {
+            for b in short.lines() {
+                mindist = mindist.min(segment_vertices_distance(&a, &b));
+            }
+        }
+        return mindist;
+    }
+
+    let tree: RTree<Line<_>> = RTree::bulk_load(short.lines().collect::<Vec<_>>());
+    // start from the distance between a vertex of the longer line string and the shorter one,
+    // an upper bound of the minimum
+    let first = Point::from(long.0[0]);
+    let mut mindist = tree
+        .nearest_neighbor(&first)
+        .map(|b| b.euclidean_distance(&first))
+        .unwrap_or_else(Bounded::max_value);
+    for a in long.lines() {
+        let envelope = a.envelope();
+        let (lower, upper) = (envelope.lower(), envelope.upper());
+        let search = AABB::from_corners(
+            Point::new(lower.x() - mindist, lower.y() - mindist),
+            Point::new(upper.x() + mindist, upper.y() + mindist),
+        );
+        for b in tree.locate_in_envelope_intersecting(&search) {
+            mindist = mindist.min(segment_vertices_distance(&a, b));
+        }
+    }
+    mindist
+}
+
+/// Uses R* trees of the segments of both geometries, and a branch-and-bound search of pairs of
+/// their nodes, to calculate the minimum distance between the vertices of either geometry and
+/// the segments of the other.
+///
+/// Pairs of nodes are visited nearest first, and the search stops at the first pair whose
+/// envelopes are further apart than the nearest pair of segments found so far, so most of the
+/// pairs of segments are never compared.
 pub fn nearest_neighbour_distance<T>(geom1: &LineString<T>, geom2: &LineString<T>) -> T
 where
     T: GeoFloat + RTreeNum,
 {
     let tree_a: RTree<Line<_>> = RTree::bulk_load(geom1.lines().collect::<Vec<_>>());
     let tree_b: RTree<Line<_>> = RTree::bulk_load(geom2.lines().collect::<Vec<_>>());
-    // Return minimum distance between all geom a points and all geom b points
-    geom2
-        .points()
-        .fold(<T as Bounded>::max_value(), |acc, point| {
-            let nearest = tree_a.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
-        })
-        .min(geom1.points().fold(Bounded::max_value(), |acc, point| {
-            let nearest = tree_b.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
-        }))
+
+    let mut queue = BinaryHeap::new();
+    for a in tree_a.root().children() {
+        for b in tree_b.root().children() {
+            queue.push(NodePair::new(a, b));
+        }
+    }
+
+    let mut mindist = <T as Bounded>::max_value();
+    while let Some(NodePair { distance, a, b }) = queue.pop() {
+        if distance >= mindist {
+            // every other pair is at least as far apart
+            break;
+        }
+        // expand the larger node, or the only parent
+        let (parent, other, parent_is_a) = match (a, b) {
+            (RTreeNode::Leaf(line_a), RTreeNode::Leaf(line_b)) => {
+                mindist = mindist.min(segment_vertices_distance(line_a, line_b));
+                continue;
+            }
+            (RTreeNode::Parent(parent_a), RTreeNode::Parent(parent_b))
+                if parent_a.envelope().area() < parent_b.envelope().area() =>
+            {
+                (parent_b, a, false)
+            }
+            (RTreeNode::Parent(parent_a), _) => (parent_a, b, true),
+            (_, RTreeNode::Parent(parent_b)) => (parent_b, a, false),
+        };
+        for child in parent.children() {
+            let pair = if parent_is_a {
+                NodePair::new(child, other)
+            } else {
+                NodePair::new(other, child)
+            };
+            if pair.distance < mindist {
+                queue.push(pair);
+            }
+        }
+    }
+    mindist
+}
+
+/// The minimum distance between the end points of either segment and the other segment.
+fn segment_vertices_distance<T: GeoFloat>(a: &Line<T>, b: &Line<T>) -> T {
+    a.euclidean_distance(&b.start_point())
+        .min(a.euclidean_distance(&b.end_point()))
+        .min(b.euclidean_distance(&a.start_point()))
+        .min(b.euclidean_distance(&a.end_point()))
+}
+
+/// A pair of nodes of the R* trees of `nearest_neighbour_distance`, with the distance between
+/// their envelopes, a lower bound of the distance between their segments.
+struct NodePair<'a, T: GeoFloat + RTreeNum> {
+    distance: T,
+    a: &'a RTreeNode<Line<T>>,
+    b: &'a RTreeNode<Line<T>>,
+}
+
+impl<'a, T: GeoFloat + RTreeNum> NodePair<'a, T> {
+    fn new(a: &'a RTreeNode<Line<T>>, b: &'a RTreeNode<Line<T>>) -> Self {
+        let (a_env, b_env) = (a.envelope(), b.envelope());
+        let (a_lower, a_upper) = (a_env.lower(), a_env.upper());
+        let (b_lower, b_upper) = (b_env.lower(), b_env.upper());
+        let dx = (a_lower.x() - b_upper.x())
+            .max(b_lower.x() - a_upper.x())
+            .max(T::zero());
+        let dy = (a_lower.y() - b_upper.y())
+            .max(b_lower.y() - a_upper.y())
+            .max(T::zero());
+        NodePair {
+            distance: dx.hypot(dy),
+            a,
+            b,
+        }
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> PartialEq for NodePair<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> Eq for NodePair<'a, T> {}
+
+impl<'a, T: GeoFloat + RTreeNum> PartialOrd for NodePair<'a, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> Ord for NodePair<'a, T> {
+    // reversed, so that `BinaryHeap` pops the nearest pair first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
 }
 
 #[cfg(test)]
@@ -1039,6 +1436,13 @@ mod test {
         assert_eq!(dist, dist2);
     }
     #[test]
+    fn test_minimum_polygon_distance_empty() {
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        let poly = Rect::new((2., 2.), (3., 3.)).to_polygon();
+        assert_eq!(min_convex_poly_dist(&empty, &poly), f64::INFINITY);
+        assert_eq!(min_convex_poly_dist(&poly, &empty), f64::INFINITY);
+    }
+    #[test]
     fn test_minimum_polygon_distance_5() {
         let poly1 = polygon!(
                 exterior: [
@@ -1083,6 +1487,37 @@ mod test {
         assert_relative_eq!(distance, 2.2864896295566055);
     }
     #[test]
+    fn test_concave_polygon_distance_matches_all_pairs() {
+        // two interleaved combs, each tooth half a unit away from the other comb's teeth
+        let comb = |x0: f64, y0: f64, flip: f64| {
+            let mut coords = vec![(x0, y0), (x0 + 40., y0), (x0 + 40., y0 + flip)];
+            for i in (0..20).rev() {
+                let x = x0 + f64::from(i) * 2.;
+                coords.push((x + 0.5, y0 + flip));
+                coords.push((x + 0.5, y0 + flip * 10.));
+                coords.push((x, y0 + flip * 10.));
+                coords.push((x, y0 + flip));
+            }
+            Polygon::new(LineString::from(coords), vec![])
+        };
+        let poly1 = comb(0., 0., 1.);
+        let poly2 = comb(1., 12., -1.);
+        let all_pairs = |a: &LineString<f64>, b: &LineString<f64>| {
+            a.lines()
+                .flat_map(|line| b.points().map(move |p| line.euclidean_distance(&p)))
+                .fold(f64::MAX, f64::min)
+        };
+        let expected = all_pairs(poly1.exterior(), poly2.exterior())
+            .min(all_pairs(poly2.exterior(), poly1.exterior()));
+
+        assert_relative_eq!(expected, 0.5);
+        assert_relative_eq!(poly1.euclidean_distance(&poly2), expected);
+        assert_relative_eq!(
+            nearest_neighbour_distance(poly2.exterior(), poly1.exterior()),
+            expected
+        );
+    }
+    #[test]
     // A polygon inside another polygon's ring; they're disjoint in the DE-9IM sense:
     // FF2FF1212
     fn test_poly_in_ring() {
@@ -1102,6 +1537,24 @@ mod test {
         assert_relative_eq!(ring.euclidean_distance(&poly_in_ring), 5.992772737231033);
     }
     #[test]
+    fn test_long_linestring_distance() {
+        let wave = |n: u32, dx: f64, dy: f64| {
+            (0..n)
+                .map(|i| {
+                    let x = f64::from(i) * 0.5 + dx;
+                    (x, x.sin() + dy)
+                })
+                .collect::<LineString<_>>()
+        };
+        let long = wave(1000, 0., 0.);
+        // indexed, and compared pair by pair
+        for short in [wave(40, 100.3, 2.5), wave(5, 200.1, -2.25)] {
+            let expected = nearest_neighbour_distance(&long, &short);
+            assert_relative_eq!(long.euclidean_distance(&short), expected);
+            assert_relative_eq!(short.euclidean_distance(&long), expected);
+        }
+    }
+    #[test]
     // Line-Polygon test: closest point on Polygon is NOT nearest to a Line end-point
     fn test_line_polygon_simple() {
         let line = Line::from([(0.0, 0.0), (0.0, 3.0)]);
@@ -1166,6 +1619,72 @@ mod test {
         assert_relative_eq!(triangle.euclidean_distance(&point), 0.0);
     }
 
+    #[test]
+    fn rect_distances() {
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 1. });
+        assert_relative_eq!(rect.euclidean_distance(&Point::new(1., 0.5)), 0.);
+        assert_relative_eq!(rect.euclidean_distance(&Point::new(1., 3.)), 2.);
+        assert_relative_eq!(Point::new(5., 5.).euclidean_distance(&rect), 5.);
+        let other = Rect::new(coord! { x: 5., y: -4. }, coord! { x: 6., y: -6. });
+        assert_relative_eq!(rect.euclidean_distance(&other), 5.);
+        assert_relative_eq!(rect.euclidean_distance(&rect), 0.);
+
+        let line = Line::from([(3., 3.), (5., 1.)]);
+        assert_relative_eq!(rect.euclidean_distance(&line), 3. / 2f64.sqrt());
+        assert_relative_eq!(line.euclidean_distance(&rect), 3. / 2f64.sqrt());
+        let polygon = polygon![(x: 3., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.)];
+        assert_relative_eq!(rect.euclidean_distance(&polygon), 1.);
+        // the polygon is inside the rect
+        let big = Rect::new(coord! { x: -10., y: -10. }, coord! { x: 10., y: 10. });
+        assert_relative_eq!(big.euclidean_distance(&polygon), 0.);
+    }
+
+    #[test]
+    fn triangle_distances() {
+        let triangle = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
+        assert_relative_eq!(
+            Point::new(0., 2.).euclidean_distance(&triangle),
+            2f64.sqrt()
+        );
+        let other = Triangle::from([(4.0, 0.0), (5.0, 0.0), (5.0, 2.0)]);
+        assert_relative_eq!(triangle.euclidean_distance(&other), 2.);
+        let rect = Rect::new(coord! { x: -3., y: 0. }, coord! { x: -1., y: 5. });
+        assert_relative_eq!(triangle.euclidean_distance(&rect), 1.);
+        assert_relative_eq!(rect.euclidean_distance(&triangle), 1.);
+        let line_string = LineString::from(vec![(0.5, 0.1), (1.5, 0.1)]);
+        assert_relative_eq!(triangle.euclidean_distance(&line_string), 0.);
+    }
+
+    #[test]
+    fn heterogeneous_collections() {
+        let collection = GeometryCollection::new_from(vec![
+            Point::new(10., 0.).into(),
+            Line::from([(0., 5.), (4., 5.)]).into(),
+            Rect::new(coord! { x: -4., y: -1. }, coord! { x: -3., y: 1. }).into(),
+        ]);
+        let triangle = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
+        assert_relative_eq!(collection.euclidean_distance(&triangle), 3.);
+        assert_relative_eq!(triangle.euclidean_distance(&collection), 3.);
+
+        let multi_line_string = MultiLineString::new(vec![
+            LineString::from(vec![(12., 0.), (12., 10.)]),
+            LineString::from(vec![(0., -10.), (10., -10.)]),
+        ]);
+        assert_relative_eq!(collection.euclidean_distance(&multi_line_string), 2.);
+        let geometry = Geometry::MultiLineString(multi_line_string);
+        assert_relative_eq!(geometry.euclidean_distance(&collection), 2.);
+        assert_relative_eq!(
+            geometry.euclidean_distance(&Geometry::Triangle(triangle)),
+            10.
+        );
+
+        let nested =
+            GeometryCollection::new_from(vec![Geometry::GeometryCollection(collection.clone())]);
+        assert_relative_eq!(nested.euclidean_distance(&collection), 0.);
+        let multi_point = MultiPoint::new(vec![Point::new(-3.5, 4.), Point::new(20., 20.)]);
+        assert_relative_eq!(multi_point.euclidean_distance(&nested), 3.);
+    }
+
     #[test]
     fn convex_and_nearest_neighbour_comparison() {
         let ls1: LineString<f64> = vec![
@@ -1196,4 +1715,20 @@ mod test {
             nearest_neighbour_distance(first_polygon.exterior(), second_polygon.exterior())
         );
     }
+
+    #[test]
+    fn integer_geometries() {
+        let point = Point::new(0i32, 0);
+        let square = polygon![(x: 3i32, y: 4), (x: 6, y: 4), (x: 6, y: 8), (x: 3, y: 8)];
+        let line = Line::new(coord! { x: -2i32, y: 1 }, coord! { x: 2, y: 1 });
+        assert_relative_eq!(point.euclidean_distance(&square), 5.);
+        assert_relative_eq!(point.euclidean_distance(&line), 1.);
+        assert_relative_eq!(square.euclidean_distance(&line), 10f64.sqrt());
+        assert_relative_eq!(
+            Point::new(3i64, 4).euclidean_distance(&Point::new(0i64, 0)),
+            5.
+        );
+        let rect = Rect::new(coord! { x: 3i32, y: -1 }, coord! { x: 4, y: 0 });
+        assert_relative_eq!(rect.euclidean_distance(&line), 2f64.sqrt());
+    }
 }
@@ -1,6 +1,6 @@
-use std::iter::Sum;
+use core::iter::Sum;
 
-use crate::{CoordFloat, Line, LineString, MultiLineString};
+use crate::{CoordFloat, Line, LineString, LineStringRef, LineStringZM, MultiLineString};
 
 /// Calculation of the length
 
@@ -44,6 +44,15 @@ where
     }
 }
 
+impl<'a, T> EuclideanLength<T> for LineStringRef<'a, T>
+where
+    T: CoordFloat + Sum,
+{
+    fn euclidean_length(&self) -> T {
+        self.lines().map(|line| line.euclidean_length()).sum()
+    }
+}
+
 impl<T> EuclideanLength<T> for MultiLineString<T>
 where
     T: CoordFloat + Sum,
@@ -55,6 +64,29 @@ where
     }
 }
 
+/// The length of a `LineStringZM` is measured in three dimensions when it has Z values, and in
+/// two dimensions otherwise. Measure its [`xy`](LineStringZM::xy) line string for the
+/// two-dimensional length of a line string with Z values.
+impl<T> EuclideanLength<T> for LineStringZM<T>
+where
+    T: CoordFloat + Sum,
+{
+    fn euclidean_length(&self) -> T {
+        let z = match self.z() {
+            Some(z) => z,
+            None => return self.xy().euclidean_length(),
+        };
+        self.xy()
+            .lines()
+            .zip(z.windows(2))
+            .map(|(line, z)| {
+                let dz = z[1] - z[0];
+                (line.dx() * line.dx() + line.dy() * line.dy() + dz * dz).sqrt()
+            })
+            .sum()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::line_string;
@@ -108,4 +140,16 @@ mod test {
         assert_relative_eq!(line0.euclidean_length(), 1.);
         assert_relative_eq!(line1.euclidean_length(), 5.);
     }
+
+    #[test]
+    fn line_string_zm_test() {
+        use crate::LineStringZM;
+
+        let xy = line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 4.)];
+        let track = LineStringZM::new(xy.clone(), Some(vec![0., 4., 4.]), None);
+        assert_relative_eq!(track.euclidean_length(), 9.);
+        assert_relative_eq!(track.xy().euclidean_length(), 7.);
+        let flat = LineStringZM::new(xy, None, Some(vec![0., 1., 2.]));
+        assert_relative_eq!(flat.euclidean_length(), 7.);
+    }
 }
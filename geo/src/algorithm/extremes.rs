@@ -1,8 +1,15 @@
+use std::cmp::Ordering;
+
 use crate::CoordsIter;
 use crate::{Coord, CoordNum};
 
 /// Find the extreme coordinates and indices of a geometry.
 ///
+/// Implemented for every geometry, such as `LineString`, `MultiPoint`, `MultiPolygon` and
+/// `Geometry`. Only the exterior coordinates are considered, i.e. not those of the interiors of
+/// polygons, and the indices are those of the coordinates in
+/// [`exterior_coords_iter`](CoordsIter::exterior_coords_iter).
+///
 /// # Examples
 ///
 /// ```
@@ -23,18 +30,33 @@ use crate::{Coord, CoordNum};
 /// assert_eq!(extremes.y_max.index, 2);
 /// assert_eq!(extremes.y_max.coord.x, 1.);
 /// assert_eq!(extremes.y_max.coord.y, 2.);
+///
+/// // the bottom edge of a square
+/// let square = polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 1)];
+/// let y_min: Vec<usize> = square.all_extremes().unwrap().y_min.iter().map(|e| e.index).collect();
+/// assert_eq!(y_min, vec![0, 1]);
 /// ```
 pub trait Extremes<'a, T: CoordNum> {
+    /// The first coordinate found at each extreme, or `None` if the geometry is empty.
     fn extremes(&'a self) -> Option<Outcome<T>>;
+
+    /// Every coordinate at each extreme, where several tie, in order, or `None` if the geometry
+    /// is empty.
+    ///
+    /// Repeated coordinates, such as the last coordinate of a closed ring, are only included
+    /// once, at the index where they're first found.
+    fn all_extremes(&'a self) -> Option<AllExtremes<T>>;
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Extreme<T: CoordNum> {
     pub index: usize,
     pub coord: Coord<T>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Outcome<T: CoordNum> {
     pub x_min: Extreme<T>,
     pub y_min: Extreme<T>,
@@ -42,6 +64,17 @@ pub struct Outcome<T: CoordNum> {
     pub y_max: Extreme<T>,
 }
 
+/// The extremes of a geometry, with every coordinate tied at each, as found by
+/// [`Extremes::all_extremes`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllExtremes<T: CoordNum> {
+    pub x_min: Vec<Extreme<T>>,
+    pub y_min: Vec<Extreme<T>>,
+    pub x_max: Vec<Extreme<T>>,
+    pub y_max: Vec<Extreme<T>>,
+}
+
 impl<'a, T, G> Extremes<'a, T> for G
 where
     G: CoordsIter<'a, Scalar = T>,
@@ -77,12 +110,66 @@ where
 
         Some(outcome)
     }
+
+    fn all_extremes(&'a self) -> Option<AllExtremes<T>> {
+        let mut iter = self.exterior_coords_iter().enumerate();
+
+        let first = iter
+            .next()
+            .map(|(index, coord)| vec![Extreme { index, coord }])?;
+        let mut outcome = AllExtremes {
+            x_min: first.clone(),
+            y_min: first.clone(),
+            x_max: first.clone(),
+            y_max: first,
+        };
+
+        for (index, coord) in iter {
+            let extreme = Extreme { coord, index };
+            tie_or_beat(&mut outcome.x_min, extreme, |e| e.coord.x, Ordering::Less);
+            tie_or_beat(&mut outcome.y_min, extreme, |e| e.coord.y, Ordering::Less);
+            tie_or_beat(
+                &mut outcome.x_max,
+                extreme,
+                |e| e.coord.x,
+                Ordering::Greater,
+            );
+            tie_or_beat(
+                &mut outcome.y_max,
+                extreme,
+                |e| e.coord.y,
+                Ordering::Greater,
+            );
+        }
+
+        Some(outcome)
+    }
+}
+
+// Add `extreme` to the non-empty `extremes` it ties with, or replace them with it if it's further
+// in the direction of `beyond`.
+fn tie_or_beat<T: CoordNum>(
+    extremes: &mut Vec<Extreme<T>>,
+    extreme: Extreme<T>,
+    value: impl Fn(&Extreme<T>) -> T,
+    beyond: Ordering,
+) {
+    match value(&extreme).partial_cmp(&value(&extremes[0])) {
+        Some(ordering) if ordering == beyond => {
+            extremes.clear();
+            extremes.push(extreme);
+        }
+        Some(Ordering::Equal) if extremes.iter().all(|e| e.coord != extreme.coord) => {
+            extremes.push(extreme);
+        }
+        _ => {}
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{coord, polygon, MultiPoint};
+    use crate::{coord, line_string, point, polygon, Geometry, MultiPoint, MultiPolygon};
 
     #[test]
     fn polygon() {
@@ -127,5 +214,69 @@ mod test {
         let actual = multi_point.extremes();
 
         assert!(actual.is_none());
+        assert!(multi_point.all_extremes().is_none());
+    }
+
+    #[test]
+    fn other_geometries() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 3., y: -1.), (x: 1., y: 2.)];
+        let extremes = line_string.extremes().unwrap();
+        assert_eq!(
+            (
+                extremes.x_max.index,
+                extremes.y_min.index,
+                extremes.y_max.index
+            ),
+            (1, 1, 2)
+        );
+
+        let multi_point = MultiPoint::new(vec![point!(x: 5, y: 5), point!(x: -5, y: 0)]);
+        assert_eq!(multi_point.extremes().unwrap().x_min.index, 1);
+
+        // indexed across the exteriors of the polygons, ignoring the interiors
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon!(
+                exterior: [(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+                interiors: [[(x: 0.5, y: 0.2), (x: 0.8, y: 0.2), (x: 0.8, y: 0.5)]],
+            ),
+            polygon![(x: 10., y: 0.), (x: 11., y: 0.), (x: 11., y: 3.)],
+        ]);
+        let extremes = multi_polygon.extremes().unwrap();
+        assert_eq!(extremes.x_max.index, 5);
+        assert_eq!(extremes.x_max.coord, coord! { x: 11., y: 0. });
+
+        let geometry = Geometry::from(multi_polygon.clone());
+        assert_eq!(geometry.extremes(), multi_polygon.extremes());
+        assert_eq!(geometry.all_extremes(), multi_polygon.all_extremes());
+    }
+
+    #[test]
+    fn ties() {
+        let square = polygon![(x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 2)];
+        let extremes = square.all_extremes().unwrap();
+        let indices = |extremes: &[Extreme<i32>]| -> Vec<usize> {
+            extremes.iter().map(|e| e.index).collect()
+        };
+        // the closing coordinate, at index 4, repeats the first
+        assert_eq!(indices(&extremes.x_min), vec![0, 3]);
+        assert_eq!(indices(&extremes.y_min), vec![0, 1]);
+        assert_eq!(indices(&extremes.x_max), vec![1, 2]);
+        assert_eq!(indices(&extremes.y_max), vec![2, 3]);
+
+        // the first tie is the extreme
+        let single = square.extremes().unwrap();
+        assert_eq!(single.x_max, extremes.x_max[0]);
+
+        // a tie is dropped when beaten
+        let line_string = line_string![(x: 0, y: 1), (x: 1, y: 1), (x: 2, y: 3)];
+        let extremes = line_string.all_extremes().unwrap();
+        assert_eq!(
+            extremes.y_max,
+            vec![Extreme {
+                index: 2,
+                coord: coord! { x: 2, y: 3 }
+            }]
+        );
+        assert_eq!(indices(&extremes.y_min), vec![0, 1]);
     }
 }
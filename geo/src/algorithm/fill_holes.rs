@@ -0,0 +1,125 @@
+use crate::area::get_linestring_area;
+use crate::chamberlain_duquette_area::ring_area;
+use crate::{CoordFloat, LineString, MultiPolygon, Polygon};
+
+/// Fill the holes of a polygonal geometry smaller than an area, e.g. the small clearings of a
+/// forest when generalizing land-cover data.
+///
+/// The interior rings with an area below `min_area` are removed, and the others are kept, in
+/// their order. The area of a ring is that of the hole it makes, regardless of its winding.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, FillHoles};
+///
+/// let forest = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+///         [(x: 4., y: 4.), (x: 8., y: 4.), (x: 8., y: 8.), (x: 4., y: 8.)],
+///     ],
+/// ];
+///
+/// let generalized = forest.fill_holes(2.);
+/// assert_eq!(generalized.interiors(), &forest.interiors()[1..]);
+/// ```
+pub trait FillHoles<T: CoordFloat> {
+    /// The geometry without the holes with a planar area below `min_area`, in the units of its
+    /// coordinates squared.
+    fn fill_holes(&self, min_area: T) -> Self;
+
+    /// The geometry without the holes with a geodesic area below `min_area`, in meters², for
+    /// coordinates in degrees of longitude and latitude. The areas are approximated on a sphere,
+    /// as by [`ChamberlainDuquetteArea`](crate::ChamberlainDuquetteArea).
+    fn geodesic_fill_holes(&self, min_area: T) -> Self;
+}
+
+impl<T: CoordFloat> FillHoles<T> for Polygon<T> {
+    fn fill_holes(&self, min_area: T) -> Self {
+        fill_holes(self, |ring| get_linestring_area(ring).abs() >= min_area)
+    }
+
+    fn geodesic_fill_holes(&self, min_area: T) -> Self {
+        fill_holes(self, |ring| ring_area(ring).abs() >= min_area)
+    }
+}
+
+impl<T: CoordFloat> FillHoles<T> for MultiPolygon<T> {
+    fn fill_holes(&self, min_area: T) -> Self {
+        MultiPolygon::new(self.iter().map(|poly| poly.fill_holes(min_area)).collect())
+    }
+
+    fn geodesic_fill_holes(&self, min_area: T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|poly| poly.geodesic_fill_holes(min_area))
+                .collect(),
+        )
+    }
+}
+
+fn fill_holes<T: CoordFloat>(
+    polygon: &Polygon<T>,
+    keep: impl Fn(&LineString<T>) -> bool,
+) -> Polygon<T> {
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter(|interior| keep(interior))
+        .cloned()
+        .collect();
+    Polygon::new(polygon.exterior().clone(), interiors)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, ChamberlainDuquetteArea};
+
+    #[test]
+    fn planar() {
+        let polygons = MultiPolygon::new(vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [
+                    // wound either way
+                    [(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)],
+                    [(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+                ],
+            ],
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 25., y: 10.)],
+        ]);
+
+        let filled = polygons.fill_holes(4.);
+        assert_eq!(filled.0[0].interiors(), &polygons.0[0].interiors()[..1]);
+        assert_eq!(filled.0[1], polygons.0[1]);
+
+        assert_eq!(polygons.fill_holes(0.), polygons);
+        assert!(polygons.fill_holes(100.).0[0].interiors().is_empty());
+    }
+
+    #[test]
+    fn geodesic() {
+        // about 12,400 km² at the equator
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            interiors: [
+                // about 1.2 km²
+                [(x: 0.1, y: 0.1), (x: 0.11, y: 0.1), (x: 0.11, y: 0.11), (x: 0.1, y: 0.11)],
+                // about 124 km²
+                [(x: 0.5, y: 0.5), (x: 0.6, y: 0.5), (x: 0.6, y: 0.6), (x: 0.5, y: 0.6)],
+            ],
+        ];
+
+        let filled = polygon.geodesic_fill_holes(10_000_000.);
+        assert_eq!(filled.interiors(), &polygon.interiors()[1..]);
+        assert_relative_eq!(
+            filled.chamberlain_duquette_unsigned_area(),
+            polygon.chamberlain_duquette_unsigned_area()
+                + Polygon::new(polygon.interiors()[0].clone(), vec![])
+                    .chamberlain_duquette_unsigned_area(),
+            max_relative = 1e-12
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{AffineOps, AffineTransform, BoundingRect, CoordFloat, Rect};
+
+/// An affine transformation which scales and translates a geometry to fit into a target
+/// rectangle, e.g. to draw it as a thumbnail, or to normalize shapes before comparing them.
+///
+/// The bounding rectangle of the geometry is mapped onto the target, either exactly, stretching
+/// the geometry as needed, or, preserving its aspect ratio, onto the largest rectangle of the
+/// same proportions centered in the target.
+///
+/// A geometry with no extent along an axis, such as a vertical line, isn't scaled along it, but
+/// centered in the target, and a geometry preserving its aspect ratio is then scaled along the
+/// other axis alone. Empty geometries, which have no bounding rectangle, are left unchanged.
+///
+/// ## Performance
+///
+/// To transform other geometries the same way, e.g. the rest of a layer, get the transformation
+/// with [`fit_into_transform`](Self::fit_into_transform) and apply it with
+/// [`AffineOps`](crate::AffineOps).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, FitInto, Rect};
+///
+/// let line_string = line_string![(x: 10., y: 10.), (x: 30., y: 20.)];
+/// let target = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 100., y: 100. });
+///
+/// let (_, stretched) = line_string.fit_into(target, false);
+/// assert_eq!(stretched, line_string![(x: 0., y: 0.), (x: 100., y: 100.)]);
+///
+/// let (transform, fitted) = line_string.fit_into(target, true);
+/// assert_eq!(fitted, line_string![(x: 0., y: 25.), (x: 100., y: 75.)]);
+/// assert_eq!(transform.apply(coord! { x: 20., y: 15. }), coord! { x: 50., y: 50. });
+/// ```
+pub trait FitInto<T: CoordFloat> {
+    /// The transformation fitting the geometry into `target`, preserving its aspect ratio or
+    /// not.
+    fn fit_into_transform(&self, target: Rect<T>, preserve_aspect: bool) -> AffineTransform<T>;
+
+    /// Fit the geometry into `target`, preserving its aspect ratio or not, returning the
+    /// transformation along with the transformed geometry.
+    #[must_use]
+    fn fit_into(&self, target: Rect<T>, preserve_aspect: bool) -> (AffineTransform<T>, Self);
+}
+
+impl<T, IR, G> FitInto<T> for G
+where
+    T: CoordFloat,
+    IR: Into<Option<Rect<T>>>,
+    G: AffineOps<T> + BoundingRect<T, Output = IR>,
+{
+    fn fit_into_transform(&self, target: Rect<T>, preserve_aspect: bool) -> AffineTransform<T> {
+        let bounds = match self.bounding_rect().into() {
+            Some(rect) => rect,
+            None => return AffineTransform::identity(),
+        };
+        let factor = |extent: T, target_extent: T| {
+            if extent > T::zero() {
+                Some(target_extent / extent)
+            } else {
+                None
+            }
+        };
+        let x_factor = factor(bounds.width(), target.width());
+        let y_factor = factor(bounds.height(), target.height());
+        let (x_factor, y_factor) = if preserve_aspect {
+            let factor = match (x_factor, y_factor) {
+                (Some(x_factor), Some(y_factor)) => x_factor.min(y_factor),
+                (Some(factor), None) | (None, Some(factor)) => factor,
+                (None, None) => T::one(),
+            };
+            (factor, factor)
+        } else {
+            (
+                x_factor.unwrap_or_else(T::one),
+                y_factor.unwrap_or_else(T::one),
+            )
+        };
+
+        let (center, target_center) = (bounds.center(), target.center());
+        AffineTransform::new(
+            x_factor,
+            T::zero(),
+            target_center.x - x_factor * center.x,
+            T::zero(),
+            y_factor,
+            target_center.y - y_factor * center.y,
+        )
+    }
+
+    fn fit_into(&self, target: Rect<T>, preserve_aspect: bool) -> (AffineTransform<T>, Self) {
+        let transform = self.fit_into_transform(target, preserve_aspect);
+        let fitted = self.affine_transform(&transform);
+        (transform, fitted)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, Geometry, LineString};
+
+    fn target() -> Rect<f64> {
+        Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 20. })
+    }
+
+    #[test]
+    fn stretched_and_preserved() {
+        let poly = polygon![(x: -2., y: 1.), (x: 2., y: 1.), (x: 2., y: 3.), (x: -2., y: 3.)];
+
+        let (_, stretched) = poly.fit_into(target(), false);
+        assert_eq!(
+            stretched,
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 20.), (x: 0., y: 20.)]
+        );
+
+        // as wide as the target, and centered along its height
+        let (_, fitted) = poly.fit_into(target(), true);
+        assert_eq!(
+            fitted,
+            polygon![(x: 0., y: 7.5), (x: 10., y: 7.5), (x: 10., y: 12.5), (x: 0., y: 12.5)]
+        );
+
+        let geometry = Geometry::from(poly);
+        assert_eq!(
+            geometry.fit_into(target(), true).1.bounding_rect(),
+            Some(Rect::new(
+                coord! { x: 0., y: 7.5 },
+                coord! { x: 10., y: 12.5 }
+            ))
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        // vertical
+        let line_string = line_string![(x: 1., y: 0.), (x: 1., y: 5.)];
+        assert_eq!(
+            line_string.fit_into(target(), false).1,
+            line_string![(x: 5., y: 0.), (x: 5., y: 20.)]
+        );
+        assert_eq!(
+            line_string.fit_into(target(), true).1,
+            line_string![(x: 5., y: 0.), (x: 5., y: 20.)]
+        );
+
+        let point = point!(x: 3., y: 3.);
+        assert_eq!(point.fit_into(target(), true).1, point!(x: 5., y: 10.));
+
+        let empty = LineString::<f64>::new(vec![]);
+        let (transform, fitted) = empty.fit_into(target(), true);
+        assert!(transform.is_identity());
+        assert_eq!(fitted, empty);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::area::get_linestring_area;
+use crate::{
+    Coord, FillHoles, GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon, Simplify,
+};
+
+/// The sizes, in the units of the coordinates, to generalize geometries with by
+/// [`GeneralizeForScale`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Generalization<T: GeoFloat> {
+    /// The spacing of the grid coordinates are snapped to, 0 not to snap them.
+    pub grid: T,
+    /// The tolerance of the Ramer–Douglas–Peucker simplification.
+    pub tolerance: T,
+    /// The length below which line strings are dropped.
+    pub min_length: T,
+    /// The area below which polygons are dropped and holes filled.
+    pub min_area: T,
+}
+
+impl<T: GeoFloat> Generalization<T> {
+    /// The sizes for a map at a scale of 1:`scale_denominator`, for coordinates in meters: a
+    /// grid of 0.05 mm on the map, a tolerance of 0.2 mm, a length of 0.5 mm and an area of
+    /// 0.5 mm², below which details can't be told apart in print, or on screen at the standard
+    /// pixel size of 0.28 mm.
+    pub fn for_scale(scale_denominator: T) -> Self {
+        // a millimeter on the map
+        let millimeter = scale_denominator / T::from(1000).unwrap();
+        let on_map = |millimeters: f64| T::from(millimeters).unwrap() * millimeter;
+        Generalization {
+            grid: on_map(0.05),
+            tolerance: on_map(0.2),
+            min_length: on_map(0.5),
+            min_area: on_map(0.5) * millimeter,
+        }
+    }
+}
+
+/// Generalize a geometry for a map scale, e.g. for each zoom level of a web map, in one step
+/// rather than tuning each pass by hand.
+///
+/// The coordinates are snapped to a grid, removing repeated consecutive ones, and the line
+/// strings and rings are simplified with [`Simplify`](crate::Simplify). Then line strings
+/// shorter than a minimum length and polygons smaller than a minimum area are dropped, holes
+/// smaller than that area are filled with [`FillHoles`](crate::FillHoles), and so are rings
+/// with no area left. The sizes are chosen from the scale by [`Generalization::for_scale`], or
+/// given as a [`Generalization`].
+///
+/// The coordinates must be in meters, e.g. in a projected coordinate system. Like
+/// [`Simplify`](crate::Simplify), generalization may make polygons invalid.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, GeneralizeForScale, MultiPolygon};
+///
+/// let lakes = MultiPolygon::new(vec![
+///     polygon![(x: 0., y: 0.), (x: 1000., y: 0.), (x: 1000., y: 1000.), (x: 0., y: 1000.)],
+///     // a pond of 100 m²
+///     polygon![(x: 2000., y: 0.), (x: 2010., y: 0.), (x: 2010., y: 10.), (x: 2000., y: 10.)],
+/// ]);
+///
+/// // at 1:100,000, the smallest area drawn is 5,000 m²
+/// let generalized = lakes.generalize_for_scale(100_000.);
+/// assert_eq!(generalized, MultiPolygon::new(vec![lakes.0[0].clone()]));
+/// ```
+pub trait GeneralizeForScale<T: GeoFloat> {
+    /// The geometry generalized for a map at a scale of 1:`scale_denominator`.
+    fn generalize_for_scale(&self, scale_denominator: T) -> Self
+    where
+        Self: Sized,
+    {
+        self.generalize_with(&Generalization::for_scale(scale_denominator))
+    }
+
+    /// The geometry generalized with the sizes of `generalization`. Dropped geometries are
+    /// empty.
+    fn generalize_with(&self, generalization: &Generalization<T>) -> Self;
+}
+
+impl<T: GeoFloat> GeneralizeForScale<T> for LineString<T> {
+    fn generalize_with(&self, generalization: &Generalization<T>) -> Self {
+        let simplified = snap(self, generalization.grid).simplify(&generalization.tolerance);
+        let length = simplified.lines().fold(T::zero(), |length, line| {
+            let delta = line.delta();
+            length + delta.x.hypot(delta.y)
+        });
+        if simplified.0.len() < 2 || length < generalization.min_length {
+            return LineString::new(vec![]);
+        }
+        simplified
+    }
+}
+
+impl<T: GeoFloat> GeneralizeForScale<T> for MultiLineString<T> {
+    fn generalize_with(&self, generalization: &Generalization<T>) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|line_string| line_string.generalize_with(generalization))
+                .filter(|line_string| !line_string.0.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> GeneralizeForScale<T> for Polygon<T> {
+    fn generalize_with(&self, generalization: &Generalization<T>) -> Self {
+        let exterior = match ring(self.exterior(), generalization) {
+            Some(exterior) if get_linestring_area(&exterior).abs() >= generalization.min_area => {
+                exterior
+            }
+            _ => return Polygon::new(LineString::new(vec![]), vec![]),
+        };
+        let interiors = self
+            .interiors()
+            .iter()
+            .filter_map(|interior| ring(interior, generalization))
+            .collect();
+        Polygon::new(exterior, interiors).fill_holes(generalization.min_area)
+    }
+}
+
+impl<T: GeoFloat> GeneralizeForScale<T> for MultiPolygon<T> {
+    fn generalize_with(&self, generalization: &Generalization<T>) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.generalize_with(generalization))
+                .filter(|polygon| !polygon.exterior().0.is_empty())
+                .collect(),
+        )
+    }
+}
+
+/// A ring snapped and simplified, or `None` if it has no area left.
+fn ring<T: GeoFloat>(
+    ring: &LineString<T>,
+    generalization: &Generalization<T>,
+) -> Option<LineString<T>> {
+    let simplified = snap(ring, generalization.grid).simplify(&generalization.tolerance);
+    (simplified.0.len() >= 4 && get_linestring_area(&simplified) != T::zero()).then(|| simplified)
+}
+
+/// A line string with its coordinates snapped to a grid, without repeated consecutive ones.
+fn snap<T: GeoFloat>(line_string: &LineString<T>, grid: T) -> LineString<T> {
+    let mut coords: Vec<_> = if grid > T::zero() {
+        line_string
+            .coords()
+            .map(|coord| Coord {
+                x: (coord.x / grid).round() * grid,
+                y: (coord.y / grid).round() * grid,
+            })
+            .collect()
+    } else {
+        line_string.0.clone()
+    };
+    coords.dedup();
+    LineString::new(coords)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn for_scale() {
+        let generalization = Generalization::for_scale(10_000.);
+        assert_relative_eq!(generalization.grid, 0.5);
+        assert_relative_eq!(generalization.tolerance, 2.);
+        assert_relative_eq!(generalization.min_length, 5.);
+        assert_relative_eq!(generalization.min_area, 50.);
+    }
+
+    #[test]
+    fn generalize() {
+        let generalization = Generalization {
+            grid: 1.,
+            tolerance: 1.,
+            min_length: 5.,
+            min_area: 10.,
+        };
+
+        let road = line_string![
+            (x: 0.2, y: 0.),
+            (x: 5., y: 0.4),
+            (x: 10., y: -0.3),
+            (x: 10., y: 0.2),
+        ];
+        assert_eq!(
+            road.generalize_with(&generalization),
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)]
+        );
+        let lines = MultiLineString::new(vec![road, line_string![(x: 0., y: 0.), (x: 3., y: 0.)]]);
+        assert_eq!(lines.generalize_with(&generalization).0.len(), 1);
+
+        let field = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                // filled
+                [(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+                // collapsed by snapping
+                [(x: 5., y: 5.), (x: 5.2, y: 5.), (x: 5.2, y: 5.2)],
+                [(x: 4., y: 4.), (x: 8., y: 4.), (x: 8., y: 8.), (x: 4., y: 8.)],
+            ],
+        ];
+        let generalized = field.generalize_with(&generalization);
+        assert_eq!(generalized.exterior(), field.exterior());
+        assert_eq!(generalized.interiors(), &field.interiors()[2..]);
+
+        let fields = MultiPolygon::new(vec![
+            field,
+            polygon![(x: 20., y: 0.), (x: 23., y: 0.), (x: 23., y: 3.), (x: 20., y: 3.)],
+        ]);
+        assert_eq!(fields.generalize_with(&generalization).0.len(), 1);
+    }
+}
@@ -0,0 +1,448 @@
+use std::cmp::Ordering;
+
+use crate::{
+    Coord, CoordFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// The bounds of a geometry of longitudes and latitudes, in degrees, as calculated by
+/// [`GeodesicBoundingRect`].
+///
+/// Following the [GeoJSON] convention, bounds crossing the antimeridian have their `west` edge
+/// greater than their `east` edge.
+///
+/// [GeoJSON]: https://www.rfc-editor.org/rfc/rfc7946#section-5.2
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeodesicBounds<T: CoordFloat> {
+    pub west: T,
+    pub south: T,
+    pub east: T,
+    pub north: T,
+}
+
+impl<T: CoordFloat> GeodesicBounds<T> {
+    /// Whether the bounds cross the antimeridian, i.e. their `west` edge is greater than their
+    /// `east` edge.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// The bounds as planar rectangles: the bounds themselves, or, if they cross the
+    /// antimeridian, their parts on either side of it, from `west` to 180 first.
+    pub fn rects(&self) -> Vec<Rect<T>> {
+        let rect = |west: T, east: T| Rect::new((west, self.south), (east, self.north));
+        if self.crosses_antimeridian() {
+            vec![
+                rect(self.west, T::from(180).unwrap()),
+                rect(T::from(-180).unwrap(), self.east),
+            ]
+        } else {
+            vec![rect(self.west, self.east)]
+        }
+    }
+}
+
+/// Calculate the bounds of a geometry of longitudes and latitudes, in degrees, taking its
+/// segments as great circle arcs.
+///
+/// The planar [`BoundingRect`](crate::BoundingRect) of such a geometry is wrong in two ways:
+/// great circle arcs bulge towards the poles, beyond the latitudes of their ends, and a
+/// geometry crossing the antimeridian spans the world in longitude. Here, the bounds include
+/// the highest and lowest points of the arcs, and they're the narrowest range of longitudes
+/// covering the geometry, crossing the antimeridian if need be, in which case their `west`
+/// edge is greater than their `east` edge.
+///
+/// Arcs go the shorter way around, so consecutive coordinates shouldn't be 180° of longitude
+/// apart. A polygon ring going all the way around the world covers a pole: the North Pole
+/// going east, and the South Pole going west, as for an exterior ring wound counter-clockwise.
+///
+/// The arcs are on a sphere: the geodesics of the ellipsoid, as used by
+/// [`GeodesicLength`](crate::GeodesicLength), bulge by slightly different amounts.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, GeodesicBoundingRect};
+///
+/// let line_string = line_string![(x: -45., y: 45.), (x: 45., y: 45.)];
+/// let bounds = line_string.geodesic_bounding_rect().unwrap();
+/// assert_eq!((bounds.west, bounds.east, bounds.south), (-45., 45., 45.));
+/// // the arc reaches further north than its ends
+/// assert_relative_eq!(bounds.north, 2f64.sqrt().atan().to_degrees(), epsilon = 1e-9);
+///
+/// let line_string = line_string![(x: 170., y: 0.), (x: -170., y: 0.)];
+/// let bounds = line_string.geodesic_bounding_rect().unwrap();
+/// assert!(bounds.crosses_antimeridian());
+/// assert_eq!((bounds.west, bounds.east), (170., -170.));
+/// ```
+pub trait GeodesicBoundingRect<T: CoordFloat> {
+    /// The bounds of the geometry, or `None` if it's empty.
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>>;
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for Point<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        bounds.add_coord(self.0);
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for Line<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        bounds.add_line(*self);
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for LineString<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        bounds.add_line_string(self);
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for Polygon<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        bounds.add_polygon(self);
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for MultiPoint<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        for point in self {
+            bounds.add_coord(point.0);
+        }
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for MultiLineString<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        for line_string in self {
+            bounds.add_line_string(line_string);
+        }
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for MultiPolygon<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        for polygon in self {
+            bounds.add_polygon(polygon);
+        }
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for Rect<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        self.to_polygon().geodesic_bounding_rect()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for Triangle<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        self.to_polygon().geodesic_bounding_rect()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for Geometry<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        bounds.add_geometry(self);
+        bounds.finish()
+    }
+}
+
+impl<T: CoordFloat> GeodesicBoundingRect<T> for GeometryCollection<T> {
+    fn geodesic_bounding_rect(&self) -> Option<GeodesicBounds<T>> {
+        let mut bounds = Bounds::new();
+        for geometry in self {
+            bounds.add_geometry(geometry);
+        }
+        bounds.finish()
+    }
+}
+
+fn full_turn<T: CoordFloat>() -> T {
+    T::from(360).unwrap()
+}
+
+// Wrap a longitude, or a difference of longitudes, into the range from -180 to 180.
+fn wrap<T: CoordFloat>(x: T) -> T {
+    let half_turn = T::from(180).unwrap();
+    x - ((x + half_turn) / full_turn()).floor() * full_turn()
+}
+
+// The unit vector of a coordinate on the sphere.
+fn to_vector<T: CoordFloat>(coord: Coord<T>) -> [T; 3] {
+    let (lon, lat) = (coord.x.to_radians(), coord.y.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn cross<T: CoordFloat>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot<T: CoordFloat>(a: [T; 3], b: [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// The latitudes and ranges of longitudes covered by a geometry so far.
+struct Bounds<T: CoordFloat> {
+    south: T,
+    north: T,
+    // the start of each range, and its extent eastwards
+    longitudes: Vec<(T, T)>,
+}
+
+impl<T: CoordFloat> Bounds<T> {
+    fn new() -> Self {
+        Bounds {
+            south: T::infinity(),
+            north: T::neg_infinity(),
+            longitudes: vec![],
+        }
+    }
+
+    fn add_latitude(&mut self, latitude: T) {
+        self.south = self.south.min(latitude);
+        self.north = self.north.max(latitude);
+    }
+
+    fn add_coord(&mut self, coord: Coord<T>) {
+        self.add_latitude(coord.y);
+        self.longitudes.push((wrap(coord.x), T::zero()));
+    }
+
+    fn add_line(&mut self, line: Line<T>) {
+        self.add_coord(line.start);
+        self.add_coord(line.end);
+        let delta = wrap(line.end.x - line.start.x);
+        if delta >= T::zero() {
+            self.longitudes.push((wrap(line.start.x), delta));
+        } else {
+            self.longitudes.push((wrap(line.end.x), -delta));
+        }
+
+        // the highest point of the great circle, where it's furthest from the equator
+        let (start, end) = (to_vector(line.start), to_vector(line.end));
+        let normal = cross(start, end);
+        let length = dot(normal, normal).sqrt();
+        if length == T::zero() {
+            return;
+        }
+        let n = normal.map(|c| c / length);
+        let highest = [-n[2] * n[0], -n[2] * n[1], T::one() - n[2] * n[2]];
+        if highest[2] <= T::zero() {
+            // along the equator
+            return;
+        }
+        let latitude = highest[2].atan2(highest[0].hypot(highest[1])).to_degrees();
+        let on_arc = |point: [T; 3]| {
+            dot(cross(start, point), normal) > T::zero()
+                && dot(cross(point, end), normal) > T::zero()
+        };
+        if on_arc(highest) {
+            self.add_latitude(latitude);
+        }
+        if on_arc(highest.map(|c| -c)) {
+            self.add_latitude(-latitude);
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>) {
+        if let [coord] = line_string.0[..] {
+            self.add_coord(coord);
+        }
+        for line in line_string.lines() {
+            self.add_line(line);
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        // the interiors are within the exterior
+        let exterior = polygon.exterior();
+        self.add_line_string(exterior);
+        let turned = exterior.lines().fold(T::zero(), |turned, line| {
+            turned + wrap(line.end.x - line.start.x)
+        });
+        if turned.abs() > T::from(180).unwrap() {
+            let pole = T::from(90).unwrap();
+            self.add_latitude(if turned > T::zero() { pole } else { -pole });
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &Geometry<T>) {
+        match geometry {
+            Geometry::Point(g) => self.add_coord(g.0),
+            Geometry::Line(g) => self.add_line(*g),
+            Geometry::LineString(g) => self.add_line_string(g),
+            Geometry::Polygon(g) => self.add_polygon(g),
+            Geometry::MultiPoint(g) => g.iter().for_each(|point| self.add_coord(point.0)),
+            Geometry::MultiLineString(g) => g.iter().for_each(|ls| self.add_line_string(ls)),
+            Geometry::MultiPolygon(g) => g.iter().for_each(|polygon| self.add_polygon(polygon)),
+            Geometry::GeometryCollection(g) => g.iter().for_each(|g| self.add_geometry(g)),
+            Geometry::Rect(g) => self.add_polygon(&g.to_polygon()),
+            Geometry::Triangle(g) => self.add_polygon(&g.to_polygon()),
+        }
+    }
+
+    // The bounds, between the ends of the widest gap between the ranges of longitudes.
+    fn finish(mut self) -> Option<GeodesicBounds<T>> {
+        self.longitudes
+            .sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        let mut merged: Vec<(T, T)> = vec![];
+        for &(start, extent) in &self.longitudes {
+            let end = start + extent;
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        // the ranges the last one reaches round to
+        while merged.len() > 1 && merged[0].0 + full_turn() <= merged[merged.len() - 1].1 {
+            let first = merged.remove(0);
+            let last = merged.last_mut().unwrap();
+            last.1 = last.1.max(first.1 + full_turn());
+        }
+
+        let (first, last) = (*merged.first()?, *merged.last()?);
+        let mut gap = (last.1, first.0 + full_turn());
+        for pair in merged.windows(2) {
+            if pair[1].0 - pair[0].1 > gap.1 - gap.0 {
+                gap = (pair[0].1, pair[1].0);
+            }
+        }
+        let (west, east) = if gap.1 > gap.0 {
+            let west = wrap(gap.1);
+            let east = west + full_turn() - (gap.1 - gap.0);
+            let half_turn = T::from(180).unwrap();
+            (
+                west,
+                if east > half_turn {
+                    east - full_turn()
+                } else {
+                    east
+                },
+            )
+        } else {
+            (T::from(-180).unwrap(), T::from(180).unwrap())
+        };
+        Some(GeodesicBounds {
+            west,
+            south: self.south,
+            east,
+            north: self.north,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, BoundingRect, MultiPoint};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn bulges() {
+        let north = line_string![(x: -45., y: 45.), (x: 45., y: 45.)];
+        let bounds = north.geodesic_bounding_rect().unwrap();
+        assert_relative_eq!(bounds.north, 54.735610317245346, epsilon = 1e-9);
+        assert_eq!(bounds.south, 45.);
+        assert_eq!(north.bounding_rect().unwrap().max().y, 45.);
+
+        let south = Line::new((-45., -45.), (45., -45.));
+        let bounds = south.geodesic_bounding_rect().unwrap();
+        assert_relative_eq!(bounds.south, -54.735610317245346, epsilon = 1e-9);
+        assert_eq!(bounds.north, -45.);
+
+        // the highest point is beyond the end of the arc
+        let bounds = Line::new((0., 45.), (30., 50.))
+            .geodesic_bounding_rect()
+            .unwrap();
+        assert_eq!((bounds.south, bounds.north), (45., 50.));
+    }
+
+    #[test]
+    fn antimeridian() {
+        let line_string = line_string![(x: 170., y: 0.), (x: -170., y: 0.)];
+        let bounds = line_string.geodesic_bounding_rect().unwrap();
+        assert_eq!(
+            bounds,
+            GeodesicBounds {
+                west: 170.,
+                south: 0.,
+                east: -170.,
+                north: 0.
+            }
+        );
+        assert_eq!(
+            bounds.rects(),
+            vec![
+                Rect::new((170., 0.), (180., 0.)),
+                Rect::new((-180., 0.), (-170., 0.))
+            ]
+        );
+
+        // the widest gap is from -170 to 10
+        let points = MultiPoint::new(vec![
+            point!(x: -170., y: 0.),
+            point!(x: 10., y: 0.),
+            point!(x: 170., y: 0.),
+        ]);
+        let bounds = points.geodesic_bounding_rect().unwrap();
+        assert_eq!((bounds.west, bounds.east), (10., -170.));
+
+        let line_string = line_string![(x: 170., y: 0.), (x: 180., y: 0.)];
+        let bounds = line_string.geodesic_bounding_rect().unwrap();
+        assert!(!bounds.crosses_antimeridian());
+        assert_eq!((bounds.west, bounds.east), (170., 180.));
+    }
+
+    #[test]
+    fn points_and_poles() {
+        let bounds = point!(x: 10., y: 20.).geodesic_bounding_rect().unwrap();
+        assert_eq!(
+            bounds,
+            GeodesicBounds {
+                west: 10.,
+                south: 20.,
+                east: 10.,
+                north: 20.
+            }
+        );
+
+        let polar = polygon![(x: 0., y: 80.), (x: 120., y: 80.), (x: -120., y: 80.)];
+        let bounds = polar.geodesic_bounding_rect().unwrap();
+        assert_eq!(
+            bounds,
+            GeodesicBounds {
+                west: -180.,
+                south: 80.,
+                east: 180.,
+                north: 90.
+            }
+        );
+        assert_eq!(Geometry::from(polar).geodesic_bounding_rect(), Some(bounds));
+
+        assert_eq!(
+            LineString::<f64>::new(vec![]).geodesic_bounding_rect(),
+            None
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use crate::orient::{Direction, Orient};
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+
+use geojson::{Feature, FeatureCollection, GeoJson, PolygonType, Position, Value};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Convert a geometry to its [GeoJSON](https://www.rfc-editor.org/rfc/rfc7946) representation.
+///
+/// The output follows RFC 7946: exterior rings are wound counter-clockwise and interior rings
+/// clockwise, whatever the orientation of the input, and `Rect`s, `Triangle`s and `Line`s are
+/// written as `Polygon`s and `LineString`s. Coordinates are assumed to be longitudes and
+/// latitudes, so the `bbox` member is `[west, south, east, north]`, where `west > east` if the
+/// tightest box spanning the geometry crosses the antimeridian.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Geometry, ToGeoJson};
+///
+/// // wound clockwise
+/// let polygon: Geometry<f64> = polygon![
+///     (x: 0., y: 0.),
+///     (x: 0., y: 10.),
+///     (x: 10., y: 10.),
+///     (x: 10., y: 0.),
+/// ]
+/// .into();
+///
+/// let geojson = polygon.to_geojson();
+/// assert_eq!(geojson.bbox, Some(vec![0., 0., 10., 10.]));
+///
+/// let expected = vec![vec![
+///     vec![0., 0.],
+///     vec![10., 0.],
+///     vec![10., 10.],
+///     vec![0., 10.],
+///     vec![0., 0.],
+/// ]];
+/// assert_eq!(geojson.value, geojson::Value::Polygon(expected));
+///
+/// // crossing the antimeridian
+/// let line: Geometry<f64> = line_string![(x: 170., y: 0.), (x: -175., y: 5.)].into();
+/// assert_eq!(line.to_geojson().bbox, Some(vec![170., 0., -175., 5.]));
+/// ```
+pub trait ToGeoJson {
+    type Output;
+
+    /// Convert `self` to GeoJSON, with a `bbox` member.
+    fn to_geojson(&self) -> Self::Output;
+}
+
+/// Convert [GeoJSON](https://www.rfc-editor.org/rfc/rfc7946) to a geometry.
+///
+/// A `Feature` is converted to its geometry, and a `FeatureCollection` to a
+/// `GeometryCollection` of the geometries of its features. Ring orientation is kept as it is in
+/// the input, and any coordinates beyond the second of each position are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Geometry, TryFromGeoJson};
+/// use geojson::GeoJson;
+///
+/// let geojson: GeoJson = r#"{
+///     "type": "Feature",
+///     "properties": { "name": "Null Island" },
+///     "geometry": { "type": "Point", "coordinates": [0.0, 0.0, 12.0] }
+/// }"#
+/// .parse()
+/// .unwrap();
+///
+/// let geometry = Geometry::<f64>::try_from_geojson(&geojson).unwrap();
+/// assert_eq!(geometry, point!(x: 0., y: 0.).into());
+/// ```
+pub trait TryFromGeoJson: Sized {
+    /// Convert `geojson` to `Self`, failing if it holds a different kind of geometry, or
+    /// positions which can't be represented.
+    fn try_from_geojson(geojson: &GeoJson) -> Result<Self, GeoJsonError>;
+}
+
+/// The ways converting GeoJSON to a geometry can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoJsonError {
+    /// A position with fewer than two values, holding its number of values.
+    InvalidPosition(usize),
+    /// A coordinate which can't be represented by the coordinate type.
+    InvalidCoordinate(f64),
+    /// A `Feature` without a geometry.
+    MissingGeometry,
+    /// A geometry of a different kind than the one being converted to.
+    MismatchedGeometry,
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoJsonError::InvalidPosition(len) => write!(
+                f,
+                "expected a position of at least 2 values, found {} values",
+                len
+            ),
+            GeoJsonError::InvalidCoordinate(value) => {
+                write!(f, "coordinate {} isn't representable", value)
+            }
+            GeoJsonError::MissingGeometry => write!(f, "feature has no geometry"),
+            GeoJsonError::MismatchedGeometry => {
+                write!(f, "GeoJSON holds a different kind of geometry")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+impl<T> ToGeoJson for Geometry<T>
+where
+    T: GeoFloat + Into<f64>,
+{
+    type Output = geojson::Geometry;
+
+    fn to_geojson(&self) -> Self::Output {
+        let value = geometry_value(self);
+        geojson::Geometry {
+            bbox: value_bbox(&value),
+            value,
+            foreign_members: None,
+        }
+    }
+}
+
+impl<T> ToGeoJson for GeometryCollection<T>
+where
+    T: GeoFloat + Into<f64>,
+{
+    type Output = FeatureCollection;
+
+    /// Each geometry of the collection becomes a `Feature` without properties.
+    fn to_geojson(&self) -> Self::Output {
+        let features: Vec<Feature> = self
+            .iter()
+            .map(|geometry| {
+                let geometry = geometry.to_geojson();
+                Feature {
+                    bbox: geometry.bbox.clone(),
+                    geometry: Some(geometry),
+                    id: None,
+                    properties: None,
+                    foreign_members: None,
+                }
+            })
+            .collect();
+        let bbox = bbox(
+            features
+                .iter()
+                .filter_map(|feature| feature.geometry.as_ref())
+                .flat_map(|geometry| value_positions(&geometry.value)),
+        );
+        FeatureCollection {
+            bbox,
+            features,
+            foreign_members: None,
+        }
+    }
+}
+
+impl<T: GeoFloat> TryFromGeoJson for Geometry<T> {
+    fn try_from_geojson(geojson: &GeoJson) -> Result<Self, GeoJsonError> {
+        match geojson {
+            GeoJson::Geometry(geometry) => try_geometry(&geometry.value),
+            GeoJson::Feature(feature) => try_feature(feature),
+            GeoJson::FeatureCollection(collection) => Ok(Geometry::GeometryCollection(
+                try_feature_collection(collection)?,
+            )),
+        }
+    }
+}
+
+impl<T: GeoFloat> TryFromGeoJson for GeometryCollection<T> {
+    /// Any GeoJSON can be converted to a `GeometryCollection`: a single geometry which isn't a
+    /// `GeometryCollection` becomes a collection of one geometry.
+    fn try_from_geojson(geojson: &GeoJson) -> Result<Self, GeoJsonError> {
+        match geojson {
+            GeoJson::FeatureCollection(collection) => try_feature_collection(collection),
+            _ => match Geometry::try_from_geojson(geojson)? {
+                Geometry::GeometryCollection(collection) => Ok(collection),
+                geometry => Ok(GeometryCollection::new_from(vec![geometry])),
+            },
+        }
+    }
+}
+
+macro_rules! try_from_geojson_impl {
+    ($($type:ident),*) => {
+        $(
+            impl<T: GeoFloat> TryFromGeoJson for $type<T> {
+                fn try_from_geojson(geojson: &GeoJson) -> Result<Self, GeoJsonError> {
+                    Geometry::<T>::try_from_geojson(geojson)?
+                        .try_into()
+                        .map_err(|_| GeoJsonError::MismatchedGeometry)
+                }
+            }
+        )*
+    };
+}
+
+try_from_geojson_impl!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon
+);
+
+fn position<T: GeoFloat + Into<f64>>(coord: Coord<T>) -> Position {
+    vec![coord.x.into(), coord.y.into()]
+}
+
+fn line_string_positions<T: GeoFloat + Into<f64>>(line_string: &LineString<T>) -> Vec<Position> {
+    line_string.coords().copied().map(position).collect()
+}
+
+// The rings of `polygon`, wound as RFC 7946 requires.
+fn polygon_rings<T: GeoFloat + Into<f64>>(polygon: &Polygon<T>) -> PolygonType {
+    if polygon.exterior().0.is_empty() {
+        return vec![];
+    }
+    let polygon = polygon.orient(Direction::Default);
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(line_string_positions)
+        .collect()
+}
+
+fn geometry_value<T: GeoFloat + Into<f64>>(geometry: &Geometry<T>) -> Value {
+    match geometry {
+        Geometry::Point(point) => Value::Point(position(point.0)),
+        Geometry::Line(line) => Value::LineString(vec![position(line.start), position(line.end)]),
+        Geometry::LineString(line_string) => Value::LineString(line_string_positions(line_string)),
+        Geometry::Polygon(polygon) => Value::Polygon(polygon_rings(polygon)),
+        Geometry::MultiPoint(multi_point) => {
+            Value::MultiPoint(multi_point.iter().map(|point| position(point.0)).collect())
+        }
+        Geometry::MultiLineString(multi_line_string) => Value::MultiLineString(
+            multi_line_string
+                .iter()
+                .map(line_string_positions)
+                .collect(),
+        ),
+        Geometry::MultiPolygon(multi_polygon) => {
+            Value::MultiPolygon(multi_polygon.iter().map(polygon_rings).collect())
+        }
+        Geometry::GeometryCollection(collection) => Value::GeometryCollection(
+            collection
+                .iter()
+                .map(|geometry| geojson::Geometry::new(geometry_value(geometry)))
+                .collect(),
+        ),
+        Geometry::Rect(rect) => Value::Polygon(polygon_rings(&rect.to_polygon())),
+        Geometry::Triangle(triangle) => Value::Polygon(polygon_rings(&triangle.to_polygon())),
+    }
+}
+
+fn value_positions(value: &Value) -> Box<dyn Iterator<Item = &Position> + '_> {
+    match value {
+        Value::Point(position) => Box::new(std::iter::once(position)),
+        Value::MultiPoint(positions) | Value::LineString(positions) => Box::new(positions.iter()),
+        Value::MultiLineString(lines) | Value::Polygon(lines) => Box::new(lines.iter().flatten()),
+        Value::MultiPolygon(polygons) => Box::new(polygons.iter().flatten().flatten()),
+        Value::GeometryCollection(geometries) => Box::new(
+            geometries
+                .iter()
+                .flat_map(|geometry| value_positions(&geometry.value)),
+        ),
+    }
+}
+
+fn value_bbox(value: &Value) -> Option<Vec<f64>> {
+    bbox(value_positions(value))
+}
+
+// The `[west, south, east, north]` box spanning `positions`. Longitudes wrap around, so the
+// narrowest span is the complement of the widest gap between consecutive longitudes, which may
+// be the one across the antimeridian (giving an ordinary box) or any other one (giving a box
+// with `west > east`).
+fn bbox<'a>(positions: impl Iterator<Item = &'a Position>) -> Option<Vec<f64>> {
+    let mut longitudes = vec![];
+    let (mut south, mut north) = (f64::INFINITY, f64::NEG_INFINITY);
+    for position in positions {
+        longitudes.push(position[0]);
+        south = south.min(position[1]);
+        north = north.max(position[1]);
+    }
+    longitudes.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let (&first, &last) = (longitudes.first()?, longitudes.last()?);
+
+    let (mut west, mut east) = (first, last);
+    let mut widest_gap = first + 360. - last;
+    for pair in longitudes.windows(2) {
+        if pair[1] - pair[0] > widest_gap {
+            widest_gap = pair[1] - pair[0];
+            west = pair[1];
+            east = pair[0];
+        }
+    }
+    Some(vec![west, south, east, north])
+}
+
+fn try_coord<T: GeoFloat>(position: &[f64]) -> Result<Coord<T>, GeoJsonError> {
+    let value = |v: f64| T::from(v).ok_or(GeoJsonError::InvalidCoordinate(v));
+    match position {
+        [x, y, ..] => Ok(Coord {
+            x: value(*x)?,
+            y: value(*y)?,
+        }),
+        _ => Err(GeoJsonError::InvalidPosition(position.len())),
+    }
+}
+
+fn try_line_string<T: GeoFloat>(positions: &[Position]) -> Result<LineString<T>, GeoJsonError> {
+    positions
+        .iter()
+        .map(|position| try_coord(position))
+        .collect::<Result<Vec<_>, _>>()
+        .map(LineString::new)
+}
+
+fn try_polygon<T: GeoFloat>(rings: &[Vec<Position>]) -> Result<Polygon<T>, GeoJsonError> {
+    let mut rings = rings.iter().map(|ring| try_line_string(ring));
+    let exterior = rings
+        .next()
+        .unwrap_or_else(|| Ok(LineString::new(vec![])))?;
+    Ok(Polygon::new(exterior, rings.collect::<Result<_, _>>()?))
+}
+
+fn try_geometry<T: GeoFloat>(value: &Value) -> Result<Geometry<T>, GeoJsonError> {
+    Ok(match value {
+        Value::Point(position) => Point(try_coord(position)?).into(),
+        Value::MultiPoint(positions) => MultiPoint::new(
+            positions
+                .iter()
+                .map(|position| try_coord(position).map(Point))
+                .collect::<Result<_, _>>()?,
+        )
+        .into(),
+        Value::LineString(positions) => try_line_string(positions)?.into(),
+        Value::MultiLineString(lines) => MultiLineString::new(
+            lines
+                .iter()
+                .map(|line| try_line_string(line))
+                .collect::<Result<_, _>>()?,
+        )
+        .into(),
+        Value::Polygon(rings) => try_polygon(rings)?.into(),
+        Value::MultiPolygon(polygons) => MultiPolygon::new(
+            polygons
+                .iter()
+                .map(|rings| try_polygon(rings))
+                .collect::<Result<_, _>>()?,
+        )
+        .into(),
+        Value::GeometryCollection(geometries) => {
+            Geometry::GeometryCollection(GeometryCollection::new_from(
+                geometries
+                    .iter()
+                    .map(|geometry| try_geometry(&geometry.value))
+                    .collect::<Result<_, _>>()?,
+            ))
+        }
+    })
+}
+
+fn try_feature<T: GeoFloat>(feature: &Feature) -> Result<Geometry<T>, GeoJsonError> {
+    let geometry = feature
+        .geometry
+        .as_ref()
+        .ok_or(GeoJsonError::MissingGeometry)?;
+    try_geometry(&geometry.value)
+}
+
+fn try_feature_collection<T: GeoFloat>(
+    collection: &FeatureCollection,
+) -> Result<GeometryCollection<T>, GeoJsonError> {
+    collection
+        .features
+        .iter()
+        .map(try_feature)
+        .collect::<Result<_, _>>()
+        .map(GeometryCollection::new_from)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Rect, Triangle, Winding};
+
+    #[test]
+    fn round_trip() {
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 1., y: 2.).into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into(),
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+            ]
+            .into(),
+            MultiPoint::new(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]).into(),
+            MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 1., y: 1.)]]).into(),
+            MultiPolygon::new(vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)],
+            ])
+            .into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 1., y: 2.).into()
+            ])),
+        ];
+        for geometry in geometries {
+            let geojson = GeoJson::Geometry(geometry.to_geojson());
+            assert_eq!(Geometry::try_from_geojson(&geojson), Ok(geometry));
+        }
+    }
+
+    #[test]
+    fn rings_are_reoriented() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let geojson = GeoJson::Geometry(Geometry::Polygon(polygon).to_geojson());
+        let polygon = Polygon::<f64>::try_from_geojson(&geojson).unwrap();
+        assert!(polygon.exterior().is_ccw());
+        assert!(polygon.interiors()[0].is_cw());
+
+        let rect = Rect::new((0., 0.), (1., 1.));
+        let triangle = Triangle::from([(0., 0.), (0., 1.), (1., 0.)]);
+        for geometry in [Geometry::Rect(rect), Geometry::Triangle(triangle)] {
+            let geojson = GeoJson::Geometry(geometry.to_geojson());
+            let polygon = Polygon::<f64>::try_from_geojson(&geojson).unwrap();
+            assert!(polygon.exterior().is_ccw());
+        }
+    }
+
+    #[test]
+    fn bbox_across_the_antimeridian() {
+        let line: Geometry<f64> = line_string![
+            (x: 175., y: -5.),
+            (x: -178., y: 2.),
+            (x: 179., y: 8.),
+        ]
+        .into();
+        assert_eq!(line.to_geojson().bbox, Some(vec![175., -5., -178., 8.]));
+
+        let line: Geometry<f64> = line_string![(x: -20., y: 1.), (x: 30., y: 2.)].into();
+        assert_eq!(line.to_geojson().bbox, Some(vec![-20., 1., 30., 2.]));
+
+        let empty: Geometry<f64> = LineString::new(vec![]).into();
+        assert_eq!(empty.to_geojson().bbox, None);
+    }
+
+    #[test]
+    fn feature_collections() {
+        let collection = GeometryCollection::<f64>::new_from(vec![
+            point!(x: 170., y: 1.).into(),
+            point!(x: -170., y: -1.).into(),
+        ]);
+        let features = collection.to_geojson();
+        assert_eq!(features.features.len(), 2);
+        assert_eq!(features.bbox, Some(vec![170., -1., -170., 1.]));
+
+        let geojson = GeoJson::FeatureCollection(features);
+        assert_eq!(
+            GeometryCollection::try_from_geojson(&geojson),
+            Ok(collection.clone())
+        );
+        assert_eq!(
+            Geometry::try_from_geojson(&geojson),
+            Ok(Geometry::GeometryCollection(collection))
+        );
+
+        let point = point!(x: 1., y: 2.);
+        let geojson = GeoJson::Geometry(Geometry::Point(point).to_geojson());
+        assert_eq!(
+            GeometryCollection::try_from_geojson(&geojson),
+            Ok(GeometryCollection::new_from(vec![point.into()]))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let wrap = |value| GeoJson::Geometry(geojson::Geometry::new(value));
+        assert_eq!(
+            Geometry::<f64>::try_from_geojson(&wrap(Value::Point(vec![1.]))),
+            Err(GeoJsonError::InvalidPosition(1))
+        );
+        assert_eq!(
+            LineString::<f64>::try_from_geojson(&wrap(Value::Point(vec![1., 2.]))),
+            Err(GeoJsonError::MismatchedGeometry)
+        );
+
+        let feature = GeoJson::Feature(Feature {
+            bbox: None,
+            geometry: None,
+            id: None,
+            properties: None,
+            foreign_members: None,
+        });
+        assert_eq!(
+            Geometry::<f64>::try_from_geojson(&feature),
+            Err(GeoJsonError::MissingGeometry)
+        );
+    }
+}
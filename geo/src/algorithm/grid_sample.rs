@@ -0,0 +1,266 @@
+use crate::{
+    BoundingRect, Coord, EuclideanLength, GeoFloat, Intersects, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+#[cfg(feature = "use-rand")]
+use rand::Rng;
+
+/// Sample points on a regular grid within a geometry.
+///
+/// For areal geometries, the bounding rectangle of the geometry is divided into square cells with
+/// sides of length `spacing`, starting from its minimum coordinate. One point is placed in each
+/// cell, and kept if it intersects the geometry. For linear geometries, each component is divided
+/// into consecutive intervals of length `spacing`, measured from its start, and one point is
+/// placed along each interval.
+///
+/// [`grid_sample`](Self::grid_sample) places points at the center of every cell or interval,
+/// producing a regular lattice. Stratified sampling, where each point is placed randomly within
+/// its cell, is available through [`jittered_grid_sample`](Self::jittered_grid_sample) (requires
+/// the `use-rand` feature) or by providing the offsets directly with
+/// [`grid_sample_by`](Self::grid_sample_by).
+///
+/// # Panics
+///
+/// If `spacing` is not greater than 0.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, GridSample};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 2.),
+///     (x: 0., y: 2.),
+/// ];
+/// let samples = polygon.grid_sample(1.);
+/// assert_eq!(samples.0.len(), 8);
+/// assert_eq!(samples.0[0].x_y(), (0.5, 0.5));
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.)];
+/// let samples = line_string.grid_sample(2.);
+/// assert_eq!(
+///     samples.iter().map(|p| p.x_y()).collect::<Vec<_>>(),
+///     vec![(1., 0.), (3., 0.), (3., 2.)],
+/// );
+/// ```
+pub trait GridSample<T: GeoFloat> {
+    /// Sample one point at the center of every grid cell, or of every interval along linear
+    /// geometries.
+    fn grid_sample(&self, spacing: T) -> MultiPoint<T> {
+        let half = T::from(0.5).unwrap();
+        self.grid_sample_by(spacing, &mut || half)
+    }
+
+    /// Sample one point at a uniformly random position within every grid cell, or every interval
+    /// along linear geometries.
+    #[cfg(feature = "use-rand")]
+    fn jittered_grid_sample<R: Rng + ?Sized>(&self, spacing: T, rng: &mut R) -> MultiPoint<T> {
+        self.grid_sample_by(spacing, &mut || T::from(rng.gen::<f64>()).unwrap())
+    }
+
+    /// Sample one point within every grid cell, or every interval along linear geometries, at a
+    /// position given by `offset`.
+    ///
+    /// `offset` is called once per dimension for every cell (first for `x`, then for `y`), and
+    /// must return a fraction of the cell size in the range `[0, 1)`.
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T>;
+}
+
+impl<T: GeoFloat> GridSample<T> for Polygon<T> {
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T> {
+        sample_area(self, self.bounding_rect(), spacing, offset)
+    }
+}
+
+impl<T: GeoFloat> GridSample<T> for MultiPolygon<T> {
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T> {
+        sample_area(self, self.bounding_rect(), spacing, offset)
+    }
+}
+
+impl<T: GeoFloat> GridSample<T> for Rect<T> {
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T> {
+        sample_area(self, Some(*self), spacing, offset)
+    }
+}
+
+impl<T: GeoFloat> GridSample<T> for Triangle<T> {
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T> {
+        self.to_polygon().grid_sample_by(spacing, offset)
+    }
+}
+
+impl<T: GeoFloat> GridSample<T> for Line<T> {
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T> {
+        LineString::from(*self).grid_sample_by(spacing, offset)
+    }
+}
+
+impl<T: GeoFloat> GridSample<T> for LineString<T> {
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T> {
+        assert!(spacing > T::zero(), "grid spacing must be positive");
+        let length = self
+            .lines()
+            .fold(T::zero(), |length, line| length + line.euclidean_length());
+        let distances = (0..cell_count(length, spacing))
+            .map(|i| (T::from(i).unwrap() + offset()) * spacing)
+            .filter(|distance| *distance <= length);
+        MultiPoint::new(points_at_distances(self, distances))
+    }
+}
+
+impl<T: GeoFloat> GridSample<T> for MultiLineString<T> {
+    fn grid_sample_by(&self, spacing: T, offset: &mut dyn FnMut() -> T) -> MultiPoint<T> {
+        self.iter()
+            .flat_map(|line_string| line_string.grid_sample_by(spacing, offset))
+            .collect()
+    }
+}
+
+fn cell_count<T: GeoFloat>(extent: T, spacing: T) -> usize {
+    (extent / spacing).ceil().to_usize().unwrap_or(0)
+}
+
+fn sample_area<T, G>(
+    geometry: &G,
+    bounds: Option<Rect<T>>,
+    spacing: T,
+    offset: &mut dyn FnMut() -> T,
+) -> MultiPoint<T>
+where
+    T: GeoFloat,
+    G: Intersects<Coord<T>>,
+{
+    assert!(spacing > T::zero(), "grid spacing must be positive");
+    let bounds = match bounds {
+        Some(bounds) => bounds,
+        None => return MultiPoint::new(vec![]),
+    };
+    let min = bounds.min();
+    let columns = cell_count(bounds.width(), spacing);
+    let rows = cell_count(bounds.height(), spacing);
+
+    let mut points = vec![];
+    for row in 0..rows {
+        for column in 0..columns {
+            let x = min.x + (T::from(column).unwrap() + offset()) * spacing;
+            let y = min.y + (T::from(row).unwrap() + offset()) * spacing;
+            let coord = Coord { x, y };
+            if geometry.intersects(&coord) {
+                points.push(Point::from(coord));
+            }
+        }
+    }
+    MultiPoint::new(points)
+}
+
+// Interpolate points at the given, increasing, distances from the start of a `LineString`.
+fn points_at_distances<T: GeoFloat>(
+    line_string: &LineString<T>,
+    distances: impl Iterator<Item = T>,
+) -> Vec<Point<T>> {
+    let mut lines = line_string.lines();
+    let mut current = lines.next();
+    // distance from the start of the `LineString` to the start of the current line
+    let mut traversed = T::zero();
+    let mut points = vec![];
+    for distance in distances {
+        while let Some(line) = current {
+            let length = line.euclidean_length();
+            if distance <= traversed + length {
+                let fraction = if length > T::zero() {
+                    (distance - traversed) / length
+                } else {
+                    T::zero()
+                };
+                points.push(Point::from(line.start + line.delta() * fraction));
+                break;
+            }
+            traversed = traversed + length;
+            current = lines.next();
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn polygon_with_hole() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        let samples = poly.grid_sample(1.);
+        assert_eq!(samples.0.len(), 100 - 36);
+        assert!(samples.iter().all(|p| poly.intersects(p)));
+    }
+
+    #[test]
+    fn rect_partial_cells() {
+        let rect = Rect::new((0., 0.), (2.5, 1.));
+        let samples = rect.grid_sample(1.);
+        assert_eq!(
+            samples.iter().map(|p| p.x_y()).collect::<Vec<_>>(),
+            vec![(0.5, 0.5), (1.5, 0.5), (2.5, 0.5)]
+        );
+    }
+
+    #[test]
+    fn custom_offsets() {
+        let rect = Rect::new((0., 0.), (2., 2.));
+        let samples = rect.grid_sample_by(1., &mut || 0.);
+        assert_eq!(
+            samples.iter().map(|p| p.x_y()).collect::<Vec<_>>(),
+            vec![(0., 0.), (1., 0.), (0., 1.), (1., 1.)]
+        );
+    }
+
+    #[test]
+    fn multi_line_string() {
+        let mls = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+            line_string![(x: 0., y: 1.), (x: 0.5, y: 1.)],
+        ]);
+        let samples = mls.grid_sample(1.);
+        assert_eq!(samples.0.len(), 4 + 1);
+        assert_eq!(samples.0[4].x_y(), (0.5, 1.));
+    }
+
+    #[test]
+    fn empty_geometries() {
+        assert!(LineString::<f64>::new(vec![]).grid_sample(1.).0.is_empty());
+        assert!(MultiPolygon::<f64>::new(vec![])
+            .grid_sample(1.)
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "grid spacing must be positive")]
+    fn zero_spacing() {
+        line_string![(x: 0., y: 0.), (x: 1., y: 0.)].grid_sample(0.);
+    }
+
+    #[cfg(feature = "use-rand")]
+    #[test]
+    fn jittered() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let rect: Rect = Rect::new((0., 0.), (10., 10.));
+        let mut rng = StdRng::seed_from_u64(3);
+        let samples = rect.jittered_grid_sample(1., &mut rng);
+        assert_eq!(samples.0.len(), 100);
+        for (i, point) in samples.iter().enumerate() {
+            assert_eq!(point.x().floor() as usize, i % 10);
+            assert_eq!(point.y().floor() as usize, i / 10);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use crate::{Coord, CoordFloat, Geometry, MapCoords, Normalize, Polygon};
+
+/// Hash geometries tolerantly, so that near-identical geometries, such as the same features
+/// from different datasets, can be deduplicated with a `HashSet` or `HashMap`.
+///
+/// The coordinates are snapped to a grid of square cells `precision` wide, centred on the
+/// multiples of `precision`, giving a geometry of integer cell coordinates: its repeated
+/// consecutive vertices are removed, and it's [normalized](Normalize) so that it doesn't
+/// depend on where rings start, which way round they go, or the order of parts.
+///
+/// Coordinates closer than `precision / 2` usually snap to the same cell, but not always:
+/// coordinates on either side of the border of two cells don't, however close they are. The
+/// coordinates must be finite, and within `i64::MAX` cells of the origin.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use geo::{polygon, GeoHashable};
+///
+/// let polygons = vec![
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+///     // the same square, starting at another corner, with a slight error
+///     polygon![(x: 1., y: 1.), (x: 0.000_01, y: 1.), (x: 0., y: 0.), (x: 1., y: 0.)],
+///     polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+/// ];
+///
+/// let mut seen = HashSet::new();
+/// let unique: Vec<_> = polygons
+///     .iter()
+///     .filter(|polygon| seen.insert(polygon.hash_key(0.001)))
+///     .collect();
+/// assert_eq!(unique.len(), 2);
+/// ```
+pub trait GeoHashable<T: CoordFloat> {
+    /// The geometry snapped to a grid of cells `precision` wide, and normalized, in integer
+    /// cell coordinates: geometries with equal keys are the same, up to `precision`.
+    fn hash_key(&self, precision: T) -> Geometry<i64>;
+
+    /// Feed the [`hash_key`](Self::hash_key) of the geometry into `state`.
+    fn geo_hash<H: Hasher>(&self, precision: T, state: &mut H) {
+        self.hash_key(precision).hash(state)
+    }
+}
+
+impl<T, G> GeoHashable<T> for G
+where
+    T: CoordFloat,
+    G: MapCoords<T, i64>,
+    G::Output: Into<Geometry<i64>>,
+{
+    fn hash_key(&self, precision: T) -> Geometry<i64> {
+        let cell = |value: T| {
+            (value / precision)
+                .round()
+                .to_i64()
+                .expect("coordinates must be finite, and within i64::MAX cells of the origin")
+        };
+        let mut geometry = self
+            .map_coords(|coord| Coord {
+                x: cell(coord.x),
+                y: cell(coord.y),
+            })
+            .into();
+        remove_repeated_vertices(&mut geometry);
+        geometry.normalize()
+    }
+}
+
+/// Remove the duplicates from `geometries`, e.g. the features found in several overlapping
+/// datasets being merged, keeping the first of each, and the order of the geometries kept.
+///
+/// Two geometries are duplicates if their [`hash_key`](GeoHashable::hash_key) with
+/// `tolerance` as the precision is the same, i.e. if they're equal once snapped to a grid of
+/// cells `tolerance` wide and normalized. Exact duplicates always are, but see
+/// [`GeoHashable`] for the near-identical geometries which aren't.
+///
+/// Returns the indices, in the original `geometries`, of the geometries removed, in ascending
+/// order.
+///
+/// Note: `tolerance` must be greater than 0.
+///
+/// # Examples
+///
+/// ```
+/// use geo::hashable::dedup_geometries;
+/// use geo::{point, Geometry, Rect};
+///
+/// let mut geometries: Vec<Geometry<f64>> = vec![
+///     point!(x: 1., y: 2.).into(),
+///     Rect::new((0., 0.), (1., 1.)).into(),
+///     point!(x: 1.000_01, y: 2.).into(),
+///     point!(x: 1., y: 2.).into(),
+/// ];
+///
+/// assert_eq!(dedup_geometries(&mut geometries, 0.001), vec![2, 3]);
+/// assert_eq!(geometries.len(), 2);
+/// ```
+pub fn dedup_geometries<T: CoordFloat>(
+    geometries: &mut Vec<Geometry<T>>,
+    tolerance: T,
+) -> Vec<usize> {
+    let mut seen = HashSet::new();
+    let mut dropped = vec![];
+    let mut idx = 0;
+    geometries.retain(|geometry| {
+        let unique = seen.insert(geometry.hash_key(tolerance));
+        if !unique {
+            dropped.push(idx);
+        }
+        idx += 1;
+        unique
+    });
+    dropped
+}
+
+fn remove_repeated_vertices(geometry: &mut Geometry<i64>) {
+    let polygon = |polygon: &mut Polygon<i64>| {
+        polygon.exterior_mut(|exterior| exterior.0.dedup());
+        polygon.interiors_mut(|interiors| {
+            for interior in interiors {
+                interior.0.dedup();
+            }
+        });
+    };
+    match geometry {
+        Geometry::LineString(line_string) => line_string.0.dedup(),
+        Geometry::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string {
+                line_string.0.dedup();
+            }
+        }
+        Geometry::Polygon(p) => polygon(p),
+        Geometry::MultiPolygon(multi_polygon) => multi_polygon.iter_mut().for_each(polygon),
+        Geometry::GeometryCollection(collection) => {
+            collection.iter_mut().for_each(remove_repeated_vertices)
+        }
+        Geometry::Point(_)
+        | Geometry::Line(_)
+        | Geometry::MultiPoint(_)
+        | Geometry::Rect(_)
+        | Geometry::Triangle(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Line, MultiPolygon, Rect};
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash<G: GeoHashable<f64>>(geometry: &G, precision: f64) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        geometry.geo_hash(precision, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn snapping() {
+        let a = point!(x: 1.000_01, y: 2.);
+        let b = point!(x: 0.999_99, y: 2.000_02);
+        assert_eq!(a.hash_key(0.001), b.hash_key(0.001));
+        assert_eq!(a.hash_key(0.001), point!(x: 1000, y: 2000).into());
+        assert_eq!(hash(&a, 0.001), hash(&b, 0.001));
+        assert_ne!(a.hash_key(0.000_001), b.hash_key(0.000_001));
+
+        // a line and a line string aren't the same geometry
+        let line = Line::new((0., 0.), (1., 1.));
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_ne!(line.hash_key(0.1), line_string.hash_key(0.1));
+    }
+
+    #[test]
+    fn normalizing() {
+        let a = Rect::new((0., 0.), (2., 2.)).to_polygon();
+        // reversed, from another corner, with a vertex which snaps onto the next one
+        let b = polygon![
+            (x: 2., y: 2.),
+            (x: 2.000_1, y: 0.000_1),
+            (x: 2., y: 0.),
+            (x: 0., y: 0.),
+            (x: 0., y: 2.),
+        ];
+        assert_eq!(a.hash_key(0.01), b.hash_key(0.01));
+
+        let c = Rect::new((5., 5.), (6., 6.)).to_polygon();
+        assert_eq!(
+            MultiPolygon::new(vec![a.clone(), c.clone()]).hash_key(0.01),
+            MultiPolygon::new(vec![c, b]).hash_key(0.01),
+        );
+        assert_ne!(
+            a.hash_key(0.01),
+            Rect::new((0., 0.), (2., 2.1)).to_polygon().hash_key(0.01)
+        );
+    }
+
+    #[test]
+    fn dedup() {
+        let square = Rect::new((0., 0.), (2., 2.)).to_polygon();
+        let mut geometries: Vec<Geometry<f64>> = vec![
+            square.clone().into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into(),
+            // the same square, as a rectangle
+            Rect::new((0., 0.), (2., 2.)).into(),
+            // the same line string, backwards, with a repeated vertex
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.), (x: 0.000_1, y: 0.)].into(),
+            // the same square, as a multi-polygon
+            MultiPolygon::new(vec![square.clone()]).into(),
+            square.into(),
+        ];
+        let expected = vec![
+            geometries[0].clone(),
+            geometries[1].clone(),
+            geometries[2].clone(),
+            geometries[4].clone(),
+        ];
+
+        assert_eq!(dedup_geometries(&mut geometries, 0.01), vec![3, 5]);
+        assert_eq!(geometries, expected);
+
+        assert!(dedup_geometries(&mut geometries, 0.01).is_empty());
+    }
+}
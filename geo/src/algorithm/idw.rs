@@ -0,0 +1,199 @@
+use crate::{Coord, GeoFloat, Point, Rect};
+
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+
+/// Interpolate values at arbitrary points from scattered samples using
+/// [inverse distance weighting](https://en.wikipedia.org/wiki/Inverse_distance_weighting).
+///
+/// The value at a point is the average of the sample values, each weighted by the inverse of its
+/// distance to the point raised to `power` (2 by default). Higher powers give more influence to
+/// the closest samples. The samples are indexed in an R-tree, so that interpolation can be
+/// restricted to the `k` nearest samples with [`with_neighbors`](Self::with_neighbors), which is
+/// much faster for large sample sets.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Idw};
+///
+/// let idw = Idw::new(vec![
+///     (point!(x: 0., y: 0.), 10.),
+///     (point!(x: 2., y: 0.), 20.),
+///     (point!(x: 10., y: 10.), 100.),
+/// ])
+/// .with_neighbors(2);
+///
+/// assert_eq!(idw.interpolate(point!(x: 1., y: 0.)), Some(15.));
+/// // samples are interpolated exactly
+/// assert_eq!(idw.interpolate(point!(x: 10., y: 10.)), Some(100.));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Idw<T: GeoFloat> {
+    tree: RTree<GeomWithData<Point<T>, T>>,
+    power: T,
+    neighbors: Option<usize>,
+}
+
+impl<T: GeoFloat> Idw<T> {
+    /// Create an interpolator from `(location, value)` samples.
+    pub fn new(samples: impl IntoIterator<Item = (Point<T>, T)>) -> Self {
+        let samples = samples
+            .into_iter()
+            .map(|(point, value)| GeomWithData::new(point, value))
+            .collect();
+        Idw {
+            tree: RTree::bulk_load(samples),
+            power: T::one() + T::one(),
+            neighbors: None,
+        }
+    }
+
+    /// Set the power applied to distances when weighting samples.
+    pub fn with_power(mut self, power: T) -> Self {
+        self.power = power;
+        self
+    }
+
+    /// Only use the `k` samples nearest to each point. By default, all samples are used.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is 0.
+    pub fn with_neighbors(mut self, k: usize) -> Self {
+        assert!(k > 0, "at least one neighbor is needed to interpolate");
+        self.neighbors = Some(k);
+        self
+    }
+
+    /// The interpolated value at `point`, or `None` if there are no samples.
+    ///
+    /// If `point` coincides with a sample, the sample's value is returned.
+    pub fn interpolate(&self, point: Point<T>) -> Option<T> {
+        let neighbors = self
+            .tree
+            .nearest_neighbor_iter_with_distance_2(&point)
+            .take(self.neighbors.unwrap_or(usize::MAX));
+
+        // distances are squared, so halve the exponent
+        let exponent = -self.power / (T::one() + T::one());
+        let mut weighted_sum = T::zero();
+        let mut total_weight = T::zero();
+        for (sample, distance_2) in neighbors {
+            if distance_2 == T::zero() {
+                return Some(sample.data);
+            }
+            let weight = distance_2.powf(exponent);
+            weighted_sum = weighted_sum + weight * sample.data;
+            total_weight = total_weight + weight;
+        }
+        if total_weight == T::zero() {
+            None
+        } else {
+            Some(weighted_sum / total_weight)
+        }
+    }
+
+    /// Interpolate values at the nodes of a regular grid with `width` columns and `height` rows
+    /// spanning `bounds`, in row-major order from the minimum corner.
+    ///
+    /// The result can be contoured with [`ContourGrid`](crate::ContourGrid), using a transform
+    /// mapping grid nodes to `bounds`. Returns an empty `Vec` if there are no samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, AffineTransform, ContourGrid, Idw, Rect};
+    ///
+    /// let idw = Idw::new(vec![(point!(x: 0., y: 0.), 0.), (point!(x: 20., y: 0.), 10.)]);
+    /// let bounds = Rect::new((0., 0.), (20., 10.));
+    /// let values = idw.interpolate_grid(bounds, 11, 6);
+    /// assert_eq!(values.len(), 66);
+    ///
+    /// // nodes are spaced 2 units apart
+    /// let transform = AffineTransform::scale(2., 2., (0., 0.));
+    /// let grid = ContourGrid::new(values, 11, 6).with_transform(transform);
+    /// assert_eq!(grid.isolines(5.).0.len(), 1);
+    /// ```
+    pub fn interpolate_grid(&self, bounds: Rect<T>, width: usize, height: usize) -> Vec<T> {
+        let step = |extent: T, count: usize| {
+            if count > 1 {
+                extent / T::from(count - 1).unwrap()
+            } else {
+                T::zero()
+            }
+        };
+        let dx = step(bounds.width(), width);
+        let dy = step(bounds.height(), height);
+        let min = bounds.min();
+
+        let mut values = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                let coord = Coord {
+                    x: min.x + dx * T::from(col).unwrap(),
+                    y: min.y + dy * T::from(row).unwrap(),
+                };
+                match self.interpolate(coord.into()) {
+                    Some(value) => values.push(value),
+                    None => return vec![],
+                }
+            }
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn weights_by_inverse_distance() {
+        let idw = Idw::new(vec![(point!(x: 0., y: 0.), 0.), (point!(x: 3., y: 0.), 3.)]);
+        // weights are 1 / 1² and 1 / 2²
+        assert_relative_eq!(idw.interpolate(point!(x: 1., y: 0.)).unwrap(), 0.6);
+        let linear = idw.clone().with_power(1.);
+        assert_relative_eq!(linear.interpolate(point!(x: 1., y: 0.)).unwrap(), 1.);
+    }
+
+    #[test]
+    fn nearest_neighbors() {
+        let idw = Idw::new(vec![
+            (point!(x: 0., y: 0.), 1.),
+            (point!(x: 1., y: 0.), 2.),
+            (point!(x: 100., y: 0.), 1000.),
+        ]);
+        assert!(idw.interpolate(point!(x: 0.5, y: 0.)).unwrap() > 1.5);
+        let nearest = idw.with_neighbors(2);
+        assert_relative_eq!(nearest.interpolate(point!(x: 0.5, y: 0.)).unwrap(), 1.5);
+        assert_relative_eq!(
+            nearest
+                .with_neighbors(1)
+                .interpolate(point!(x: 90., y: 0.))
+                .unwrap(),
+            1000.
+        );
+    }
+
+    #[test]
+    fn grid() {
+        let idw = Idw::new(vec![(point!(x: 0., y: 0.), 1.), (point!(x: 4., y: 2.), 5.)]);
+        let values = idw.interpolate_grid(Rect::new((0., 0.), (4., 2.)), 3, 2);
+        assert_eq!(values.len(), 6);
+        assert_eq!(values[0], 1.);
+        assert_eq!(values[5], 5.);
+        // the weights of the samples are swapped at these nodes
+        assert_relative_eq!(values[1] + values[4], 6.);
+    }
+
+    #[test]
+    fn no_samples() {
+        let idw = Idw::<f64>::new(vec![]);
+        assert_eq!(idw.interpolate(point!(x: 0., y: 0.)), None);
+        assert!(idw
+            .interpolate_grid(Rect::new((0., 0.), (1., 1.)), 2, 2)
+            .is_empty());
+    }
+}
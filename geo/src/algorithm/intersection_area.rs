@@ -0,0 +1,280 @@
+use std::cmp::Ordering;
+use std::iter;
+
+use rstar::{RTree, RTreeObject};
+
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::orient::{Direction, Orient};
+use crate::{
+    Area, BoundingRect, Coord, GeoFloat, Intersects, Line, LineString, MultiPolygon, Polygon,
+};
+
+/// Calculate the area of the intersection of two polygons, without computing the intersection
+/// itself.
+///
+/// By Green's theorem, the area of a polygon is an integral over its boundary, and the
+/// boundary of the intersection of two polygons is made of the parts of the boundary of each
+/// polygon lying inside the other. Summing over these parts is much faster than assembling
+/// the intersection with [`BooleanOps`](crate::BooleanOps), and then measuring it, which makes
+/// it fit for scoring many pairs of polygons, e.g. the intersection over union of the
+/// predictions of object detection models.
+///
+/// The polygons must be valid, and so must be the polygons of multi-polygons, which must not
+/// overlap one another.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{IntersectionArea, Rect};
+///
+/// let a = Rect::new((0., 0.), (4., 4.)).to_polygon();
+/// let b = Rect::new((2., 1.), (6., 3.)).to_polygon();
+/// assert_eq!(a.intersection_area(&b), 4.);
+///
+/// // 4 / (16 + 8 - 4)
+/// assert_eq!(a.intersection_over_union(&b), 0.2);
+/// ```
+pub trait IntersectionArea<T: GeoFloat, Rhs = Self> {
+    fn intersection_area(&self, other: &Rhs) -> T;
+
+    /// The [Jaccard index] of the two geometries: the area of their intersection over the area
+    /// of their union, from 0, for geometries which don't overlap, to 1, for equal geometries.
+    /// It's 0 if both geometries are empty.
+    ///
+    /// [Jaccard index]: https://en.wikipedia.org/wiki/Jaccard_index
+    fn intersection_over_union(&self, other: &Rhs) -> T
+    where
+        Self: Area<T>,
+        Rhs: Area<T>,
+    {
+        let intersection = self.intersection_area(other);
+        let union = self.unsigned_area() + other.unsigned_area() - intersection;
+        if union > T::zero() {
+            intersection / union
+        } else {
+            T::zero()
+        }
+    }
+}
+
+impl<T: GeoFloat> IntersectionArea<T> for Polygon<T> {
+    fn intersection_area(&self, other: &Polygon<T>) -> T {
+        polygons_intersection_area(self, other)
+    }
+}
+
+impl<T: GeoFloat> IntersectionArea<T, MultiPolygon<T>> for Polygon<T> {
+    fn intersection_area(&self, other: &MultiPolygon<T>) -> T {
+        other.iter().fold(T::zero(), |sum, polygon| {
+            sum + polygons_intersection_area(self, polygon)
+        })
+    }
+}
+
+impl<T: GeoFloat> IntersectionArea<T, Polygon<T>> for MultiPolygon<T> {
+    fn intersection_area(&self, other: &Polygon<T>) -> T {
+        other.intersection_area(self)
+    }
+}
+
+impl<T: GeoFloat> IntersectionArea<T> for MultiPolygon<T> {
+    fn intersection_area(&self, other: &MultiPolygon<T>) -> T {
+        self.iter().fold(T::zero(), |sum, polygon| {
+            sum + polygon.intersection_area(other)
+        })
+    }
+}
+
+fn polygons_intersection_area<T: GeoFloat>(a: &Polygon<T>, b: &Polygon<T>) -> T {
+    let (a_rect, b_rect) = match (a.bounding_rect(), b.bounding_rect()) {
+        (Some(a_rect), Some(b_rect)) => (a_rect, b_rect),
+        _ => return T::zero(),
+    };
+    if !a_rect.intersects(&b_rect) {
+        return T::zero();
+    }
+    // both passes must follow the same conventions, and take the same origin
+    let (a, b) = (a.orient(Direction::Default), b.orient(Direction::Default));
+    let origin = a_rect.min();
+    let twice_area =
+        boundary_integral(&a, &b, true, origin) + boundary_integral(&b, &a, false, origin);
+    (twice_area / (T::one() + T::one())).max(T::zero())
+}
+
+fn rings<T: GeoFloat>(polygon: &Polygon<T>) -> impl Iterator<Item = &LineString<T>> {
+    iter::once(polygon.exterior()).chain(polygon.interiors())
+}
+
+// Twice the contribution of the parts of the boundary of `subject` inside `clip` to the area of
+// their intersection, about `origin`. Where the boundaries overlap, both pass along the same
+// part of the boundary of the intersection, if they go the same way: it's only counted if
+// `shared`, so that it's counted once.
+fn boundary_integral<T: GeoFloat>(
+    subject: &Polygon<T>,
+    clip: &Polygon<T>,
+    shared: bool,
+    origin: Coord<T>,
+) -> T {
+    let tree: RTree<Line<T>> =
+        RTree::bulk_load(rings(clip).flat_map(|ring| ring.lines()).collect());
+    let cross = |start: Coord<T>, end: Coord<T>| {
+        let (start, end) = (start - origin, end - origin);
+        start.x * end.y - end.x * start.y
+    };
+    let mut sum = T::zero();
+    for ring in rings(subject) {
+        // the position of the current segment, while segments don't meet the boundary of `clip`
+        let mut position = None;
+        for segment in ring.lines() {
+            let along = |c: Coord<T>| {
+                let (delta, offset) = (segment.delta(), c - segment.start);
+                offset.x * delta.x + offset.y * delta.y
+            };
+            let mut splits: Vec<(T, Coord<T>)> = vec![];
+            let mut overlaps: Vec<(T, T, bool)> = vec![];
+            for other in tree.locate_in_envelope_intersecting(&segment.envelope()) {
+                match line_intersection(segment, *other) {
+                    Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                        splits.push((along(intersection), intersection));
+                    }
+                    Some(LineIntersection::Collinear { intersection }) => {
+                        let (start, end) = (along(intersection.start), along(intersection.end));
+                        splits.push((start, intersection.start));
+                        splits.push((end, intersection.end));
+                        let (delta, other_delta) = (segment.delta(), other.delta());
+                        let same_way =
+                            delta.x * other_delta.x + delta.y * other_delta.y > T::zero();
+                        overlaps.push((start.min(end), start.max(end), same_way));
+                    }
+                    None => {}
+                }
+            }
+
+            if splits.is_empty() {
+                // the segment is wholly inside or outside, like its neighbours meeting nothing
+                let position =
+                    *position.get_or_insert_with(|| clip.coordinate_position(&segment.start));
+                if position == CoordPos::Inside {
+                    sum = sum + cross(segment.start, segment.end);
+                }
+                continue;
+            }
+            position = None;
+
+            splits.push((T::zero(), segment.start));
+            splits.push((along(segment.end), segment.end));
+            splits.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            for pair in splits.windows(2) {
+                let ((t0, start), (t1, end)) = (pair[0], pair[1]);
+                if t1 <= t0 {
+                    continue;
+                }
+                let overlap = overlaps
+                    .iter()
+                    .find(|&&(min, max, _)| min <= t0 && t1 <= max);
+                let inside = match overlap {
+                    Some(&(_, _, same_way)) => shared && same_way,
+                    None => {
+                        let two = T::one() + T::one();
+                        let middle = Coord {
+                            x: (start.x + end.x) / two,
+                            y: (start.y + end.y) / two,
+                        };
+                        clip.coordinate_position(&middle) == CoordPos::Inside
+                    }
+                };
+                if inside {
+                    sum = sum + cross(start, end);
+                }
+            }
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, BooleanOps, Rect};
+    use approx::assert_relative_eq;
+
+    fn assert_matches_overlay(a: &Polygon<f64>, b: &Polygon<f64>) {
+        let expected = a.intersection(b).unsigned_area();
+        assert_relative_eq!(a.intersection_area(b), expected, epsilon = 1e-9);
+        assert_relative_eq!(b.intersection_area(a), expected, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn rects() {
+        let a = Rect::new((0., 0.), (4., 4.)).to_polygon();
+        assert_eq!(a.intersection_area(&a), 16.);
+        // contained
+        assert_eq!(
+            a.intersection_area(&Rect::new((1., 1.), (2., 3.)).to_polygon()),
+            2.
+        );
+        // disjoint, or only touching
+        assert_eq!(
+            a.intersection_area(&Rect::new((5., 5.), (6., 6.)).to_polygon()),
+            0.
+        );
+        assert_eq!(
+            a.intersection_area(&Rect::new((4., 0.), (6., 4.)).to_polygon()),
+            0.
+        );
+        // sharing part of their boundary
+        assert_eq!(
+            a.intersection_area(&Rect::new((0., 0.), (2., 6.)).to_polygon()),
+            8.
+        );
+        assert_eq!(
+            a.intersection_area(&Rect::new((-1., 1.), (4., 2.)).to_polygon()),
+            4.
+        );
+    }
+
+    #[test]
+    fn holes_and_concavities() {
+        let a = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.)]],
+        );
+        let b = polygon![
+            (x: 1., y: 1.),
+            (x: 12., y: 5.),
+            (x: 1., y: 9.),
+            (x: 5., y: 5.),
+        ];
+        assert_matches_overlay(&a, &b);
+
+        // exactly filling the hole
+        let hole = Rect::new((2., 2.), (8., 8.)).to_polygon();
+        assert_eq!(a.intersection_area(&hole), 0.);
+        assert_eq!(
+            a.intersection_area(&Rect::new((1., 1.), (9., 9.)).to_polygon()),
+            28.
+        );
+
+        // clockwise, starting anywhere
+        let b = polygon![(x: 5., y: 5.), (x: 1., y: 9.), (x: 12., y: 5.), (x: 1., y: 1.)];
+        assert_matches_overlay(&a, &b);
+    }
+
+    #[test]
+    fn multi_polygons() {
+        let a = MultiPolygon::new(vec![
+            Rect::new((0., 0.), (2., 2.)).to_polygon(),
+            Rect::new((3., 0.), (5., 2.)).to_polygon(),
+        ]);
+        let b = Rect::new((1., 1.), (4., 3.)).to_polygon();
+        assert_eq!(a.intersection_area(&b), 2.);
+        assert_eq!(b.intersection_area(&a), 2.);
+        assert_eq!(a.intersection_area(&a), 8.);
+        assert_relative_eq!(a.intersection_over_union(&b), 2. / 12.);
+        assert_eq!(
+            MultiPolygon::<f64>::new(vec![]).intersection_over_union(&MultiPolygon::new(vec![])),
+            0.
+        );
+    }
+}
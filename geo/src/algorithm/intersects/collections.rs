@@ -25,7 +25,9 @@ where
 symmetric_intersects_impl!(Coord<T>, Geometry<T>);
 symmetric_intersects_impl!(Line<T>, Geometry<T>);
 symmetric_intersects_impl!(Rect<T>, Geometry<T>);
+symmetric_intersects_impl!(Triangle<T>, Geometry<T>);
 symmetric_intersects_impl!(Polygon<T>, Geometry<T>);
+symmetric_intersects_impl!(LineString<T>, Geometry<T>);
 
 impl<T, G> Intersects<G> for GeometryCollection<T>
 where
@@ -43,4 +45,6 @@ where
 symmetric_intersects_impl!(Coord<T>, GeometryCollection<T>);
 symmetric_intersects_impl!(Line<T>, GeometryCollection<T>);
 symmetric_intersects_impl!(Rect<T>, GeometryCollection<T>);
+symmetric_intersects_impl!(Triangle<T>, GeometryCollection<T>);
 symmetric_intersects_impl!(Polygon<T>, GeometryCollection<T>);
+symmetric_intersects_impl!(LineString<T>, GeometryCollection<T>);
@@ -1,24 +1,71 @@
-use super::{has_disjoint_bboxes, Intersects};
+use super::{has_disjoint_bboxes, monotone_chain, Intersects};
 use crate::BoundingRect;
 use crate::*;
 
-// Blanket implementation using self.lines().any().
-impl<T, G> Intersects<G> for LineString<T>
+// Implementation using self.lines().any(), for geometries
+// that aren't made of many segments themselves.
+macro_rules! impl_intersects_line_string_from_lines {
+    ($($k:ty),* $(,)?) => {
+        $(
+            impl<T> Intersects<$k> for LineString<T>
+            where
+                T: CoordNum,
+                Line<T>: Intersects<$k>,
+            {
+                fn intersects(&self, geom: &$k) -> bool {
+                    if has_disjoint_bboxes(self, geom) {
+                        return false;
+                    }
+                    self.lines().any(|l| l.intersects(geom))
+                }
+            }
+        )*
+    };
+}
+impl_intersects_line_string_from_lines!(
+    Coord<T>,
+    Point<T>,
+    MultiPoint<T>,
+    Line<T>,
+    Rect<T>,
+    Triangle<T>,
+);
+symmetric_intersects_impl!(Coord<T>, LineString<T>);
+symmetric_intersects_impl!(Line<T>, LineString<T>);
+symmetric_intersects_impl!(Rect<T>, LineString<T>);
+
+// Uses a monotone chain decomposition of both line strings,
+// instead of comparing every pair of segments.
+impl<T> Intersects<LineString<T>> for LineString<T>
 where
-    T: CoordNum,
-    Line<T>: Intersects<G>,
-    G: BoundingRect<T>,
+    T: GeoNum,
 {
-    fn intersects(&self, geom: &G) -> bool {
-        if has_disjoint_bboxes(self, geom) {
+    fn intersects(&self, line_string: &LineString<T>) -> bool {
+        if has_disjoint_bboxes(self, line_string) {
             return false;
         }
-        self.lines().any(|l| l.intersects(geom))
+        monotone_chain::line_strings_intersect(&self.0, &line_string.0)
+    }
+}
+
+impl<T> Intersects<Polygon<T>> for LineString<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, polygon: &Polygon<T>) -> bool {
+        if self.0.len() < 2 || has_disjoint_bboxes(self, polygon) {
+            return false;
+        }
+        // either self crosses a ring of the polygon, or it lies
+        // entirely on one side of every ring
+        polygon.exterior().intersects(self)
+            || polygon
+                .interiors()
+                .iter()
+                .any(|inner| inner.intersects(self))
+            || polygon.intersects(&self.0[0])
     }
 }
-symmetric_intersects_impl!(Coord<T>, LineString<T>);
-symmetric_intersects_impl!(Line<T>, LineString<T>);
-symmetric_intersects_impl!(Rect<T>, LineString<T>);
 
 // Blanket implementation from LineString<T>
 impl<T, G> Intersects<G> for MultiLineString<T>
@@ -38,3 +85,4 @@ where
 symmetric_intersects_impl!(Point<T>, MultiLineString<T>);
 symmetric_intersects_impl!(Line<T>, MultiLineString<T>);
 symmetric_intersects_impl!(Rect<T>, MultiLineString<T>);
+symmetric_intersects_impl!(LineString<T>, MultiLineString<T>);
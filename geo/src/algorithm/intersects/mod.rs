@@ -68,6 +68,7 @@ mod collections;
 mod coordinate;
 mod line;
 mod line_string;
+pub(crate) mod monotone_chain;
 mod point;
 mod polygon;
 mod rect;
@@ -131,7 +132,7 @@ mod test {
     use crate::Intersects;
     use crate::{
         coord, line_string, polygon, Geometry, Line, LineString, MultiLineString, MultiPoint,
-        MultiPolygon, Point, Polygon, Rect,
+        MultiPolygon, Point, Polygon, Rect, Triangle,
     };
 
     /// Tests: intersection LineString and LineString
@@ -513,6 +514,49 @@ mod test {
         assert!(!poly2.intersects(&line0));
     }
     #[test]
+    fn linestring_intersects_polygon_test() {
+        let poly = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (4., 4.),
+                (6., 4.),
+                (6., 6.),
+                (4., 6.),
+                (4., 4.),
+            ])],
+        );
+        let inside = LineString::from(vec![(1., 1.), (3., 2.), (1., 3.)]);
+        let in_hole = LineString::from(vec![(4.5, 4.5), (5.5, 5.), (4.5, 5.5)]);
+        let across_hole = LineString::from(vec![(4.5, 4.5), (5.5, 5.), (7., 5.5)]);
+        let outside = LineString::from(vec![(11., 1.), (12., 5.), (11., 9.)]);
+        assert!(inside.intersects(&poly));
+        assert!(!in_hole.intersects(&poly));
+        assert!(across_hole.intersects(&poly));
+        assert!(!outside.intersects(&poly));
+        assert!(poly.intersects(&inside));
+        assert!(!poly.intersects(&in_hole));
+    }
+    #[test]
+    fn long_linestrings_intersect_test() {
+        // two sawtooth lines running alongside each other,
+        // which only cross near the far end
+        let a: LineString<f64> = (0..10_000)
+            .map(|i| (i as f64, (i % 2) as f64))
+            .collect::<Vec<_>>()
+            .into();
+        let mut b: LineString<f64> = (0..10_000)
+            .map(|i| (i as f64, 2. + (i % 2) as f64))
+            .collect::<Vec<_>>()
+            .into();
+        assert!(!a.intersects(&b));
+        assert!(!b.intersects(&a));
+        b.0[9_990].y = -0.5;
+        assert!(a.intersects(&b));
+        assert!(b.intersects(&a));
+        assert!(MultiLineString::new(vec![a.clone()]).intersects(&b));
+        assert!(Geometry::from(b).intersects(&a));
+    }
+    #[test]
     // See https://github.com/georust/geo/issues/419
     fn rect_test_419() {
         let a = Rect::new(
@@ -547,9 +591,44 @@ mod test {
         assert!(geom.intersects(&geom));
     }
 
+    #[test]
+    fn triangle_intersects_without_polygon() {
+        let tri = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 4, y: 0 },
+            coord! { x: 0, y: 4 },
+        );
+        assert!(tri.intersects(&coord! { x: 1, y: 1 }));
+        assert!(tri.intersects(&coord! { x: 2, y: 2 }));
+        assert!(!tri.intersects(&coord! { x: 3, y: 2 }));
+        assert!(tri.intersects(&Line::from([(-1, 2), (5, 2)])));
+        assert!(!tri.intersects(&Line::from([(3, 3), (5, 1)])));
+        // a rect inside the triangle, and one containing it
+        assert!(tri.intersects(&Rect::new(coord! { x: 1, y: 1 }, coord! { x: 2, y: 2 })));
+        assert!(tri.intersects(&Rect::new(coord! { x: -1, y: -1 }, coord! { x: 9, y: 9 })));
+        assert!(!tri.intersects(&Rect::new(coord! { x: 3, y: 3 }, coord! { x: 9, y: 9 })));
+        let inner = Triangle::new(
+            coord! { x: 1, y: 1 },
+            coord! { x: 2, y: 1 },
+            coord! { x: 1, y: 2 },
+        );
+        assert!(tri.intersects(&inner));
+        assert!(inner.intersects(&tri));
+
+        // a degenerate triangle is only its segment
+        let flat = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 2, y: 0 },
+            coord! { x: 4, y: 0 },
+        );
+        assert!(flat.intersects(&coord! { x: 3, y: 0 }));
+        assert!(!flat.intersects(&coord! { x: 3, y: 1 }));
+        assert!(!flat.intersects(&coord! { x: 5, y: 0 }));
+    }
+
     #[test]
     fn exhaustive_compile_test() {
-        use geo_types::{GeometryCollection, Triangle};
+        use geo_types::GeometryCollection;
         let pt: Point = Point::new(0., 0.);
         let ln: Line = Line::new((0., 0.), (1., 1.));
         let ls = line_string![(0., 0.).into(), (1., 1.).into()];
@@ -0,0 +1,161 @@
+use super::Intersects;
+use crate::{Coord, CoordNum, GeoNum, Line, Rect};
+
+use std::cmp::Ordering;
+
+// A run of consecutive segments of a line string along which neither `x` nor `y` changes
+// direction, so that the envelope of the run, and of any sub-run of it, is spanned by its first
+// and last coordinates.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MonotoneChain<'a, T: CoordNum> {
+    pub(crate) coords: &'a [Coord<T>],
+    pub(crate) envelope: Rect<T>,
+}
+
+impl<'a, T: CoordNum> MonotoneChain<'a, T> {
+    // `coords` must hold at least two coordinates
+    fn new(coords: &'a [Coord<T>]) -> Self {
+        MonotoneChain {
+            coords,
+            envelope: Rect::new(coords[0], coords[coords.len() - 1]),
+        }
+    }
+}
+
+// Split `coords` into monotone chains, starting a new chain whenever a segment heads into a
+// different quadrant from the previous one. Consecutive chains share their boundary coordinate.
+pub(crate) fn monotone_chains<T: CoordNum>(coords: &[Coord<T>]) -> Vec<MonotoneChain<'_, T>> {
+    let quadrant =
+        |segment: &[Coord<T>]| (segment[1].x >= segment[0].x, segment[1].y >= segment[0].y);
+
+    let mut chains = vec![];
+    let mut start = 0;
+    for (i, segment) in coords.windows(2).enumerate().skip(1) {
+        if quadrant(segment) != quadrant(&coords[i - 1..=i]) {
+            chains.push(MonotoneChain::new(&coords[start..=i]));
+            start = i;
+        }
+    }
+    if coords.len() - start >= 2 {
+        chains.push(MonotoneChain::new(&coords[start..]));
+    }
+    chains
+}
+
+// Whether two monotone chains meet, bisecting the longer one until either the envelopes are
+// disjoint or both sides are single segments.
+pub(crate) fn chains_intersect<T: GeoNum>(a: MonotoneChain<T>, b: MonotoneChain<T>) -> bool {
+    if !a.envelope.intersects(&b.envelope) {
+        return false;
+    }
+    match (a.coords, b.coords) {
+        ([a_start, a_end], [b_start, b_end]) => {
+            Line::new(*a_start, *a_end).intersects(&Line::new(*b_start, *b_end))
+        }
+        (coords, _) if coords.len() >= b.coords.len() => {
+            let mid = coords.len() / 2;
+            chains_intersect(MonotoneChain::new(&coords[..=mid]), b)
+                || chains_intersect(MonotoneChain::new(&coords[mid..]), b)
+        }
+        _ => chains_intersect(b, a),
+    }
+}
+
+/// Whether any segment of the line string made of `a` meets any segment of the line string made
+/// of `b`.
+///
+/// Both are decomposed into monotone chains, which are swept from left to right so that only
+/// chains whose envelopes overlap are compared, and compared by recursive bisection. This is
+/// roughly `O((n + m) log(n + m))` for typical inputs, rather than the `O(n * m)` of testing
+/// every pair of segments.
+pub(super) fn line_strings_intersect<T: GeoNum>(a: &[Coord<T>], b: &[Coord<T>]) -> bool {
+    let mut chains: Vec<(bool, MonotoneChain<T>)> = monotone_chains(a)
+        .into_iter()
+        .map(|chain| (false, chain))
+        .chain(monotone_chains(b).into_iter().map(|chain| (true, chain)))
+        .collect();
+    chains.sort_unstable_by(|(_, c1), (_, c2)| {
+        c1.envelope
+            .min()
+            .x
+            .partial_cmp(&c2.envelope.min().x)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    // the chains of `a` and `b` whose envelopes may still overlap the sweep line
+    let mut active: [Vec<MonotoneChain<T>>; 2] = [vec![], vec![]];
+    for (from_b, chain) in chains {
+        let x = chain.envelope.min().x;
+        let others = &mut active[usize::from(!from_b)];
+        others.retain(|other| other.envelope.max().x >= x);
+        if others.iter().any(|other| chains_intersect(*other, chain)) {
+            return true;
+        }
+        active[usize::from(from_b)].push(chain);
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, LineString};
+
+    // every pair of segments, for comparison
+    fn brute_force<T: GeoNum>(a: &LineString<T>, b: &LineString<T>) -> bool {
+        a.lines().any(|l1| b.lines().any(|l2| l1.intersects(&l2)))
+    }
+
+    #[test]
+    fn chains_share_boundary_coords() {
+        let coords = vec![
+            coord! { x: 0, y: 0 },
+            coord! { x: 1, y: 1 },
+            coord! { x: 2, y: 3 },
+            coord! { x: 3, y: 1 },
+            coord! { x: 4, y: 0 },
+            coord! { x: 3, y: -2 },
+        ];
+        let chains = monotone_chains(&coords);
+        let lengths: Vec<_> = chains.iter().map(|chain| chain.coords.len()).collect();
+        assert_eq!(lengths, vec![3, 3, 2]);
+        assert_eq!(chains[1].coords[0], coords[2]);
+        assert_eq!(chains[1].envelope, Rect::new(coords[2], coords[4]));
+
+        assert!(monotone_chains::<i32>(&[]).is_empty());
+        assert!(monotone_chains(&coords[..1]).is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        // zigzags sliding past each other
+        let zigzag = |offset: i64, step: i64| -> LineString<i64> {
+            (0..50)
+                .map(|i| (i * step, offset + (i % 3) * 2 - (i % 5)))
+                .collect::<Vec<_>>()
+                .into()
+        };
+        for offset in -8..8 {
+            for step in 1..4 {
+                let a = zigzag(0, 2);
+                let b = zigzag(offset, step);
+                assert_eq!(
+                    line_strings_intersect(&a.0, &b.0),
+                    brute_force(&a, &b),
+                    "offset {} step {}",
+                    offset,
+                    step
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn touching_at_a_single_coordinate() {
+        let a: LineString<f64> = vec![(0., 0.), (1., 2.), (2., 0.)].into();
+        let b: LineString<f64> = vec![(1., 2.), (1., 4.), (3., 4.)].into();
+        let c: LineString<f64> = vec![(1., 2.1), (1., 4.), (3., 4.)].into();
+        assert!(line_strings_intersect(&a.0, &b.0));
+        assert!(!line_strings_intersect(&a.0, &c.0));
+    }
+}
@@ -40,7 +40,13 @@ where
     T: GeoNum,
 {
     fn intersects(&self, rect: &Rect<T>) -> bool {
-        self.intersects(&rect.to_polygon())
+        // an edge of the rect meets the polygon, or the polygon is inside the rect
+        rect.to_lines().iter().any(|edge| self.intersects(edge))
+            || self
+                .exterior()
+                .0
+                .first()
+                .map_or(false, |coord| rect.intersects(coord))
     }
 }
 symmetric_intersects_impl!(Rect<T>, Polygon<T>);
@@ -82,6 +88,7 @@ symmetric_intersects_impl!(Point<T>, MultiPolygon<T>);
 symmetric_intersects_impl!(Line<T>, MultiPolygon<T>);
 symmetric_intersects_impl!(Rect<T>, MultiPolygon<T>);
 symmetric_intersects_impl!(Polygon<T>, MultiPolygon<T>);
+symmetric_intersects_impl!(LineString<T>, MultiPolygon<T>);
 
 #[cfg(test)]
 mod tests {
@@ -1,16 +1,78 @@
 use super::Intersects;
+use crate::kernels::{Kernel, Orientation};
 use crate::*;
 
-impl<T, G> Intersects<G> for Triangle<T>
+impl<T> Intersects<Coord<T>> for Triangle<T>
 where
-    T: CoordNum,
-    Polygon<T>: Intersects<G>,
+    T: GeoNum,
 {
-    fn intersects(&self, rhs: &G) -> bool {
-        self.to_polygon().intersects(rhs)
+    fn intersects(&self, rhs: &Coord<T>) -> bool {
+        if T::Ker::orient2d(self.0, self.1, self.2) == Orientation::Collinear {
+            return self.to_lines().iter().any(|edge| edge.intersects(rhs));
+        }
+        // the coord is outside iff it's strictly on the outer side of an edge, and then
+        // strictly on the inner side of another one
+        let orientations = [
+            T::Ker::orient2d(self.0, self.1, *rhs),
+            T::Ker::orient2d(self.1, self.2, *rhs),
+            T::Ker::orient2d(self.2, self.0, *rhs),
+        ];
+        !(orientations.contains(&Orientation::CounterClockwise)
+            && orientations.contains(&Orientation::Clockwise))
     }
 }
 symmetric_intersects_impl!(Coord<T>, Triangle<T>);
+symmetric_intersects_impl!(Triangle<T>, Point<T>);
+symmetric_intersects_impl!(Triangle<T>, MultiPoint<T>);
+
+impl<T> Intersects<Line<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Line<T>) -> bool {
+        self.intersects(&rhs.start)
+            || self.intersects(&rhs.end)
+            || self.to_lines().iter().any(|edge| edge.intersects(rhs))
+    }
+}
 symmetric_intersects_impl!(Line<T>, Triangle<T>);
+symmetric_intersects_impl!(Triangle<T>, LineString<T>);
+symmetric_intersects_impl!(Triangle<T>, MultiLineString<T>);
+
+impl<T> Intersects<Rect<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Rect<T>) -> bool {
+        // an edge meets the rect, or the rect is inside the triangle
+        self.to_lines().iter().any(|edge| rhs.intersects(edge)) || self.intersects(&rhs.min())
+    }
+}
 symmetric_intersects_impl!(Rect<T>, Triangle<T>);
+
+impl<T> Intersects<Triangle<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Triangle<T>) -> bool {
+        // an edge of `rhs` meets `self`, or `self` is inside `rhs`
+        rhs.to_lines().iter().any(|edge| self.intersects(edge)) || rhs.intersects(&self.0)
+    }
+}
+
+impl<T> Intersects<Polygon<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Polygon<T>) -> bool {
+        // an edge meets the polygon, or the polygon is inside the triangle
+        self.to_lines().iter().any(|edge| rhs.intersects(edge))
+            || rhs
+                .exterior()
+                .0
+                .first()
+                .map_or(false, |coord| self.intersects(coord))
+    }
+}
 symmetric_intersects_impl!(Polygon<T>, Triangle<T>);
+symmetric_intersects_impl!(Triangle<T>, MultiPolygon<T>);
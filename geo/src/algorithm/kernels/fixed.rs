@@ -0,0 +1,425 @@
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::str::FromStr;
+
+use super::{HasKernel, IntegerKernel, Kernel, Orientation};
+use crate::Coord;
+
+use num_traits::float::FloatCore;
+use num_traits::{Bounded, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+/// A fixed-point decimal number, with `DECIMALS` digits after the decimal point, for
+/// coordinates on which results must be exact and reproducible across platforms.
+///
+/// A `Fixed` is stored as an `i64` counting units of `10^-DECIMALS`, so `DECIMALS` may be at
+/// most 18. Addition, subtraction and remainder are exact. Products and quotients are rounded
+/// to the nearest unit, with halves rounded away from zero. All operations panic on overflow,
+/// in release builds too, rather than silently giving a wrong result.
+///
+/// The predicates of its kernel, [`FixedKernel`], are exact.
+///
+/// # Examples
+///
+/// ```
+/// use geo::kernels::{Fixed, FixedKernel, Kernel, Orientation};
+/// use geo::Coord;
+///
+/// type Micro = Fixed<6>;
+///
+/// let p = Coord { x: "0.1".parse::<Micro>().unwrap(), y: "0.1".parse().unwrap() };
+/// let q = Coord { x: "0.2".parse::<Micro>().unwrap(), y: "0.2".parse().unwrap() };
+/// let r = Coord { x: "0.3".parse::<Micro>().unwrap(), y: "0.3".parse().unwrap() };
+/// assert_eq!(FixedKernel::orient2d(p, q, r), Orientation::Collinear);
+/// assert_eq!(p.x + q.x, r.x);
+/// assert_eq!((p.x * q.x).to_string(), "0.020000");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const DECIMALS: u32>(i64);
+
+const OVERFLOW: &str = "fixed-point overflow";
+
+impl<const DECIMALS: u32> Fixed<DECIMALS> {
+    /// The number of units in one, `10^DECIMALS`.
+    pub const SCALE: i64 = 10i64.pow(DECIMALS);
+
+    /// The number `raw * 10^-DECIMALS`.
+    pub const fn from_raw(raw: i64) -> Self {
+        Fixed(raw)
+    }
+
+    /// The number of units of `10^-DECIMALS` in `self`.
+    pub const fn raw(self) -> i64 {
+        self.0
+    }
+
+    /// # Panics
+    ///
+    /// If `n * 10^DECIMALS` overflows an `i64`.
+    pub fn from_integer(n: i64) -> Self {
+        Fixed(n.checked_mul(Self::SCALE).expect(OVERFLOW))
+    }
+
+    fn from_wide(raw: i128) -> Self {
+        Fixed(i64::try_from(raw).expect(OVERFLOW))
+    }
+}
+
+// `a * b / c`, rounded to the nearest integer, with halves away from zero
+fn mul_div_round(a: i64, b: i64, c: i64) -> i128 {
+    let (numerator, denominator) = (a as i128 * b as i128, c as i128);
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if 2 * remainder.abs() >= denominator.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+impl<const DECIMALS: u32> fmt::Debug for Fixed<DECIMALS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const DECIMALS: u32> fmt::Display for Fixed<DECIMALS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let units = self.0.unsigned_abs();
+        let scale = Self::SCALE as u64;
+        if DECIMALS == 0 {
+            write!(f, "{}{}", sign, units)
+        } else {
+            write!(
+                f,
+                "{}{}.{:0width$}",
+                sign,
+                units / scale,
+                units % scale,
+                width = DECIMALS as usize
+            )
+        }
+    }
+}
+
+/// The error returned when parsing a [`Fixed`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFixedError;
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid fixed-point number: expected decimal digits, with no more fractional \
+             digits than the type has, and a value within its range"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFixedError {}
+
+impl<const DECIMALS: u32> FromStr for Fixed<DECIMALS> {
+    type Err = ParseFixedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (digits, ""),
+        };
+        if (integer.is_empty() && fraction.is_empty())
+            || fraction.len() > DECIMALS as usize
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(ParseFixedError);
+        }
+
+        // accumulate negatively, so that the minimum value can be parsed
+        let mut raw: i64 = 0;
+        let padding = DECIMALS as usize - fraction.len();
+        let padded_digits = integer
+            .bytes()
+            .chain(fraction.bytes())
+            .chain(core::iter::repeat(b'0').take(padding));
+        for digit in padded_digits {
+            raw = raw
+                .checked_mul(10)
+                .and_then(|raw| raw.checked_sub((digit - b'0') as i64))
+                .ok_or(ParseFixedError)?;
+        }
+        if negative {
+            Ok(Fixed(raw))
+        } else {
+            raw.checked_neg().map(Fixed).ok_or(ParseFixedError)
+        }
+    }
+}
+
+impl<const DECIMALS: u32> Add for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fixed(self.0.checked_add(other.0).expect(OVERFLOW))
+    }
+}
+
+impl<const DECIMALS: u32> Sub for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Fixed(self.0.checked_sub(other.0).expect(OVERFLOW))
+    }
+}
+
+impl<const DECIMALS: u32> Mul for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::from_wide(mul_div_round(self.0, other.0, Self::SCALE))
+    }
+}
+
+impl<const DECIMALS: u32> Div for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::from_wide(mul_div_round(self.0, Self::SCALE, other.0))
+    }
+}
+
+impl<const DECIMALS: u32> Rem for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Fixed(self.0 % other.0)
+    }
+}
+
+impl<const DECIMALS: u32> Neg for Fixed<DECIMALS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(self.0.checked_neg().expect(OVERFLOW))
+    }
+}
+
+impl<const DECIMALS: u32> Zero for Fixed<DECIMALS> {
+    fn zero() -> Self {
+        Fixed(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const DECIMALS: u32> One for Fixed<DECIMALS> {
+    fn one() -> Self {
+        Fixed(Self::SCALE)
+    }
+}
+
+impl<const DECIMALS: u32> Num for Fixed<DECIMALS> {
+    type FromStrRadixErr = ParseFixedError;
+
+    /// Only decimal numbers can be parsed: `radix` must be 10.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(ParseFixedError);
+        }
+        s.parse()
+    }
+}
+
+impl<const DECIMALS: u32> Signed for Fixed<DECIMALS> {
+    fn abs(&self) -> Self {
+        Fixed(self.0.checked_abs().expect(OVERFLOW))
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Self::from_integer(self.0.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0 > 0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+}
+
+impl<const DECIMALS: u32> Bounded for Fixed<DECIMALS> {
+    fn min_value() -> Self {
+        Fixed(i64::MIN)
+    }
+
+    fn max_value() -> Self {
+        Fixed(i64::MAX)
+    }
+}
+
+impl<const DECIMALS: u32> ToPrimitive for Fixed<DECIMALS> {
+    /// The integer part, truncated towards zero.
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.0 / Self::SCALE)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_i64()?.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0 as f64 / Self::SCALE as f64)
+    }
+}
+
+impl<const DECIMALS: u32> NumCast for Fixed<DECIMALS> {
+    /// Integers are converted exactly, and floats are rounded to the nearest unit.
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let float = n.to_f64()?;
+        if FloatCore::fract(float) == 0. {
+            if let Some(integer) = n.to_i64() {
+                return integer.checked_mul(Self::SCALE).map(Fixed);
+            }
+        }
+        FloatCore::round(float * Self::SCALE as f64)
+            .to_i64()
+            .map(Fixed)
+    }
+}
+
+/// Exact kernel for [`Fixed`] coordinates.
+///
+/// The orientation of points, and the sign of dot products, don't change when all
+/// coordinates are scaled by `10^DECIMALS`, so the predicates are evaluated exactly on the
+/// underlying integers by [`IntegerKernel`].
+#[derive(Default, Debug)]
+pub struct FixedKernel;
+
+fn to_raw<const DECIMALS: u32>(coord: Coord<Fixed<DECIMALS>>) -> Coord<i64> {
+    Coord {
+        x: coord.x.0,
+        y: coord.y.0,
+    }
+}
+
+impl<const DECIMALS: u32> Kernel<Fixed<DECIMALS>> for FixedKernel {
+    fn orient2d(
+        p: Coord<Fixed<DECIMALS>>,
+        q: Coord<Fixed<DECIMALS>>,
+        r: Coord<Fixed<DECIMALS>>,
+    ) -> Orientation {
+        <IntegerKernel as Kernel<i64>>::orient2d(to_raw(p), to_raw(q), to_raw(r))
+    }
+
+    fn dot_product_sign(u: Coord<Fixed<DECIMALS>>, v: Coord<Fixed<DECIMALS>>) -> Orientation {
+        <IntegerKernel as Kernel<i64>>::dot_product_sign(to_raw(u), to_raw(v))
+    }
+}
+
+impl<const DECIMALS: u32> HasKernel for Fixed<DECIMALS> {
+    type Ker = FixedKernel;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::twice_signed_ring_area;
+    use crate::{coord, polygon, Contains};
+    use alloc::string::ToString;
+
+    type Micro = Fixed<6>;
+
+    fn fixed(s: &str) -> Micro {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parsing_and_formatting() {
+        assert_eq!(fixed("1.5"), Micro::from_raw(1_500_000));
+        assert_eq!(fixed("-.25"), Micro::from_raw(-250_000));
+        assert_eq!(fixed("+3"), Micro::from_integer(3));
+        assert_eq!(fixed("-0.000001").to_string(), "-0.000001");
+        assert_eq!(Micro::min_value().to_string(), "-9223372036854.775808");
+        assert_eq!(
+            Micro::min_value().to_string().parse(),
+            Ok(Micro::min_value())
+        );
+        assert_eq!(Fixed::<0>::from_integer(42).to_string(), "42");
+        // too many digits, out of range, or not a number
+        assert_eq!("0.0000001".parse::<Micro>(), Err(ParseFixedError));
+        assert_eq!("9223372036855".parse::<Micro>(), Err(ParseFixedError));
+        assert_eq!(".".parse::<Micro>(), Err(ParseFixedError));
+        assert_eq!("1e3".parse::<Micro>(), Err(ParseFixedError));
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(fixed("0.000001") * fixed("0.5"), fixed("0.000001"));
+        assert_eq!(fixed("-0.000001") * fixed("0.5"), fixed("-0.000001"));
+        assert_eq!(fixed("0.000001") * fixed("0.4"), Micro::zero());
+        assert_eq!(fixed("1") / fixed("3"), fixed("0.333333"));
+        assert_eq!(fixed("2") / fixed("3"), fixed("0.666667"));
+        assert_eq!(fixed("-2") / fixed("3"), fixed("-0.666667"));
+        assert_eq!(fixed("7.5") % fixed("2"), fixed("1.5"));
+    }
+
+    #[test]
+    fn conversions() {
+        let tenth: Micro = NumCast::from(0.1).unwrap();
+        assert_eq!(tenth, fixed("0.1"));
+        let big: Micro = NumCast::from(9_000_000_000_000i64).unwrap();
+        assert_eq!(big, Micro::from_integer(9_000_000_000_000));
+        assert_eq!(<Micro as NumCast>::from(1e20), None);
+        assert_eq!(fixed("-2.75").to_i64(), Some(-2));
+        assert_eq!(fixed("0.75").to_f64(), Some(0.75));
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn overflow() {
+        let _ = Micro::max_value() + fixed("0.000001");
+    }
+
+    #[test]
+    fn algorithms_on_fixed() {
+        let p = coord! { x: fixed("0.1"), y: fixed("0.1") };
+        let q = coord! { x: fixed("0.2"), y: fixed("0.3") };
+        let r = coord! { x: fixed("0.3"), y: fixed("0.5") };
+        assert_eq!(FixedKernel::orient2d(p, q, r), Orientation::Collinear);
+        let r = coord! { x: fixed("0.3"), y: fixed("0.500001") };
+        assert_eq!(
+            FixedKernel::orient2d(p, q, r),
+            Orientation::CounterClockwise
+        );
+
+        let triangle = polygon![
+            (x: fixed("0"), y: fixed("0")),
+            (x: fixed("0.3"), y: fixed("0")),
+            (x: fixed("0"), y: fixed("0.1")),
+        ];
+        assert_eq!(
+            twice_signed_ring_area(&triangle.exterior().0),
+            fixed("0.03")
+        );
+        // exactly on the hypotenuse, then just inside
+        assert!(!triangle.contains(&coord! { x: fixed("0.15"), y: fixed("0.05") }));
+        assert!(triangle.contains(&coord! { x: fixed("0.15"), y: fixed("0.049999") }));
+    }
+}
@@ -0,0 +1,287 @@
+use core::cmp::Ordering;
+
+use super::{CoordNum, Kernel, Orientation};
+use crate::{Coord, Line};
+
+use num_traits::PrimInt;
+
+/// Exact kernel for integer coordinates up to 64 bits.
+///
+/// Unlike [`SimpleKernel`](super::SimpleKernel), whose intermediate products overflow for
+/// coordinates beyond about 31 bits, the predicates are evaluated with 128-bit intermediate
+/// arithmetic and are exact over the full range of `i64`. This is the kernel of `i64`, `i32`,
+/// `i16` and `isize`.
+#[derive(Default, Debug)]
+pub struct IntegerKernel;
+
+impl<T> Kernel<T> for IntegerKernel
+where
+    T: CoordNum + PrimInt,
+{
+    fn orient2d(p: Coord<T>, q: Coord<T>, r: Coord<T>) -> Orientation {
+        let (p, q, r) = (widen(p), widen(q), widen(r));
+        // the sign of (q - p) × (r - q)
+        match cmp_products(q.0 - p.0, r.1 - q.1, q.1 - p.1, r.0 - q.0) {
+            Ordering::Greater => Orientation::CounterClockwise,
+            Ordering::Less => Orientation::Clockwise,
+            Ordering::Equal => Orientation::Collinear,
+        }
+    }
+
+    fn dot_product_sign(u: Coord<T>, v: Coord<T>) -> Orientation {
+        let (u, v) = (widen(u), widen(v));
+        // the sign of u.x * v.x - (-u.y * v.y)
+        match cmp_products(u.0, v.0, -u.1, v.1) {
+            Ordering::Greater => Orientation::CounterClockwise,
+            Ordering::Less => Orientation::Clockwise,
+            Ordering::Equal => Orientation::Collinear,
+        }
+    }
+}
+
+/// The intersection of two segments with `i64` coordinates, as computed by
+/// [`IntegerKernel::line_intersection`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum IntegerLineIntersection {
+    /// Segments intersecting in a single point.
+    SinglePoint {
+        /// The intersection point, rounded to the nearest integer coordinates for proper
+        /// intersections.
+        intersection: Coord<i64>,
+        /// Whether the point lies in the interior of both segments.
+        is_proper: bool,
+    },
+    /// Collinear segments overlapping along a segment, with its coordinates in lexicographic
+    /// order.
+    Collinear { intersection: Line<i64> },
+}
+
+impl IntegerLineIntersection {
+    pub fn is_proper(&self) -> bool {
+        match self {
+            Self::Collinear { .. } => false,
+            Self::SinglePoint { is_proper, .. } => *is_proper,
+        }
+    }
+}
+
+impl IntegerKernel {
+    /// Compute the intersection of two segments with `i64` coordinates.
+    ///
+    /// Whether and how the segments intersect is decided exactly, for any coordinates. When
+    /// the segments cross in the interior of both, the intersection point generally doesn't
+    /// have integer coordinates, and is rounded to the nearest integer coordinates, with halves
+    /// rounded up. Collinear overlaps and intersections at endpoints are exact.
+    ///
+    /// # Panics
+    ///
+    /// If the segments cross in the interior of both, and have coordinates of magnitude
+    /// greater than 2<sup>40</sup>, as the rounded intersection point would overflow 128-bit
+    /// intermediate arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::kernels::{IntegerKernel, IntegerLineIntersection};
+    /// use geo::{coord, Line};
+    ///
+    /// let p = Line::new(coord! { x: 0, y: 0 }, coord! { x: 10, y: 5 });
+    /// let q = Line::new(coord! { x: 0, y: 5 }, coord! { x: 10, y: 0 });
+    /// assert_eq!(
+    ///     IntegerKernel::line_intersection(p, q),
+    ///     Some(IntegerLineIntersection::SinglePoint {
+    ///         intersection: coord! { x: 5, y: 3 },
+    ///         is_proper: true,
+    ///     })
+    /// );
+    /// ```
+    pub fn line_intersection(p: Line<i64>, q: Line<i64>) -> Option<IntegerLineIntersection> {
+        let orient = <Self as Kernel<i64>>::orient2d;
+        let q_start_side = orient(p.start, p.end, q.start);
+        let q_end_side = orient(p.start, p.end, q.end);
+        let p_start_side = orient(q.start, q.end, p.start);
+        let p_end_side = orient(q.start, q.end, p.end);
+
+        let separates = |a: Orientation, b: Orientation| a == b && a != Orientation::Collinear;
+        if separates(q_start_side, q_end_side) || separates(p_start_side, p_end_side) {
+            return None;
+        }
+
+        let point = |intersection| IntegerLineIntersection::SinglePoint {
+            intersection,
+            is_proper: false,
+        };
+        if [q_start_side, q_end_side, p_start_side, p_end_side]
+            .iter()
+            .all(|o| *o == Orientation::Collinear)
+        {
+            let key = |c: &Coord<i64>| (c.x, c.y);
+            let (p_min, p_max) = minmax_by_key(p.start, p.end, key);
+            let (q_min, q_max) = minmax_by_key(q.start, q.end, key);
+            let low = core::cmp::max_by_key(p_min, q_min, key);
+            let high = core::cmp::min_by_key(p_max, q_max, key);
+            return match key(&low).cmp(&key(&high)) {
+                Ordering::Less => Some(IntegerLineIntersection::Collinear {
+                    intersection: Line::new(low, high),
+                }),
+                Ordering::Equal => Some(point(low)),
+                Ordering::Greater => None,
+            };
+        }
+
+        // an endpoint lying on the other segment
+        for (orientation, endpoint) in [
+            (q_start_side, q.start),
+            (q_end_side, q.end),
+            (p_start_side, p.start),
+            (p_end_side, p.end),
+        ] {
+            if orientation == Orientation::Collinear {
+                return Some(point(endpoint));
+            }
+        }
+
+        // a proper crossing at p.start + (p.end - p.start) * t, with t = numerator / denominator
+        let (p_start, p_end, q_start) = (widen(p.start), widen(p.end), widen(q.start));
+        let q_end = widen(q.end);
+        let p_delta = (p_end.0 - p_start.0, p_end.1 - p_start.1);
+        let q_delta = (q_end.0 - q_start.0, q_end.1 - q_start.1);
+        let offset = (q_start.0 - p_start.0, q_start.1 - p_start.1);
+        let cross = |a: (i128, i128), b: (i128, i128)| a.0 * b.1 - a.1 * b.0;
+        let mut numerator = cross(offset, q_delta);
+        let mut denominator = cross(p_delta, q_delta);
+        if denominator < 0 {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        let round = |start: i64, delta: i128| {
+            let scaled = numerator
+                .checked_mul(delta)
+                .and_then(|n| n.checked_mul(2))
+                .expect("coordinates are too large for a rounded intersection point");
+            let offset = (scaled + denominator).div_euclid(2 * denominator);
+            (start as i128 + offset) as i64
+        };
+        Some(IntegerLineIntersection::SinglePoint {
+            intersection: Coord {
+                x: round(p.start.x, p_delta.0),
+                y: round(p.start.y, p_delta.1),
+            },
+            is_proper: true,
+        })
+    }
+}
+
+fn widen<T: CoordNum>(coord: Coord<T>) -> (i128, i128) {
+    (
+        coord.x.to_i64().unwrap() as i128,
+        coord.y.to_i64().unwrap() as i128,
+    )
+}
+
+fn minmax_by_key<K: Ord>(
+    a: Coord<i64>,
+    b: Coord<i64>,
+    key: impl Fn(&Coord<i64>) -> K,
+) -> (Coord<i64>, Coord<i64>) {
+    if key(&a) <= key(&b) {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+// Compare `a * b` with `c * d`, for factors of magnitude less than 2^64, whose products may not
+// fit in an `i128`.
+fn cmp_products(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let sign = |x: i128, y: i128| x.signum() * y.signum();
+    let (left_sign, right_sign) = (sign(a, b), sign(c, d));
+    if left_sign != right_sign {
+        return left_sign.cmp(&right_sign);
+    }
+    let left = a.unsigned_abs() * b.unsigned_abs();
+    let right = c.unsigned_abs() * d.unsigned_abs();
+    match left_sign {
+        1 => left.cmp(&right),
+        -1 => right.cmp(&left),
+        _ => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord;
+
+    #[test]
+    fn orientation_at_the_limits() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        let orient = <IntegerKernel as Kernel<i64>>::orient2d;
+        let low = coord! { x: min, y: min };
+        let high = coord! { x: max, y: max };
+        assert_eq!(
+            orient(low, high, coord! { x: -1, y: -1 }),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            orient(low, high, coord! { x: -1, y: 0 }),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            orient(low, high, coord! { x: max, y: max - 1 }),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            <IntegerKernel as Kernel<i64>>::dot_product_sign(
+                coord! { x: max, y: min },
+                coord! { x: max, y: max },
+            ),
+            Orientation::Clockwise
+        );
+    }
+
+    #[test]
+    fn intersections() {
+        let line = |a: (i64, i64), b: (i64, i64)| Line::new(a, b);
+        // touching at an endpoint
+        assert_eq!(
+            IntegerKernel::line_intersection(line((0, 0), (4, 4)), line((4, 4), (8, 0))),
+            Some(IntegerLineIntersection::SinglePoint {
+                intersection: coord! { x: 4, y: 4 },
+                is_proper: false,
+            })
+        );
+        // collinear overlap
+        assert_eq!(
+            IntegerKernel::line_intersection(line((6, 6), (0, 0)), line((2, 2), (9, 9))),
+            Some(IntegerLineIntersection::Collinear {
+                intersection: line((2, 2), (6, 6)),
+            })
+        );
+        // collinear, but disjoint
+        assert_eq!(
+            IntegerKernel::line_intersection(line((0, 0), (1, 1)), line((2, 2), (3, 3))),
+            None
+        );
+        // rounded proper intersection, at (1/3, 1/3)
+        assert_eq!(
+            IntegerKernel::line_intersection(line((0, 0), (1, 1)), line((0, 1), (1, -1))),
+            Some(IntegerLineIntersection::SinglePoint {
+                intersection: coord! { x: 0, y: 0 },
+                is_proper: true,
+            })
+        );
+    }
+
+    #[test]
+    fn nearly_parallel_far_away() {
+        // segments whose orientation overflows 64-bit arithmetic
+        let big = 1 << 62;
+        let p = Line::new(coord! { x: -big, y: -big }, coord! { x: big, y: big });
+        let q = Line::new(
+            coord! { x: -big, y: -big + 1 },
+            coord! { x: big, y: big + 1 },
+        );
+        assert_eq!(IntegerKernel::line_intersection(p, q), None);
+    }
+}
@@ -1,5 +1,5 @@
+use core::cmp::Ordering;
 use num_traits::Zero;
-use std::cmp::Ordering;
 
 use crate::{coord, Coord, CoordNum};
 
@@ -54,6 +54,26 @@ pub trait Kernel<T: CoordNum> {
         };
         Self::orient2d(zero, u, vdash)
     }
+
+    /// Tells whether `d` lies inside the circle through `a`, `b`
+    /// and `c`, which must be in counter-clockwise order. The
+    /// output is `CounterClockwise` if `d` is inside, `Clockwise`
+    /// if outside, and `Collinear` if on the circle.
+    fn incircle(a: Coord<T>, b: Coord<T>, c: Coord<T>, d: Coord<T>) -> Orientation {
+        let (adx, ady) = (a.x - d.x, a.y - d.y);
+        let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+        let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+        let res = (adx * adx + ady * ady) * (bdx * cdy - cdx * bdy)
+            + (bdx * bdx + bdy * bdy) * (cdx * ady - adx * cdy)
+            + (cdx * cdx + cdy * cdy) * (adx * bdy - bdx * ady);
+        if res > Zero::zero() {
+            Orientation::CounterClockwise
+        } else if res < Zero::zero() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
 }
 
 /// Marker trait to assign Kernel for scalars
@@ -81,10 +101,23 @@ has_kernel!(f32, RobustKernel);
 pub mod simple;
 pub use self::simple::SimpleKernel;
 
-has_kernel!(i64, SimpleKernel);
-has_kernel!(i32, SimpleKernel);
-has_kernel!(i16, SimpleKernel);
-has_kernel!(isize, SimpleKernel);
+pub mod integer;
+pub use self::integer::{IntegerKernel, IntegerLineIntersection};
+
+has_kernel!(i64, IntegerKernel);
+has_kernel!(i32, IntegerKernel);
+has_kernel!(i16, IntegerKernel);
+has_kernel!(isize, IntegerKernel);
+
+pub mod fixed;
+pub use self::fixed::{Fixed, FixedKernel, ParseFixedError};
 
 #[cfg(has_i128)]
 has_kernel!(i128, SimpleKernel);
+
+#[cfg(feature = "use-rational")]
+pub mod rational;
+#[cfg(feature = "use-rational")]
+pub use self::rational::{Rational, RationalKernel};
+#[cfg(feature = "use-rational")]
+has_kernel!(Rational, RationalKernel);
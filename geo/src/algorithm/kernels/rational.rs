@@ -0,0 +1,259 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use super::{Kernel, Orientation};
+use crate::Coord;
+
+use num_rational::{BigRational, Ratio, Rational64};
+use num_traits::{FromPrimitive, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+/// An exact rational number, for coordinates on which predicates must never give wrong
+/// answers, however degenerate the configuration.
+///
+/// Requires the `use-rational` feature.
+///
+/// `Rational` wraps a [`Rational64`], whose arithmetic overflows if numerators or denominators
+/// grow beyond 64 bits, but the predicates of its kernel, [`RationalKernel`], are evaluated
+/// with arbitrary precision, and are always exact. Conversions from floats are exact for
+/// integers, and otherwise use the closest fraction with 64-bit parts.
+///
+/// # Examples
+///
+/// ```
+/// use geo::kernels::{Kernel, Orientation, Rational, RationalKernel};
+/// use geo::Coord;
+///
+/// let third = Rational::new(1, 3);
+/// let p = Coord { x: Rational::from_integer(0), y: Rational::from_integer(0) };
+/// let q = Coord { x: third, y: third };
+/// let r = Coord { x: third * Rational::from_integer(2), y: third * Rational::from_integer(2) };
+/// assert_eq!(RationalKernel::orient2d(p, q, r), Orientation::Collinear);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rational(pub Rational64);
+
+impl Rational {
+    /// The fraction `numerator / denominator`, in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        Rational(Ratio::new(numerator, denominator))
+    }
+
+    pub fn from_integer(n: i64) -> Self {
+        Rational(Ratio::from_integer(n))
+    }
+
+    fn to_big(self) -> BigRational {
+        Ratio::new_raw((*self.0.numer()).into(), (*self.0.denom()).into())
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! forward_binary_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                Rational(self.0.$method(other.0))
+            }
+        }
+    };
+}
+
+forward_binary_op!(Add, add);
+forward_binary_op!(Sub, sub);
+forward_binary_op!(Mul, mul);
+forward_binary_op!(Div, div);
+forward_binary_op!(Rem, rem);
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational(-self.0)
+    }
+}
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Rational(Ratio::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Rational(Ratio::one())
+    }
+}
+
+impl Num for Rational {
+    type FromStrRadixErr = <Rational64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Rational64::from_str_radix(s, radix).map(Rational)
+    }
+}
+
+impl Signed for Rational {
+    fn abs(&self) -> Self {
+        Rational(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Rational(self.0.abs_sub(&other.0))
+    }
+
+    fn signum(&self) -> Self {
+        Rational(self.0.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl ToPrimitive for Rational {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+impl NumCast for Rational {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let float = n.to_f64()?;
+        if float.fract() == 0. {
+            if let Some(integer) = n.to_i64() {
+                return Some(Rational::from_integer(integer));
+            }
+        }
+        Rational64::from_f64(float).map(Rational)
+    }
+}
+
+/// Exact kernel for [`Rational`] coordinates, evaluating predicates with arbitrary precision.
+///
+/// Requires the `use-rational` feature.
+#[derive(Default, Debug)]
+pub struct RationalKernel;
+
+impl Kernel<Rational> for RationalKernel {
+    fn orient2d(p: Coord<Rational>, q: Coord<Rational>, r: Coord<Rational>) -> Orientation {
+        let (px, py) = (p.x.to_big(), p.y.to_big());
+        let (qx, qy) = (q.x.to_big(), q.y.to_big());
+        let (rx, ry) = (r.x.to_big(), r.y.to_big());
+        let res = (&qx - &px) * (&ry - &qy) - (&qy - &py) * (&rx - &qx);
+        if res.is_positive() {
+            Orientation::CounterClockwise
+        } else if res.is_negative() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    fn dot_product_sign(u: Coord<Rational>, v: Coord<Rational>) -> Orientation {
+        let res = u.x.to_big() * v.x.to_big() + u.y.to_big() * v.y.to_big();
+        if res.is_positive() {
+            Orientation::CounterClockwise
+        } else if res.is_negative() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    fn incircle(
+        a: Coord<Rational>,
+        b: Coord<Rational>,
+        c: Coord<Rational>,
+        d: Coord<Rational>,
+    ) -> Orientation {
+        let (dx, dy) = (d.x.to_big(), d.y.to_big());
+        let (adx, ady) = (a.x.to_big() - &dx, a.y.to_big() - &dy);
+        let (bdx, bdy) = (b.x.to_big() - &dx, b.y.to_big() - &dy);
+        let (cdx, cdy) = (c.x.to_big() - &dx, c.y.to_big() - &dy);
+        let res = (&adx * &adx + &ady * &ady) * (&bdx * &cdy - &cdx * &bdy)
+            + (&bdx * &bdx + &bdy * &bdy) * (&cdx * &ady - &adx * &cdy)
+            + (&cdx * &cdx + &cdy * &cdy) * (&adx * &bdy - &bdx * &ady);
+        if res.is_positive() {
+            Orientation::CounterClockwise
+        } else if res.is_negative() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::area::twice_signed_ring_area;
+    use crate::{coord, polygon, Contains};
+
+    #[test]
+    fn orientation_with_large_terms() {
+        // the cross product overflows 64-bit numerators and denominators
+        let big = Rational::new(i64::MAX, i64::MAX - 1);
+        let p = coord! { x: Rational::zero(), y: Rational::zero() };
+        let q = coord! { x: big, y: big };
+        let r = coord! { x: Rational::new(i64::MAX - 2, 3), y: Rational::new(i64::MAX - 2, 3) };
+        assert_eq!(RationalKernel::orient2d(p, q, r), Orientation::Collinear);
+        let r = coord! { x: Rational::new(i64::MAX - 2, 3), y: Rational::new(i64::MAX - 3, 3) };
+        assert_eq!(RationalKernel::orient2d(p, q, r), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn conversions() {
+        let half: Rational = NumCast::from(0.5).unwrap();
+        assert_eq!(half, Rational::new(1, 2));
+        let big: Rational = NumCast::from(i64::MAX).unwrap();
+        assert_eq!(big, Rational::from_integer(i64::MAX));
+        assert_eq!(Rational::new(3, 4).to_f64(), Some(0.75));
+    }
+
+    #[test]
+    fn algorithms_on_rationals() {
+        let r = |n, d| Rational::new(n, d);
+        let triangle = polygon![
+            (x: r(0, 1), y: r(0, 1)),
+            (x: r(1, 1), y: r(0, 1)),
+            (x: r(0, 1), y: r(1, 3)),
+        ];
+        assert_eq!(twice_signed_ring_area(&triangle.exterior().0), r(1, 3));
+        // exactly on the hypotenuse
+        assert!(!triangle.contains(&coord! { x: r(1, 2), y: r(1, 6) }));
+        assert!(triangle.contains(&coord! { x: r(1, 2), y: r(1, 7) }));
+    }
+}
@@ -8,37 +8,108 @@ use num_traits::{Float, NumCast};
 /// provide robust floating point predicates. Should only be
 /// used with types that can _always_ be casted to `f64`
 /// _without loss in precision_.
+///
+/// This is the kernel of both `f64` and `f32`: `f32`
+/// coordinates are promoted to `f64`, which is exact, so the
+/// predicates are exact on `f32` data too, however close to
+/// degenerate the configuration.
 #[derive(Default, Debug)]
 pub struct RobustKernel;
 
+fn to_robust<T: CoordNum + Float>(coord: Coord<T>) -> robust::Coord<f64> {
+    robust::Coord {
+        x: <f64 as NumCast>::from(coord.x).unwrap(),
+        y: <f64 as NumCast>::from(coord.y).unwrap(),
+    }
+}
+
+fn sign_to_orientation(sign: f64) -> Orientation {
+    if sign < 0. {
+        Orientation::Clockwise
+    } else if sign > 0. {
+        Orientation::CounterClockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
 impl<T> Kernel<T> for RobustKernel
 where
     T: CoordNum + Float,
 {
     fn orient2d(p: Coord<T>, q: Coord<T>, r: Coord<T>) -> Orientation {
-        use robust::{orient2d, Coord};
-
-        let orientation = orient2d(
-            Coord {
-                x: <f64 as NumCast>::from(p.x).unwrap(),
-                y: <f64 as NumCast>::from(p.y).unwrap(),
-            },
-            Coord {
-                x: <f64 as NumCast>::from(q.x).unwrap(),
-                y: <f64 as NumCast>::from(q.y).unwrap(),
-            },
-            Coord {
-                x: <f64 as NumCast>::from(r.x).unwrap(),
-                y: <f64 as NumCast>::from(r.y).unwrap(),
-            },
-        );
+        sign_to_orientation(robust::orient2d(to_robust(p), to_robust(q), to_robust(r)))
+    }
 
-        if orientation < 0. {
-            Orientation::Clockwise
-        } else if orientation > 0. {
-            Orientation::CounterClockwise
-        } else {
-            Orientation::Collinear
+    fn incircle(a: Coord<T>, b: Coord<T>, c: Coord<T>, d: Coord<T>) -> Orientation {
+        sign_to_orientation(robust::incircle(
+            to_robust(a),
+            to_robust(b),
+            to_robust(c),
+            to_robust(d),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, polygon, Contains, ConvexHull, MultiPoint};
+
+    #[test]
+    fn near_collinear_f32() {
+        // points a few ulps away from the line y = x, where the naive f32 determinant is
+        // dominated by rounding errors
+        let p = coord! { x: 12f32, y: 12. };
+        let q = coord! { x: 24f32, y: 24. };
+        for i in 0..8 {
+            for j in 0..8 {
+                let r = coord! {
+                    x: 0.5 + i as f32 * f32::EPSILON,
+                    y: 0.5 + j as f32 * f32::EPSILON,
+                };
+                let expected = match j.cmp(&i) {
+                    core::cmp::Ordering::Greater => Orientation::CounterClockwise,
+                    core::cmp::Ordering::Less => Orientation::Clockwise,
+                    core::cmp::Ordering::Equal => Orientation::Collinear,
+                };
+                assert_eq!(RobustKernel::orient2d(p, q, r), expected);
+            }
         }
     }
+
+    #[test]
+    fn near_cocircular_f32() {
+        let a = coord! { x: 4097f32, y: 4096. };
+        let b = coord! { x: 4096f32, y: 4097. };
+        let c = coord! { x: 4095f32, y: 4096. };
+        // 2^-12 is the spacing of f32 values around 4095
+        let ulp = 1. / 4096.;
+        let on = coord! { x: 4096f32, y: 4095. };
+        let inside = coord! { x: 4096f32, y: 4095. + ulp };
+        let outside = coord! { x: 4096f32, y: 4095. - ulp };
+        assert_eq!(RobustKernel::incircle(a, b, c, on), Orientation::Collinear);
+        assert_eq!(
+            RobustKernel::incircle(a, b, c, inside),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            RobustKernel::incircle(a, b, c, outside),
+            Orientation::Clockwise
+        );
+    }
+
+    #[test]
+    fn algorithms_on_near_degenerate_f32() {
+        // the middle point is one ulp above the segment joining the others
+        let ulp = f32::EPSILON / 2.;
+        let points = MultiPoint::from(vec![(0.5f32, 0.5), (12., 12.), (0.75, 0.75 + ulp)]);
+        assert_eq!(points.convex_hull().exterior().0.len(), 4);
+
+        let triangle = polygon![(x: 0.5f32, y: 0.5), (x: 12., y: 0.5), (x: 12., y: 12.)];
+        // on the hypotenuse, then just above it
+        assert!(!triangle.contains(&coord! { x: 0.75f32, y: 0.75 }));
+        assert!(!triangle.contains(&coord! { x: 0.75f32, y: 0.75 + ulp }));
+        assert!(triangle.contains(&coord! { x: 0.75f32, y: 0.75 - ulp }));
+    }
 }
@@ -0,0 +1,212 @@
+use crate::{Coord, GeoFloat, MultiPoint, Point};
+
+use rand::Rng;
+
+/// Partition points into `k` clusters using [k-means](https://en.wikipedia.org/wiki/K-means_clustering)
+/// (Lloyd's algorithm), with initial centroids chosen by
+/// [k-means++](https://en.wikipedia.org/wiki/K-means%2B%2B) seeding.
+///
+/// Starting from the seeded centroids, every point is assigned to the cluster with the nearest
+/// centroid, and centroids are moved to the mean of their points, until the assignments stop
+/// changing or `max_iterations` is reached. A cluster left without points keeps its previous
+/// centroid.
+///
+/// Returns the cluster of each input point, in the same order, and the centroid of each
+/// cluster. If there are fewer than `k` points, there are as many clusters as points.
+///
+/// Requires the `use-rand` feature.
+///
+/// # Panics
+///
+/// If `k` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, KMeans, MultiPoint};
+/// use rand::thread_rng;
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 1., y: 0.),
+///     point!(x: 10., y: 10.),
+///     point!(x: 11., y: 10.),
+/// ]);
+///
+/// let (assignments, centroids) = points.kmeans(2, 100, &mut thread_rng());
+/// assert_eq!(assignments[0], assignments[1]);
+/// assert_eq!(assignments[2], assignments[3]);
+/// assert_ne!(assignments[0], assignments[2]);
+/// assert_eq!(centroids.0[assignments[0]], point!(x: 0.5, y: 0.));
+/// ```
+pub trait KMeans<T: GeoFloat> {
+    fn kmeans<R: Rng + ?Sized>(
+        &self,
+        k: usize,
+        max_iterations: usize,
+        rng: &mut R,
+    ) -> (Vec<usize>, MultiPoint<T>);
+}
+
+impl<T: GeoFloat> KMeans<T> for [Point<T>] {
+    fn kmeans<R: Rng + ?Sized>(
+        &self,
+        k: usize,
+        max_iterations: usize,
+        rng: &mut R,
+    ) -> (Vec<usize>, MultiPoint<T>) {
+        assert!(k > 0, "k-means needs at least one cluster");
+        let coords: Vec<Coord<T>> = self.iter().map(|point| point.0).collect();
+        let mut centroids = seed(&coords, k.min(coords.len()), rng);
+
+        let mut assignments: Vec<usize> = coords
+            .iter()
+            .map(|coord| nearest(&centroids, *coord).0)
+            .collect();
+        for _ in 0..max_iterations {
+            // move the centroids to the mean of their points
+            let mut sums = vec![(Coord::zero(), 0usize); centroids.len()];
+            for (coord, cluster) in coords.iter().zip(&assignments) {
+                let (sum, count) = &mut sums[*cluster];
+                *sum = *sum + *coord;
+                *count += 1;
+            }
+            for (centroid, (sum, count)) in centroids.iter_mut().zip(sums) {
+                if count > 0 {
+                    *centroid = sum / T::from(count).unwrap();
+                }
+            }
+
+            let mut changed = false;
+            for (coord, cluster) in coords.iter().zip(assignments.iter_mut()) {
+                let (closest, _) = nearest(&centroids, *coord);
+                if closest != *cluster {
+                    *cluster = closest;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        (
+            assignments,
+            centroids.into_iter().map(Point::from).collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> KMeans<T> for MultiPoint<T> {
+    fn kmeans<R: Rng + ?Sized>(
+        &self,
+        k: usize,
+        max_iterations: usize,
+        rng: &mut R,
+    ) -> (Vec<usize>, MultiPoint<T>) {
+        self.0.kmeans(k, max_iterations, rng)
+    }
+}
+
+fn distance_2<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    let delta = a - b;
+    delta.x * delta.x + delta.y * delta.y
+}
+
+// The index of the centroid nearest to `coord`, and its squared distance.
+fn nearest<T: GeoFloat>(centroids: &[Coord<T>], coord: Coord<T>) -> (usize, T) {
+    centroids
+        .iter()
+        .map(|centroid| distance_2(*centroid, coord))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .unwrap()
+}
+
+// k-means++: the first centroid is chosen uniformly at random, and every following one with a
+// probability proportional to its squared distance to the nearest centroid chosen so far.
+fn seed<T: GeoFloat, R: Rng + ?Sized>(coords: &[Coord<T>], k: usize, rng: &mut R) -> Vec<Coord<T>> {
+    if k == 0 {
+        return vec![];
+    }
+    let mut centroids = vec![coords[rng.gen_range(0..coords.len())]];
+    let mut distances: Vec<T> = coords
+        .iter()
+        .map(|coord| distance_2(centroids[0], *coord))
+        .collect();
+    while centroids.len() < k {
+        let total = distances.iter().fold(T::zero(), |sum, d| sum + *d);
+        let next = if total > T::zero() {
+            let mut target = total * T::from(rng.gen::<f64>()).unwrap();
+            distances
+                .iter()
+                .position(|distance| {
+                    target = target - *distance;
+                    target < T::zero()
+                })
+                // rounding may leave a small remainder
+                .unwrap_or_else(|| distances.iter().rposition(|d| *d > T::zero()).unwrap())
+        } else {
+            // all points coincide with a centroid
+            rng.gen_range(0..coords.len())
+        };
+        let centroid = coords[next];
+        centroids.push(centroid);
+        for (distance, coord) in distances.iter_mut().zip(coords) {
+            *distance = distance.min(distance_2(centroid, *coord));
+        }
+    }
+    centroids
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn separated_clusters() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let centers = [(0., 0.), (20., 0.), (0., 20.)];
+        let offsets = [(-1., 0.), (1., 0.), (0., -1.), (0., 1.)];
+        let points: Vec<Point<f64>> = centers
+            .iter()
+            .flat_map(|(x, y)| {
+                offsets
+                    .iter()
+                    .map(move |(dx, dy)| point!(x: x + dx, y: y + dy))
+            })
+            .collect();
+
+        let (assignments, centroids) = points.kmeans(3, 100, &mut rng);
+        assert_eq!(centroids.0.len(), 3);
+        for (group, center) in assignments.chunks(4).zip(centers) {
+            assert!(group.iter().all(|cluster| *cluster == group[0]));
+            assert_relative_eq!(centroids.0[group[0]], Point::from(center));
+        }
+    }
+
+    #[test]
+    fn fewer_points_than_clusters() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let points = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]);
+        let (mut assignments, centroids) = points.kmeans(5, 10, &mut rng);
+        assert_eq!(centroids.0.len(), 2);
+        assignments.sort_unstable();
+        assert_eq!(assignments, vec![0, 1]);
+
+        let (assignments, centroids) = MultiPoint::<f64>::new(vec![]).kmeans(3, 10, &mut rng);
+        assert!(assignments.is_empty());
+        assert!(centroids.0.is_empty());
+    }
+
+    #[test]
+    fn duplicate_points() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let points = [point!(x: 1., y: 1.); 4];
+        let (assignments, centroids) = points.kmeans(2, 10, &mut rng);
+        assert_eq!(assignments.len(), 4);
+        assert!(centroids.iter().all(|c| *c == point!(x: 1., y: 1.)));
+    }
+}
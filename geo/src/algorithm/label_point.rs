@@ -0,0 +1,314 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{
+    Area, BoundingRect, Centroid, Contains, Coord, EuclideanDistance, GeoFloat, Line, LineString,
+    MultiLineString, MultiPolygon, Point, Polygon,
+};
+
+/// The largest turn, in degrees, between the segments of a straight-ish run of a line string.
+const MAX_TURN: f64 = 30.;
+
+/// The precision the pole of inaccessibility is found to, as a fraction of the largest side of
+/// the bounding rectangle of the polygon.
+const POLE_PRECISION: f64 = 0.001;
+
+/// Where to anchor the label of a geometry, as found by [`LabelPoint`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabelAnchor<T: GeoFloat> {
+    /// The point to center the label on.
+    pub point: Point<T>,
+    /// The angle to rotate the label by, in degrees counter-clockwise from the x axis, between
+    /// -90° excluded and 90° so that the label reads left to right. 0 for polygons.
+    pub angle: T,
+}
+
+/// Find a good point to anchor the label of a geometry on, e.g. to place the names of roads and
+/// lakes on a map.
+///
+/// The label of a line string is centered halfway along its longest straight-ish run, where
+/// the segments turn by 30° at most, and rotated along the line from the start to the end of
+/// the run.
+/// The label of a polygon is centered on its pole of inaccessibility, the point inside it
+/// farthest from its rings, found to a thousandth of its size by the polylabel algorithm:
+/// square cells, with the best cells searched first, are split until none can hold a farther
+/// point. The label of a multi-geometry is that of its longest line string, or of its largest
+/// polygon.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, LabelPoint};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 3.)];
+///
+/// let anchor = road.label_point().unwrap();
+/// assert_eq!(anchor.point, point!(x: 5., y: 0.));
+/// assert_eq!(anchor.angle, 0.);
+/// ```
+pub trait LabelPoint<T: GeoFloat> {
+    /// The label anchor of the geometry, or `None` if it has no length, or, for a polygon, no
+    /// area.
+    fn label_point(&self) -> Option<LabelAnchor<T>>;
+}
+
+impl<T: GeoFloat> LabelPoint<T> for LineString<T> {
+    fn label_point(&self) -> Option<LabelAnchor<T>> {
+        let max_turn = T::from(MAX_TURN).unwrap().to_radians();
+        let lines: Vec<Line<T>> = self.lines().filter(|line| line.start != line.end).collect();
+
+        // the start and end of the longest run, and its length
+        let mut longest: Option<(usize, usize)> = None;
+        let mut longest_length = T::zero();
+        let (mut start, mut length) = (0, T::zero());
+        for (idx, line) in lines.iter().enumerate() {
+            if idx > start && turn(lines[idx - 1], *line) > max_turn {
+                start = idx;
+                length = T::zero();
+            }
+            length = length + line_length(*line);
+            if length > longest_length {
+                longest = Some((start, idx + 1));
+                longest_length = length;
+            }
+        }
+        let (start, end) = longest?;
+        let run = &lines[start..end];
+
+        let mut point = run[run.len() - 1].end;
+        let mut remaining = longest_length / (T::one() + T::one());
+        for line in run {
+            let length = line_length(*line);
+            if remaining <= length {
+                point = line.start + line.delta() * (remaining / length);
+                break;
+            }
+            remaining = remaining - length;
+        }
+
+        let chord = run[run.len() - 1].end - run[0].start;
+        let mut angle = chord.y.atan2(chord.x).to_degrees();
+        let (right_angle, half_turn) = (T::from(90).unwrap(), T::from(180).unwrap());
+        if angle > right_angle {
+            angle = angle - half_turn;
+        } else if angle <= -right_angle {
+            angle = angle + half_turn;
+        }
+        Some(LabelAnchor {
+            point: point.into(),
+            angle,
+        })
+    }
+}
+
+impl<T: GeoFloat> LabelPoint<T> for MultiLineString<T> {
+    fn label_point(&self) -> Option<LabelAnchor<T>> {
+        self.iter()
+            .map(|line_string| {
+                let length = line_string
+                    .lines()
+                    .fold(T::zero(), |length, line| length + line_length(line));
+                (line_string, length)
+            })
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .and_then(|(line_string, _)| line_string.label_point())
+    }
+}
+
+impl<T: GeoFloat> LabelPoint<T> for Polygon<T> {
+    fn label_point(&self) -> Option<LabelAnchor<T>> {
+        let bounds = self.exterior().bounding_rect()?;
+        let size = bounds.width().min(bounds.height());
+        if size == T::zero() {
+            return None;
+        }
+        let precision = bounds.width().max(bounds.height()) * T::from(POLE_PRECISION).unwrap();
+
+        let two = T::one() + T::one();
+        let half = size / two;
+        let mut cells = BinaryHeap::new();
+        let mut x = bounds.min().x;
+        while x < bounds.max().x {
+            let mut y = bounds.min().y;
+            while y < bounds.max().y {
+                cells.push(Cell::new(
+                    Coord {
+                        x: x + half,
+                        y: y + half,
+                    },
+                    half,
+                    self,
+                ));
+                y = y + size;
+            }
+            x = x + size;
+        }
+
+        let mut best = Cell::new(bounds.center(), T::zero(), self);
+        if let Some(centroid) = self.centroid() {
+            let cell = Cell::new(centroid.0, T::zero(), self);
+            if cell.distance > best.distance {
+                best = cell;
+            }
+        }
+        while let Some(cell) = cells.pop() {
+            if cell.distance > best.distance {
+                best = cell;
+            }
+            if cell.max_distance() - best.distance <= precision {
+                continue;
+            }
+            let half = cell.half / two;
+            for (x, y) in [(-half, -half), (half, -half), (-half, half), (half, half)] {
+                cells.push(Cell::new(cell.center + Coord { x, y }, half, self));
+            }
+        }
+
+        Some(LabelAnchor {
+            point: best.center.into(),
+            angle: T::zero(),
+        })
+    }
+}
+
+impl<T: GeoFloat> LabelPoint<T> for MultiPolygon<T> {
+    fn label_point(&self) -> Option<LabelAnchor<T>> {
+        self.iter()
+            .max_by(|a, b| {
+                a.unsigned_area()
+                    .partial_cmp(&b.unsigned_area())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .and_then(|polygon| polygon.label_point())
+    }
+}
+
+fn line_length<T: GeoFloat>(line: Line<T>) -> T {
+    let delta = line.delta();
+    delta.x.hypot(delta.y)
+}
+
+/// The angle, in radians, between the directions of two lines.
+fn turn<T: GeoFloat>(a: Line<T>, b: Line<T>) -> T {
+    let (a, b) = (a.delta(), b.delta());
+    let cross = a.x * b.y - a.y * b.x;
+    let dot = a.x * b.x + a.y * b.y;
+    cross.atan2(dot).abs()
+}
+
+/// A square cell searched for the pole of inaccessibility, ordered by the largest distance a
+/// point in it can be from the rings of the polygon.
+#[derive(Clone, Copy)]
+struct Cell<T: GeoFloat> {
+    center: Coord<T>,
+    /// Half the side of the cell.
+    half: T,
+    /// The distance from the center to the rings, negative outside the polygon.
+    distance: T,
+}
+
+impl<T: GeoFloat> Cell<T> {
+    fn new(center: Coord<T>, half: T, polygon: &Polygon<T>) -> Self {
+        let distance = polygon
+            .exterior()
+            .lines()
+            .chain(polygon.interiors().iter().flat_map(|ring| ring.lines()))
+            .map(|line| center.euclidean_distance(&line))
+            .fold(T::infinity(), T::min);
+        Cell {
+            center,
+            half,
+            distance: if polygon.contains(&center) {
+                distance
+            } else {
+                -distance
+            },
+        }
+    }
+
+    fn max_distance(&self) -> T {
+        self.distance + self.half * T::from(std::f64::consts::SQRT_2).unwrap()
+    }
+}
+
+impl<T: GeoFloat> PartialEq for Cell<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_distance() == other.max_distance()
+    }
+}
+
+impl<T: GeoFloat> Eq for Cell<T> {}
+
+impl<T: GeoFloat> PartialOrd for Cell<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for Cell<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_distance()
+            .partial_cmp(&other.max_distance())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn lines() {
+        let road = line_string![
+            // a straight-ish run
+            (x: 0., y: 0.),
+            (x: 4., y: 1.),
+            (x: 8., y: 0.),
+            // the longest one, downwards
+            (x: 8., y: -10.),
+        ];
+        let anchor = road.label_point().unwrap();
+        assert_eq!(anchor.point, point!(x: 8., y: -5.));
+        assert_relative_eq!(anchor.angle, 90.);
+
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 4., y: 1.), (x: 8., y: 0.)],
+        ]);
+        let anchor = lines.label_point().unwrap();
+        assert_eq!(anchor.point, point!(x: 4., y: 1.));
+        assert_eq!(anchor.angle, 0.);
+
+        assert_eq!(
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.)].label_point(),
+            None
+        );
+    }
+
+    #[test]
+    fn polygons() {
+        let rect = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.), (x: 0., y: 4.)];
+        let anchor = rect.label_point().unwrap();
+        assert_relative_eq!(anchor.point.y(), 2., epsilon = 0.01);
+        assert!((2.0..=8.0).contains(&anchor.point.x()));
+        assert_eq!(anchor.angle, 0.);
+
+        // the centroid is in the hole
+        let ring = polygon![
+            exterior: [(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 20., y: 10.)],
+            interiors: [[(x: 22., y: 2.), (x: 28., y: 2.), (x: 28., y: 8.), (x: 22., y: 8.)]],
+        ];
+        let anchor = ring.label_point().unwrap();
+        assert!(ring.contains(&anchor.point));
+        // in a corner, where the ring is widest, as far from both sides as from the hole's corner
+        assert_relative_eq!(
+            Cell::new(anchor.point.0, 0., &ring).distance,
+            2. * 2f64.sqrt() / (1. + 2f64.sqrt()),
+            epsilon = 0.01
+        );
+
+        let polygons = MultiPolygon::new(vec![rect, ring]);
+        assert_eq!(polygons.label_point(), polygons.0[1].label_point());
+    }
+}
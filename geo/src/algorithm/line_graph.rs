@@ -0,0 +1,689 @@
+use std::cmp::Ordering;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+
+use num_traits::FromPrimitive;
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+
+use crate::line_intersection::LineIntersection;
+use crate::segment_intersections::{intersections_between, SegmentId};
+use crate::utils::lex_cmp;
+use crate::{
+    Coord, EuclideanLength, GeoFloat, HaversineDistance, Line, LineString, MultiLineString, Point,
+};
+
+/// A network graph of linework, such as roads, rivers or pipes, for routing and connectivity
+/// analysis.
+///
+/// The line strings are noded: they're split wherever they cross or touch one another, or
+/// themselves, so that the graph has a node at every endpoint and every intersection, and an
+/// edge for every part of a line string between two nodes. Edges keep the intermediate
+/// vertices of their line strings, and are undirected.
+///
+/// Nodes are merged only if their coordinates are exactly equal, so line strings meant to be
+/// connected must share their endpoints exactly, or cross one another. Line strings
+/// overlapping along a stretch give parallel edges, one for each line string.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, LineGraph, MultiLineString};
+///
+/// let streets = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+///     line_string![(x: 2., y: -2.), (x: 2., y: 2.)],
+/// ]);
+/// let graph = LineGraph::new(&streets);
+///
+/// // the ends of the streets, and their crossing
+/// assert_eq!(graph.nodes().len(), 5);
+/// assert_eq!(graph.edges().len(), 4);
+///
+/// let crossing = graph.node_index(coord! { x: 2., y: 0. }).unwrap();
+/// assert_eq!(graph.adjacent_edges(crossing).len(), 4);
+/// assert!(graph.edges().iter().all(|edge| edge.length == 2.));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineGraph<T: GeoFloat> {
+    nodes: Vec<Coord<T>>,
+    edges: Vec<LineGraphEdge<T>>,
+    adjacency: Vec<Vec<usize>>,
+    // the segments of the edges, with the index of their edge and their index in it
+    segments: RTree<GeomWithData<Line<T>, (usize, usize)>>,
+}
+
+/// An edge of a [`LineGraph`]: a part of a line string between two nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineGraphEdge<T: GeoFloat> {
+    /// The index of the node at the start of the edge.
+    pub from: usize,
+    /// The index of the node at the end of the edge.
+    pub to: usize,
+    /// The index of the line string the edge is part of.
+    pub line: usize,
+    /// The part of the line string, from the `from` node to the `to` node.
+    pub geometry: LineString<T>,
+    /// The euclidean length of the edge.
+    pub length: T,
+}
+
+impl<T: GeoFloat> LineGraphEdge<T> {
+    /// The node at the other end of the edge from `node`.
+    pub fn other(&self, node: usize) -> usize {
+        if node == self.from {
+            self.to
+        } else {
+            self.from
+        }
+    }
+}
+
+impl<T: GeoFloat> LineGraph<T> {
+    /// Build the graph of the linework, noding it at the endpoints and intersections of its
+    /// line strings.
+    pub fn new(lines: &MultiLineString<T>) -> Self {
+        let splits = split_points(lines);
+
+        let mut nodes: Vec<Coord<T>> = splits.values().flatten().copied().collect();
+        for line_string in lines.iter().filter(|line_string| line_string.0.len() >= 2) {
+            nodes.push(line_string[0]);
+            nodes.push(line_string[line_string.0.len() - 1]);
+        }
+        nodes.sort_unstable_by(lex_cmp);
+        nodes.dedup();
+
+        let mut graph = LineGraph {
+            adjacency: vec![vec![]; nodes.len()],
+            nodes,
+            edges: vec![],
+            segments: RTree::new(),
+        };
+        for (line, line_string) in lines.iter().enumerate() {
+            let mut part = match line_string.0.first() {
+                Some(&start) => vec![start],
+                None => continue,
+            };
+            for (segment, l) in line_string.lines().enumerate() {
+                let mut points = splits
+                    .get(&SegmentId { line, segment })
+                    .cloned()
+                    .unwrap_or_default();
+                let along = |c: &Coord<T>| {
+                    let (delta, offset) = (l.delta(), *c - l.start);
+                    offset.x * delta.x + offset.y * delta.y
+                };
+                points.sort_by(|a, b| along(a).partial_cmp(&along(b)).unwrap_or(Ordering::Equal));
+                points.push(l.end);
+                for point in points {
+                    if part.last() != Some(&point) {
+                        part.push(point);
+                    }
+                    if part.len() >= 2 && graph.node_index(point).is_some() {
+                        let geometry = LineString::new(std::mem::replace(&mut part, vec![point]));
+                        graph.add_edge(line, geometry);
+                    }
+                }
+            }
+        }
+        graph.segments = RTree::bulk_load(
+            graph
+                .edges
+                .iter()
+                .enumerate()
+                .flat_map(|(edge, LineGraphEdge { geometry, .. })| {
+                    geometry
+                        .lines()
+                        .enumerate()
+                        .map(move |(segment, line)| GeomWithData::new(line, (edge, segment)))
+                })
+                .collect(),
+        );
+        graph
+    }
+
+    fn add_edge(&mut self, line: usize, geometry: LineString<T>) {
+        let endpoint = |c| self.node_index(c).expect("edges end at nodes");
+        let (from, to) = (
+            endpoint(geometry[0]),
+            endpoint(geometry[geometry.0.len() - 1]),
+        );
+        let idx = self.edges.len();
+        self.adjacency[from].push(idx);
+        if to != from {
+            self.adjacency[to].push(idx);
+        }
+        self.edges.push(LineGraphEdge {
+            from,
+            to,
+            line,
+            length: geometry
+                .lines()
+                .fold(T::zero(), |length, line| length + line.euclidean_length()),
+            geometry,
+        });
+    }
+
+    /// The coordinates of the nodes, in lexicographic order.
+    pub fn nodes(&self) -> &[Coord<T>] {
+        &self.nodes
+    }
+
+    /// The edges, in the order of the line strings, and of their parts along each line string.
+    pub fn edges(&self) -> &[LineGraphEdge<T>] {
+        &self.edges
+    }
+
+    /// The index of the node at `coord`, if there's one.
+    pub fn node_index(&self, coord: Coord<T>) -> Option<usize> {
+        self.nodes
+            .binary_search_by(|node| lex_cmp(node, &coord))
+            .ok()
+    }
+
+    /// The indices of the edges starting or ending at `node`.
+    ///
+    /// # Panics
+    ///
+    /// If there's no such node.
+    pub fn adjacent_edges(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    /// The nodes connected to `node` by an edge, with the index of the edge.
+    ///
+    /// # Panics
+    ///
+    /// If there's no such node.
+    pub fn neighbours(&self, node: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency[node]
+            .iter()
+            .map(move |&edge| (edge, self.edges[edge].other(node)))
+    }
+
+    /// The position on the edges closest to `point`, found with an R-tree of their segments.
+    ///
+    /// Returns `None` if the graph has no edges.
+    pub fn snap(&self, point: Point<T>) -> Option<EdgePosition<T>> {
+        let nearest = self.segments.nearest_neighbor(&point)?;
+        let (edge, segment) = nearest.data;
+        Some(EdgePosition {
+            edge,
+            segment,
+            coord: closest_on_segment(*nearest.geom(), point.0),
+        })
+    }
+
+    /// The shortest path along the edges between the positions closest to `start` and `end`,
+    /// measured with euclidean distances.
+    ///
+    /// The path is searched with the A* algorithm, i.e. Dijkstra's algorithm guided by the
+    /// straight line distance to the end. Returns `None` if the graph has no edges, or if the
+    /// end can't be reached from the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, point, LineGraph, MultiLineString};
+    ///
+    /// let streets = MultiLineString::new(vec![
+    ///     line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+    ///     line_string![(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.)],
+    /// ]);
+    /// let graph = LineGraph::new(&streets);
+    ///
+    /// let path = graph
+    ///     .shortest_path(point!(x: 1., y: -0.5), point!(x: 4.5, y: 1.))
+    ///     .unwrap();
+    /// assert_eq!(path.geometry, line_string![(x: 1., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.)]);
+    /// assert_eq!(path.length, 4.);
+    /// ```
+    pub fn shortest_path(&self, start: Point<T>, end: Point<T>) -> Option<GraphPath<T>> {
+        self.path_between(self.snap(start)?, self.snap(end)?, euclidean_distance)
+    }
+
+    /// The shortest path along the edges between the positions closest to `start` and `end`,
+    /// measured with [haversine](HaversineDistance) distances, in meters, for linework in
+    /// longitude and latitude.
+    ///
+    /// The positions are still snapped onto the edges in the plane of the longitudes and
+    /// latitudes, which is close enough for points near the linework.
+    pub fn haversine_shortest_path(&self, start: Point<T>, end: Point<T>) -> Option<GraphPath<T>>
+    where
+        T: FromPrimitive,
+    {
+        self.path_between(self.snap(start)?, self.snap(end)?, |a, b| {
+            Point::from(a).haversine_distance(&Point::from(b))
+        })
+    }
+
+    // The closest position on each edge within `radius` of `point`, in the order of the edges.
+    pub(crate) fn positions_within(&self, point: Point<T>, radius: T) -> Vec<EdgePosition<T>> {
+        let mut nearest: BTreeMap<usize, (T, EdgePosition<T>)> = BTreeMap::new();
+        for candidate in self.segments.locate_within_distance(point, radius * radius) {
+            let (edge, segment) = candidate.data;
+            let coord = closest_on_segment(*candidate.geom(), point.0);
+            let distance = euclidean_distance(coord, point.0);
+            let position = EdgePosition {
+                edge,
+                segment,
+                coord,
+            };
+            match nearest.entry(edge) {
+                Entry::Vacant(entry) => {
+                    entry.insert((distance, position));
+                }
+                Entry::Occupied(mut entry) => {
+                    if distance < entry.get().0 {
+                        entry.insert((distance, position));
+                    }
+                }
+            }
+        }
+        nearest
+            .into_values()
+            .map(|(_, position)| position)
+            .collect()
+    }
+
+    // A* over the nodes, from the ends of the start edge, to the ends of the end edge
+    pub(crate) fn path_between(
+        &self,
+        start: EdgePosition<T>,
+        end: EdgePosition<T>,
+        distance: impl Fn(Coord<T>, Coord<T>) -> T,
+    ) -> Option<GraphPath<T>> {
+        let length = |coords: &[Coord<T>]| {
+            coords.windows(2).fold(T::zero(), |length, pair| {
+                length + distance(pair[0], pair[1])
+            })
+        };
+        let (start_edge, end_edge) = (&self.edges[start.edge], &self.edges[end.edge]);
+
+        // the legs from the start to the ends of its edge, and from those of the end's edge
+        let (mut to_from, to_to) = self.split(start);
+        to_from.reverse();
+        let (from_end, mut to_end) = self.split(end);
+        to_end.reverse();
+        let start_legs = [(start_edge.from, to_from), (start_edge.to, to_to)];
+        let end_legs = [(end_edge.from, from_end), (end_edge.to, to_end)];
+
+        let mut best: Option<(T, Vec<Coord<T>>)> = None;
+        if start.edge == end.edge {
+            let direct = self.along_edge(start, end);
+            best = Some((length(&direct), direct));
+        }
+
+        // only the nodes reached are recorded, as the search rarely needs to reach them all
+        let mut distances: HashMap<usize, T> = HashMap::new();
+        let mut previous: HashMap<usize, usize> = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        for (node, leg) in &start_legs {
+            let leg_length = length(leg);
+            if distances.get(node).map_or(true, |&d| leg_length < d) {
+                distances.insert(*node, leg_length);
+                queue.push(State {
+                    estimate: leg_length + distance(self.nodes[*node], end.coord),
+                    distance: leg_length,
+                    node: *node,
+                });
+            }
+        }
+
+        let mut finish = None;
+        while let Some(State {
+            estimate,
+            distance: travelled,
+            node,
+        }) = queue.pop()
+        {
+            if matches!(best, Some((shortest, _)) if estimate >= shortest) {
+                break;
+            }
+            if travelled > distances[&node] {
+                continue;
+            }
+            for (end_node, leg) in &end_legs {
+                let total = travelled + length(leg);
+                if *end_node == node
+                    && best
+                        .as_ref()
+                        .map_or(true, |(shortest, _)| total < *shortest)
+                {
+                    best = Some((total, leg.clone()));
+                    finish = Some(node);
+                }
+            }
+            for &edge in &self.adjacency[node] {
+                let next = self.edges[edge].other(node);
+                let next_distance = travelled + length(&self.edges[edge].geometry.0);
+                if distances.get(&next).map_or(true, |&d| next_distance < d) {
+                    distances.insert(next, next_distance);
+                    previous.insert(next, edge);
+                    queue.push(State {
+                        estimate: next_distance + distance(self.nodes[next], end.coord),
+                        distance: next_distance,
+                        node: next,
+                    });
+                }
+            }
+        }
+
+        // the whole path if it stays on one edge, or else the leg from the last node to the end
+        let (total, last_leg) = best?;
+        let mut edges = vec![end.edge];
+        let mut coords = match finish {
+            None => last_leg,
+            Some(mut node) => {
+                // walk back to the start, then turn around
+                let mut coords: Vec<Coord<T>> = last_leg.into_iter().rev().collect();
+                while let Some(&edge) = previous.get(&node) {
+                    let geometry = &self.edges[edge].geometry.0;
+                    if self.edges[edge].to == node {
+                        coords.extend(geometry.iter().rev());
+                    } else {
+                        coords.extend(geometry);
+                    }
+                    edges.push(edge);
+                    node = self.edges[edge].other(node);
+                }
+                let start_leg = start_legs
+                    .iter()
+                    .filter(|(start_node, _)| *start_node == node)
+                    .map(|(_, leg)| leg)
+                    .min_by(|a, b| length(a).partial_cmp(&length(b)).unwrap_or(Ordering::Equal))
+                    .expect("the path starts from an end of the start edge");
+                coords.extend(start_leg.iter().rev());
+                coords.reverse();
+                coords
+            }
+        };
+        edges.push(start.edge);
+        edges.reverse();
+        edges.dedup();
+        coords.dedup();
+        Some(GraphPath {
+            geometry: LineString::new(coords),
+            length: total,
+            edges,
+        })
+    }
+
+    // the parts of the edge from its start to the position, and from the position to its end
+    fn split(&self, position: EdgePosition<T>) -> (Vec<Coord<T>>, Vec<Coord<T>>) {
+        let coords = &self.edges[position.edge].geometry.0;
+        let mut before = coords[..=position.segment].to_vec();
+        before.push(position.coord);
+        let mut after = vec![position.coord];
+        after.extend(&coords[position.segment + 1..]);
+        before.dedup();
+        after.dedup();
+        (before, after)
+    }
+
+    // the part of an edge between two positions on it
+    fn along_edge(&self, start: EdgePosition<T>, end: EdgePosition<T>) -> Vec<Coord<T>> {
+        let coords = &self.edges[start.edge].geometry.0;
+        let key = |position: EdgePosition<T>| {
+            let offset = position.coord - coords[position.segment];
+            (position.segment, offset.x.abs() + offset.y.abs())
+        };
+        let mut path = vec![start.coord];
+        if key(start) <= key(end) {
+            path.extend(&coords[start.segment + 1..=end.segment]);
+        } else {
+            path.extend(coords[end.segment + 1..=start.segment].iter().rev());
+        }
+        path.push(end.coord);
+        path.dedup();
+        path
+    }
+}
+
+/// A position on an edge of a [`LineGraph`], as found by [`LineGraph::snap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgePosition<T: GeoFloat> {
+    /// The index of the edge.
+    pub edge: usize,
+    /// The index of the segment of the geometry of the edge the position is on.
+    pub segment: usize,
+    pub coord: Coord<T>,
+}
+
+/// A path along the edges of a [`LineGraph`], as found by [`LineGraph::shortest_path`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphPath<T: GeoFloat> {
+    /// The path, from the position the start is snapped to, to that the end is snapped to.
+    pub geometry: LineString<T>,
+    /// The length of the path, as measured to find it.
+    pub length: T,
+    /// The indices of the edges the path goes along, wholly or in part, in order.
+    pub edges: Vec<usize>,
+}
+
+pub(crate) fn euclidean_distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    Line::new(a, b).euclidean_length()
+}
+
+fn closest_on_segment<T: GeoFloat>(line: Line<T>, coord: Coord<T>) -> Coord<T> {
+    let (delta, offset) = (line.delta(), coord - line.start);
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+    if length_2 == T::zero() {
+        return line.start;
+    }
+    let t = ((offset.x * delta.x + offset.y * delta.y) / length_2)
+        .max(T::zero())
+        .min(T::one());
+    line.start + delta * t
+}
+
+#[derive(Debug)]
+struct State<T: GeoFloat> {
+    // the distance travelled, plus the straight line distance left to the end
+    estimate: T,
+    distance: T,
+    node: usize,
+}
+
+impl<T: GeoFloat> PartialEq for State<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<T: GeoFloat> Eq for State<T> {}
+
+impl<T: GeoFloat> PartialOrd for State<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for State<T> {
+    // reversed, so that `BinaryHeap` pops the most promising state first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .partial_cmp(&self.estimate)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+// The points where each segment meets another segment, other than the vertex it shares with
+// the segments next to it.
+fn split_points<T: GeoFloat>(lines: &MultiLineString<T>) -> HashMap<SegmentId, Vec<Coord<T>>> {
+    let mut splits: HashMap<SegmentId, Vec<Coord<T>>> = HashMap::new();
+    // every pair of segments is found twice: the intersection is taken from the first, so that
+    // both segments are split at exactly the same points
+    for found in intersections_between(lines, lines) {
+        let (a, b) = (found.a, found.b);
+        if a >= b {
+            continue;
+        }
+        let points = match found.intersection {
+            LineIntersection::SinglePoint { intersection, .. } => {
+                let next = a.line == b.line && a.segment + 1 == b.segment;
+                if next && intersection == lines.0[a.line][b.segment] {
+                    continue;
+                }
+                vec![intersection]
+            }
+            LineIntersection::Collinear { intersection } => {
+                vec![intersection.start, intersection.end]
+            }
+        };
+        splits.entry(a).or_default().extend(&points);
+        splits.entry(b).or_default().extend(points);
+    }
+    splits
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, HaversineLength};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn noding() {
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+            line_string![(x: 2., y: -2.), (x: 2., y: 2.)],
+            // joining the first at its end, with a vertex which isn't a node
+            line_string![(x: 4., y: 0.), (x: 5., y: 1.), (x: 6., y: 0.)],
+        ]);
+        let graph = LineGraph::new(&lines);
+        assert_eq!(
+            graph.nodes(),
+            &[
+                coord! { x: 0., y: 0. },
+                coord! { x: 2., y: -2. },
+                coord! { x: 2., y: 0. },
+                coord! { x: 2., y: 2. },
+                coord! { x: 4., y: 0. },
+                coord! { x: 6., y: 0. },
+            ]
+        );
+
+        let edges: Vec<_> = graph
+            .edges()
+            .iter()
+            .map(|edge| (edge.from, edge.to, edge.line))
+            .collect();
+        assert_eq!(
+            edges,
+            vec![(0, 2, 0), (2, 4, 0), (1, 2, 1), (2, 3, 1), (4, 5, 2)]
+        );
+        assert_eq!(graph.edges()[0].length, 2.);
+        assert_eq!(
+            graph.edges()[4].geometry,
+            line_string![(x: 4., y: 0.), (x: 5., y: 1.), (x: 6., y: 0.)]
+        );
+
+        assert_eq!(graph.adjacent_edges(2), &[0, 1, 2, 3]);
+        let neighbours: Vec<_> = graph.neighbours(4).collect();
+        assert_eq!(neighbours, vec![(1, 2), (4, 5)]);
+        assert_eq!(graph.node_index(coord! { x: 5., y: 1. }), None);
+    }
+
+    #[test]
+    fn self_intersections() {
+        // a loop, crossing itself at (1, 0)
+        let lines = MultiLineString::new(vec![line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: -1.),
+        ]]);
+        let graph = LineGraph::new(&lines);
+        assert_eq!(graph.nodes().len(), 3);
+        let edges: Vec<_> = graph
+            .edges()
+            .iter()
+            .map(|edge| (edge.from, edge.to))
+            .collect();
+        assert_eq!(edges, vec![(0, 2), (2, 2), (2, 1)]);
+        assert_eq!(graph.edges()[1].length, 4.);
+        assert_eq!(graph.adjacent_edges(2), &[0, 1, 2]);
+
+        // a closed ring without crossings
+        let ring = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        let graph = LineGraph::new(&MultiLineString::new(vec![ring, line_string![]]));
+        assert_eq!(graph.nodes().len(), 1);
+        assert_eq!(graph.edges().len(), 1);
+        assert_eq!(graph.adjacent_edges(0), &[0]);
+    }
+
+    fn square() -> LineGraph<f64> {
+        LineGraph::new(&MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 0., y: 4.)],
+            line_string![(x: 0., y: 4.), (x: 4., y: 4.)],
+            line_string![(x: 4., y: 0.), (x: 4., y: 4.)],
+            // unconnected
+            line_string![(x: 10., y: 10.), (x: 11., y: 10.)],
+        ]))
+    }
+
+    #[test]
+    fn snapping() {
+        let graph = square();
+        assert_eq!(
+            graph.snap(point!(x: 2., y: 1.)),
+            Some(EdgePosition {
+                edge: 0,
+                segment: 0,
+                coord: coord! { x: 2., y: 0. },
+            })
+        );
+        let empty = LineGraph::new(&MultiLineString::<f64>::new(vec![]));
+        assert_eq!(empty.snap(point!(x: 2., y: 1.)), None);
+        assert_eq!(
+            empty.shortest_path(point!(x: 0., y: 0.), point!(x: 1., y: 1.)),
+            None
+        );
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let graph = square();
+        let (start, end) = (point!(x: 1., y: -0.5), point!(x: 1., y: 4.2));
+        let path = graph.shortest_path(start, end).unwrap();
+        assert_eq!(
+            path.geometry,
+            line_string![(x: 1., y: 0.), (x: 0., y: 0.), (x: 0., y: 4.), (x: 1., y: 4.)]
+        );
+        assert_eq!(path.length, 6.);
+        assert_eq!(path.edges, vec![0, 1, 2]);
+
+        let back = graph.shortest_path(end, start).unwrap();
+        assert_eq!(
+            back.geometry.0,
+            path.geometry.0.into_iter().rev().collect::<Vec<_>>()
+        );
+        assert_eq!(back.edges, vec![2, 1, 0]);
+
+        // along a single edge, backwards
+        let path = graph
+            .shortest_path(point!(x: 3., y: 0.5), point!(x: 1., y: -0.5))
+            .unwrap();
+        assert_eq!(path.geometry, line_string![(x: 3., y: 0.), (x: 1., y: 0.)]);
+        assert_eq!(path.edges, vec![0]);
+
+        assert_eq!(graph.shortest_path(start, point!(x: 10.5, y: 11.)), None);
+    }
+
+    #[test]
+    fn haversine_shortest_path() {
+        let graph = square();
+        let path = graph
+            .haversine_shortest_path(point!(x: 1., y: -0.5), point!(x: 1., y: 4.2))
+            .unwrap();
+        assert_eq!(
+            path.geometry,
+            line_string![(x: 1., y: 0.), (x: 0., y: 0.), (x: 0., y: 4.), (x: 1., y: 4.)]
+        );
+        assert_relative_eq!(path.length, path.geometry.haversine_length());
+    }
+}
@@ -1,9 +1,15 @@
-use crate::{Coord, GeoFloat, Line};
+use std::cmp::Ordering;
+
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+};
 use geo_types::coord;
+use rstar::{RTree, RTreeObject};
 
 use crate::BoundingRect;
 use crate::Intersects;
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum LineIntersection<F: GeoFloat> {
     /// Lines intersect in a single point
@@ -158,6 +164,107 @@ where
     }
 }
 
+/// Returns the intersection between two [`LineStrings`](LineString), made of the points where
+/// they cross or touch, and the parts along which they overlap.
+///
+/// The result is a [`MultiPoint`] if the `LineString`s only meet at isolated points, a
+/// [`MultiLineString`] if they only overlap, or a [`GeometryCollection`] of both otherwise. If
+/// the `LineString`s don't intersect, the result is an empty `GeometryCollection`.
+///
+/// Points are listed in the order they are met along `a`, and points lying on an overlap are
+/// left out. Overlapping parts follow the direction of `a`, and consecutive overlaps are joined
+/// into a single `LineString`.
+///
+/// The segments of `b` are indexed with an R-tree, so that every segment of `a` is only tested
+/// against the segments of `b` near it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_intersection::line_string_intersection;
+/// use geo::{line_string, Geometry, MultiLineString, MultiPoint};
+///
+/// let a = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+/// let b = line_string![(x: 2., y: -1.), (x: 2., y: 1.)];
+/// assert_eq!(
+///     line_string_intersection(&a, &b),
+///     Geometry::MultiPoint(MultiPoint::from(vec![(2., 0.)]))
+/// );
+///
+/// let b = line_string![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 6., y: 2.)];
+/// assert_eq!(
+///     line_string_intersection(&a, &b),
+///     Geometry::MultiLineString(MultiLineString::new(vec![line_string![
+///         (x: 2., y: 0.),
+///         (x: 4., y: 0.),
+///         (x: 4., y: 2.),
+///     ]]))
+/// );
+/// ```
+pub fn line_string_intersection<F>(a: &LineString<F>, b: &LineString<F>) -> Geometry<F>
+where
+    F: GeoFloat,
+{
+    let tree: RTree<Line<F>> = RTree::bulk_load(b.lines().collect());
+    let mut points: Vec<Coord<F>> = vec![];
+    let mut overlaps: Vec<Line<F>> = vec![];
+    for segment in a.lines() {
+        let position = |c: Coord<F>| {
+            let (delta, offset) = (segment.delta(), c - segment.start);
+            offset.x * delta.x + offset.y * delta.y
+        };
+        let mut hits: Vec<(F, LineIntersection<F>)> = tree
+            .locate_in_envelope_intersecting(&segment.envelope())
+            .filter_map(|other| line_intersection(segment, *other))
+            .map(|hit| match hit {
+                LineIntersection::SinglePoint { intersection, .. } => (position(intersection), hit),
+                LineIntersection::Collinear { intersection } => {
+                    // follow the direction of `a`
+                    let (start, end) = (position(intersection.start), position(intersection.end));
+                    let intersection = if start <= end {
+                        intersection
+                    } else {
+                        Line::new(intersection.end, intersection.start)
+                    };
+                    (start.min(end), LineIntersection::Collinear { intersection })
+                }
+            })
+            .collect();
+        hits.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        for (_, hit) in hits {
+            match hit {
+                LineIntersection::SinglePoint { intersection, .. } => {
+                    if !points.contains(&intersection) {
+                        points.push(intersection);
+                    }
+                }
+                LineIntersection::Collinear { intersection } => overlaps.push(intersection),
+            }
+        }
+    }
+
+    points.retain(|point| !overlaps.iter().any(|overlap| overlap.intersects(point)));
+    let mut chains: Vec<LineString<F>> = vec![];
+    for overlap in overlaps {
+        match chains.last_mut() {
+            Some(chain) if chain.0.last() == Some(&overlap.start) => chain.0.push(overlap.end),
+            _ => chains.push(LineString::new(vec![overlap.start, overlap.end])),
+        }
+    }
+
+    let points = MultiPoint::from(points);
+    let chains = MultiLineString::new(chains);
+    match (points.0.is_empty(), chains.0.is_empty()) {
+        (false, true) => Geometry::MultiPoint(points),
+        (true, false) => Geometry::MultiLineString(chains),
+        (true, true) => Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+        (false, false) => Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            points.into(),
+            chains.into(),
+        ])),
+    }
+}
+
 fn collinear_intersection<F: GeoFloat>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>> {
     fn collinear<F: GeoFloat>(intersection: Line<F>) -> LineIntersection<F> {
         LineIntersection::Collinear { intersection }
@@ -602,4 +709,49 @@ mod test {
         };
         assert_eq!(actual, Some(expected));
     }
+
+    #[test]
+    fn line_string_crossings_and_overlaps() {
+        use crate::{line_string, point};
+
+        // a zigzag crossing a horizontal line, and running along it in the middle
+        let a = line_string![
+            (x: 0., y: -1.),
+            (x: 1., y: 1.),
+            (x: 2., y: 0.),
+            (x: 4., y: 0.),
+            (x: 5., y: 1.),
+        ];
+        let b = line_string![(x: 0., y: 0.), (x: 6., y: 0.)];
+        let expected = GeometryCollection::new_from(vec![
+            MultiPoint::new(vec![point!(x: 0.5, y: 0.)]).into(),
+            MultiLineString::new(vec![line_string![(x: 2., y: 0.), (x: 4., y: 0.)]]).into(),
+        ]);
+        assert_eq!(
+            line_string_intersection(&a, &b),
+            Geometry::GeometryCollection(expected)
+        );
+
+        let b = line_string![(x: 0., y: 5.), (x: 6., y: 5.)];
+        assert_eq!(
+            line_string_intersection(&a, &b),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![]))
+        );
+    }
+
+    #[test]
+    fn line_string_overlap_follows_first_argument() {
+        use crate::line_string;
+
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let b = line_string![(x: 2., y: 1.), (x: 2., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(
+            line_string_intersection(&a, &b),
+            Geometry::MultiLineString(MultiLineString::new(vec![line_string![
+                (x: 1., y: 0.),
+                (x: 2., y: 0.),
+                (x: 2., y: 1.),
+            ]]))
+        );
+    }
 }
@@ -0,0 +1,157 @@
+use crate::{Coord, CoordFloat, EuclideanLength, Line, LineString, Point};
+
+/// Place markers at regular intervals along a [`LineString`], e.g. arrowheads showing the
+/// direction of a one-way street, chevrons along a route, or kilometer posts.
+///
+/// Each marker is a tuple of its point on the line string, the heading of the line string
+/// there, and its distance from the start along the line string. Headings are in degrees,
+/// counter-clockwise from the x axis, from -180 to 180: a line string going along the x axis
+/// heads at 0°, and one going along the y axis at 90°. Distances are euclidean, so the line
+/// string should be projected.
+///
+/// A marker on a vertex takes the heading of the segment starting there, except on the last
+/// vertex. Segments of zero length have no heading, and never carry a marker.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, LineMarkers};
+///
+/// let route = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+///
+/// let markers: Vec<_> = route.markers(1., 2.).collect();
+/// assert_eq!(
+///     markers,
+///     vec![
+///         (point!(x: 1., y: 0.), 0., 1.),
+///         (point!(x: 3., y: 0.), 0., 3.),
+///         (point!(x: 4., y: 1.), 90., 5.),
+///         (point!(x: 4., y: 3.), 90., 7.),
+///     ]
+/// );
+/// ```
+pub trait LineMarkers<T: CoordFloat> {
+    /// Iterate over the markers `interval` apart along the line string, from `offset` along it
+    /// to its end.
+    ///
+    /// Note: `offset` must not be negative, and `interval` must be greater than 0.
+    fn markers(&self, offset: T, interval: T) -> Markers<'_, T>;
+}
+
+impl<T: CoordFloat> LineMarkers<T> for LineString<T> {
+    fn markers(&self, offset: T, interval: T) -> Markers<'_, T> {
+        assert!(offset >= T::zero());
+        assert!(interval > T::zero());
+        Markers {
+            coords: &self.0,
+            offset,
+            interval,
+            count: 0,
+            segment: 0,
+            travelled: T::zero(),
+            last: None,
+        }
+    }
+}
+
+/// An iterator over the markers along a [`LineString`], as `(point, heading, distance)` tuples.
+/// See [`LineMarkers`].
+#[derive(Debug, Clone)]
+pub struct Markers<'a, T: CoordFloat> {
+    coords: &'a [Coord<T>],
+    offset: T,
+    interval: T,
+    // the number of markers so far
+    count: usize,
+    // the segment the next marker is on or past, with the distance to its start
+    segment: usize,
+    travelled: T,
+    // the last segment of non-zero length before it
+    last: Option<Line<T>>,
+}
+
+impl<'a, T: CoordFloat> Markers<'a, T> {
+    /// The next marker, with the segment it's on, rather than its heading.
+    pub(crate) fn next_on_segment(&mut self) -> Option<(Point<T>, Line<T>, T)> {
+        let distance = self.offset + self.interval * T::from(self.count)?;
+        while self.segment + 1 < self.coords.len() {
+            let line = Line::new(self.coords[self.segment], self.coords[self.segment + 1]);
+            let length = line.euclidean_length();
+            if length > T::zero() {
+                if distance < self.travelled + length {
+                    self.count += 1;
+                    let fraction = (distance - self.travelled) / length;
+                    let point = line.start + line.delta() * fraction;
+                    return Some((point.into(), line, distance));
+                }
+                self.last = Some(line);
+            }
+            self.travelled = self.travelled + length;
+            self.segment += 1;
+        }
+
+        // on the last vertex
+        let line = self.last?;
+        if distance > self.travelled {
+            return None;
+        }
+        self.count += 1;
+        Some((line.end_point(), line, distance))
+    }
+}
+
+impl<'a, T: CoordFloat> Iterator for Markers<'a, T> {
+    type Item = (Point<T>, T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (point, line, distance) = self.next_on_segment()?;
+        Some((point, heading(line), distance))
+    }
+}
+
+fn heading<T: CoordFloat>(line: Line<T>) -> T {
+    line.dy().atan2(line.dx()).to_degrees()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn on_vertices() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            // a repeated coordinate
+            (x: 2., y: 0.),
+            (x: 0., y: 0.),
+            (x: 0., y: -2.),
+        ];
+        let markers: Vec<_> = line_string.markers(0., 2.).collect();
+        assert_eq!(
+            markers,
+            vec![
+                (point!(x: 0., y: 0.), 0., 0.),
+                (point!(x: 2., y: 0.), 180., 2.),
+                (point!(x: 0., y: 0.), -90., 4.),
+                (point!(x: 0., y: -2.), -90., 6.),
+            ]
+        );
+
+        // the end isn't reached
+        assert_eq!(line_string.markers(0.5, 2.).last().unwrap().2, 4.5);
+        assert_eq!(line_string.markers(7., 1.).next(), None);
+    }
+
+    #[test]
+    fn degenerate() {
+        let point = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(point.markers(0., 1.).next(), None);
+        assert_eq!(LineString::<f64>::new(vec![]).markers(0., 1.).next(), None);
+
+        let diagonal = line_string![(x: 0., y: 0.), (x: -3., y: -3.)];
+        let (_, heading, _) = diagonal.markers(1., 1.).next().unwrap();
+        assert_eq!(heading, -135.);
+    }
+}
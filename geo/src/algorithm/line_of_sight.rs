@@ -0,0 +1,154 @@
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coord, GeoFloat, Intersects, Line, Point, Polygon};
+
+use rstar::{RTree, AABB};
+
+/// A set of obstacle polygons, indexed for [`line_of_sight`] queries.
+///
+/// The edges of the polygons are stored in an R-tree, so that a query only examines the edges
+/// near the line of sight.
+#[derive(Debug, Clone)]
+pub struct Obstacles<T: GeoFloat> {
+    polygons: RTree<Polygon<T>>,
+    edges: RTree<Line<T>>,
+}
+
+impl<T: GeoFloat> Obstacles<T> {
+    /// Index the edges of `polygons`, including the edges of their holes.
+    pub fn new(polygons: impl IntoIterator<Item = Polygon<T>>) -> Self {
+        let polygons: Vec<Polygon<T>> = polygons.into_iter().collect();
+        let edges = polygons
+            .iter()
+            .flat_map(|polygon| {
+                std::iter::once(polygon.exterior())
+                    .chain(polygon.interiors())
+                    .flat_map(|ring| ring.lines())
+            })
+            .collect();
+        Obstacles {
+            polygons: RTree::bulk_load(polygons),
+            edges: RTree::bulk_load(edges),
+        }
+    }
+}
+
+/// Test whether the segment from `a` to `b` is clear of obstacles.
+///
+/// Returns `None` if the segment does not intersect any obstacle, or the point of the first
+/// obstacle met when travelling from `a` to `b`. Obstacles are closed, so a segment touching an
+/// obstacle's boundary is blocked. If `a` lies within an obstacle, `a` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_of_sight::{line_of_sight, Obstacles};
+/// use geo::{point, Rect};
+///
+/// let obstacles = Obstacles::new(vec![
+///     Rect::new((2., -1.), (3., 1.)).to_polygon(),
+///     Rect::new((5., -1.), (6., 1.)).to_polygon(),
+/// ]);
+///
+/// let a = point!(x: 0., y: 0.);
+/// assert_eq!(
+///     line_of_sight(a, point!(x: 10., y: 0.), &obstacles),
+///     Some(point!(x: 2., y: 0.))
+/// );
+/// assert_eq!(line_of_sight(a, point!(x: 10., y: 10.), &obstacles), None);
+/// ```
+pub fn line_of_sight<T: GeoFloat>(
+    a: Point<T>,
+    b: Point<T>,
+    obstacles: &Obstacles<T>,
+) -> Option<Point<T>> {
+    if obstacles
+        .polygons
+        .locate_in_envelope_intersecting(&AABB::from_point(a))
+        .any(|polygon| polygon.intersects(&a))
+    {
+        return Some(a);
+    }
+
+    let sight = Line::new(a, b);
+    let distance_2 = |c: Coord<T>| {
+        let delta = c - a.0;
+        delta.x * delta.x + delta.y * delta.y
+    };
+    let envelope = AABB::from_corners(a, b);
+    obstacles
+        .edges
+        .locate_in_envelope_intersecting(&envelope)
+        .filter_map(|edge| match line_intersection(sight, *edge)? {
+            LineIntersection::SinglePoint { intersection, .. } => Some(intersection),
+            LineIntersection::Collinear { intersection } => {
+                let (start, end) = intersection.points();
+                if distance_2(start.0) <= distance_2(end.0) {
+                    Some(start.0)
+                } else {
+                    Some(end.0)
+                }
+            }
+        })
+        .min_by(|p, q| distance_2(*p).partial_cmp(&distance_2(*q)).unwrap())
+        .map(Point::from)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Rect};
+
+    #[test]
+    fn start_inside_obstacle() {
+        let obstacles = Obstacles::new(vec![Rect::new((0., 0.), (2., 2.)).to_polygon()]);
+        let a = point!(x: 1., y: 1.);
+        assert_eq!(line_of_sight(a, point!(x: 5., y: 1.), &obstacles), Some(a));
+    }
+
+    #[test]
+    fn view_through_hole() {
+        let courtyard = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        let obstacles = Obstacles::new(vec![courtyard]);
+        // within the courtyard
+        assert_eq!(
+            line_of_sight(point!(x: 3., y: 3.), point!(x: 7., y: 7.), &obstacles),
+            None
+        );
+        // looking out of the courtyard, the inner wall blocks the view
+        assert_eq!(
+            line_of_sight(point!(x: 5., y: 5.), point!(x: 20., y: 5.), &obstacles),
+            Some(point!(x: 8., y: 5.))
+        );
+    }
+
+    #[test]
+    fn grazing_and_collinear() {
+        let obstacles = Obstacles::new(vec![Rect::new((2., 0.), (4., 2.)).to_polygon()]);
+        assert_eq!(
+            line_of_sight(point!(x: 0., y: 2.), point!(x: 4., y: 4.), &obstacles),
+            None
+        );
+        // touching a corner blocks the view
+        assert_eq!(
+            line_of_sight(point!(x: 0., y: 0.), point!(x: 4., y: 4.), &obstacles),
+            Some(point!(x: 2., y: 2.))
+        );
+        // running along an edge
+        assert_eq!(
+            line_of_sight(point!(x: 6., y: 2.), point!(x: 0., y: 2.), &obstacles),
+            Some(point!(x: 4., y: 2.))
+        );
+    }
+
+    #[test]
+    fn no_obstacles() {
+        let obstacles = Obstacles::<f64>::new(vec![]);
+        assert_eq!(
+            line_of_sight(point!(x: 0., y: 0.), point!(x: 1., y: 1.), &obstacles),
+            None
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{Coord, CoordFloat, EuclideanLength, LineString, Point};
+
+/// Calculate the direction of a [`LineString`] at a distance along it, e.g. to align a label
+/// with a road, offset it to one side, or cut a cross-section through a river.
+///
+/// Both methods return the point at `distance` along the line string, interpolated along its
+/// segments, with a unit vector: the tangent points in the direction of travel, and the normal
+/// to its left, the tangent turned a quarter counter-clockwise. Distances are euclidean, so the
+/// line string should be projected, and they're clamped to the length of the line string.
+///
+/// On a vertex, the direction is that of the segment starting there, except on the last
+/// vertex. Segments of zero length have no direction, and are skipped. Both methods return
+/// `None` if the line string has no segment of non-zero length.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, point, LineTangent};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+///
+/// assert_eq!(
+///     road.tangent_at_distance(5.),
+///     Some((point!(x: 4., y: 1.), coord! { x: 0., y: 1. }))
+/// );
+/// // the left of a road heading north is to the west
+/// assert_eq!(
+///     road.normal_at_distance(5.),
+///     Some((point!(x: 4., y: 1.), coord! { x: -1., y: 0. }))
+/// );
+/// ```
+pub trait LineTangent<T: CoordFloat> {
+    /// The point at `distance` along the line string, with the unit tangent vector there.
+    fn tangent_at_distance(&self, distance: T) -> Option<(Point<T>, Coord<T>)>;
+
+    /// The point at `distance` along the line string, with the unit normal vector there, to
+    /// the left.
+    fn normal_at_distance(&self, distance: T) -> Option<(Point<T>, Coord<T>)>;
+}
+
+impl<T: CoordFloat> LineTangent<T> for LineString<T> {
+    fn tangent_at_distance(&self, distance: T) -> Option<(Point<T>, Coord<T>)> {
+        let mut travelled = T::zero();
+        let mut last = None;
+        for line in self.lines() {
+            let length = line.euclidean_length();
+            if length > T::zero() {
+                if distance < travelled + length {
+                    let fraction = ((distance - travelled) / length).max(T::zero());
+                    let point = line.start + line.delta() * fraction;
+                    return Some((point.into(), line.delta() / length));
+                }
+                last = Some((line, length));
+            }
+            travelled = travelled + length;
+        }
+        let (line, length) = last?;
+        Some((line.end_point(), line.delta() / length))
+    }
+
+    fn normal_at_distance(&self, distance: T) -> Option<(Point<T>, Coord<T>)> {
+        let (point, tangent) = self.tangent_at_distance(distance)?;
+        Some((point, normal(tangent)))
+    }
+}
+
+/// The vector turned a quarter counter-clockwise.
+pub(crate) fn normal<T: CoordFloat>(vector: Coord<T>) -> Coord<T> {
+    Coord {
+        x: -vector.y,
+        y: vector.x,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point};
+
+    #[test]
+    fn along_segments() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 3., y: 4.),
+            // a repeated coordinate
+            (x: 3., y: 4.),
+            (x: 3., y: 0.),
+        ];
+        assert_eq!(
+            line_string.tangent_at_distance(2.5),
+            Some((point!(x: 1.5, y: 2.), coord! { x: 0.6, y: 0.8 }))
+        );
+        assert_eq!(
+            line_string.normal_at_distance(2.5),
+            Some((point!(x: 1.5, y: 2.), coord! { x: -0.8, y: 0.6 }))
+        );
+
+        // on the vertex, heading down the next segment
+        assert_eq!(
+            line_string.tangent_at_distance(5.),
+            Some((point!(x: 3., y: 4.), coord! { x: 0., y: -1. }))
+        );
+        // clamped to either end
+        assert_eq!(
+            line_string.tangent_at_distance(-1.),
+            Some((point!(x: 0., y: 0.), coord! { x: 0.6, y: 0.8 }))
+        );
+        assert_eq!(
+            line_string.normal_at_distance(100.),
+            Some((point!(x: 3., y: 0.), coord! { x: 1., y: 0. }))
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let point = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(point.tangent_at_distance(0.), None);
+        assert_eq!(LineString::<f64>::new(vec![]).normal_at_distance(0.), None);
+    }
+}
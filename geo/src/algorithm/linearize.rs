@@ -0,0 +1,321 @@
+use crate::{
+    CircularString, CompoundCurve, Coord, CoordFloat, Curve, CurvePolygon, LineString, Polygon,
+};
+use alloc::vec::Vec;
+
+/// Approximate curved geometries with straight segments.
+///
+/// Each circular arc is replaced by segments of equal angular length, chosen so that no
+/// point of the arc is further than `max_deviation` from the segment approximating it. The
+/// endpoints of the arcs are kept exactly, but the middle coordinates which define them are
+/// not necessarily vertices of the output. Arcs whose three coordinates are collinear are
+/// replaced by the two segments joining them, and coordinates of a `CircularString` which
+/// don't complete an arc are joined by a straight segment.
+///
+/// # Panics
+///
+/// If `max_deviation` isn't strictly positive.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{coord, CircularString, EuclideanDistance, Linearize, Point};
+///
+/// // a half circle of radius 1, centred on (1, 0)
+/// let arc = CircularString::new(vec![
+///     coord! { x: 0., y: 0. },
+///     coord! { x: 1., y: 1. },
+///     coord! { x: 2., y: 0. },
+/// ]);
+/// let line_string = arc.linearize(0.01);
+/// assert_eq!(line_string.0.first(), Some(&coord! { x: 0., y: 0. }));
+/// assert_eq!(line_string.0.last(), Some(&coord! { x: 2., y: 0. }));
+/// for coord in line_string.coords() {
+///     assert_relative_eq!(Point::from(*coord).euclidean_distance(&Point::new(1., 0.)), 1.);
+/// }
+/// ```
+pub trait Linearize<T: CoordFloat> {
+    type Output;
+
+    fn linearize(&self, max_deviation: T) -> Self::Output;
+}
+
+impl<T: CoordFloat> Linearize<T> for CircularString<T> {
+    type Output = LineString<T>;
+
+    fn linearize(&self, max_deviation: T) -> Self::Output {
+        assert!(
+            max_deviation > T::zero(),
+            "max_deviation must be strictly positive"
+        );
+        let mut coords = Vec::new();
+        push_circular_string(&mut coords, self, max_deviation);
+        LineString::new(coords)
+    }
+}
+
+impl<T: CoordFloat> Linearize<T> for CompoundCurve<T> {
+    type Output = LineString<T>;
+
+    fn linearize(&self, max_deviation: T) -> Self::Output {
+        assert!(
+            max_deviation > T::zero(),
+            "max_deviation must be strictly positive"
+        );
+        let mut coords = Vec::new();
+        push_compound_curve(&mut coords, self, max_deviation);
+        LineString::new(coords)
+    }
+}
+
+impl<T: CoordFloat> Linearize<T> for Curve<T> {
+    type Output = LineString<T>;
+
+    fn linearize(&self, max_deviation: T) -> Self::Output {
+        match self {
+            Curve::LineString(line_string) => line_string.clone(),
+            Curve::CircularString(circular_string) => circular_string.linearize(max_deviation),
+            Curve::CompoundCurve(compound_curve) => compound_curve.linearize(max_deviation),
+        }
+    }
+}
+
+impl<T: CoordFloat> Linearize<T> for CurvePolygon<T> {
+    type Output = Polygon<T>;
+
+    fn linearize(&self, max_deviation: T) -> Self::Output {
+        Polygon::new(
+            self.exterior().linearize(max_deviation),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.linearize(max_deviation))
+                .collect(),
+        )
+    }
+}
+
+// Append `coords`, skipping the first one if it's where `output` already ends, as consecutive
+// components of a compound curve share their endpoints.
+fn push_continuing<T: CoordFloat>(output: &mut Vec<Coord<T>>, coords: &[Coord<T>]) {
+    match (output.last(), coords.first()) {
+        (Some(last), Some(first)) if last == first => output.extend_from_slice(&coords[1..]),
+        _ => output.extend_from_slice(coords),
+    }
+}
+
+fn push_compound_curve<T: CoordFloat>(
+    output: &mut Vec<Coord<T>>,
+    compound_curve: &CompoundCurve<T>,
+    max_deviation: T,
+) {
+    for component in &compound_curve.0 {
+        match component {
+            Curve::LineString(line_string) => push_continuing(output, &line_string.0),
+            Curve::CircularString(circular_string) => {
+                push_circular_string(output, circular_string, max_deviation)
+            }
+            Curve::CompoundCurve(compound_curve) => {
+                push_compound_curve(output, compound_curve, max_deviation)
+            }
+        }
+    }
+}
+
+fn push_circular_string<T: CoordFloat>(
+    output: &mut Vec<Coord<T>>,
+    circular_string: &CircularString<T>,
+    max_deviation: T,
+) {
+    let coords = &circular_string.0;
+    let first = match coords.first() {
+        Some(first) => *first,
+        None => return,
+    };
+    push_continuing(output, &[first]);
+    for [start, middle, end] in circular_string.arcs() {
+        push_arc(output, start, middle, end, max_deviation);
+    }
+    // a trailing coordinate which doesn't complete an arc
+    if coords.len() % 2 == 0 {
+        output.push(coords[coords.len() - 1]);
+    }
+}
+
+// Append the vertices approximating the arc from `start` to `end` through `middle`, except
+// `start`.
+fn push_arc<T: CoordFloat>(
+    output: &mut Vec<Coord<T>>,
+    start: Coord<T>,
+    middle: Coord<T>,
+    end: Coord<T>,
+    max_deviation: T,
+) {
+    let two = T::one() + T::one();
+    let tau = T::from(core::f64::consts::TAU).unwrap();
+
+    let (center, sweep) = if start == end {
+        // a full circle, of which `middle` is the opposite point
+        ((start + middle) / two, tau)
+    } else {
+        // twice the cross product of `middle - start` and `end - start`: positive if the arc
+        // turns counter-clockwise, zero if it's degenerate
+        let (b, c) = (middle - start, end - start);
+        let d = two * (b.x * c.y - b.y * c.x);
+        if d == T::zero() {
+            output.push(middle);
+            output.push(end);
+            return;
+        }
+        let (b_sq, c_sq) = (b.x * b.x + b.y * b.y, c.x * c.x + c.y * c.y);
+        let center = start
+            + Coord {
+                x: (c.y * b_sq - b.y * c_sq) / d,
+                y: (b.x * c_sq - c.x * b_sq) / d,
+            };
+        let sweep = angle(end - center) - angle(start - center);
+        let sweep = if d > T::zero() && sweep <= T::zero() {
+            sweep + tau
+        } else if d < T::zero() && sweep >= T::zero() {
+            sweep - tau
+        } else {
+            sweep
+        };
+        (center, sweep)
+    };
+
+    let radius = {
+        let r = start - center;
+        r.x.hypot(r.y)
+    };
+    if radius == T::zero() {
+        output.push(end);
+        return;
+    }
+    // a chord spanning an angle θ deviates from the arc by r (1 - cos(θ / 2))
+    let max_angle = two * (T::one() - max_deviation / radius).max(-T::one()).acos();
+    let segments = (sweep.abs() / max_angle).ceil().max(T::one());
+    let start_angle = angle(start - center);
+    let count = segments.to_usize().unwrap_or(usize::MAX);
+    for i in 1..count {
+        let theta = start_angle + sweep * T::from(i).unwrap() / segments;
+        output.push(Coord {
+            x: center.x + radius * theta.cos(),
+            y: center.y + radius * theta.sin(),
+        });
+    }
+    output.push(end);
+}
+
+fn angle<T: CoordFloat>(vector: Coord<T>) -> T {
+    vector.y.atan2(vector.x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, Area, EuclideanDistance, Point};
+
+    fn half_circle() -> CircularString<f64> {
+        CircularString::new(vec![
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 0. },
+        ])
+    }
+
+    #[test]
+    fn deviation_is_bounded() {
+        let center = Point::new(1., 0.);
+        for max_deviation in [0.1, 0.01, 0.001] {
+            let line_string = half_circle().linearize(max_deviation);
+            for line in line_string.lines() {
+                let midpoint = Point::from((line.start + line.end) / 2.);
+                let deviation = 1. - midpoint.euclidean_distance(&center);
+                assert!(deviation <= max_deviation + 1e-12);
+            }
+        }
+        // half a turn, with a deviation of r (1 - cos(π / 8)) for 4 segments
+        let deviation = 1. - (core::f64::consts::PI / 8.).cos();
+        assert_eq!(half_circle().linearize(deviation + 1e-9).0.len(), 5);
+    }
+
+    #[test]
+    fn reversed_arcs_and_full_circles() {
+        let counter_clockwise = CircularString::new(vec![
+            coord! { x: 2., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 0., y: 0. },
+        ]);
+        // the same vertices, in reverse
+        let mut reversed = half_circle().linearize(0.01).0;
+        reversed.reverse();
+        let line_string = counter_clockwise.linearize(0.01);
+        assert_eq!(line_string.0.len(), reversed.len());
+        for (a, b) in line_string.coords().zip(&reversed) {
+            assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+
+        let circle = CircularString::new(vec![
+            coord! { x: 1., y: 0. },
+            coord! { x: -1., y: 0. },
+            coord! { x: 1., y: 0. },
+        ]);
+        let ring = circle.linearize(0.001);
+        assert!(ring.is_closed());
+        assert_relative_eq!(
+            Polygon::new(ring, vec![]).signed_area(),
+            core::f64::consts::PI,
+            max_relative = 0.01
+        );
+    }
+
+    #[test]
+    fn degenerate_arcs() {
+        let collinear = CircularString::new(vec![
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 3., y: 3. },
+            coord! { x: 4., y: 3. },
+        ]);
+        assert_eq!(
+            collinear.linearize(0.1),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.), (x: 4., y: 3.)]
+        );
+        assert_eq!(
+            CircularString::<f64>::new(vec![]).linearize(0.1),
+            line_string![]
+        );
+    }
+
+    #[test]
+    fn compound_curves_and_polygons() {
+        // a stadium: two half circles joined by straight segments
+        let stadium = CompoundCurve::new(vec![
+            Curve::LineString(line_string![(x: 0., y: -1.), (x: 2., y: -1.)]),
+            Curve::CircularString(CircularString::from(vec![(2., -1.), (3., 0.), (2., 1.)])),
+            Curve::LineString(line_string![(x: 2., y: 1.), (x: 0., y: 1.)]),
+            Curve::CircularString(CircularString::from(vec![(0., 1.), (-1., 0.), (0., -1.)])),
+        ]);
+        let ring = stadium.linearize(0.001);
+        assert!(ring.is_closed());
+        // shared endpoints aren't repeated
+        assert!(ring.lines().all(|line| line.start != line.end));
+
+        let hole = Curve::LineString(line_string![
+            (x: 0., y: -0.5),
+            (x: 1., y: -0.5),
+            (x: 1., y: 0.5),
+            (x: 0., y: -0.5),
+        ]);
+        let polygon = CurvePolygon::new(Curve::CompoundCurve(stadium), vec![hole]).linearize(0.001);
+        let expected = 4. + core::f64::consts::PI - 0.5;
+        assert_relative_eq!(polygon.unsigned_area(), expected, max_relative = 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_deviation must be strictly positive")]
+    fn non_positive_deviation() {
+        half_circle().linearize(0.);
+    }
+}
@@ -28,6 +28,8 @@ pub use modern::*;
 mod modern {
     pub(crate) use crate::geometry::*;
     pub(crate) use crate::CoordNum;
+    use alloc::vec::Vec;
+    use core::cell::{Cell, RefCell};
 
     /// Map a function over all the coordinates in an object, returning a new one
     pub trait MapCoords<T, NT> {
@@ -125,6 +127,65 @@ mod modern {
         where
             T: CoordNum,
             NT: CoordNum;
+
+        /// Map a fallible function over all the coordinates in a geometry, returning the new
+        /// geometry, or every error along with the index of the coordinate that caused it.
+        ///
+        /// Unlike [`try_map_coords`](Self::try_map_coords), which stops at the first error, all
+        /// the coordinates are mapped, e.g. to report every vertex that can't be reprojected at
+        /// once. Coordinates are indexed in the order of
+        /// [`CoordsIter::coord_at`](crate::CoordsIter::coord_at), except for a `Rect`, whose
+        /// coordinates are its minimum and maximum corners.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use geo::{line_string, Coord, MapCoords};
+        ///
+        /// let line_string = line_string![(x: 1., y: 1.), (x: -2., y: 4.), (x: 9., y: -9.)];
+        /// let sqrt = |Coord { x, y }: Coord| -> Result<Coord, String> {
+        ///     if x < 0. || y < 0. {
+        ///         return Err(format!("can't take the square root of ({}, {})", x, y));
+        ///     }
+        ///     Ok(Coord { x: x.sqrt(), y: y.sqrt() })
+        /// };
+        ///
+        /// assert_eq!(
+        ///     line_string.try_map_coords_all(sqrt),
+        ///     Err(vec![
+        ///         (1, "can't take the square root of (-2, 4)".to_string()),
+        ///         (2, "can't take the square root of (9, -9)".to_string()),
+        ///     ])
+        /// );
+        /// ```
+        fn try_map_coords_all<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E>,
+        ) -> Result<Self::Output, Vec<(usize, E)>>
+        where
+            T: CoordNum,
+            NT: CoordNum,
+        {
+            let index = Cell::new(0);
+            let errors = RefCell::new(Vec::new());
+            let output = self.map_coords(|coord| {
+                index.set(index.get() + 1);
+                func(coord).unwrap_or_else(|error| {
+                    errors.borrow_mut().push((index.get() - 1, error));
+                    // the output is dropped anyway
+                    Coord {
+                        x: NT::zero(),
+                        y: NT::zero(),
+                    }
+                })
+            });
+            let errors = errors.into_inner();
+            if errors.is_empty() {
+                Ok(output)
+            } else {
+                Err(errors)
+            }
+        }
     }
 
     pub trait MapCoordsInPlace<T> {
@@ -178,6 +239,53 @@ mod modern {
         ) -> Result<(), E>
         where
             T: CoordNum;
+
+        /// Map a fallible function over all the coordinates in a geometry, in place, returning
+        /// every error along with the index of the coordinate that caused it, as
+        /// [`MapCoords::try_map_coords_all`] does.
+        ///
+        /// The coordinates that caused an error are left as they are.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use geo::{line_string, Coord, MapCoordsInPlace};
+        ///
+        /// let mut line_string = line_string![(x: 1u32, y: 1u32), (x: 2, y: 4), (x: 4, y: 0)];
+        /// let divide = |Coord { x, y }: Coord<u32>| -> Result<_, &str> {
+        ///     Ok(Coord { x: x.checked_div(y).ok_or("Division by zero")?, y })
+        /// };
+        /// let result = line_string.try_map_coords_in_place_all(divide);
+        ///
+        /// assert_eq!(result, Err(vec![(2, "Division by zero")]));
+        /// assert_eq!(
+        ///     line_string,
+        ///     line_string![(x: 1u32, y: 1u32), (x: 0, y: 4), (x: 4, y: 0)]
+        /// );
+        /// ```
+        fn try_map_coords_in_place_all<E>(
+            &mut self,
+            func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+        ) -> Result<(), Vec<(usize, E)>>
+        where
+            T: CoordNum,
+        {
+            let index = Cell::new(0);
+            let errors = RefCell::new(Vec::new());
+            self.map_coords_in_place(|coord| {
+                index.set(index.get() + 1);
+                func(coord).unwrap_or_else(|error| {
+                    errors.borrow_mut().push((index.get() - 1, error));
+                    coord
+                })
+            });
+            let errors = errors.into_inner();
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
     }
 
     //-----------------------//
@@ -645,7 +753,7 @@ mod modern {
     impl<T: CoordNum> MapCoordsInPlace<T> for Rect<T> {
         fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T>) {
             let mut new_rect = Rect::new(func(self.min()), func(self.max()));
-            ::std::mem::swap(self, &mut new_rect);
+            ::core::mem::swap(self, &mut new_rect);
         }
 
         fn try_map_coords_in_place<E>(
@@ -653,7 +761,7 @@ mod modern {
             func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
         ) -> Result<(), E> {
             let mut new_rect = Rect::new(func(self.min())?, func(self.max())?);
-            ::std::mem::swap(self, &mut new_rect);
+            ::core::mem::swap(self, &mut new_rect);
             Ok(())
         }
     }
@@ -681,7 +789,7 @@ mod modern {
         fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T>) {
             let mut new_triangle = Triangle::new(func(self.0), func(self.1), func(self.2));
 
-            ::std::mem::swap(self, &mut new_triangle);
+            ::core::mem::swap(self, &mut new_triangle);
         }
 
         fn try_map_coords_in_place<E>(
@@ -690,11 +798,83 @@ mod modern {
         ) -> Result<(), E> {
             let mut new_triangle = Triangle::new(func(self.0)?, func(self.1)?, func(self.2)?);
 
-            ::std::mem::swap(self, &mut new_triangle);
+            ::core::mem::swap(self, &mut new_triangle);
 
             Ok(())
         }
     }
+
+    //------------------------------------------//
+    // PointZM and LineStringZM implementations //
+    //------------------------------------------//
+
+    // The Z and M values are kept as they are, so they can't change type.
+
+    impl<T: CoordNum> MapCoords<T, T> for PointZM<T> {
+        type Output = PointZM<T>;
+
+        fn map_coords(&self, func: impl Fn(Coord<T>) -> Coord<T> + Copy) -> Self::Output {
+            PointZM::new(self.xy.map_coords(func), self.z, self.m)
+        }
+
+        fn try_map_coords<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        ) -> Result<Self::Output, E> {
+            Ok(PointZM::new(self.xy.try_map_coords(func)?, self.z, self.m))
+        }
+    }
+
+    impl<T: CoordNum> MapCoordsInPlace<T> for PointZM<T> {
+        fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T> + Copy) {
+            self.xy.map_coords_in_place(func);
+        }
+
+        fn try_map_coords_in_place<E>(
+            &mut self,
+            func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+        ) -> Result<(), E> {
+            self.xy.try_map_coords_in_place(func)
+        }
+    }
+
+    impl<T: CoordNum> MapCoords<T, T> for LineStringZM<T> {
+        type Output = LineStringZM<T>;
+
+        fn map_coords(&self, func: impl Fn(Coord<T>) -> Coord<T> + Copy) -> Self::Output {
+            LineStringZM::new(
+                self.xy().map_coords(func),
+                self.z().map(|z| z.to_vec()),
+                self.m().map(|m| m.to_vec()),
+            )
+        }
+
+        fn try_map_coords<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<T>, E> + Copy,
+        ) -> Result<Self::Output, E> {
+            Ok(LineStringZM::new(
+                self.xy().try_map_coords(func)?,
+                self.z().map(|z| z.to_vec()),
+                self.m().map(|m| m.to_vec()),
+            ))
+        }
+    }
+
+    impl<T: CoordNum> MapCoordsInPlace<T> for LineStringZM<T> {
+        fn map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T> + Copy) {
+            self.xy_mut(|xy| xy.map_coords_in_place(func));
+        }
+
+        fn try_map_coords_in_place<E>(
+            &mut self,
+            func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+        ) -> Result<(), E> {
+            let mut result = Ok(());
+            self.xy_mut(|xy| result = xy.try_map_coords_in_place(func));
+            result
+        }
+    }
 }
 pub use deprecated::*;
 pub(crate) mod deprecated {
@@ -1205,6 +1385,49 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fallible_all() {
+        let f = |Coord { x, y }: Coord| -> Result<Coord, f64> {
+            if x < 0. {
+                Err(x)
+            } else {
+                Ok(coord! { x: x * 2., y: y })
+            }
+        };
+        let mut polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: -1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: -2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+        ];
+        assert_eq!(polygon.try_map_coords_all(f), Err(vec![(1, -1.), (5, -2.)]));
+
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Rect(Rect::new((-3., 0.), (1., 1.))),
+            Geometry::Polygon(polygon.clone()),
+        ]);
+        assert_eq!(
+            collection.try_map_coords_all(f),
+            Err(vec![(0, -3.), (3, -1.), (7, -2.)])
+        );
+
+        assert_eq!(
+            polygon.try_map_coords_in_place_all(f),
+            Err(vec![(1, -1.), (5, -2.)])
+        );
+        assert_eq!(
+            polygon,
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: -1., y: 0.), (x: 2., y: 1.), (x: 0., y: 0.)],
+                interiors: [[(x: 2., y: 1.), (x: -2., y: 1.), (x: 4., y: 2.), (x: 2., y: 1.)]],
+            ]
+        );
+
+        let line = Line::new((1., 1.), (2., 2.));
+        assert_eq!(
+            line.try_map_coords_all(f),
+            Ok(Line::new((2., 1.), (4., 2.)))
+        );
+    }
+
     #[test]
     fn rect_map_invert_coords() {
         let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
@@ -1213,4 +1436,26 @@ mod test {
         // constructor panics if min coords > max coords
         rect.map_coords(|Coord { x, y }| (-x, -y).into());
     }
+
+    #[test]
+    fn zm_values_survive_transforms() {
+        use crate::{line_string, LineStringZM, PointZM, Rotate, Scale, Translate};
+
+        let track = LineStringZM::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            Some(vec![100., 200.]),
+            Some(vec![0., 1.]),
+        );
+        let moved = track
+            .translate(10., 10.)
+            .rotate_around_point(90., Point::new(10., 10.))
+            .scale(2.);
+        assert_eq!(moved.z(), track.z());
+        assert_eq!(moved.m(), track.m());
+        assert_relative_eq!(moved.xy().0[1], coord! { x: 10., y: 11.5 }, epsilon = 1e-9);
+
+        let mut fix = PointZM::new(Point::new(1., 2.), Some(3.), None);
+        fix.map_coords_in_place(|Coord { x, y }| Coord { x: y, y: x });
+        assert_eq!(fix, PointZM::new(Point::new(2., 1.), Some(3.), None));
+    }
 }
@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+
+use crate::line_graph::{euclidean_distance, EdgePosition, LineGraph};
+use crate::{GeoFloat, LineString};
+
+/// A point of a trace, matched onto the edges of a [`LineGraph`] by
+/// [`LineGraph::match_trace`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchedPoint<T: GeoFloat> {
+    /// The position on the edges the point is matched to.
+    pub position: EdgePosition<T>,
+    /// The distance from the observed point to its position.
+    pub offset: T,
+}
+
+// The candidates of a point: where it may have been, with the cost of the likeliest sequence of
+// candidates ending there, and the index of the candidate of the previous point in it.
+type Candidates<T> = Vec<(MatchedPoint<T>, T, usize)>;
+
+impl<T: GeoFloat> LineGraph<T> {
+    /// Match the points of a trace, such as a GPS track, onto the edges of the graph, such as
+    /// the roads travelled, e.g. to clean the trace, or to find which roads were taken.
+    ///
+    /// Snapping every point onto the nearest edge goes astray where edges are close to one
+    /// another, so this follows [Newson and Krumm (2009)], with a hidden Markov model: each
+    /// point may have been on any edge within three times the `accuracy` of the observations,
+    /// i.e. the standard deviation of their errors, and the likeliest sequence of positions is
+    /// the one balancing the offsets of the points from their positions with the differences
+    /// between the routes along the edges from one position to the next and the straight line
+    /// distances between the points.
+    ///
+    /// There's a match for every point, or `None` for the points with no edge within reach.
+    /// Where a point can't be reached along the edges from the candidates of the previous
+    /// point, the matching starts afresh from it.
+    ///
+    /// Distances are euclidean, so the trace and the graph should be projected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, line_string, LineGraph, MultiLineString};
+    ///
+    /// // a road along the x axis, and a parallel service road joining it at its end
+    /// let roads = MultiLineString::new(vec![
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+    ///     line_string![(x: 0., y: 2.), (x: 10., y: 2.), (x: 10., y: 0.)],
+    /// ]);
+    /// let graph = LineGraph::new(&roads);
+    ///
+    /// let trace = line_string![(x: 1., y: 0.5), (x: 3., y: 1.2), (x: 5., y: 0.3)];
+    /// let matched = graph.match_trace(&trace, 1.);
+    ///
+    /// // the second point is nearer the service road, but went along the road
+    /// let second = matched[1].unwrap();
+    /// assert_eq!(second.position.coord, coord! { x: 3., y: 0. });
+    /// assert_eq!(second.offset, 1.2);
+    /// ```
+    ///
+    /// [Newson and Krumm (2009)]: https://www.microsoft.com/en-us/research/publication/hidden-markov-map-matching-noise-sparseness/
+    pub fn match_trace(&self, trace: &LineString<T>, accuracy: T) -> Vec<Option<MatchedPoint<T>>> {
+        let two = T::one() + T::one();
+        let radius = accuracy * (two + T::one());
+        // the negative logarithms of the probabilities, less constants
+        let emission = |offset: T| (offset / accuracy).powi(2) / two;
+        let transition = |route: T, straight: T| (route - straight).abs() / accuracy;
+
+        let mut matched = vec![None; trace.0.len()];
+        // the candidates of the points of the current run, from `run_start`, along which each
+        // point can be reached from the one before
+        let mut run: Vec<Candidates<T>> = vec![];
+        let mut run_start = 0;
+        for (i, &coord) in trace.0.iter().enumerate() {
+            let points: Vec<MatchedPoint<T>> = self
+                .positions_within(coord.into(), radius)
+                .into_iter()
+                .map(|position| MatchedPoint {
+                    position,
+                    offset: euclidean_distance(position.coord, coord),
+                })
+                .collect();
+
+            let mut candidates: Candidates<T> = vec![];
+            if let Some(previous) = run.last() {
+                let straight = euclidean_distance(trace.0[i - 1], coord);
+                for point in &points {
+                    let best = previous
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(j, (from, cost, _))| {
+                            let route = self
+                                .path_between(from.position, point.position, euclidean_distance)?
+                                .length;
+                            Some((*cost + transition(route, straight), j))
+                        })
+                        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+                    if let Some((cost, j)) = best {
+                        candidates.push((*point, cost + emission(point.offset), j));
+                    }
+                }
+            }
+            if candidates.is_empty() {
+                backtrack(&run, run_start, &mut matched);
+                run.clear();
+                run_start = i;
+                candidates = points
+                    .into_iter()
+                    .map(|point| (point, emission(point.offset), 0))
+                    .collect();
+            }
+            if candidates.is_empty() {
+                // out of reach: the next run starts after it
+                run_start = i + 1;
+            } else {
+                run.push(candidates);
+            }
+        }
+        backtrack(&run, run_start, &mut matched);
+        matched
+    }
+}
+
+// Follow the likeliest sequence of candidates back from the last point of the run.
+fn backtrack<T: GeoFloat>(
+    run: &[Candidates<T>],
+    run_start: usize,
+    matched: &mut [Option<MatchedPoint<T>>],
+) {
+    let last = match run.last() {
+        Some(last) => last,
+        None => return,
+    };
+    let mut j = (0..last.len())
+        .min_by(|&a, &b| last[a].1.partial_cmp(&last[b].1).unwrap_or(Ordering::Equal))
+        .expect("runs only hold points with candidates");
+    for (k, candidates) in run.iter().enumerate().rev() {
+        let (point, _, previous) = candidates[j];
+        matched[run_start + k] = Some(point);
+        j = previous;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, MultiLineString};
+    use approx::assert_relative_eq;
+
+    fn roads() -> LineGraph<f64> {
+        LineGraph::new(&MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 2.), (x: 10., y: 2.)],
+            line_string![(x: 10., y: 0.), (x: 10., y: 2.)],
+        ]))
+    }
+
+    #[test]
+    fn parallel_roads() {
+        let graph = roads();
+        let trace = line_string![
+            (x: 1., y: 0.9),
+            (x: 3., y: 0.4),
+            // nearer the other road
+            (x: 5., y: 1.1),
+            (x: 7., y: 0.6),
+            (x: 9., y: 0.5),
+        ];
+        let matched = graph.match_trace(&trace, 1.);
+        for (point, observed) in matched.iter().zip(trace.coords()) {
+            let point = point.unwrap();
+            assert_eq!(point.position.edge, 0);
+            assert_relative_eq!(point.position.coord, coord! { x: observed.x, y: 0. });
+            assert_relative_eq!(point.offset, observed.y);
+        }
+
+        // nearest edge matching
+        assert_eq!(graph.snap(point!(x: 5., y: 1.1)).unwrap().edge, 1);
+    }
+
+    #[test]
+    fn gaps() {
+        let graph = roads();
+        let trace = line_string![
+            (x: 1., y: 0.5),
+            // out of reach
+            (x: 50., y: 50.),
+            (x: 3., y: 1.5),
+            (x: 5., y: 1.6),
+        ];
+        let matched = graph.match_trace(&trace, 1.);
+        assert_eq!(matched[0].unwrap().position.edge, 0);
+        assert_eq!(matched[1], None);
+        // starting afresh, on the road nearer the points
+        assert_eq!(matched[2].unwrap().position.edge, 1);
+        assert_eq!(matched[3].unwrap().position.edge, 1);
+
+        assert!(graph.match_trace(&line_string![], 1.).is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{ConvexHull, Coord, CoordNum, CoordsIter, GeoFloat, Point, Polygon, RotatingCalipers};
+
+/// Calculate the maximum euclidean distance between two geometries: the distance between
+/// their farthest points, complementing the minimum distance of
+/// [`EuclideanDistance`](crate::EuclideanDistance).
+///
+/// Every point of `rhs` is within this distance of every point of `self`, which makes it e.g.
+/// the radius to search around any point of `self` for all of `rhs`.
+///
+/// The farthest points of two geometries are vertices of their convex hulls, and they're
+/// found by [rotating calipers](RotatingCalipers) around both hulls at once, in linear time in
+/// the number of vertices of the hulls, after computing the hulls in `O(n log n)`.
+///
+/// Both methods return `None` if either geometry is empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, MaxDistance};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let other = polygon![(x: 3., y: 3.), (x: 4., y: 3.), (x: 4., y: 4.), (x: 3., y: 4.)];
+///
+/// assert_eq!(square.max_distance(&other), Some(32f64.sqrt()));
+/// assert_eq!(
+///     square.farthest_points(&other),
+///     Some((point!(x: 0., y: 0.), point!(x: 4., y: 4.)))
+/// );
+/// ```
+pub trait MaxDistance<T: CoordNum, Rhs = Self> {
+    /// The distance between the farthest points of `self` and `rhs`.
+    fn max_distance(&self, rhs: &Rhs) -> Option<T>;
+
+    /// The farthest points of `self` and `rhs`, in that order.
+    fn farthest_points(&self, rhs: &Rhs) -> Option<(Point<T>, Point<T>)>;
+}
+
+impl<T, G, Rhs> MaxDistance<T, Rhs> for G
+where
+    T: GeoFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T>,
+    Rhs: for<'a> CoordsIter<'a, Scalar = T>,
+{
+    fn max_distance(&self, rhs: &Rhs) -> Option<T> {
+        let (a, b) = self.farthest_points(rhs)?;
+        Some((a.0 - b.0).x.hypot((a.0 - b.0).y))
+    }
+
+    fn farthest_points(&self, rhs: &Rhs) -> Option<(Point<T>, Point<T>)> {
+        let (a, b) = farthest_pair(&self.convex_hull(), &rhs.convex_hull())?;
+        Some((a.into(), b.into()))
+    }
+}
+
+/// The farthest pair of a vertex of `a` and one of `b`, convex polygons as required by
+/// [`RotatingCalipers`], or `None` if either is empty.
+pub(crate) fn farthest_pair<T: GeoFloat>(
+    a: &Polygon<T>,
+    b: &Polygon<T>,
+) -> Option<(Coord<T>, Coord<T>)> {
+    let squared = |(a, b): (Coord<T>, Coord<T>)| {
+        let delta = a - b;
+        delta.x * delta.x + delta.y * delta.y
+    };
+    RotatingCalipers::new(a, b)
+        .map(|(i, j)| (a.exterior().0[i], b.exterior().0[j]))
+        .reduce(|best, pair| {
+            if squared(pair) > squared(best) {
+                pair
+            } else {
+                best
+            }
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, LineString, MultiPoint};
+
+    fn brute_force(a: &[Coord<f64>], b: &[Coord<f64>]) -> f64 {
+        a.iter()
+            .flat_map(|a| b.iter().map(move |b| (*a - *b).x.hypot((*a - *b).y)))
+            .fold(0., f64::max)
+    }
+
+    #[test]
+    fn against_brute_force() {
+        // scattered, deterministically
+        let scatter = |n: usize, seed: f64, offset: f64| -> MultiPoint<f64> {
+            (0..n)
+                .map(|i| {
+                    let t = i as f64 + seed;
+                    point!(x: (t * 12.9898).sin() * 10. + offset, y: (t * 78.233).sin() * 7.)
+                })
+                .collect()
+        };
+        for (n, m, offset) in [(5, 7, 0.), (20, 30, 5.), (50, 3, 30.), (100, 100, -12.)] {
+            let a = scatter(n, 0.5, 0.);
+            let b = scatter(m, 100.5, offset);
+            let coords =
+                |mp: &MultiPoint<f64>| -> Vec<Coord<f64>> { mp.iter().map(|p| p.0).collect() };
+            let expected = brute_force(&coords(&a), &coords(&b));
+            assert_eq!(a.max_distance(&b), Some(expected));
+            assert_eq!(b.max_distance(&a), Some(expected));
+        }
+    }
+
+    #[test]
+    fn overlapping_and_degenerate() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        assert_eq!(square.max_distance(&square), Some(8f64.sqrt()));
+
+        // a segment, and a point
+        let segment = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 3., y: 0.)];
+        let point = point!(x: 0., y: 4.);
+        assert_eq!(segment.max_distance(&point), Some(5.));
+        assert_eq!(
+            point.farthest_points(&segment),
+            Some((point, point!(x: 3., y: 0.)))
+        );
+        assert_eq!(segment.max_distance(&square), Some(13f64.sqrt()));
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.max_distance(&square), None);
+        assert_eq!(square.farthest_points(&empty), None);
+    }
+}
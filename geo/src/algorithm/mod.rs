@@ -2,72 +2,174 @@
 pub mod kernels;
 pub use kernels::{HasKernel, Kernel, Orientation};
 
+/// Split geometries crossing the antimeridian, and wrap longitudes.
+#[cfg(feature = "std")]
+pub mod antimeridian;
+#[cfg(feature = "std")]
+pub use antimeridian::{SplitAntimeridian, WrapLongitude};
+
 /// Calculate the area of the surface of a `Geometry`.
 pub mod area;
 pub use area::Area;
 
+/// Evaluate predicates and distances over slices of coordinates, vectorized with the `use-simd` feature.
+#[cfg(feature = "std")]
+pub mod batch;
+
 /// Calculate the bearing to another `Point`, in degrees.
+#[cfg(feature = "std")]
 pub mod bearing;
+#[cfg(feature = "std")]
 pub use bearing::Bearing;
 
 /// Boolean Ops such as union, xor, difference;
+#[cfg(feature = "std")]
 pub mod bool_ops;
-pub use bool_ops::{BooleanOps, OpType};
+#[cfg(feature = "std")]
+pub use bool_ops::{BooleanOps, OpType, OverlayArena};
 
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
 pub use bounding_rect::BoundingRect;
 
+/// Cache the bounding rectangle of a geometry to speed up repeated predicates.
+#[cfg(feature = "std")]
+pub mod bounded;
+#[cfg(feature = "std")]
+pub use bounded::Bounded;
+
+/// Extract the centerline of an elongated `Polygon`.
+#[cfg(feature = "std")]
+pub mod centerline;
+#[cfg(feature = "std")]
+pub use centerline::Centerline;
+
+/// Calculate the mean center, median center and dispersion of a set of points.
+#[cfg(feature = "std")]
+pub mod centrography;
+#[cfg(feature = "std")]
+pub use centrography::{Centrography, StandardDeviationalEllipse};
+
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
 pub use centroid::Centroid;
 
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
+#[cfg(feature = "std")]
 pub mod chaikin_smoothing;
-pub use chaikin_smoothing::ChaikinSmoothing;
+#[cfg(feature = "std")]
+pub use chaikin_smoothing::{AreaPreservingChaikinSmoothing, ChaikinSmoothing};
 
 /// Calculate the signed approximate geodesic area of a `Geometry`.
+#[cfg(feature = "std")]
 pub mod chamberlain_duquette_area;
+#[cfg(feature = "std")]
 pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 
+/// Clip a geometry to a `Rect`, much faster than a general intersection.
+#[cfg(feature = "std")]
+pub mod clip_to_rect;
+#[cfg(feature = "std")]
+pub use clip_to_rect::ClipToRect;
+
+/// Clip a geometry to a convex polygon or to half-planes.
+#[cfg(feature = "std")]
+pub mod clip_to_window;
+#[cfg(feature = "std")]
+pub use clip_to_window::{ClipToWindow, ClipWindow, HalfPlane};
+
+/// Find the closest pair of points of a `MultiPoint`.
+#[cfg(feature = "std")]
+pub mod closest_pair;
+#[cfg(feature = "std")]
+pub use closest_pair::ClosestPair;
+
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
+#[cfg(feature = "std")]
 pub mod closest_point;
+#[cfg(feature = "std")]
 pub use closest_point::ClosestPoint;
 
 /// Calculate the concave hull of a `Geometry`.
+#[cfg(feature = "std")]
 pub mod concave_hull;
+#[cfg(feature = "std")]
 pub use concave_hull::ConcaveHull;
 
 /// Determine whether `Geometry` `A` completely encloses `Geometry` `B`.
+#[cfg(feature = "std")]
 pub mod contains;
+#[cfg(feature = "std")]
 pub use contains::Contains;
 
 /// Convert the type of a geometry’s coordinate value.
+#[cfg(feature = "std")]
 pub mod convert;
+#[cfg(feature = "std")]
 pub use convert::{Convert, TryConvert};
 
+/// Extract isolines and isobands from a grid of values using marching squares.
+#[cfg(feature = "std")]
+pub mod contour;
+#[cfg(feature = "std")]
+pub use contour::ContourGrid;
+
 /// Calculate the convex hull of a `Geometry`.
 pub mod convex_hull;
 pub use convex_hull::ConvexHull;
 
 /// Determine whether a `Coord` lies inside, outside, or on the boundary of a geometry.
+#[cfg(feature = "std")]
 pub mod coordinate_position;
+#[cfg(feature = "std")]
 pub use coordinate_position::CoordinatePosition;
 
 /// Iterate over geometry coordinates.
 pub mod coords_iter;
 pub use coords_iter::CoordsIter;
 
+/// Cluster points using DBSCAN.
+#[cfg(feature = "std")]
+pub mod dbscan;
+#[cfg(feature = "std")]
+pub use dbscan::Dbscan;
+
 /// Densify linear geometry components
+#[cfg(feature = "std")]
 pub mod densify;
+#[cfg(feature = "std")]
 pub use densify::Densify;
 
+/// Calculate the diameter of a `Geometry`: the distance between its farthest coordinates.
+#[cfg(feature = "std")]
+pub mod diameter;
+#[cfg(feature = "std")]
+pub use diameter::Diameter;
+
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 pub mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Merge geometries sharing a key into a single `MultiPolygon`.
+#[cfg(feature = "std")]
+pub mod dissolve;
+
+/// Interpolate the elevation along a `LineStringZM`, and total its gain and loss.
+#[cfg(feature = "std")]
+pub mod elevation;
+#[cfg(feature = "std")]
+pub use elevation::{ElevationChange, ElevationProfile};
+
+/// Determine whether two geometries consist of the same points.
+#[cfg(feature = "std")]
+pub mod equals_topo;
+#[cfg(feature = "std")]
+pub use equals_topo::EqualsTopo;
+
 /// Calculate the minimum Euclidean distance between two `Geometries`.
+#[cfg(feature = "std")]
 pub mod euclidean_distance;
+#[cfg(feature = "std")]
 pub use euclidean_distance::EuclideanDistance;
 
 /// Calculate the length of a planar line between two `Geometries`.
@@ -75,94 +177,291 @@ pub mod euclidean_length;
 pub use euclidean_length::EuclideanLength;
 
 /// Calculate the extreme coordinates and indices of a geometry.
+#[cfg(feature = "std")]
 pub mod extremes;
+#[cfg(feature = "std")]
 pub use extremes::Extremes;
 
+/// Fill the holes of a polygonal geometry smaller than an area.
+#[cfg(feature = "std")]
+pub mod fill_holes;
+#[cfg(feature = "std")]
+pub use fill_holes::FillHoles;
+
+/// Scale and translate a `Geometry` to fit into a rectangle.
+pub mod fit_into;
+pub use fit_into::FitInto;
+
 /// Calculate the Frechet distance between two `LineStrings`.
+#[cfg(feature = "std")]
 pub mod frechet_distance;
+#[cfg(feature = "std")]
 pub use frechet_distance::FrechetDistance;
 
+/// Generalize geometries for a map scale.
+#[cfg(feature = "std")]
+pub mod generalize;
+#[cfg(feature = "std")]
+pub use generalize::{Generalization, GeneralizeForScale};
+
+/// Calculate the bounds of a geometry of longitudes and latitudes, along great circles.
+#[cfg(feature = "std")]
+pub mod geodesic_bounding_rect;
+#[cfg(feature = "std")]
+pub use geodesic_bounding_rect::{GeodesicBoundingRect, GeodesicBounds};
+
 /// Calculate the Geodesic distance between two `Point`s.
+#[cfg(feature = "std")]
 pub mod geodesic_distance;
+#[cfg(feature = "std")]
 pub use geodesic_distance::GeodesicDistance;
 
 /// Calculate a new `Point` lying on a Geodesic arc between two `Point`s.
+#[cfg(feature = "std")]
 pub mod geodesic_intermediate;
+#[cfg(feature = "std")]
 pub use geodesic_intermediate::GeodesicIntermediate;
 
 /// Calculate the Geodesic length of a line.
+#[cfg(feature = "std")]
 pub mod geodesic_length;
+#[cfg(feature = "std")]
 pub use geodesic_length::GeodesicLength;
 
+/// Convert geometries to and from GeoJSON.
+#[cfg(feature = "use-geojson")]
+pub mod geojson_conversion;
+#[cfg(feature = "use-geojson")]
+pub use geojson_conversion::{GeoJsonError, ToGeoJson, TryFromGeoJson};
+
+/// Sample points on a regular or stratified grid within a `Geometry`.
+#[cfg(feature = "std")]
+pub mod grid_sample;
+#[cfg(feature = "std")]
+pub use grid_sample::GridSample;
+
+/// Hash geometries up to a precision, to deduplicate near-identical geometries.
+#[cfg(feature = "std")]
+pub mod hashable;
+#[cfg(feature = "std")]
+pub use hashable::GeoHashable;
+
 /// Calculate a destination `Point`, given a distance and a bearing.
+#[cfg(feature = "std")]
 pub mod haversine_destination;
+#[cfg(feature = "std")]
 pub use haversine_destination::HaversineDestination;
 
 /// Calculate the Haversine distance between two `Geometries`.
+#[cfg(feature = "std")]
 pub mod haversine_distance;
+#[cfg(feature = "std")]
 pub use haversine_distance::HaversineDistance;
 
 /// Calculate a new `Point` lying on a Great Circle arc between two `Point`s.
+#[cfg(feature = "std")]
 pub mod haversine_intermediate;
+#[cfg(feature = "std")]
 pub use haversine_intermediate::HaversineIntermediate;
 
 /// Calculate the Haversine length of a Line.
+#[cfg(feature = "std")]
 pub mod haversine_length;
+#[cfg(feature = "std")]
 pub use haversine_length::HaversineLength;
 
+/// Interpolate values from scattered samples using inverse distance weighting.
+#[cfg(feature = "std")]
+pub mod idw;
+#[cfg(feature = "std")]
+pub use idw::Idw;
+
 /// Calculate a representative `Point` inside a `Geometry`
+#[cfg(feature = "std")]
 pub mod interior_point;
+#[cfg(feature = "std")]
 pub use interior_point::InteriorPoint;
 
+/// Calculate the area of the intersection of two polygons, without computing the intersection.
+#[cfg(feature = "std")]
+pub mod intersection_area;
+#[cfg(feature = "std")]
+pub use intersection_area::IntersectionArea;
+
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
+#[cfg(feature = "std")]
 pub mod intersects;
+#[cfg(feature = "std")]
 pub use intersects::Intersects;
 
 /// Determines whether a `LineString` is convex.
+#[cfg(feature = "std")]
 pub mod is_convex;
+#[cfg(feature = "std")]
 pub use is_convex::IsConvex;
 
 /// Calculate concave hull using k-nearest algorithm
+#[cfg(feature = "std")]
 pub mod k_nearest_concave_hull;
+#[cfg(feature = "std")]
 pub use k_nearest_concave_hull::KNearestConcaveHull;
 
+/// Cluster points using k-means.
+#[cfg(feature = "use-rand")]
+pub mod kmeans;
+#[cfg(feature = "use-rand")]
+pub use kmeans::KMeans;
+
+/// Find where to anchor the label of a geometry.
+#[cfg(feature = "std")]
+pub mod label_point;
+#[cfg(feature = "std")]
+pub use label_point::{LabelAnchor, LabelPoint};
+
+/// Build a network graph of linework, noded at endpoints and intersections, and route over it.
+#[cfg(feature = "std")]
+pub mod line_graph;
+#[cfg(feature = "std")]
+pub use line_graph::{EdgePosition, GraphPath, LineGraph, LineGraphEdge};
+
 /// Interpolate a point along a `Line` or `LineString`.
+#[cfg(feature = "std")]
 pub mod line_interpolate_point;
+#[cfg(feature = "std")]
 pub use line_interpolate_point::LineInterpolatePoint;
 
 /// Computes the intersection of two Lines.
+#[cfg(feature = "std")]
 pub mod line_intersection;
+#[cfg(feature = "std")]
 pub use line_intersection::LineIntersection;
 
+/// Place markers at regular intervals along a `LineString`, with their headings.
+#[cfg(feature = "std")]
+pub mod line_markers;
+#[cfg(feature = "std")]
+pub use line_markers::{LineMarkers, Markers};
+
+/// Test whether the segment between two points is clear of obstacle polygons.
+#[cfg(feature = "std")]
+pub mod line_of_sight;
+
 /// Locate a point along a `Line` or `LineString`.
+#[cfg(feature = "std")]
 pub mod line_locate_point;
+#[cfg(feature = "std")]
 pub use line_locate_point::LineLocatePoint;
 
+/// Calculate the tangent and normal vectors of a `LineString` at a distance along it.
+#[cfg(feature = "std")]
+pub mod line_tangent;
+#[cfg(feature = "std")]
+pub use line_tangent::LineTangent;
+
+/// Approximate curved geometries with straight segments.
+pub mod linearize;
+pub use linearize::Linearize;
+
 /// Iterate over the lines in a geometry.
+#[cfg(feature = "std")]
 pub mod lines_iter;
+#[cfg(feature = "std")]
 pub use lines_iter::LinesIter;
 
+/// Match the points of a trace onto the edges of a `LineGraph`.
+#[cfg(feature = "std")]
+pub mod map_matching;
+#[cfg(feature = "std")]
+pub use map_matching::MatchedPoint;
+
 /// Apply a function to all `Coord`s of a `Geometry`.
 pub mod map_coords;
 pub use map_coords::{MapCoords, MapCoordsInPlace};
 #[allow(deprecated)]
 pub use map_coords::{MapCoordsInplace, TryMapCoords, TryMapCoordsInplace};
 
-/// Orient a `Polygon`'s exterior and interior rings.
+/// Calculate the maximum distance between geometries: the distance between their farthest points.
+#[cfg(feature = "std")]
+pub mod max_distance;
+#[cfg(feature = "std")]
+pub use max_distance::MaxDistance;
+
+/// Test for monotonicity and decompose a `Polygon` into y-monotone pieces.
+#[cfg(feature = "std")]
+pub mod monotone;
+#[cfg(feature = "std")]
+pub use monotone::{IsMonotone, MonotoneDecomposition};
+
+/// Rewrite geometries into a canonical form.
+#[cfg(feature = "std")]
+pub mod normalize;
+#[cfg(feature = "std")]
+pub use normalize::Normalize;
+
+/// Orient a `Polygon`'s exterior and interior rings, or fix them in place throughout a geometry.
+#[cfg(feature = "std")]
 pub mod orient;
-pub use orient::Orient;
+#[cfg(feature = "std")]
+pub use orient::{FixOrientation, Orient};
+
+/// Measure how much two polygons overlap: intersection over union, coverage and Dice coefficient.
+#[cfg(feature = "std")]
+pub mod overlap;
+#[cfg(feature = "std")]
+pub use overlap::{Overlap, OverlapMetrics};
+
+/// Overlay two layers of `Polygon`s, keeping track of the source of every piece.
+#[cfg(feature = "std")]
+pub mod overlay;
+#[cfg(feature = "std")]
+pub use overlay::{LayerOverlay, OverlayPiece};
+
+/// Calculate the perimeter of a polygonal geometry, including its interior rings.
+#[cfg(feature = "std")]
+pub mod perimeter;
+#[cfg(feature = "std")]
+pub use perimeter::Perimeter;
 
 /// Helper functions for the "fast path" variant of the Polygon-Polygon Euclidean distance method.
+#[cfg(feature = "std")]
 pub(crate) mod polygon_distance_fast_path;
 
+/// Find the shortest path between two points inside a `Polygon`.
+#[cfg(feature = "std")]
+pub mod polygon_shortest_path;
+#[cfg(feature = "std")]
+pub use polygon_shortest_path::PolygonShortestPath;
+
+/// Prepare a `Polygon` for testing whether it contains many other geometries.
+#[cfg(feature = "std")]
+pub mod prepared_polygon;
+#[cfg(feature = "std")]
+pub use prepared_polygon::PreparedPolygon;
+
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
 
+/// Generate random geometries for testing and benchmarking.
+#[cfg(feature = "use-rand")]
+pub mod random;
+
+/// Calculate the union of many axis-aligned `Rect`s, and the area they cover, with a sweep.
+#[cfg(feature = "std")]
+pub mod rect_union;
+
 /// Relate two geometries based on DE-9IM
+#[cfg(feature = "std")]
 pub mod relate;
+#[cfg(feature = "std")]
 pub use relate::Relate;
 
+/// Resample a `LineString` into evenly spaced coordinates.
+#[cfg(feature = "std")]
+pub mod resample;
+#[cfg(feature = "std")]
+pub use resample::Resample;
+
 /// Rotate a `Geometry` by an angle given in degrees.
 pub mod rotate;
 pub use rotate::Rotate;
@@ -170,6 +469,16 @@ pub use rotate::Rotate;
 #[allow(deprecated)]
 pub use rotate::RotatePoint;
 
+/// Rotate calipers around two convex polygons, iterating over their antipodal pairs of vertices.
+pub mod rotating_calipers;
+pub use rotating_calipers::RotatingCalipers;
+
+/// Sample random points from the interior of a `Geometry`.
+#[cfg(feature = "use-rand")]
+pub mod sample_points;
+#[cfg(feature = "use-rand")]
+pub use sample_points::SamplePoints;
+
 /// Scale a `Geometry` up or down by a factor
 pub mod scale;
 pub use scale::Scale;
@@ -182,13 +491,85 @@ pub use skew::Skew;
 pub mod affine_ops;
 pub use affine_ops::{AffineOps, AffineTransform};
 
+/// Find every intersection between the segments of two `MultiLineString`s.
+#[cfg(feature = "std")]
+pub mod segment_intersections;
+#[cfg(feature = "std")]
+pub use segment_intersections::{SegmentId, SegmentIntersection};
+
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
-pub use simplify::{Simplify, SimplifyIdx};
+pub use simplify::{Simplify, SimplifyIdx, SimplifyMut};
 
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
+#[cfg(feature = "std")]
 pub mod simplifyvw;
-pub use simplifyvw::{SimplifyVW, SimplifyVWPreserve, SimplifyVwIdx};
+#[cfg(feature = "std")]
+pub use simplifyvw::{SimplifyVW, SimplifyVWPreserve, SimplifyVwIdx, SimplifyVwMut};
+
+/// Measure the thinness of polygons, and clean slivers out of them.
+#[cfg(feature = "std")]
+pub mod slivers;
+#[cfg(feature = "std")]
+pub use slivers::{RemoveSlivers, Thinness};
+
+/// Measure the slopes of the segments of a `LineStringZM`, and summarize its grades.
+#[cfg(feature = "std")]
+pub mod slope;
+#[cfg(feature = "std")]
+pub use slope::Slope;
+
+/// Snap the vertices of a geometry onto a reference geometry.
+#[cfg(feature = "std")]
+pub mod snap;
+#[cfg(feature = "std")]
+pub use snap::{Snap, SnapPolicy, SnapTarget};
+
+/// Join points to the polygons containing them, intersecting them, or near them.
+#[cfg(feature = "std")]
+pub mod spatial_join;
+#[cfg(feature = "std")]
+pub use spatial_join::JoinPredicate;
+
+/// Detect the stay points of a trajectory.
+#[cfg(feature = "std")]
+pub mod stay_points;
+#[cfg(feature = "std")]
+pub use stay_points::{StayPoint, StayPoints, TimedStayPoints};
+
+/// Measure, simplify and densify streams of coordinates, without collecting them.
+pub mod streaming;
+pub use streaming::{DensifyStream, SimplifyWindowed, StreamingCoords};
+
+/// Draw geometries as SVG paths.
+#[cfg(feature = "std")]
+pub mod svg;
+#[cfg(feature = "std")]
+pub use svg::ToSvg;
+
+/// Tessellate a `Geometry` into square or hexagonal grid cells.
+#[cfg(feature = "std")]
+pub mod tessellation;
+#[cfg(feature = "std")]
+pub use tessellation::Tessellate;
+
+/// Cut geometries into the tiles of slippy maps, and find the tiles covering them.
+#[cfg(feature = "std")]
+pub mod tiles;
+#[cfg(feature = "std")]
+pub use tiles::Tile;
+
+/// Encode polygons sharing boundaries as shared arcs, as TopoJSON does.
+#[cfg(feature = "std")]
+pub mod topology;
+#[cfg(feature = "std")]
+pub use topology::Topology;
+
+/// Cut perpendicular transects across a `LineString` at regular intervals.
+#[cfg(feature = "std")]
+pub mod transects;
+#[cfg(feature = "std")]
+pub use transects::Transects;
 
 /// Transform a geometry using PROJ.
 #[cfg(feature = "use-proj")]
@@ -200,25 +581,50 @@ pub use transform::Transform;
 pub mod translate;
 pub use translate::Translate;
 
+/// Check that the interior rings of a `Polygon` are valid holes in it.
+#[cfg(feature = "std")]
+pub mod validate_rings;
+#[cfg(feature = "std")]
+pub use validate_rings::{RingProblem, ValidateRings};
+
 /// Calculate the Vincenty distance between two `Point`s.
+#[cfg(feature = "std")]
 pub mod vincenty_distance;
+#[cfg(feature = "std")]
 pub use vincenty_distance::VincentyDistance;
 
 /// Calculate the Vincenty length of a `LineString`.
+#[cfg(feature = "std")]
 pub mod vincenty_length;
+#[cfg(feature = "std")]
 pub use vincenty_length::VincentyLength;
 
+/// Calculate the region of a `Polygon` visible from a point inside it.
+#[cfg(feature = "std")]
+pub mod visibility;
+#[cfg(feature = "std")]
+pub use visibility::VisibilityPolygon;
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
 
 /// Determine whether `Geometry` `A` is completely within by `Geometry` `B`.
+#[cfg(feature = "std")]
 pub mod within;
+#[cfg(feature = "std")]
 pub use within::Within;
 
 /// Planar sweep algorithm and related utils
+#[cfg(feature = "std")]
 pub mod sweep;
 
 /// Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
+#[cfg(feature = "std")]
 pub mod outlier_detection;
+#[cfg(feature = "std")]
 pub use outlier_detection::OutlierDetection;
+
+/// Parallel versions of algorithms over the components of multi-part geometries.
+#[cfg(feature = "use-rayon")]
+pub mod parallel;
@@ -0,0 +1,589 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::orient::{Direction, Orient};
+use crate::{Coord, GeoFloat, GeoNum, LineString, MultiPolygon, Polygon, Rect, Triangle};
+
+/// Test whether a geometry is monotone with respect to a direction.
+///
+/// A `LineString` is _monotone_ with respect to a direction `d` if the projections of its
+/// coordinates onto `d` never decrease, or never increase, as the `LineString` is traversed.
+///
+/// A `Polygon` is monotone with respect to `d` if it has no interior rings, and its exterior can
+/// be split into two chains that are each monotone with respect to `d`. Equivalently, every line
+/// perpendicular to `d` intersects the polygon in at most one connected piece.
+///
+/// The direction does not need to be normalized. Consecutive coordinates with equal projections
+/// (e.g. horizontal edges when testing against the y-axis) are permitted.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon};
+/// use geo::IsMonotone;
+///
+/// // A square with a notch cut into the top edge
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 3.),
+///     (x: 2., y: 1.),
+///     (x: 0., y: 3.),
+/// ];
+///
+/// assert!(polygon.is_monotone(coord! { x: 1., y: 0. }));
+/// assert!(!polygon.is_monotone(coord! { x: 0., y: 1. }));
+/// ```
+pub trait IsMonotone<T: GeoNum> {
+    fn is_monotone(&self, direction: Coord<T>) -> bool;
+}
+
+impl<T: GeoNum> IsMonotone<T> for LineString<T> {
+    fn is_monotone(&self, direction: Coord<T>) -> bool {
+        let signs = delta_signs(self, direction);
+        signs.windows(2).all(|w| w[0] == w[1])
+    }
+}
+
+impl<T: GeoNum> IsMonotone<T> for Polygon<T> {
+    fn is_monotone(&self, direction: Coord<T>) -> bool {
+        if !self.interiors().is_empty() {
+            return false;
+        }
+        // A closed ring is monotone iff its projection changes
+        // direction at most twice: once at the minimum and once
+        // at the maximum.
+        let signs = delta_signs(self.exterior(), direction);
+        let n = signs.len();
+        let changes = (0..n).filter(|&i| signs[i] != signs[(i + 1) % n]).count();
+        changes <= 2
+    }
+}
+
+impl<T: GeoNum> IsMonotone<T> for Rect<T> {
+    fn is_monotone(&self, _direction: Coord<T>) -> bool {
+        // Convex geometries are monotone in every direction
+        true
+    }
+}
+
+impl<T: GeoNum> IsMonotone<T> for Triangle<T> {
+    fn is_monotone(&self, _direction: Coord<T>) -> bool {
+        // Convex geometries are monotone in every direction
+        true
+    }
+}
+
+fn projection<T: GeoNum>(coord: Coord<T>, direction: Coord<T>) -> T {
+    coord.x * direction.x + coord.y * direction.y
+}
+
+// The signs (`true` when increasing) of all the non-zero changes
+// in projection between consecutive coordinates.
+fn delta_signs<T: GeoNum>(line_string: &LineString<T>, direction: Coord<T>) -> Vec<bool> {
+    line_string
+        .lines()
+        .filter_map(|line| {
+            let delta = projection(line.end, direction) - projection(line.start, direction);
+            if delta > T::zero() {
+                Some(true)
+            } else if delta < T::zero() {
+                Some(false)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Decompose a polygon into [y-monotone](IsMonotone) pieces.
+///
+/// The decomposition is computed by sweeping a horizontal line from top to bottom and inserting
+/// diagonals at _split_ and _merge_ vertices, as described in de Berg et al., _Computational
+/// Geometry: Algorithms and Applications_ (3rd ed., ch. 3). Interior rings are supported: every
+/// hole is connected to the rest of the polygon by the inserted diagonals, so each resulting
+/// piece is a simple polygon without interior rings.
+///
+/// The pieces are returned with counter-clockwise exteriors and together cover exactly the input
+/// polygon. They are a useful building block for triangulation (every y-monotone polygon can be
+/// triangulated in linear time) and for custom sweep-line algorithms.
+///
+/// The input is expected to be a valid polygon. The status structure of the sweep is a simple
+/// list, so the worst-case running time is quadratic in the number of vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon};
+/// use geo::{Area, IsMonotone, MonotoneDecomposition};
+///
+/// // A square with a notch cut into the top edge
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 3.),
+///     (x: 2., y: 1.),
+///     (x: 0., y: 3.),
+/// ];
+///
+/// let pieces = polygon.monotone_decomposition();
+/// assert_eq!(pieces.0.len(), 2);
+/// assert!(pieces.iter().all(|p| p.is_monotone(coord! { x: 0., y: 1. })));
+/// assert_eq!(pieces.unsigned_area(), polygon.unsigned_area());
+/// ```
+pub trait MonotoneDecomposition<T: GeoFloat> {
+    fn monotone_decomposition(&self) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat> MonotoneDecomposition<T> for Polygon<T> {
+    fn monotone_decomposition(&self) -> MultiPolygon<T> {
+        MultiPolygon::new(monotone_pieces(self))
+    }
+}
+
+impl<T: GeoFloat> MonotoneDecomposition<T> for MultiPolygon<T> {
+    fn monotone_decomposition(&self) -> MultiPolygon<T> {
+        MultiPolygon::new(self.iter().flat_map(monotone_pieces).collect())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VertexType {
+    Start,
+    End,
+    Split,
+    Merge,
+    Regular,
+}
+
+// Sweep order: `Less` if `a` is above `b`, i.e. processed before it.
+fn sweep_cmp<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> Ordering {
+    b.y.partial_cmp(&a.y)
+        .unwrap()
+        .then(a.x.partial_cmp(&b.x).unwrap())
+}
+
+struct Vertices<T: GeoFloat> {
+    coords: Vec<Coord<T>>,
+    prev: Vec<usize>,
+    next: Vec<usize>,
+}
+
+impl<T: GeoFloat> Vertices<T> {
+    // Collect the vertices of all rings. The polygon must be oriented so that the interior lies
+    // to the left of every ring: counter-clockwise exterior, clockwise interiors.
+    fn new(polygon: &Polygon<T>) -> Self {
+        let mut vertices = Vertices {
+            coords: vec![],
+            prev: vec![],
+            next: vec![],
+        };
+        vertices.push_ring(polygon.exterior());
+        for interior in polygon.interiors() {
+            vertices.push_ring(interior);
+        }
+        vertices
+    }
+
+    fn push_ring(&mut self, ring: &LineString<T>) {
+        let mut coords: Vec<Coord<T>> = ring.0.clone();
+        coords.dedup();
+        if coords.len() > 1 && coords.first() == coords.last() {
+            coords.pop();
+        }
+        if coords.len() < 3 {
+            return;
+        }
+        let offset = self.coords.len();
+        let n = coords.len();
+        for (i, coord) in coords.into_iter().enumerate() {
+            self.coords.push(coord);
+            self.prev.push(offset + (i + n - 1) % n);
+            self.next.push(offset + (i + 1) % n);
+        }
+    }
+
+    fn is_below(&self, a: usize, b: usize) -> bool {
+        sweep_cmp(self.coords[b], self.coords[a]) == Ordering::Less
+    }
+
+    fn vertex_type(&self, v: usize) -> VertexType {
+        let (prev, next) = (self.prev[v], self.next[v]);
+        let prev_below = self.is_below(prev, v);
+        let next_below = self.is_below(next, v);
+        let orientation = T::Ker::orient2d(self.coords[prev], self.coords[v], self.coords[next]);
+        let reflex = orientation == Orientation::Clockwise;
+        match (prev_below, next_below) {
+            (true, true) if reflex => VertexType::Split,
+            (true, true) => VertexType::Start,
+            (false, false) if reflex => VertexType::Merge,
+            (false, false) => VertexType::End,
+            _ => VertexType::Regular,
+        }
+    }
+
+    // The x coordinate of the edge starting at `edge` at the given height.
+    fn edge_x_at(&self, edge: usize, y: T) -> T {
+        let a = self.coords[edge];
+        let b = self.coords[self.next[edge]];
+        if a.y == b.y {
+            a.x.min(b.x)
+        } else {
+            a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y)
+        }
+    }
+}
+
+// The sweep-line status: edges intersecting the sweep line with the
+// polygon interior to their right, together with their helper vertex.
+struct Status {
+    edges: Vec<(usize, usize)>,
+}
+
+impl Status {
+    fn insert(&mut self, edge: usize, helper: usize) {
+        self.edges.push((edge, helper));
+    }
+
+    fn remove(&mut self, edge: usize) -> Option<usize> {
+        let idx = self.edges.iter().position(|(e, _)| *e == edge)?;
+        Some(self.edges.swap_remove(idx).1)
+    }
+
+    fn helper(&self, edge: usize) -> Option<usize> {
+        self.edges
+            .iter()
+            .find(|(e, _)| *e == edge)
+            .map(|(_, helper)| *helper)
+    }
+
+    fn set_helper(&mut self, edge: usize, helper: usize) {
+        if let Some(entry) = self.edges.iter_mut().find(|(e, _)| *e == edge) {
+            entry.1 = helper;
+        }
+    }
+
+    // The edge directly to the left of vertex `v`.
+    fn left_of<T: GeoFloat>(&self, vertices: &Vertices<T>, v: usize) -> Option<usize> {
+        let coord = vertices.coords[v];
+        self.edges
+            .iter()
+            .map(|(e, _)| (*e, vertices.edge_x_at(*e, coord.y)))
+            .filter(|(_, x)| *x <= coord.x)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(e, _)| e)
+    }
+}
+
+fn monotone_pieces<T: GeoFloat>(polygon: &Polygon<T>) -> Vec<Polygon<T>> {
+    let polygon = polygon.orient(Direction::Default);
+    let vertices = Vertices::new(&polygon);
+    if vertices.coords.is_empty() {
+        return vec![];
+    }
+
+    let mut order: Vec<usize> = (0..vertices.coords.len()).collect();
+    order.sort_by(|a, b| sweep_cmp(vertices.coords[*a], vertices.coords[*b]));
+    let types: Vec<VertexType> = (0..vertices.coords.len())
+        .map(|v| vertices.vertex_type(v))
+        .collect();
+
+    let mut status = Status { edges: vec![] };
+    let mut diagonals = vec![];
+    let is_merge = |v: Option<usize>| matches!(v, Some(v) if types[v] == VertexType::Merge);
+
+    for &v in &order {
+        let prev_edge = vertices.prev[v];
+        match types[v] {
+            VertexType::Start => status.insert(v, v),
+            VertexType::End => {
+                let helper = status.remove(prev_edge);
+                if is_merge(helper) {
+                    diagonals.push((v, helper.unwrap()));
+                }
+            }
+            VertexType::Split => {
+                if let Some(left) = status.left_of(&vertices, v) {
+                    if let Some(helper) = status.helper(left) {
+                        diagonals.push((v, helper));
+                    }
+                    status.set_helper(left, v);
+                }
+                status.insert(v, v);
+            }
+            VertexType::Merge => {
+                let helper = status.remove(prev_edge);
+                if is_merge(helper) {
+                    diagonals.push((v, helper.unwrap()));
+                }
+                if let Some(left) = status.left_of(&vertices, v) {
+                    let helper = status.helper(left);
+                    if is_merge(helper) {
+                        diagonals.push((v, helper.unwrap()));
+                    }
+                    status.set_helper(left, v);
+                }
+            }
+            VertexType::Regular => {
+                if vertices.is_below(vertices.next[v], v) {
+                    // The interior of the polygon lies to the right of `v`
+                    let helper = status.remove(prev_edge);
+                    if is_merge(helper) {
+                        diagonals.push((v, helper.unwrap()));
+                    }
+                    status.insert(v, v);
+                } else if let Some(left) = status.left_of(&vertices, v) {
+                    let helper = status.helper(left);
+                    if is_merge(helper) {
+                        diagonals.push((v, helper.unwrap()));
+                    }
+                    status.set_helper(left, v);
+                }
+            }
+        }
+    }
+
+    extract_faces(&vertices, diagonals)
+}
+
+// Walk the faces of the planar subdivision formed by the rings and the diagonals, keeping the
+// interior to the left of every traversed edge.
+fn extract_faces<T: GeoFloat>(
+    vertices: &Vertices<T>,
+    mut diagonals: Vec<(usize, usize)>,
+) -> Vec<Polygon<T>> {
+    let n = vertices.coords.len();
+    let mut outgoing: Vec<Vec<usize>> = (0..n).map(|v| vec![vertices.next[v]]).collect();
+
+    for diagonal in diagonals.iter_mut() {
+        if diagonal.0 > diagonal.1 {
+            *diagonal = (diagonal.1, diagonal.0);
+        }
+    }
+    diagonals.sort_unstable();
+    diagonals.dedup();
+    for &(a, b) in &diagonals {
+        if a != b {
+            outgoing[a].push(b);
+            outgoing[b].push(a);
+        }
+    }
+
+    let tau = T::from(std::f64::consts::TAU).unwrap();
+    let angle = |from: usize, to: usize| {
+        let delta = vertices.coords[to] - vertices.coords[from];
+        delta.y.atan2(delta.x)
+    };
+    // Having arrived at `at` from `from`, the next edge keeping the
+    // face on the left is the first one clockwise from the reverse edge.
+    let next_vertex = |from: usize, at: usize| -> usize {
+        let reverse = angle(at, from);
+        outgoing[at]
+            .iter()
+            .map(|&to| {
+                let mut cw = reverse - angle(at, to);
+                if to == from || cw <= T::zero() {
+                    cw = cw + tau;
+                }
+                (to, cw)
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(to, _)| to)
+            .unwrap()
+    };
+
+    let half_edge_count = n + 2 * diagonals.len();
+    let mut visited = HashSet::new();
+    let mut faces = vec![];
+    for (start, targets) in outgoing.iter().enumerate() {
+        for &first in targets {
+            if visited.contains(&(start, first)) {
+                continue;
+            }
+            let mut ring = vec![];
+            let (mut from, mut to) = (start, first);
+            while visited.insert((from, to)) && ring.len() <= half_edge_count {
+                ring.push(vertices.coords[from]);
+                let next = next_vertex(from, to);
+                from = to;
+                to = next;
+            }
+            if ring.len() >= 3 {
+                faces.push(Polygon::new(LineString::new(ring), vec![]));
+            }
+        }
+    }
+    faces
+}
+
+/// Triangulate a polygon by decomposing it into y-monotone pieces and clipping the ears of each
+/// piece. Degenerate (zero area) triangles are omitted.
+pub(crate) fn triangulate<T: GeoFloat>(polygon: &Polygon<T>) -> Vec<Triangle<T>> {
+    let mut triangles = vec![];
+    for piece in monotone_pieces(polygon) {
+        let mut ring = piece.into_inner().0 .0;
+        ring.pop();
+        clip_ears(ring, &mut triangles);
+    }
+    triangles
+}
+
+// Ear clipping of a simple, counter-clockwise ring without a repeated closing coordinate.
+fn clip_ears<T: GeoFloat>(mut ring: Vec<Coord<T>>, triangles: &mut Vec<Triangle<T>>) {
+    let is_ear = |ring: &[Coord<T>], i: usize| {
+        let n = ring.len();
+        let (a, b, c) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+        T::Ker::orient2d(a, b, c) == Orientation::CounterClockwise
+            && !ring.iter().any(|&p| {
+                p != a
+                    && p != b
+                    && p != c
+                    && T::Ker::orient2d(a, b, p) != Orientation::Clockwise
+                    && T::Ker::orient2d(b, c, p) != Orientation::Clockwise
+                    && T::Ker::orient2d(c, a, p) != Orientation::Clockwise
+            })
+    };
+
+    while ring.len() > 3 {
+        let n = ring.len();
+        // Collinear vertices can be dropped without producing a triangle
+        if let Some(i) = (0..n).find(|&i| {
+            T::Ker::orient2d(ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n])
+                == Orientation::Collinear
+        }) {
+            ring.remove(i);
+            continue;
+        }
+        match (0..n).find(|&i| is_ear(&ring, i)) {
+            Some(i) => {
+                triangles.push(Triangle::new(
+                    ring[(i + n - 1) % n],
+                    ring[i],
+                    ring[(i + 1) % n],
+                ));
+                ring.remove(i);
+            }
+            // Only possible for invalid input
+            None => return,
+        }
+    }
+    if ring.len() == 3 && T::Ker::orient2d(ring[0], ring[1], ring[2]) != Orientation::Collinear {
+        triangles.push(Triangle::new(ring[0], ring[1], ring[2]));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, polygon, Area};
+
+    #[test]
+    fn monotone_line_string() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 5.), (x: 1., y: 2.), (x: 3., y: 1.)];
+        assert!(ls.is_monotone(coord! { x: 1., y: 0. }));
+        assert!(!ls.is_monotone(coord! { x: 0., y: 1. }));
+        // A diagonal direction
+        assert!(!ls.is_monotone(coord! { x: 1., y: 1. }));
+    }
+
+    #[test]
+    fn monotone_integer_polygon() {
+        let poly = polygon![
+            (x: 0, y: 0),
+            (x: 4, y: 0),
+            (x: 4, y: 3),
+            (x: 2, y: 1),
+            (x: 0, y: 3),
+        ];
+        assert!(poly.is_monotone(coord! { x: 1, y: 0 }));
+        assert!(!poly.is_monotone(coord! { x: 0, y: 1 }));
+    }
+
+    #[test]
+    fn polygon_with_hole_is_not_monotone() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        assert!(!poly.is_monotone(coord! { x: 0., y: 1. }));
+    }
+
+    #[test]
+    fn decompose_convex() {
+        let poly = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let pieces = poly.monotone_decomposition();
+        assert_eq!(pieces.0.len(), 1);
+        assert_relative_eq!(pieces.unsigned_area(), 4.);
+    }
+
+    #[test]
+    fn decompose_merge_vertex() {
+        // clockwise input is re-oriented before the sweep
+        let poly = polygon![
+            (x: 0., y: 3.),
+            (x: 2., y: 1.),
+            (x: 4., y: 3.),
+            (x: 4., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        let pieces = poly.monotone_decomposition();
+        assert_eq!(pieces.0.len(), 2);
+        assert_relative_eq!(pieces.unsigned_area(), 8.);
+        for piece in &pieces {
+            assert!(piece.is_monotone(coord! { x: 0., y: 1. }));
+        }
+    }
+
+    #[test]
+    fn decompose_split_and_merge_with_hole() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let pieces = poly.monotone_decomposition();
+        assert!(pieces.0.len() >= 2);
+        assert_relative_eq!(pieces.unsigned_area(), 96.);
+        for piece in &pieces {
+            assert!(piece.interiors().is_empty());
+            assert!(piece.is_monotone(coord! { x: 0., y: 1. }));
+        }
+    }
+
+    #[test]
+    fn decompose_comb() {
+        // A comb with three teeth pointing down: two split vertices
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 3., y: 0.),
+            (x: 3., y: 2.),
+            (x: 4., y: 2.),
+            (x: 4., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 3.),
+            (x: 0., y: 3.),
+        ];
+        let pieces = poly.monotone_decomposition();
+        assert_relative_eq!(pieces.unsigned_area(), poly.unsigned_area());
+        for piece in &pieces {
+            assert!(piece.is_monotone(coord! { x: 0., y: 1. }));
+        }
+    }
+
+    #[test]
+    fn triangulate_polygon_with_hole() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let triangles = triangulate(&poly);
+        // n + 2h - 2 triangles for n vertices and h holes
+        assert_eq!(triangles.len(), 8 + 2 - 2);
+        let area: f64 = triangles.iter().map(|t| t.unsigned_area()).sum();
+        assert_relative_eq!(area, 96.);
+    }
+}
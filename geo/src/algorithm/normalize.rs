@@ -0,0 +1,373 @@
+use std::cmp::Ordering;
+
+use crate::utils::lex_cmp;
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{
+    Coord, CoordsIter, GeoNum, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Rewrite a geometry into a canonical form, so that geometries describing the same shape with
+/// the same coordinates are equal, hash alike, and print alike.
+///
+/// - closed rings start at their lexicographically least coordinate (by `x`, then `y`). The
+///   exteriors of polygons, and closed `LineString`s, are wound counter-clockwise and the
+///   interiors of polygons clockwise, as with [`Direction::Default`](crate::orient::Direction);
+/// - `Line`s and open `LineString`s go from their least end to their greatest;
+/// - the interiors of polygons, and the members of multi-geometries and geometry collections,
+///   are sorted, by type and then by their coordinates.
+///
+/// Normalization doesn't remove coordinates: repeated points, and the members of collections
+/// appearing twice, are kept. The coordinates must not be `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon, Normalize};
+///
+/// let a = polygon![(x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.), (x: 2., y: 0.)];
+/// let b = polygon![(x: 0., y: 0.), (x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 0.)];
+/// let c = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)];
+/// assert_ne!(a, b);
+/// assert_eq!(a.normalize(), b.normalize());
+/// assert_eq!(
+///     a.normalize(),
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+/// );
+///
+/// assert_eq!(
+///     MultiPolygon::new(vec![c.clone(), a]).normalize(),
+///     MultiPolygon::new(vec![b, c]).normalize(),
+/// );
+/// ```
+pub trait Normalize {
+    fn normalize(&self) -> Self;
+}
+
+// Compare sequences of coordinates lexicographically.
+fn cmp_coords<T: GeoNum>(
+    mut a: impl Iterator<Item = Coord<T>>,
+    mut b: impl Iterator<Item = Coord<T>>,
+) -> Ordering {
+    loop {
+        match (a.next(), b.next()) {
+            (Some(p), Some(q)) => match lex_cmp(&p, &q) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            },
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+        }
+    }
+}
+
+fn normalize_open<T: GeoNum>(line_string: &LineString<T>) -> LineString<T> {
+    let mut line_string = line_string.clone();
+    let forwards = line_string.0.iter().copied();
+    if cmp_coords(forwards, line_string.0.iter().rev().copied()) == Ordering::Greater {
+        line_string.0.reverse();
+    }
+    line_string
+}
+
+// Wind a closed ring as `winding_order`, and start it at its least coordinate. A ring which
+// passes through that coordinate twice, or is degenerate, has no reliable winding order: the
+// least of its rotations in either direction is taken instead.
+fn normalize_ring<T: GeoNum>(ring: &LineString<T>, winding_order: WindingOrder) -> LineString<T> {
+    if ring.0.len() < 2 || !ring.is_closed() {
+        return normalize_open(ring);
+    }
+    let mut ring = ring.clone();
+    // the closing coordinate repeats the first one
+    let n = ring.0.len() - 1;
+    let least = ring.0[..n]
+        .iter()
+        .copied()
+        .min_by(lex_cmp)
+        .expect("rings have coordinates");
+    let visits = ring.0[..n].iter().filter(|&&coord| coord == least).count();
+    let directions: &[bool] = if visits == 1 && ring.winding_order().is_some() {
+        ring.make_winding_order(winding_order);
+        &[false]
+    } else {
+        &[false, true]
+    };
+    let starts: Vec<usize> = (0..n).filter(|&i| ring.0[i] == least).collect();
+    let coords = &ring.0;
+    let rotation = move |start: usize, reversed: bool| {
+        (0..=n).map(move |i| match reversed {
+            false => coords[(start + i) % n],
+            true => coords[(start + n - i % n) % n],
+        })
+    };
+    let mut best = (starts[0], false);
+    for &start in &starts {
+        for &reversed in directions {
+            if cmp_coords(rotation(start, reversed), rotation(best.0, best.1)) == Ordering::Less {
+                best = (start, reversed);
+            }
+        }
+    }
+    rotation(best.0, best.1).collect()
+}
+
+// The order of the types of geometries in sorted collections.
+fn rank<T: GeoNum>(geometry: &Geometry<T>) -> u8 {
+    match geometry {
+        Geometry::Point(_) => 0,
+        Geometry::MultiPoint(_) => 1,
+        Geometry::Line(_) => 2,
+        Geometry::LineString(_) => 3,
+        Geometry::MultiLineString(_) => 4,
+        Geometry::Triangle(_) => 5,
+        Geometry::Rect(_) => 6,
+        Geometry::Polygon(_) => 7,
+        Geometry::MultiPolygon(_) => 8,
+        Geometry::GeometryCollection(_) => 9,
+    }
+}
+
+impl<T: GeoNum> Normalize for Point<T> {
+    fn normalize(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GeoNum> Normalize for Line<T> {
+    fn normalize(&self) -> Self {
+        match lex_cmp(&self.start, &self.end) {
+            Ordering::Greater => Line::new(self.end, self.start),
+            _ => *self,
+        }
+    }
+}
+
+impl<T: GeoNum> Normalize for LineString<T> {
+    fn normalize(&self) -> Self {
+        normalize_ring(self, WindingOrder::CounterClockwise)
+    }
+}
+
+impl<T: GeoNum> Normalize for Polygon<T> {
+    fn normalize(&self) -> Self {
+        let mut interiors: Vec<_> = self
+            .interiors()
+            .iter()
+            .map(|interior| normalize_ring(interior, WindingOrder::Clockwise))
+            .collect();
+        interiors.sort_by(|a, b| cmp_coords(a.coords_iter(), b.coords_iter()));
+        Polygon::new(
+            normalize_ring(self.exterior(), WindingOrder::CounterClockwise),
+            interiors,
+        )
+    }
+}
+
+impl<T: GeoNum> Normalize for MultiPoint<T> {
+    fn normalize(&self) -> Self {
+        let mut points = self.0.clone();
+        points.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+        MultiPoint::new(points)
+    }
+}
+
+impl<T: GeoNum> Normalize for MultiLineString<T> {
+    fn normalize(&self) -> Self {
+        let mut line_strings: Vec<_> = self.iter().map(Normalize::normalize).collect();
+        line_strings.sort_by(|a, b| cmp_coords(a.coords_iter(), b.coords_iter()));
+        MultiLineString::new(line_strings)
+    }
+}
+
+impl<T: GeoNum> Normalize for MultiPolygon<T> {
+    fn normalize(&self) -> Self {
+        let mut polygons: Vec<_> = self.iter().map(Normalize::normalize).collect();
+        polygons.sort_by(|a, b| cmp_coords(a.coords_iter(), b.coords_iter()));
+        MultiPolygon::new(polygons)
+    }
+}
+
+impl<T: GeoNum> Normalize for Rect<T> {
+    fn normalize(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GeoNum> Normalize for Triangle<T> {
+    fn normalize(&self) -> Self {
+        let ring = self.to_polygon().exterior().normalize();
+        Triangle::new(ring[0], ring[1], ring[2])
+    }
+}
+
+impl<T: GeoNum> Normalize for GeometryCollection<T> {
+    fn normalize(&self) -> Self {
+        let mut geometries: Vec<_> = self.iter().map(Normalize::normalize).collect();
+        geometries.sort_by(|a, b| {
+            rank(a)
+                .cmp(&rank(b))
+                .then_with(|| cmp_coords(a.coords_iter(), b.coords_iter()))
+        });
+        GeometryCollection::new_from(geometries)
+    }
+}
+
+impl<T: GeoNum> Normalize for Geometry<T> {
+    fn normalize(&self) -> Self {
+        match self {
+            Geometry::Point(g) => Geometry::Point(g.normalize()),
+            Geometry::Line(g) => Geometry::Line(g.normalize()),
+            Geometry::LineString(g) => Geometry::LineString(g.normalize()),
+            Geometry::Polygon(g) => Geometry::Polygon(g.normalize()),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.normalize()),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.normalize()),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.normalize()),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.normalize()),
+            Geometry::Rect(g) => Geometry::Rect(g.normalize()),
+            Geometry::Triangle(g) => Geometry::Triangle(g.normalize()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Coord};
+
+    #[test]
+    fn rings() {
+        let ring =
+            line_string![(x: 2, y: 2), (x: 0, y: 2), (x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2)];
+        let expected =
+            line_string![(x: 0, y: 0), (x: 2, y: 0), (x: 2, y: 2), (x: 0, y: 2), (x: 0, y: 0)];
+        assert_eq!(ring.normalize(), expected);
+        assert_eq!(expected.normalize(), expected);
+
+        // the other way round
+        let mut reversed = ring;
+        reversed.0.reverse();
+        assert_eq!(reversed.normalize(), expected);
+
+        // passing through its least coordinate twice
+        let loops = line_string![
+            (x: 0, y: 0), (x: 2, y: 1), (x: 2, y: -1), (x: 0, y: 0),
+            (x: 2, y: 3), (x: 2, y: 2), (x: 0, y: 0)
+        ];
+        let rotated = line_string![
+            (x: 0, y: 0), (x: 2, y: 3), (x: 2, y: 2), (x: 0, y: 0),
+            (x: 2, y: 1), (x: 2, y: -1), (x: 0, y: 0)
+        ];
+        let expected = line_string![
+            (x: 0, y: 0), (x: 2, y: -1), (x: 2, y: 1), (x: 0, y: 0),
+            (x: 2, y: 2), (x: 2, y: 3), (x: 0, y: 0)
+        ];
+        assert_eq!(loops.normalize(), expected);
+        assert_eq!(rotated.normalize(), expected);
+
+        // degenerate, without a winding order
+        let spike = line_string![(x: 1, y: 1), (x: 2, y: 2), (x: 0, y: 0), (x: 1, y: 1)];
+        let expected = line_string![(x: 0, y: 0), (x: 1, y: 1), (x: 2, y: 2), (x: 0, y: 0)];
+        assert_eq!(spike.normalize(), expected);
+        let mut reversed = spike;
+        reversed.0.reverse();
+        assert_eq!(reversed.normalize(), expected);
+    }
+
+    #[test]
+    fn open_lines() {
+        let line_string = line_string![(x: 3., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_eq!(
+            line_string.normalize(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 0.)]
+        );
+        // ends with the same x: their y decides
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.), (x: 0., y: 0.5)];
+        assert_eq!(line_string.normalize(), line_string);
+
+        let line = Line::new((1., 1.), (0., 5.));
+        assert_eq!(line.normalize(), Line::new((0., 5.), (1., 1.)));
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.normalize(), empty);
+    }
+
+    #[test]
+    fn polygons() {
+        let polygon = polygon!(
+            exterior: [(x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 0.), (x: 0., y: 4.)],
+            interiors: [
+                [(x: 3., y: 3.), (x: 3., y: 2.), (x: 2., y: 2.)],
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)],
+            ],
+        );
+        let expected = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 1.)],
+                [(x: 2., y: 2.), (x: 3., y: 3.), (x: 3., y: 2.)],
+            ],
+        );
+        assert_eq!(polygon.normalize(), expected);
+        assert_eq!(expected.normalize(), expected);
+
+        let triangle = Triangle::new(
+            Coord { x: 1., y: 1. },
+            Coord { x: 0., y: 2. },
+            Coord { x: 0., y: 0. },
+        );
+        assert_eq!(
+            triangle.normalize(),
+            Triangle::new(
+                Coord { x: 0., y: 0. },
+                Coord { x: 1., y: 1. },
+                Coord { x: 0., y: 2. },
+            )
+        );
+    }
+
+    #[test]
+    fn collections() {
+        let multi_point = MultiPoint::new(vec![
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 1.),
+            point!(x: 1., y: 0.),
+        ]);
+        assert_eq!(
+            multi_point.normalize(),
+            MultiPoint::new(vec![
+                point!(x: 0., y: 1.),
+                point!(x: 1., y: 0.),
+                point!(x: 1., y: 0.),
+            ])
+        );
+
+        let a = line_string![(x: 5., y: 5.), (x: 0., y: 0.)];
+        let b = line_string![(x: 1., y: 0.), (x: 0., y: 1.)];
+        assert_eq!(
+            MultiLineString::new(vec![a.clone(), b.clone()]).normalize(),
+            MultiLineString::new(vec![a.normalize(), b.normalize()]),
+        );
+        assert_eq!(
+            MultiLineString::new(vec![b.clone(), a.clone()]).normalize(),
+            MultiLineString::new(vec![a.clone(), b.clone()]).normalize(),
+        );
+
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::from(MultiLineString::new(vec![b.clone(), a.clone()])),
+            Geometry::from(b.clone()),
+            Geometry::from(point!(x: 9., y: 9.)),
+            Geometry::from(a.clone()),
+        ]);
+        assert_eq!(
+            collection.normalize(),
+            GeometryCollection::new_from(vec![
+                Geometry::from(point!(x: 9., y: 9.)),
+                Geometry::from(a.normalize()),
+                Geometry::from(b.normalize()),
+                Geometry::from(MultiLineString::new(vec![a, b]).normalize()),
+            ])
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{GeoNum, MultiPolygon, Polygon};
+use crate::{GeoNum, Geometry, GeometryCollection, LineString, MultiPolygon, Polygon};
 
 use crate::winding_order::{Winding, WindingOrder};
 
@@ -83,6 +83,105 @@ where
     }
 }
 
+/// Orient the rings of the polygons of a geometry in place, according to convention: exterior
+/// rings counter-clockwise, and interior rings clockwise, throughout nested collections.
+///
+/// Unlike [`Orient`], only the rings wound the wrong way are rewritten, and the geometry is
+/// walked once, whatever its nesting. Rings without a winding order, e.g. with fewer than three
+/// distinct coordinates, are left alone.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, FixOrientation, Geometry, GeometryCollection, Polygon, Winding};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)];
+/// assert!(polygon.exterior().is_cw());
+///
+/// let mut collection = GeometryCollection::new_from(vec![Geometry::from(polygon)]);
+/// assert!(collection.fix_orientation());
+/// assert!(!collection.fix_orientation());
+///
+/// let fixed = Polygon::try_from(collection.0[0].clone()).unwrap();
+/// assert!(fixed.exterior().is_ccw());
+/// ```
+pub trait FixOrientation {
+    /// Orient the rings of the geometry, returning whether any was rewound.
+    fn fix_orientation(&mut self) -> bool;
+}
+
+impl<T> FixOrientation for Polygon<T>
+where
+    T: GeoNum,
+{
+    fn fix_orientation(&mut self) -> bool {
+        let mut changed = false;
+        self.exterior_mut(|ring| changed |= fix_ring(ring, WindingOrder::CounterClockwise));
+        self.interiors_mut(|rings| {
+            for ring in rings {
+                changed |= fix_ring(ring, WindingOrder::Clockwise);
+            }
+        });
+        changed
+    }
+}
+
+impl<T> FixOrientation for MultiPolygon<T>
+where
+    T: GeoNum,
+{
+    fn fix_orientation(&mut self) -> bool {
+        self.iter_mut()
+            .fold(false, |changed, poly| poly.fix_orientation() | changed)
+    }
+}
+
+impl<T> FixOrientation for GeometryCollection<T>
+where
+    T: GeoNum,
+{
+    fn fix_orientation(&mut self) -> bool {
+        self.iter_mut().fold(false, |changed, geometry| {
+            geometry.fix_orientation() | changed
+        })
+    }
+}
+
+impl<T> FixOrientation for Geometry<T>
+where
+    T: GeoNum,
+{
+    fn fix_orientation(&mut self) -> bool {
+        match self {
+            Geometry::Polygon(g) => g.fix_orientation(),
+            Geometry::MultiPolygon(g) => g.fix_orientation(),
+            Geometry::GeometryCollection(g) => g.fix_orientation(),
+            // without rings, or with rings which can't be wound the wrong way
+            Geometry::Point(_)
+            | Geometry::Line(_)
+            | Geometry::LineString(_)
+            | Geometry::MultiPoint(_)
+            | Geometry::MultiLineString(_)
+            | Geometry::Rect(_)
+            | Geometry::Triangle(_) => false,
+        }
+    }
+}
+
+// rewind a ring wound against the given order, returning whether it was
+fn fix_ring<T>(ring: &mut LineString<T>, winding_order: WindingOrder) -> bool
+where
+    T: GeoNum,
+{
+    match ring.winding_order() {
+        Some(order) if order != winding_order => {
+            ring.make_winding_order(winding_order);
+            true
+        }
+        _ => false,
+    }
+}
+
 /// By default, a properly-oriented Polygon has its outer ring oriented counter-clockwise,
 /// and its inner ring(s) oriented clockwise. Selecting `Reversed` will result in a Polygon
 /// with a clockwise-oriented exterior ring, and counter-clockwise interior ring(s)
@@ -145,4 +244,38 @@ mod test {
         assert_eq!(oriented.exterior().0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors()[0].0, oriented_int_ls.0);
     }
+
+    #[test]
+    fn fix_nested_orientation() {
+        use crate::{polygon, Winding};
+
+        // wound the wrong way around
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        ];
+        let fixed = orient(&polygon, Direction::Default);
+
+        let mut geometry = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            Geometry::from(crate::point!(x: 0., y: 0.)),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::from(
+                MultiPolygon::new(vec![fixed.clone(), polygon]),
+            )])),
+        ]));
+        assert!(geometry.fix_orientation());
+
+        let expected = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            Geometry::from(crate::point!(x: 0., y: 0.)),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::from(
+                MultiPolygon::new(vec![fixed.clone(), fixed.clone()]),
+            )])),
+        ]));
+        assert_eq!(geometry, expected);
+        assert!(!geometry.fix_orientation());
+
+        // only the interior ring is wound the wrong way
+        let mut polygon = Polygon::new(fixed.exterior().clone(), vec![fixed.exterior().clone()]);
+        assert!(polygon.fix_orientation());
+        assert!(polygon.interiors()[0].is_cw());
+    }
 }
@@ -0,0 +1,110 @@
+use crate::{Area, GeoFloat, IntersectionArea};
+
+/// Measure how much two polygons or multi-polygons overlap, e.g. to score the outlines found by
+/// a model against reference outlines, or to match the features of two datasets.
+///
+/// All the metrics are computed from a single [`IntersectionArea`], which doesn't compute the
+/// intersection itself.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Overlap, Rect};
+///
+/// let a = Rect::new((0., 0.), (4., 4.)).to_polygon();
+/// let b = Rect::new((2., 0.), (4., 2.)).to_polygon();
+/// let metrics = a.overlap(&b);
+///
+/// assert_eq!(metrics.intersection_area, 4.);
+/// assert_eq!(metrics.intersection_over_union, 0.25);
+/// // a quarter of `a` is covered by `b`, which is wholly covered by `a`
+/// assert_eq!(metrics.self_covered, 0.25);
+/// assert_eq!(metrics.other_covered, 1.);
+/// assert_eq!(metrics.dice, 0.4);
+/// ```
+pub trait Overlap<T: GeoFloat, Rhs = Self> {
+    fn overlap(&self, other: &Rhs) -> OverlapMetrics<T>;
+}
+
+/// How much two geometries overlap, as computed by [`Overlap::overlap`].
+///
+/// The ratios range from 0, for geometries which don't overlap, to 1. Those which would divide
+/// by zero, because a geometry is empty, are 0.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlapMetrics<T: GeoFloat> {
+    /// The area of the intersection of the geometries.
+    pub intersection_area: T,
+    /// The area of the intersection over the area of the union of the geometries, or
+    /// [Jaccard index](https://en.wikipedia.org/wiki/Jaccard_index).
+    pub intersection_over_union: T,
+    /// The fraction of the area of the first geometry covered by the second.
+    pub self_covered: T,
+    /// The fraction of the area of the second geometry covered by the first.
+    pub other_covered: T,
+    /// Twice the area of the intersection over the sum of the areas of the geometries, or
+    /// [Sørensen–Dice coefficient].
+    ///
+    /// [Sørensen–Dice coefficient]: https://en.wikipedia.org/wiki/Sørensen–Dice_coefficient
+    pub dice: T,
+}
+
+impl<T, G, Rhs> Overlap<T, Rhs> for G
+where
+    T: GeoFloat,
+    G: IntersectionArea<T, Rhs> + Area<T>,
+    Rhs: Area<T>,
+{
+    fn overlap(&self, other: &Rhs) -> OverlapMetrics<T> {
+        let ratio = |numerator: T, denominator: T| {
+            if denominator > T::zero() {
+                numerator / denominator
+            } else {
+                T::zero()
+            }
+        };
+        let intersection = self.intersection_area(other);
+        let (area, other_area) = (self.unsigned_area(), other.unsigned_area());
+        OverlapMetrics {
+            intersection_area: intersection,
+            intersection_over_union: ratio(intersection, area + other_area - intersection),
+            self_covered: ratio(intersection, area),
+            other_covered: ratio(intersection, other_area),
+            dice: ratio(intersection + intersection, area + other_area),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{LineString, MultiPolygon, Polygon, Rect};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn metrics() {
+        let a = Rect::new((0., 0.), (2., 2.)).to_polygon();
+        let metrics = a.overlap(&a);
+        assert_eq!(metrics.intersection_over_union, 1.);
+        assert_eq!(metrics.dice, 1.);
+        assert_eq!(metrics.self_covered, 1.);
+
+        let b = MultiPolygon::new(vec![
+            Rect::new((1., 0.), (3., 1.)).to_polygon(),
+            Rect::new((5., 5.), (6., 6.)).to_polygon(),
+        ]);
+        let metrics = a.overlap(&b);
+        assert_eq!(metrics.intersection_area, 1.);
+        assert_relative_eq!(metrics.intersection_over_union, 1. / 6.);
+        assert_eq!(metrics.self_covered, 0.25);
+        assert_relative_eq!(metrics.other_covered, 1. / 3.);
+        assert_relative_eq!(metrics.dice, 2. / 7.);
+        assert_eq!(b.overlap(&a).self_covered, metrics.other_covered);
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        let metrics = a.overlap(&empty);
+        assert_eq!(metrics.intersection_over_union, 0.);
+        assert_eq!(metrics.other_covered, 0.);
+        assert_eq!(empty.overlap(&empty).dice, 0.);
+    }
+}
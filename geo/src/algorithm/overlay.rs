@@ -0,0 +1,123 @@
+use crate::{BooleanOps, GeoFloat, MultiPolygon, Polygon};
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeObject};
+
+/// Overlay two layers of polygons, intersecting every polygon of one layer with every polygon
+/// of the other.
+///
+/// Only pairs of polygons with intersecting bounding boxes are intersected, using an R-tree of
+/// the bounding boxes of the `other` layer. Every non-empty intersection is returned as an
+/// [`OverlayPiece`], tagged with the indices of its source polygons, so that their attributes
+/// can be joined by the caller. Pieces are ordered by the index in `self`, then the index in
+/// `other`. Pairs of polygons that only touch along their boundaries don't produce a piece.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Area, LayerOverlay, Rect};
+///
+/// let parcels = vec![
+///     Rect::new((0., 0.), (2., 2.)).to_polygon(),
+///     Rect::new((2., 0.), (4., 2.)).to_polygon(),
+/// ];
+/// let flood_zones = vec![
+///     Rect::new((1., 1.), (3., 3.)).to_polygon(),
+///     Rect::new((10., 10.), (11., 11.)).to_polygon(),
+/// ];
+///
+/// let pieces = parcels.overlay(&flood_zones);
+/// assert_eq!(pieces.len(), 2);
+/// assert_eq!((pieces[0].a, pieces[0].b), (0, 0));
+/// assert_eq!((pieces[1].a, pieces[1].b), (1, 0));
+/// assert_eq!(pieces[1].geometry.unsigned_area(), 1.);
+/// ```
+pub trait LayerOverlay<T: GeoFloat> {
+    fn overlay(&self, other: &[Polygon<T>]) -> Vec<OverlayPiece<T>>;
+}
+
+/// The intersection of a polygon from each layer of an [overlay](LayerOverlay).
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayPiece<T: GeoFloat> {
+    /// The index of the source polygon in the first layer.
+    pub a: usize,
+    /// The index of the source polygon in the second layer.
+    pub b: usize,
+    pub geometry: MultiPolygon<T>,
+}
+
+impl<T: GeoFloat> LayerOverlay<T> for [Polygon<T>] {
+    fn overlay(&self, other: &[Polygon<T>]) -> Vec<OverlayPiece<T>> {
+        let tree = RTree::bulk_load(
+            other
+                .iter()
+                .enumerate()
+                .map(|(idx, polygon)| {
+                    GeomWithData::new(Rectangle::from_aabb(polygon.envelope()), idx)
+                })
+                .collect(),
+        );
+
+        let mut pieces = vec![];
+        for (a, polygon) in self.iter().enumerate() {
+            let mut candidates: Vec<usize> = tree
+                .locate_in_envelope_intersecting(&polygon.envelope())
+                .map(|candidate| candidate.data)
+                .collect();
+            candidates.sort_unstable();
+            for b in candidates {
+                let geometry = polygon.intersection(&other[b]);
+                if !geometry.0.is_empty() {
+                    pieces.push(OverlayPiece { a, b, geometry });
+                }
+            }
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, Rect};
+
+    #[test]
+    fn grid_over_polygon() {
+        // a triangle overlaid with a 2 x 2 grid of unit squares
+        let layer = [polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 0., y: 2.)]];
+        let grid: Vec<Polygon<f64>> = (0..2)
+            .flat_map(|i| {
+                (0..2).map(move |j| {
+                    Rect::new((i as f64, j as f64), (i as f64 + 1., j as f64 + 1.)).to_polygon()
+                })
+            })
+            .collect();
+
+        let pieces = layer.overlay(&grid);
+        // the triangle doesn't reach into the square at (1, 1)
+        assert_eq!(
+            pieces.iter().map(|piece| piece.b).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!(pieces.iter().all(|piece| piece.a == 0));
+        let areas: Vec<f64> = pieces
+            .iter()
+            .map(|piece| piece.geometry.unsigned_area())
+            .collect();
+        assert_relative_eq!(areas[0], 1.);
+        assert_relative_eq!(areas[1], 0.5);
+        assert_relative_eq!(areas[2], 0.5);
+    }
+
+    #[test]
+    fn touching_and_disjoint() {
+        let a = [Rect::new((0., 0.), (1., 1.)).to_polygon()];
+        let b = vec![
+            Rect::new((1., 0.), (2., 1.)).to_polygon(),
+            Rect::new((5., 5.), (6., 6.)).to_polygon(),
+        ];
+        assert!(a.overlay(&b).is_empty());
+        assert!(a.overlay(&[]).is_empty());
+    }
+}
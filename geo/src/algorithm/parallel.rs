@@ -0,0 +1,589 @@
+//! Parallel versions of algorithms over the components of multi-part geometries, using
+//! [rayon](https://docs.rs/rayon).
+//!
+//! Requires the `use-rayon` feature.
+//!
+//! # When is it worth it?
+//!
+//! Distributing work among threads has a fixed cost, which dwarfs the work itself for small
+//! geometries. The parallel methods fall back to the sequential algorithms unless the geometry
+//! has at least two components, and at least [`PARALLEL_THRESHOLD`] coordinates in total. Above
+//! the threshold, the speedup depends on how evenly the coordinates are spread among the
+//! components: a `MultiPolygon` made of one huge polygon and many tiny islands is hardly faster
+//! to process in parallel. [`ParMapCoords`] and [`ParMapCoordsInPlace`] also split line strings
+//! and rings into chunks, so that they don't depend on the components of the geometry.
+//!
+//! Results are the same as those of the sequential algorithms, up to floating point rounding,
+//! as sums are accumulated in a different order.
+
+use crate::algorithm::centroid::par_multi_polygon_centroid;
+use crate::algorithm::spatial_join::{spatial_join, JoinPredicate, PolygonIndex};
+use crate::{
+    Area, Centroid, ConvexHull, Coord, CoordFloat, CoordNum, CoordsIter, GeoFloat, GeoNum,
+    Geometry, GeometryCollection, LineString, MapCoords, MapCoordsInPlace, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Simplify,
+};
+
+use rayon::prelude::*;
+
+/// The minimum number of coordinates of a geometry for the parallel methods to actually run in
+/// parallel.
+///
+/// The threshold is set for the cheapest of the algorithms, such as `par_unsigned_area`, which
+/// spend a few nanoseconds per coordinate: below it, the whole computation takes about as long
+/// as waking up the threads of the pool.
+pub const PARALLEL_THRESHOLD: usize = 10_000;
+
+fn worth_parallelizing<'a, G: CoordsIter<'a>>(geometry: &'a G, components: usize) -> bool {
+    components > 1 && geometry.coords_count() >= PARALLEL_THRESHOLD
+}
+
+/// Signed and unsigned area of a `MultiPolygon`, summing the areas of its polygons in
+/// parallel. See [`Area`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::parallel::ParArea;
+/// use geo::{MultiPolygon, Rect};
+///
+/// let squares: MultiPolygon<f64> = (0..100)
+///     .map(|i| Rect::new((i as f64 * 2., 0.), (i as f64 * 2. + 1., 1.)).to_polygon())
+///     .collect();
+/// assert_eq!(squares.par_unsigned_area(), 100.);
+/// ```
+pub trait ParArea<T: CoordNum> {
+    fn par_signed_area(&self) -> T;
+
+    fn par_unsigned_area(&self) -> T;
+}
+
+impl<T> ParArea<T> for MultiPolygon<T>
+where
+    T: CoordFloat + Send + Sync,
+{
+    fn par_signed_area(&self) -> T {
+        if !worth_parallelizing(self, self.0.len()) {
+            return self.signed_area();
+        }
+        self.0
+            .par_iter()
+            .map(|polygon| polygon.signed_area())
+            .reduce(T::zero, |a, b| a + b)
+    }
+
+    fn par_unsigned_area(&self) -> T {
+        if !worth_parallelizing(self, self.0.len()) {
+            return self.unsigned_area();
+        }
+        self.0
+            .par_iter()
+            .map(|polygon| polygon.signed_area().abs())
+            .reduce(T::zero, |a, b| a + b)
+    }
+}
+
+/// Centroid of a `MultiPolygon`, accumulating the centroids of its polygons in parallel. See
+/// [`Centroid`].
+pub trait ParCentroid<T: GeoFloat> {
+    fn par_centroid(&self) -> Option<Point<T>>;
+}
+
+impl<T> ParCentroid<T> for MultiPolygon<T>
+where
+    T: GeoFloat + Send + Sync,
+{
+    fn par_centroid(&self) -> Option<Point<T>> {
+        if !worth_parallelizing(self, self.0.len()) {
+            return self.centroid();
+        }
+        par_multi_polygon_centroid(self)
+    }
+}
+
+/// Ramer–Douglas–Peucker simplification of the components of a geometry, in parallel. See
+/// [`Simplify`].
+pub trait ParSimplify<T> {
+    fn par_simplify(&self, epsilon: &T) -> Self;
+}
+
+impl<T> ParSimplify<T> for MultiLineString<T>
+where
+    T: GeoFloat + Send + Sync,
+{
+    fn par_simplify(&self, epsilon: &T) -> Self {
+        if !worth_parallelizing(self, self.0.len()) {
+            return self.simplify(epsilon);
+        }
+        MultiLineString::new(self.0.par_iter().map(|l| l.simplify(epsilon)).collect())
+    }
+}
+
+impl<T> ParSimplify<T> for MultiPolygon<T>
+where
+    T: GeoFloat + Send + Sync,
+{
+    fn par_simplify(&self, epsilon: &T) -> Self {
+        if !worth_parallelizing(self, self.0.len()) {
+            return self.simplify(epsilon);
+        }
+        MultiPolygon::new(self.0.par_iter().map(|p| p.simplify(epsilon)).collect())
+    }
+}
+
+/// Convex hull of a multi-part geometry, computed from the hulls of its parts, which are
+/// computed in parallel. See [`ConvexHull`].
+pub trait ParConvexHull<T: GeoNum> {
+    fn par_convex_hull(&self) -> Polygon<T>;
+}
+
+impl<T> ParConvexHull<T> for MultiLineString<T>
+where
+    T: GeoNum + Send + Sync,
+{
+    fn par_convex_hull(&self) -> Polygon<T> {
+        if !worth_parallelizing(self, self.0.len()) {
+            return self.convex_hull();
+        }
+        hull_of_hulls(
+            self.0
+                .par_iter()
+                .map(|line_string| line_string.convex_hull()),
+        )
+    }
+}
+
+impl<T> ParConvexHull<T> for MultiPolygon<T>
+where
+    T: GeoNum + Send + Sync,
+{
+    fn par_convex_hull(&self) -> Polygon<T> {
+        if !worth_parallelizing(self, self.0.len()) {
+            return self.convex_hull();
+        }
+        hull_of_hulls(self.0.par_iter().map(|polygon| polygon.convex_hull()))
+    }
+}
+
+// The hull of the union of `hulls` is the hull of their vertices.
+fn hull_of_hulls<T>(hulls: impl ParallelIterator<Item = Polygon<T>>) -> Polygon<T>
+where
+    T: GeoNum + Send,
+{
+    let vertices: Vec<Coord<T>> = hulls.flat_map_iter(|hull| hull.into_inner().0 .0).collect();
+    MultiPoint::from(vertices).convex_hull()
+}
+
+/// Map a function over the coordinates of a geometry, in parallel. See [`MapCoords`].
+///
+/// Besides mapping the components of a geometry in parallel, line strings and rings are split
+/// into chunks of at least [`PARALLEL_THRESHOLD`] coordinates, which are mapped in parallel,
+/// so that a geometry made of a single huge polygon, such as a country being reprojected, is
+/// mapped in parallel too.
+///
+/// # Examples
+///
+/// ```
+/// use geo::parallel::ParMapCoords;
+/// use geo::{Coord, MultiPoint};
+///
+/// let points: MultiPoint<f64> = (0..1000).map(|i| (i as f64, 0.)).collect();
+/// let shifted = points.par_map_coords(|Coord { x, y }| Coord { x, y: y + 1. });
+/// assert!(shifted.iter().all(|point| point.y() == 1.));
+/// ```
+pub trait ParMapCoords<T, NT> {
+    type Output;
+
+    fn par_map_coords(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+    ) -> Self::Output
+    where
+        T: CoordNum,
+        NT: CoordNum;
+}
+
+/// Map a function over the coordinates of a geometry in place, in parallel, splitting line
+/// strings and rings into chunks like [`ParMapCoords`]. See [`MapCoordsInPlace`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::parallel::ParMapCoordsInPlace;
+/// use geo::{Coord, LineString};
+///
+/// let mut line_string: LineString<f64> = (0..100_000).map(|i| (i as f64, 0.)).collect();
+/// line_string.par_map_coords_in_place(|Coord { x, y }| Coord { x: x / 2., y });
+/// assert_eq!(line_string[99_999], Coord { x: 49_999.5, y: 0. });
+/// ```
+pub trait ParMapCoordsInPlace<T> {
+    fn par_map_coords_in_place(&mut self, func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync)
+    where
+        T: CoordNum;
+}
+
+impl<T, NT> ParMapCoords<T, NT> for LineString<T>
+where
+    T: CoordNum + Send + Sync,
+    NT: CoordNum + Send + Sync,
+{
+    type Output = LineString<NT>;
+
+    fn par_map_coords(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+    ) -> Self::Output {
+        if self.0.len() < PARALLEL_THRESHOLD {
+            return self.map_coords(func);
+        }
+        LineString::new(
+            self.0
+                .par_iter()
+                .with_min_len(PARALLEL_THRESHOLD)
+                .map(|&coord| func(coord))
+                .collect(),
+        )
+    }
+}
+
+impl<T> ParMapCoordsInPlace<T> for LineString<T>
+where
+    T: CoordNum + Send + Sync,
+{
+    fn par_map_coords_in_place(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync,
+    ) {
+        if self.0.len() < PARALLEL_THRESHOLD {
+            self.map_coords_in_place(func);
+        } else {
+            self.0
+                .par_iter_mut()
+                .with_min_len(PARALLEL_THRESHOLD)
+                .for_each(|coord| *coord = func(*coord));
+        }
+    }
+}
+
+impl<T, NT> ParMapCoords<T, NT> for Polygon<T>
+where
+    T: CoordNum + Send + Sync,
+    NT: CoordNum + Send + Sync,
+{
+    type Output = Polygon<NT>;
+
+    fn par_map_coords(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+    ) -> Self::Output {
+        if self.coords_count() < PARALLEL_THRESHOLD {
+            return self.map_coords(func);
+        }
+        let (exterior, interiors) = rayon::join(
+            || self.exterior().par_map_coords(func),
+            || {
+                self.interiors()
+                    .par_iter()
+                    .map(|ring| ring.par_map_coords(func))
+                    .collect()
+            },
+        );
+        Polygon::new(exterior, interiors)
+    }
+}
+
+impl<T> ParMapCoordsInPlace<T> for Polygon<T>
+where
+    T: CoordNum + Send + Sync,
+{
+    fn par_map_coords_in_place(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync,
+    ) {
+        if self.coords_count() < PARALLEL_THRESHOLD {
+            self.map_coords_in_place(func);
+        } else {
+            self.exterior_mut(|exterior| exterior.par_map_coords_in_place(func));
+            self.interiors_mut(|interiors| {
+                interiors
+                    .par_iter_mut()
+                    .for_each(|ring| ring.par_map_coords_in_place(func))
+            });
+        }
+    }
+}
+
+impl<T, NT> ParMapCoords<T, NT> for MultiPoint<T>
+where
+    T: CoordNum + Send + Sync,
+    NT: CoordNum + Send + Sync,
+{
+    type Output = MultiPoint<NT>;
+
+    fn par_map_coords(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+    ) -> Self::Output {
+        if self.0.len() < PARALLEL_THRESHOLD {
+            return self.map_coords(func);
+        }
+        MultiPoint(
+            self.0
+                .par_iter()
+                .with_min_len(PARALLEL_THRESHOLD)
+                .map(|point| point.map_coords(func))
+                .collect(),
+        )
+    }
+}
+
+impl<T> ParMapCoordsInPlace<T> for MultiPoint<T>
+where
+    T: CoordNum + Send + Sync,
+{
+    fn par_map_coords_in_place(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync,
+    ) {
+        if self.0.len() < PARALLEL_THRESHOLD {
+            self.map_coords_in_place(func);
+        } else {
+            self.0
+                .par_iter_mut()
+                .with_min_len(PARALLEL_THRESHOLD)
+                .for_each(|point| point.0 = func(point.0));
+        }
+    }
+}
+
+// Geometries made of parts which are mapped in parallel.
+macro_rules! impl_par_map_coords {
+    ($type:ident) => {
+        impl<T, NT> ParMapCoords<T, NT> for $type<T>
+        where
+            T: CoordNum + Send + Sync,
+            NT: CoordNum + Send + Sync,
+        {
+            type Output = $type<NT>;
+
+            fn par_map_coords(
+                &self,
+                func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+            ) -> Self::Output {
+                if self.coords_count() < PARALLEL_THRESHOLD {
+                    return self.map_coords(func);
+                }
+                $type(
+                    self.0
+                        .par_iter()
+                        .map(|part| part.par_map_coords(func))
+                        .collect(),
+                )
+            }
+        }
+
+        impl<T> ParMapCoordsInPlace<T> for $type<T>
+        where
+            T: CoordNum + Send + Sync,
+        {
+            fn par_map_coords_in_place(
+                &mut self,
+                func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync,
+            ) {
+                if self.coords_count() < PARALLEL_THRESHOLD {
+                    self.map_coords_in_place(func);
+                } else {
+                    self.0
+                        .par_iter_mut()
+                        .for_each(|part| part.par_map_coords_in_place(func));
+                }
+            }
+        }
+    };
+}
+
+impl_par_map_coords!(MultiLineString);
+impl_par_map_coords!(MultiPolygon);
+impl_par_map_coords!(GeometryCollection);
+
+impl<T, NT> ParMapCoords<T, NT> for Geometry<T>
+where
+    T: CoordNum + Send + Sync,
+    NT: CoordNum + Send + Sync,
+{
+    type Output = Geometry<NT>;
+
+    fn par_map_coords(
+        &self,
+        func: impl Fn(Coord<T>) -> Coord<NT> + Copy + Send + Sync,
+    ) -> Self::Output {
+        match self {
+            Geometry::LineString(g) => Geometry::LineString(g.par_map_coords(func)),
+            Geometry::Polygon(g) => Geometry::Polygon(g.par_map_coords(func)),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.par_map_coords(func)),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.par_map_coords(func)),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.par_map_coords(func)),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.par_map_coords(func)),
+            // a few coordinates at most
+            _ => self.map_coords(func),
+        }
+    }
+}
+
+impl<T> ParMapCoordsInPlace<T> for Geometry<T>
+where
+    T: CoordNum + Send + Sync,
+{
+    fn par_map_coords_in_place(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Coord<T> + Copy + Send + Sync,
+    ) {
+        match self {
+            Geometry::LineString(g) => g.par_map_coords_in_place(func),
+            Geometry::Polygon(g) => g.par_map_coords_in_place(func),
+            Geometry::MultiPoint(g) => g.par_map_coords_in_place(func),
+            Geometry::MultiLineString(g) => g.par_map_coords_in_place(func),
+            Geometry::MultiPolygon(g) => g.par_map_coords_in_place(func),
+            Geometry::GeometryCollection(g) => g.par_map_coords_in_place(func),
+            // a few coordinates at most
+            _ => self.map_coords_in_place(func),
+        }
+    }
+}
+
+/// Join each of `points` to the `polygons` satisfying `predicate`, looking the points up in
+/// parallel. See [`spatial_join`].
+///
+/// The points are looked up sequentially unless there are at least [`PARALLEL_THRESHOLD`] of
+/// them. The R-tree of the polygons is built before, on the calling thread.
+///
+/// # Examples
+///
+/// ```
+/// use geo::parallel::par_spatial_join;
+/// use geo::spatial_join::JoinPredicate;
+/// use geo::{MultiPoint, Rect};
+///
+/// let districts = vec![Rect::new((0., 0.), (10., 10.)).to_polygon()];
+/// let addresses: MultiPoint<f64> = (0..20_000).map(|i| (i as f64 / 1000., 1.)).collect();
+///
+/// let joined = par_spatial_join(&addresses.0, &districts, JoinPredicate::Contains);
+/// assert_eq!(joined.iter().filter(|polygons| polygons.is_empty()).count(), 10_001);
+/// ```
+pub fn par_spatial_join<T>(
+    points: &[Point<T>],
+    polygons: &[Polygon<T>],
+    predicate: JoinPredicate<T>,
+) -> Vec<Vec<usize>>
+where
+    T: GeoFloat + Send + Sync,
+{
+    if points.len() < PARALLEL_THRESHOLD {
+        return spatial_join(points, polygons, predicate);
+    }
+    let index = PolygonIndex::new(polygons);
+    points
+        .par_iter()
+        .map(|point| index.join(point, predicate))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Rect};
+
+    // enough squares, of 5 coordinates each, to go past the threshold
+    fn squares() -> MultiPolygon<f64> {
+        (0..PARALLEL_THRESHOLD / 4)
+            .map(|i| {
+                let (x, y) = ((i % 50) as f64 * 2., (i / 50) as f64 * 2.);
+                Rect::new((x, y), (x + 1., y + 1.)).to_polygon()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_results_as_sequential() {
+        let squares = squares();
+        assert!(squares.coords_count() >= PARALLEL_THRESHOLD);
+
+        assert_relative_eq!(squares.par_signed_area(), squares.signed_area());
+        assert_relative_eq!(squares.par_unsigned_area(), squares.unsigned_area());
+        assert_relative_eq!(squares.par_centroid().unwrap(), squares.centroid().unwrap());
+        assert_eq!(squares.par_simplify(&0.5), squares.simplify(&0.5));
+        assert_eq!(squares.par_convex_hull(), squares.convex_hull());
+
+        let double = |c: Coord<f64>| Coord {
+            x: c.x * 2.,
+            y: c.y * 2.,
+        };
+        assert_eq!(squares.par_map_coords(double), squares.map_coords(double));
+        let mut doubled = squares.clone();
+        doubled.par_map_coords_in_place(double);
+        assert_eq!(doubled, squares.map_coords(double));
+
+        let points: Vec<Point<f64>> = (0..PARALLEL_THRESHOLD)
+            .map(|i| Point::new((i % 100) as f64, (i / 100) as f64 / 2.))
+            .collect();
+        for predicate in [
+            JoinPredicate::Contains,
+            JoinPredicate::Intersects,
+            JoinPredicate::WithinDistance(0.5),
+        ] {
+            assert_eq!(
+                par_spatial_join(&points, &squares.0, predicate),
+                spatial_join(&points, &squares.0, predicate)
+            );
+        }
+    }
+
+    #[test]
+    fn map_coords_in_chunks() {
+        // a single ring past the threshold
+        let ring: LineString<f64> = (0..PARALLEL_THRESHOLD * 3)
+            .map(|i| (i as f64, (i % 7) as f64))
+            .collect();
+        let polygon = Polygon::new(ring, vec![]);
+        let shift = |c: Coord<f64>| Coord {
+            x: c.x + 1.,
+            y: c.y - 1.,
+        };
+        let mapped = polygon.map_coords(shift);
+        assert_eq!(polygon.par_map_coords(shift), mapped);
+
+        let mut geometry = Geometry::Polygon(polygon);
+        geometry.par_map_coords_in_place(shift);
+        assert_eq!(geometry, Geometry::Polygon(mapped));
+    }
+
+    #[test]
+    fn degenerate_polygons_in_centroid() {
+        // a polygon whose hole covers it entirely contributes its boundary, which is ignored as
+        // soon as another polygon has an area
+        let mut polygons = squares().0;
+        let ring = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        polygons.insert(
+            0,
+            Polygon::new(ring.exterior().clone(), vec![ring.exterior().clone()]),
+        );
+        let multi_polygon = MultiPolygon::new(polygons);
+        assert_relative_eq!(
+            multi_polygon.par_centroid().unwrap(),
+            multi_polygon.centroid().unwrap()
+        );
+    }
+
+    #[test]
+    fn small_geometries() {
+        let multi_polygon = MultiPolygon::new(vec![polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 0., y: 4.),
+        ]]);
+        assert_eq!(multi_polygon.par_unsigned_area(), 8.);
+        assert_eq!(multi_polygon.par_convex_hull(), multi_polygon.convex_hull());
+        let empty = MultiPolygon::<f64>::new(vec![]);
+        assert_eq!(empty.par_centroid(), None);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    CoordFloat, EuclideanLength, GeodesicLength, HaversineLength, LineString, MultiPolygon,
+    Polygon, Rect, Triangle,
+};
+
+/// Calculate the perimeter of a polygonal geometry: the total length of its boundary, i.e. of
+/// its exterior ring and of all its interior rings.
+///
+/// Unlike measuring the length of a polygon's [`exterior`](Polygon::exterior), which leaves
+/// out its holes, the perimeter includes every ring. Each method measures the rings as the
+/// length trait of the same name does.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, EuclideanLength, Perimeter};
+///
+/// let polygon = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+/// );
+///
+/// assert_eq!(polygon.exterior().euclidean_length(), 16.);
+/// assert_eq!(polygon.euclidean_perimeter(), 20.);
+/// ```
+pub trait Perimeter<T: CoordFloat> {
+    /// The planar perimeter of the geometry, in the units of its coordinates.
+    fn euclidean_perimeter(&self) -> T
+    where
+        LineString<T>: EuclideanLength<T>;
+
+    /// The perimeter of the geometry on a sphere, using the [haversine formula], in meters.
+    ///
+    /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+    fn haversine_perimeter(&self) -> T
+    where
+        LineString<T>: HaversineLength<T>;
+
+    /// The perimeter of the geometry on an ellipsoidal model of the earth, using the method of
+    /// [Karney (2013)], in meters.
+    ///
+    /// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+    fn geodesic_perimeter(&self) -> T
+    where
+        LineString<T>: GeodesicLength<T>;
+}
+
+fn rings_length<T: CoordFloat>(polygon: &Polygon<T>, length: impl Fn(&LineString<T>) -> T) -> T {
+    polygon
+        .interiors()
+        .iter()
+        .fold(length(polygon.exterior()), |total, interior| {
+            total + length(interior)
+        })
+}
+
+impl<T: CoordFloat> Perimeter<T> for Polygon<T> {
+    fn euclidean_perimeter(&self) -> T
+    where
+        LineString<T>: EuclideanLength<T>,
+    {
+        rings_length(self, |ring| ring.euclidean_length())
+    }
+
+    fn haversine_perimeter(&self) -> T
+    where
+        LineString<T>: HaversineLength<T>,
+    {
+        rings_length(self, |ring| ring.haversine_length())
+    }
+
+    fn geodesic_perimeter(&self) -> T
+    where
+        LineString<T>: GeodesicLength<T>,
+    {
+        rings_length(self, |ring| ring.geodesic_length())
+    }
+}
+
+impl<T: CoordFloat> Perimeter<T> for MultiPolygon<T> {
+    fn euclidean_perimeter(&self) -> T
+    where
+        LineString<T>: EuclideanLength<T>,
+    {
+        self.iter().fold(T::zero(), |total, polygon| {
+            total + polygon.euclidean_perimeter()
+        })
+    }
+
+    fn haversine_perimeter(&self) -> T
+    where
+        LineString<T>: HaversineLength<T>,
+    {
+        self.iter().fold(T::zero(), |total, polygon| {
+            total + polygon.haversine_perimeter()
+        })
+    }
+
+    fn geodesic_perimeter(&self) -> T
+    where
+        LineString<T>: GeodesicLength<T>,
+    {
+        self.iter().fold(T::zero(), |total, polygon| {
+            total + polygon.geodesic_perimeter()
+        })
+    }
+}
+
+macro_rules! impl_perimeter_via_polygon {
+    ($type:ident) => {
+        impl<T: CoordFloat> Perimeter<T> for $type<T> {
+            fn euclidean_perimeter(&self) -> T
+            where
+                LineString<T>: EuclideanLength<T>,
+            {
+                self.to_polygon().euclidean_perimeter()
+            }
+
+            fn haversine_perimeter(&self) -> T
+            where
+                LineString<T>: HaversineLength<T>,
+            {
+                self.to_polygon().haversine_perimeter()
+            }
+
+            fn geodesic_perimeter(&self) -> T
+            where
+                LineString<T>: GeodesicLength<T>,
+            {
+                self.to_polygon().geodesic_perimeter()
+            }
+        }
+    };
+}
+
+impl_perimeter_via_polygon!(Rect);
+impl_perimeter_via_polygon!(Triangle);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, polygon};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn euclidean() {
+        let rect = Rect::new((0., 0.), (4., 2.));
+        assert_eq!(rect.euclidean_perimeter(), 12.);
+        let triangle = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 3., y: 0. },
+            coord! { x: 0., y: 4. },
+        );
+        assert_eq!(triangle.euclidean_perimeter(), 12.);
+
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)],
+            interiors: [[(x: 1., y: 1.), (x: 1.5, y: 1.), (x: 1.5, y: 1.5), (x: 1., y: 1.5)]],
+        );
+        assert_eq!(polygon.euclidean_perimeter(), 14.);
+        let multi_polygon = MultiPolygon::new(vec![polygon, rect.to_polygon()]);
+        assert_eq!(multi_polygon.euclidean_perimeter(), 26.);
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).euclidean_perimeter(), 0.);
+    }
+
+    #[test]
+    fn geographic() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            interiors: [[(x: 0.2, y: 0.2), (x: 0.4, y: 0.2), (x: 0.4, y: 0.4)]],
+        );
+        let (exterior, interior) = (polygon.exterior(), &polygon.interiors()[0]);
+        assert_eq!(
+            polygon.haversine_perimeter(),
+            exterior.haversine_length() + interior.haversine_length()
+        );
+        assert_eq!(
+            polygon.geodesic_perimeter(),
+            exterior.geodesic_length() + interior.geodesic_length()
+        );
+        // the same ring, going the other way round
+        assert_relative_eq!(
+            Rect::new((0., 0.), (1., 1.)).geodesic_perimeter(),
+            exterior.geodesic_length(),
+            epsilon = 1e-6
+        );
+    }
+}
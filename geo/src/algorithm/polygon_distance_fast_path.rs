@@ -17,8 +17,11 @@ pub(crate) fn min_convex_poly_dist<T>(poly1: &Polygon<T>, poly2: &Polygon<T>) ->
 where
     T: GeoFloat + FloatConst,
 {
-    let poly1_extremes = poly1.extremes().unwrap();
-    let poly2_extremes = poly2.extremes().unwrap();
+    let (poly1_extremes, poly2_extremes) = match (poly1.extremes(), poly2.extremes()) {
+        (Some(poly1_extremes), Some(poly2_extremes)) => (poly1_extremes, poly2_extremes),
+        // an empty polygon has no extremes, nor any coordinate to be near
+        _ => return T::infinity(),
+    };
     let ymin1 = Point::from(poly1.exterior().0[poly1_extremes.y_min.index]);
     let ymax2 = Point::from(poly2.exterior().0[poly2_extremes.y_max.index]);
 
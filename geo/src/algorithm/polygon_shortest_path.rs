@@ -0,0 +1,359 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::monotone::triangulate;
+use crate::{Coord, GeoFloat, Line, LineString, Point, Polygon, Triangle};
+
+use rstar::primitives::GeomWithData;
+use rstar::{RTree, RTreeObject};
+
+/// Find the shortest path between two points that stays inside a polygon, such as a walking
+/// route through a building's floor plan.
+///
+/// The polygon is triangulated, the sequence of triangles leading from the start to the end is
+/// found, and the path is pulled taut through the edges shared by consecutive triangles with the
+/// [funnel algorithm](https://digestingduck.blogspot.com/2010/03/simple-stupid-funnel-algorithm.html).
+/// The resulting path only bends at vertices of the polygon.
+///
+/// For a polygon without holes, the path is the geodesic shortest path. When there are holes,
+/// there is more than one way around them: the sequence of triangles is chosen by the length of
+/// the path through the midpoints of their shared edges, so the path is the shortest one passing
+/// on the chosen sides of the holes, which may occasionally not be the overall shortest path.
+///
+/// Returns `None` if either point lies outside the polygon. Points on the boundary are allowed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, EuclideanLength, Polygon, PolygonShortestPath};
+///
+/// // a U-shaped corridor
+/// let corridor: Polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 3., y: 0.),
+///     (x: 3., y: 3.),
+///     (x: 2., y: 3.),
+///     (x: 2., y: 1.),
+///     (x: 1., y: 1.),
+///     (x: 1., y: 3.),
+///     (x: 0., y: 3.),
+/// ];
+///
+/// let path = corridor
+///     .shortest_path(point!(x: 0.5, y: 2.), point!(x: 2.5, y: 2.))
+///     .unwrap();
+/// // the path turns around the two inner corners of the U
+/// assert_eq!(path.0[1], (1., 1.).into());
+/// assert_eq!(path.0[2], (2., 1.).into());
+/// assert!((path.euclidean_length() - (5f64.sqrt() + 1.)).abs() < 1e-9);
+/// ```
+pub trait PolygonShortestPath<T: GeoFloat> {
+    fn shortest_path(&self, start: Point<T>, end: Point<T>) -> Option<LineString<T>>;
+}
+
+impl<T: GeoFloat> PolygonShortestPath<T> for Polygon<T> {
+    fn shortest_path(&self, start: Point<T>, end: Point<T>) -> Option<LineString<T>> {
+        let (start, end) = (start.0, end.0);
+        let triangles = triangulate(self);
+        let first = triangles.iter().position(|t| covers(t, start))?;
+        let last = triangles.iter().position(|t| covers(t, end))?;
+        let portals = channel(&triangles, first, last, start)?;
+        Some(funnel(start, end, &portals))
+    }
+}
+
+// Whether a counter-clockwise triangle contains a coordinate, or has it on its boundary.
+fn covers<T: GeoFloat>(triangle: &Triangle<T>, coord: Coord<T>) -> bool {
+    let [a, b, c] = triangle.to_array();
+    [(a, b), (b, c), (c, a)]
+        .iter()
+        .all(|(p, q)| T::Ker::orient2d(*p, *q, coord) != Orientation::Clockwise)
+}
+
+// An edge shared by two triangles, as seen when travelling from the first into the second.
+#[derive(Debug, Clone, Copy)]
+struct Portal<T: GeoFloat> {
+    left: Coord<T>,
+    right: Coord<T>,
+}
+
+// The triangles sharing part of an edge with each triangle, with the shared part.
+//
+// The triangulation may have vertices lying in the middle of an edge of a neighbouring triangle,
+// so edges are matched by overlap rather than by their endpoints.
+fn neighbours<T: GeoFloat>(triangles: &[Triangle<T>]) -> Vec<Vec<(usize, Portal<T>)>> {
+    let edges: RTree<GeomWithData<Line<T>, usize>> = RTree::bulk_load(
+        triangles
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, triangle)| {
+                let [a, b, c] = triangle.to_array();
+                [(a, b), (b, c), (c, a)]
+                    .into_iter()
+                    .map(move |(p, q)| GeomWithData::new(Line::new(p, q), idx))
+            })
+            .collect(),
+    );
+
+    let mut neighbours = vec![vec![]; triangles.len()];
+    for edge in edges.iter() {
+        let Line { start: a, end: b } = *edge.geom();
+        let direction = b - a;
+        let length_2 = direction.x * direction.x + direction.y * direction.y;
+        let position = |c: Coord<T>| {
+            let delta = c - a;
+            (delta.x * direction.x + delta.y * direction.y) / length_2
+        };
+        for other in edges.locate_in_envelope_intersecting(&edge.envelope()) {
+            let Line { start: p, end: q } = *other.geom();
+            if other.data == edge.data
+                || T::Ker::orient2d(a, b, p) != Orientation::Collinear
+                || T::Ker::orient2d(a, b, q) != Orientation::Collinear
+            {
+                continue;
+            }
+            // the edges of neighbouring counter-clockwise triangles run in opposite directions,
+            // so `q` is the end of the other edge nearest to `a`
+            let (from, to) = (position(q), position(p));
+            if from >= to {
+                continue;
+            }
+            // moving out of a counter-clockwise triangle, its edge runs from right to left
+            let right = if from > T::zero() { q } else { a };
+            let left = if to < T::one() { p } else { b };
+            if position(left) > position(right) {
+                neighbours[edge.data].push((other.data, Portal { left, right }));
+            }
+        }
+    }
+    neighbours
+}
+
+#[derive(Debug)]
+struct State<T: GeoFloat> {
+    distance: T,
+    triangle: usize,
+    position: Coord<T>,
+}
+
+impl<T: GeoFloat> PartialEq for State<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl<T: GeoFloat> Eq for State<T> {}
+
+impl<T: GeoFloat> PartialOrd for State<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Ord for State<T> {
+    // reversed, so that `BinaryHeap` pops the nearest state first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+// The portals crossed on the way from the `first` to the `last` triangle.
+//
+// The dual graph of the triangulation is searched with Dijkstra's algorithm, entering each
+// triangle at the midpoint of the portal it is reached through. Without holes, the dual graph is
+// a tree, and there is a single sequence of triangles to find.
+fn channel<T: GeoFloat>(
+    triangles: &[Triangle<T>],
+    first: usize,
+    last: usize,
+    start: Coord<T>,
+) -> Option<Vec<Portal<T>>> {
+    let neighbours = neighbours(triangles);
+    let two = T::one() + T::one();
+    let mut distances = vec![T::infinity(); triangles.len()];
+    let mut previous: Vec<Option<(usize, Portal<T>)>> = vec![None; triangles.len()];
+    let mut queue = BinaryHeap::new();
+    distances[first] = T::zero();
+    queue.push(State {
+        distance: T::zero(),
+        triangle: first,
+        position: start,
+    });
+    while let Some(State {
+        distance,
+        triangle,
+        position,
+    }) = queue.pop()
+    {
+        if triangle == last {
+            break;
+        }
+        if distance > distances[triangle] {
+            continue;
+        }
+        for (neighbour, portal) in &neighbours[triangle] {
+            let midpoint = (portal.left + portal.right) / two;
+            let delta = midpoint - position;
+            let distance = distance + delta.x.hypot(delta.y);
+            if distance < distances[*neighbour] {
+                distances[*neighbour] = distance;
+                previous[*neighbour] = Some((triangle, *portal));
+                queue.push(State {
+                    distance,
+                    triangle: *neighbour,
+                    position: midpoint,
+                });
+            }
+        }
+    }
+
+    let mut portals = vec![];
+    let mut triangle = last;
+    while triangle != first {
+        let (from, portal) = previous[triangle]?;
+        portals.push(portal);
+        triangle = from;
+    }
+    portals.reverse();
+    Some(portals)
+}
+
+// The simple stupid funnel algorithm, by Mikko Mononen.
+fn funnel<T: GeoFloat>(start: Coord<T>, end: Coord<T>, portals: &[Portal<T>]) -> LineString<T> {
+    let side = |p, q, r| T::Ker::orient2d(p, q, r);
+    let mut portals = portals.to_vec();
+    portals.push(Portal {
+        left: end,
+        right: end,
+    });
+
+    let mut path = vec![start];
+    let (mut apex, mut left, mut right) = (start, start, start);
+    let (mut left_idx, mut right_idx) = (0, 0);
+    // portals are numbered from 1, with the start as portal 0
+    let mut idx = 1;
+    while idx <= portals.len() {
+        let portal = portals[idx - 1];
+
+        // tighten the right side of the funnel
+        if side(apex, right, portal.right) != Orientation::Clockwise {
+            if apex == right || side(apex, left, portal.right) == Orientation::Clockwise {
+                right = portal.right;
+                right_idx = idx;
+            } else {
+                // the right side crosses over the left side, which becomes the new apex
+                apex = left;
+                path.push(apex);
+                right = apex;
+                right_idx = left_idx;
+                idx = left_idx + 1;
+                continue;
+            }
+        }
+
+        // tighten the left side of the funnel
+        if side(apex, left, portal.left) != Orientation::CounterClockwise {
+            if apex == left || side(apex, right, portal.left) == Orientation::CounterClockwise {
+                left = portal.left;
+                left_idx = idx;
+            } else {
+                apex = right;
+                path.push(apex);
+                left = apex;
+                left_idx = right_idx;
+                idx = right_idx + 1;
+                continue;
+            }
+        }
+        idx += 1;
+    }
+    path.push(end);
+    path.dedup();
+    if path.len() == 1 {
+        // the start and end coincide
+        path.push(end);
+    }
+    LineString::new(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, EuclideanLength};
+
+    #[test]
+    fn straight_line_in_convex_polygon() {
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let path = poly
+            .shortest_path(point!(x: 1., y: 3.), point!(x: 3., y: 1.))
+            .unwrap();
+        assert_eq!(path, LineString::from(vec![(1., 3.), (3., 1.)]));
+    }
+
+    #[test]
+    fn around_corners() {
+        // a spiral, turning left then right
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 3.),
+            (x: 2., y: 3.),
+            (x: 2., y: 4.),
+            (x: 5., y: 4.),
+            (x: 5., y: 5.),
+            (x: 1., y: 5.),
+            (x: 1., y: 2.),
+            (x: 4., y: 2.),
+            (x: 4., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let path = poly
+            .shortest_path(point!(x: 0.5, y: 0.5), point!(x: 4.5, y: 4.5))
+            .unwrap();
+        assert_eq!(
+            path,
+            LineString::from(vec![
+                (0.5, 0.5),
+                (4., 1.),
+                (4., 2.),
+                (2., 3.),
+                (2., 4.),
+                (4.5, 4.5)
+            ])
+        );
+    }
+
+    #[test]
+    fn around_a_hole() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 3.), (x: 6., y: 3.), (x: 6., y: 7.), (x: 4., y: 7.)]],
+        );
+        let path = poly
+            .shortest_path(point!(x: 2., y: 5.), point!(x: 8., y: 5.))
+            .unwrap();
+        // the path passes two corners of the hole, on either side
+        assert_eq!(path.0.len(), 4);
+        assert_relative_eq!(path.euclidean_length(), 2. * 8f64.sqrt() + 2.);
+    }
+
+    #[test]
+    fn boundary_and_outside_points() {
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        assert!(poly
+            .shortest_path(point!(x: 1., y: 1.), point!(x: 5., y: 1.))
+            .is_none());
+        let path = poly
+            .shortest_path(point!(x: 0., y: 0.), point!(x: 4., y: 4.))
+            .unwrap();
+        assert_eq!(path, LineString::from(vec![(0., 0.), (4., 4.)]));
+        let path = poly
+            .shortest_path(point!(x: 1., y: 1.), point!(x: 1., y: 1.))
+            .unwrap();
+        assert_eq!(path.0.len(), 2);
+    }
+}
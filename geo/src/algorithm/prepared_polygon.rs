@@ -0,0 +1,221 @@
+use crate::coordinate_position::{ray_crossing, CoordPos};
+use crate::intersects::monotone_chain::{chains_intersect, monotone_chains, MonotoneChain};
+use crate::{
+    BoundingRect, Contains, Coord, CoordinatePosition, GeoFloat, Intersects, Line, MultiPolygon,
+    Point, Polygon, Rect,
+};
+
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+use std::iter;
+
+/// A [`Polygon`] prepared for testing whether it contains many other geometries, such as the
+/// parcels within a single boundary.
+///
+/// Preparing the polygon splits its rings into monotone chains, runs of segments along which
+/// neither `x` nor `y` changes direction, and indexes them in an R* tree. A candidate is then
+/// only compared with the chains near it. If their boundaries don't meet, the candidate is
+/// contained if one of its vertices is inside the polygon, which only takes the chains crossing
+/// the ray from that vertex. Only candidates whose boundary touches or crosses the polygon's
+/// fall back to the [`Relate`](crate::Relate) computation of `Polygon::contains`.
+///
+/// For valid polygons, the results are those of `Polygon::contains`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Contains, PreparedPolygon};
+///
+/// let boundary = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let prepared = PreparedPolygon::new(&boundary);
+///
+/// let parcel = polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)];
+/// assert!(prepared.contains(&parcel));
+///
+/// let straddling = polygon![(x: 9., y: 1.), (x: 11., y: 1.), (x: 11., y: 2.), (x: 9., y: 2.)];
+/// assert!(!prepared.contains(&straddling));
+/// assert_eq!(prepared.contains(&straddling), boundary.contains(&straddling));
+/// ```
+#[derive(Debug)]
+pub struct PreparedPolygon<'a, T: GeoFloat + RTreeNum> {
+    polygon: &'a Polygon<T>,
+    bounding_rect: Option<Rect<T>>,
+    chains: RTree<MonotoneChain<'a, T>>,
+}
+
+impl<'a, T: GeoFloat + RTreeNum> PreparedPolygon<'a, T> {
+    pub fn new(polygon: &'a Polygon<T>) -> Self {
+        let chains = iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .flat_map(|ring| monotone_chains(&ring.0))
+            .collect();
+        PreparedPolygon {
+            polygon,
+            bounding_rect: polygon.bounding_rect(),
+            chains: RTree::bulk_load(chains),
+        }
+    }
+
+    pub fn polygon(&self) -> &'a Polygon<T> {
+        self.polygon
+    }
+
+    /// The position of `coord` relative to the polygon, counting the crossings of the ray from
+    /// `coord` to positive infinity with the chains whose envelopes it meets.
+    fn coord_pos(&self, coord: Coord<T>) -> CoordPos {
+        let max_x = match self.bounding_rect {
+            Some(rect) if rect.intersects(&coord) => rect.max().x,
+            _ => return CoordPos::Outside,
+        };
+        let ray = AABB::from_corners(Point::from(coord), Point::new(max_x, coord.y));
+
+        let mut crossings = 0;
+        for chain in self.chains.locate_in_envelope_intersecting(&ray) {
+            for segment in chain.coords.windows(2) {
+                match ray_crossing(coord, Line::new(segment[0], segment[1])) {
+                    None => return CoordPos::OnBoundary,
+                    Some(true) => crossings += 1,
+                    Some(false) => {}
+                }
+            }
+        }
+        // the holes of a valid polygon are inside its exterior, so a coord is inside the
+        // polygon if the ray crosses its rings an odd number of times overall
+        if crossings % 2 == 1 {
+            CoordPos::Inside
+        } else {
+            CoordPos::Outside
+        }
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> From<&'a Polygon<T>> for PreparedPolygon<'a, T> {
+    fn from(polygon: &'a Polygon<T>) -> Self {
+        PreparedPolygon::new(polygon)
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> RTreeObject for MonotoneChain<'a, T> {
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners(self.envelope.min().into(), self.envelope.max().into())
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> Contains<Coord<T>> for PreparedPolygon<'a, T> {
+    fn contains(&self, coord: &Coord<T>) -> bool {
+        self.coord_pos(*coord) == CoordPos::Inside
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> Contains<Point<T>> for PreparedPolygon<'a, T> {
+    fn contains(&self, point: &Point<T>) -> bool {
+        self.contains(&point.0)
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> Contains<Polygon<T>> for PreparedPolygon<'a, T> {
+    fn contains(&self, polygon: &Polygon<T>) -> bool {
+        let (rect, candidate_rect) = match (self.bounding_rect, polygon.bounding_rect()) {
+            (Some(rect), Some(candidate_rect)) => (rect, candidate_rect),
+            _ => return false,
+        };
+        if candidate_rect.min().x < rect.min().x
+            || candidate_rect.min().y < rect.min().y
+            || candidate_rect.max().x > rect.max().x
+            || candidate_rect.max().y > rect.max().y
+        {
+            return false;
+        }
+
+        // A candidate whose boundary meets the polygon's may still be contained, touching it
+        // from the inside, which takes the full computation.
+        for ring in iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            for chain in monotone_chains(&ring.0) {
+                let meets = self
+                    .chains
+                    .locate_in_envelope_intersecting(&chain.envelope())
+                    .any(|&indexed| chains_intersect(indexed, chain));
+                if meets {
+                    return self.polygon.contains(polygon);
+                }
+            }
+        }
+
+        // Otherwise, the candidate is either entirely inside the polygon or entirely outside,
+        // and only isn't contained in the first case if one of the polygon's holes is inside it.
+        self.coord_pos(polygon.exterior().0[0]) == CoordPos::Inside
+            && !self
+                .polygon
+                .interiors()
+                .iter()
+                .filter_map(|hole| hole.0.first())
+                .any(|coord| polygon.coordinate_position(coord) == CoordPos::Inside)
+    }
+}
+
+impl<'a, T: GeoFloat + RTreeNum> Contains<MultiPolygon<T>> for PreparedPolygon<'a, T> {
+    fn contains(&self, multi_polygon: &MultiPolygon<T>) -> bool {
+        !multi_polygon.0.is_empty() && multi_polygon.iter().all(|p| self.contains(p))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, polygon};
+
+    #[test]
+    fn matches_polygon_contains() {
+        let boundary = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 5., y: 7.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [[(x: 4., y: 2.), (x: 6., y: 2.), (x: 6., y: 4.), (x: 4., y: 4.)]],
+        ];
+        let prepared = PreparedPolygon::new(&boundary);
+
+        // squares inside, outside, straddling and touching the rings, and around the hole
+        let mut candidates = vec![Rect::new((3., 1.), (7., 5.)).to_polygon()];
+        for i in -2..24 {
+            for j in -2..24 {
+                let (x, y) = (f64::from(i) * 0.5, f64::from(j) * 0.5);
+                candidates.push(Rect::new((x, y), (x + 1., y + 1.)).to_polygon());
+            }
+        }
+        for candidate in &candidates {
+            assert_eq!(
+                prepared.contains(candidate),
+                boundary.contains(candidate),
+                "{:?}",
+                candidate
+            );
+        }
+        let parcels = MultiPolygon::new(vec![
+            Rect::new((1., 1.), (2., 2.)).to_polygon(),
+            Rect::new((7., 5.), (8., 6.)).to_polygon(),
+        ]);
+        assert!(prepared.contains(&parcels));
+
+        for coord in [
+            coord! { x: 1., y: 1. },
+            coord! { x: 5., y: 3. },
+            coord! { x: 4., y: 3. },
+            coord! { x: 5., y: 7. },
+            coord! { x: 5., y: 8. },
+            coord! { x: 0., y: 5. },
+            coord! { x: 11., y: 5. },
+        ] {
+            assert_eq!(
+                prepared.contains(&coord),
+                boundary.contains(&coord),
+                "{:?}",
+                coord
+            );
+        }
+    }
+}
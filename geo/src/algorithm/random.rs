@@ -0,0 +1,345 @@
+//! Generate random geometries.
+//!
+//! These generators are useful for fuzzing geometric pipelines and for benchmarking algorithms
+//! with inputs of a controlled size. All generators take a caller-provided random number
+//! generator, so results are reproducible when a seeded generator is used.
+//!
+//! Requires the `use-rand` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::random::{brownian_line_string, convex_polygon};
+//! use geo::{coord, IsConvex, Rect};
+//! use rand::rngs::StdRng;
+//! use rand::SeedableRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(0);
+//! let bounds = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 100., y: 100. });
+//!
+//! let polygon = convex_polygon(&mut rng, 20, bounds);
+//! assert!(polygon.exterior().is_convex());
+//!
+//! let trace = brownian_line_string(&mut rng, 1000, coord! { x: 0., y: 0. }, 1.);
+//! assert_eq!(trace.0.len(), 1000);
+//! ```
+
+use crate::{BoundingRect, Coord, GeoFloat, LineString, MultiPoint, Point, Polygon, Rect};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generate `num_points` points uniformly distributed within `bounds`.
+pub fn uniform_points<T, R>(rng: &mut R, num_points: usize, bounds: Rect<T>) -> MultiPoint<T>
+where
+    T: GeoFloat,
+    R: Rng + ?Sized,
+{
+    (0..num_points)
+        .map(|_| Point::from(uniform_coord(rng, bounds)))
+        .collect()
+}
+
+/// Generate a random convex polygon with `num_vertices` vertices, fitted to `bounds`.
+///
+/// The vertices are generated using Valtr's algorithm, so every convex polygon with the given
+/// number of vertices can be produced, and the resulting shapes are well spread out rather than
+/// clustered around a circle. The polygon touches all four sides of `bounds`.
+///
+/// # Panics
+///
+/// If `num_vertices` is less than 3.
+pub fn convex_polygon<T, R>(rng: &mut R, num_vertices: usize, bounds: Rect<T>) -> Polygon<T>
+where
+    T: GeoFloat,
+    R: Rng + ?Sized,
+{
+    assert!(num_vertices >= 3, "a polygon requires at least 3 vertices");
+
+    let x_components = random_chain_components::<T, R>(rng, num_vertices);
+    let mut y_components = random_chain_components::<T, R>(rng, num_vertices);
+    y_components.shuffle(rng);
+
+    // The components sum to zero in both dimensions, so laying the vectors out in order of their
+    // angle produces a closed convex ring.
+    let mut vectors: Vec<Coord<T>> = x_components
+        .into_iter()
+        .zip(y_components)
+        .map(|(x, y)| Coord { x, y })
+        .collect();
+    vectors.sort_by(|a, b| a.y.atan2(a.x).partial_cmp(&b.y.atan2(b.x)).unwrap());
+
+    let mut current = Coord::zero();
+    let mut ring = Vec::with_capacity(num_vertices + 1);
+    for vector in vectors {
+        ring.push(current);
+        current = current + vector;
+    }
+    Polygon::new(fit_to_bounds(ring, bounds).into(), vec![])
+}
+
+/// Generate a random star-shaped polygon with `num_vertices` vertices around `center`.
+///
+/// Vertices are placed at random angles around `center`, at a random distance between
+/// `min_radius` and `max_radius`. Every point of the polygon is visible from `center`.
+///
+/// # Panics
+///
+/// If `num_vertices` is less than 3.
+pub fn star_polygon<T, R>(
+    rng: &mut R,
+    num_vertices: usize,
+    center: Coord<T>,
+    min_radius: T,
+    max_radius: T,
+) -> Polygon<T>
+where
+    T: GeoFloat,
+    R: Rng + ?Sized,
+{
+    assert!(num_vertices >= 3, "a polygon requires at least 3 vertices");
+
+    let tau = T::from(std::f64::consts::TAU).unwrap();
+    let mut angles: Vec<T> = (0..num_vertices)
+        .map(|_| random_fraction::<T, R>(rng) * tau)
+        .collect();
+    angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let ring: Vec<Coord<T>> = angles
+        .into_iter()
+        .map(|angle| {
+            let radius = min_radius + (max_radius - min_radius) * random_fraction::<T, R>(rng);
+            let (sin, cos) = angle.sin_cos();
+            Coord {
+                x: center.x + radius * cos,
+                y: center.y + radius * sin,
+            }
+        })
+        .collect();
+    Polygon::new(ring.into(), vec![])
+}
+
+/// Generate a random simple polygon with `num_vertices` vertices within `bounds`.
+///
+/// Vertices have random x coordinates, and are randomly assigned to either the upper or the
+/// lower half of `bounds`. The upper vertices are connected from left to right, and the lower
+/// ones from right to left, producing a jagged polygon which is simple and x-monotone, but in
+/// general neither convex nor star-shaped.
+///
+/// # Panics
+///
+/// If `num_vertices` is less than 3.
+pub fn simple_polygon<T, R>(rng: &mut R, num_vertices: usize, bounds: Rect<T>) -> Polygon<T>
+where
+    T: GeoFloat,
+    R: Rng + ?Sized,
+{
+    assert!(num_vertices >= 3, "a polygon requires at least 3 vertices");
+
+    let (min, max) = (bounds.min(), bounds.max());
+    let mid_y = (min.y + max.y) / (T::one() + T::one());
+
+    let mut xs: Vec<T> = (0..num_vertices - 2)
+        .map(|_| min.x + bounds.width() * random_fraction::<T, R>(rng))
+        .collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut upper = vec![Coord { x: min.x, y: mid_y }];
+    let mut lower = vec![Coord { x: max.x, y: mid_y }];
+    for x in xs {
+        let fraction = random_fraction::<T, R>(rng);
+        // the halves are half-open, so the two chains never touch
+        if rng.gen::<bool>() {
+            upper.push(Coord {
+                x,
+                y: max.y - (max.y - mid_y) * fraction,
+            });
+        } else {
+            lower.push(Coord {
+                x,
+                y: min.y + (mid_y - min.y) * fraction,
+            });
+        }
+    }
+    // the lower chain is traversed from right to left
+    lower[1..].reverse();
+    upper.extend(lower);
+    Polygon::new(upper.into(), vec![])
+}
+
+/// Generate a random walk of `num_coords` coordinates, starting at `start`.
+///
+/// Each step is drawn from an isotropic normal distribution, with a standard deviation of
+/// `step_size` in each dimension, approximating Brownian motion. This resembles noisy tracks,
+/// such as GPS traces.
+pub fn brownian_line_string<T, R>(
+    rng: &mut R,
+    num_coords: usize,
+    start: Coord<T>,
+    step_size: T,
+) -> LineString<T>
+where
+    T: GeoFloat,
+    R: Rng + ?Sized,
+{
+    let mut current = start;
+    (0..num_coords)
+        .map(|i| {
+            if i > 0 {
+                let (dx, dy) = standard_normal_pair::<T, R>(rng);
+                current = current
+                    + Coord {
+                        x: dx * step_size,
+                        y: dy * step_size,
+                    };
+            }
+            current
+        })
+        .collect()
+}
+
+fn random_fraction<T: GeoFloat, R: Rng + ?Sized>(rng: &mut R) -> T {
+    T::from(rng.gen::<f64>()).unwrap()
+}
+
+fn uniform_coord<T: GeoFloat, R: Rng + ?Sized>(rng: &mut R, bounds: Rect<T>) -> Coord<T> {
+    Coord {
+        x: bounds.min().x + bounds.width() * random_fraction(rng),
+        y: bounds.min().y + bounds.height() * random_fraction(rng),
+    }
+}
+
+// Two independent standard normal samples, using the Box-Muller transform.
+fn standard_normal_pair<T: GeoFloat, R: Rng + ?Sized>(rng: &mut R) -> (T, T) {
+    // `gen` samples from [0, 1), so flip to avoid taking the logarithm of 0
+    let u1 = 1. - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    let radius = (-2. * u1.ln()).sqrt();
+    let (sin, cos) = (std::f64::consts::TAU * u2).sin_cos();
+    (
+        T::from(radius * cos).unwrap(),
+        T::from(radius * sin).unwrap(),
+    )
+}
+
+// Split `n` sorted random values into two chains between the minimum and maximum value, and
+// return the differences between consecutive values along both chains. The differences sum to
+// zero.
+fn random_chain_components<T: GeoFloat, R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<T> {
+    let mut values: Vec<T> = (0..n).map(|_| random_fraction(rng)).collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let (min, max) = (values[0], values[n - 1]);
+    let (mut last_a, mut last_b) = (min, min);
+    let mut components = Vec::with_capacity(n);
+    for &value in &values[1..n - 1] {
+        if rng.gen::<bool>() {
+            components.push(value - last_a);
+            last_a = value;
+        } else {
+            components.push(last_b - value);
+            last_b = value;
+        }
+    }
+    components.push(max - last_a);
+    components.push(last_b - max);
+    components
+}
+
+// Scale and translate coordinates so that their bounding rectangle matches `bounds`.
+fn fit_to_bounds<T: GeoFloat>(coords: Vec<Coord<T>>, bounds: Rect<T>) -> Vec<Coord<T>> {
+    let current = match LineString::from(coords.clone()).bounding_rect() {
+        Some(rect) => rect,
+        None => return coords,
+    };
+    let scale = |target: T, extent: T| {
+        if extent > T::zero() {
+            target / extent
+        } else {
+            T::one()
+        }
+    };
+    let scale_x = scale(bounds.width(), current.width());
+    let scale_y = scale(bounds.height(), current.height());
+    coords
+        .into_iter()
+        .map(|c| Coord {
+            x: bounds.min().x + (c.x - current.min().x) * scale_x,
+            y: bounds.min().y + (c.y - current.min().y) * scale_y,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, Contains, Intersects, IsConvex, Winding};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn bounds() -> Rect {
+        Rect::new(coord! { x: -10., y: 5. }, coord! { x: 30., y: 25. })
+    }
+
+    fn is_simple(polygon: &Polygon) -> bool {
+        let lines: Vec<_> = polygon.exterior().lines().collect();
+        let n = lines.len();
+        (0..n).all(|i| {
+            (i + 2..n)
+                .filter(|&j| !(i == 0 && j == n - 1))
+                .all(|j| !lines[i].intersects(&lines[j]))
+        })
+    }
+
+    #[test]
+    fn convex() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for n in [3, 4, 10, 100] {
+            let polygon = convex_polygon(&mut rng, n, bounds());
+            assert_eq!(polygon.exterior().0.len(), n + 1);
+            assert!(polygon.exterior().is_convex());
+            assert!(polygon.exterior().is_ccw());
+            assert!(bounds().to_polygon().intersects(&polygon));
+            assert_relative_eq!(polygon.bounding_rect().unwrap(), bounds(), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn star() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let center = coord! { x: 1., y: 1. };
+        let polygon = star_polygon(&mut rng, 50, center, 1., 2.);
+        assert_eq!(polygon.exterior().0.len(), 51);
+        assert!(polygon.contains(&Point::from(center)));
+        assert!(is_simple(&polygon));
+    }
+
+    #[test]
+    fn simple() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for n in [3, 5, 50] {
+            let polygon = simple_polygon(&mut rng, n, bounds());
+            assert_eq!(polygon.exterior().0.len(), n + 1);
+            assert!(is_simple(&polygon));
+            assert!(polygon.exterior().coords().all(|c| bounds().intersects(c)));
+        }
+    }
+
+    #[test]
+    fn brownian() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let start = coord! { x: 3., y: 4. };
+        let ls = brownian_line_string(&mut rng, 100, start, 0.5);
+        assert_eq!(ls.0.len(), 100);
+        assert_eq!(ls.0[0], start);
+        assert!(brownian_line_string(&mut rng, 0, start, 0.5).0.is_empty());
+    }
+
+    #[test]
+    fn points() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let points = uniform_points(&mut rng, 100, bounds());
+        assert_eq!(points.0.len(), 100);
+        assert!(points.iter().all(|p| bounds().intersects(p)));
+    }
+}
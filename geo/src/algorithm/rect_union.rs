@@ -0,0 +1,423 @@
+use std::cmp::Ordering;
+
+use crate::area::get_linestring_area;
+use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, LineString, MultiPolygon, Polygon, Rect};
+
+/// Calculate the union of many axis-aligned rectangles, e.g. of the extents of tiles or of
+/// datasets, much faster than by unioning their polygons with [`BooleanOps`](crate::BooleanOps).
+///
+/// The rectangles are swept along the x axis, and then along the y axis, keeping track of the
+/// parts of the sweep line they cover in a segment tree, in `O((n + k) log n)` for `n`
+/// rectangles and `k` vertices of the union. The edges of the union are then linked into
+/// rings, and the holes are assigned to the smallest exterior ring around them.
+///
+/// The polygons of the union are rectilinear, without collinear or repeated vertices. Their
+/// exterior rings are wound counter-clockwise, and their interior rings clockwise. Polygons,
+/// and the holes of a polygon, may touch at a point, as may an interior ring and an exterior
+/// ring. Where the corners of two rectangles touch, the rectangles stay in separate polygons.
+/// Rectangles without an area are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::rect_union::rect_union;
+/// use geo::{polygon, Area, Rect};
+///
+/// let extents = vec![
+///     Rect::new((0., 0.), (2., 2.)),
+///     Rect::new((1., 1.), (3., 3.)),
+///     Rect::new((1., 1.), (2., 2.)),
+/// ];
+///
+/// let union = rect_union(&extents);
+/// assert_eq!(
+///     union.0,
+///     vec![polygon![
+///         (x: 0., y: 2.),
+///         (x: 0., y: 0.),
+///         (x: 2., y: 0.),
+///         (x: 2., y: 1.),
+///         (x: 3., y: 1.),
+///         (x: 3., y: 3.),
+///         (x: 1., y: 3.),
+///         (x: 1., y: 2.),
+///     ]]
+/// );
+/// assert_eq!(union.unsigned_area(), 7.);
+/// ```
+pub fn rect_union<T: GeoFloat>(rects: &[Rect<T>]) -> MultiPolygon<T> {
+    // the vertical edges, then the horizontal ones, directed counter-clockwise around the union
+    let mut edges: Vec<(Coord<T>, Coord<T>)> = sweep(rects, false, true)
+        .1
+        .into_iter()
+        .map(|(x, from, to)| (Coord { x, y: from }, Coord { x, y: to }))
+        .collect();
+    edges.extend(
+        sweep(rects, true, true)
+            .1
+            .into_iter()
+            .map(|(y, from, to)| (Coord { x: to, y }, Coord { x: from, y })),
+    );
+
+    let (exteriors, interiors): (Vec<_>, Vec<_>) = link_rings(&edges)
+        .into_iter()
+        .map(|ring| (get_linestring_area(&ring), ring))
+        .partition(|(area, _)| *area > T::zero());
+
+    let mut holes = vec![vec![]; exteriors.len()];
+    for (_, interior) in interiors {
+        let inside = (interior.0[0] + interior.0[1]) / (T::one() + T::one());
+        let exterior = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, exterior))| {
+                coord_pos_relative_to_ring(inside, exterior) == CoordPos::Inside
+            })
+            .min_by(|(_, (a, _)), (_, (b, _))| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        if let Some((idx, _)) = exterior {
+            holes[idx].push(interior);
+        }
+    }
+
+    exteriors
+        .into_iter()
+        .zip(holes)
+        .map(|((_, exterior), interiors)| Polygon::new(exterior, interiors))
+        .collect()
+}
+
+/// Calculate the area covered by many axis-aligned rectangles, counting the areas where they
+/// overlap once, in `O(n log n)` for `n` rectangles, without computing their union.
+///
+/// # Examples
+///
+/// ```
+/// use geo::rect_union::rect_union_area;
+/// use geo::Rect;
+///
+/// let extents = vec![
+///     Rect::new((0., 0.), (2., 2.)),
+///     Rect::new((1., 1.), (3., 3.)),
+///     Rect::new((5., 5.), (6., 6.)),
+/// ];
+/// assert_eq!(rect_union_area(&extents), 8.);
+/// ```
+pub fn rect_union_area<T: GeoFloat>(rects: &[Rect<T>]) -> T {
+    sweep(rects, false, false).0
+}
+
+/// A segment tree over the intervals between consecutive `ys`, counting the rectangles
+/// covering each of its nodes entirely.
+struct CoverTree<T: GeoFloat> {
+    ys: Vec<T>,
+    count: Vec<usize>,
+    // the length covered under each node
+    covered: Vec<T>,
+}
+
+impl<T: GeoFloat> CoverTree<T> {
+    fn new(ys: Vec<T>) -> Self {
+        let nodes = 4 * ys.len();
+        CoverTree {
+            ys,
+            count: vec![0; nodes],
+            covered: vec![T::zero(); nodes],
+        }
+    }
+
+    fn leaves(&self) -> usize {
+        self.ys.len() - 1
+    }
+
+    /// Add, or remove, a rectangle covering the leaves `a..b`.
+    fn update(&mut self, node: usize, lo: usize, hi: usize, (a, b): (usize, usize), add: bool) {
+        if b <= lo || hi <= a {
+            return;
+        }
+        if a <= lo && hi <= b {
+            if add {
+                self.count[node] += 1;
+            } else {
+                self.count[node] -= 1;
+            }
+        } else {
+            let mid = (lo + hi) / 2;
+            self.update(2 * node + 1, lo, mid, (a, b), add);
+            self.update(2 * node + 2, mid, hi, (a, b), add);
+        }
+        self.covered[node] = if self.count[node] > 0 {
+            self.ys[hi] - self.ys[lo]
+        } else if hi - lo == 1 {
+            T::zero()
+        } else {
+            self.covered[2 * node + 1] + self.covered[2 * node + 2]
+        };
+    }
+
+    /// Push the maximal ranges of uncovered leaves within `a..b` onto `ranges`.
+    fn uncovered(
+        &self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        (a, b): (usize, usize),
+        ranges: &mut Vec<(usize, usize)>,
+    ) {
+        if b <= lo || hi <= a || self.count[node] > 0 {
+            return;
+        }
+        if self.covered[node] == T::zero() {
+            let range = (lo.max(a), hi.min(b));
+            match ranges.last_mut() {
+                Some(last) if last.1 == range.0 => last.1 = range.1,
+                _ => ranges.push(range),
+            }
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.uncovered(2 * node + 1, lo, mid, (a, b), ranges);
+        self.uncovered(2 * node + 2, mid, hi, (a, b), ranges);
+    }
+}
+
+/// Sweep the rectangles along the x axis, or the y axis if `swap`, returning the area they
+/// cover and, if `boundary`, the maximal pieces of the boundary of their union across the
+/// sweep, as `(x, from, to)`: going down where the union starts, and up where it ends.
+fn sweep<T: GeoFloat>(rects: &[Rect<T>], swap: bool, boundary: bool) -> (T, Vec<(T, T, T)>) {
+    let extents: Vec<_> = rects
+        .iter()
+        .map(|rect| {
+            let (min, max) = (rect.min(), rect.max());
+            if swap {
+                (min.y, max.y, min.x, max.x)
+            } else {
+                (min.x, max.x, min.y, max.y)
+            }
+        })
+        .filter(|(x_min, x_max, y_min, y_max)| x_min < x_max && y_min < y_max)
+        .collect();
+    if extents.is_empty() {
+        return (T::zero(), vec![]);
+    }
+
+    let mut ys: Vec<T> = extents
+        .iter()
+        .flat_map(|&(_, _, y_min, y_max)| [y_min, y_max])
+        .collect();
+    ys.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    ys.dedup();
+    let leaf = |y: T| ys.partition_point(|&other| other < y);
+    let leaves: Vec<_> = extents
+        .iter()
+        .map(|&(_, _, y_min, y_max)| (leaf(y_min), leaf(y_max)))
+        .collect();
+
+    // the rectangles starting, and ending, at each x
+    let mut events: Vec<(T, bool, usize)> = extents
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, &(x_min, x_max, _, _))| [(x_min, true, idx), (x_max, false, idx)])
+        .collect();
+    events.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let mut tree = CoverTree::new(ys);
+    let n = tree.leaves();
+    let mut area = T::zero();
+    let mut pieces = vec![];
+    let mut previous = events[0].0;
+    let mut start = 0;
+    while start < events.len() {
+        let x = events[start].0;
+        let end = start
+            + events[start..]
+                .iter()
+                .take_while(|event| event.0 == x)
+                .count();
+        let group = &events[start..end];
+        start = end;
+        area = area + tree.covered[0] * (x - previous);
+        previous = x;
+
+        // the ranges of leaves whose coverage may change
+        let mut ranges: Vec<(usize, usize)> = vec![];
+        if boundary {
+            let mut changed: Vec<_> = group.iter().map(|&(_, _, idx)| leaves[idx]).collect();
+            changed.sort_unstable();
+            for range in changed {
+                match ranges.last_mut() {
+                    Some(last) if range.0 <= last.1 => last.1 = last.1.max(range.1),
+                    _ => ranges.push(range),
+                }
+            }
+        }
+        let uncovered = |tree: &CoverTree<T>| {
+            let mut uncovered = vec![];
+            for &range in &ranges {
+                tree.uncovered(0, 0, n, range, &mut uncovered);
+            }
+            uncovered
+        };
+
+        let before = uncovered(&tree);
+        for &(_, add, idx) in group {
+            tree.update(0, 0, n, leaves[idx], add);
+        }
+        let after = uncovered(&tree);
+
+        for (lo, hi) in difference(&before, &after) {
+            pieces.push((x, tree.ys[hi], tree.ys[lo]));
+        }
+        for (lo, hi) in difference(&after, &before) {
+            pieces.push((x, tree.ys[lo], tree.ys[hi]));
+        }
+    }
+    (area, pieces)
+}
+
+/// The ranges of leaves in `a` but not in `b`, both sorted and disjoint.
+fn difference(a: &[(usize, usize)], b: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut difference = vec![];
+    let mut j = 0;
+    for &(lo, hi) in a {
+        while j < b.len() && b[j].1 <= lo {
+            j += 1;
+        }
+        let mut lo = lo;
+        let mut k = j;
+        while k < b.len() && b[k].0 < hi {
+            if b[k].0 > lo {
+                difference.push((lo, b[k].0));
+            }
+            lo = lo.max(b[k].1);
+            k += 1;
+        }
+        if lo < hi {
+            difference.push((lo, hi));
+        }
+    }
+    difference
+}
+
+/// Link directed edges into closed rings. Where two rings meet at a vertex, each edge is
+/// followed by the edge turning left from it, so that the area on the left of the rings is
+/// kept apart.
+fn link_rings<T: GeoFloat>(edges: &[(Coord<T>, Coord<T>)]) -> Vec<LineString<T>> {
+    let mut starts: Vec<usize> = (0..edges.len()).collect();
+    starts.sort_unstable_by(|&a, &b| lex_cmp(&edges[a].0, &edges[b].0));
+    let next = |edge: usize| {
+        let (start, end) = edges[edge];
+        let first = starts.partition_point(|&other| lex_cmp(&edges[other].0, &end).is_lt());
+        let outgoing = starts[first..]
+            .iter()
+            .take_while(|&&other| edges[other].0 == end)
+            .copied();
+        let incoming = end - start;
+        let mut next = None;
+        for other in outgoing {
+            let turn = edges[other].1 - end;
+            // the only edge, or the one turning left
+            if next.is_none() || incoming.x * turn.y - incoming.y * turn.x > T::zero() {
+                next = Some(other);
+            }
+        }
+        next.expect("the boundary of a union is closed")
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = vec![];
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut coords = vec![edges[first].0];
+        let mut edge = first;
+        loop {
+            used[edge] = true;
+            edge = next(edge);
+            if edge == first {
+                break;
+            }
+            coords.push(edges[edge].0);
+        }
+        coords.push(edges[first].0);
+        rings.push(LineString::new(coords));
+    }
+    rings
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, Normalize};
+
+    fn assert_union(rects: &[Rect<f64>], expected: Vec<Polygon<f64>>) {
+        let union = rect_union(rects);
+        assert_eq!(union.normalize(), MultiPolygon::new(expected).normalize());
+        assert_eq!(rect_union_area(rects), union.unsigned_area());
+    }
+
+    #[test]
+    fn merged() {
+        // sharing an edge, and overlapping
+        assert_union(
+            &[
+                Rect::new((0., 0.), (1., 1.)),
+                Rect::new((1., 0.), (2., 1.)),
+                Rect::new((0.5, 0.), (1.5, 1.)),
+            ],
+            vec![Rect::new((0., 0.), (2., 1.)).to_polygon()],
+        );
+        // touching at a corner
+        assert_union(
+            &[Rect::new((0., 0.), (1., 1.)), Rect::new((1., 1.), (2., 2.))],
+            vec![
+                Rect::new((0., 0.), (1., 1.)).to_polygon(),
+                Rect::new((1., 1.), (2., 2.)).to_polygon(),
+            ],
+        );
+    }
+
+    #[test]
+    fn holes_and_islands() {
+        let rects = [
+            // a frame
+            Rect::new((0., 0.), (9., 1.)),
+            Rect::new((0., 8.), (9., 9.)),
+            Rect::new((0., 0.), (1., 9.)),
+            Rect::new((8., 0.), (9., 9.)),
+            // an island in it
+            Rect::new((4., 4.), (5., 5.)),
+            // a nook in the frame
+            Rect::new((1., 1.), (2., 2.)),
+            // without an area
+            Rect::new((3., 3.), (3., 7.)),
+        ];
+        assert_union(
+            &rects,
+            vec![
+                polygon![
+                    exterior: [(x: 0., y: 0.), (x: 9., y: 0.), (x: 9., y: 9.), (x: 0., y: 9.)],
+                    interiors: [[
+                        (x: 2., y: 1.),
+                        (x: 8., y: 1.),
+                        (x: 8., y: 8.),
+                        (x: 1., y: 8.),
+                        (x: 1., y: 2.),
+                        (x: 2., y: 2.),
+                    ]],
+                ],
+                Rect::new((4., 4.), (5., 5.)).to_polygon(),
+            ],
+        );
+        assert_eq!(rect_union_area(&rects), 81. - 48. + 1.);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(rect_union::<f64>(&[]).0.is_empty());
+        assert_eq!(rect_union_area::<f64>(&[]), 0.);
+        assert!(rect_union(&[Rect::new((0., 0.), (0., 1.))]).0.is_empty());
+    }
+}
@@ -20,6 +20,7 @@ use crate::{coordinate_position::CoordPos, dimensions::Dimensions};
 /// - Wikipedia article on [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM)
 ///
 /// This implementation is heavily based on that from the [JTS project](https://github.com/locationtech/jts/blob/master/modules/core/src/main/java/org/locationtech/jts/geom/IntersectionMatrix.java).
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone)]
 pub struct IntersectionMatrix(LocationArray<LocationArray<Dimensions>>);
 
@@ -30,6 +31,7 @@ pub struct IntersectionMatrix(LocationArray<LocationArray<Dimensions>>);
 ///
 /// So we can't simply `CoordPos as usize` without losing the conventional ordering
 /// of elements, which is useful for debug / interop.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy)]
 struct LocationArray<T>([T; 3]);
 
@@ -220,6 +222,19 @@ impl IntersectionMatrix {
             && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
     }
 
+    /// Tests whether this matrix matches `[T*F**FFF*]`.
+    ///
+    /// returns `true` if the two geometries are topologically equal, i.e. consist of the same
+    /// points. Two empty geometries are equal too, but their matrix doesn't show it: see
+    /// [`EqualsTopo`](crate::EqualsTopo).
+    pub fn is_equal_topo(&self) -> bool {
+        self.0[CoordPos::Inside][CoordPos::Inside] != Dimensions::Empty
+            && self.0[CoordPos::Inside][CoordPos::Outside] == Dimensions::Empty
+            && self.0[CoordPos::OnBoundary][CoordPos::Outside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::Inside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
+    }
+
     /// Directly accesses this matrix
     ///
     /// ```
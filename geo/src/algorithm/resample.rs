@@ -0,0 +1,270 @@
+use crate::{
+    Coord, CoordFloat, EuclideanLength, HaversineIntermediate, HaversineLength, Line, LineString,
+    Point,
+};
+
+/// Resample a [`LineString`] into evenly spaced coordinates along it, e.g. to bring two
+/// trajectories recorded at different rates to the same sampling before comparing them with
+/// [`FrechetDistance`](crate::FrechetDistance) or dynamic time warping.
+///
+/// The new coordinates are interpolated along the line string, so its corners are cut unless a
+/// new coordinate happens to fall on them. The first and last coordinates are kept.
+///
+/// The euclidean methods interpolate linearly, and the haversine methods along great circles,
+/// with distances in meters, for line strings of longitudes and latitudes.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Resample};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.)];
+///
+/// assert_eq!(
+///     line_string.resample(4),
+///     line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 3., y: 1.), (x: 3., y: 3.)]
+/// );
+/// assert_eq!(
+///     line_string.resample_by_distance(4.),
+///     line_string![(x: 0., y: 0.), (x: 3., y: 1.), (x: 3., y: 3.)]
+/// );
+/// ```
+pub trait Resample<T: CoordFloat> {
+    /// Resample into `n_points` coordinates, evenly spaced along the euclidean length of the
+    /// line string.
+    ///
+    /// Asking for a single point gives the first coordinate, and an empty line string stays
+    /// empty.
+    fn resample(&self, n_points: usize) -> LineString<T>;
+
+    /// Resample into coordinates `spacing` apart along the euclidean length of the line
+    /// string, from its start. The last coordinate is kept, so the last spacing may be shorter.
+    ///
+    /// Note: `spacing` must be greater than 0.
+    fn resample_by_distance(&self, spacing: T) -> LineString<T>;
+
+    /// Resample into `n_points` coordinates, evenly spaced along the haversine length of the
+    /// line string.
+    ///
+    /// Asking for a single point gives the first coordinate, and an empty line string stays
+    /// empty.
+    fn haversine_resample(&self, n_points: usize) -> LineString<T>
+    where
+        Line<T>: HaversineLength<T>,
+        Point<T>: HaversineIntermediate<T>;
+
+    /// Resample into coordinates `spacing` meters apart along the haversine length of the line
+    /// string, from its start. The last coordinate is kept, so the last spacing may be shorter.
+    ///
+    /// Note: `spacing` must be greater than 0.
+    fn haversine_resample_by_distance(&self, spacing: T) -> LineString<T>
+    where
+        Line<T>: HaversineLength<T>,
+        Point<T>: HaversineIntermediate<T>;
+}
+
+impl<T: CoordFloat> Resample<T> for LineString<T> {
+    fn resample(&self, n_points: usize) -> LineString<T> {
+        resample_count(self, n_points, euclidean_length, euclidean_interpolate)
+    }
+
+    fn resample_by_distance(&self, spacing: T) -> LineString<T> {
+        resample_spacing(self, spacing, euclidean_length, euclidean_interpolate)
+    }
+
+    fn haversine_resample(&self, n_points: usize) -> LineString<T>
+    where
+        Line<T>: HaversineLength<T>,
+        Point<T>: HaversineIntermediate<T>,
+    {
+        resample_count(self, n_points, haversine_length, haversine_interpolate)
+    }
+
+    fn haversine_resample_by_distance(&self, spacing: T) -> LineString<T>
+    where
+        Line<T>: HaversineLength<T>,
+        Point<T>: HaversineIntermediate<T>,
+    {
+        resample_spacing(self, spacing, haversine_length, haversine_interpolate)
+    }
+}
+
+fn euclidean_length<T: CoordFloat>(line: Line<T>) -> T {
+    line.euclidean_length()
+}
+
+fn euclidean_interpolate<T: CoordFloat>(line: Line<T>, fraction: T) -> Coord<T> {
+    line.start + line.delta() * fraction
+}
+
+fn haversine_length<T: CoordFloat>(line: Line<T>) -> T
+where
+    Line<T>: HaversineLength<T>,
+{
+    line.haversine_length()
+}
+
+fn haversine_interpolate<T: CoordFloat>(line: Line<T>, fraction: T) -> Coord<T>
+where
+    Point<T>: HaversineIntermediate<T>,
+{
+    let (start, end) = line.points();
+    start.haversine_intermediate(&end, fraction).into()
+}
+
+fn resample_count<T: CoordFloat>(
+    line_string: &LineString<T>,
+    n_points: usize,
+    length: impl Fn(Line<T>) -> T,
+    interpolate: impl Fn(Line<T>, T) -> Coord<T>,
+) -> LineString<T> {
+    if n_points < 2 {
+        return line_string.0.iter().take(n_points).copied().collect();
+    }
+    let total = line_string
+        .lines()
+        .fold(T::zero(), |total, line| total + length(line));
+    let spacing = total / T::from(n_points - 1).unwrap();
+    resample_at(line_string, n_points - 1, spacing, length, interpolate)
+}
+
+fn resample_spacing<T: CoordFloat>(
+    line_string: &LineString<T>,
+    spacing: T,
+    length: impl Fn(Line<T>) -> T,
+    interpolate: impl Fn(Line<T>, T) -> Coord<T>,
+) -> LineString<T> {
+    assert!(spacing > T::zero());
+    let total = line_string
+        .lines()
+        .fold(T::zero(), |total, line| total + length(line));
+    let n_spaced = (total / spacing).ceil().to_usize().unwrap();
+    resample_at(line_string, n_spaced, spacing, length, interpolate)
+}
+
+// The coordinates at `n_spaced` multiples of `spacing` along the line string, from zero, followed
+// by its last coordinate.
+fn resample_at<T: CoordFloat>(
+    line_string: &LineString<T>,
+    n_spaced: usize,
+    spacing: T,
+    length: impl Fn(Line<T>) -> T,
+    interpolate: impl Fn(Line<T>, T) -> Coord<T>,
+) -> LineString<T> {
+    let last = match line_string.0.last() {
+        Some(last) => *last,
+        None => return LineString::new(vec![]),
+    };
+    let mut lines = line_string.lines().map(|line| (line, length(line)));
+    let mut current = lines.next();
+    let mut travelled = T::zero();
+    let mut coords = Vec::with_capacity(n_spaced + 1);
+    for i in 0..n_spaced {
+        let distance = spacing * T::from(i).unwrap();
+        let coord = loop {
+            match current {
+                Some((_, length)) if travelled + length < distance => {
+                    travelled = travelled + length;
+                    current = lines.next();
+                }
+                // zero length segments are only reached at their start
+                Some((line, length)) if length > T::zero() => {
+                    let fraction = (distance - travelled) / length;
+                    break interpolate(line, fraction.min(T::one()));
+                }
+                Some((line, _)) => break line.start,
+                // past the end, by rounding
+                None => break last,
+            }
+        };
+        coords.push(coord);
+    }
+    coords.push(last);
+    LineString::new(coords)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, HaversineDistance};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn by_count() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            // a repeated coordinate
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+        ];
+        assert_eq!(
+            line_string.resample(4),
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.)]
+        );
+        assert_eq!(
+            line_string.resample(2),
+            line_string![(x: 0., y: 0.), (x: 4., y: 2.)]
+        );
+        assert_eq!(line_string.resample(1), line_string![(x: 0., y: 0.)]);
+        assert_eq!(line_string.resample(0), line_string![]);
+
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.resample(3), line_string![]);
+        let point = line_string![(x: 1., y: 1.)];
+        assert_eq!(
+            point.resample(3),
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.), (x: 1., y: 1.)]
+        );
+    }
+
+    #[test]
+    fn by_distance() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)];
+        assert_eq!(
+            line_string.resample_by_distance(4.),
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 5., y: 3.), (x: 5., y: 5.)]
+        );
+        // the spacing divides the length
+        assert_eq!(
+            line_string.resample_by_distance(5.),
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)]
+        );
+        assert_eq!(
+            line_string.resample_by_distance(20.),
+            line_string![(x: 0., y: 0.), (x: 5., y: 5.)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_spacing() {
+        line_string![(x: 0., y: 0.), (x: 1., y: 0.)].resample_by_distance(0.);
+    }
+
+    #[test]
+    fn haversine() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let resampled = line_string.haversine_resample(5);
+        assert_eq!(resampled.0.len(), 5);
+        assert_relative_eq!(resampled[2], coord! { x: 10., y: 0. }, epsilon = 1e-9);
+        let points: Vec<Point<f64>> = resampled.points().collect();
+        let spacing = points[0].haversine_distance(&points[1]);
+        for pair in points.windows(2) {
+            assert_relative_eq!(
+                pair[0].haversine_distance(&pair[1]),
+                spacing,
+                max_relative = 1e-9
+            );
+        }
+
+        let resampled = line_string.haversine_resample_by_distance(1_000_000.);
+        let length = line_string.haversine_length();
+        assert_eq!(resampled.0.len(), (length / 1_000_000.).ceil() as usize + 1);
+        assert_relative_eq!(
+            Point::from(resampled[0]).haversine_distance(&Point::from(resampled[1])),
+            1_000_000.,
+            max_relative = 1e-6
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoNum, Polygon};
+
+/// Rotate a pair of parallel calipers around two convex polygons at once, one caliper
+/// supporting each polygon from opposite sides, as in [Toussaint (1983)], and iterate over the
+/// pairs of vertices they touch, by index into the exteriors of the polygons.
+///
+/// These are the pairs of vertices with parallel lines of support facing away from one another:
+/// the antipodal pairs of the two polygons, or, of a polygon and itself, the antipodal pairs
+/// of its vertices. When the calipers lie along parallel edges, only the pairs of the vertices
+/// they start and end at are included, as the pairs across the edges are never farther apart.
+/// The farthest pair of points of the polygons is among them, so they're how
+/// [`MaxDistance`](crate::MaxDistance) and [`Diameter`](crate::Diameter) measure geometries.
+///
+/// There are at most as many pairs as the polygons have vertices in all, so they're found in
+/// linear time. The polygons must be convex, with counter-clockwise exteriors and no repeated
+/// or collinear vertices, like those returned by [`ConvexHull`](crate::ConvexHull). If either
+/// has fewer than three vertices, every pair of their vertices is returned instead.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, RotatingCalipers};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let triangle = polygon![(x: 3., y: 0.), (x: 4., y: 0.), (x: 3., y: 1.)];
+///
+/// let mut pairs: Vec<_> = RotatingCalipers::new(&square, &triangle).collect();
+/// pairs.sort();
+/// assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2), (2, 0), (3, 1)]);
+///
+/// // opposite corners of the square, both ways
+/// let mut pairs: Vec<_> = RotatingCalipers::new(&square, &square).collect();
+/// pairs.sort();
+/// assert_eq!(pairs, vec![(0, 2), (1, 3), (2, 0), (3, 1)]);
+/// ```
+///
+/// [Toussaint (1983)]: https://doi.org/10.1109/MELCON.1983.6315043
+#[derive(Debug, Clone)]
+pub struct RotatingCalipers<'a, T: GeoNum> {
+    a: &'a [Coord<T>],
+    b: &'a [Coord<T>],
+    a_start: usize,
+    b_start: usize,
+    // the number of vertices of `a` and `b` the calipers have gone past
+    i: usize,
+    j: usize,
+}
+
+impl<'a, T: GeoNum> RotatingCalipers<'a, T> {
+    /// Rotate the calipers around `a` and `b`, from their vertices supported by a vertical
+    /// caliper on the left of `a` and the right of `b`.
+    pub fn new(a: &'a Polygon<T>, b: &'a Polygon<T>) -> Self {
+        let (a, b) = (vertices(a), vertices(b));
+        let extreme = |coords: &[Coord<T>], lowest: bool| {
+            let indices = 0..coords.len();
+            let by_coord = |i: &usize, j: &usize| lex_cmp(&coords[*i], &coords[*j]);
+            if lowest {
+                indices.min_by(by_coord)
+            } else {
+                indices.max_by(by_coord)
+            }
+            .unwrap_or(0)
+        };
+        RotatingCalipers {
+            a,
+            b,
+            a_start: extreme(a, true),
+            b_start: extreme(b, false),
+            i: 0,
+            j: 0,
+        }
+    }
+
+    fn is_degenerate(&self) -> bool {
+        self.a.len() < 3 || self.b.len() < 3
+    }
+}
+
+impl<'a, T: GeoNum> Iterator for RotatingCalipers<'a, T> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (n, m) = (self.a.len(), self.b.len());
+        if self.is_degenerate() {
+            // every pair, in order
+            if self.i == n || m == 0 {
+                return None;
+            }
+            let pair = (self.i, self.j);
+            self.j += 1;
+            if self.j == m {
+                self.i += 1;
+                self.j = 0;
+            }
+            return Some(pair);
+        }
+        if self.i == n && self.j == m {
+            return None;
+        }
+
+        let (p, q) = ((self.a_start + self.i) % n, (self.b_start + self.j) % m);
+        // the calipers turn to the next edge of either polygon, by the edge turning least
+        let a_edge = self.a[(p + 1) % n] - self.a[p];
+        let b_edge = self.b[(q + 1) % m] - self.b[q];
+        let a_first = a_edge.x * b_edge.y;
+        let b_first = a_edge.y * b_edge.x;
+        if self.j == m || (self.i < n && a_first < b_first) {
+            self.i += 1;
+        } else if self.i == n || b_first < a_first {
+            self.j += 1;
+        } else {
+            // parallel edges
+            self.i += 1;
+            self.j += 1;
+        }
+        Some((p, q))
+    }
+}
+
+// The vertices of the exterior of a polygon, without repeating the first.
+fn vertices<T: GeoNum>(polygon: &Polygon<T>) -> &[Coord<T>] {
+    let coords = &polygon.exterior().0[..];
+    match coords {
+        [first, .., last] if first == last => &coords[..coords.len() - 1],
+        _ => coords,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, ConvexHull, LineString, MultiPoint};
+
+    #[test]
+    fn antipodal_pairs() {
+        let hexagon = polygon![
+            (x: 2., y: 0.),
+            (x: 4., y: 1.),
+            (x: 4., y: 3.),
+            (x: 2., y: 4.),
+            (x: 0., y: 3.),
+            (x: 0., y: 1.),
+        ];
+        let mut pairs: Vec<_> = RotatingCalipers::new(&hexagon, &hexagon).collect();
+        pairs.sort_unstable();
+        // opposite vertices, along the parallel opposite edges
+        assert_eq!(pairs, vec![(0, 3), (1, 4), (2, 5), (3, 0), (4, 1), (5, 2)]);
+
+        let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 3.)];
+        let mut pairs: Vec<_> = RotatingCalipers::new(&triangle, &triangle).collect();
+        pairs.sort_unstable();
+        // each vertex against the opposite edge, both ways
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn degenerate() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let segment = MultiPoint::from(vec![(3., 3.), (5., 3.)]).convex_hull();
+        let pairs: Vec<_> = RotatingCalipers::new(&segment, &square).collect();
+        assert_eq!(pairs.len(), 8);
+        assert_eq!(pairs[..2], [(0, 0), (0, 1)]);
+
+        let point = MultiPoint::from(vec![(3., 3.)]).convex_hull();
+        let pairs: Vec<_> = RotatingCalipers::new(&square, &point).collect();
+        assert_eq!(pairs, vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(RotatingCalipers::new(&empty, &square).next(), None);
+        assert_eq!(RotatingCalipers::new(&square, &empty).next(), None);
+    }
+}
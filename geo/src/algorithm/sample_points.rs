@@ -0,0 +1,170 @@
+use crate::monotone::triangulate;
+use crate::{Area, Coord, GeoFloat, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle};
+
+use rand::Rng;
+
+/// Sample points uniformly at random from the interior of a geometry.
+///
+/// The geometry is first triangulated, then for every sample a triangle is selected with a
+/// probability proportional to its area, and a point is drawn uniformly from that triangle. No
+/// samples are rejected, so the running time does not depend on the shape of the geometry.
+///
+/// Geometries with zero area produce an empty `MultiPoint`.
+///
+/// Requires the `use-rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Contains, SamplePoints};
+/// use rand::thread_rng;
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 3.),
+///     (x: 2., y: 1.),
+///     (x: 0., y: 3.),
+/// ];
+///
+/// let samples = polygon.sample_points(100, &mut thread_rng());
+/// assert_eq!(samples.0.len(), 100);
+/// assert!(samples.iter().all(|p| polygon.contains(p)));
+/// ```
+pub trait SamplePoints<T: GeoFloat> {
+    fn sample_points<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T>;
+}
+
+impl<T: GeoFloat> SamplePoints<T> for Polygon<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T> {
+        sample_triangles(&triangulate(self), n, rng)
+    }
+}
+
+impl<T: GeoFloat> SamplePoints<T> for MultiPolygon<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T> {
+        let triangles: Vec<Triangle<T>> = self.iter().flat_map(triangulate).collect();
+        sample_triangles(&triangles, n, rng)
+    }
+}
+
+impl<T: GeoFloat> SamplePoints<T> for Rect<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T> {
+        if self.unsigned_area() == T::zero() {
+            return MultiPoint::new(vec![]);
+        }
+        let min = self.min();
+        (0..n)
+            .map(|_| {
+                Point::new(
+                    min.x + self.width() * random_fraction(rng),
+                    min.y + self.height() * random_fraction(rng),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> SamplePoints<T> for Triangle<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> MultiPoint<T> {
+        sample_triangles(&[*self], n, rng)
+    }
+}
+
+fn random_fraction<T: GeoFloat, R: Rng + ?Sized>(rng: &mut R) -> T {
+    T::from(rng.gen::<f64>()).unwrap()
+}
+
+fn sample_triangles<T: GeoFloat, R: Rng + ?Sized>(
+    triangles: &[Triangle<T>],
+    n: usize,
+    rng: &mut R,
+) -> MultiPoint<T> {
+    // cumulative areas, for area-weighted selection of a triangle
+    let mut total = T::zero();
+    let cumulative: Vec<T> = triangles
+        .iter()
+        .map(|triangle| {
+            total = total + triangle.unsigned_area();
+            total
+        })
+        .collect();
+    if total == T::zero() {
+        return MultiPoint::new(vec![]);
+    }
+
+    (0..n)
+        .map(|_| {
+            let target = total * random_fraction(rng);
+            let idx = cumulative
+                .partition_point(|area| *area <= target)
+                .min(triangles.len() - 1);
+            sample_triangle(triangles[idx], rng)
+        })
+        .collect()
+}
+
+fn sample_triangle<T: GeoFloat, R: Rng + ?Sized>(triangle: Triangle<T>, rng: &mut R) -> Point<T> {
+    let mut u: T = random_fraction(rng);
+    let mut v: T = random_fraction(rng);
+    // reflect samples from the far half of the parallelogram back into the triangle
+    if u + v > T::one() {
+        u = T::one() - u;
+        v = T::one() - v;
+    }
+    let Triangle(a, b, c) = triangle;
+    let coord: Coord<T> = a + (b - a) * u + (c - a) * v;
+    coord.into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Intersects};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn samples_lie_inside_polygon_with_hole() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        let mut rng = StdRng::seed_from_u64(42);
+        let samples = poly.sample_points(500, &mut rng);
+        assert_eq!(samples.0.len(), 500);
+        assert!(samples.iter().all(|p| poly.intersects(p)));
+    }
+
+    #[test]
+    fn samples_are_area_weighted() {
+        let small = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let large = polygon![(x: 10., y: 0.), (x: 13., y: 0.), (x: 13., y: 3.), (x: 10., y: 3.)];
+        let multi = MultiPolygon::new(vec![small.clone(), large]);
+        let mut rng = StdRng::seed_from_u64(7);
+        let samples = multi.sample_points(10_000, &mut rng);
+        let in_small = samples.iter().filter(|p| small.intersects(*p)).count();
+        // expected fraction is 1/10
+        assert!((800..1200).contains(&in_small), "{}", in_small);
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let degenerate = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(degenerate.sample_points(10, &mut rng).0.is_empty());
+        assert!(MultiPolygon::<f64>::new(vec![])
+            .sample_points(10, &mut rng)
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn triangle_samples() {
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+        let mut rng = StdRng::seed_from_u64(1);
+        let samples = triangle.sample_points(100, &mut rng);
+        let polygon = triangle.to_polygon();
+        assert!(samples.iter().all(|p| polygon.intersects(p)));
+    }
+}
@@ -0,0 +1,163 @@
+use rstar::primitives::GeomWithData;
+use rstar::{RTree, RTreeObject};
+
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{GeoFloat, Line, MultiLineString};
+
+/// The position of a segment in a [`MultiLineString`]: the index of its line string, and its
+/// index among the segments of that line string.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SegmentId {
+    pub line: usize,
+    pub segment: usize,
+}
+
+/// An intersection of a segment of each of two [`MultiLineString`]s, as found by
+/// [`intersections_between`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentIntersection<T: GeoFloat> {
+    /// The segment of the first multi-line string.
+    pub a: SegmentId,
+    /// The segment of the second multi-line string.
+    pub b: SegmentId,
+    /// Where the segments meet: a point, or the segment along which they overlap.
+    pub intersection: LineIntersection<T>,
+}
+
+/// Find every intersection between the segments of `a` and those of `b`, e.g. to find where
+/// the roads of one dataset cross the rivers of another.
+///
+/// The segments of `b` are indexed in an R-tree, which each segment of `a` is looked up in,
+/// so this is much faster than testing every pair of segments. Intersections within `a`, or
+/// within `b`, aren't reported.
+///
+/// Every pair of intersecting segments is reported once, ordered by the segment of `a`, then
+/// that of `b`. Line strings meeting at a vertex are reported for each pair of segments
+/// sharing the vertex.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_intersection::LineIntersection;
+/// use geo::segment_intersections::{intersections_between, SegmentId};
+/// use geo::{coord, line_string, MultiLineString};
+///
+/// let roads = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 1.), (x: 4., y: 1.)],
+///     line_string![(x: 0., y: 3.), (x: 2., y: 3.), (x: 4., y: 3.)],
+/// ]);
+/// let rivers = MultiLineString::new(vec![line_string![(x: 3., y: 0.), (x: 3., y: 4.)]]);
+///
+/// let crossings = intersections_between(&roads, &rivers);
+/// assert_eq!(crossings.len(), 2);
+/// assert_eq!(crossings[1].a, SegmentId { line: 1, segment: 1 });
+/// assert_eq!(crossings[1].b, SegmentId { line: 0, segment: 0 });
+/// assert_eq!(
+///     crossings[1].intersection,
+///     LineIntersection::SinglePoint {
+///         intersection: coord! { x: 3., y: 3. },
+///         is_proper: true,
+///     }
+/// );
+/// ```
+pub fn intersections_between<T: GeoFloat>(
+    a: &MultiLineString<T>,
+    b: &MultiLineString<T>,
+) -> Vec<SegmentIntersection<T>> {
+    let tree: RTree<GeomWithData<Line<T>, SegmentId>> = RTree::bulk_load(
+        segments(b)
+            .map(|(id, line)| GeomWithData::new(line, id))
+            .collect(),
+    );
+
+    let mut intersections = vec![];
+    for (a_id, segment) in segments(a) {
+        let start = intersections.len();
+        intersections.extend(
+            tree.locate_in_envelope_intersecting(&segment.envelope())
+                .filter_map(|candidate| {
+                    let intersection = line_intersection(segment, *candidate.geom())?;
+                    Some(SegmentIntersection {
+                        a: a_id,
+                        b: candidate.data,
+                        intersection,
+                    })
+                }),
+        );
+        intersections[start..].sort_unstable_by_key(|intersection| intersection.b);
+    }
+    intersections
+}
+
+fn segments<T: GeoFloat>(
+    multi_line_string: &MultiLineString<T>,
+) -> impl Iterator<Item = (SegmentId, Line<T>)> + '_ {
+    multi_line_string
+        .iter()
+        .enumerate()
+        .flat_map(|(line, line_string)| {
+            line_string
+                .lines()
+                .enumerate()
+                .map(move |(segment, l)| (SegmentId { line, segment }, l))
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn crossings_and_overlaps() {
+        let a = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 4., y: 0.)],
+            line_string![(x: 0., y: 5.), (x: 4., y: 5.)],
+        ]);
+        let b = MultiLineString::new(vec![
+            line_string![(x: 10., y: 10.), (x: 11., y: 11.)],
+            // crossing both segments of the first line string
+            line_string![(x: 0., y: 1.), (x: 4., y: 1.)],
+            // overlapping the second line string, through the vertex of the first
+            line_string![(x: 2., y: 2.), (x: 2., y: 5.), (x: 3., y: 5.), (x: 6., y: 5.)],
+        ]);
+
+        let found: Vec<_> = intersections_between(&a, &b)
+            .into_iter()
+            .map(|i| {
+                (
+                    (i.a.line, i.a.segment),
+                    (i.b.line, i.b.segment),
+                    i.intersection,
+                )
+            })
+            .collect();
+        let point = |x: f64, y: f64| LineIntersection::SinglePoint {
+            intersection: (x, y).into(),
+            is_proper: false,
+        };
+        let proper = |x: f64, y: f64| LineIntersection::SinglePoint {
+            intersection: (x, y).into(),
+            is_proper: true,
+        };
+        let overlap = |x0: f64, x1: f64| LineIntersection::Collinear {
+            intersection: Line::new((x0, 5.), (x1, 5.)),
+        };
+        assert_eq!(
+            found,
+            vec![
+                ((0, 0), (1, 0), proper(1., 1.)),
+                ((0, 0), (2, 0), point(2., 2.)),
+                ((0, 1), (1, 0), proper(3., 1.)),
+                ((0, 1), (2, 0), point(2., 2.)),
+                ((1, 0), (2, 0), point(2., 5.)),
+                ((1, 0), (2, 1), overlap(2., 3.)),
+                ((1, 0), (2, 2), overlap(3., 4.)),
+            ]
+        );
+
+        assert!(intersections_between(&a, &MultiLineString::new(vec![])).is_empty());
+    }
+}
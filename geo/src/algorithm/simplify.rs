@@ -1,5 +1,11 @@
-use crate::{Coord, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon};
-use crate::{CoordsIter, EuclideanDistance};
+use crate::CoordsIter;
+use crate::{
+    Coord, CoordNum, GeoFloat, Line, LineString, LineStringZM, MultiLineString, MultiPolygon,
+    Polygon,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use geo_types::private_utils::point_line_euclidean_distance;
 
 // Because the RDP algorithm is recursive, we can't assign an index to a point inside the loop
 // instead, we wrap a simple struct around index and point in a wrapper function,
@@ -14,7 +20,7 @@ where
 }
 
 // Wrapper for the RDP algorithm, returning simplified points
-fn rdp<T>(coords: impl Iterator<Item = Coord<T>>, epsilon: &T) -> Vec<Coord<T>>
+pub(crate) fn rdp<T>(coords: impl Iterator<Item = Coord<T>>, epsilon: &T) -> Vec<Coord<T>>
 where
     T: GeoFloat,
 {
@@ -72,7 +78,12 @@ where
         .enumerate()
         .take(rdp_indices.len() - 1) // Don't include the last index
         .skip(1) // Don't include the first index
-        .map(|(index, rdp_index)| (index, rdp_index.coord.euclidean_distance(&first_last_line)))
+        .map(|(index, rdp_index)| {
+            (
+                index,
+                point_line_euclidean_distance(rdp_index.coord, first_last_line),
+            )
+        })
         .fold(
             (0usize, T::zero()),
             |(farthest_index, farthest_distance), (index, distance)| {
@@ -209,6 +220,22 @@ where
     }
 }
 
+/// The coordinates are simplified in two dimensions, and keep their Z and M values.
+impl<T> Simplify<T> for LineStringZM<T>
+where
+    T: GeoFloat,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        let indices = self.xy().simplify_idx(epsilon);
+        let select = |values: &[T]| indices.iter().map(|&i| values[i]).collect::<Vec<_>>();
+        LineStringZM::new(
+            indices.iter().map(|&i| self.xy().0[i]).collect(),
+            self.z().map(select),
+            self.m().map(select),
+        )
+    }
+}
+
 impl<T> Simplify<T> for MultiLineString<T>
 where
     T: GeoFloat,
@@ -242,6 +269,147 @@ where
     }
 }
 
+/// Simplifies a geometry in place, truncating its coordinate `Vec`s.
+///
+/// The result is the same as [`Simplify`]'s, but the retained coordinates are moved to the
+/// front of the existing storage, whose capacity is kept, instead of being copied into a new
+/// geometry.
+///
+/// An epsilon less than or equal to zero leaves the geometry unaltered.
+pub trait SimplifyMut<T, Epsilon = T> {
+    /// Simplifies a geometry in place, using the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::SimplifyMut;
+    /// use geo::line_string;
+    ///
+    /// let mut line_string = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 4.0),
+    ///     (x: 11.0, y: 5.5),
+    ///     (x: 17.3, y: 3.2),
+    ///     (x: 27.8, y: 0.1),
+    /// ];
+    ///
+    /// line_string.simplify_mut(&1.0);
+    ///
+    /// let expected = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 4.0),
+    ///     (x: 11.0, y: 5.5),
+    ///     (x: 27.8, y: 0.1),
+    /// ];
+    ///
+    /// assert_eq!(expected, line_string);
+    /// assert!(line_string.0.capacity() >= 5);
+    /// ```
+    fn simplify_mut(&mut self, epsilon: &T)
+    where
+        T: GeoFloat;
+}
+
+// Push the indices of the coordinates RDP retains, except the last one, offset by `offset`.
+// This is `compute_rdp` working on a slice of the coordinates, so that they aren't copied.
+fn push_rdp_indices<T>(coords: &[Coord<T>], offset: usize, epsilon: &T, indices: &mut Vec<usize>)
+where
+    T: GeoFloat,
+{
+    let first_last_line = Line::new(coords[0], coords[coords.len() - 1]);
+    let (farthest_index, farthest_distance) = coords
+        .iter()
+        .enumerate()
+        .take(coords.len() - 1)
+        .skip(1)
+        .map(|(index, coord)| {
+            (
+                index,
+                point_line_euclidean_distance(*coord, first_last_line),
+            )
+        })
+        .fold(
+            (0usize, T::zero()),
+            |(farthest_index, farthest_distance), (index, distance)| {
+                if distance > farthest_distance {
+                    (index, distance)
+                } else {
+                    (farthest_index, farthest_distance)
+                }
+            },
+        );
+    if farthest_distance > *epsilon {
+        push_rdp_indices(&coords[..=farthest_index], offset, epsilon, indices);
+        push_rdp_indices(
+            &coords[farthest_index..],
+            offset + farthest_index,
+            epsilon,
+            indices,
+        );
+    } else {
+        indices.push(offset);
+    }
+}
+
+/// Move the coordinates at the (increasing) `indices` to the front, and drop the others.
+pub(crate) fn retain_indices<T: CoordNum>(line_string: &mut LineString<T>, indices: &[usize]) {
+    for (retained, &index) in indices.iter().enumerate() {
+        line_string.0[retained] = line_string.0[index];
+    }
+    line_string.0.truncate(indices.len());
+}
+
+impl<T> SimplifyMut<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_mut(&mut self, epsilon: &T) {
+        if *epsilon <= T::zero() || self.0.len() < 3 {
+            return;
+        }
+        let mut indices = vec![];
+        push_rdp_indices(&self.0, 0, epsilon, &mut indices);
+        indices.push(self.0.len() - 1);
+        retain_indices(self, &indices);
+    }
+}
+
+impl<T> SimplifyMut<T> for MultiLineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_mut(&mut self, epsilon: &T) {
+        for line_string in self.iter_mut() {
+            line_string.simplify_mut(epsilon);
+        }
+    }
+}
+
+impl<T> SimplifyMut<T> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_mut(&mut self, epsilon: &T) {
+        self.exterior_mut(|exterior| exterior.simplify_mut(epsilon));
+        self.interiors_mut(|interiors| {
+            for interior in interiors {
+                interior.simplify_mut(epsilon);
+            }
+        });
+    }
+}
+
+impl<T> SimplifyMut<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_mut(&mut self, epsilon: &T) {
+        for polygon in self.iter_mut() {
+            polygon.simplify_mut(epsilon);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -354,6 +522,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn simplify_mut_matches_simplify() {
+        let ls = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 5.0, y: 4.0),
+            (x: 11.0, y: 5.5),
+            (x: 17.3, y: 3.2),
+            (x: 27.8, y: 0.1),
+            (x: 30.0, y: 9.0),
+            (x: 31.0, y: 9.5),
+        ];
+        for epsilon in [-1., 0.5, 1., 3., 100.] {
+            let mut simplified = ls.clone();
+            simplified.simplify_mut(&epsilon);
+            assert_eq!(simplified, ls.simplify(&epsilon));
+            assert_eq!(simplified.0.capacity(), ls.0.len());
+        }
+
+        let mut mpoly = MultiPolygon::new(vec![polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 10.),
+            (x: 5., y: 11.),
+            (x: 10., y: 10.),
+            (x: 10., y: 0.),
+            (x: 0., y: 0.),
+        ]]);
+        let expected = mpoly.simplify(&2.);
+        mpoly.simplify_mut(&2.);
+        assert_eq!(mpoly, expected);
+
+        let mut short = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        short.simplify_mut(&1.);
+        assert_eq!(short, line_string![(x: 0., y: 0.), (x: 1., y: 1.)]);
+    }
+
     #[test]
     fn simplify_negative_epsilon() {
         let ls = line_string![
@@ -379,4 +582,25 @@ mod test {
         let indices = ls.simplify_idx(&-1.0);
         assert_eq!(vec![0usize, 1, 2, 3, 4], indices);
     }
+
+    #[test]
+    fn simplify_line_string_zm() {
+        let track = LineStringZM::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 5., y: 0.1),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+            ],
+            Some(vec![1., 2., 3., 4.]),
+            Some(vec![10., 20., 30., 40.]),
+        );
+        let simplified = track.simplify(&1.0);
+        assert_eq!(
+            simplified.xy(),
+            &line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]
+        );
+        assert_eq!(simplified.z(), Some(&[1., 3., 4.][..]));
+        assert_eq!(simplified.m(), Some(&[10., 30., 40.][..]));
+    }
 }
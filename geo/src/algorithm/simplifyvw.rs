@@ -1,3 +1,4 @@
+use crate::algorithm::simplify::retain_indices;
 use crate::prelude::*;
 use crate::{
     Coord, CoordFloat, HasKernel, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon,
@@ -664,10 +665,99 @@ where
     }
 }
 
+/// Simplifies a geometry in place, truncating its coordinate `Vec`s.
+///
+/// The result is the same as [`SimplifyVW`]'s, but the retained coordinates are moved to the
+/// front of the existing storage, whose capacity is kept, instead of being copied into a new
+/// geometry.
+///
+/// An epsilon less than or equal to zero leaves the geometry unaltered.
+pub trait SimplifyVwMut<T, Epsilon = T> {
+    /// Simplifies a geometry in place, using the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::SimplifyVwMut;
+    /// use geo::line_string;
+    ///
+    /// let mut line_string = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 3.0, y: 8.0),
+    ///     (x: 6.0, y: 20.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// line_string.simplify_vw_mut(&30.0);
+    ///
+    /// let expected = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// assert_eq!(expected, line_string);
+    /// ```
+    fn simplify_vw_mut(&mut self, epsilon: &T)
+    where
+        T: CoordFloat;
+}
+
+impl<T> SimplifyVwMut<T> for LineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_mut(&mut self, epsilon: &T) {
+        if *epsilon <= T::zero() {
+            return;
+        }
+        let indices = visvalingam_indices(self, epsilon);
+        retain_indices(self, &indices);
+    }
+}
+
+impl<T> SimplifyVwMut<T> for MultiLineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_mut(&mut self, epsilon: &T) {
+        for line_string in self.iter_mut() {
+            line_string.simplify_vw_mut(epsilon);
+        }
+    }
+}
+
+impl<T> SimplifyVwMut<T> for Polygon<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_mut(&mut self, epsilon: &T) {
+        self.exterior_mut(|exterior| exterior.simplify_vw_mut(epsilon));
+        self.interiors_mut(|interiors| {
+            for interior in interiors {
+                interior.simplify_vw_mut(epsilon);
+            }
+        });
+    }
+}
+
+impl<T> SimplifyVwMut<T> for MultiPolygon<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_mut(&mut self, epsilon: &T) {
+        for polygon in self.iter_mut() {
+            polygon.simplify_vw_mut(epsilon);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{
-        cartesian_intersect, visvalingam, vwp_wrapper, GeomSettings, SimplifyVW, SimplifyVWPreserve,
+        cartesian_intersect, visvalingam, vwp_wrapper, GeomSettings, SimplifyVW,
+        SimplifyVWPreserve, SimplifyVwMut,
     };
     use crate::{
         line_string, point, polygon, Coord, LineString, MultiLineString, MultiPolygon, Point,
@@ -890,6 +980,35 @@ mod test {
         );
     }
 
+    #[test]
+    fn simplify_vw_mut_matches_simplifyvw() {
+        let ls = line_string![
+            (x: 5.0, y: 2.0),
+            (x: 3.0, y: 8.0),
+            (x: 6.0, y: 20.0),
+            (x: 7.0, y: 25.0),
+            (x: 10.0, y: 10.0),
+        ];
+        for epsilon in [0., 1., 30., 1000.] {
+            let mut simplified = ls.clone();
+            simplified.simplify_vw_mut(&epsilon);
+            assert_eq!(simplified, ls.simplifyvw(&epsilon));
+            assert_eq!(simplified.0.capacity(), ls.0.len());
+        }
+
+        let mut poly = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 10.),
+            (x: 5., y: 11.),
+            (x: 10., y: 10.),
+            (x: 10., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        let expected = poly.simplifyvw(&10.);
+        poly.simplify_vw_mut(&10.);
+        assert_eq!(poly, expected);
+    }
+
     #[test]
     fn multipolygon() {
         let mpoly = MultiPolygon::new(vec![Polygon::new(
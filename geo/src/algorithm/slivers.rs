@@ -0,0 +1,212 @@
+use std::cmp::Ordering;
+use std::iter::Sum;
+
+use num_traits::float::FloatConst;
+
+use crate::area::get_linestring_area;
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{
+    Area, BooleanOps, BoundingRect, CoordFloat, EuclideanLength, GeoFloat, Intersects, LineString,
+    LinesIter, MultiPolygon, Perimeter, Polygon,
+};
+
+/// Measure how thin a polygon or a ring is, with the Polsby-Popper ratio of its area to its
+/// squared perimeter: `4πA / P²`.
+///
+/// The thinness of a circle is 1, the highest, and that of a square `π / 4`. It tends to 0 for
+/// long and narrow shapes, like the slivers left between the polygons of two layers which don't
+/// quite line up, and is 0 for shapes without a perimeter. Holes count towards both the area
+/// and the perimeter of a polygon.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Rect, Thinness};
+///
+/// let square = Rect::new((0., 0.), (2., 2.)).to_polygon();
+/// assert_eq!(square.thinness(), std::f64::consts::FRAC_PI_4);
+///
+/// let sliver = Rect::new((0., 0.), (10., 0.1)).to_polygon();
+/// assert!(sliver.thinness() < 0.05);
+/// ```
+pub trait Thinness<T: CoordFloat> {
+    /// The thinness ratio of the geometry, from 0 to 1.
+    fn thinness(&self) -> T;
+}
+
+impl<T: CoordFloat + FloatConst + Sum> Thinness<T> for LineString<T> {
+    /// The thinness of the ring, which should be closed.
+    fn thinness(&self) -> T {
+        thinness(get_linestring_area(self).abs(), self.euclidean_length())
+    }
+}
+
+impl<T: CoordFloat + FloatConst + Sum> Thinness<T> for Polygon<T> {
+    fn thinness(&self) -> T {
+        thinness(self.unsigned_area(), self.euclidean_perimeter())
+    }
+}
+
+fn thinness<T: CoordFloat + FloatConst>(area: T, perimeter: T) -> T {
+    if perimeter == T::zero() {
+        return T::zero();
+    }
+    (T::one() + T::one()) * T::TAU() * area / (perimeter * perimeter)
+}
+
+/// Clean the slivers out of polygons, e.g. out of the output of an overlay of two layers which
+/// don't quite line up: the polygons and holes whose [`Thinness`] is below a threshold.
+///
+/// Sliver holes are filled. Each sliver polygon is merged, by a union, into the polygon which
+/// isn't a sliver that shares the longest boundary with it, and dropped if there's none, e.g. if
+/// it only touches other slivers. The other polygons keep their order, and a polygon a sliver
+/// is merged into is replaced by the pieces of the union.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Area, MultiPolygon, Rect, RemoveSlivers};
+///
+/// let polygons: MultiPolygon = MultiPolygon::new(vec![
+///     Rect::new((0., 0.), (2., 2.)).to_polygon(),
+///     // along the right of the first polygon
+///     Rect::new((2., 0.), (2.05, 2.)).to_polygon(),
+///     // on its own
+///     Rect::new((5., 0.), (5.05, 2.)).to_polygon(),
+/// ]);
+///
+/// let cleaned = polygons.remove_slivers(0.2);
+/// assert_eq!(cleaned.0.len(), 1);
+/// assert!((cleaned.unsigned_area() - 4.1).abs() < 1e-9);
+/// ```
+pub trait RemoveSlivers<T: CoordFloat> {
+    /// The geometry without the polygons and holes thinner than `threshold`.
+    fn remove_slivers(&self, threshold: T) -> Self;
+}
+
+impl<T: GeoFloat + FloatConst + Sum> RemoveSlivers<T> for Polygon<T> {
+    /// The polygon with its sliver holes filled. The exterior is kept even if the polygon is a
+    /// sliver itself, since there's no polygon to merge it into.
+    fn remove_slivers(&self, threshold: T) -> Self {
+        let interiors = self
+            .interiors()
+            .iter()
+            .filter(|interior| interior.thinness() >= threshold)
+            .cloned()
+            .collect();
+        Polygon::new(self.exterior().clone(), interiors)
+    }
+}
+
+impl<T: GeoFloat + FloatConst + Sum> RemoveSlivers<T> for MultiPolygon<T> {
+    fn remove_slivers(&self, threshold: T) -> Self {
+        let (slivers, mut kept): (Vec<_>, Vec<_>) = self
+            .iter()
+            .map(|polygon| polygon.remove_slivers(threshold))
+            .partition(|polygon| polygon.thinness() < threshold);
+
+        for sliver in slivers {
+            let neighbour = kept
+                .iter()
+                .enumerate()
+                .map(|(idx, polygon)| (idx, shared_length(&sliver, polygon)))
+                .filter(|(_, length)| *length > T::zero())
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+            if let Some((idx, _)) = neighbour {
+                let merged = kept[idx].union(&sliver);
+                kept.splice(idx..=idx, merged.0);
+            }
+        }
+        MultiPolygon::new(kept)
+    }
+}
+
+/// The total length of the boundaries of two polygons which overlap.
+fn shared_length<T: GeoFloat>(a: &Polygon<T>, b: &Polygon<T>) -> T {
+    match (a.bounding_rect(), b.bounding_rect()) {
+        (Some(a_rect), Some(b_rect)) if a_rect.intersects(&b_rect) => {}
+        _ => return T::zero(),
+    }
+    let mut length = T::zero();
+    for a_line in a.lines_iter() {
+        for b_line in b.lines_iter() {
+            if let Some(LineIntersection::Collinear { intersection }) =
+                line_intersection(a_line, b_line)
+            {
+                length = length + intersection.euclidean_length();
+            }
+        }
+    }
+    length
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Rect};
+
+    #[test]
+    fn thinness() {
+        let square = Rect::new((0., 0.), (2., 2.)).to_polygon();
+        assert_relative_eq!(square.thinness(), std::f64::consts::FRAC_PI_4);
+        assert_relative_eq!(square.exterior().thinness(), std::f64::consts::FRAC_PI_4);
+
+        let circle: Polygon<f64> = Polygon::new(
+            (0..1000)
+                .map(|i| {
+                    let angle = i as f64 * std::f64::consts::TAU / 1000.;
+                    (angle.cos(), angle.sin())
+                })
+                .collect(),
+            vec![],
+        );
+        assert_relative_eq!(circle.thinness(), 1., epsilon = 1e-5);
+
+        // a hole makes it thinner
+        let framed = polygon![
+            exterior: [(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            interiors: [[(x: 0.5, y: 0.5), (x: 1.5, y: 0.5), (x: 1.5, y: 1.5), (x: 0.5, y: 1.5)]],
+        ];
+        assert!(framed.thinness() < square.thinness());
+
+        assert_eq!(
+            Polygon::<f64>::new(LineString::new(vec![]), vec![]).thinness(),
+            0.
+        );
+    }
+
+    #[test]
+    fn merges_and_drops_slivers() {
+        let square = Rect::new((0., 0.), (2., 2.)).to_polygon();
+        let holed = polygon![
+            exterior: [(x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 8.), (x: 0., y: 8.)],
+            interiors: [
+                // a sliver
+                [(x: 1., y: 5.), (x: 3., y: 5.), (x: 3., y: 5.05), (x: 1., y: 5.05)],
+                [(x: 1., y: 6.), (x: 2., y: 6.), (x: 2., y: 7.), (x: 1., y: 7.)],
+            ],
+        ];
+        let polygons = MultiPolygon::new(vec![
+            // along the top of the square, and of the next sliver
+            Rect::new((0., 2.), (2.1, 2.05)).to_polygon(),
+            square,
+            // along the right of the square only
+            Rect::new((2., 0.), (2.1, 2.)).to_polygon(),
+            holed.clone(),
+            // on its own
+            Rect::new((10., 0.), (10.05, 2.)).to_polygon(),
+        ]);
+
+        let cleaned = polygons.remove_slivers(0.2);
+        assert_eq!(cleaned.0.len(), 2);
+        // the square, with the slivers it shares a boundary with
+        assert_relative_eq!(cleaned.0[0].unsigned_area(), 4. + 2.1 * 0.05 + 0.2);
+        assert_eq!(
+            cleaned.0[1],
+            Polygon::new(holed.exterior().clone(), vec![holed.interiors()[1].clone()])
+        );
+
+        // nothing is thin enough
+        assert_eq!(polygons.remove_slivers(0.), polygons);
+    }
+}
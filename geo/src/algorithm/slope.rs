@@ -0,0 +1,144 @@
+use crate::{CoordFloat, EuclideanLength, LineStringZM};
+
+/// Measure the slopes of the segments of a [`LineStringZM`], from its Z values, such as the
+/// grades of a route.
+///
+/// The slope of a segment is its rise over its run: the change in elevation over its
+/// horizontal length, i.e. the length of the segment of its two-dimensional
+/// [`xy`](LineStringZM::xy) line string. It's positive going up, negative going down, and
+/// 0.1 for a 10% grade.
+///
+/// Every method returns `None` if the line string has no Z values.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LineStringZM, Slope};
+///
+/// let route = LineStringZM::new(
+///     line_string![(x: 0., y: 0.), (x: 100., y: 0.), (x: 100., y: 200.)],
+///     Some(vec![10., 15., 5.]),
+///     None,
+/// );
+///
+/// assert_eq!(route.segment_slopes(), Some(vec![0.05, -0.05]));
+/// assert_eq!(route.max_grade(), Some(0.05));
+///
+/// // the lengths going down, on the flat, and going up at 2% or more
+/// assert_eq!(route.length_by_grade(&[-0.02, 0.02]), Some(vec![200., 0., 100.]));
+/// ```
+pub trait Slope<T: CoordFloat> {
+    /// The slope of each segment, in order.
+    ///
+    /// Segments without horizontal length are vertical: their slope is infinite, with the sign
+    /// of their rise, or zero if they have no rise either.
+    fn segment_slopes(&self) -> Option<Vec<T>>;
+
+    /// The steepest grade of the segments, going up or down, as an absolute value.
+    ///
+    /// Vertical segments are ignored. Returns `None` if no segment has a horizontal length.
+    fn max_grade(&self) -> Option<T>;
+
+    /// The total horizontal length of the segments in each range of slopes delimited by
+    /// `breaks`, which must be in ascending order.
+    ///
+    /// There's one more range than breaks: the first holds the slopes less than the first
+    /// break, the second those from the first break up to the second, and so on, and the last
+    /// holds the slopes from the last break upwards.
+    fn length_by_grade(&self, breaks: &[T]) -> Option<Vec<T>>;
+}
+
+// the horizontal length and the slope of each segment
+fn runs_and_slopes<T: CoordFloat>(
+    line_string: &LineStringZM<T>,
+) -> Option<impl Iterator<Item = (T, T)> + '_> {
+    let z = line_string.z()?;
+    Some(line_string.xy().lines().zip(z.windows(2)).map(|(line, z)| {
+        let (run, rise) = (line.euclidean_length(), z[1] - z[0]);
+        let slope = if run > T::zero() {
+            rise / run
+        } else if rise == T::zero() {
+            T::zero()
+        } else {
+            rise.signum() * T::infinity()
+        };
+        (run, slope)
+    }))
+}
+
+impl<T: CoordFloat> Slope<T> for LineStringZM<T> {
+    fn segment_slopes(&self) -> Option<Vec<T>> {
+        Some(runs_and_slopes(self)?.map(|(_, slope)| slope).collect())
+    }
+
+    fn max_grade(&self) -> Option<T> {
+        runs_and_slopes(self)?
+            .filter(|&(run, _)| run > T::zero())
+            .map(|(_, slope)| slope.abs())
+            .fold(None, |max: Option<T>, grade| {
+                Some(max.map_or(grade, |max| max.max(grade)))
+            })
+    }
+
+    fn length_by_grade(&self, breaks: &[T]) -> Option<Vec<T>> {
+        let mut lengths = vec![T::zero(); breaks.len() + 1];
+        for (run, slope) in runs_and_slopes(self)? {
+            let range = breaks.iter().take_while(|&&b| b <= slope).count();
+            lengths[range] = lengths[range] + run;
+        }
+        Some(lengths)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn route() -> LineStringZM<f64> {
+        LineStringZM::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 3., y: 4.),
+                // a repeated coordinate, climbing vertically
+                (x: 3., y: 4.),
+                (x: 6., y: 8.),
+                (x: 6., y: 10.),
+            ],
+            Some(vec![0., 1., 5., 3., 3.]),
+            None,
+        )
+    }
+
+    #[test]
+    fn slopes() {
+        let route = route();
+        assert_eq!(
+            route.segment_slopes(),
+            Some(vec![0.2, f64::INFINITY, -0.4, 0.])
+        );
+        assert_eq!(route.max_grade(), Some(0.4));
+
+        let flat: LineStringZM<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into();
+        assert_eq!(flat.segment_slopes(), None);
+        assert_eq!(flat.max_grade(), None);
+
+        let vertical = LineStringZM::new(
+            line_string![(x: 0., y: 0.), (x: 0., y: 0.)],
+            Some(vec![1., 0.]),
+            None,
+        );
+        assert_eq!(vertical.segment_slopes(), Some(vec![f64::NEG_INFINITY]));
+        assert_eq!(vertical.max_grade(), None);
+    }
+
+    #[test]
+    fn lengths() {
+        let route = route();
+        assert_eq!(
+            route.length_by_grade(&[-0.1, 0., 0.1]),
+            Some(vec![5., 0., 2., 5.])
+        );
+        assert_eq!(route.length_by_grade(&[]), Some(vec![12.]));
+    }
+}
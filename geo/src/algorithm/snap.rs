@@ -0,0 +1,390 @@
+use std::cmp::Ordering;
+
+use crate::lines_iter::LinesIter;
+use crate::{Coord, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon};
+
+use rstar::primitives::GeomWithData;
+use rstar::{PointDistance, RTree};
+
+/// Snap the vertices of a geometry onto the vertices and edges of a reference geometry, e.g. to
+/// fix almost-touching boundaries before an overlay.
+///
+/// Every vertex within `tolerance` of a reference vertex is moved onto the nearest one. Failing
+/// that, a vertex within `tolerance` of a reference edge is moved onto the nearest point of the
+/// edge. Then every reference vertex within `tolerance` of an edge of the snapped geometry, and
+/// not already one of its vertices, is inserted into the nearest edge, so that edges running
+/// along each other share their vertices. Repeated consecutive vertices are removed.
+///
+/// Snapping may collapse parts of a geometry whose size is close to `tolerance`, so the result
+/// should be validated when the tolerance is large.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{BooleanOps, Rect, Snap};
+///
+/// let a = Rect::new((0., 0.), (1., 1.)).to_polygon();
+/// // a neighbouring square, with a tiny gap between them
+/// let b = Rect::new((1.001, 0.), (2., 1.)).to_polygon();
+/// assert_eq!(a.union(&b).0.len(), 2);
+///
+/// let b = b.snap(&a, 0.01);
+/// assert_eq!(a.union(&b).0.len(), 1);
+/// ```
+pub trait Snap<T: GeoFloat> {
+    fn snap<R>(&self, reference: &R, tolerance: T) -> Self
+    where
+        R: for<'a> LinesIter<'a, Scalar = T>;
+}
+
+impl<T: GeoFloat> Snap<T> for LineString<T> {
+    fn snap<R>(&self, reference: &R, tolerance: T) -> Self
+    where
+        R: for<'a> LinesIter<'a, Scalar = T>,
+    {
+        Snapper::new(reference, tolerance).snap_line_string(self)
+    }
+}
+
+impl<T: GeoFloat> Snap<T> for MultiLineString<T> {
+    fn snap<R>(&self, reference: &R, tolerance: T) -> Self
+    where
+        R: for<'a> LinesIter<'a, Scalar = T>,
+    {
+        let snapper = Snapper::new(reference, tolerance);
+        self.iter()
+            .map(|line_string| snapper.snap_line_string(line_string))
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> Snap<T> for Polygon<T> {
+    fn snap<R>(&self, reference: &R, tolerance: T) -> Self
+    where
+        R: for<'a> LinesIter<'a, Scalar = T>,
+    {
+        Snapper::new(reference, tolerance).snap_polygon(self)
+    }
+}
+
+impl<T: GeoFloat> Snap<T> for MultiPolygon<T> {
+    fn snap<R>(&self, reference: &R, tolerance: T) -> Self
+    where
+        R: for<'a> LinesIter<'a, Scalar = T>,
+    {
+        let snapper = Snapper::new(reference, tolerance);
+        self.iter()
+            .map(|polygon| snapper.snap_polygon(polygon))
+            .collect()
+    }
+}
+
+/// What [`snap_point`] may snap a point onto.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapPolicy {
+    /// The nearest vertex within the tolerance, or failing that, the nearest point of an edge,
+    /// as [`Snap`] does, so that points near a vertex don't land right next to it.
+    PreferVertices,
+    /// The nearest point within the tolerance, whether a vertex or a point of an edge.
+    Nearest,
+    /// The nearest vertex within the tolerance, never the interior of an edge.
+    VerticesOnly,
+}
+
+/// Where [`snap_point`] snapped a point.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SnapTarget<T: GeoFloat> {
+    /// Onto an existing vertex, which can be reused as is.
+    Vertex(Point<T>),
+    /// Into the interior of an edge, which must be split to insert the point as a vertex.
+    Edge(Point<T>),
+}
+
+impl<T: GeoFloat> SnapTarget<T> {
+    /// The point snapped onto.
+    pub fn point(&self) -> Point<T> {
+        match self {
+            SnapTarget::Vertex(point) | SnapTarget::Edge(point) => *point,
+        }
+    }
+}
+
+/// Snap `point` onto a vertex or an edge of `reference` within `tolerance` of it, as chosen by
+/// `policy`, e.g. to place a new vertex onto existing geometries in an editing tool.
+///
+/// Returns where the point was snapped, reporting whether it's an existing vertex, or `None`
+/// if nothing allowed by the policy is within the tolerance. A point snapped onto the end of
+/// an edge is snapped onto a vertex.
+///
+/// # Examples
+///
+/// ```
+/// use geo::snap::{snap_point, SnapPolicy, SnapTarget};
+/// use geo::{line_string, point};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+/// let click = point!(x: 0.5, y: 0.2);
+///
+/// assert_eq!(
+///     snap_point(click, &road, 1., SnapPolicy::PreferVertices),
+///     Some(SnapTarget::Vertex(point!(x: 0., y: 0.)))
+/// );
+/// assert_eq!(
+///     snap_point(click, &road, 1., SnapPolicy::Nearest),
+///     Some(SnapTarget::Edge(point!(x: 0.5, y: 0.)))
+/// );
+/// assert_eq!(snap_point(click, &road, 0.5, SnapPolicy::VerticesOnly), None);
+/// ```
+pub fn snap_point<T, R>(
+    point: Point<T>,
+    reference: &R,
+    tolerance: T,
+    policy: SnapPolicy,
+) -> Option<SnapTarget<T>>
+where
+    T: GeoFloat,
+    R: for<'a> LinesIter<'a, Scalar = T>,
+{
+    let distance_2 = |coord: Coord<T>| {
+        let delta = coord - point.0;
+        delta.x * delta.x + delta.y * delta.y
+    };
+    let nearer = |nearest: Option<(T, Coord<T>)>, coord: Coord<T>| {
+        let candidate = (distance_2(coord), coord);
+        match nearest {
+            Some(nearest) if nearest.0 <= candidate.0 => Some(nearest),
+            _ => Some(candidate),
+        }
+    };
+
+    let (mut vertex, mut edge) = (None, None);
+    for line in reference.lines_iter() {
+        vertex = nearer(nearer(vertex, line.start), line.end);
+        let t = position(&line, point.0).max(T::zero()).min(T::one());
+        edge = nearer(edge, line.start + line.delta() * t);
+    }
+    let tolerance_2 = tolerance * tolerance;
+    let vertex = vertex.filter(|(distance_2, _)| *distance_2 <= tolerance_2);
+    let edge = edge.filter(|(distance_2, _)| *distance_2 <= tolerance_2);
+
+    let vertex_target = |(_, coord): (T, Coord<T>)| SnapTarget::Vertex(coord.into());
+    let edge_target = |(_, coord): (T, Coord<T>)| SnapTarget::Edge(coord.into());
+    match (policy, vertex, edge) {
+        (SnapPolicy::Nearest, Some(vertex), Some(edge)) if edge.0 < vertex.0 => {
+            Some(edge_target(edge))
+        }
+        (SnapPolicy::VerticesOnly, vertex, _) => vertex.map(vertex_target),
+        (_, Some(vertex), _) => Some(vertex_target(vertex)),
+        (_, None, edge) => edge.map(edge_target),
+    }
+}
+
+// The vertices and edges of a reference geometry, indexed for snapping.
+struct Snapper<T: GeoFloat> {
+    vertices: RTree<Point<T>>,
+    edges: RTree<Line<T>>,
+    tolerance_2: T,
+}
+
+impl<T: GeoFloat> Snapper<T> {
+    fn new<R>(reference: &R, tolerance: T) -> Self
+    where
+        R: for<'a> LinesIter<'a, Scalar = T>,
+    {
+        let edges: Vec<Line<T>> = reference.lines_iter().collect();
+        let mut vertices: Vec<Point<T>> = edges
+            .iter()
+            .flat_map(|edge| [edge.start_point(), edge.end_point()])
+            .collect();
+        vertices.sort_by(|a, b| {
+            a.x()
+                .partial_cmp(&b.x())
+                .unwrap_or(Ordering::Equal)
+                .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+        });
+        vertices.dedup();
+        Snapper {
+            vertices: RTree::bulk_load(vertices),
+            edges: RTree::bulk_load(edges),
+            tolerance_2: tolerance * tolerance,
+        }
+    }
+
+    fn snap_polygon(&self, polygon: &Polygon<T>) -> Polygon<T> {
+        Polygon::new(
+            self.snap_line_string(polygon.exterior()),
+            polygon
+                .interiors()
+                .iter()
+                .map(|ring| self.snap_line_string(ring))
+                .collect(),
+        )
+    }
+
+    fn snap_line_string(&self, line_string: &LineString<T>) -> LineString<T> {
+        let mut coords: Vec<Coord<T>> = line_string
+            .coords()
+            .map(|coord| self.snap_coord(*coord))
+            .collect();
+        coords.dedup();
+        if coords.len() < 2 {
+            return LineString::new(coords);
+        }
+
+        // insert the reference vertices near an edge into the nearest edge
+        let edges = RTree::bulk_load(
+            coords
+                .windows(2)
+                .enumerate()
+                .map(|(idx, pair)| GeomWithData::new(Line::new(pair[0], pair[1]), idx))
+                .collect(),
+        );
+        let mut insertions: Vec<Vec<(T, Coord<T>)>> = vec![vec![]; coords.len() - 1];
+        for vertex in self.vertices.iter() {
+            let edge = match edges.nearest_neighbor(vertex) {
+                Some(edge) => edge,
+                None => continue,
+            };
+            let line = edge.geom();
+            if line.start == vertex.0
+                || line.end == vertex.0
+                || edge.distance_2(vertex) > self.tolerance_2
+            {
+                continue;
+            }
+            insertions[edge.data].push((position(line, vertex.0), vertex.0));
+        }
+
+        let mut snapped = Vec::with_capacity(coords.len());
+        for (coord, mut inserted) in coords.iter().zip(insertions) {
+            snapped.push(*coord);
+            inserted.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            snapped.extend(inserted.into_iter().map(|(_, coord)| coord));
+        }
+        snapped.push(*coords.last().unwrap());
+        snapped.dedup();
+        LineString::new(snapped)
+    }
+
+    fn snap_coord(&self, coord: Coord<T>) -> Coord<T> {
+        let point = Point::from(coord);
+        if let Some(vertex) = self.vertices.nearest_neighbor(&point) {
+            if vertex.distance_2(&point) <= self.tolerance_2 {
+                return vertex.0;
+            }
+        }
+        if let Some(edge) = self.edges.nearest_neighbor(&point) {
+            if edge.distance_2(&point) <= self.tolerance_2 {
+                let t = position(edge, coord).max(T::zero()).min(T::one());
+                return edge.start + edge.delta() * t;
+            }
+        }
+        coord
+    }
+}
+
+// The position of the projection of `coord` on the line through `line`, in multiples of its
+// length from its start.
+fn position<T: GeoFloat>(line: &Line<T>, coord: Coord<T>) -> T {
+    let delta = line.delta();
+    let offset = coord - line.start;
+    (offset.x * delta.x + offset.y * delta.y) / (delta.x * delta.x + delta.y * delta.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Rect};
+
+    #[test]
+    fn snap_to_vertices_and_edges() {
+        let reference = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let subject = line_string![(x: 0.05, y: 0.05), (x: 5., y: 0.05), (x: 5., y: 3.)];
+        let snapped = subject.snap(&reference, 0.1);
+        assert_eq!(
+            snapped,
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 3.)]
+        );
+        // nothing is close enough with a smaller tolerance
+        assert_eq!(subject.snap(&reference, 0.01), subject);
+    }
+
+    #[test]
+    fn insert_reference_vertices() {
+        let reference = line_string![(x: 3., y: 0.001), (x: 3., y: 5.), (x: 7., y: -0.001)];
+        let subject = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let snapped = subject.snap(&reference, 0.01);
+        assert_eq!(
+            snapped,
+            line_string![(x: 0., y: 0.), (x: 3., y: 0.001), (x: 7., y: -0.001), (x: 10., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn snap_polygon_with_hole() {
+        let reference = Rect::new((0., 0.), (10., 10.)).to_polygon();
+        let poly = polygon!(
+            exterior: [(x: -0.01, y: -0.01), (x: 10.01, y: 0.), (x: 10., y: 10.), (x: 0., y: 9.99)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let snapped = poly.snap(&reference, 0.1);
+        assert_eq!(
+            snapped.exterior(),
+            &line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)]
+        );
+        assert_eq!(snapped.interiors(), poly.interiors());
+    }
+
+    #[test]
+    fn snap_point_policies() {
+        let reference =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let snap =
+            |x, y, tolerance, policy| snap_point(Point::new(x, y), &reference, tolerance, policy);
+
+        // nearer the edge, but within the tolerance of the corner
+        assert_eq!(
+            snap(9.5, 0.1, 1., SnapPolicy::PreferVertices),
+            Some(SnapTarget::Vertex(Point::new(10., 0.)))
+        );
+        assert_eq!(
+            snap(9.5, 0.1, 1., SnapPolicy::Nearest),
+            Some(SnapTarget::Edge(Point::new(9.5, 0.)))
+        );
+        assert_eq!(
+            snap(9.5, 0.1, 1., SnapPolicy::VerticesOnly),
+            Some(SnapTarget::Vertex(Point::new(10., 0.)))
+        );
+
+        // too far from the corner
+        assert_eq!(
+            snap(5., 0.1, 1., SnapPolicy::PreferVertices),
+            Some(SnapTarget::Edge(Point::new(5., 0.)))
+        );
+        assert_eq!(snap(5., 0.1, 1., SnapPolicy::VerticesOnly), None);
+        assert_eq!(snap(5., 2., 1., SnapPolicy::Nearest), None);
+
+        // beyond the corner, the nearest point of both its edges is the vertex
+        let snapped = snap(10.2, -0.2, 1., SnapPolicy::Nearest);
+        assert_eq!(snapped, Some(SnapTarget::Vertex(Point::new(10., 0.))));
+        assert_eq!(snapped.unwrap().point(), Point::new(10., 0.));
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(
+            snap_point(Point::new(0., 0.), &empty, 1., SnapPolicy::Nearest),
+            None
+        );
+    }
+
+    #[test]
+    fn collapse_repeated_vertices() {
+        let reference = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        let subject = line_string![(x: 0., y: 0.01), (x: 0.01, y: 0.), (x: 1., y: 0.)];
+        assert_eq!(
+            subject.snap(&reference, 0.1),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)]
+        );
+    }
+}
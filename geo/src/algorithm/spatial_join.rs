@@ -0,0 +1,168 @@
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeObject, AABB};
+
+use crate::{Contains, EuclideanDistance, GeoFloat, Intersects, Point, Polygon};
+
+/// The relationship between a point and a polygon for them to be joined by [`spatial_join`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinPredicate<T: GeoFloat> {
+    /// The polygon contains the point: the point is in its interior, not on its boundary.
+    Contains,
+    /// The point is in the polygon, or on its boundary.
+    Intersects,
+    /// The point is within a euclidean distance of the polygon, or in it.
+    WithinDistance(T),
+}
+
+/// Join each of `points` to the `polygons` satisfying `predicate`, e.g. to find the district
+/// of each of many addresses, or the parks within walking distance of each.
+///
+/// Returns, for each point, the indices of the polygons it's joined to, in ascending order.
+///
+/// The bounding rectangles of the polygons are indexed in an R-tree, so only the polygons near
+/// each point are tested, which is much faster than testing every pair of a point and a
+/// polygon. With the `use-rayon` feature, [`par_spatial_join`](crate::parallel::par_spatial_join)
+/// looks the points up in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use geo::spatial_join::{spatial_join, JoinPredicate};
+/// use geo::{point, Rect};
+///
+/// let districts = vec![
+///     Rect::new((0., 0.), (2., 2.)).to_polygon(),
+///     Rect::new((2., 0.), (4., 2.)).to_polygon(),
+/// ];
+/// let addresses = vec![point!(x: 1., y: 1.), point!(x: 2., y: 1.), point!(x: 5., y: 1.)];
+///
+/// assert_eq!(
+///     spatial_join(&addresses, &districts, JoinPredicate::Intersects),
+///     vec![vec![0], vec![0, 1], vec![]]
+/// );
+/// assert_eq!(
+///     spatial_join(&addresses, &districts, JoinPredicate::Contains),
+///     vec![vec![0], vec![], vec![]]
+/// );
+/// assert_eq!(
+///     spatial_join(&addresses, &districts, JoinPredicate::WithinDistance(1.5)),
+///     vec![vec![0, 1], vec![0, 1], vec![1]]
+/// );
+/// ```
+pub fn spatial_join<T: GeoFloat>(
+    points: &[Point<T>],
+    polygons: &[Polygon<T>],
+    predicate: JoinPredicate<T>,
+) -> Vec<Vec<usize>> {
+    let index = PolygonIndex::new(polygons);
+    points
+        .iter()
+        .map(|point| index.join(point, predicate))
+        .collect()
+}
+
+/// The polygons of a [`spatial_join`], with an R-tree of their bounding rectangles.
+pub(crate) struct PolygonIndex<'a, T: GeoFloat> {
+    polygons: &'a [Polygon<T>],
+    tree: RTree<GeomWithData<Rectangle<Point<T>>, usize>>,
+}
+
+impl<'a, T: GeoFloat> PolygonIndex<'a, T> {
+    pub(crate) fn new(polygons: &'a [Polygon<T>]) -> Self {
+        let tree = RTree::bulk_load(
+            polygons
+                .iter()
+                .enumerate()
+                .map(|(idx, polygon)| {
+                    GeomWithData::new(Rectangle::from_aabb(polygon.envelope()), idx)
+                })
+                .collect(),
+        );
+        PolygonIndex { polygons, tree }
+    }
+
+    /// The indices of the polygons joined to `point`, in ascending order.
+    pub(crate) fn join(&self, point: &Point<T>, predicate: JoinPredicate<T>) -> Vec<usize> {
+        let envelope = match predicate {
+            JoinPredicate::Contains | JoinPredicate::Intersects => AABB::from_point(*point),
+            JoinPredicate::WithinDistance(distance) => AABB::from_corners(
+                Point::new(point.x() - distance, point.y() - distance),
+                Point::new(point.x() + distance, point.y() + distance),
+            ),
+        };
+        let mut joined: Vec<usize> = self
+            .tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|candidate| candidate.data)
+            .filter(|&idx| {
+                let polygon = &self.polygons[idx];
+                match predicate {
+                    JoinPredicate::Contains => polygon.contains(point),
+                    JoinPredicate::Intersects => polygon.intersects(point),
+                    JoinPredicate::WithinDistance(distance) => {
+                        point.euclidean_distance(polygon) <= distance
+                    }
+                }
+            })
+            .collect();
+        joined.sort_unstable();
+        joined
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+
+    #[test]
+    fn overlapping_polygons_with_holes() {
+        let polygons = vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+            ],
+            polygon![(x: 5., y: 5.), (x: 8., y: 5.), (x: 8., y: 8.)],
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 25., y: 10.)],
+        ];
+        let points = vec![
+            // in the hole of the first polygon, and the second
+            point!(x: 5.5, y: 5.2),
+            // in the hole alone
+            point!(x: 4.5, y: 5.5),
+            // on the boundary of the hole
+            point!(x: 4., y: 5.),
+            // between the first and third polygons
+            point!(x: 15., y: 5.),
+        ];
+
+        assert_eq!(
+            spatial_join(&points, &polygons, JoinPredicate::Contains),
+            vec![vec![1], vec![], vec![], vec![]]
+        );
+        assert_eq!(
+            spatial_join(&points, &polygons, JoinPredicate::Intersects),
+            vec![vec![1], vec![], vec![0], vec![]]
+        );
+        assert_eq!(
+            spatial_join(&points, &polygons, JoinPredicate::WithinDistance(0.5)),
+            vec![vec![0, 1], vec![0], vec![0], vec![]]
+        );
+        // the third polygon is farther than its bounding rectangle
+        assert_eq!(
+            spatial_join(&points, &polygons, JoinPredicate::WithinDistance(5.)),
+            vec![vec![0, 1], vec![0, 1], vec![0, 1], vec![0]]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let polygons = vec![polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]];
+        assert!(spatial_join(&[], &polygons, JoinPredicate::Intersects).is_empty());
+        assert_eq!(
+            spatial_join(&[point!(x: 0.5, y: 0.2)], &[], JoinPredicate::Intersects),
+            vec![Vec::<usize>::new()]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::ops::Range;
+
+use crate::{Coord, CoordFloat, EuclideanLength, Line, LineString, LineStringZM, Point};
+
+/// A place where a trajectory stayed: a run of its consecutive vertices within a distance of
+/// the first of them, as found by [`StayPoints`] or [`TimedStayPoints`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StayPoint<T: CoordFloat> {
+    /// The center of the vertices of the stay.
+    pub point: Point<T>,
+    /// The indices of the vertices of the stay.
+    pub vertices: Range<usize>,
+}
+
+/// Detect the stay points of a trajectory, such as a GPS track: the places where it lingered,
+/// recording several positions close to one another.
+///
+/// This follows [Li et al. (2008)]: going along the trajectory, a stay is a run of
+/// consecutive vertices all within `max_distance` of its first vertex, long enough to count as
+/// a stay. When a run is too short, the search moves on to the next vertex, and after a stay,
+/// to the vertex following it, so stays never overlap.
+///
+/// Distances are euclidean, so the trajectory should be projected.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, StayPoints};
+///
+/// let track = line_string![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 11., y: 0.),
+///     (x: 10., y: 1.),
+///     (x: 20., y: 0.),
+/// ];
+///
+/// let stays = track.stay_points(2., 3);
+/// assert_eq!(stays.len(), 1);
+/// assert_eq!(stays[0].vertices, 1..4);
+/// assert_eq!(stays[0].point, point!(x: 31. / 3., y: 1. / 3.));
+/// ```
+///
+/// [Li et al. (2008)]: https://doi.org/10.1145/1463434.1463477
+pub trait StayPoints<T: CoordFloat> {
+    /// The stays of at least `min_vertices` vertices, centered on the mean of their vertices.
+    fn stay_points(&self, max_distance: T, min_vertices: usize) -> Vec<StayPoint<T>>;
+}
+
+/// Detect the stay points of a trajectory from the timestamps in its M values, such as a GPS
+/// track: the places where it lingered for some time.
+///
+/// The stays are found as by [`StayPoints`], but they must last at least `min_duration`, from
+/// the timestamp of their first vertex to that of their last, and they're centered on the mean
+/// of their vertices weighted by time: each vertex weighs half the time from the vertex
+/// before it to the vertex after it, within the stay, so a trajectory recorded at an uneven
+/// rate is centered where it spent its time rather than where it recorded the most positions.
+///
+/// The timestamps must be in ascending order. Returns `None` if the line string has no M
+/// values.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, LineStringZM, TimedStayPoints};
+///
+/// let track = LineStringZM::new(
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 11., y: 0.), (x: 20., y: 0.)],
+///     None,
+///     Some(vec![0., 60., 600., 660.]),
+/// );
+///
+/// let stays = track.timed_stay_points(2., 300.).unwrap();
+/// assert_eq!(stays.len(), 1);
+/// assert_eq!(stays[0].vertices, 1..3);
+/// assert_eq!(stays[0].point, point!(x: 10.5, y: 0.));
+/// ```
+pub trait TimedStayPoints<T: CoordFloat> {
+    /// The stays lasting at least `min_duration`, centered on the time weighted mean of their
+    /// vertices.
+    fn timed_stay_points(&self, max_distance: T, min_duration: T) -> Option<Vec<StayPoint<T>>>;
+}
+
+impl<T: CoordFloat> StayPoints<T> for LineString<T> {
+    fn stay_points(&self, max_distance: T, min_vertices: usize) -> Vec<StayPoint<T>> {
+        stay_points(
+            &self.0,
+            max_distance,
+            |vertices| vertices.len() >= min_vertices,
+            |_, _| T::one(),
+        )
+    }
+}
+
+impl<T: CoordFloat> TimedStayPoints<T> for LineStringZM<T> {
+    fn timed_stay_points(&self, max_distance: T, min_duration: T) -> Option<Vec<StayPoint<T>>> {
+        let m = self.m()?;
+        let two = T::one() + T::one();
+        Some(stay_points(
+            &self.xy().0,
+            max_distance,
+            |vertices| m[vertices.end - 1] - m[vertices.start] >= min_duration,
+            |vertices, i| {
+                let before = m[vertices.start.max(i.saturating_sub(1))];
+                let after = m[(vertices.end - 1).min(i + 1)];
+                (after - before) / two
+            },
+        ))
+    }
+}
+
+// The runs of `coords` within `max_distance` of their first coordinate which `is_stay`, centered
+// on the mean of their coordinates, each weighted by `weight`.
+fn stay_points<T: CoordFloat>(
+    coords: &[Coord<T>],
+    max_distance: T,
+    is_stay: impl Fn(Range<usize>) -> bool,
+    weight: impl Fn(Range<usize>, usize) -> T,
+) -> Vec<StayPoint<T>> {
+    let mut stays = vec![];
+    let mut start = 0;
+    while start < coords.len() {
+        let end = start
+            + 1
+            + coords[start + 1..]
+                .iter()
+                .take_while(|&&coord| {
+                    Line::new(coords[start], coord).euclidean_length() <= max_distance
+                })
+                .count();
+        if !is_stay(start..end) {
+            start += 1;
+            continue;
+        }
+
+        let (mut sum, mut total) = (Coord::zero(), T::zero());
+        for (i, &coord) in (start..end).zip(&coords[start..end]) {
+            let weight = weight(start..end, i);
+            sum = sum + coord * weight;
+            total = total + weight;
+        }
+        let center = if total > T::zero() {
+            sum / total
+        } else {
+            // no time passed: weigh the vertices evenly
+            let n = T::from(end - start).unwrap();
+            coords[start..end]
+                .iter()
+                .fold(Coord::zero(), |sum, &coord| sum + coord)
+                / n
+        };
+        stays.push(StayPoint {
+            point: center.into(),
+            vertices: start..end,
+        });
+        start = end;
+    }
+    stays
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn by_count() {
+        let track = line_string![
+            (x: 0., y: 0.),
+            (x: 0., y: 1.),
+            (x: 10., y: 0.),
+            (x: 12., y: 0.),
+            (x: 10., y: 2.),
+            // out of reach of the start of the stay, though near its last vertex
+            (x: 10., y: 3.),
+            (x: 10., y: 4.),
+            (x: 10., y: 4.),
+        ];
+        let stays = track.stay_points(2., 2);
+        assert_eq!(
+            stays,
+            vec![
+                StayPoint {
+                    point: point!(x: 0., y: 0.5),
+                    vertices: 0..2
+                },
+                StayPoint {
+                    point: point!(x: 32. / 3., y: 2. / 3.),
+                    vertices: 2..5
+                },
+                StayPoint {
+                    point: point!(x: 10., y: 11. / 3.),
+                    vertices: 5..8
+                },
+            ]
+        );
+
+        // only from a later start
+        let stays = track.stay_points(2., 4);
+        assert_eq!(stays.len(), 1);
+        assert_eq!(stays[0].vertices, 4..8);
+
+        // every vertex on its own, but for the repeated one
+        assert_eq!(track.stay_points(0.5, 1).len(), 7);
+        assert!(LineString::<f64>::new(vec![]).stay_points(1., 1).is_empty());
+    }
+
+    #[test]
+    fn by_duration() {
+        let track = LineStringZM::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 1.),
+                (x: 11., y: 0.),
+                (x: 20., y: 0.),
+                (x: 20., y: 1.),
+            ],
+            None,
+            Some(vec![0., 10., 20., 100., 110., 115.]),
+        );
+        let stays = track.timed_stay_points(2., 50.).unwrap();
+        assert_eq!(stays.len(), 1);
+        assert_eq!(stays[0].vertices, 1..4);
+        // weighing 5, 45 and 40
+        assert_eq!(
+            stays[0].point,
+            point!(x: (50. + 450. + 440.) / 90., y: 45. / 90.)
+        );
+
+        // many vertices, but not long enough
+        assert!(track.timed_stay_points(2., 200.).unwrap().is_empty());
+
+        // all at once
+        let instant = LineStringZM::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            None,
+            Some(vec![5., 5.]),
+        );
+        assert_eq!(
+            instant.timed_stay_points(2., 0.).unwrap()[0].point,
+            point!(x: 0.5, y: 0.)
+        );
+
+        let untimed: LineStringZM<f64> = line_string![(x: 0., y: 0.)].into();
+        assert_eq!(untimed.timed_stay_points(1., 0.), None);
+    }
+}
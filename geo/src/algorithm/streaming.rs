@@ -0,0 +1,263 @@
+use crate::algorithm::simplify::rdp;
+use crate::{Coord, CoordFloat, GeoFloat};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Process a sequence of coordinates without collecting it into a `LineString` first.
+///
+/// This is implemented for every iterator over `Coord`s, so that very long traces, read from a
+/// file or a database for instance, can be measured or transformed in a single pass, while only
+/// keeping a bounded number of coordinates in memory.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{coord, StreamingCoords};
+///
+/// // a trace far too long to collect, generated on the fly
+/// let trace = (0..1_000_000).map(|i| coord! { x: i as f64, y: (i % 2) as f64 * 0.01 });
+/// let simplified: Vec<_> = trace.simplify_windowed(0.1, 1024).collect();
+/// assert_eq!(simplified.first(), Some(&coord! { x: 0., y: 0. }));
+/// assert_eq!(simplified.last(), Some(&coord! { x: 999_999., y: 0.01 }));
+/// assert!(simplified.len() < 2_000);
+///
+/// let length = vec![coord! { x: 0., y: 0. }, coord! { x: 3., y: 4. }]
+///     .into_iter()
+///     .densify(1.)
+///     .euclidean_length();
+/// assert_relative_eq!(length, 5.);
+/// ```
+pub trait StreamingCoords<T: CoordFloat>: Iterator<Item = Coord<T>> + Sized {
+    /// The length of the line string through the coordinates, as `EuclideanLength` would
+    /// measure it.
+    fn euclidean_length(self) -> T {
+        let mut coords = self;
+        let mut previous = match coords.next() {
+            Some(first) => first,
+            None => return T::zero(),
+        };
+        coords.fold(T::zero(), |length, coord| {
+            let delta = coord - previous;
+            previous = coord;
+            length + delta.x.hypot(delta.y)
+        })
+    }
+
+    /// Simplify the coordinates with the Ramer–Douglas–Peucker algorithm, applied to
+    /// consecutive windows of at most `window` coordinates.
+    ///
+    /// Consecutive windows share their boundary coordinate, which is always kept, so every
+    /// removed coordinate is still within `epsilon` of the output. The output can have a few more
+    /// coordinates than `Simplify` would keep on the whole line string, as a vertex can't be
+    /// removed across a window boundary, but it's the same when there are at most `window`
+    /// coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is less than 3, as no coordinate could be removed.
+    fn simplify_windowed(self, epsilon: T, window: usize) -> SimplifyWindowed<Self, T>
+    where
+        T: GeoFloat,
+    {
+        assert!(window >= 3, "window must hold at least 3 coordinates");
+        SimplifyWindowed {
+            coords: self,
+            epsilon,
+            window,
+            buffer: Vec::with_capacity(window),
+            output: Vec::new().into_iter(),
+            exhausted: false,
+        }
+    }
+
+    /// Insert coordinates so that consecutive coordinates are at most `max_distance` apart, as
+    /// `Densify` would.
+    ///
+    /// # Panics
+    ///
+    /// If `max_distance` isn't strictly positive.
+    fn densify(self, max_distance: T) -> DensifyStream<Self, T> {
+        assert!(max_distance > T::zero());
+        DensifyStream {
+            coords: self,
+            max_distance,
+            previous: None,
+            segment: None,
+        }
+    }
+}
+
+impl<T, I> StreamingCoords<T> for I
+where
+    T: CoordFloat,
+    I: Iterator<Item = Coord<T>>,
+{
+}
+
+/// Iterator returned by [`StreamingCoords::simplify_windowed`].
+#[derive(Debug)]
+pub struct SimplifyWindowed<I, T: CoordFloat> {
+    coords: I,
+    epsilon: T,
+    window: usize,
+    buffer: Vec<Coord<T>>,
+    output: vec::IntoIter<Coord<T>>,
+    exhausted: bool,
+}
+
+impl<I, T> Iterator for SimplifyWindowed<I, T>
+where
+    I: Iterator<Item = Coord<T>>,
+    T: GeoFloat,
+{
+    type Item = Coord<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(coord) = self.output.next() {
+                return Some(coord);
+            }
+            if self.exhausted {
+                return None;
+            }
+            let missing = self.window - self.buffer.len();
+            self.buffer.extend(self.coords.by_ref().take(missing));
+            if self.buffer.len() < self.window {
+                // the last window, of which every coordinate is emitted
+                self.exhausted = true;
+                self.output = if self.buffer.len() < 3 {
+                    core::mem::take(&mut self.buffer)
+                } else {
+                    rdp(self.buffer.drain(..), &self.epsilon)
+                }
+                .into_iter();
+            } else {
+                // keep the last coordinate back, to start the next window from
+                let mut simplified = rdp(self.buffer.drain(..), &self.epsilon);
+                self.buffer.extend(simplified.pop());
+                self.output = simplified.into_iter();
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`StreamingCoords::densify`].
+#[derive(Debug)]
+pub struct DensifyStream<I, T: CoordFloat> {
+    coords: I,
+    max_distance: T,
+    previous: Option<Coord<T>>,
+    segment: Option<DensifiedSegment<T>>,
+}
+
+// A segment being split into `count` pieces, of which the end of the `index`th was emitted last
+#[derive(Debug)]
+struct DensifiedSegment<T: CoordFloat> {
+    start: Coord<T>,
+    end: Coord<T>,
+    count: u64,
+    index: u64,
+}
+
+impl<I, T> Iterator for DensifyStream<I, T>
+where
+    I: Iterator<Item = Coord<T>>,
+    T: CoordFloat,
+{
+    type Item = Coord<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(segment) = &mut self.segment {
+                segment.index += 1;
+                if segment.index < segment.count {
+                    let frac = T::one() / T::from(segment.count).unwrap();
+                    let ratio = frac * T::from(segment.index).unwrap();
+                    return Some(segment.start + (segment.end - segment.start) * ratio);
+                }
+                let end = segment.end;
+                self.segment = None;
+                return Some(end);
+            }
+            let coord = self.coords.next()?;
+            match self.previous.replace(coord) {
+                None => return Some(coord),
+                Some(start) => {
+                    let delta = coord - start;
+                    let count = (delta.x.hypot(delta.y) / self.max_distance)
+                        .ceil()
+                        .to_u64()
+                        .unwrap()
+                        .max(1);
+                    self.segment = Some(DensifiedSegment {
+                        start,
+                        end: coord,
+                        count,
+                        index: 0,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, Densify, EuclideanLength, LineString, Simplify};
+
+    #[test]
+    fn matches_whole_geometry_algorithms() {
+        let line_string: LineString<f64> = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 5.0, y: 4.0),
+            (x: 11.0, y: 5.5),
+            (x: 17.3, y: 3.2),
+            (x: 27.8, y: 0.1),
+        ];
+        assert_relative_eq!(
+            line_string.coords().copied().euclidean_length(),
+            line_string.euclidean_length()
+        );
+        let densified: LineString<f64> = line_string.coords().copied().densify(1.5).collect();
+        assert_eq!(densified, line_string.densify(1.5));
+        // with a single window, simplification is the same
+        let simplified: LineString<f64> = line_string
+            .coords()
+            .copied()
+            .simplify_windowed(1.0, 5)
+            .collect();
+        assert_eq!(simplified, line_string.simplify(&1.0));
+    }
+
+    #[test]
+    fn windows_keep_their_boundaries() {
+        // a straight line, which is simplified to its endpoints within each window
+        let coords = (0..10).map(|i| coord! { x: f64::from(i), y: 0. });
+        let simplified: Vec<_> = coords.clone().simplify_windowed(0.1, 4).collect();
+        let xs: Vec<f64> = simplified.iter().map(|coord| coord.x).collect();
+        assert_eq!(xs, vec![0., 3., 6., 9.]);
+        let simplified: Vec<_> = coords.clone().simplify_windowed(0.1, 3).collect();
+        assert_eq!(simplified.len(), 6);
+        let simplified: Vec<_> = coords.simplify_windowed(0.1, 100).collect();
+        assert_eq!(simplified.len(), 2);
+
+        // leftovers too short to simplify are kept
+        let coords = (0..5).map(|i| coord! { x: f64::from(i), y: 0. });
+        let xs: Vec<f64> = coords.simplify_windowed(0.1, 4).map(|c| c.x).collect();
+        assert_eq!(xs, vec![0., 3., 4.]);
+    }
+
+    #[test]
+    fn empty_and_single_coordinates() {
+        let empty = core::iter::empty::<Coord<f64>>;
+        assert_eq!(empty().euclidean_length(), 0.);
+        assert_eq!(empty().densify(1.).count(), 0);
+        assert_eq!(empty().simplify_windowed(1., 10).count(), 0);
+        let single = || core::iter::once(coord! { x: 1., y: 1. });
+        assert_eq!(single().euclidean_length(), 0.);
+        assert_eq!(single().densify(1.).count(), 1);
+        assert_eq!(single().simplify_windowed(1., 10).count(), 1);
+    }
+}
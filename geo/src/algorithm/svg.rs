@@ -0,0 +1,357 @@
+use crate::dimensions::{Dimensions, HasDimensions};
+use crate::{
+    BoundingRect, Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+use std::fmt::{self, Display, Write};
+
+/// Which way the y axis of a geometry's coordinates points, when drawing it as SVG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YAxis {
+    /// The y axis points upwards, as in maps and plots: y coordinates are negated in the output,
+    /// so that the geometry isn't drawn upside down.
+    Up,
+    /// The y axis points downwards, as in SVG: coordinates are written as they are.
+    Down,
+}
+
+impl YAxis {
+    fn apply<T: CoordNum>(self, coord: Coord<T>) -> Coord<T> {
+        match self {
+            YAxis::Up => Coord {
+                x: coord.x,
+                y: T::zero() - coord.y,
+            },
+            YAxis::Down => coord,
+        }
+    }
+}
+
+/// Draw a geometry as an SVG path, e.g. to look at the output of an algorithm.
+///
+/// Rings are closed with `Z`, so that they're filled with the `evenodd` fill rule, holes
+/// included, and points are zero-length subpaths, which are drawn as dots with round line caps.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::svg::{ToSvg, YAxis};
+/// use geo::{line_string, polygon};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 2.), (x: 3., y: 1.)];
+/// assert_eq!(line_string.to_svg_path(YAxis::Down), "M0 0L1 2L3 1");
+/// assert_eq!(line_string.to_svg_path(YAxis::Up), "M0 0L1 -2L3 -1");
+///
+/// let triangle = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)];
+/// assert_eq!(
+///     triangle.to_svg(YAxis::Up),
+///     "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-0.05 -1.05 1.1 1.1\">\
+///     <path d=\"M0 0L1 0L0 -1Z\" fill=\"lightsteelblue\" fill-rule=\"evenodd\" \
+///     stroke=\"black\" stroke-width=\"2\" stroke-linecap=\"round\" stroke-linejoin=\"round\" \
+///     vector-effect=\"non-scaling-stroke\"/></svg>"
+/// );
+/// ```
+pub trait ToSvg<T: CoordNum + Display> {
+    /// The path data of the geometry, i.e. the `d` attribute of an SVG `<path>` element.
+    fn to_svg_path(&self, y_axis: YAxis) -> String;
+
+    /// A standalone SVG document drawing the geometry, with a view box fitting its bounding
+    /// rectangle and a margin of a twentieth of its size. Areal geometries are filled.
+    fn to_svg(&self, y_axis: YAxis) -> String
+    where
+        Self: BoundingRect<T> + HasDimensions,
+    {
+        let rect: Option<Rect<T>> = self.bounding_rect().into();
+        let rect = match rect {
+            Some(rect) => rect,
+            None => return "<svg xmlns=\"http://www.w3.org/2000/svg\"/>".to_string(),
+        };
+        let size = if rect.width() > rect.height() {
+            rect.width()
+        } else {
+            rect.height()
+        };
+        let twenty = T::from(20).unwrap();
+        let padding = if size / twenty > T::zero() {
+            size / twenty
+        } else {
+            T::one()
+        };
+        let fill = match self.dimensions() {
+            Dimensions::TwoDimensional => "lightsteelblue",
+            _ => "none",
+        };
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{}\">\
+             <path d=\"{}\" fill=\"{}\" fill-rule=\"evenodd\" stroke=\"black\" stroke-width=\"2\" \
+             stroke-linecap=\"round\" stroke-linejoin=\"round\" \
+             vector-effect=\"non-scaling-stroke\"/></svg>",
+            ViewBox::fit(rect, padding, y_axis),
+            self.to_svg_path(y_axis),
+            fill
+        )
+    }
+}
+
+/// The `viewBox` attribute of an SVG element: the rectangle of user coordinates it shows.
+///
+/// Its `Display` implementation writes it as the value of the attribute.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::svg::{ViewBox, YAxis};
+/// use geo::Rect;
+///
+/// let rect = Rect::new((10., 20.), (50., 40.));
+/// let view_box = ViewBox::fit(rect, 5., YAxis::Up);
+/// assert_eq!(view_box.to_string(), "5 -45 50 30");
+/// // the height of an image 500 pixels wide showing it without distortion
+/// assert_eq!(view_box.height_for_width(500.), 300.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewBox<T: CoordNum> {
+    pub min_x: T,
+    pub min_y: T,
+    pub width: T,
+    pub height: T,
+}
+
+impl<T: CoordNum> ViewBox<T> {
+    /// The view box showing `rect`, and a margin of `padding` around it, as drawn by
+    /// [`ToSvg::to_svg_path`] with `y_axis`.
+    pub fn fit(rect: Rect<T>, padding: T, y_axis: YAxis) -> Self {
+        let two = T::one() + T::one();
+        let min_y = match y_axis {
+            YAxis::Up => T::zero() - rect.max().y,
+            YAxis::Down => rect.min().y,
+        };
+        ViewBox {
+            min_x: rect.min().x - padding,
+            min_y: min_y - padding,
+            width: rect.width() + padding * two,
+            height: rect.height() + padding * two,
+        }
+    }
+
+    /// The height of an image `width` wide showing the view box without distorting it.
+    pub fn height_for_width(&self, width: T) -> T {
+        width * self.height / self.width
+    }
+
+    /// The width of an image `height` high showing the view box without distorting it.
+    pub fn width_for_height(&self, height: T) -> T {
+        height * self.width / self.height
+    }
+}
+
+impl<T: CoordNum + Display> Display for ViewBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.min_x, self.min_y, self.width, self.height
+        )
+    }
+}
+
+// Path data being written, with the coordinates of every command flipped according to `y_axis`.
+struct PathData<T: CoordNum> {
+    d: String,
+    y_axis: YAxis,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: CoordNum + Display> PathData<T> {
+    fn new(y_axis: YAxis) -> Self {
+        PathData {
+            d: String::new(),
+            y_axis,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    fn command(&mut self, command: char, coord: Coord<T>) {
+        let coord = self.y_axis.apply(coord);
+        // writing to a String can't fail
+        let _ = write!(self.d, "{}{} {}", command, coord.x, coord.y);
+    }
+
+    fn point(&mut self, coord: Coord<T>) {
+        self.command('M', coord);
+        self.d.push_str("h0");
+    }
+
+    fn line(&mut self, coords: &[Coord<T>]) {
+        for (i, coord) in coords.iter().enumerate() {
+            self.command(if i == 0 { 'M' } else { 'L' }, *coord);
+        }
+    }
+
+    fn ring(&mut self, coords: &[Coord<T>]) {
+        let coords = match coords {
+            [first, rest @ .., last] if first == last && !rest.is_empty() => {
+                &coords[..coords.len() - 1]
+            }
+            _ => coords,
+        };
+        if !coords.is_empty() {
+            self.line(coords);
+            self.d.push('Z');
+        }
+    }
+
+    fn polygon(&mut self, polygon: &Polygon<T>) {
+        self.ring(&polygon.exterior().0);
+        for interior in polygon.interiors() {
+            self.ring(&interior.0);
+        }
+    }
+}
+
+macro_rules! to_svg_impl {
+    ($type:ident, |$geometry:ident, $path:ident| $body:expr) => {
+        impl<T: CoordNum + Display> ToSvg<T> for $type<T> {
+            fn to_svg_path(&self, y_axis: YAxis) -> String {
+                let $geometry = self;
+                let mut $path = PathData::new(y_axis);
+                $body;
+                $path.d
+            }
+        }
+    };
+}
+
+to_svg_impl!(Point, |point, path| path.point(point.0));
+to_svg_impl!(Line, |line, path| path.line(&[line.start, line.end]));
+to_svg_impl!(LineString, |line_string, path| path.line(&line_string.0));
+to_svg_impl!(Polygon, |polygon, path| path.polygon(polygon));
+to_svg_impl!(MultiPoint, |multi_point, path| {
+    for point in multi_point {
+        path.point(point.0);
+    }
+});
+to_svg_impl!(MultiLineString, |multi_line_string, path| {
+    for line_string in multi_line_string {
+        path.line(&line_string.0);
+    }
+});
+to_svg_impl!(MultiPolygon, |multi_polygon, path| {
+    for polygon in multi_polygon {
+        path.polygon(polygon);
+    }
+});
+to_svg_impl!(Rect, |rect, path| path.polygon(&rect.to_polygon()));
+to_svg_impl!(Triangle, |triangle, path| path.ring(&triangle.to_array()));
+
+impl<T: CoordNum + Display> ToSvg<T> for GeometryCollection<T> {
+    fn to_svg_path(&self, y_axis: YAxis) -> String {
+        self.iter()
+            .map(|geometry| geometry.to_svg_path(y_axis))
+            .collect()
+    }
+}
+
+impl<T: CoordNum + Display> ToSvg<T> for Geometry<T> {
+    fn to_svg_path(&self, y_axis: YAxis) -> String {
+        match self {
+            Geometry::Point(g) => g.to_svg_path(y_axis),
+            Geometry::Line(g) => g.to_svg_path(y_axis),
+            Geometry::LineString(g) => g.to_svg_path(y_axis),
+            Geometry::Polygon(g) => g.to_svg_path(y_axis),
+            Geometry::MultiPoint(g) => g.to_svg_path(y_axis),
+            Geometry::MultiLineString(g) => g.to_svg_path(y_axis),
+            Geometry::MultiPolygon(g) => g.to_svg_path(y_axis),
+            Geometry::GeometryCollection(g) => g.to_svg_path(y_axis),
+            Geometry::Rect(g) => g.to_svg_path(y_axis),
+            Geometry::Triangle(g) => g.to_svg_path(y_axis),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+
+    #[test]
+    fn paths() {
+        let polygon = polygon![
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 1, y: 2), (x: 2, y: 2)]],
+        ];
+        assert_eq!(
+            polygon.to_svg_path(YAxis::Down),
+            "M0 0L4 0L4 4L0 4ZM1 1L1 2L2 2Z"
+        );
+        assert_eq!(
+            polygon.to_svg_path(YAxis::Up),
+            "M0 0L4 0L4 -4L0 -4ZM1 -1L1 -2L2 -2Z"
+        );
+
+        let multi_point = MultiPoint::new(vec![point!(x: 1, y: 2), point!(x: 3, y: 4)]);
+        assert_eq!(multi_point.to_svg_path(YAxis::Down), "M1 2h0M3 4h0");
+
+        let line = Line::new(coord! { x: 0.5, y: 0. }, coord! { x: 1., y: -1.5 });
+        assert_eq!(line.to_svg_path(YAxis::Up), "M0.5 0L1 1.5");
+
+        let rect = Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 });
+        assert_eq!(rect.to_svg_path(YAxis::Down), "M0 0L0 2L1 2L1 0Z");
+
+        let triangle = Triangle::new(
+            coord! { x: 0, y: 0 },
+            coord! { x: 1, y: 0 },
+            coord! { x: 0, y: 1 },
+        );
+        assert_eq!(triangle.to_svg_path(YAxis::Down), "M0 0L1 0L0 1Z");
+
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            point!(x: 1, y: 1).into(),
+            line_string![(x: 0, y: 0), (x: 2, y: 2)].into(),
+        ]));
+        assert_eq!(collection.to_svg_path(YAxis::Down), "M1 1h0M0 0L2 2");
+
+        assert_eq!(LineString::<f64>::new(vec![]).to_svg_path(YAxis::Up), "");
+        assert_eq!(MultiPolygon::<f64>::new(vec![]).to_svg_path(YAxis::Up), "");
+    }
+
+    #[test]
+    fn documents() {
+        let line_string = line_string![(x: 0, y: 0), (x: 10, y: 40)];
+        assert_eq!(
+            line_string.to_svg(YAxis::Down),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-2 -2 14 44\">\
+             <path d=\"M0 0L10 40\" fill=\"none\" fill-rule=\"evenodd\" stroke=\"black\" \
+             stroke-width=\"2\" stroke-linecap=\"round\" stroke-linejoin=\"round\" \
+             vector-effect=\"non-scaling-stroke\"/></svg>"
+        );
+
+        // a single point still gets a view box around it
+        let svg = point!(x: 3, y: 4).to_svg(YAxis::Up);
+        assert!(svg.contains("viewBox=\"2 -5 2 2\""));
+
+        let empty = GeometryCollection::<f64>::new_from(vec![]);
+        assert_eq!(
+            empty.to_svg(YAxis::Up),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\"/>"
+        );
+    }
+
+    #[test]
+    fn view_box() {
+        let rect = Rect::new(coord! { x: -10., y: 0. }, coord! { x: 10., y: 5. });
+        let view_box = ViewBox::fit(rect, 0., YAxis::Down);
+        assert_eq!(
+            view_box,
+            ViewBox {
+                min_x: -10.,
+                min_y: 0.,
+                width: 20.,
+                height: 5.,
+            }
+        );
+        assert_eq!(view_box.width_for_height(100.), 400.);
+        assert_eq!(ViewBox::fit(rect, 1., YAxis::Up).to_string(), "-11 -6 22 7");
+    }
+}
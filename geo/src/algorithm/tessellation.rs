@@ -0,0 +1,549 @@
+use crate::{
+    BooleanOps, BoundingRect, Coord, GeoFloat, Geometry, GeometryCollection, Intersects, Line,
+    LineString, MultiLineString, MultiPoint, MultiPolygon, OpType, OverlayArena, Point, Polygon,
+    Rect, Triangle,
+};
+
+/// Identifier of a cell in a [`SquareGrid`] (column, row) or [`HexGrid`] (axial `q`, `r`).
+pub type GridCell = (i64, i64);
+
+/// An infinite grid of axis-aligned squares with sides of length `size`, with a cell corner at
+/// `origin`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::tessellation::SquareGrid;
+/// use geo::coord;
+///
+/// let grid = SquareGrid::new(coord! { x: 0., y: 0. }, 10.);
+/// assert_eq!(grid.cell(coord! { x: 25., y: -3. }), (2, -1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SquareGrid<T: GeoFloat> {
+    origin: Coord<T>,
+    size: T,
+}
+
+impl<T: GeoFloat> SquareGrid<T> {
+    /// Create a grid of squares with sides of length `size`.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is not greater than 0.
+    pub fn new(origin: Coord<T>, size: T) -> Self {
+        assert!(size > T::zero(), "grid cells must have a positive size");
+        SquareGrid { origin, size }
+    }
+
+    /// The cell containing `coord`. Coordinates on the boundary between cells belong to the cell
+    /// above and to the right.
+    pub fn cell(&self, coord: Coord<T>) -> GridCell {
+        (
+            ((coord.x - self.origin.x) / self.size)
+                .floor()
+                .to_i64()
+                .unwrap(),
+            ((coord.y - self.origin.y) / self.size)
+                .floor()
+                .to_i64()
+                .unwrap(),
+        )
+    }
+
+    // The minimum corner of a cell.
+    fn cell_min(&self, (column, row): GridCell) -> Coord<T> {
+        Coord {
+            x: self.origin.x + T::from(column).unwrap() * self.size,
+            y: self.origin.y + T::from(row).unwrap() * self.size,
+        }
+    }
+
+    /// The polygon covered by a cell.
+    pub fn cell_polygon(&self, cell: GridCell) -> Polygon<T> {
+        let min = self.cell_min(cell);
+        let max = Coord {
+            x: min.x + self.size,
+            y: min.y + self.size,
+        };
+        Rect::new(min, max).to_polygon()
+    }
+
+    /// All cells intersecting `bounds`, row by row.
+    pub fn cells_covering(&self, bounds: Rect<T>) -> Vec<GridCell> {
+        let (min_column, min_row) = self.cell(bounds.min());
+        let (mut max_column, mut max_row) = self.cell(bounds.max());
+        // Unlike the minimum corner, the maximum corner only touches the
+        // boundary of the cell above and to the right of it.
+        let max_cell_min = self.cell_min((max_column, max_row));
+        if max_cell_min.x == bounds.max().x && max_column > min_column {
+            max_column -= 1;
+        }
+        if max_cell_min.y == bounds.max().y && max_row > min_row {
+            max_row -= 1;
+        }
+        (min_row..=max_row)
+            .flat_map(|row| (min_column..=max_column).map(move |column| (column, row)))
+            .collect()
+    }
+
+    /// Tessellate `bounds` into the squares of the grid intersecting it.
+    pub fn tessellate(&self, bounds: Rect<T>) -> MultiPolygon<T> {
+        self.cells_covering(bounds)
+            .into_iter()
+            .map(|cell| self.cell_polygon(cell))
+            .collect()
+    }
+}
+
+/// An infinite grid of regular, flat-topped hexagons with a circumradius (and side length) of
+/// `size`, with the center of the cell `(0, 0)` at `origin`.
+///
+/// Cells are identified by their [axial coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-axial)
+/// `(q, r)`: moving one cell to the right along a row of hexagons increments `q`, and moving
+/// one cell up increments `r`.
+///
+/// This makes it easy to bin points into hexagons:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use geo::tessellation::HexGrid;
+/// use geo::{coord, point, MultiPoint};
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: 0.1, y: 0.2),
+///     point!(x: -0.3, y: 0.1),
+///     point!(x: 10., y: 10.),
+/// ]);
+/// let grid = HexGrid::new(coord! { x: 0., y: 0. }, 1.);
+///
+/// let mut counts = HashMap::new();
+/// for point in &points {
+///     *counts.entry(grid.cell(point.0)).or_insert(0) += 1;
+/// }
+/// assert_eq!(counts[&(0, 0)], 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HexGrid<T: GeoFloat> {
+    origin: Coord<T>,
+    size: T,
+}
+
+impl<T: GeoFloat> HexGrid<T> {
+    /// Create a grid of hexagons with a circumradius of `size`.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is not greater than 0.
+    pub fn new(origin: Coord<T>, size: T) -> Self {
+        assert!(size > T::zero(), "grid cells must have a positive size");
+        HexGrid { origin, size }
+    }
+
+    fn half_height(&self) -> T {
+        self.size * T::from(3.0).unwrap().sqrt() / (T::one() + T::one())
+    }
+
+    /// The center of a cell.
+    pub fn cell_center(&self, (q, r): GridCell) -> Coord<T> {
+        let q = T::from(q).unwrap();
+        let r = T::from(r).unwrap();
+        let two = T::one() + T::one();
+        Coord {
+            x: self.origin.x + self.size * T::from(1.5).unwrap() * q,
+            y: self.origin.y + self.half_height() * (two * r + q),
+        }
+    }
+
+    /// The cell containing `coord`.
+    pub fn cell(&self, coord: Coord<T>) -> GridCell {
+        let x = (coord.x - self.origin.x) / self.size;
+        let y = (coord.y - self.origin.y) / self.size;
+        let third = T::one() / T::from(3.0).unwrap();
+        // fractional axial coordinates, rounded via cube coordinates
+        let q = (T::one() + T::one()) * third * x;
+        let r = -third * x + T::from(3.0).unwrap().sqrt() * third * y;
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        (rq.to_i64().unwrap(), rr.to_i64().unwrap())
+    }
+
+    /// The hexagon covered by a cell, with counter-clockwise winding.
+    pub fn cell_polygon(&self, cell: GridCell) -> Polygon<T> {
+        let center = self.cell_center(cell);
+        let half_size = self.size / (T::one() + T::one());
+        let half_height = self.half_height();
+        let offsets = [
+            (self.size, T::zero()),
+            (half_size, half_height),
+            (-half_size, half_height),
+            (-self.size, T::zero()),
+            (-half_size, -half_height),
+            (half_size, -half_height),
+        ];
+        let ring: LineString<T> = offsets
+            .iter()
+            .map(|&(dx, dy)| Coord {
+                x: center.x + dx,
+                y: center.y + dy,
+            })
+            .collect();
+        Polygon::new(ring, vec![])
+    }
+
+    /// All cells intersecting `bounds`, ordered by `q`, then `r`.
+    pub fn cells_covering(&self, bounds: Rect<T>) -> Vec<GridCell> {
+        let column_width = self.size * T::from(1.5).unwrap();
+        let row_height = self.half_height() + self.half_height();
+        let half = T::from(0.5).unwrap();
+        let to_i64 = |value: T| value.to_i64().unwrap();
+
+        let min_q = to_i64(((bounds.min().x - self.origin.x - self.size) / column_width).floor());
+        let max_q = to_i64(((bounds.max().x - self.origin.x + self.size) / column_width).ceil());
+        let mut cells = vec![];
+        for q in min_q..=max_q {
+            let shift = T::from(q).unwrap() * half;
+            let min_r = to_i64(
+                ((bounds.min().y - self.origin.y - self.half_height()) / row_height - shift)
+                    .floor(),
+            );
+            let max_r = to_i64(
+                ((bounds.max().y - self.origin.y + self.half_height()) / row_height - shift).ceil(),
+            );
+            for r in min_r..=max_r {
+                if self.cell_polygon((q, r)).intersects(&bounds) {
+                    cells.push((q, r));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Tessellate `bounds` into the hexagons of the grid intersecting it.
+    pub fn tessellate(&self, bounds: Rect<T>) -> MultiPolygon<T> {
+        self.cells_covering(bounds)
+            .into_iter()
+            .map(|cell| self.cell_polygon(cell))
+            .collect()
+    }
+}
+
+/// Tessellate the bounding rectangle of a geometry into square or hexagonal cells.
+///
+/// Grids are anchored at the minimum corner of the bounding rectangle: squares start at this
+/// corner, and the center of the first hexagon lies on it. Each cell is returned with its
+/// [`GridCell`] in the [`SquareGrid`] or [`HexGrid`] anchored there. For a grid anchored
+/// elsewhere, use these directly.
+///
+/// If `clip` is `false`, every cell intersecting the bounding rectangle is returned in full. If
+/// it is `true`, the cells are intersected with the polygons of the geometry, cells intersecting
+/// its points or lines are returned in full, and empty cells are dropped. A cell split by the
+/// geometry into several pieces is returned once, with all of them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Area, Polygon, Tessellate};
+///
+/// let triangle: Polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)];
+///
+/// let squares = triangle.square_tessellation(1., false);
+/// assert_eq!(squares.len(), 16);
+/// assert_eq!(squares[5].0, (1, 1));
+///
+/// let clipped = triangle.square_tessellation(1., true);
+/// assert_eq!(clipped.len(), 10);
+/// let area: f64 = clipped.iter().map(|(_, piece)| piece.unsigned_area()).sum();
+/// assert!((area - triangle.unsigned_area()).abs() < 1e-9);
+///
+/// let hexagons = triangle.hex_tessellation(1., true);
+/// let area: f64 = hexagons.iter().map(|(_, piece)| piece.unsigned_area()).sum();
+/// assert!((area - triangle.unsigned_area()).abs() < 1e-9);
+///
+/// // the squares a line string passes through
+/// let line_string = line_string![(x: 0., y: 0.), (x: 3., y: 2.)];
+/// let cells: Vec<_> = line_string
+///     .square_tessellation(1., true)
+///     .into_iter()
+///     .map(|(cell, _)| cell)
+///     .collect();
+/// assert_eq!(cells, vec![(0, 0), (1, 0), (1, 1), (2, 1)]);
+/// ```
+pub trait Tessellate<T: GeoFloat> {
+    /// Tessellate into squares with sides of length `size`.
+    fn square_tessellation(&self, size: T, clip: bool) -> Vec<(GridCell, MultiPolygon<T>)>;
+
+    /// Tessellate into flat-topped hexagons with a circumradius of `size`.
+    fn hex_tessellation(&self, size: T, clip: bool) -> Vec<(GridCell, MultiPolygon<T>)>;
+}
+
+macro_rules! tessellate_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<T: GeoFloat> Tessellate<T> for $type<T> {
+                fn square_tessellation(
+                    &self,
+                    size: T,
+                    clip: bool,
+                ) -> Vec<(GridCell, MultiPolygon<T>)> {
+                    tessellation(self, clip, |bounds| {
+                        let grid = SquareGrid::new(bounds.min(), size);
+                        grid.cells_covering(bounds)
+                            .into_iter()
+                            .map(|cell| (cell, grid.cell_polygon(cell)))
+                            .collect()
+                    })
+                }
+
+                fn hex_tessellation(
+                    &self,
+                    size: T,
+                    clip: bool,
+                ) -> Vec<(GridCell, MultiPolygon<T>)> {
+                    tessellation(self, clip, |bounds| {
+                        let grid = HexGrid::new(bounds.min(), size);
+                        grid.cells_covering(bounds)
+                            .into_iter()
+                            .map(|cell| (cell, grid.cell_polygon(cell)))
+                            .collect()
+                    })
+                }
+            }
+        )+
+    };
+}
+
+tessellate_impl!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+    Geometry
+);
+
+fn tessellation<T, G>(
+    geometry: &G,
+    clip: bool,
+    cells: impl FnOnce(Rect<T>) -> Vec<(GridCell, Polygon<T>)>,
+) -> Vec<(GridCell, MultiPolygon<T>)>
+where
+    T: GeoFloat,
+    G: BoundingRect<T> + ClipCell<T>,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    let bounds = match geometry.bounding_rect().into() {
+        Some(bounds) => bounds,
+        None => return vec![],
+    };
+    let cells = cells(bounds).into_iter();
+    if !clip {
+        return cells
+            .map(|(cell, polygon)| (cell, MultiPolygon::new(vec![polygon])))
+            .collect();
+    }
+    let mut arena = OverlayArena::new();
+    cells
+        .map(|(cell, polygon)| (cell, geometry.clip_cell(&polygon, &mut arena)))
+        .filter(|(_, piece)| !piece.0.is_empty())
+        .collect()
+}
+
+// The part of a cell covered by a geometry: its intersection with polygons, and the whole cell
+// if it intersects points or lines.
+trait ClipCell<T: GeoFloat> {
+    fn clip_cell(&self, cell: &Polygon<T>, arena: &mut OverlayArena<T>) -> MultiPolygon<T>;
+}
+
+macro_rules! clip_cell_by_intersection_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<T: GeoFloat> ClipCell<T> for $type<T> {
+                fn clip_cell(
+                    &self,
+                    cell: &Polygon<T>,
+                    _arena: &mut OverlayArena<T>,
+                ) -> MultiPolygon<T> {
+                    if self.intersects(cell) {
+                        MultiPolygon::new(vec![cell.clone()])
+                    } else {
+                        MultiPolygon::new(vec![])
+                    }
+                }
+            }
+        )+
+    };
+}
+
+clip_cell_by_intersection_impl!(Point, Line, LineString, MultiPoint, MultiLineString);
+
+impl<T: GeoFloat> ClipCell<T> for Polygon<T> {
+    fn clip_cell(&self, cell: &Polygon<T>, arena: &mut OverlayArena<T>) -> MultiPolygon<T> {
+        if !self.intersects(cell) {
+            return MultiPolygon::new(vec![]);
+        }
+        cell.boolean_op_in(self, OpType::Intersection, arena)
+    }
+}
+
+impl<T: GeoFloat> ClipCell<T> for MultiPolygon<T> {
+    fn clip_cell(&self, cell: &Polygon<T>, arena: &mut OverlayArena<T>) -> MultiPolygon<T> {
+        if !self.intersects(cell) {
+            return MultiPolygon::new(vec![]);
+        }
+        MultiPolygon::new(vec![cell.clone()]).boolean_op_in(self, OpType::Intersection, arena)
+    }
+}
+
+impl<T: GeoFloat> ClipCell<T> for Rect<T> {
+    fn clip_cell(&self, cell: &Polygon<T>, arena: &mut OverlayArena<T>) -> MultiPolygon<T> {
+        self.to_polygon().clip_cell(cell, arena)
+    }
+}
+
+impl<T: GeoFloat> ClipCell<T> for Triangle<T> {
+    fn clip_cell(&self, cell: &Polygon<T>, arena: &mut OverlayArena<T>) -> MultiPolygon<T> {
+        self.to_polygon().clip_cell(cell, arena)
+    }
+}
+
+impl<T: GeoFloat> ClipCell<T> for GeometryCollection<T> {
+    fn clip_cell(&self, cell: &Polygon<T>, arena: &mut OverlayArena<T>) -> MultiPolygon<T> {
+        self.iter()
+            .fold(MultiPolygon::new(vec![]), |covered, geometry| {
+                let piece = geometry.clip_cell(cell, arena);
+                if covered.0.is_empty() {
+                    piece
+                } else if piece.0.is_empty() {
+                    covered
+                } else {
+                    covered.boolean_op_in(&piece, OpType::Union, arena)
+                }
+            })
+    }
+}
+
+impl<T: GeoFloat> ClipCell<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn clip_cell(&self, cell: &Polygon<T>, arena: &mut OverlayArena<T>) -> MultiPolygon<T>;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, Area, Centroid};
+
+    fn area(cells: &[(GridCell, MultiPolygon<f64>)]) -> f64 {
+        cells.iter().map(|(_, piece)| piece.unsigned_area()).sum()
+    }
+
+    #[test]
+    fn square_cells() {
+        let grid = SquareGrid::new(coord! { x: 1., y: 1. }, 2.);
+        assert_eq!(grid.cell(coord! { x: 1., y: 1. }), (0, 0));
+        assert_eq!(grid.cell(coord! { x: 0.5, y: 3. }), (-1, 1));
+        let cells = grid.cells_covering(Rect::new((1., 1.), (5., 4.)));
+        assert_eq!(cells, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn square_tessellation_of_rect() {
+        let rect = Rect::new((0., 0.), (3., 2.));
+        let squares = rect.square_tessellation(1., false);
+        assert_eq!(squares.len(), 6);
+        assert_eq!(squares[4].0, (1, 1));
+        assert_relative_eq!(area(&squares), 6.);
+    }
+
+    #[test]
+    fn hex_cells_round_trip() {
+        let grid = HexGrid::new(coord! { x: 10., y: -5. }, 2.5);
+        for q in -3..3 {
+            for r in -3..3 {
+                let polygon = grid.cell_polygon((q, r));
+                let centroid = polygon.centroid().unwrap();
+                let center = grid.cell_center((q, r));
+                assert_relative_eq!(centroid.x(), center.x, epsilon = 1e-9);
+                assert_relative_eq!(centroid.y(), center.y, epsilon = 1e-9);
+                assert_eq!(grid.cell(grid.cell_center((q, r))), (q, r));
+            }
+        }
+    }
+
+    #[test]
+    fn hex_tessellation_covers_bounds() {
+        let rect = Rect::new((0., 0.), (10., 7.));
+        let hexagons = rect.hex_tessellation(1., false);
+        assert!(area(&hexagons) > rect.unsigned_area());
+        // hexagons tile the plane without overlaps
+        let clipped = rect.hex_tessellation(1., true);
+        assert_relative_eq!(area(&clipped), 70., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn clipped_polygon_with_hole() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        let squares = poly.square_tessellation(2., true);
+        assert_relative_eq!(area(&squares), 64., epsilon = 1e-9);
+        // cells inside the hole only touch the polygon, and are dropped
+        assert_eq!(squares.len(), 25 - 9);
+    }
+
+    #[test]
+    fn pieces_grouped_by_cell() {
+        let polygons = MultiPolygon::new(vec![
+            Rect::new((0., 0.), (1., 1.)).to_polygon(),
+            Rect::new((3., 3.), (4., 4.)).to_polygon(),
+        ]);
+        let squares = polygons.square_tessellation(10., true);
+        assert_eq!(squares.len(), 1);
+        assert_eq!(squares[0].0, (0, 0));
+        assert_eq!(squares[0].1 .0.len(), 2);
+    }
+
+    #[test]
+    fn tessellation_of_points_and_lines() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 3., y: 2.)];
+        assert_eq!(line_string.square_tessellation(1., false).len(), 6);
+        let squares = line_string.square_tessellation(1., true);
+        let cells: Vec<GridCell> = squares.iter().map(|(cell, _)| *cell).collect();
+        assert_eq!(cells, vec![(0, 0), (1, 0), (1, 1), (2, 1)]);
+        // cells intersecting lines are kept whole
+        assert_relative_eq!(area(&squares), 4.);
+
+        let hexagons = point!(x: 5., y: 5.).hex_tessellation(1., true);
+        assert_eq!(hexagons.len(), 1);
+        assert_eq!(hexagons[0].0, (0, 0));
+
+        assert!(LineString::<f64>::new(vec![])
+            .square_tessellation(1., false)
+            .is_empty());
+    }
+
+    #[test]
+    fn tessellation_of_collection() {
+        let collection = GeometryCollection::new_from(vec![
+            Rect::new((0., 0.), (1.5, 1.)).into(),
+            point!(x: 3.5, y: 0.5).into(),
+        ]);
+        let squares = Geometry::GeometryCollection(collection).square_tessellation(1., true);
+        let cells: Vec<GridCell> = squares.iter().map(|(cell, _)| *cell).collect();
+        assert_eq!(cells, vec![(0, 0), (1, 0), (3, 0)]);
+        assert_relative_eq!(area(&squares), 2.5);
+    }
+}
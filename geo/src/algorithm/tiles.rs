@@ -0,0 +1,514 @@
+use std::collections::HashSet;
+
+use crate::clip_to_window::non_empty;
+use crate::geometry::*;
+use crate::{BoundingRect, ClipToRect, Contains, GeoFloat, Intersects};
+
+/// Half the width of the Web Mercator (EPSG:3857) square, in meters: π times the radius of the
+/// WGS84 ellipsoid.
+const HALF_WORLD: f64 = 20_037_508.342_789_244;
+
+/// The deepest zoom level, whose tile indices still fit in a `u32`.
+const MAX_ZOOM: u8 = 32;
+
+/// A tile of the Web Mercator square in the XYZ scheme of slippy maps: at zoom `z`, the square
+/// is cut into `2^z` by `2^z` tiles, numbered from the west for `x` and from the north for `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+    pub z: u8,
+}
+
+impl Tile {
+    /// The extent of the tile, in Web Mercator meters.
+    ///
+    /// # Panics
+    ///
+    /// If the zoom of the tile is greater than 32.
+    pub fn bounds<T: GeoFloat>(&self) -> Rect<T> {
+        check_zoom(self.z);
+        let (half_world, size) = world_and_tile_size::<T>(self.z);
+        let x = T::from(self.x).unwrap();
+        let y = T::from(self.y).unwrap();
+        Rect::new(
+            Coord {
+                x: -half_world + x * size,
+                y: half_world - (y + T::one()) * size,
+            },
+            Coord {
+                x: -half_world + (x + T::one()) * size,
+                y: half_world - y * size,
+            },
+        )
+    }
+
+    /// The quadkey of the tile, as used by Bing Maps: a digit per zoom level, from the
+    /// coarsest, with the bit of `x` at that level as its low bit and that of `y` as its high
+    /// bit. Empty at zoom 0.
+    ///
+    /// # Panics
+    ///
+    /// If the zoom of the tile is greater than 32.
+    pub fn quadkey(&self) -> String {
+        check_zoom(self.z);
+        (1..=self.z)
+            .rev()
+            .map(|level| {
+                let mask = 1 << (level - 1);
+                let digit = (self.x & mask != 0) as u8 + 2 * (self.y & mask != 0) as u8;
+                char::from(b'0' + digit)
+            })
+            .collect()
+    }
+}
+
+/// Cut a geometry into the tiles it overlaps at a zoom level, e.g. as the geometric step of a
+/// vector-tile pipeline, returning each tile with the part of the geometry within it.
+///
+/// The geometry is in Web Mercator (EPSG:3857) meters. It's clipped by
+/// [`ClipToRect`](crate::ClipToRect) to the extent of each tile its bounding rectangle
+/// overlaps, grown on every side by `buffer` times the width of a tile, e.g. `64. / 4096.` for
+/// a buffer of 64 pixels in tiles of 4096 pixels, so that lines and the borders of polygons
+/// aren't cut right at the edge of the tiles where they're drawn. Tiles are returned row by
+/// row, from the north-west, and those the geometry doesn't reach are skipped. Parts of the
+/// geometry outside the Web Mercator square are dropped.
+///
+/// # Panics
+///
+/// If `zoom` is greater than 32.
+///
+/// # Examples
+///
+/// ```
+/// use geo::tiles::slice_to_tiles;
+/// use geo::{Geometry, Rect, Tile};
+///
+/// let square: Geometry = Rect::new((-1000., -1000.), (1000., 1000.)).into();
+///
+/// let tiles = slice_to_tiles(&square, 1, 0.);
+/// assert_eq!(tiles.len(), 4);
+/// assert_eq!(tiles[0].0, Tile { x: 0, y: 0, z: 1 });
+/// assert_eq!(tiles[0].1, Rect::new((-1000., 0.), (0., 1000.)).into());
+/// ```
+pub fn slice_to_tiles<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    zoom: u8,
+    buffer: T,
+) -> Vec<(Tile, Geometry<T>)> {
+    check_zoom(zoom);
+    let bounds = match geometry.bounding_rect() {
+        Some(bounds) => bounds,
+        None => return vec![],
+    };
+    let margin = buffer * world_and_tile_size::<T>(zoom).1;
+    let grow = Coord {
+        x: margin,
+        y: margin,
+    };
+    tiles_around(bounds, zoom, margin)
+        .filter_map(|tile| {
+            let extent = tile.bounds::<T>();
+            let buffered = Rect::new(extent.min() - grow, extent.max() + grow);
+            geometry
+                .clip_to_rect(&buffered)
+                .map(|clipped| (tile, clipped))
+        })
+        .collect()
+}
+
+/// The tiles of a zoom level which a geometry intersects, e.g. to prefetch them or to invalidate
+/// them in a cache when the geometry changes, or, if `interior_only`, those wholly within it.
+///
+/// The geometry is in Web Mercator (EPSG:3857) meters. The tiles its bounding rectangle
+/// overlaps are checked with [`Intersects`](crate::Intersects), so that tiles whose border
+/// touches the geometry are included, or with [`Contains`](crate::Contains). Tiles are
+/// returned row by row, from the north-west. Use [`Tile::quadkey`] for their quadkeys.
+///
+/// # Panics
+///
+/// If `zoom` is greater than 32.
+///
+/// # Examples
+///
+/// ```
+/// use geo::tiles::tiles_covering;
+/// use geo::{Geometry, Rect, Tile};
+///
+/// let square: Geometry = Rect::new((-1000., -1000.), (1000., 1000.)).into();
+///
+/// let tiles = tiles_covering(&square, 1, false);
+/// assert_eq!(tiles.len(), 4);
+/// assert_eq!(tiles[0], Tile { x: 0, y: 0, z: 1 });
+/// assert_eq!(tiles[0].quadkey(), "0");
+/// assert!(tiles_covering(&square, 1, true).is_empty());
+/// ```
+pub fn tiles_covering<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    zoom: u8,
+    interior_only: bool,
+) -> Vec<Tile> {
+    check_zoom(zoom);
+    let bounds = match geometry.bounding_rect() {
+        Some(bounds) => bounds,
+        None => return vec![],
+    };
+    tiles_around(bounds, zoom, T::zero())
+        .filter(|tile| {
+            let extent = tile.bounds::<T>();
+            if interior_only {
+                geometry.contains(&extent)
+            } else {
+                geometry.intersects(&extent)
+            }
+        })
+        .collect()
+}
+
+/// Quantize a geometry into the integer coordinates of a tile, e.g. a part of a geometry cut by
+/// [`slice_to_tiles`], for encoders of vector tiles such as Mapbox Vector Tiles.
+///
+/// The geometry is in Web Mercator (EPSG:3857) meters. Its coordinates are scaled so that the
+/// tile spans `0` to `extent`, e.g. 4096, with `x` from the west and `y` from the north of the
+/// tile, and rounded to the nearest integer; those in a buffer around the tile fall outside
+/// this range. The parts which rounding makes degenerate are then cleaned up: repeated
+/// consecutive coordinates and repeated points are removed, as are lines collapsed to a point
+/// and rings with no area left, along with their polygon for an exterior ring. `None` if
+/// nothing is left.
+///
+/// As `y` is flipped, the winding order of rings, as computed from their coordinates, is
+/// reversed: orient them as the encoder expects.
+///
+/// # Panics
+///
+/// If the zoom of `tile` is greater than 32.
+///
+/// # Examples
+///
+/// ```
+/// use geo::tiles::quantize_to_tile;
+/// use geo::{line_string, Geometry, Tile};
+///
+/// // the tile east of the prime meridian and north of the equator
+/// let tile = Tile { x: 1, y: 0, z: 1 };
+/// let road: Geometry = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5e6, y: 5e6)].into();
+///
+/// assert_eq!(
+///     quantize_to_tile(&road, tile, 4096),
+///     Some(line_string![(x: 0, y: 4096), (x: 1022, y: 3074)].into())
+/// );
+/// ```
+pub fn quantize_to_tile<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    tile: Tile,
+    extent: u32,
+) -> Option<Geometry<i32>> {
+    let bounds = tile.bounds::<T>();
+    let quantizer = Quantizer {
+        min_x: bounds.min().x,
+        max_y: bounds.max().y,
+        scale: T::from(extent).unwrap() / bounds.width(),
+    };
+    quantizer.geometry(geometry)
+}
+
+/// The tiles of a zoom level which `bounds`, grown on every side by `margin`, overlaps, row by
+/// row from the north-west, clamped to the Web Mercator square.
+fn tiles_around<T: GeoFloat>(bounds: Rect<T>, zoom: u8, margin: T) -> impl Iterator<Item = Tile> {
+    let (half_world, size) = world_and_tile_size::<T>(zoom);
+    let last_index = (1_u64 << zoom) - 1;
+    // the index of a tile along an axis, from the west or the north, clamped to the square
+    let clamp = |index: T| {
+        if index < T::zero() {
+            0
+        } else {
+            index
+                .to_u64()
+                .map_or(last_index, |index| index.min(last_index))
+        }
+    };
+    // the first and last tiles reaching an offset along an axis: on an edge between two
+    // tiles, the first is the one before the edge and the last the one after it
+    let first = |offset: T| clamp((offset / size).ceil() - T::one());
+    let last = |offset: T| clamp((offset / size).floor());
+
+    let (min_x, max_x) = (
+        first(bounds.min().x - margin + half_world),
+        last(bounds.max().x + margin + half_world),
+    );
+    let (min_y, max_y) = (
+        first(half_world - bounds.max().y - margin),
+        last(half_world - bounds.min().y + margin),
+    );
+    (min_y..=max_y).flat_map(move |y| {
+        (min_x..=max_x).map(move |x| Tile {
+            x: x as u32,
+            y: y as u32,
+            z: zoom,
+        })
+    })
+}
+
+/// Scales the coordinates of a geometry into those of a tile, and cleans up the degenerate parts.
+struct Quantizer<T: GeoFloat> {
+    min_x: T,
+    max_y: T,
+    scale: T,
+}
+
+impl<T: GeoFloat> Quantizer<T> {
+    fn coord(&self, coord: Coord<T>) -> Coord<i32> {
+        Coord {
+            x: round((coord.x - self.min_x) * self.scale),
+            y: round((self.max_y - coord.y) * self.scale),
+        }
+    }
+
+    /// The coordinates of a line string, without repeated consecutive ones.
+    fn coords(&self, line_string: &LineString<T>) -> Vec<Coord<i32>> {
+        let mut coords: Vec<_> = line_string.0.iter().map(|c| self.coord(*c)).collect();
+        coords.dedup();
+        coords
+    }
+
+    fn line_string(&self, line_string: &LineString<T>) -> Option<LineString<i32>> {
+        let coords = self.coords(line_string);
+        (coords.len() >= 2).then(|| LineString::new(coords))
+    }
+
+    fn ring(&self, ring: &LineString<T>) -> Option<LineString<i32>> {
+        let mut ring = LineString::new(self.coords(ring));
+        ring.close();
+        (twice_area(&ring) != 0).then(|| ring)
+    }
+
+    fn polygon(&self, polygon: &Polygon<T>) -> Option<Polygon<i32>> {
+        let exterior = self.ring(polygon.exterior())?;
+        let interiors = polygon
+            .interiors()
+            .iter()
+            .filter_map(|interior| self.ring(interior))
+            .collect();
+        Some(Polygon::new(exterior, interiors))
+    }
+
+    fn geometry(&self, geometry: &Geometry<T>) -> Option<Geometry<i32>> {
+        match geometry {
+            Geometry::Point(point) => Some(Point(self.coord(point.0)).into()),
+            Geometry::Line(line) => {
+                let (start, end) = (self.coord(line.start), self.coord(line.end));
+                (start != end).then(|| Line::new(start, end).into())
+            }
+            Geometry::LineString(g) => self.line_string(g).map(Geometry::LineString),
+            Geometry::Polygon(g) => self.polygon(g).map(Geometry::Polygon),
+            Geometry::MultiPoint(g) => {
+                let mut seen = HashSet::new();
+                let points = g
+                    .iter()
+                    .map(|point| self.coord(point.0))
+                    .filter(|coord| seen.insert(*coord))
+                    .map(Point)
+                    .collect();
+                non_empty(MultiPoint::new(points)).map(Geometry::MultiPoint)
+            }
+            Geometry::MultiLineString(g) => {
+                let line_strings = g.iter().filter_map(|ls| self.line_string(ls)).collect();
+                non_empty(MultiLineString::new(line_strings)).map(Geometry::MultiLineString)
+            }
+            Geometry::MultiPolygon(g) => {
+                let polygons = g
+                    .iter()
+                    .filter_map(|polygon| self.polygon(polygon))
+                    .collect();
+                non_empty(MultiPolygon::new(polygons)).map(Geometry::MultiPolygon)
+            }
+            Geometry::GeometryCollection(g) => {
+                let geometries = g.iter().filter_map(|g| self.geometry(g)).collect();
+                non_empty(GeometryCollection::new_from(geometries))
+                    .map(Geometry::GeometryCollection)
+            }
+            Geometry::Rect(rect) => {
+                let (min, max) = (self.coord(rect.min()), self.coord(rect.max()));
+                (min.x != max.x && min.y != max.y).then(|| Rect::new(min, max).into())
+            }
+            Geometry::Triangle(g) => self.polygon(&g.to_polygon()).map(Geometry::Polygon),
+        }
+    }
+}
+
+/// Round to the nearest integer, saturating at the bounds of `i32`.
+fn round<T: GeoFloat>(value: T) -> i32 {
+    value.round().to_i32().unwrap_or(if value < T::zero() {
+        i32::MIN
+    } else {
+        i32::MAX
+    })
+}
+
+/// Twice the signed area of a closed ring, exactly.
+fn twice_area(ring: &LineString<i32>) -> i64 {
+    ring.lines()
+        .map(|line| {
+            i64::from(line.start.x) * i64::from(line.end.y)
+                - i64::from(line.end.x) * i64::from(line.start.y)
+        })
+        .sum()
+}
+
+/// Panics if the tile indices at `zoom` don't fit in a `u32`.
+fn check_zoom(zoom: u8) {
+    assert!(zoom <= MAX_ZOOM, "the zoom must be {} at most", MAX_ZOOM);
+}
+
+/// Half the width of the Web Mercator square, and the width of a tile at `zoom`.
+fn world_and_tile_size<T: GeoFloat>(zoom: u8) -> (T, T) {
+    let half_world = T::from(HALF_WORLD).unwrap();
+    let tiles = T::from(1_u64 << zoom).unwrap();
+    (half_world, (half_world + half_world) / tiles)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Area};
+
+    #[test]
+    fn bounds() {
+        let world: Rect<f64> = Tile { x: 0, y: 0, z: 0 }.bounds();
+        assert_eq!(world.min(), Coord::from((-HALF_WORLD, -HALF_WORLD)));
+        assert_eq!(world.max(), Coord::from((HALF_WORLD, HALF_WORLD)));
+
+        let tile: Rect<f64> = Tile { x: 1, y: 0, z: 1 }.bounds();
+        assert_eq!(tile, Rect::new((0., 0.), (HALF_WORLD, HALF_WORLD)));
+    }
+
+    #[test]
+    fn slices() {
+        let square: Geometry<f64> = Rect::new((-1000., -1000.), (1000., 1000.)).into();
+        let tiles = slice_to_tiles(&square, 1, 0.);
+        assert_eq!(
+            tiles
+                .iter()
+                .map(|(tile, _)| (tile.x, tile.y))
+                .collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+        for (_, clipped) in &tiles {
+            assert_eq!(clipped.unsigned_area(), 1000. * 1000.);
+        }
+
+        // just north of the equator, within a single tile
+        let line: Geometry<f64> = line_string![(x: 100., y: 10.), (x: 200., y: 10.)].into();
+        let tiles = slice_to_tiles(&line, 1, 0.);
+        assert_eq!(tiles, vec![(Tile { x: 1, y: 0, z: 1 }, line)]);
+
+        // outside the Web Mercator square
+        let outside: Geometry<f64> = point!(x: 0., y: 3. * HALF_WORLD).into();
+        assert!(slice_to_tiles(&outside, 2, 0.).is_empty());
+    }
+
+    #[test]
+    fn buffer() {
+        let near_corner: Geometry<f64> = point!(x: 1., y: 1.).into();
+        assert_eq!(slice_to_tiles(&near_corner, 1, 0.).len(), 1);
+
+        let tiles = slice_to_tiles(&near_corner, 1, 0.01);
+        assert_eq!(tiles.len(), 4);
+        assert!(tiles.iter().all(|(_, clipped)| clipped == &near_corner));
+    }
+
+    #[test]
+    fn covering() {
+        // over the whole north-west tile, and reaching into the others
+        let rect: Geometry<f64> = Rect::new((-HALF_WORLD, -1000.), (1000., HALF_WORLD)).into();
+        assert_eq!(tiles_covering(&rect, 1, false).len(), 4);
+        assert_eq!(
+            tiles_covering(&rect, 1, true),
+            vec![Tile { x: 0, y: 0, z: 1 }]
+        );
+
+        let point: Geometry<f64> = point!(x: 1., y: 1.).into();
+        assert_eq!(
+            tiles_covering(&point, 3, false),
+            vec![Tile { x: 4, y: 3, z: 3 }]
+        );
+        assert!(tiles_covering(&point, 3, true).is_empty());
+
+        // on the corner of four tiles, and on the edge between two
+        let corner: Geometry<f64> = point!(x: 0., y: 0.).into();
+        assert_eq!(
+            tiles_covering(&corner, 1, false),
+            vec![
+                Tile { x: 0, y: 0, z: 1 },
+                Tile { x: 1, y: 0, z: 1 },
+                Tile { x: 0, y: 1, z: 1 },
+                Tile { x: 1, y: 1, z: 1 },
+            ]
+        );
+        let edge: Geometry<f64> = line_string![(x: 0., y: 1000.), (x: 0., y: 2000.)].into();
+        assert_eq!(
+            tiles_covering(&edge, 1, false),
+            vec![Tile { x: 0, y: 0, z: 1 }, Tile { x: 1, y: 0, z: 1 }]
+        );
+    }
+
+    #[test]
+    fn quantize() {
+        let tile = Tile { x: 1, y: 0, z: 1 };
+        let (h, half, quarter) = (HALF_WORLD, HALF_WORLD / 2., HALF_WORLD / 4.);
+
+        let square: Geometry<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: half, y: 0.), (x: half, y: half), (x: 0., y: half)],
+            interiors: [
+                // collapsed by rounding
+                [
+                    (x: quarter, y: quarter),
+                    (x: quarter + 1., y: quarter),
+                    (x: quarter, y: quarter + 1.),
+                ],
+            ],
+        ]
+        .into();
+        assert_eq!(
+            quantize_to_tile(&square, tile, 4096),
+            Some(
+                polygon![
+                    (x: 0, y: 4096),
+                    (x: 2048, y: 4096),
+                    (x: 2048, y: 2048),
+                    (x: 0, y: 2048),
+                ]
+                .into()
+            )
+        );
+        let speck: Geometry<f64> =
+            Rect::new((quarter, quarter), (quarter + 1., quarter + 1.)).into();
+        assert_eq!(quantize_to_tile(&speck, tile, 4096), None);
+
+        let points: Geometry<f64> = MultiPoint::new(vec![
+            // in the buffer
+            point!(x: -h / 4096. * 10., y: h),
+            point!(x: half, y: half),
+            point!(x: half + 1., y: half),
+        ])
+        .into();
+        assert_eq!(
+            quantize_to_tile(&points, tile, 4096),
+            Some(MultiPoint::new(vec![point!(x: -10, y: 0), point!(x: 2048, y: 2048)]).into())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the zoom must be 32 at most")]
+    fn zoom_too_deep() {
+        let point: Geometry<f64> = point!(x: 1., y: 1.).into();
+        tiles_covering(&point, 33, false);
+    }
+
+    #[test]
+    fn quadkeys() {
+        assert_eq!(Tile { x: 3, y: 5, z: 3 }.quadkey(), "213");
+        assert_eq!(Tile { x: 1, y: 0, z: 1 }.quadkey(), "1");
+        assert_eq!(Tile { x: 0, y: 0, z: 0 }.quadkey(), "");
+    }
+}
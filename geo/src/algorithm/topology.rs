@@ -0,0 +1,359 @@
+use crate::utils::{least_index, lex_cmp};
+use crate::{Coord, CoordNum, LineString, Polygon};
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Polygons whose shared boundaries are stored once, as [TopoJSON] does.
+///
+/// The rings of the polygons are cut at every junction, where rings meeting along a boundary
+/// start or stop running alongside each other, into arcs, and every distinct arc is stored once.
+/// Each ring is then a list of references to arcs: `i` is `arcs()[i]` and `!i` (`-1 - i`) is
+/// `arcs()[i]` reversed, which concatenated, dropping the first coordinate of every arc but the
+/// first, give back the ring.
+///
+/// Besides being more compact, this allows modifying the arcs, rather than the polygons, with
+/// [`map_arcs`](Topology::map_arcs), so that e.g. simplifying a coverage of polygons doesn't open
+/// gaps or overlaps between them.
+///
+/// [TopoJSON]: https://github.com/topojson/topojson-specification
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Topology};
+///
+/// let left = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let right = polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)];
+///
+/// let topology = Topology::new(&[left, right]);
+/// // the boundaries of each polygon, apart from the edge they share, and the shared edge
+/// assert_eq!(topology.arcs().len(), 3);
+/// // the shared edge is the first arc of the left polygon, and is reversed in the right one
+/// assert_eq!(topology.polygon_arcs(), &[vec![vec![0, 1]], vec![vec![2, !0]]]);
+///
+/// let decoded = topology.to_polygons();
+/// assert_eq!(
+///     decoded[0],
+///     polygon![(x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.)]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Topology<T: CoordNum> {
+    arcs: Vec<LineString<T>>,
+    polygon_arcs: Vec<Vec<Vec<isize>>>,
+}
+
+impl<T: CoordNum> Topology<T> {
+    /// Cut the rings of `polygons` into shared arcs.
+    ///
+    /// Repeated consecutive coordinates are dropped, and rings are closed, but otherwise
+    /// polygons are taken as they are: boundaries are only shared if they have the same
+    /// coordinates, and ring orientations are kept.
+    ///
+    /// # Panics
+    ///
+    /// If any coordinate is NaN.
+    pub fn new(polygons: &[Polygon<T>]) -> Self {
+        let rings: Vec<Vec<Vec<Coord<T>>>> = polygons
+            .iter()
+            .map(|polygon| {
+                if polygon.exterior().0.is_empty() {
+                    return vec![];
+                }
+                std::iter::once(polygon.exterior())
+                    .chain(polygon.interiors())
+                    .map(ring_vertices)
+                    .filter(|ring| !ring.is_empty())
+                    .collect()
+            })
+            .collect();
+        let junctions = junctions(rings.iter().flatten());
+
+        let mut arcs = vec![];
+        let mut arc_indices: BTreeMap<Vec<CoordKey<T>>, usize> = BTreeMap::new();
+        let polygon_arcs = rings
+            .iter()
+            .map(|rings| {
+                rings
+                    .iter()
+                    .map(|ring| {
+                        cut_ring(ring, &junctions)
+                            .into_iter()
+                            .map(|arc| {
+                                let key: Vec<_> = arc.iter().copied().map(CoordKey).collect();
+                                if let Some(&index) = arc_indices.get(&key) {
+                                    return index as isize;
+                                }
+                                let reversed: Vec<_> = key.iter().rev().copied().collect();
+                                if let Some(&index) = arc_indices.get(&reversed) {
+                                    return !(index as isize);
+                                }
+                                arc_indices.insert(key, arcs.len());
+                                arcs.push(LineString::new(arc));
+                                arcs.len() as isize - 1
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Topology { arcs, polygon_arcs }
+    }
+
+    /// Assemble a topology from its arcs, and the references to them of each ring of each
+    /// polygon, e.g. as read from TopoJSON.
+    ///
+    /// Returns `None` if any reference is to an arc which doesn't exist.
+    pub fn from_arcs(arcs: Vec<LineString<T>>, polygon_arcs: Vec<Vec<Vec<isize>>>) -> Option<Self> {
+        let valid = |&index: &isize| {
+            let index = if index < 0 { !index } else { index };
+            (index as usize) < arcs.len()
+        };
+        if !polygon_arcs.iter().flatten().flatten().all(valid) {
+            return None;
+        }
+        Some(Topology { arcs, polygon_arcs })
+    }
+
+    /// The distinct arcs of the rings of the polygons.
+    pub fn arcs(&self) -> &[LineString<T>] {
+        &self.arcs
+    }
+
+    /// For each polygon, for each of its rings, starting with the exterior, the references to the
+    /// arcs making it up.
+    pub fn polygon_arcs(&self) -> &[Vec<Vec<isize>>] {
+        &self.polygon_arcs
+    }
+
+    /// Replace every arc with `f(arc)`.
+    ///
+    /// Each arc is only passed to `f` once, however many rings it belongs to, so that rings
+    /// sharing it stay joined. `f` must keep the first and last coordinates of the arc, where it
+    /// joins other arcs, which is the case of e.g. [`Simplify`](crate::Simplify).
+    pub fn map_arcs(self, f: impl FnMut(&LineString<T>) -> LineString<T>) -> Self {
+        Topology {
+            arcs: self.arcs.iter().map(f).collect(),
+            polygon_arcs: self.polygon_arcs,
+        }
+    }
+
+    /// Join the arcs back into polygons.
+    pub fn to_polygons(&self) -> Vec<Polygon<T>> {
+        self.polygon_arcs
+            .iter()
+            .map(|rings| {
+                let mut rings = rings.iter().map(|ring| self.join_arcs(ring));
+                let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+                Polygon::new(exterior, rings.collect())
+            })
+            .collect()
+    }
+
+    fn join_arcs(&self, ring: &[isize]) -> LineString<T> {
+        let mut coords: Vec<Coord<T>> = vec![];
+        for &index in ring {
+            let skip = usize::from(!coords.is_empty());
+            if index < 0 {
+                coords.extend(self.arcs[!index as usize].0.iter().rev().skip(skip));
+            } else {
+                coords.extend(self.arcs[index as usize].0.iter().skip(skip));
+            }
+        }
+        LineString::new(coords)
+    }
+}
+
+// The vertices of a ring, without repeated consecutive coordinates, nor the closing coordinate.
+fn ring_vertices<T: CoordNum>(ring: &LineString<T>) -> Vec<Coord<T>> {
+    let mut vertices: Vec<Coord<T>> = ring.0.clone();
+    vertices.dedup();
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    vertices
+}
+
+// The vertices at which rings start or stop running alongside each other: those which don't
+// have the same pair of neighbours in every ring they belong to.
+fn junctions<'a, T: CoordNum + 'a>(
+    rings: impl Iterator<Item = &'a Vec<Coord<T>>>,
+) -> BTreeSet<CoordKey<T>> {
+    let mut neighbours: BTreeMap<CoordKey<T>, (CoordKey<T>, CoordKey<T>)> = BTreeMap::new();
+    let mut junctions = BTreeSet::new();
+    for ring in rings {
+        let n = ring.len();
+        for (i, vertex) in ring.iter().enumerate() {
+            let previous = CoordKey(ring[(i + n - 1) % n]);
+            let next = CoordKey(ring[(i + 1) % n]);
+            let pair = if previous <= next {
+                (previous, next)
+            } else {
+                (next, previous)
+            };
+            let expected = *neighbours.entry(CoordKey(*vertex)).or_insert(pair);
+            if expected != pair {
+                junctions.insert(CoordKey(*vertex));
+            }
+        }
+    }
+    junctions
+}
+
+// Cut the open ring of `vertices` into arcs at `junctions`. A ring without junctions is a single
+// closed arc, starting from its least vertex so that it's stored once whichever rings share it.
+fn cut_ring<T: CoordNum>(
+    vertices: &[Coord<T>],
+    junctions: &BTreeSet<CoordKey<T>>,
+) -> Vec<Vec<Coord<T>>> {
+    let n = vertices.len();
+    let is_junction = |coord: &Coord<T>| junctions.contains(&CoordKey(*coord));
+    let start = match vertices.iter().position(is_junction) {
+        Some(start) => start,
+        None => {
+            let start = least_index(vertices);
+            let mut arc = vertices[start..].to_vec();
+            arc.extend_from_slice(&vertices[..=start]);
+            return vec![arc];
+        }
+    };
+
+    let mut arcs = vec![];
+    let mut arc = vec![vertices[start]];
+    for i in 1..=n {
+        let coord = vertices[(start + i) % n];
+        arc.push(coord);
+        if i == n || is_junction(&coord) {
+            arcs.push(std::mem::replace(&mut arc, vec![coord]));
+        }
+    }
+    arcs
+}
+
+// A coordinate ordered lexicographically, to key maps with.
+#[derive(Debug, Clone, Copy)]
+struct CoordKey<T: CoordNum>(Coord<T>);
+
+impl<T: CoordNum> Ord for CoordKey<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        lex_cmp(&self.0, &other.0)
+    }
+}
+
+impl<T: CoordNum> PartialOrd for CoordKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: CoordNum> PartialEq for CoordKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: CoordNum> Eq for CoordKey<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, Simplify};
+
+    #[test]
+    fn shared_edge() {
+        let left = polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 1)];
+        let right = polygon![(x: 1, y: 0), (x: 2, y: 0), (x: 2, y: 1), (x: 1, y: 1)];
+        let topology = Topology::new(&[left, right]);
+
+        assert_eq!(
+            topology.arcs(),
+            &[
+                LineString::from(vec![(1, 0), (1, 1)]),
+                LineString::from(vec![(1, 1), (0, 1), (0, 0), (1, 0)]),
+                LineString::from(vec![(1, 0), (2, 0), (2, 1), (1, 1)]),
+            ]
+        );
+        assert_eq!(
+            topology.polygon_arcs(),
+            &[vec![vec![0, 1]], vec![vec![2, -1]]]
+        );
+        assert_eq!(
+            topology.to_polygons(),
+            vec![
+                polygon![(x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 1), (x: 0, y: 0)],
+                polygon![(x: 1, y: 0), (x: 2, y: 0), (x: 2, y: 1), (x: 1, y: 1)],
+            ]
+        );
+    }
+
+    #[test]
+    fn island_in_a_hole() {
+        let lake = polygon![
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 1, y: 3), (x: 3, y: 3), (x: 3, y: 1)]],
+        ];
+        let island = polygon![(x: 3, y: 3), (x: 1, y: 3), (x: 1, y: 1), (x: 3, y: 1)];
+        let topology = Topology::new(&[lake, island]);
+
+        // rings without junctions are single closed arcs, from their least vertex
+        assert_eq!(topology.arcs().len(), 2);
+        assert_eq!(
+            topology.arcs()[1],
+            LineString::from(vec![(1, 1), (1, 3), (3, 3), (3, 1), (1, 1)])
+        );
+        assert_eq!(
+            topology.polygon_arcs(),
+            &[vec![vec![0], vec![1]], vec![vec![!1]]]
+        );
+        assert_eq!(
+            topology.to_polygons()[1],
+            polygon![(x: 1, y: 1), (x: 3, y: 1), (x: 3, y: 3), (x: 1, y: 3)]
+        );
+    }
+
+    #[test]
+    fn simplifying_arcs_keeps_the_coverage() {
+        let left = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2.1, y: 0.5),
+            (x: 1.9, y: 1.),
+            (x: 2.1, y: 1.5),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let right = polygon![
+            (x: 2., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2.1, y: 1.5),
+            (x: 1.9, y: 1.),
+            (x: 2.1, y: 0.5),
+        ];
+        let topology = Topology::new(&[left, right]).map_arcs(|arc| arc.simplify(&0.5));
+        let polygons = topology.to_polygons();
+        assert_eq!(polygons[0].unsigned_area(), 4.);
+        assert_eq!(polygons[1].unsigned_area(), 4.);
+    }
+
+    #[test]
+    fn repeated_coords_and_empty_polygons() {
+        let square = polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 1)];
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        let topology = Topology::new(&[square, empty.clone()]);
+        assert_eq!(topology.arcs().len(), 1);
+        assert_eq!(topology.arcs()[0].0.len(), 5);
+        assert_eq!(topology.to_polygons()[1], empty);
+    }
+
+    #[test]
+    fn from_arcs() {
+        let arcs = vec![LineString::from(vec![(0, 0), (1, 0), (0, 1), (0, 0)])];
+        assert!(Topology::from_arcs(arcs.clone(), vec![vec![vec![0]], vec![vec![!0]]]).is_some());
+        assert!(Topology::from_arcs(arcs.clone(), vec![vec![vec![1]]]).is_none());
+        assert!(Topology::from_arcs(arcs, vec![vec![vec![!1]]]).is_none());
+    }
+}
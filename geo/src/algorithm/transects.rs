@@ -0,0 +1,84 @@
+use crate::line_tangent::normal;
+use crate::{CoordFloat, EuclideanLength, Line, LineMarkers, LineString};
+
+/// Cut transects across a [`LineString`] at regular intervals along it: lines perpendicular to
+/// it, centered on it, e.g. to sample the cross-sections of a river along its centerline, or the
+/// width of a corridor.
+///
+/// Transects start at the start of the line string, and go every `spacing` along it, up to its
+/// end, which only gets a transect if its length is a multiple of `spacing`. Each transect is
+/// `length` long, and goes from the right of the line string to its left, looking in the
+/// direction of travel. On a vertex, a transect is perpendicular to the segment starting there,
+/// except on the last vertex. Distances are euclidean, so the line string should be projected.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Line, Transects};
+///
+/// let river = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+///
+/// assert_eq!(
+///     river.transects(3., 2.),
+///     vec![
+///         Line::new((0., -1.), (0., 1.)),
+///         Line::new((3., -1.), (3., 1.)),
+///         Line::new((5., 2.), (3., 2.)),
+///     ]
+/// );
+/// ```
+pub trait Transects<T: CoordFloat> {
+    /// The transects `spacing` apart along the line string, each `length` long.
+    ///
+    /// Note: `spacing` must be greater than 0.
+    fn transects(&self, spacing: T, length: T) -> Vec<Line<T>>;
+}
+
+impl<T: CoordFloat> Transects<T> for LineString<T> {
+    fn transects(&self, spacing: T, length: T) -> Vec<Line<T>> {
+        let half = length / (T::one() + T::one());
+        let mut markers = self.markers(T::zero(), spacing);
+        let mut transects = vec![];
+        while let Some((point, line, _)) = markers.next_on_segment() {
+            let offset = normal(line.delta()) * (half / line.euclidean_length());
+            transects.push(Line::new(point.0 - offset, point.0 + offset));
+        }
+        transects
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn around_corners() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            // a repeated coordinate
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        assert_eq!(
+            line_string.transects(4., 2.),
+            vec![
+                Line::new((0., -1.), (0., 1.)),
+                // on the vertices, across the segments starting there
+                Line::new((5., 0.), (3., 0.)),
+                Line::new((4., 5.), (4., 3.)),
+                // across the last segment, on the last vertex
+                Line::new((0., 5.), (0., 3.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let point = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert!(point.transects(1., 1.).is_empty());
+        assert!(LineString::<f64>::new(vec![]).transects(1., 1.).is_empty());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coord, GeoFloat, LineString, Polygon, Relate};
+
+/// A problem with the interior rings of a polygon, found by [`ValidateRings`], with the indices
+/// of the rings in [`Polygon::interiors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RingProblem {
+    /// The interior ring isn't within the exterior ring.
+    InteriorOutsideExterior { interior: usize },
+    /// The interior ring touches the exterior ring at more than one point, e.g. along a segment,
+    /// which disconnects the interior of the polygon.
+    InteriorTouchesExterior { interior: usize },
+    /// The `inner` interior ring is within the `outer` one.
+    NestedInteriors { outer: usize, inner: usize },
+}
+
+/// Check that the interior rings of a polygon are valid holes in it: each lies within the
+/// exterior ring, touching it at one point at most, and none lies within another.
+///
+/// Rings which touch, or which are within another ring, are found by [`Relate`], and the
+/// points where an interior ring touches the exterior ring by intersecting every pair of their
+/// segments.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, RingProblem, ValidateRings};
+///
+/// let polygon = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         [(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)],
+///         [(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)],
+///     ],
+/// ];
+///
+/// assert_eq!(
+///     polygon.ring_problems(),
+///     vec![RingProblem::NestedInteriors { outer: 0, inner: 1 }]
+/// );
+/// ```
+pub trait ValidateRings {
+    /// The problems with the interior rings of the polygon: those with a single ring first, in
+    /// the order of the rings, then the nested rings, in the order of the outer, then the inner,
+    /// ring. Empty if the rings are valid.
+    fn ring_problems(&self) -> Vec<RingProblem>;
+}
+
+impl<T: GeoFloat> ValidateRings for Polygon<T> {
+    fn ring_problems(&self) -> Vec<RingProblem> {
+        let exterior = Polygon::new(self.exterior().clone(), vec![]);
+        let interiors: Vec<_> = self
+            .interiors()
+            .iter()
+            .map(|interior| Polygon::new(interior.clone(), vec![]))
+            .collect();
+
+        let mut problems = vec![];
+        for (idx, interior) in interiors.iter().enumerate() {
+            if !exterior.relate(interior).is_contains() {
+                problems.push(RingProblem::InteriorOutsideExterior { interior: idx });
+            } else if !touch_once_at_most(self.exterior(), interior.exterior()) {
+                problems.push(RingProblem::InteriorTouchesExterior { interior: idx });
+            }
+        }
+        for (i, a) in interiors.iter().enumerate() {
+            for (j, b) in interiors.iter().enumerate().skip(i + 1) {
+                let matrix = a.relate(b);
+                if matrix.is_contains() {
+                    problems.push(RingProblem::NestedInteriors { outer: i, inner: j });
+                } else if matrix.is_within() {
+                    problems.push(RingProblem::NestedInteriors { outer: j, inner: i });
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// Whether two rings touch at one point at most.
+fn touch_once_at_most<T: GeoFloat>(a: &LineString<T>, b: &LineString<T>) -> bool {
+    let mut touch: Option<Coord<T>> = None;
+    for a_line in a.lines() {
+        for b_line in b.lines() {
+            match line_intersection(a_line, b_line) {
+                None => {}
+                Some(LineIntersection::Collinear { .. }) => return false,
+                Some(LineIntersection::SinglePoint { intersection, .. }) => match touch {
+                    Some(point) if point != intersection => return false,
+                    _ => touch = Some(intersection),
+                },
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn problems() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                // touching the exterior at a single point
+                [(x: 0., y: 5.), (x: 2., y: 4.), (x: 2., y: 6.)],
+                [(x: 4., y: 4.), (x: 8., y: 4.), (x: 8., y: 8.), (x: 4., y: 8.)],
+                [(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)],
+                // crossing the exterior
+                [(x: 9., y: 1.), (x: 11., y: 1.), (x: 11., y: 2.), (x: 9., y: 2.)],
+                // along the exterior
+                [(x: 3., y: 0.), (x: 4., y: 1.), (x: 5., y: 0.)],
+                // touching the exterior at two points
+                [(x: 0., y: 9.), (x: 1., y: 8.), (x: 2., y: 9.), (x: 1., y: 10.)],
+            ],
+        ];
+        assert_eq!(
+            polygon.ring_problems(),
+            vec![
+                RingProblem::InteriorOutsideExterior { interior: 3 },
+                RingProblem::InteriorTouchesExterior { interior: 4 },
+                RingProblem::InteriorTouchesExterior { interior: 5 },
+                RingProblem::NestedInteriors { outer: 1, inner: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn valid() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 4.), (x: 1., y: 4.)],
+                // touching the other interior ring
+                [(x: 4., y: 4.), (x: 8., y: 4.), (x: 8., y: 8.)],
+            ],
+        ];
+        assert!(polygon.ring_problems().is_empty());
+        assert!(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]
+            .ring_problems()
+            .is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::iter::once;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::{Contains, Coord, GeoFloat, LineString, Point, Polygon};
+
+/// Calculate the [visibility polygon](https://en.wikipedia.org/wiki/Visibility_polygon), or
+/// isovist, of a point inside a polygon: the region of the polygon that can be seen from the
+/// point, when its boundary (including the boundaries of its holes) blocks the view.
+///
+/// The rays from the viewpoint through every vertex of the polygon are swept in angular order.
+/// Along each ray, the boundary of the visibility polygon runs from the first point blocking the
+/// view on one side of the ray to the first point blocking it on the other side, so that a ray
+/// grazing a vertex extends to the next edge behind it. The running time is `O(n²)` for a
+/// polygon with `n` vertices.
+///
+/// Returns `None` if the viewpoint does not lie in the interior of the polygon.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Area, Polygon, VisibilityPolygon};
+///
+/// // an L-shaped room
+/// let room: Polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 1.),
+///     (x: 1., y: 1.),
+///     (x: 1., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+///
+/// // in the corner of the room, everything is visible
+/// let visible = room.visibility_polygon(point!(x: 0.5, y: 0.5)).unwrap();
+/// assert_eq!(visible.unsigned_area(), room.unsigned_area());
+///
+/// // at the end of a corridor, the view around the corner is limited
+/// let visible = room.visibility_polygon(point!(x: 0.5, y: 3.5)).unwrap();
+/// assert!((visible.unsigned_area() - 4.1).abs() < 1e-9);
+/// ```
+pub trait VisibilityPolygon<T: GeoFloat> {
+    fn visibility_polygon(&self, viewpoint: Point<T>) -> Option<Polygon<T>>;
+}
+
+impl<T: GeoFloat> VisibilityPolygon<T> for Polygon<T> {
+    fn visibility_polygon(&self, viewpoint: Point<T>) -> Option<Polygon<T>> {
+        if !self.contains(&viewpoint) {
+            return None;
+        }
+        let origin = viewpoint.0;
+        let rings: Vec<Vec<Coord<T>>> = once(self.exterior())
+            .chain(self.interiors())
+            .map(ring_coords)
+            .filter(|ring| ring.len() >= 3)
+            .collect();
+
+        // The boundary of the visibility polygon can only change direction on the rays from the
+        // viewpoint through the vertices of the polygon, so sweep over these rays in angular
+        // order, merging the rays through several vertices.
+        let angle = |c: &Coord<T>| (c.y - origin.y).atan2(c.x - origin.x);
+        let mut targets: Vec<Coord<T>> = rings.iter().flatten().copied().collect();
+        targets.sort_by(|a, b| angle(a).partial_cmp(&angle(b)).unwrap_or(Ordering::Equal));
+        targets.dedup_by(|a, b| {
+            let (da, db) = (*a - origin, *b - origin);
+            side(origin, *a, *b) == Orientation::Collinear && da.x * db.x + da.y * db.y > T::zero()
+        });
+
+        let mut coords = vec![];
+        for target in targets {
+            let (before, after) = match ray_hits(origin, target, &rings) {
+                Some(hits) => hits,
+                None => continue,
+            };
+            // where a single edge crosses the ray, the boundary doesn't change direction
+            if before.crossing && after.crossing && before.coord == after.coord {
+                continue;
+            }
+            coords.push(before.coord);
+            coords.push(after.coord);
+        }
+        coords.dedup();
+        Some(Polygon::new(LineString::new(coords), vec![]))
+    }
+}
+
+// The coordinates of a ring, without the closing coordinate or repeated coordinates.
+fn ring_coords<T: GeoFloat>(ring: &LineString<T>) -> Vec<Coord<T>> {
+    let mut coords = ring.0.clone();
+    coords.dedup();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    coords
+}
+
+fn side<T: GeoFloat>(p: Coord<T>, q: Coord<T>, r: Coord<T>) -> Orientation {
+    T::Ker::orient2d(p, q, r)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Hit<T: GeoFloat> {
+    // distance along the ray, in multiples of the distance to its target
+    t: T,
+    coord: Coord<T>,
+    // whether an edge crosses the ray, rather than ending on it
+    crossing: bool,
+}
+
+// The first points where the ray from `origin` towards `target` is blocked by the boundary,
+// in the limit of rays rotated slightly clockwise, and slightly counter-clockwise.
+fn ray_hits<T: GeoFloat>(
+    origin: Coord<T>,
+    target: Coord<T>,
+    rings: &[Vec<Coord<T>>],
+) -> Option<(Hit<T>, Hit<T>)> {
+    let direction = target - origin;
+    let cross = |a: Coord<T>, b: Coord<T>| a.x * b.y - a.y * b.x;
+    let dot = |a: Coord<T>, b: Coord<T>| a.x * b.x + a.y * b.y;
+    let length_2 = dot(direction, direction);
+    let endpoint = |coord: Coord<T>| Hit {
+        t: dot(coord - origin, direction) / length_2,
+        coord,
+        crossing: false,
+    };
+
+    let mut clockwise: Option<Hit<T>> = None;
+    let mut counter_clockwise: Option<Hit<T>> = None;
+    let edges = rings
+        .iter()
+        .flat_map(|ring| (0..ring.len()).map(move |k| (ring[k], ring[(k + 1) % ring.len()])));
+    for (a, b) in edges {
+        // an edge ending on the ray only blocks rays on its side, while edges along the ray
+        // don't block rays on either side
+        let (hit, blocks) = match (side(origin, target, a), side(origin, target, b)) {
+            (Orientation::Collinear, Orientation::Collinear) => continue,
+            (Orientation::Collinear, side_b) => (endpoint(a), Some(side_b)),
+            (side_a, Orientation::Collinear) => (endpoint(b), Some(side_a)),
+            (side_a, side_b) if side_a != side_b => {
+                let t = cross(a - origin, b - a) / cross(direction, b - a);
+                let hit = Hit {
+                    t,
+                    coord: origin + direction * t,
+                    crossing: true,
+                };
+                (hit, None)
+            }
+            _ => continue,
+        };
+        if hit.t <= T::zero() {
+            continue;
+        }
+        let update = |nearest: &mut Option<Hit<T>>| {
+            if nearest.map_or(true, |nearest| hit.t < nearest.t) {
+                *nearest = Some(hit);
+            }
+        };
+        if blocks != Some(Orientation::CounterClockwise) {
+            update(&mut clockwise);
+        }
+        if blocks != Some(Orientation::Clockwise) {
+            update(&mut counter_clockwise);
+        }
+    }
+    Some((clockwise?, counter_clockwise?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Area};
+
+    #[test]
+    fn convex_polygon_is_fully_visible() {
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 5., y: 3.),
+            (x: 2., y: 5.),
+            (x: -1., y: 3.),
+        ];
+        let visible = poly.visibility_polygon(point!(x: 2., y: 2.)).unwrap();
+        assert_relative_eq!(visible.unsigned_area(), poly.unsigned_area());
+        assert_eq!(visible.exterior().0.len(), 6);
+    }
+
+    #[test]
+    fn hole_casts_shadow() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        let visible = poly.visibility_polygon(point!(x: 2., y: 5.)).unwrap();
+        // the shadow behind the hole is a trapezoid of area 30, minus the hole itself
+        assert_relative_eq!(visible.unsigned_area(), 100. - 30., epsilon = 1e-9);
+        assert!(visible.interiors().is_empty());
+        assert!(visible.exterior().0.contains(&(10., 9.).into()));
+        assert!(visible.exterior().0.contains(&(10., 1.).into()));
+    }
+
+    #[test]
+    fn grazing_along_collinear_vertices() {
+        // a comb, with the viewpoint in line with the tips of its teeth
+        let poly = polygon![
+            (x: 0., y: 0.),
+            (x: 6., y: 0.),
+            (x: 6., y: 4.),
+            (x: 5., y: 4.),
+            (x: 5., y: 2.),
+            (x: 4., y: 2.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let visible = poly.visibility_polygon(point!(x: 1., y: 2.)).unwrap();
+        // the top of the last tooth is hidden
+        assert_relative_eq!(visible.unsigned_area(), 20.);
+        assert_eq!(
+            visible.exterior().0,
+            vec![
+                (0., 0.).into(),
+                (6., 0.).into(),
+                (6., 2.).into(),
+                (4., 2.).into(),
+                (4., 4.).into(),
+                (0., 4.).into(),
+                (0., 0.).into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn outside_viewpoint() {
+        let poly = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        assert!(poly.visibility_polygon(point!(x: 2., y: 2.)).is_none());
+        assert!(poly.visibility_polygon(point!(x: 1., y: 0.5)).is_none());
+    }
+}
@@ -2,8 +2,8 @@ use super::kernels::*;
 use crate::coords_iter::CoordsIter;
 use crate::utils::EitherIter;
 use crate::{CoordNum, LineString, Point};
+use core::iter::Rev;
 use geo_types::PointsIter;
-use std::iter::Rev;
 
 /// Iterates through a list of `Point`s
 #[allow(missing_debug_implementations)]
@@ -25,6 +25,7 @@ where
 }
 
 /// How a linestring is wound, clockwise or counter-clockwise
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Clone, Debug, Eq, Copy)]
 pub enum WindingOrder {
     Clockwise,
@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/georust/meta/master/logo/logo.png")]
 
 //! The `geo` crate provides geospatial primitive types and algorithms.
@@ -35,16 +36,36 @@
 //!
 //! - **[`Area`](Area)**: Calculate the planar area of a geometry
 //! - **[`ChamberlainDuquetteArea`](ChamberlainDuquetteArea)**: Calculate the geodesic area of a geometry
+//! - **[`IntersectionArea`](IntersectionArea)**: Calculate the area of the intersection of two
+//!   polygons, and their intersection over union, without computing the intersection
+//! - **[`Overlap`](Overlap)**: Measure how much two polygons overlap, with their intersection
+//!   over union, the fractions of each covered by the other, and their Dice coefficient
+//! - **[`Thinness`](Thinness)**: Measure how thin a polygon is, with the ratio of its area to
+//!   its squared perimeter
 //!
 //! ## Boolean Operations
 //!
 //! - **[`BooleanOps`](BooleanOps)**: combine or split (Multi)Polygons using intersecton, union, xor, or difference operations
+//! - **[`ClipToRect`](ClipToRect)**: Clip a geometry to a rectangle, keeping its type, much
+//!   faster than a general intersection
+//! - **[`ClipToWindow`](ClipToWindow)**: Clip a geometry to a convex polygon or to
+//!   half-planes
+//! - **[`LayerOverlay`](LayerOverlay)**: Intersect every polygon of a layer with every polygon of another, tagging pieces with their sources
+//! - **[`rect_union`](rect_union::rect_union)**: Union many axis-aligned rectangles, and
+//!   measure the area they cover, much faster than with `BooleanOps`
+//! - **[`dissolve_by`](dissolve::dissolve_by)**: Group geometries by a key and union each group
+//! - **[`RemoveSlivers`](RemoveSlivers)**: Fill sliver holes, and merge sliver polygons into
+//!   their neighbours
+//! - **[`FillHoles`](FillHoles)**: Fill the holes of a polygon smaller than a planar or geodesic area
+//! - **[`Snap`](Snap)**: Snap the vertices of a geometry onto the vertices and edges of a reference geometry
 //!
 //! ## Distance
 //!
+//! - **[`Diameter`](Diameter)**: Calculate the largest euclidean distance between two points of a geometry, and find them
 //! - **[`EuclideanDistance`](EuclideanDistance)**: Calculate the minimum euclidean distance between geometries
 //! - **[`GeodesicDistance`](GeodesicDistance)**: Calculate the minimum geodesic distance between geometries using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //! - **[`HaversineDistance`](HaversineDistance)**: Calculate the minimum geodesic distance between geometries using the haversine formula
+//! - **[`MaxDistance`](MaxDistance)**: Calculate the maximum euclidean distance between geometries: the distance between their farthest points
 //! - **[`VincentyDistance`](VincentyDistance)**: Calculate the minimum geodesic distance between geometries using Vincenty’s formula
 //!
 //! ## Length
@@ -52,8 +73,15 @@
 //! - **[`EuclideanLength`](EuclideanLength)**: Calculate the euclidean length of a geometry
 //! - **[`GeodesicLength`](GeodesicLength)**: Calculate the geodesic length of a geometry using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //! - **[`HaversineLength`](HaversineLength)**: Calculate the geodesic length of a geometry using the haversine formula
+//! - **[`Perimeter`](Perimeter)**: Calculate the euclidean, haversine or geodesic perimeter of a
+//!   polygonal geometry, including its interior rings
 //! - **[`VincentyLength`](VincentyLength)**: Calculate the geodesic length of a geometry using Vincenty’s formula
 //!
+//! ## Clustering
+//!
+//! - **[`Dbscan`](Dbscan)**: Cluster points by density using [DBSCAN](https://en.wikipedia.org/wiki/DBSCAN)
+//! - **[`KMeans`](KMeans)**: Partition points into clusters using [k-means](https://en.wikipedia.org/wiki/K-means_clustering) (requires the `use-rand` feature)
+//!
 //! ## Outlier Detection
 //!
 //! - **[`OutlierDetection`](OutlierDetection)**: Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
@@ -65,23 +93,47 @@
 //! - **[`SimplifyVW`](SimplifyVW)**: Simplify a geometry using the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVWPreserve`](SimplifyVWPreserve)**: Simplify a geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwIdx`](SimplifyVwIdx)**: Calculate a simplified geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm, returning coordinate indices
+//! - **[`SimplifyMut`](SimplifyMut)**: Simplify a geometry in place using the Ramer–Douglas–Peucker algorithm, keeping its allocations
+//! - **[`SimplifyVwMut`](SimplifyVwMut)**: Simplify a geometry in place using the Visvalingam-Whyatt algorithm, keeping its allocations
+//! - **[`GeneralizeForScale`](GeneralizeForScale)**: Snap, simplify and drop the small parts and
+//!   holes of a geometry, with sizes chosen for a map scale
 //!
 //! ## Query
 //!
 //! - **[`Bearing`](Bearing)**: Calculate the bearing between points
+//! - **[`ClosestPair`](ClosestPair)**: Find the closest pair of points of a
+//!   [`MultiPoint`], and the distance between them
 //! - **[`ClosestPoint`](ClosestPoint)**: Find the point on a geometry
 //!   closest to a given point
+//! - **[`ElevationProfile`](ElevationProfile)**: Interpolate the elevation along a
+//!   [`LineStringZM`], and calculate its cumulative elevation gain and loss
 //! - **[`IsConvex`](IsConvex)**: Calculate the convexity of a
 //!   [`LineString`]
+//! - **[`IsMonotone`](IsMonotone)**: Calculate whether a geometry is monotone with respect to
+//!   a direction
 //! - **[`LineInterpolatePoint`](LineInterpolatePoint)**:
 //!   Generates a point that lies a given fraction along the line
 //! - **[`LineLocatePoint`](LineLocatePoint)**: Calculate the
 //!   fraction of a line’s total length representing the location of the closest point on the
 //!   line to the given point
+//! - **[`LineMarkers`](LineMarkers)**: Place markers at regular intervals along a [`LineString`],
+//!   with their headings and distances along it
+//! - **[`LineTangent`](LineTangent)**: Calculate the unit tangent and normal vectors of a
+//!   [`LineString`] at a distance along it
+//! - **[`Slope`](Slope)**: Calculate the slopes of the segments of a [`LineStringZM`], its
+//!   steepest grade, and its length by range of grades
+//! - **[`spatial_join`](spatial_join::spatial_join)**: Join each of many points to the polygons
+//!   containing them, intersecting them, or within a distance of them, using an R-tree
+//! - **[`Transects`](Transects)**: Cut perpendicular transects across a [`LineString`] at
+//!   regular intervals along it
 //!
 //! ## Similarity
 //!
 //! - **[`FrechetDistance`](FrechetDistance)**: Calculate the similarity between [`LineString`]s using the Fréchet distance
+//! - **[`GeoHashable`](GeoHashable)**: Hash geometries up to a precision, to deduplicate
+//!   near-identical geometries with hash sets
+//! - **[`dedup_geometries`](hashable::dedup_geometries)**: Remove the exact and near-identical
+//!   duplicates from a list of geometries, reporting which were removed
 //!
 //! ## Topology
 //!
@@ -89,17 +141,31 @@
 //!   geometry
 //! - **[`CoordinatePosition`](CoordinatePosition)**: Calculate
 //!   the position of a coordinate relative to a geometry
+//! - **[`EqualsTopo`](EqualsTopo)**: Calculate if two geometries consist of the same
+//!   points, however their coordinates are ordered
 //! - **[`HasDimensions`](HasDimensions)**: Determine the dimensions of a geometry
 //! - **[`Intersects`](Intersects)**: Calculate if a geometry intersects
 //!   another geometry
+//! - **[`intersections_between`](segment_intersections::intersections_between)**: Find every
+//!   intersection between the segments of two multi-line strings
 //! - **[`line_intersection`](line_intersection::line_intersection)**: Calculates the
 //!   intersection, if any, between two lines.
+//! - **[`line_string_intersection`](line_intersection::line_string_intersection)**: Calculates the
+//!   crossing points and overlapping parts of two line strings.
 //! - **[`Relate`](Relate)**: Topologically relate two geometries based on
 //!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
+//! - **[`ValidateRings`](ValidateRings)**: Check that the interior rings of a polygon lie
+//!   within its exterior, touch it at one point at most, and aren't nested
 //! - **[`Within`]**: Calculate if a geometry lies completely within another geometry.
+//! - **[`Bounded`](Bounded)**: Cache the bounding rectangle, and optionally an edge index, of a
+//!   geometry tested repeatedly with `Intersects` or `Contains`
 //!
 //! ## Winding
 //!
+//! - **[`Normalize`](Normalize)**: Rewrite a geometry into a canonical form, with sorted
+//!   parts, and rings wound conventionally from their least coordinate
+//! - **[`FixOrientation`](FixOrientation)**: Rewind the rings of the polygons of a geometry
+//!   wound against convention in place, throughout nested collections
 //! - **[`Orient`](Orient)**: Apply a specified winding [`Direction`](orient::Direction) to a [`Polygon`]’s interior and exterior rings
 //! - **[`Winding`](Winding)**: Calculate and manipulate the [`WindingOrder`](winding_order::WindingOrder) of a [`LineString`]
 //!
@@ -115,6 +181,10 @@
 //! - **[`TryMapCoordsInplace`](TryMapCoordsInplace)**: Map a fallible function over all
 //!   the coordinates in a geometry in-place
 //! - **[`LinesIter`](LinesIter)**: Iterate over lines of a geometry
+//! - **[`RotatingCalipers`](RotatingCalipers)**: Iterate over the antipodal pairs of vertices of two
+//!   convex polygons
+//! - **[`StreamingCoords`](StreamingCoords)**: Measure, simplify and densify an iterator of
+//!   coordinates in a single pass, without collecting it into a geometry
 //!
 //! ## Boundary
 //!
@@ -126,6 +196,9 @@
 //!   geometry
 //! - **[`Extremes`](Extremes)**: Calculate the extreme coordinates and
 //!   indices of a geometry
+//! - **[`GeodesicBoundingRect`](GeodesicBoundingRect)**: Calculate the
+//!   bounds of a geometry of longitudes and latitudes, along great circles
+//!   and across the antimeridian
 //!
 //! ## Affine transformations
 //!
@@ -134,29 +207,73 @@
 //! - **[`Skew`](Skew)**: Skew a geometry by shearing angles along the `x` and `y` dimension
 //! - **[`Translate`](Translate)**: Translate a geometry along its axis
 //! - **[`AffineOps`](AffineOps)**: generalised composable affine operations
+//! - **[`FitInto`](FitInto)**: Scale and translate a geometry to fit into a rectangle, optionally preserving its aspect ratio
 //!
 //! ## Conversion
 //!
 //! - **[`Convert`]**: Convert (infalliby) the type of a geometry’s coordinate value
 //! - **[`TryConvert`]**: Convert (falliby) the type of a geometry’s coordinate value
+//! - **[`Linearize`](Linearize)**: Approximate curved geometries, such as a `CircularString`, with straight segments
+//! - **[`ToGeoJson`](ToGeoJson)** / **[`TryFromGeoJson`](TryFromGeoJson)**: Convert geometries to and from GeoJSON, with RFC 7946 ring orientation and antimeridian-aware bounding boxes (requires the `use-geojson` feature)
+//! - **[`Topology`](Topology)**: Encode polygons sharing boundaries as TopoJSON-style shared arcs, and decode them back
 //!
 //! ## Miscellaneous
 //!
 //! - **[`Centroid`](Centroid)**: Calculate the centroid of a geometry
+//! - **[`Centrography`](Centrography)**: Calculate the mean center, median center, standard distance and standard deviational ellipse of a set of points
 //! - **[`HaversineDestination`](HaversineDestination)**:
 //! - **[`HaversineIntermediate`](HaversineIntermediate)**:
+//! - **[`random`](random)**: Generate random polygons and line strings for testing and benchmarking (requires the `use-rand` feature)
 //! - **[`proj`](proj)**: Project geometries with the `proj` crate (requires the `use-proj` feature)
 //! - **[`ChaikinSmoothing`](ChaikinSmoothing)**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
+//! - **[`AreaPreservingChaikinSmoothing`](AreaPreservingChaikinSmoothing)**: Smoothen `Polygon` and `MultiPolygon` using Chaikins algorithm, preserving the area of their rings
 //! - **[`Densify`](Densify)**: Densify linear geometry components by interpolating points
+//! - **[`Resample`](Resample)**: Resample a `LineString` into coordinates evenly spaced along it, by count or by distance
+//! - **[`StayPoints`](StayPoints)**: Detect where a trajectory stayed, by vertex count or, with [`TimedStayPoints`](TimedStayPoints), by duration
+//! - **[`MonotoneDecomposition`](MonotoneDecomposition)**: Decompose a polygon into y-monotone pieces
+//! - **[`GridSample`](GridSample)**: Sample points on a regular or stratified grid within a geometry
+//! - **[`SamplePoints`](SamplePoints)**: Sample uniformly distributed random points from the interior of a geometry (requires the `use-rand` feature)
+//! - **[`Tessellate`](Tessellate)**: Tessellate a geometry into square or hexagonal grid cells
+//! - **[`slice_to_tiles`](tiles::slice_to_tiles)**: Cut a geometry into the Web Mercator tiles
+//!   of a zoom level, with a buffer, for vector tiles
+//! - **[`tiles_covering`](tiles::tiles_covering)**: Find the Web Mercator tiles of a zoom level
+//!   which a geometry intersects, or which are wholly within it
+//! - **[`quantize_to_tile`](tiles::quantize_to_tile)**: Quantize a geometry into the integer
+//!   coordinates of a tile, cleaning up the parts made degenerate, for vector-tile encoders
+//! - **[`ContourGrid`](ContourGrid)**: Extract isolines and isobands from a grid of values
+//! - **[`Idw`](Idw)**: Interpolate values from scattered samples using inverse distance weighting
+//! - **[`VisibilityPolygon`](VisibilityPolygon)**: Calculate the region of a polygon visible from a point inside it
+//! - **[`line_of_sight`](line_of_sight::line_of_sight)**: Test whether the segment between two points is clear of obstacle polygons
+//! - **[`PolygonShortestPath`](PolygonShortestPath)**: Find the shortest path between two points that stays inside a polygon
+//! - **[`Centerline`](Centerline)**: Extract the main centerline of an elongated polygon, approximating its medial axis
+//! - **[`LabelPoint`](LabelPoint)**: Find where to anchor the label of a line or a polygon, at its
+//!   longest straight run or its pole of inaccessibility
+//! - **[`batch`](algorithm::batch)**: Evaluate orientations, point-segment distances and bounding box intersections over slices of coordinates
 //! - **[`Transform`](Transform)**: Transform a geometry using Proj.
+//! - **[`ToSvg`](ToSvg)**: Draw a geometry as an SVG path or document, e.g. to debug the output of an algorithm
+//! - **[`LineGraph`](LineGraph)**: Build a network graph of linework, noded at the endpoints and intersections of its line strings, find shortest paths over it, and match GPS traces onto it
+//! - **[`SplitAntimeridian`](SplitAntimeridian)**: Cut geometries crossing the antimeridian into pieces which don't
+//! - **[`WrapLongitude`](WrapLongitude)**: Wrap longitudes into the range from -180 to 180
 //!
 //! # Features
 //!
 //! The following optional [Cargo features] are available:
 //!
 //! - `proj-network`: Enables [network grid] support for the [`proj` crate]. After enabling this feature, [further configuration][proj crate file download] is required to use the network grid
+//! - `std`: Enables use of the full `std` library. Enabled by default. Without it, only a subset of the algorithms is available (`Area`, `EuclideanLength`, `Centroid`, `ConvexHull`, `Simplify`, `BoundingRect`, `MapCoords` and the affine transforms), which only depends on `alloc`, for `no_std` environments
+//! - `use-arrow`: Allows geometries to be read from, and written to, the buffers of GeoArrow arrays, mostly without copying
+//! - `use-geojson`: Enables conversion of geometries to and from the types of the [`geojson` crate][geojson crate]
+//! - `use-polyline`: Allows `LineString<f64>` to be encoded as, and decoded from, Google's Encoded Polyline format
 //! - `use-proj`: Enables coordinate conversion and transformation of `Point` geometries using the [`proj` crate]
-//! - `use-serde`: Allows geometry types to be serialized and deserialized with [Serde]
+//! - `use-rand`: Enables algorithms which generate random geometries using the [`rand` crate]
+//! - `use-rational`: Enables exact rational coordinates, with the [`Rational`](kernels::Rational) type and its kernel
+//! - `use-rayon`: Enables parallel versions of algorithms over the components of multi-part geometries, in the [`parallel`](parallel) module, using the [`rayon` crate]
+//! - `use-serde`: Allows geometry types to be serialized and deserialized with [Serde], along with the results of algorithms such as [`Extremes`], [`Closest`] and the [`IntersectionMatrix`](relate::IntersectionMatrix) of [`Relate`]
+//! - `use-shapefile`: Allows geometries to be converted to and from the shapes of the [`shapefile` crate][shapefile crate]
+//! - `use-simd`: Vectorizes the batched predicates and distances of the [`batch`](algorithm::batch) module with AVX instructions, on x86-64 CPUs supporting them
+//! - `use-smallvec`: Adds `geo_types`' line strings and polygons which store the coordinates of small rings inline rather than allocating them, and which the algorithms process through their `LineStringRef` and `PolygonRef` views
+//! - `use-wkb`: Allows geometry types to be written as, and read from, Well-Known Binary, including PostGIS' EWKB with SRIDs
+//! - `use-wkt`: Allows geometry types to be written as, and parsed from, Well-Known Text with `Display` and `FromStr`
 //!
 //! # Ecosystem
 //!
@@ -172,6 +289,8 @@
 //!
 //! [`geo-types`]: https://crates.io/crates/geo-types
 //! [`proj` crate]: https://github.com/georust/proj
+//! [`rand` crate]: https://crates.io/crates/rand
+//! [`rayon` crate]: https://crates.io/crates/rayon
 //! [geojson crate]: https://crates.io/crates/geojson
 //! [wkt crate]: https://crates.io/crates/wkt
 //! [shapefile crate]: https://crates.io/crates/shapefile
@@ -187,13 +306,22 @@
 //! [proj crate file download]: https://docs.rs/proj/*/proj/#grid-file-download
 //! [Serde]: https://serde.rs/
 
+extern crate alloc;
 extern crate geo_types;
 extern crate num_traits;
 #[cfg(feature = "use-serde")]
 #[macro_use]
 extern crate serde;
+#[cfg(feature = "use-geojson")]
+extern crate geojson;
+#[cfg(feature = "use-rational")]
+extern crate num_rational;
 #[cfg(feature = "use-proj")]
 extern crate proj;
+#[cfg(feature = "use-rand")]
+extern crate rand;
+#[cfg(feature = "use-rayon")]
+extern crate rayon;
 extern crate rstar;
 
 pub use crate::algorithm::*;
@@ -225,15 +353,19 @@ extern crate log;
 /// https://link.springer.com/article/10.1007%2Fs001900050278
 /// https://sci-hub.se/https://doi.org/10.1007/s001900050278
 /// https://en.wikipedia.org/wiki/Earth_radius#Mean_radius
+#[cfg(feature = "std")]
 const MEAN_EARTH_RADIUS: f64 = 6371008.8;
 
 // Radius of Earth at the equator in meters (derived from the WGS-84 ellipsoid)
+#[cfg(feature = "std")]
 const EQUATORIAL_EARTH_RADIUS: f64 = 6_378_137.0;
 
 // Radius of Earth at the poles in meters (derived from the WGS-84 ellipsoid)
+#[cfg(feature = "std")]
 const POLAR_EARTH_RADIUS: f64 = 6_356_752.314_245;
 
 // Flattening of the WGS-84 ellipsoid - https://en.wikipedia.org/wiki/Flattening
+#[cfg(feature = "std")]
 const EARTH_FLATTENING: f64 =
     (EQUATORIAL_EARTH_RADIUS - POLAR_EARTH_RADIUS) / EQUATORIAL_EARTH_RADIUS;
 
@@ -274,22 +406,63 @@ pub mod prelude {
 /// }
 /// ```
 pub trait GeoFloat:
-    GeoNum
-    + num_traits::Float
-    + num_traits::Signed
-    + num_traits::Bounded
-    + float_next_after::NextAfter<Self>
+    GeoNum + num_traits::Float + num_traits::Signed + num_traits::Bounded + NextAfter
 {
 }
 impl<T> GeoFloat for T where
-    T: GeoNum
-        + num_traits::Float
-        + num_traits::Signed
-        + num_traits::Bounded
-        + float_next_after::NextAfter<Self>
+    T: GeoNum + num_traits::Float + num_traits::Signed + num_traits::Bounded + NextAfter
 {
 }
 
 /// A trait for methods which work for both integers **and** floating point
 pub trait GeoNum: CoordNum + HasKernel {}
 impl<T> GeoNum for T where T: CoordNum + HasKernel {}
+
+/// Stepping to the adjacent floating point value, which every [`GeoFloat`] must support.
+///
+/// # Examples
+///
+/// ```
+/// use geo::NextAfter;
+///
+/// assert_eq!(1_f64.next_after(f64::INFINITY), 1. + f64::EPSILON);
+/// assert_eq!(0_f32.next_after(f32::NEG_INFINITY), -f32::from_bits(1));
+/// assert_eq!(f64::MAX.next_after(f64::INFINITY), f64::INFINITY);
+/// assert!(f64::NAN.next_after(1.).is_nan());
+/// ```
+pub trait NextAfter: Sized {
+    /// The next representable value after `self` in the direction of `y`, or `y` if they're
+    /// equal. Subnormal values aren't skipped.
+    fn next_after(self, y: Self) -> Self;
+}
+
+macro_rules! next_after_impl {
+    ($float:ident) => {
+        impl NextAfter for $float {
+            fn next_after(self, y: Self) -> Self {
+                if self.is_nan() || y.is_nan() {
+                    return $float::NAN;
+                }
+                if self == y {
+                    return y;
+                }
+                if self == 0. {
+                    // the smallest subnormal, with the sign of the direction
+                    let smallest = $float::from_bits(1);
+                    return if y > 0. { smallest } else { -smallest };
+                }
+                // consecutive floats of the same sign have consecutive bit patterns, growing
+                // away from zero
+                let bits = self.to_bits();
+                $float::from_bits(if (y > self) == (self > 0.) {
+                    bits + 1
+                } else {
+                    bits - 1
+                })
+            }
+        }
+    };
+}
+
+next_after_impl!(f32);
+next_after_impl!(f64);
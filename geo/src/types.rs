@@ -15,6 +15,7 @@ pub enum Closest<F: GeoFloat> {
 impl<F: GeoFloat> Closest<F> {
     /// Compare two `Closest`s relative to `p` and return a copy of the best
     /// one.
+    #[cfg(feature = "std")]
     pub fn best_of_two(&self, other: &Self, p: Point<F>) -> Self {
         use crate::EuclideanDistance;
 